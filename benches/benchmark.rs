@@ -96,6 +96,29 @@ fn benchmark_pagerank(c: &mut Criterion) {
         );
     }
     group.finish();
+
+    // Large synthetic graph, where the inner loops dominate
+    let mut builder = graph::builder::GraphBuilder::new();
+    let nodes: Vec<u32> = (0..20_000)
+        .map(|i| builder.get_or_create_node(&format!("w{}", i)))
+        .collect();
+    let mut state: u64 = 42;
+    for (i, &node) in nodes.iter().enumerate() {
+        for _ in 0..8 {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            let other = nodes[(state >> 33) as usize % nodes.len()];
+            builder.increment_edge(node, other, 1.0 + (i % 3) as f64);
+        }
+    }
+    let large_graph = graph::csr::CsrGraph::from_builder(&builder);
+    c.bench_function("pagerank_large_20k", |b| {
+        b.iter(|| pagerank::standard::StandardPageRank::new().run(black_box(&large_graph)))
+    });
+    c.bench_function("pagerank_large_20k_f32", |b| {
+        b.iter(|| {
+            pagerank::single_precision::SinglePrecisionPageRank::new().run(black_box(&large_graph))
+        })
+    });
 }
 
 fn benchmark_phrase_extraction(c: &mut Criterion) {
@@ -391,6 +414,29 @@ fn benchmark_stopwords(c: &mut Criterion) {
     });
 }
 
+fn benchmark_batch(c: &mut Criterion) {
+    let config = TextRankConfig::default();
+    let texts: Vec<String> = (0..256)
+        .map(|i| format!("{} Document {}.", SAMPLE_TEXT, i))
+        .collect();
+    let docs: Vec<BatchDocument> = texts.iter().map(BatchDocument::from).collect();
+
+    let mut group = c.benchmark_group("batch_256_docs");
+    group.throughput(Throughput::Elements(docs.len() as u64));
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            texts
+                .iter()
+                .map(|t| extract_from_text_with_info(black_box(t), &config))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("extract_batch", |b| {
+        b.iter(|| extract_batch(black_box(&docs), &config))
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     benchmark_tokenization,
@@ -400,6 +446,7 @@ criterion_group!(
     benchmark_full_pipeline,
     benchmark_pipeline_vs_direct,
     benchmark_stopwords,
+    benchmark_batch,
 );
 
 criterion_main!(benches);
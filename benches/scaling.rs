@@ -0,0 +1,135 @@
+//! Scaling benchmarks on synthetic documents
+//!
+//! Each stage is measured across input sizes generated by
+//! [`SyntheticCorpus`], so regressions show up as a change in slope as well
+//! as in absolute time. Inputs are deterministic; compare runs with
+//! `cargo bench --bench scaling -- --save-baseline <name>` and
+//! `--baseline <name>`.
+
+mod synthetic;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rapid_textrank::graph::builder::{build_graph_parallel_with_pos_and_boundaries, GraphBuilder};
+use rapid_textrank::graph::csr::CsrGraph;
+use rapid_textrank::nlp::tokenizer::Tokenizer;
+use rapid_textrank::pagerank::standard::StandardPageRank;
+use rapid_textrank::*;
+use synthetic::{Lcg, SyntheticCorpus};
+
+/// Document lengths in words
+const DOC_WORDS: [usize; 4] = [500, 5_000, 50_000, 200_000];
+
+/// Vocabulary sizes for the vocabulary sweep
+const VOCAB_SIZES: [usize; 3] = [500, 5_000, 50_000];
+
+const DEFAULT_VOCAB: usize = 5_000;
+
+fn tokens_for(corpus: &SyntheticCorpus, config: &TextRankConfig) -> Vec<Token> {
+    Tokenizer::new().tokenize_with_config(&corpus.document(0), config)
+}
+
+fn bench_tokenize(c: &mut Criterion) {
+    let config = TextRankConfig::default();
+    let tokenizer = Tokenizer::new();
+    let mut group = c.benchmark_group("scaling/tokenize");
+    for words in DOC_WORDS {
+        let text = SyntheticCorpus::new(DEFAULT_VOCAB, words).document(0);
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("owned", words), &text, |b, text| {
+            b.iter(|| tokenizer.tokenize_with_config(black_box(text), &config))
+        });
+        group.bench_with_input(BenchmarkId::new("token_stream", words), &text, |b, text| {
+            b.iter(|| pipeline::TokenStream::from_text(black_box(text), &config))
+        });
+    }
+    group.finish();
+}
+
+fn bench_graph_build(c: &mut Criterion) {
+    let config = TextRankConfig::default();
+    let mut group = c.benchmark_group("scaling/graph_build");
+    for words in DOC_WORDS {
+        let tokens = tokens_for(&SyntheticCorpus::new(DEFAULT_VOCAB, words), &config);
+        group.throughput(Throughput::Elements(tokens.len() as u64));
+        group.bench_with_input(BenchmarkId::new("words", words), &tokens, |b, tokens| {
+            b.iter(|| GraphBuilder::from_tokens(black_box(tokens), config.window_size, true))
+        });
+        group.bench_with_input(BenchmarkId::new("chunked", words), &tokens, |b, tokens| {
+            b.iter(|| {
+                build_graph_parallel_with_pos_and_boundaries(
+                    black_box(tokens),
+                    config.window_size,
+                    true,
+                    None,
+                    false,
+                    false,
+                )
+            })
+        });
+    }
+    for vocab in VOCAB_SIZES {
+        let tokens = tokens_for(&SyntheticCorpus::new(vocab, 50_000), &config);
+        group.bench_with_input(BenchmarkId::new("vocab", vocab), &tokens, |b, tokens| {
+            b.iter(|| GraphBuilder::from_tokens(black_box(tokens), config.window_size, true))
+        });
+    }
+    group.finish();
+}
+
+/// Random graph with `nodes` nodes and about `nodes * degree / 2` edges
+fn random_graph(nodes: usize, degree: usize) -> CsrGraph {
+    let mut builder = GraphBuilder::with_capacity(nodes);
+    let ids: Vec<u32> = (0..nodes)
+        .map(|i| builder.get_or_create_node(&format!("n{i}")))
+        .collect();
+    let mut rng = Lcg::new(7);
+    for &node in &ids {
+        for _ in 0..degree / 2 {
+            let other = ids[rng.next_u64() as usize % nodes];
+            builder.increment_edge(node, other, 1.0);
+        }
+    }
+    CsrGraph::from_builder(&builder)
+}
+
+fn bench_pagerank(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scaling/pagerank");
+    for (nodes, degree) in [(1_000, 8), (10_000, 8), (10_000, 64), (100_000, 8)] {
+        let graph = random_graph(nodes, degree);
+        group.throughput(Throughput::Elements(graph.num_edges() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{nodes}n_{degree}d")),
+            &graph,
+            |b, graph| b.iter(|| StandardPageRank::new().run(black_box(graph))),
+        );
+    }
+    group.finish();
+}
+
+fn bench_end_to_end(c: &mut Criterion) {
+    let config = TextRankConfig::default().with_top_n(10);
+    let mut group = c.benchmark_group("scaling/end_to_end");
+    for words in DOC_WORDS {
+        let text = SyntheticCorpus::new(DEFAULT_VOCAB, words).document(0);
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_with_input(BenchmarkId::new("textrank", words), &text, |b, text| {
+            b.iter(|| extract_from_text_with_info(black_box(text), &config))
+        });
+    }
+
+    let docs = SyntheticCorpus::new(DEFAULT_VOCAB, 1_000).documents(256);
+    let batch: Vec<BatchDocument> = docs.iter().map(BatchDocument::from).collect();
+    group.throughput(Throughput::Elements(batch.len() as u64));
+    group.bench_function("batch_256x1000", |b| {
+        b.iter(|| extract_batch(black_box(&batch), &config))
+    });
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = bench_tokenize, bench_graph_build, bench_pagerank, bench_end_to_end
+}
+
+criterion_main!(benches);
@@ -0,0 +1,153 @@
+//! Deterministic synthetic documents for benchmarks
+//!
+//! Words are drawn from a fixed vocabulary with Zipf-distributed frequencies,
+//! so term repetition (and therefore graph density) resembles natural text.
+//! Every document is a pure function of the corpus parameters and its
+//! index: runs on different machines and releases benchmark the same input.
+
+/// Function words mixed into sentences so stopword filtering and phrase
+/// boundaries have work to do
+const FUNCTION_WORDS: &[&str] = &["the", "of", "and", "in", "to", "a", "with", "for", "is"];
+
+/// Share of tokens drawn from [`FUNCTION_WORDS`], in percent
+const FUNCTION_WORD_PERCENT: u64 = 30;
+
+const ONSETS: &[&str] = &[
+    "b", "d", "f", "g", "k", "l", "m", "n", "p", "r", "s", "t", "v", "z",
+];
+const VOWELS: &[&str] = &["a", "e", "i", "o", "u"];
+
+/// Suffixes chosen so the heuristic tagger sees nouns, adjectives and verbs
+const SUFFIXES: &[&str] = &["", "", "", "", "", "", "al", "ive", "ing"];
+
+/// Parameters of a synthetic corpus
+#[derive(Debug, Clone, Copy)]
+pub struct SyntheticCorpus {
+    /// Number of distinct content words
+    pub vocab_size: usize,
+    /// Words per document
+    pub doc_words: usize,
+    /// Words per sentence
+    pub sentence_words: usize,
+    /// Seed; documents differ by seed and index
+    pub seed: u64,
+}
+
+impl SyntheticCorpus {
+    /// A corpus of `doc_words`-word documents over `vocab_size` content words
+    pub fn new(vocab_size: usize, doc_words: usize) -> Self {
+        Self {
+            vocab_size: vocab_size.max(1),
+            doc_words,
+            sentence_words: 20,
+            seed: 42,
+        }
+    }
+
+    /// Document number `index` of the corpus
+    pub fn document(&self, index: u64) -> String {
+        let vocab: Vec<String> = (0..self.vocab_size).map(word).collect();
+        let zipf = Zipf::new(self.vocab_size);
+        let mut rng = Lcg::new(self.seed ^ index.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        let mut text = String::with_capacity(self.doc_words * 8);
+
+        for i in 0..self.doc_words {
+            let sentence_pos = i % self.sentence_words.max(1);
+            let word = if rng.next_u64() % 100 < FUNCTION_WORD_PERCENT {
+                FUNCTION_WORDS[rng.next_u64() as usize % FUNCTION_WORDS.len()]
+            } else {
+                &vocab[zipf.sample(&mut rng)]
+            };
+            if sentence_pos == 0 {
+                if i > 0 {
+                    text.push(' ');
+                }
+                let mut chars = word.chars();
+                if let Some(first) = chars.next() {
+                    text.extend(first.to_uppercase());
+                    text.push_str(chars.as_str());
+                }
+            } else {
+                text.push(' ');
+                text.push_str(word);
+            }
+            if sentence_pos + 1 == self.sentence_words || i + 1 == self.doc_words {
+                text.push('.');
+            }
+        }
+        text
+    }
+
+    /// The first `count` documents of the corpus
+    pub fn documents(&self, count: u64) -> Vec<String> {
+        (0..count).map(|i| self.document(i)).collect()
+    }
+}
+
+/// Pronounceable word for vocabulary index `index`: two or more
+/// consonant-vowel syllables plus a part-of-speech suffix
+fn word(index: usize) -> String {
+    let mut out = String::new();
+    let mut n = index;
+    for _ in 0..2 {
+        out.push_str(ONSETS[n % ONSETS.len()]);
+        n /= ONSETS.len();
+        out.push_str(VOWELS[n % VOWELS.len()]);
+        n /= VOWELS.len();
+    }
+    // Remaining high digits keep large vocabularies collision-free.
+    while n > 0 {
+        out.push_str(ONSETS[n % ONSETS.len()]);
+        n /= ONSETS.len();
+        out.push('o');
+    }
+    out.push_str(SUFFIXES[index % SUFFIXES.len()]);
+    out
+}
+
+/// Zipf(1) sampler over ranks `0..n` by inverse CDF lookup
+struct Zipf {
+    cumulative: Vec<f64>,
+}
+
+impl Zipf {
+    fn new(n: usize) -> Self {
+        let mut total = 0.0;
+        let cumulative = (1..=n)
+            .map(|rank| {
+                total += 1.0 / rank as f64;
+                total
+            })
+            .collect();
+        Self { cumulative }
+    }
+
+    fn sample(&self, rng: &mut Lcg) -> usize {
+        let total = self.cumulative.last().copied().unwrap_or(0.0);
+        let target = rng.next_f64() * total;
+        self.cumulative
+            .partition_point(|&c| c < target)
+            .min(self.cumulative.len() - 1)
+    }
+}
+
+/// 64-bit linear congruential generator (Knuth's MMIX constants)
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0 >> 33
+    }
+
+    pub fn next_f64(&mut self) -> f64 {
+        self.next_u64() as f64 / (1u64 << 31) as f64
+    }
+}
@@ -0,0 +1,75 @@
+//! Keyphrase quality on a standard benchmark.
+//!
+//! Extracts the top 15 phrases of every document with `extract_batch` and
+//! prints exact- and stem-match P/R/F1@{5,10,15}, macro-averaged, plus
+//! micro-averaged F1:
+//!
+//! ```text
+//! cargo run --release --features stemming --example benchmark_quality -- inspec Inspec/test
+//! cargo run --release --features stemming --example benchmark_quality -- \
+//!     semeval2010 SemEval2010/test SemEval2010/test.combined.final
+//! cargo run --release --features stemming --example benchmark_quality -- keys DUC2001/docs DUC2001/keys
+//! ```
+//!
+//! Without the `stemming` feature the stem-match rows equal the exact ones.
+
+use std::process::ExitCode;
+
+use rapid_textrank::eval::{load_inspec, load_key_files, load_semeval2010, Evaluator, MatchMode};
+use rapid_textrank::pipeline::artifacts::PhraseSet;
+use rapid_textrank::{extract_batch, BatchDocument, StringPool, TextRankConfig};
+
+const USAGE: &str = "usage: benchmark_quality <inspec DIR | semeval2010 DIR KEYS | keys DOCS KEYS>";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let dataset = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["inspec", dir] => load_inspec(dir),
+        ["semeval2010", docs, keys] => load_semeval2010(docs, keys),
+        ["keys", docs, keys] => load_key_files(docs, keys),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let docs = match dataset.and_then(|d| d.load_all()) {
+        Ok(docs) => docs,
+        Err(e) => {
+            eprintln!("benchmark_quality: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let config = TextRankConfig::default().with_top_n(15);
+    let batch: Vec<BatchDocument> = docs.iter().map(BatchDocument::from).collect();
+    let results = extract_batch(&batch, &config);
+
+    let mut pool = StringPool::new();
+    let predicted: Vec<PhraseSet> = results
+        .iter()
+        .map(|r| PhraseSet::from_phrases(&r.phrases, &mut pool))
+        .collect();
+
+    println!("{} documents", docs.len());
+    println!(
+        "{:<8} {:>4} {:>8} {:>8} {:>8} {:>9}",
+        "match", "k", "P", "R", "F1", "micro F1"
+    );
+    for (name, mode) in [("exact", MatchMode::Exact), ("stem", MatchMode::Stem)] {
+        for k in [5, 10, 15] {
+            let evaluator = Evaluator::new().with_match_mode(mode).with_k(k);
+            let scores = evaluator.evaluate_dataset(
+                predicted
+                    .iter()
+                    .zip(&docs)
+                    .map(|(p, d)| (p, d.gold.as_slice())),
+            );
+            let m = scores.macro_avg;
+            println!(
+                "{name:<8} {k:>4} {:>8.4} {:>8.4} {:>8.4} {:>9.4}",
+                m.precision, m.recall, m.f1, scores.micro_avg.f1
+            );
+        }
+    }
+    ExitCode::SUCCESS
+}
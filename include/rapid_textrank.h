@@ -0,0 +1,158 @@
+#ifndef RAPID_TEXTRANK_H
+#define RAPID_TEXTRANK_H
+
+/* Generated by cbindgen from src/capi.rs. Do not edit by hand. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// Status code returned by every fallible C API function.
+typedef enum RtrStatus {
+  // Success
+  RTR_STATUS_OK = 0,
+  // [`ErrorCode::MissingStage`]
+  RTR_STATUS_MISSING_STAGE = 1,
+  // [`ErrorCode::InvalidCombo`]
+  RTR_STATUS_INVALID_COMBO = 2,
+  // [`ErrorCode::ModuleUnavailable`]
+  RTR_STATUS_MODULE_UNAVAILABLE = 3,
+  // [`ErrorCode::LimitExceeded`]
+  RTR_STATUS_LIMIT_EXCEEDED = 4,
+  // [`ErrorCode::UnknownField`]
+  RTR_STATUS_UNKNOWN_FIELD = 5,
+  // [`ErrorCode::InvalidValue`]
+  RTR_STATUS_INVALID_VALUE = 6,
+  // [`ErrorCode::IncompatibleModules`]
+  RTR_STATUS_INCOMPATIBLE_MODULES = 7,
+  // [`ErrorCode::ValidationFailed`]
+  RTR_STATUS_VALIDATION_FAILED = 8,
+  // [`ErrorCode::StageFailed`]
+  RTR_STATUS_STAGE_FAILED = 9,
+  // [`ErrorCode::ConvergenceFailed`]
+  RTR_STATUS_CONVERGENCE_FAILED = 10,
+  // [`ErrorCode::Timeout`]
+  RTR_STATUS_TIMEOUT = 11,
+  // [`ErrorCode::Cancelled`]
+  RTR_STATUS_CANCELLED = 12,
+  // A required pointer argument was null
+  RTR_STATUS_NULL_POINTER = 100,
+  // A string argument was not valid UTF-8
+  RTR_STATUS_INVALID_UTF8 = 101,
+  // The call panicked
+  RTR_STATUS_PANIC = 102,
+} RtrStatus;
+
+// Extraction settings: a [`TextRankConfig`] plus the preset to run.
+typedef struct RtrConfig RtrConfig;
+
+// One phrase of an [`RtrResult`]. Strings are NUL-terminated UTF-8 owned
+// by the result.
+typedef struct RtrPhrase {
+  const char *text;
+  const char *lemma;
+  double score;
+  size_t count;
+  size_t rank;
+} RtrPhrase;
+
+// Extraction result; free with [`rtr_result_free`].
+typedef struct RtrResult {
+  // `len` phrases in rank order.
+  const struct RtrPhrase *phrases;
+  size_t len;
+  bool converged;
+  uint32_t iterations;
+} RtrResult;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Message of the last failed call on this thread (empty if none).
+//
+// The pointer stays valid until the next failing call on the same thread.
+const char *rtr_last_error_message(void);
+
+// Stable name of a status code, e.g. `"limit_exceeded"`, or `"unknown"`
+// for a value that is not an [`RtrStatus`]; static storage.
+//
+// Takes a plain `int` so that any value a C caller passes is defined
+// behavior.
+const char *rtr_status_name(int status);
+
+// Create a config with default settings and the `textrank` preset.
+//
+// Free with [`rtr_config_free`].
+struct RtrConfig *rtr_config_new(void);
+
+// Free a config. Null is a no-op.
+//
+// # Safety
+//
+// `config` must be null or come from [`rtr_config_new`], and must not be
+// used afterwards.
+void rtr_config_free(struct RtrConfig *config);
+
+// Set the preset to run, e.g. `"position_rank"` or `"topic_rank"`.
+//
+// Unknown names are reported by [`rtr_extract`].
+//
+// # Safety
+//
+// `config` must be a live config; `variant` a NUL-terminated string.
+enum RtrStatus rtr_config_set_variant(struct RtrConfig *config, const char *variant);
+
+// Set the number of phrases to return (0 = all).
+//
+// # Safety
+//
+// `config` must be a live config.
+enum RtrStatus rtr_config_set_top_n(struct RtrConfig *config, size_t top_n);
+
+// Set the co-occurrence window size.
+//
+// # Safety
+//
+// `config` must be a live config.
+enum RtrStatus rtr_config_set_window_size(struct RtrConfig *config, size_t window_size);
+
+// Set the language code used for stopwords and lemmatization.
+//
+// # Safety
+//
+// `config` must be a live config; `language` a NUL-terminated string.
+enum RtrStatus rtr_config_set_language(struct RtrConfig *config, const char *language);
+
+// Apply a JSON object of [`TextRankConfig`] field overrides, e.g.
+// `{"damping": 0.9, "max_phrase_length": 3}`.
+//
+// # Safety
+//
+// `config` must be a live config; `json` a NUL-terminated string.
+enum RtrStatus rtr_config_set_json(struct RtrConfig *config, const char *json);
+
+// Extract keyphrases from `text`.
+//
+// `config` may be null for the defaults. On success `*out` receives a
+// result to free with [`rtr_result_free`]; on failure it is set to null.
+//
+// # Safety
+//
+// `text` must be a NUL-terminated string, `config` null or a live config,
+// and `out` a valid pointer.
+enum RtrStatus rtr_extract(const char *text, const struct RtrConfig *config, struct RtrResult **out);
+
+// Free a result. Null is a no-op.
+//
+// # Safety
+//
+// `result` must be null or come from [`rtr_extract`], and must not be used
+// afterwards.
+void rtr_result_free(struct RtrResult *result);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* RAPID_TEXTRANK_H */
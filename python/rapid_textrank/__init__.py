@@ -24,6 +24,8 @@ from rapid_textrank._rust import (
     BatchIter,
     validate_pipeline_spec,
     get_stopwords,
+    extract_keyphrases,
+    TextRankError,
     DebugPayload,
     GraphStats,
     ConvergenceSummary,
@@ -52,6 +54,8 @@ __all__ = [
     "BatchIter",
     "validate_pipeline_spec",
     "get_stopwords",
+    "extract_keyphrases",
+    "TextRankError",
     "DebugPayload",
     "GraphStats",
     "ConvergenceSummary",
@@ -431,6 +431,58 @@ class TestConvenienceFunction:
 
         assert isinstance(phrases, list)
 
+    def test_extract_keyphrases(self):
+        """extract_keyphrases runs any preset and returns a TextRankResult."""
+        from rapid_textrank import extract_keyphrases
+
+        text = (
+            "Machine learning is a subset of artificial intelligence. "
+            "Deep learning is a subset of machine learning."
+        )
+        for variant in ["textrank", "position_rank", "topic_rank"]:
+            result = extract_keyphrases(text, variant=variant, top_n=3)
+            assert 0 < len(result.phrases) <= 3
+            assert result.phrases[0].rank == 1
+
+
+class TestErrors:
+    """Tests for TextRankError and its error codes."""
+
+    def test_unknown_variant(self):
+        from rapid_textrank import TextRankError, extract_keyphrases
+
+        with pytest.raises(TextRankError) as excinfo:
+            extract_keyphrases("Some text.", variant="nope")
+        assert excinfo.value.code == "invalid_value"
+        assert isinstance(excinfo.value, ValueError)
+
+    def test_invalid_config_code(self):
+        from rapid_textrank import TextRankConfig, TextRankError
+
+        with pytest.raises(TextRankError) as excinfo:
+            TextRankConfig(damping=2.0)
+        assert excinfo.value.code == "invalid_value"
+
+    def test_runtime_error_attributes(self):
+        from rapid_textrank import TextRankError, extract_from_json
+
+        tokens = [
+            {"text": w, "lemma": w, "pos": "NOUN", "start": i, "end": i + 1,
+             "sentence_idx": 0, "token_idx": i}
+            for i, w in enumerate(["graph", "ranking", "model"])
+        ]
+        payload = {
+            "tokens": tokens,
+            "pipeline": {"v": 1, "runtime": {"max_tokens": 2}},
+        }
+        with pytest.raises(TextRankError) as excinfo:
+            extract_from_json(json.dumps(payload))
+        err = excinfo.value
+        assert err.code == "limit_exceeded"
+        assert err.path == "/runtime/max_tokens"
+        assert err.stage == "preprocess"
+        assert err.hint is not None
+
 
 class TestMaxThreads:
     """Tests for per-extractor thread pool via max_threads."""
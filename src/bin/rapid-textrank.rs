@@ -0,0 +1,261 @@
+//! `rapid-textrank` command-line interface.
+//!
+//! Extracts keyphrases from plain text, CoNLL-U, or JSON tokens read from a
+//! file or stdin, and prints them as versioned JSON, CSV, or TSV:
+//!
+//! ```text
+//! rapid-textrank paper.txt --variant position_rank --top-n 5 --format csv
+//! spacy-tokenize doc.txt | rapid-textrank --input-format json --timing
+//! ```
+//!
+//! Built only with the `cli` feature.
+
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use serde::Deserialize;
+
+use rapid_textrank::phrase::chunker::NounChunker;
+use rapid_textrank::pipeline::artifacts::{FormattedResult, TokenStream};
+use rapid_textrank::pipeline::observer::StageTimingObserver;
+use rapid_textrank::pipeline::spec::PipelineSpec;
+use rapid_textrank::pipeline::spec_builder::SpecPipelineBuilder;
+use rapid_textrank::pipeline::{DelimitedWriter, JsonOutput};
+use rapid_textrank::types::{PosTag, Section};
+use rapid_textrank::{parse_conllu, TextRankConfig, Token, Tokenizer};
+
+/// Token shape accepted with `--input-format json`; the same as the Python
+/// JSON interface (spaCy POS tag strings).
+#[derive(Deserialize)]
+struct InputToken {
+    text: String,
+    lemma: String,
+    pos: String,
+    start: usize,
+    end: usize,
+    sentence_idx: usize,
+    token_idx: usize,
+    #[serde(default)]
+    is_stopword: bool,
+    #[serde(default)]
+    section: Option<Section>,
+}
+
+/// `--input-format json` accepts a bare token array or `{"tokens": [...]}`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum InputDocument {
+    Tokens(Vec<InputToken>),
+    Document { tokens: Vec<InputToken> },
+}
+
+fn command() -> Command {
+    Command::new("rapid-textrank")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("Extract keyphrases with TextRank and its variants")
+        .arg(
+            Arg::new("input")
+                .value_name("FILE")
+                .help("Input file; reads stdin when omitted or '-'"),
+        )
+        .arg(
+            Arg::new("input-format")
+                .short('i')
+                .long("input-format")
+                .value_name("FORMAT")
+                .value_parser(["text", "conllu", "json"])
+                .help("Input format [default: from the file extension, else text]"),
+        )
+        .arg(
+            Arg::new("variant")
+                .short('v')
+                .long("variant")
+                .value_name("NAME")
+                .default_value("textrank")
+                .help("Algorithm preset, e.g. textrank, position_rank, topic_rank"),
+        )
+        .arg(
+            Arg::new("top-n")
+                .short('n')
+                .long("top-n")
+                .value_name("N")
+                .value_parser(value_parser!(usize))
+                .help("Number of phrases to return"),
+        )
+        .arg(
+            Arg::new("window")
+                .short('w')
+                .long("window")
+                .value_name("N")
+                .value_parser(value_parser!(usize))
+                .help("Co-occurrence window size"),
+        )
+        .arg(
+            Arg::new("language")
+                .short('l')
+                .long("language")
+                .value_name("LANG")
+                .help("Language code for stopwords and lemmatization"),
+        )
+        .arg(
+            Arg::new("format")
+                .short('f')
+                .long("format")
+                .value_name("FORMAT")
+                .value_parser(["json", "csv", "tsv"])
+                .default_value("json")
+                .help("Output format"),
+        )
+        .arg(
+            Arg::new("timing")
+                .long("timing")
+                .action(ArgAction::SetTrue)
+                .help("Print per-stage timings to stderr"),
+        )
+}
+
+fn main() -> ExitCode {
+    match run(&command().get_matches()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("rapid-textrank: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &ArgMatches) -> Result<(), String> {
+    let path = args
+        .get_one::<String>("input")
+        .filter(|p| p.as_str() != "-");
+    let input = match path {
+        Some(p) => std::fs::read_to_string(p).map_err(|e| format!("{p}: {e}"))?,
+        None => {
+            let mut buf = String::new();
+            io::stdin()
+                .read_to_string(&mut buf)
+                .map_err(|e| format!("stdin: {e}"))?;
+            buf
+        }
+    };
+
+    let mut config = TextRankConfig::default();
+    if let Some(&n) = args.get_one::<usize>("top-n") {
+        config = config.with_top_n(n);
+    }
+    if let Some(&w) = args.get_one::<usize>("window") {
+        config = config.with_window_size(w);
+    }
+    if let Some(language) = args.get_one::<String>("language") {
+        config = config.with_language(language.as_str());
+    }
+    config.validate().map_err(|e| e.to_string())?;
+
+    let input_format = match args.get_one::<String>("input-format") {
+        Some(f) => f.as_str(),
+        None => infer_input_format(path.map(String::as_str)),
+    };
+    let tokens = read_tokens(&input, input_format, &config)?;
+
+    let variant = args.get_one::<String>("variant").unwrap();
+    let chunks = NounChunker::new()
+        .with_min_length(config.min_phrase_length)
+        .with_max_length(config.max_phrase_length)
+        .with_bridge_words(&config.phrase_bridge_words)
+        .extract_chunks(&tokens);
+    let (pipeline, config) = SpecPipelineBuilder::new()
+        .with_chunks(chunks)
+        .build_with_config(&PipelineSpec::Preset(variant.clone()), &config)
+        .map_err(|e| e.to_string())?;
+
+    let mut observer = StageTimingObserver::new();
+    let result = pipeline.run(TokenStream::from_tokens(&tokens), &config, &mut observer);
+    if let Some(err) = &result.error {
+        return Err(err.to_string());
+    }
+
+    let doc_id = path.map_or("-", String::as_str);
+    let output = format_result(&result, args, doc_id, &observer)?;
+    io::stdout()
+        .write_all(output.as_bytes())
+        .map_err(|e| format!("stdout: {e}"))?;
+
+    if args.get_flag("timing") {
+        print_timings(&observer);
+    }
+    Ok(())
+}
+
+fn infer_input_format(path: Option<&str>) -> &'static str {
+    match path
+        .and_then(|p| Path::new(p).extension())
+        .and_then(|e| e.to_str())
+    {
+        Some("conllu" | "conll") => "conllu",
+        Some("json") => "json",
+        _ => "text",
+    }
+}
+
+fn read_tokens(input: &str, format: &str, config: &TextRankConfig) -> Result<Vec<Token>, String> {
+    match format {
+        "conllu" => parse_conllu(input).map_err(|e| e.to_string()),
+        "json" => {
+            let doc: InputDocument =
+                serde_json::from_str(input).map_err(|e| format!("invalid JSON tokens: {e}"))?;
+            let (InputDocument::Tokens(tokens) | InputDocument::Document { tokens }) = doc;
+            Ok(tokens
+                .into_iter()
+                .map(|t| Token {
+                    text: t.text,
+                    lemma: t.lemma,
+                    pos: PosTag::from_spacy(&t.pos),
+                    start: t.start,
+                    end: t.end,
+                    sentence_idx: t.sentence_idx,
+                    token_idx: t.token_idx,
+                    is_stopword: t.is_stopword,
+                    section: t.section,
+                })
+                .collect())
+        }
+        _ => Ok(Tokenizer::new().tokenize_with_config(input, config)),
+    }
+}
+
+fn format_result(
+    result: &FormattedResult,
+    args: &ArgMatches,
+    doc_id: &str,
+    observer: &StageTimingObserver,
+) -> Result<String, String> {
+    let writer = match args.get_one::<String>("format").map(String::as_str) {
+        Some("csv") => DelimitedWriter::csv(),
+        Some("tsv") => DelimitedWriter::tsv(),
+        _ => {
+            let mut output = JsonOutput::from_result(result);
+            if args.get_flag("timing") {
+                output = output.with_stage_reports(observer.reports());
+            }
+            let mut json = output.to_json().map_err(|e| e.to_string())?;
+            json.push('\n');
+            return Ok(json);
+        }
+    };
+    Ok(writer.write_batch([(doc_id, result)]))
+}
+
+fn print_timings(observer: &StageTimingObserver) {
+    let mut stderr = io::stderr().lock();
+    for (stage, report) in observer.reports() {
+        let _ = writeln!(stderr, "{stage:<16} {:>10.3} ms", report.duration_ms());
+    }
+    let _ = writeln!(
+        stderr,
+        "{:<16} {:>10.3} ms",
+        "total",
+        observer.total_duration_ms()
+    );
+}
@@ -0,0 +1,616 @@
+//! C API
+//!
+//! `extern "C"` functions for embedding the crate from C, C++, Go, or Swift.
+//! Build a shared library with the `capi` feature and the default features
+//! off, and include `include/rapid_textrank.h`:
+//!
+//! ```text
+//! cargo build --release --no-default-features --features capi
+//! ```
+//!
+//! ```c
+//! RtrConfig *cfg = rtr_config_new();
+//! rtr_config_set_variant(cfg, "position_rank");
+//! rtr_config_set_top_n(cfg, 5);
+//!
+//! RtrResult *result = NULL;
+//! if (rtr_extract(text, cfg, &result) != RTR_STATUS_OK) {
+//!     fprintf(stderr, "%s\n", rtr_last_error_message());
+//! } else {
+//!     for (size_t i = 0; i < result->len; i++)
+//!         printf("%s %f\n", result->phrases[i].text, result->phrases[i].score);
+//!     rtr_result_free(result);
+//! }
+//! rtr_config_free(cfg);
+//! ```
+//!
+//! Every fallible function returns an [`RtrStatus`]; the non-zero codes up to
+//! [`RtrStatus::Cancelled`] mirror [`ErrorCode`]. The message of the last
+//! failure on the calling thread is available from
+//! [`rtr_last_error_message`]. Panics are caught and reported as
+//! [`RtrStatus::Panic`].
+//!
+//! The header is generated with
+//! `cbindgen --config cbindgen.toml --output include/rapid_textrank.h`.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+use crate::nlp::tokenizer::Tokenizer;
+use crate::phrase::chunker::NounChunker;
+use crate::pipeline::artifacts::{FormattedResult, TokenStream};
+use crate::pipeline::error_code::ErrorCode;
+use crate::pipeline::observer::NoopObserver;
+use crate::pipeline::spec::{resolve_spec, PipelineSpec};
+use crate::pipeline::spec_builder::SpecPipelineBuilder;
+use crate::types::TextRankConfig;
+
+/// Status code returned by every fallible C API function.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RtrStatus {
+    /// Success
+    Ok = 0,
+    /// [`ErrorCode::MissingStage`]
+    MissingStage = 1,
+    /// [`ErrorCode::InvalidCombo`]
+    InvalidCombo = 2,
+    /// [`ErrorCode::ModuleUnavailable`]
+    ModuleUnavailable = 3,
+    /// [`ErrorCode::LimitExceeded`]
+    LimitExceeded = 4,
+    /// [`ErrorCode::UnknownField`]
+    UnknownField = 5,
+    /// [`ErrorCode::InvalidValue`]
+    InvalidValue = 6,
+    /// [`ErrorCode::IncompatibleModules`]
+    IncompatibleModules = 7,
+    /// [`ErrorCode::ValidationFailed`]
+    ValidationFailed = 8,
+    /// [`ErrorCode::StageFailed`]
+    StageFailed = 9,
+    /// [`ErrorCode::ConvergenceFailed`]
+    ConvergenceFailed = 10,
+    /// [`ErrorCode::Timeout`]
+    Timeout = 11,
+    /// [`ErrorCode::Cancelled`]
+    Cancelled = 12,
+    /// A required pointer argument was null
+    NullPointer = 100,
+    /// A string argument was not valid UTF-8
+    InvalidUtf8 = 101,
+    /// The call panicked
+    Panic = 102,
+}
+
+impl RtrStatus {
+    /// Every status, for mapping raw codes received from C.
+    const ALL: [Self; 16] = [
+        Self::Ok,
+        Self::MissingStage,
+        Self::InvalidCombo,
+        Self::ModuleUnavailable,
+        Self::LimitExceeded,
+        Self::UnknownField,
+        Self::InvalidValue,
+        Self::IncompatibleModules,
+        Self::ValidationFailed,
+        Self::StageFailed,
+        Self::ConvergenceFailed,
+        Self::Timeout,
+        Self::Cancelled,
+        Self::NullPointer,
+        Self::InvalidUtf8,
+        Self::Panic,
+    ];
+
+    /// The status with discriminant `code`, if any.
+    fn from_code(code: c_int) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|&status| status as c_int == code)
+    }
+
+    /// Stable name, NUL-terminated.
+    fn name(self) -> &'static [u8] {
+        match self {
+            Self::Ok => b"ok\0",
+            Self::MissingStage => b"missing_stage\0",
+            Self::InvalidCombo => b"invalid_combo\0",
+            Self::ModuleUnavailable => b"module_unavailable\0",
+            Self::LimitExceeded => b"limit_exceeded\0",
+            Self::UnknownField => b"unknown_field\0",
+            Self::InvalidValue => b"invalid_value\0",
+            Self::IncompatibleModules => b"incompatible_modules\0",
+            Self::ValidationFailed => b"validation_failed\0",
+            Self::StageFailed => b"stage_failed\0",
+            Self::ConvergenceFailed => b"convergence_failed\0",
+            Self::Timeout => b"timeout\0",
+            Self::Cancelled => b"cancelled\0",
+            Self::NullPointer => b"null_pointer\0",
+            Self::InvalidUtf8 => b"invalid_utf8\0",
+            Self::Panic => b"panic\0",
+        }
+    }
+}
+
+impl From<ErrorCode> for RtrStatus {
+    fn from(code: ErrorCode) -> Self {
+        match code {
+            ErrorCode::MissingStage => RtrStatus::MissingStage,
+            ErrorCode::InvalidCombo => RtrStatus::InvalidCombo,
+            ErrorCode::ModuleUnavailable => RtrStatus::ModuleUnavailable,
+            ErrorCode::LimitExceeded => RtrStatus::LimitExceeded,
+            ErrorCode::UnknownField => RtrStatus::UnknownField,
+            ErrorCode::InvalidValue => RtrStatus::InvalidValue,
+            ErrorCode::IncompatibleModules => RtrStatus::IncompatibleModules,
+            ErrorCode::ValidationFailed => RtrStatus::ValidationFailed,
+            ErrorCode::StageFailed => RtrStatus::StageFailed,
+            ErrorCode::ConvergenceFailed => RtrStatus::ConvergenceFailed,
+            ErrorCode::Timeout => RtrStatus::Timeout,
+            ErrorCode::Cancelled => RtrStatus::Cancelled,
+        }
+    }
+}
+
+/// Extraction settings: a [`TextRankConfig`] plus the preset to run.
+pub struct RtrConfig {
+    config: TextRankConfig,
+    variant: String,
+}
+
+/// One phrase of an [`RtrResult`]. Strings are NUL-terminated UTF-8 owned
+/// by the result.
+#[repr(C)]
+pub struct RtrPhrase {
+    pub text: *const c_char,
+    pub lemma: *const c_char,
+    pub score: f64,
+    pub count: usize,
+    pub rank: usize,
+}
+
+/// Extraction result; free with [`rtr_result_free`].
+#[repr(C)]
+pub struct RtrResult {
+    /// `len` phrases in rank order.
+    pub phrases: *const RtrPhrase,
+    pub len: usize,
+    pub converged: bool,
+    pub iterations: u32,
+}
+
+/// Heap block behind an `RtrResult` pointer; `header` must stay first.
+#[repr(C)]
+struct OwnedResult {
+    header: RtrResult,
+    phrases: Vec<RtrPhrase>,
+    strings: Vec<CString>,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+fn set_last_error(message: &str) {
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|e| *e.borrow_mut() = message);
+}
+
+/// Record `message` and return `status`.
+fn fail(status: RtrStatus, message: impl AsRef<str>) -> RtrStatus {
+    set_last_error(message.as_ref());
+    status
+}
+
+/// Run `f`, turning a panic into [`RtrStatus::Panic`].
+fn guard(f: impl FnOnce() -> RtrStatus) -> RtrStatus {
+    catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|_| fail(RtrStatus::Panic, "panic inside rapid_textrank"))
+}
+
+/// Borrow a C string argument as `&str`.
+///
+/// # Safety
+///
+/// `s` must be null or point to a NUL-terminated string.
+unsafe fn str_arg<'a>(s: *const c_char, name: &str) -> Result<&'a str, RtrStatus> {
+    if s.is_null() {
+        return Err(fail(RtrStatus::NullPointer, format!("{name} is null")));
+    }
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|_| fail(RtrStatus::InvalidUtf8, format!("{name} is not valid UTF-8")))
+}
+
+/// Message of the last failed call on this thread (empty if none).
+///
+/// The pointer stays valid until the next failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn rtr_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ptr())
+}
+
+/// Stable name of a status code, e.g. `"limit_exceeded"`, or `"unknown"`
+/// for a value that is not an [`RtrStatus`]; static storage.
+///
+/// Takes a plain `int` so that any value a C caller passes is defined
+/// behavior.
+#[no_mangle]
+pub extern "C" fn rtr_status_name(status: c_int) -> *const c_char {
+    let name = RtrStatus::from_code(status).map_or(&b"unknown\0"[..], RtrStatus::name);
+    name.as_ptr().cast()
+}
+
+/// Create a config with default settings and the `textrank` preset.
+///
+/// Free with [`rtr_config_free`].
+#[no_mangle]
+pub extern "C" fn rtr_config_new() -> *mut RtrConfig {
+    Box::into_raw(Box::new(RtrConfig {
+        config: TextRankConfig::default(),
+        variant: "textrank".to_string(),
+    }))
+}
+
+/// Free a config. Null is a no-op.
+///
+/// # Safety
+///
+/// `config` must be null or come from [`rtr_config_new`], and must not be
+/// used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn rtr_config_free(config: *mut RtrConfig) {
+    if !config.is_null() {
+        drop(Box::from_raw(config));
+    }
+}
+
+/// Borrow a config argument mutably.
+///
+/// # Safety
+///
+/// `config` must be null or a live pointer from [`rtr_config_new`].
+unsafe fn config_arg<'a>(config: *mut RtrConfig) -> Result<&'a mut RtrConfig, RtrStatus> {
+    config
+        .as_mut()
+        .ok_or_else(|| fail(RtrStatus::NullPointer, "config is null"))
+}
+
+/// Set the preset to run, e.g. `"position_rank"` or `"topic_rank"`.
+///
+/// Unknown names are reported by [`rtr_extract`].
+///
+/// # Safety
+///
+/// `config` must be a live config; `variant` a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn rtr_config_set_variant(
+    config: *mut RtrConfig,
+    variant: *const c_char,
+) -> RtrStatus {
+    guard(|| match (config_arg(config), str_arg(variant, "variant")) {
+        (Ok(config), Ok(variant)) => {
+            config.variant = variant.to_string();
+            RtrStatus::Ok
+        }
+        (Err(status), _) | (_, Err(status)) => status,
+    })
+}
+
+/// Set the number of phrases to return (0 = all).
+///
+/// # Safety
+///
+/// `config` must be a live config.
+#[no_mangle]
+pub unsafe extern "C" fn rtr_config_set_top_n(config: *mut RtrConfig, top_n: usize) -> RtrStatus {
+    guard(|| match config_arg(config) {
+        Ok(config) => {
+            config.config.top_n = top_n;
+            RtrStatus::Ok
+        }
+        Err(status) => status,
+    })
+}
+
+/// Set the co-occurrence window size.
+///
+/// # Safety
+///
+/// `config` must be a live config.
+#[no_mangle]
+pub unsafe extern "C" fn rtr_config_set_window_size(
+    config: *mut RtrConfig,
+    window_size: usize,
+) -> RtrStatus {
+    guard(|| match config_arg(config) {
+        Ok(config) => {
+            config.config.window_size = window_size;
+            RtrStatus::Ok
+        }
+        Err(status) => status,
+    })
+}
+
+/// Set the language code used for stopwords and lemmatization.
+///
+/// # Safety
+///
+/// `config` must be a live config; `language` a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn rtr_config_set_language(
+    config: *mut RtrConfig,
+    language: *const c_char,
+) -> RtrStatus {
+    guard(
+        || match (config_arg(config), str_arg(language, "language")) {
+            (Ok(config), Ok(language)) => {
+                config.config.language = language.to_string();
+                RtrStatus::Ok
+            }
+            (Err(status), _) | (_, Err(status)) => status,
+        },
+    )
+}
+
+/// Apply a JSON object of [`TextRankConfig`] field overrides, e.g.
+/// `{"damping": 0.9, "max_phrase_length": 3}`.
+///
+/// # Safety
+///
+/// `config` must be a live config; `json` a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn rtr_config_set_json(
+    config: *mut RtrConfig,
+    json: *const c_char,
+) -> RtrStatus {
+    guard(|| {
+        let (config, json) = match (config_arg(config), str_arg(json, "json")) {
+            (Ok(config), Ok(json)) => (config, json),
+            (Err(status), _) | (_, Err(status)) => return status,
+        };
+        let overrides = match serde_json::from_str::<serde_json::Value>(json) {
+            Ok(value) if value.is_object() => value,
+            Ok(_) => return fail(RtrStatus::InvalidValue, "config JSON must be an object"),
+            Err(err) => return fail(RtrStatus::InvalidValue, format!("invalid JSON: {err}")),
+        };
+        let spec = serde_json::json!({ "v": 1, "config": overrides });
+        let spec = match serde_json::from_value::<PipelineSpec>(spec) {
+            Ok(spec) => spec,
+            Err(err) => {
+                return fail(
+                    RtrStatus::InvalidValue,
+                    format!("invalid pipeline spec: {err}"),
+                )
+            }
+        };
+        match resolve_spec(&spec).and_then(|r| r.apply_config(&config.config)) {
+            Ok(updated) => {
+                config.config = updated;
+                RtrStatus::Ok
+            }
+            Err(err) => fail(err.code.into(), err.to_string()),
+        }
+    })
+}
+
+/// Extract keyphrases from `text`.
+///
+/// `config` may be null for the defaults. On success `*out` receives a
+/// result to free with [`rtr_result_free`]; on failure it is set to null.
+///
+/// # Safety
+///
+/// `text` must be a NUL-terminated string, `config` null or a live config,
+/// and `out` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rtr_extract(
+    text: *const c_char,
+    config: *const RtrConfig,
+    out: *mut *mut RtrResult,
+) -> RtrStatus {
+    guard(|| {
+        if out.is_null() {
+            return fail(RtrStatus::NullPointer, "out is null");
+        }
+        *out = ptr::null_mut();
+        let text = match str_arg(text, "text") {
+            Ok(text) => text,
+            Err(status) => return status,
+        };
+        let default;
+        let config = match config.as_ref() {
+            Some(config) => config,
+            None => {
+                default = RtrConfig {
+                    config: TextRankConfig::default(),
+                    variant: "textrank".to_string(),
+                };
+                &default
+            }
+        };
+        match extract(text, config) {
+            Ok(result) => {
+                *out = into_raw_result(result);
+                RtrStatus::Ok
+            }
+            Err(status) => status,
+        }
+    })
+}
+
+/// Free a result. Null is a no-op.
+///
+/// # Safety
+///
+/// `result` must be null or come from [`rtr_extract`], and must not be used
+/// afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn rtr_result_free(result: *mut RtrResult) {
+    if !result.is_null() {
+        drop(Box::from_raw(result.cast::<OwnedResult>()));
+    }
+}
+
+fn extract(text: &str, config: &RtrConfig) -> Result<FormattedResult, RtrStatus> {
+    if let Err(err) = config.config.validate() {
+        return Err(fail(RtrStatus::InvalidValue, err.to_string()));
+    }
+    let tokens = Tokenizer::new().tokenize_with_config(text, &config.config);
+    let chunks = NounChunker::new()
+        .with_min_length(config.config.min_phrase_length)
+        .with_max_length(config.config.max_phrase_length)
+        .with_bridge_words(&config.config.phrase_bridge_words)
+        .extract_chunks(&tokens);
+    let (pipeline, cfg) = SpecPipelineBuilder::new()
+        .with_chunks(chunks)
+        .build_with_config(
+            &PipelineSpec::Preset(config.variant.clone()),
+            &config.config,
+        )
+        .map_err(|err| fail(err.code.into(), err.to_string()))?;
+
+    let result = pipeline.run(TokenStream::from_tokens(&tokens), &cfg, &mut NoopObserver);
+    match &result.error {
+        Some(err) => Err(fail(err.code.into(), err.to_string())),
+        None => Ok(result),
+    }
+}
+
+fn into_raw_result(result: FormattedResult) -> *mut RtrResult {
+    let mut strings = Vec::with_capacity(result.phrases.len() * 2);
+    let mut phrases = Vec::with_capacity(result.phrases.len());
+    for phrase in result.phrases {
+        let text = CString::new(phrase.text.replace('\0', " ")).unwrap_or_default();
+        let lemma = CString::new(phrase.lemma.replace('\0', " ")).unwrap_or_default();
+        // The CString heap buffers do not move when the CStrings do.
+        phrases.push(RtrPhrase {
+            text: text.as_ptr(),
+            lemma: lemma.as_ptr(),
+            score: phrase.score,
+            count: phrase.count,
+            rank: phrase.rank,
+        });
+        strings.push(text);
+        strings.push(lemma);
+    }
+    let mut owned = Box::new(OwnedResult {
+        header: RtrResult {
+            phrases: ptr::null(),
+            len: phrases.len(),
+            converged: result.converged,
+            iterations: result.iterations,
+        },
+        phrases,
+        strings,
+    });
+    owned.header.phrases = owned.phrases.as_ptr();
+    Box::into_raw(owned).cast::<RtrResult>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "Machine learning is a subset of artificial intelligence. \
+                        Machine learning models learn from data.";
+
+    fn cstr(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(rtr_last_error_message()) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_extract_round_trip() {
+        unsafe {
+            let cfg = rtr_config_new();
+            assert_eq!(
+                rtr_config_set_variant(cfg, cstr("position_rank").as_ptr()),
+                RtrStatus::Ok
+            );
+            assert_eq!(rtr_config_set_top_n(cfg, 3), RtrStatus::Ok);
+            assert_eq!(
+                rtr_config_set_json(cfg, cstr(r#"{"damping": 0.9}"#).as_ptr()),
+                RtrStatus::Ok
+            );
+            assert_eq!((*cfg).config.damping, 0.9);
+
+            let mut result = ptr::null_mut();
+            assert_eq!(
+                rtr_extract(cstr(TEXT).as_ptr(), cfg, &mut result),
+                RtrStatus::Ok
+            );
+            let r = &*result;
+            assert_eq!(r.len, 3);
+            let phrases = std::slice::from_raw_parts(r.phrases, r.len);
+            assert_eq!(phrases[0].rank, 1);
+            assert!(!CStr::from_ptr(phrases[0].text).to_bytes().is_empty());
+
+            rtr_result_free(result);
+            rtr_config_free(cfg);
+        }
+    }
+
+    #[test]
+    fn test_status_names() {
+        let name = |code| unsafe { CStr::from_ptr(rtr_status_name(code)).to_str().unwrap() };
+        for status in RtrStatus::ALL {
+            assert_eq!(RtrStatus::from_code(status as c_int), Some(status));
+            assert_ne!(name(status as c_int), "unknown");
+        }
+        assert_eq!(name(RtrStatus::Panic as c_int), "panic");
+        for code in [-1, 13, 99, 103, c_int::MAX, c_int::MIN] {
+            assert_eq!(name(code), "unknown");
+        }
+    }
+
+    #[test]
+    fn test_error_statuses() {
+        unsafe {
+            let cfg = rtr_config_new();
+            rtr_config_set_variant(cfg, cstr("nope").as_ptr());
+            let mut result = ptr::null_mut();
+            let status = rtr_extract(cstr(TEXT).as_ptr(), cfg, &mut result);
+            assert_eq!(status, RtrStatus::InvalidValue);
+            assert!(result.is_null());
+            assert!(last_error().contains("nope"));
+            assert_eq!(
+                CStr::from_ptr(rtr_status_name(status as c_int))
+                    .to_str()
+                    .unwrap(),
+                ErrorCode::InvalidValue.as_str()
+            );
+
+            assert_eq!(
+                rtr_config_set_language(cfg, ptr::null()),
+                RtrStatus::NullPointer
+            );
+            assert_eq!(
+                rtr_config_set_json(cfg, cstr(r#"{"damping": "high"}"#).as_ptr()),
+                RtrStatus::InvalidValue
+            );
+            // Input is parsed on its own, never spliced into the spec.
+            for json in [r#"[1]"#, r#"{"top_n": 3}, "v": 2"#] {
+                assert_eq!(
+                    rtr_config_set_json(cfg, cstr(json).as_ptr()),
+                    RtrStatus::InvalidValue
+                );
+            }
+            assert!(last_error().contains("JSON"));
+            rtr_config_free(cfg);
+
+            assert_eq!(
+                rtr_extract(cstr(TEXT).as_ptr(), ptr::null(), ptr::null_mut()),
+                RtrStatus::NullPointer
+            );
+        }
+    }
+}
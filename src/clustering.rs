@@ -32,7 +32,10 @@ pub struct PhraseCandidate {
 /// Extract keyphrase candidates from tokens using noun chunking.
 ///
 /// Candidates are noun phrases trimmed to start at the first POS-matching token.
-/// At most `max_phrases` chunks are considered.
+/// At most `max_phrases` chunks are considered. Term sets hold the
+/// non-stopword lemmas, so "model" and "models" overlap; with
+/// [`LemmaStrategy::Stem`](crate::types::LemmaStrategy::Stem) lemmas they
+/// are stems.
 pub fn extract_candidates(
     tokens: &[Token],
     min_phrase_length: usize,
@@ -56,7 +59,7 @@ pub fn extract_candidates(
         let terms: FxHashSet<String> = tokens[trimmed.start_token..trimmed.end_token]
             .iter()
             .filter(|t| !t.is_stopword)
-            .map(|t| t.text.clone())
+            .map(|t| t.lemma.clone())
             .collect();
         candidates.push(PhraseCandidate {
             text,
@@ -318,6 +321,29 @@ pub fn cluster_u32_term_sets(
     cluster_from_distance_matrix(&base_dist, n, similarity_threshold, linkage)
 }
 
+/// Cluster `n` items using HAC with configurable linkage over `1 - similarity`.
+///
+/// `similarity(i, j)` is called once per pair with `i < j` and should return
+/// a value in `[0, 1]`; the threshold means the same as for the Jaccard
+/// entry points.
+#[allow(clippy::needless_range_loop)]
+pub fn cluster_by_similarity(
+    n: usize,
+    mut similarity: impl FnMut(usize, usize) -> f64,
+    similarity_threshold: f64,
+    linkage: Linkage,
+) -> Vec<Vec<usize>> {
+    let mut base_dist = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dist = 1.0 - similarity(i, j).clamp(0.0, 1.0);
+            base_dist[i][j] = dist;
+            base_dist[j][i] = dist;
+        }
+    }
+    cluster_from_distance_matrix(&base_dist, n, similarity_threshold, linkage)
+}
+
 /// Compute PKE-style positional gap between two chunks.
 /// Measures distance from the end of the earlier phrase to the start of the later,
 /// floored at 1 to avoid division by zero.
@@ -380,6 +406,33 @@ mod tests {
         assert_eq!(compute_gap(&span(0, 5), &span(3, 7)), 1);
     }
 
+    #[test]
+    fn test_inflected_variants_share_a_topic() {
+        use crate::pipeline::artifacts::{CandidateSet, TokenStream};
+        use crate::pipeline::{Clusterer, JaccardHacClusterer};
+        use crate::types::TextRankConfig;
+
+        // "Graph models" ... "graph model": no surface form in common.
+        let tokens = vec![
+            Token::new("Graph", "graph", PosTag::Noun, 0, 5, 0, 0),
+            Token::new("models", "model", PosTag::Noun, 6, 12, 0, 1),
+            Token::new("rank", "rank", PosTag::Verb, 13, 17, 0, 2),
+            Token::new("graph", "graph", PosTag::Noun, 19, 24, 1, 3),
+            Token::new("model", "model", PosTag::Noun, 25, 30, 1, 4),
+            Token::new("wins", "win", PosTag::Verb, 31, 35, 1, 5),
+        ];
+        let candidates = extract_candidates(&tokens, 1, 4, 100, &[]);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(cluster_phrases(&candidates, 0.25), vec![vec![0, 1]]);
+
+        let stream = TokenStream::from_tokens(&tokens);
+        let chunks: Vec<ChunkSpan> = candidates.iter().map(|c| c.chunk.clone()).collect();
+        let set = CandidateSet::from_phrase_chunks(&stream, &chunks);
+        let clusters =
+            JaccardHacClusterer::topic_rank().cluster(set.as_ref(), &TextRankConfig::default());
+        assert_eq!(clusters.num_clusters(), 1);
+    }
+
     #[test]
     fn test_cluster_disjoint_stay_separate() {
         let candidates = vec![
@@ -545,4 +598,20 @@ mod tests {
         assert_eq!(clusters.len(), 1);
         assert_eq!(clusters[0], vec![0]);
     }
+
+    #[test]
+    fn test_cluster_by_similarity_matches_jaccard() {
+        let sets: Vec<FxHashSet<u32>> = vec![
+            [1, 2].into_iter().collect(),
+            [1, 2, 3].into_iter().collect(),
+            [99, 100].into_iter().collect(),
+        ];
+        let by_sim = cluster_by_similarity(
+            sets.len(),
+            |i, j| 1.0 - jaccard_distance_u32(&sets[i], &sets[j]),
+            0.25,
+            Linkage::Average,
+        );
+        assert_eq!(by_sim, cluster_u32_term_sets(&sets, 0.25, Linkage::Average));
+    }
 }
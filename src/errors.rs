@@ -3,6 +3,7 @@
 //! This module defines the error types used throughout the library.
 //! All errors are designed to be informative and actionable.
 
+use crate::pipeline::error_code::ErrorCode;
 use thiserror::Error;
 
 /// Result type alias for convenience
@@ -26,12 +27,27 @@ pub enum TextRankError {
 
     /// Configuration validation failed
     #[error("Invalid configuration: {message}")]
-    InvalidConfig { message: String },
+    InvalidConfig {
+        /// Stable error code for programmatic matching
+        code: ErrorCode,
+        /// Offending config field (dotted for nested fields, e.g.
+        /// `"near_duplicates.max_distance"`), when known
+        field: Option<String>,
+        message: String,
+    },
 
     /// JSON serialization/deserialization error
     #[error("Serialization error: {message}")]
     Serialization { message: String },
 
+    /// Malformed input data (e.g., a CoNLL-U file)
+    #[error("Parse error at line {line}: {message}")]
+    Parse { line: usize, message: String },
+
+    /// I/O error while reading input
+    #[error("I/O error: {message}")]
+    Io { message: String },
+
     /// Internal error (should not occur in normal usage)
     #[error("Internal error: {message}")]
     Internal { message: String },
@@ -60,6 +76,21 @@ impl TextRankError {
     /// Create an invalid config error
     pub fn invalid_config(message: impl Into<String>) -> Self {
         Self::InvalidConfig {
+            code: ErrorCode::InvalidValue,
+            field: None,
+            message: message.into(),
+        }
+    }
+
+    /// Create an invalid config error for one field
+    pub fn invalid_field(
+        code: ErrorCode,
+        field: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::InvalidConfig {
+            code,
+            field: Some(field.into()),
             message: message.into(),
         }
     }
@@ -71,6 +102,14 @@ impl TextRankError {
         }
     }
 
+    /// Create a parse error for a 1-indexed input line
+    pub fn parse(line: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+
     /// Create an internal error
     pub fn internal(message: impl Into<String>) -> Self {
         Self::Internal {
@@ -78,6 +117,26 @@ impl TextRankError {
         }
     }
 
+    /// Error code of an [`InvalidConfig`](Self::InvalidConfig) error
+    pub fn config_code(&self) -> Option<ErrorCode> {
+        match self {
+            Self::InvalidConfig { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// JSON pointer of the offending field of an
+    /// [`InvalidConfig`](Self::InvalidConfig) error, relative to the config
+    /// (e.g. `"/near_duplicates/max_distance"`)
+    pub fn config_path(&self) -> Option<String> {
+        match self {
+            Self::InvalidConfig {
+                field: Some(field), ..
+            } => Some(format!("/{}", field.replace('.', "/"))),
+            _ => None,
+        }
+    }
+
     /// Check if this error indicates non-convergence
     /// (which may still have usable partial results)
     pub fn is_convergence_failure(&self) -> bool {
@@ -91,6 +150,14 @@ impl From<serde_json::Error> for TextRankError {
     }
 }
 
+impl From<std::io::Error> for TextRankError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io {
+            message: err.to_string(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,275 @@
+//! Loaders for keyphrase benchmark datasets
+//!
+//! Each loader scans a directory layout, reads the gold keyphrases up front,
+//! and returns a [`Dataset`] whose documents are read lazily, in file-name
+//! order, as [`GoldDocument`]s:
+//!
+//! - [`load_inspec`] — Hulth (2003) Inspec: `<id>.abstr` abstracts with
+//!   `<id>.uncontr` gold files of `;`-separated keyphrases.
+//! - [`load_semeval2010`] — SemEval-2010 Task 5: `<id>.txt.final` (or
+//!   `<id>.txt`) articles plus one combined answer file of
+//!   `<id> : kp1,kp2,...` lines. Alternatives joined with `+` become
+//!   separate gold keyphrases.
+//! - [`load_key_files`] — the `docs/` + `keys/` layout used for DUC-2001 and
+//!   most redistributed benchmarks: `<id>.txt` documents with `<id>.key`
+//!   files of one keyphrase per line.
+//!
+//! A [`GoldDocument`] converts into a [`BatchDocument`] for
+//! [`extract_batch`](crate::phrase::batch::extract_batch), and its `gold`
+//! list is what [`Evaluator::evaluate`](super::Evaluator::evaluate) takes.
+
+use crate::errors::{Result, TextRankError};
+use crate::phrase::batch::BatchDocument;
+use rustc_hash::FxHashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// One benchmark document with its gold keyphrases
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoldDocument {
+    /// Document ID (the file name up to its first `.`)
+    pub id: String,
+    /// Raw document text
+    pub text: String,
+    /// Gold keyphrases, whitespace-normalized
+    pub gold: Vec<String>,
+}
+
+impl<'a> From<&'a GoldDocument> for BatchDocument<'a> {
+    fn from(doc: &'a GoldDocument) -> Self {
+        BatchDocument::Text(&doc.text)
+    }
+}
+
+/// A benchmark dataset: document paths with their gold keyphrases
+#[derive(Debug, Clone, Default)]
+pub struct Dataset {
+    entries: Vec<DatasetEntry>,
+}
+
+#[derive(Debug, Clone)]
+struct DatasetEntry {
+    id: String,
+    path: PathBuf,
+    gold: Vec<String>,
+}
+
+impl Dataset {
+    /// Number of documents
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the dataset has no documents
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the documents, reading each text file on demand.
+    pub fn iter(&self) -> impl Iterator<Item = Result<GoldDocument>> + '_ {
+        self.entries.iter().map(|entry| {
+            Ok(GoldDocument {
+                id: entry.id.clone(),
+                text: read(&entry.path)?,
+                gold: entry.gold.clone(),
+            })
+        })
+    }
+
+    /// Read every document into memory.
+    pub fn load_all(&self) -> Result<Vec<GoldDocument>> {
+        self.iter().collect()
+    }
+}
+
+/// Load an Inspec-style directory of `.abstr` / `.uncontr` pairs.
+pub fn load_inspec(dir: impl AsRef<Path>) -> Result<Dataset> {
+    let entries = files_with_suffix(dir.as_ref(), ".abstr")?
+        .into_iter()
+        .map(|(id, path)| {
+            let gold = parse_separated(&read(&path.with_extension("uncontr"))?, ';');
+            Ok(DatasetEntry { id, path, gold })
+        })
+        .collect::<Result<_>>()?;
+    Ok(Dataset { entries })
+}
+
+/// Load SemEval-2010 articles from `docs_dir` with gold keyphrases from the
+/// combined answer file `keys_file` (e.g. `train.combined.final`).
+///
+/// Articles without an answer line are skipped.
+pub fn load_semeval2010(
+    docs_dir: impl AsRef<Path>,
+    keys_file: impl AsRef<Path>,
+) -> Result<Dataset> {
+    let keys = read(keys_file.as_ref())?;
+    let mut answers: FxHashMap<&str, Vec<String>> = FxHashMap::default();
+    for (line_no, line) in keys.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (id, phrases) = line
+            .split_once(':')
+            .ok_or_else(|| TextRankError::parse(line_no + 1, "expected '<id> : <keyphrases>'"))?;
+        let gold = phrases
+            .split(',')
+            .flat_map(|p| p.split('+'))
+            .filter_map(normalize_phrase)
+            .collect();
+        answers.insert(id.trim(), gold);
+    }
+
+    let mut docs = files_with_suffix(docs_dir.as_ref(), ".txt.final")?;
+    if docs.is_empty() {
+        docs = files_with_suffix(docs_dir.as_ref(), ".txt")?;
+    }
+    let entries = docs
+        .into_iter()
+        .filter_map(|(id, path)| {
+            let gold = answers.remove(id.as_str())?;
+            Some(DatasetEntry { id, path, gold })
+        })
+        .collect();
+    Ok(Dataset { entries })
+}
+
+/// Load `<id>.txt` documents from `docs_dir` with `<id>.key` gold files
+/// (one keyphrase per line) from `keys_dir`.
+pub fn load_key_files(docs_dir: impl AsRef<Path>, keys_dir: impl AsRef<Path>) -> Result<Dataset> {
+    let keys_dir = keys_dir.as_ref();
+    let entries = files_with_suffix(docs_dir.as_ref(), ".txt")?
+        .into_iter()
+        .map(|(id, path)| {
+            let gold = parse_separated(&read(&keys_dir.join(format!("{id}.key")))?, '\n');
+            Ok(DatasetEntry { id, path, gold })
+        })
+        .collect::<Result<_>>()?;
+    Ok(Dataset { entries })
+}
+
+/// `(id, path)` of every file in `dir` whose name ends with `suffix`,
+/// sorted by file name.
+fn files_with_suffix(dir: &Path, suffix: &str) -> Result<Vec<(String, PathBuf)>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|e| io_error(dir, e))? {
+        let path = entry.map_err(|e| io_error(dir, e))?.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if name.ends_with(suffix) && path.is_file() {
+            let id = name.split('.').next().unwrap_or(name).to_string();
+            files.push((id, path));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| io_error(path, e))
+}
+
+fn io_error(path: &Path, err: std::io::Error) -> TextRankError {
+    TextRankError::Io {
+        message: format!("{}: {}", path.display(), err),
+    }
+}
+
+fn parse_separated(input: &str, separator: char) -> Vec<String> {
+    input
+        .split(separator)
+        .filter_map(normalize_phrase)
+        .collect()
+}
+
+/// Collapse whitespace (gold files wrap long lists across lines).
+fn normalize_phrase(phrase: &str) -> Option<String> {
+    let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!phrase.is_empty()).then_some(phrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fresh scratch directory under the system temp dir.
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "rapid_textrank_datasets_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_inspec() {
+        let dir = scratch_dir("inspec");
+        fs::write(dir.join("2.abstr"), "Graph ranking of words.").unwrap();
+        fs::write(dir.join("2.uncontr"), "graph ranking; word\n\tgraphs;").unwrap();
+        fs::write(dir.join("1.abstr"), "Neural networks.").unwrap();
+        fs::write(dir.join("1.uncontr"), "neural networks").unwrap();
+        fs::write(dir.join("1.contr"), "ignored").unwrap();
+
+        let dataset = load_inspec(&dir).unwrap();
+        let docs = dataset.load_all().unwrap();
+        assert_eq!(docs.len(), 2);
+        assert_eq!(docs[0].id, "1");
+        assert_eq!(docs[1].text, "Graph ranking of words.");
+        assert_eq!(docs[1].gold, ["graph ranking", "word graphs"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_semeval2010() {
+        let dir = scratch_dir("semeval");
+        let docs_dir = dir.join("train");
+        fs::create_dir_all(&docs_dir).unwrap();
+        fs::write(docs_dir.join("C-41.txt.final"), "Grid computing.").unwrap();
+        fs::write(docs_dir.join("H-7.txt.final"), "No answers for this one.").unwrap();
+        let keys = dir.join("train.combined.final");
+        fs::write(&keys, "C-41 : grid computing,resource+resources\n\n").unwrap();
+
+        let docs = load_semeval2010(&docs_dir, &keys)
+            .unwrap()
+            .load_all()
+            .unwrap();
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].id, "C-41");
+        assert_eq!(docs[0].gold, ["grid computing", "resource", "resources"]);
+
+        fs::write(&keys, "no separator").unwrap();
+        assert!(matches!(
+            load_semeval2010(&docs_dir, &keys),
+            Err(TextRankError::Parse { line: 1, .. })
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_key_files() {
+        let dir = scratch_dir("keys");
+        let (docs_dir, keys_dir) = (dir.join("docs"), dir.join("keys"));
+        fs::create_dir_all(&docs_dir).unwrap();
+        fs::create_dir_all(&keys_dir).unwrap();
+        fs::write(docs_dir.join("AP880101.txt"), "Election results.").unwrap();
+        fs::write(
+            keys_dir.join("AP880101.key"),
+            "election results\n\nvoters\n",
+        )
+        .unwrap();
+
+        let dataset = load_key_files(&docs_dir, &keys_dir).unwrap();
+        assert_eq!(dataset.len(), 1);
+        let doc = dataset.iter().next().unwrap().unwrap();
+        assert_eq!(doc.gold, ["election results", "voters"]);
+        assert!(matches!(BatchDocument::from(&doc), BatchDocument::Text(t) if t == doc.text));
+
+        fs::remove_file(keys_dir.join("AP880101.key")).unwrap();
+        let err = load_key_files(&docs_dir, &keys_dir).unwrap_err();
+        assert!(err.to_string().contains("AP880101.key"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,336 @@
+//! Precision, recall and F1@k
+//!
+//! [`Evaluator::evaluate`] scores the top-k phrases of a [`PhraseSet`]
+//! against a list of gold keyphrases; [`Evaluator::evaluate_dataset`]
+//! averages over many documents, both macro (mean of per-document scores)
+//! and micro (scores of the pooled counts).
+//!
+//! Phrases are compared after lowercasing and collapsing whitespace. Each
+//! gold keyphrase is matched at most once, by the highest-ranked predicted
+//! phrase that matches it, and duplicate predictions or gold entries (under
+//! the active [`MatchMode`]) count once.
+//!
+//! [`MatchMode::Stem`] and [`MatchMode::Partial`] stem every word with the
+//! Snowball stemmer of the configured language. Without the `stemming`
+//! feature words are only lowercased, so `Stem` behaves like `Exact`.
+
+use crate::nlp::lemma::Lemmatizer;
+use crate::pipeline::artifacts::PhraseSet;
+use crate::types::{LemmaStrategy, StemLanguage};
+
+/// How a predicted phrase is matched against a gold keyphrase
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MatchMode {
+    /// Identical after lowercasing and whitespace normalization
+    #[default]
+    Exact,
+    /// Identical after stemming every word
+    Stem,
+    /// After stemming, one phrase occurs as a contiguous run of words in the
+    /// other ("neural network" matches "deep neural network" and vice versa)
+    Partial,
+}
+
+/// Precision, recall and F1
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EvalScores {
+    /// Fraction of predicted phrases that match a gold keyphrase
+    pub precision: f64,
+    /// Fraction of gold keyphrases matched by a predicted phrase
+    pub recall: f64,
+    /// Harmonic mean of precision and recall
+    pub f1: f64,
+}
+
+impl EvalScores {
+    /// Scores from match counts; empty denominators score zero.
+    pub fn from_counts(matched: usize, predicted: usize, gold: usize) -> Self {
+        let ratio = |n: usize, d: usize| if d == 0 { 0.0 } else { n as f64 / d as f64 };
+        let precision = ratio(matched, predicted);
+        let recall = ratio(matched, gold);
+        Self {
+            precision,
+            recall,
+            f1: harmonic_mean(precision, recall),
+        }
+    }
+}
+
+/// Scores and counts for one document
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DocumentScores {
+    /// Precision, recall and F1@k
+    pub scores: EvalScores,
+    /// Predicted phrases that matched a gold keyphrase
+    pub matched: usize,
+    /// Distinct predicted phrases considered (at most k)
+    pub predicted: usize,
+    /// Distinct gold keyphrases
+    pub gold: usize,
+}
+
+/// Macro- and micro-averaged scores over a dataset
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DatasetScores {
+    /// Mean of the per-document precision, recall and F1
+    pub macro_avg: EvalScores,
+    /// Scores of the match counts summed over all documents
+    pub micro_avg: EvalScores,
+    /// Number of documents evaluated
+    pub num_docs: usize,
+}
+
+/// Scores predicted phrases against gold keyphrases
+///
+/// ```
+/// use rapid_textrank::eval::Evaluator;
+/// use rapid_textrank::pipeline::artifacts::PhraseSet;
+/// use rapid_textrank::{Phrase, StringPool};
+///
+/// let phrases = [
+///     Phrase::new("neural networks", "neural network", 0.4, 2),
+///     Phrase::new("training data", "training datum", 0.3, 1),
+/// ];
+/// let predicted = PhraseSet::from_phrases(&phrases, &mut StringPool::new());
+/// let gold = vec!["Neural Networks".to_string(), "backpropagation".to_string()];
+///
+/// let doc = Evaluator::new().with_k(5).evaluate(&predicted, &gold);
+/// assert_eq!(doc.matched, 1);
+/// assert_eq!(doc.scores.precision, 0.5);
+/// assert_eq!(doc.scores.recall, 0.5);
+/// ```
+#[derive(Debug)]
+pub struct Evaluator {
+    mode: MatchMode,
+    k: Option<usize>,
+    stemmer: Lemmatizer,
+}
+
+impl Default for Evaluator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Evaluator {
+    /// Exact matching over all predicted phrases, English stemming
+    pub fn new() -> Self {
+        Self {
+            mode: MatchMode::Exact,
+            k: None,
+            stemmer: Lemmatizer::new(LemmaStrategy::Stem(StemLanguage::English)),
+        }
+    }
+
+    /// Set the match mode
+    pub fn with_match_mode(mut self, mode: MatchMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Only score the `k` highest-scoring predicted phrases
+    pub fn with_k(mut self, k: usize) -> Self {
+        self.k = Some(k);
+        self
+    }
+
+    /// Stemming language for [`MatchMode::Stem`] and [`MatchMode::Partial`]
+    pub fn with_language(mut self, language: StemLanguage) -> Self {
+        self.stemmer = Lemmatizer::new(LemmaStrategy::Stem(language));
+        self
+    }
+
+    /// Score one document.
+    ///
+    /// Predicted phrases are ranked by score (ties keep their order in the
+    /// set); entries without a surface form fall back to their lemma text.
+    pub fn evaluate(&self, predicted: &PhraseSet, gold: &[String]) -> DocumentScores {
+        let mut ranked: Vec<_> = predicted.entries().iter().collect();
+        ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        let mut predicted_keys: Vec<Vec<String>> = Vec::new();
+        for entry in ranked {
+            if self.k.is_some_and(|k| predicted_keys.len() >= k) {
+                break;
+            }
+            let Some(text) = entry.surface.as_deref().or(entry.lemma_text.as_deref()) else {
+                continue;
+            };
+            push_unique(&mut predicted_keys, self.key(text));
+        }
+        let mut gold_keys: Vec<Vec<String>> = Vec::new();
+        for g in gold {
+            push_unique(&mut gold_keys, self.key(g));
+        }
+
+        let mut gold_matched = vec![false; gold_keys.len()];
+        let mut matched = 0;
+        for p in &predicted_keys {
+            let hit = gold_keys
+                .iter()
+                .enumerate()
+                .position(|(i, g)| !gold_matched[i] && self.matches(p, g));
+            if let Some(i) = hit {
+                gold_matched[i] = true;
+                matched += 1;
+            }
+        }
+
+        DocumentScores {
+            scores: EvalScores::from_counts(matched, predicted_keys.len(), gold_keys.len()),
+            matched,
+            predicted: predicted_keys.len(),
+            gold: gold_keys.len(),
+        }
+    }
+
+    /// Score a dataset of `(predicted, gold)` pairs.
+    pub fn evaluate_dataset<'a, I>(&self, docs: I) -> DatasetScores
+    where
+        I: IntoIterator<Item = (&'a PhraseSet, &'a [String])>,
+    {
+        let mut sum = EvalScores::default();
+        let (mut matched, mut predicted, mut gold, mut num_docs) = (0, 0, 0, 0);
+        for (p, g) in docs {
+            let doc = self.evaluate(p, g);
+            sum.precision += doc.scores.precision;
+            sum.recall += doc.scores.recall;
+            sum.f1 += doc.scores.f1;
+            matched += doc.matched;
+            predicted += doc.predicted;
+            gold += doc.gold;
+            num_docs += 1;
+        }
+        if num_docs == 0 {
+            return DatasetScores::default();
+        }
+
+        let n = num_docs as f64;
+        DatasetScores {
+            macro_avg: EvalScores {
+                precision: sum.precision / n,
+                recall: sum.recall / n,
+                f1: sum.f1 / n,
+            },
+            micro_avg: EvalScores::from_counts(matched, predicted, gold),
+            num_docs,
+        }
+    }
+
+    /// Normalized words of a phrase under the active match mode.
+    fn key(&self, phrase: &str) -> Vec<String> {
+        phrase
+            .split_whitespace()
+            .map(|w| match self.mode {
+                MatchMode::Exact => w.to_lowercase(),
+                MatchMode::Stem | MatchMode::Partial => self.stemmer.lemma(w, w),
+            })
+            .collect()
+    }
+
+    fn matches(&self, predicted: &[String], gold: &[String]) -> bool {
+        match self.mode {
+            MatchMode::Exact | MatchMode::Stem => predicted == gold,
+            MatchMode::Partial => contains_run(predicted, gold) || contains_run(gold, predicted),
+        }
+    }
+}
+
+fn push_unique(keys: &mut Vec<Vec<String>>, key: Vec<String>) {
+    if !key.is_empty() && !keys.contains(&key) {
+        keys.push(key);
+    }
+}
+
+/// Whether `needle` occurs as a contiguous run of words in `haystack`.
+fn contains_run(haystack: &[String], needle: &[String]) -> bool {
+    !needle.is_empty() && haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+fn harmonic_mean(a: f64, b: f64) -> f64 {
+    if a + b == 0.0 {
+        0.0
+    } else {
+        2.0 * a * b / (a + b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Phrase, StringPool};
+    use approx::assert_relative_eq;
+
+    fn phrase_set(texts: &[&str]) -> PhraseSet {
+        let phrases: Vec<Phrase> = texts
+            .iter()
+            .enumerate()
+            .map(|(i, t)| Phrase::new(*t, t.to_lowercase(), 1.0 / (i + 1) as f64, 1))
+            .collect();
+        PhraseSet::from_phrases(&phrases, &mut StringPool::new())
+    }
+
+    fn gold(texts: &[&str]) -> Vec<String> {
+        texts.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn test_exact_match_at_k() {
+        let predicted = phrase_set(&["machine learning", "Neural  Networks", "data", "graphs"]);
+        let gold = gold(&["neural networks", "machine learning", "deep learning"]);
+
+        let all = Evaluator::new().evaluate(&predicted, &gold);
+        assert_eq!((all.matched, all.predicted, all.gold), (2, 4, 3));
+        assert_relative_eq!(all.scores.precision, 0.5);
+        assert_relative_eq!(all.scores.recall, 2.0 / 3.0);
+        assert_relative_eq!(all.scores.f1, 4.0 / 7.0);
+
+        let top1 = Evaluator::new().with_k(1).evaluate(&predicted, &gold);
+        assert_eq!((top1.matched, top1.predicted), (1, 1));
+        assert_relative_eq!(top1.scores.precision, 1.0);
+    }
+
+    #[test]
+    fn test_partial_match_and_gold_used_once() {
+        let predicted = phrase_set(&["deep neural network", "neural network"]);
+        let gold = gold(&["neural network"]);
+
+        let exact = Evaluator::new().evaluate(&predicted, &gold);
+        assert_eq!(exact.matched, 1);
+        let partial = Evaluator::new()
+            .with_match_mode(MatchMode::Partial)
+            .evaluate(&predicted, &gold);
+        // The top-ranked phrase claims the single gold keyphrase.
+        assert_eq!(partial.matched, 1);
+        assert_relative_eq!(partial.scores.precision, 0.5);
+    }
+
+    #[cfg(feature = "stemming")]
+    #[test]
+    fn test_stem_match() {
+        let predicted = phrase_set(&["neural networks"]);
+        let gold = gold(&["neural network"]);
+        assert_eq!(Evaluator::new().evaluate(&predicted, &gold).matched, 0);
+        let stem = Evaluator::new().with_match_mode(MatchMode::Stem);
+        assert_eq!(stem.evaluate(&predicted, &gold).matched, 1);
+    }
+
+    #[test]
+    fn test_dataset_macro_and_micro() {
+        let a = phrase_set(&["rust", "graphs"]);
+        let b = phrase_set(&["python", "java", "go", "c"]);
+        let gold_a = gold(&["rust"]);
+        let gold_b = gold(&["python", "haskell"]);
+
+        let scores =
+            Evaluator::new().evaluate_dataset([(&a, gold_a.as_slice()), (&b, gold_b.as_slice())]);
+        assert_eq!(scores.num_docs, 2);
+        assert_relative_eq!(scores.macro_avg.precision, (0.5 + 0.25) / 2.0);
+        assert_relative_eq!(scores.macro_avg.recall, (1.0 + 0.5) / 2.0);
+        assert_relative_eq!(scores.micro_avg.precision, 2.0 / 6.0);
+        assert_relative_eq!(scores.micro_avg.recall, 2.0 / 3.0);
+
+        let empty = Evaluator::new().evaluate_dataset(std::iter::empty());
+        assert_eq!(empty, DatasetScores::default());
+    }
+}
@@ -0,0 +1,12 @@
+//! Keyphrase evaluation against gold standards
+//!
+//! This module scores extracted phrases against gold keyphrases with the
+//! precision/recall/F1@k metrics used in the keyphrase extraction
+//! literature, so paper-style comparisons don't have to reimplement them,
+//! and loads the standard benchmark datasets to score against.
+
+pub mod datasets;
+pub mod metrics;
+
+pub use datasets::{load_inspec, load_key_files, load_semeval2010, Dataset, GoldDocument};
+pub use metrics::{DatasetScores, DocumentScores, EvalScores, Evaluator, MatchMode};
@@ -3,14 +3,39 @@
 //! This module provides a mutable graph builder that uses FxHashMap
 //! for O(1) edge lookups during construction.
 
-use crate::types::{PosTag, Token};
+use crate::types::{NodeKey, PosTag, Token};
 use rayon::prelude::*;
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::hash_map::Entry;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 
 type EdgeWeights = FxHashMap<(Arc<str>, Arc<str>), f64>;
 
+/// How a batch of weights for one node pair combines with the existing edge
+#[derive(Debug, Clone, Copy)]
+enum EdgeMerge {
+    /// Add every weight, in order
+    Sum,
+    /// Keep the existing weight, else the first new one
+    First,
+    /// Keep the largest weight
+    Max,
+}
+
+impl EdgeMerge {
+    fn apply(self, existing: Option<f64>, mut weights: impl Iterator<Item = f64>) -> f64 {
+        match self {
+            EdgeMerge::Sum => weights.fold(existing.unwrap_or(0.0), |w, x| w + x),
+            EdgeMerge::First => existing.or_else(|| weights.next()).unwrap_or(0.0),
+            EdgeMerge::Max => {
+                let first = existing.or_else(|| weights.next()).unwrap_or(0.0);
+                weights.fold(first, |w, x| if w < x { x } else { w })
+            }
+        }
+    }
+}
+
 /// A node in the graph builder
 #[derive(Debug, Clone)]
 pub struct BuilderNode {
@@ -123,6 +148,129 @@ impl GraphBuilder {
         }
     }
 
+    /// Raise the edge weight between two nodes to at least `weight`
+    ///
+    /// Creates the edge if it doesn't exist; otherwise keeps the larger of
+    /// the existing and new weights.
+    pub fn max_edge(&mut self, from: u32, to: u32, weight: f64) {
+        if from == to {
+            return; // No self-loops
+        }
+
+        for (a, b) in [(from, to), (to, from)] {
+            if let Some(node) = self.nodes.get_mut(a as usize) {
+                let w = node.edges.entry(b).or_insert(weight);
+                if *w < weight {
+                    *w = weight;
+                }
+            }
+        }
+    }
+
+    /// Add a batch of undirected edges `(from, to, weight)`, each as if by
+    /// [`increment_edge`](Self::increment_edge) in slice order.
+    ///
+    /// Sorts a copy of `edges` by node pair (stably, so each pair's weights
+    /// are still summed in their original order and results are
+    /// bit-identical) and updates each pair's adjacency entries once,
+    /// instead of hashing into two maps per co-occurrence.
+    pub fn increment_edges(&mut self, edges: &[(u32, u32, f64)]) {
+        self.apply_edges(edges, EdgeMerge::Sum);
+    }
+
+    /// Like [`increment_edges`](Self::increment_edges), with
+    /// [`set_edge`](Self::set_edge) semantics: the first weight of each pair
+    /// wins.
+    pub fn set_edges(&mut self, edges: &[(u32, u32, f64)]) {
+        self.apply_edges(edges, EdgeMerge::First);
+    }
+
+    /// Like [`increment_edges`](Self::increment_edges), with
+    /// [`max_edge`](Self::max_edge) semantics.
+    pub fn max_edges(&mut self, edges: &[(u32, u32, f64)]) {
+        self.apply_edges(edges, EdgeMerge::Max);
+    }
+
+    fn apply_edges(&mut self, edges: &[(u32, u32, f64)], merge: EdgeMerge) {
+        // Pack each pair as (min << 32 | max) so sorting compares one u64.
+        let mut keyed: Vec<(u64, f64)> = edges
+            .iter()
+            .filter(|&&(a, b, _)| a != b && a.max(b) < self.nodes.len() as u32)
+            .map(|&(a, b, w)| ((u64::from(a.min(b)) << 32) | u64::from(a.max(b)), w))
+            .collect();
+        // Order within a pair only matters when its weights differ.
+        if keyed
+            .windows(2)
+            .all(|w| w[0].1.to_bits() == w[1].1.to_bits())
+        {
+            keyed.sort_unstable_by_key(|e| e.0);
+        } else {
+            keyed.sort_by_key(|e| e.0);
+        }
+
+        // Split into one run per pair, counting each node's new neighbors.
+        let mut runs = Vec::new();
+        let mut degree = vec![0usize; self.nodes.len()];
+        let mut start = 0;
+        while start < keyed.len() {
+            let key = keyed[start].0;
+            let end = keyed[start..]
+                .iter()
+                .position(|e| e.0 != key)
+                .map_or(keyed.len(), |n| start + n);
+            let (a, b) = ((key >> 32) as u32, key as u32);
+            degree[a as usize] += 1;
+            degree[b as usize] += 1;
+            runs.push((a, b, start..end));
+            start = end;
+        }
+
+        // Group both directions of every run by source node (counting
+        // sort), so each adjacency map is reserved once and filled
+        // independently of the others.
+        let mut offsets = Vec::with_capacity(degree.len() + 1);
+        offsets.push(0);
+        for d in &degree {
+            offsets.push(offsets[offsets.len() - 1] + d);
+        }
+        let mut cursor = offsets[..degree.len()].to_vec();
+        let mut directed = vec![(0u32, 0usize); 2 * runs.len()];
+        for (i, &(a, b, _)) in runs.iter().enumerate() {
+            for (from, to) in [(a, b), (b, a)] {
+                directed[cursor[from as usize]] = (to, i);
+                cursor[from as usize] += 1;
+            }
+        }
+
+        let fill = |node: &mut BuilderNode, targets: &[(u32, usize)]| {
+            node.edges.reserve(targets.len());
+            for &(to, i) in targets {
+                let weights = keyed[runs[i].2.clone()].iter().map(|e| e.1);
+                match node.edges.entry(to) {
+                    Entry::Occupied(mut e) => {
+                        let w = merge.apply(Some(*e.get()), weights);
+                        e.insert(w);
+                    }
+                    Entry::Vacant(e) => {
+                        e.insert(merge.apply(None, weights));
+                    }
+                }
+            }
+        };
+        let groups = offsets.windows(2).map(|w| &directed[w[0]..w[1]]);
+        if directed.len() >= PARALLEL_CHUNK_LEN {
+            let groups: Vec<_> = groups.collect();
+            self.nodes
+                .par_iter_mut()
+                .zip(groups)
+                .for_each(|(node, targets)| fill(node, targets));
+        } else {
+            for (node, targets) in self.nodes.iter_mut().zip(groups) {
+                fill(node, targets);
+            }
+        }
+    }
+
     /// Build a graph from tokens using a sliding window
     ///
     /// This creates edges between tokens that co-occur within the window.
@@ -137,19 +285,21 @@ impl GraphBuilder {
     /// If `include_pos` is None, uses the default content word filter.
     /// If `include_pos` is Some, only includes tokens with matching POS tags.
     /// Sentence boundaries are always respected (no cross-sentence edges).
+    /// Nodes are keyed by `node_key`, a [`NodeKey`] or `use_pos_in_nodes` as
+    /// a `bool`.
     pub fn from_tokens_with_pos(
         tokens: &[Token],
         window_size: usize,
         use_weights: bool,
         include_pos: Option<&[PosTag]>,
-        use_pos_in_nodes: bool,
+        node_key: impl Into<NodeKey>,
     ) -> Self {
         Self::from_tokens_with_pos_and_boundaries(
             tokens,
             window_size,
             use_weights,
             include_pos,
-            use_pos_in_nodes,
+            node_key,
             true, // default: respect sentence boundaries
         )
     }
@@ -166,13 +316,131 @@ impl GraphBuilder {
         window_size: usize,
         use_weights: bool,
         include_pos: Option<&[PosTag]>,
-        use_pos_in_nodes: bool,
+        node_key: impl Into<NodeKey>,
         respect_sentence_boundaries: bool,
     ) -> Self {
         let mut builder = Self::with_capacity(tokens.len() / 2);
+        builder.add_cooccurrences(
+            tokens,
+            window_size,
+            use_weights,
+            include_pos,
+            &node_key.into(),
+            respect_sentence_boundaries,
+            1.0,
+        );
+        builder
+    }
+
+    /// Build a single graph from several token streams, scaling each
+    /// stream's co-occurrence edges by its multiplier.
+    ///
+    /// Used by ExpandRank, where the target document (multiplier `1.0`) is
+    /// combined with neighbor documents weighted by their similarity to it.
+    /// With `use_weights`, edge weights are the multiplier-weighted sum of
+    /// co-occurrence counts across streams; without it, each edge takes the
+    /// largest multiplier of any stream in which the pair co-occurs.
+    /// Streams with a non-positive multiplier are skipped.
+    pub fn from_weighted_streams(
+        streams: &[(&[Token], f64)],
+        window_size: usize,
+        use_weights: bool,
+        include_pos: Option<&[PosTag]>,
+        node_key: impl Into<NodeKey>,
+        respect_sentence_boundaries: bool,
+    ) -> Self {
+        let total: usize = streams.iter().map(|(t, _)| t.len()).sum();
+        let node_key = node_key.into();
+        let mut builder = Self::with_capacity(total / 2);
+        for &(tokens, multiplier) in streams {
+            if multiplier <= 0.0 {
+                continue;
+            }
+            builder.add_cooccurrences(
+                tokens,
+                window_size,
+                use_weights,
+                include_pos,
+                &node_key,
+                respect_sentence_boundaries,
+                multiplier,
+            );
+        }
+        builder
+    }
+
+    /// Accumulate co-occurrence edges from a further piece of a document.
+    ///
+    /// Incremental counterpart of [`from_tokens_with_pos_and_boundaries`]:
+    /// with `respect_sentence_boundaries`, feeding a document in pieces that
+    /// each end on a sentence boundary yields exactly the graph (node IDs and
+    /// edge weights) of building it in one call.
+    ///
+    /// [`from_tokens_with_pos_and_boundaries`]: GraphBuilder::from_tokens_with_pos_and_boundaries
+    pub fn add_tokens(
+        &mut self,
+        tokens: &[Token],
+        window_size: usize,
+        use_weights: bool,
+        include_pos: Option<&[PosTag]>,
+        node_key: impl Into<NodeKey>,
+        respect_sentence_boundaries: bool,
+    ) {
+        self.add_cooccurrences(
+            tokens,
+            window_size,
+            use_weights,
+            include_pos,
+            &node_key.into(),
+            respect_sentence_boundaries,
+            1.0,
+        );
+    }
 
-        // Filter to graph candidates based on POS tags
-        let candidates: Vec<_> = tokens
+    /// Add co-occurrence edges from one token stream, scaled by `multiplier`.
+    #[allow(clippy::too_many_arguments)]
+    fn add_cooccurrences(
+        &mut self,
+        tokens: &[Token],
+        window_size: usize,
+        use_weights: bool,
+        include_pos: Option<&[PosTag]>,
+        node_key: &NodeKey,
+        respect_sentence_boundaries: bool,
+        multiplier: f64,
+    ) {
+        let occurrences = self.candidate_occurrences(tokens, include_pos, node_key);
+
+        let mut edges = Vec::with_capacity(occurrences.len() * window_size.saturating_sub(1));
+        for j in 0..occurrences.len() {
+            // Window extends forward, within the sentence when bounded
+            let window_end = std::cmp::min(j + window_size, occurrences.len());
+            for k in (j + 1)..window_end {
+                if respect_sentence_boundaries && occurrences[k].0 != occurrences[j].0 {
+                    break;
+                }
+                edges.push((occurrences[j].2, occurrences[k].2, multiplier));
+            }
+        }
+
+        // Weighted mode accumulates; otherwise keep the largest multiplier.
+        if use_weights {
+            self.increment_edges(&edges);
+        } else {
+            self.max_edges(&edges);
+        }
+    }
+
+    /// Graph candidates among `tokens` (filtered by POS tags) as
+    /// `(sentence_idx, token_idx, node)` in document order, creating nodes
+    /// in order of first occurrence.
+    fn candidate_occurrences(
+        &mut self,
+        tokens: &[Token],
+        include_pos: Option<&[PosTag]>,
+        node_key: &NodeKey,
+    ) -> Vec<(u32, u32, u32)> {
+        tokens
             .iter()
             .filter(|t| {
                 if t.is_stopword {
@@ -183,59 +451,11 @@ impl GraphBuilder {
                     None => t.pos.is_content_word(),
                 }
             })
-            .collect();
-        let candidate_keys: Vec<String> = candidates
-            .iter()
-            .map(|t| t.graph_key(use_pos_in_nodes))
-            .collect();
-
-        if respect_sentence_boundaries {
-            // Process each sentence separately (don't create edges across sentences)
-            let mut i = 0;
-            while i < candidates.len() {
-                let sent_idx = candidates[i].sentence_idx;
-
-                // Find all candidates in this sentence
-                let sent_start = i;
-                while i < candidates.len() && candidates[i].sentence_idx == sent_idx {
-                    i += 1;
-                }
-                let sent_end = i;
-
-                // Create nodes and edges within the sentence
-                for j in sent_start..sent_end {
-                    let node_j = builder.get_or_create_node(&candidate_keys[j]);
-
-                    // Window extends forward
-                    let window_end = std::cmp::min(j + window_size, sent_end);
-                    for key in candidate_keys.iter().take(window_end).skip(j + 1) {
-                        let node_k = builder.get_or_create_node(key);
-                        if use_weights {
-                            builder.increment_edge(node_j, node_k, 1.0);
-                        } else {
-                            builder.set_edge(node_j, node_k, 1.0);
-                        }
-                    }
-                }
-            }
-        } else {
-            // Single pass: window slides across the entire candidate sequence
-            for j in 0..candidates.len() {
-                let node_j = builder.get_or_create_node(&candidate_keys[j]);
-
-                let window_end = std::cmp::min(j + window_size, candidates.len());
-                for key in candidate_keys.iter().take(window_end).skip(j + 1) {
-                    let node_k = builder.get_or_create_node(key);
-                    if use_weights {
-                        builder.increment_edge(node_j, node_k, 1.0);
-                    } else {
-                        builder.set_edge(node_j, node_k, 1.0);
-                    }
-                }
-            }
-        }
-
-        builder
+            .map(|t| {
+                let node = self.get_or_create_node(&node_key.key(t));
+                (t.sentence_idx as u32, t.token_idx as u32, node)
+            })
+            .collect()
     }
 
     /// Get the number of nodes in the graph
@@ -320,7 +540,7 @@ pub fn build_graph_parallel_with_pos(
     window_size: usize,
     use_weights: bool,
     include_pos: Option<&[PosTag]>,
-    use_pos_in_nodes: bool,
+    node_key: impl Into<NodeKey>,
 ) -> GraphBuilder {
     // For small documents, sequential is faster
     if tokens.len() < 1000 {
@@ -329,10 +549,12 @@ pub fn build_graph_parallel_with_pos(
             window_size,
             use_weights,
             include_pos,
-            use_pos_in_nodes,
+            node_key,
         );
     }
 
+    let node_key = node_key.into();
+
     // Group tokens by sentence for parallel processing
     let mut sentences: Vec<Vec<&Token>> = Vec::new();
     let mut current_sent = Vec::new();
@@ -364,7 +586,7 @@ pub fn build_graph_parallel_with_pos(
 
     // Use specialized unweighted path for better deduplication with HashSet
     if !use_weights {
-        return build_unweighted_parallel(sentences, window_size, use_pos_in_nodes);
+        return build_unweighted_parallel(sentences, window_size, &node_key);
     }
 
     // Convert lemmas to Arc<str> once before parallel processing
@@ -374,7 +596,7 @@ pub fn build_graph_parallel_with_pos(
         .map(|sent_tokens| {
             sent_tokens
                 .iter()
-                .map(|&t| (t, Arc::from(t.graph_key(use_pos_in_nodes))))
+                .map(|&t| (t, Arc::from(node_key.key(t))))
                 .collect()
         })
         .collect();
@@ -434,44 +656,140 @@ pub fn build_graph_parallel_with_pos(
 /// cross-sentence windowing.
 ///
 /// When `respect_sentence_boundaries` is `false`, the parallel sentence-based
-/// splitting doesn't apply, so this falls back to the sequential
-/// `from_tokens_with_pos_and_boundaries`. For `true`, delegates to
-/// `build_graph_parallel_with_pos`.
+/// splitting doesn't apply, so co-occurrences are counted over overlapping
+/// chunks with [`window_cooccurrences_parallel`] (same node order and
+/// weights as `from_tokens_with_pos_and_boundaries`). For `true`, delegates
+/// to `build_graph_parallel_with_pos`.
 pub fn build_graph_parallel_with_pos_and_boundaries(
     tokens: &[Token],
     window_size: usize,
     use_weights: bool,
     include_pos: Option<&[PosTag]>,
-    use_pos_in_nodes: bool,
+    node_key: impl Into<NodeKey>,
     respect_sentence_boundaries: bool,
 ) -> GraphBuilder {
     if respect_sentence_boundaries {
-        build_graph_parallel_with_pos(
-            tokens,
-            window_size,
-            use_weights,
-            include_pos,
-            use_pos_in_nodes,
-        )
-    } else {
-        // Cross-sentence windowing: the window spans the entire document,
-        // so sentence-based parallelism doesn't apply. Use the sequential path.
+        build_graph_parallel_with_pos(tokens, window_size, use_weights, include_pos, node_key)
+    } else if tokens.len() < 1000 {
         GraphBuilder::from_tokens_with_pos_and_boundaries(
             tokens,
             window_size,
             use_weights,
             include_pos,
-            use_pos_in_nodes,
+            node_key,
             false,
         )
+    } else {
+        // Cross-sentence windowing: the window spans the entire document,
+        // so count co-occurrences over overlapping chunks instead. Nodes
+        // are created serially, in the same order as the sequential path.
+        let mut builder = GraphBuilder::new();
+        let occurrences = builder.candidate_occurrences(tokens, include_pos, &node_key.into());
+        let totals = window_cooccurrences_parallel(&occurrences, window_size, false, |_| 1.0);
+        let edges: Vec<_> = totals
+            .iter()
+            .map(|&((a, b), count)| (a, b, if use_weights { count } else { 1.0 }))
+            .collect();
+        if use_weights {
+            builder.increment_edges(&edges);
+        } else {
+            builder.max_edges(&edges);
+        }
+        builder
+    }
+}
+
+/// Candidate occurrences per chunk in [`window_cooccurrences_parallel`]
+pub const PARALLEL_CHUNK_LEN: usize = 8_192;
+
+/// Candidate occurrences at which pipeline window graphs are built with
+/// [`window_cooccurrences_parallel`] (`parallel` feature only)
+pub const PARALLEL_GRAPH_THRESHOLD: usize = 4 * PARALLEL_CHUNK_LEN;
+
+/// Count windowed co-occurrences in parallel over overlapping chunks.
+///
+/// `occurrences` lists `(sentence_idx, token_idx, node)` for each graph
+/// candidate in document order, as collected by the window builders. The
+/// sequence is split into chunks of [`PARALLEL_CHUNK_LEN`] window starts;
+/// each chunk also reads the `window_size - 1` occurrences after it, so a
+/// window straddling a boundary is counted once, by the chunk it starts in.
+/// Each pair contributes `weight(distance)`, where `distance` is the token
+/// distance (at least 1).
+///
+/// Chunks are counted with rayon and their partial edge maps merged in
+/// chunk order. Returns one total per undirected pair `(min, max)`, sorted
+/// by pair, without self-pairs. Chunking does not depend on the thread
+/// count, so results are reproducible; integer weights sum exactly as in
+/// the serial builder, while fractional ones may differ in the last bits.
+pub fn window_cooccurrences_parallel<F>(
+    occurrences: &[(u32, u32, u32)],
+    window_size: usize,
+    sentence_bounded: bool,
+    weight: F,
+) -> Vec<((u32, u32), f64)>
+where
+    F: Fn(u32) -> f64 + Sync,
+{
+    let partials: Vec<Vec<(u64, f64)>> = (0..occurrences.len())
+        .step_by(PARALLEL_CHUNK_LEN)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|start| {
+            let end = std::cmp::min(start + PARALLEL_CHUNK_LEN, occurrences.len());
+            let mut pairs = Vec::with_capacity((end - start) * window_size.saturating_sub(1));
+            for j in start..end {
+                // Windows may run past `end` into the overlap.
+                let window_end = std::cmp::min(j + window_size, occurrences.len());
+                for k in (j + 1)..window_end {
+                    let ((sent_j, pos_j, a), (sent_k, pos_k, b)) = (occurrences[j], occurrences[k]);
+                    if sentence_bounded && sent_k != sent_j {
+                        break;
+                    }
+                    if a != b {
+                        let key = (u64::from(a.min(b)) << 32) | u64::from(a.max(b));
+                        pairs.push((key, weight(pos_k.saturating_sub(pos_j).max(1))));
+                    }
+                }
+            }
+            // Order within a pair only matters when its weights differ.
+            if pairs
+                .windows(2)
+                .all(|w| w[0].1.to_bits() == w[1].1.to_bits())
+            {
+                pairs.sort_unstable_by_key(|e| e.0);
+            } else {
+                pairs.sort_by_key(|e| e.0);
+            }
+            sum_runs(pairs)
+        })
+        .collect();
+
+    // Stable, so each pair's partials are summed in chunk order.
+    let mut merged = partials.concat();
+    merged.par_sort_by_key(|e| e.0);
+    sum_runs(merged)
+        .into_iter()
+        .map(|(key, w)| (((key >> 32) as u32, key as u32), w))
+        .collect()
+}
+
+/// Sum adjacent entries with equal keys, in order.
+fn sum_runs(sorted: Vec<(u64, f64)>) -> Vec<(u64, f64)> {
+    let mut out: Vec<(u64, f64)> = Vec::with_capacity(sorted.len());
+    for (key, w) in sorted {
+        match out.last_mut() {
+            Some(last) if last.0 == key => last.1 += w,
+            _ => out.push((key, w)),
+        }
     }
+    out
 }
 
 /// Build unweighted graph in parallel using HashSet for efficient deduplication
 fn build_unweighted_parallel(
     sentences: Vec<Vec<&Token>>,
     window_size: usize,
-    use_pos_in_nodes: bool,
+    node_key: &NodeKey,
 ) -> GraphBuilder {
     // Build partial edge sets in parallel - HashSet automatically deduplicates
     let partial_sets: Vec<FxHashSet<(Arc<str>, Arc<str>)>> = sentences
@@ -480,7 +798,7 @@ fn build_unweighted_parallel(
             // Convert lemmas to Arc<str> once per sentence
             let lemma_arcs: Vec<Arc<str>> = sent_tokens
                 .iter()
-                .map(|t| Arc::from(t.graph_key(use_pos_in_nodes)))
+                .map(|t| Arc::from(node_key.key(t)))
                 .collect();
 
             let mut edge_set = FxHashSet::default();
@@ -541,6 +859,7 @@ mod tests {
             sentence_idx: sent_idx,
             token_idx: tok_idx,
             is_stopword: false,
+            section: None,
         }
     }
 
@@ -636,6 +955,61 @@ mod tests {
         assert_eq!(builder.get_node(id_b).unwrap().edges.get(&id_a), Some(&1.0));
     }
 
+    #[test]
+    fn test_weighted_streams_scale_edges() {
+        let target = vec![
+            make_token("machine", "machine", 0, 0),
+            make_token("learning", "learning", 0, 1),
+        ];
+        let neighbor = vec![
+            make_token("machine", "machine", 0, 0),
+            make_token("learning", "learning", 0, 1),
+            make_token("model", "model", 0, 2),
+        ];
+        let streams: Vec<(&[Token], f64)> =
+            vec![(&target, 1.0), (&neighbor, 0.5), (&neighbor, 0.0)];
+
+        let weighted = GraphBuilder::from_weighted_streams(&streams, 2, true, None, false, true);
+        let m = weighted.get_node_id("machine").unwrap();
+        let l = weighted.get_node_id("learning").unwrap();
+        assert_eq!(weighted.node_count(), 3);
+        assert_eq!(weighted.get_node(m).unwrap().edges.get(&l), Some(&1.5));
+
+        let binary = GraphBuilder::from_weighted_streams(&streams, 2, false, None, false, true);
+        let m = binary.get_node_id("machine").unwrap();
+        let l = binary.get_node_id("learning").unwrap();
+        let model = binary.get_node_id("model").unwrap();
+        assert_eq!(binary.get_node(m).unwrap().edges.get(&l), Some(&1.0));
+        assert_eq!(binary.get_node(l).unwrap().edges.get(&model), Some(&0.5));
+    }
+
+    #[test]
+    fn test_add_tokens_sentence_pieces_match_single_build() {
+        let tokens = vec![
+            make_token("machine", "machine", 0, 0),
+            make_token("learning", "learning", 0, 1),
+            make_token("model", "model", 0, 2),
+            make_token("learning", "learning", 1, 3),
+            make_token("model", "model", 1, 4),
+            make_token("data", "data", 2, 5),
+            make_token("machine", "machine", 2, 6),
+        ];
+        let whole = GraphBuilder::from_tokens_with_pos(&tokens, 3, true, None, false);
+
+        let mut pieces = GraphBuilder::new();
+        for piece in [&tokens[..3], &tokens[3..5], &tokens[5..]] {
+            pieces.add_tokens(piece, 3, true, None, false, true);
+        }
+
+        let (whole, pieces) = (
+            CsrGraph::from_builder(&whole),
+            CsrGraph::from_builder(&pieces),
+        );
+        assert_eq!(whole.lemmas, pieces.lemmas);
+        assert_eq!(whole.col_idx, pieces.col_idx);
+        assert_eq!(whole.weights, pieces.weights);
+    }
+
     #[test]
     fn test_use_edge_weights_true_accumulates() {
         // Create tokens where same pair co-occurs multiple times
@@ -699,6 +1073,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 0,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "runs".to_string(),
@@ -709,6 +1084,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 1,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "fast".to_string(),
@@ -719,6 +1095,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 2,
                 is_stopword: false,
+                section: None,
             },
         ];
 
@@ -771,6 +1148,7 @@ mod tests {
                 sentence_idx: sent_idx,
                 token_idx: sent_idx * 3,
                 is_stopword: false,
+                section: None,
             });
             tokens.push(Token {
                 text: "learning".to_string(),
@@ -781,6 +1159,7 @@ mod tests {
                 sentence_idx: sent_idx,
                 token_idx: sent_idx * 3 + 1,
                 is_stopword: false,
+                section: None,
             });
             tokens.push(Token {
                 text: "system".to_string(),
@@ -791,6 +1170,7 @@ mod tests {
                 sentence_idx: sent_idx,
                 token_idx: sent_idx * 3 + 2,
                 is_stopword: false,
+                section: None,
             });
         }
 
@@ -828,6 +1208,7 @@ mod tests {
                 sentence_idx: sent_idx,
                 token_idx: sent_idx * 3,
                 is_stopword: false,
+                section: None,
             });
             tokens.push(Token {
                 text: "learning".to_string(),
@@ -838,6 +1219,7 @@ mod tests {
                 sentence_idx: sent_idx,
                 token_idx: sent_idx * 3 + 1,
                 is_stopword: false,
+                section: None,
             });
             tokens.push(Token {
                 text: "system".to_string(),
@@ -848,6 +1230,7 @@ mod tests {
                 sentence_idx: sent_idx,
                 token_idx: sent_idx * 3 + 2,
                 is_stopword: false,
+                section: None,
             });
         }
 
@@ -889,6 +1272,7 @@ mod tests {
                 sentence_idx: sent_idx,
                 token_idx: sent_idx * 3,
                 is_stopword: false,
+                section: None,
             });
             tokens.push(Token {
                 text: "beta".to_string(),
@@ -899,6 +1283,7 @@ mod tests {
                 sentence_idx: sent_idx,
                 token_idx: sent_idx * 3 + 1,
                 is_stopword: false,
+                section: None,
             });
             tokens.push(Token {
                 text: "gamma".to_string(),
@@ -909,6 +1294,7 @@ mod tests {
                 sentence_idx: sent_idx,
                 token_idx: sent_idx * 3 + 2,
                 is_stopword: false,
+                section: None,
             });
         }
 
@@ -1132,4 +1518,77 @@ mod tests {
         assert_eq!(seq_csr.col_idx, par_csr.col_idx);
         assert_eq!(seq_csr.weights, par_csr.weights);
     }
+
+    #[test]
+    fn test_batched_edges_match_per_pair_updates() {
+        // Non-dyadic weights make the summation order observable.
+        let edges: Vec<(u32, u32, f64)> = (0..200u32)
+            .map(|i| (i * 7 % 5, i * 3 % 5, 1.0 / f64::from(i % 7 + 1)))
+            .collect();
+        let fresh = || {
+            let mut b = GraphBuilder::new();
+            for i in 0..5 {
+                b.get_or_create_node(&format!("n{i}"));
+            }
+            b.increment_directed_edge(0, 1, 0.1);
+            b
+        };
+
+        for (batch, single) in [
+            (
+                GraphBuilder::increment_edges as fn(&mut GraphBuilder, &[(u32, u32, f64)]),
+                GraphBuilder::increment_edge as fn(&mut GraphBuilder, u32, u32, f64),
+            ),
+            (GraphBuilder::set_edges, GraphBuilder::set_edge),
+            (GraphBuilder::max_edges, GraphBuilder::max_edge),
+        ] {
+            let mut expected = fresh();
+            for &(a, b, w) in &edges {
+                single(&mut expected, a, b, w);
+            }
+            let mut actual = fresh();
+            batch(&mut actual, &edges);
+
+            for id in 0..5 {
+                let (e, a) = (expected.get_node(id).unwrap(), actual.get_node(id).unwrap());
+                assert_eq!(e.edges, a.edges, "node {id}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parallel_cross_sentence_chunks_match_sequential() {
+        // Several chunks, so windows straddle chunk boundaries.
+        let tokens: Vec<Token> = (0..3 * PARALLEL_CHUNK_LEN + 17)
+            .map(|i| {
+                let lemma = format!("w{}", (i * 7919) % 101);
+                make_token(&lemma, &lemma, i / 12, i)
+            })
+            .collect();
+
+        for use_weights in [true, false] {
+            let seq = GraphBuilder::from_tokens_with_pos_and_boundaries(
+                &tokens,
+                4,
+                use_weights,
+                None,
+                false,
+                false,
+            );
+            let par = build_graph_parallel_with_pos_and_boundaries(
+                &tokens,
+                4,
+                use_weights,
+                None,
+                false,
+                false,
+            );
+
+            let (seq_csr, par_csr) = (CsrGraph::from_builder(&seq), CsrGraph::from_builder(&par));
+            assert_eq!(seq_csr.lemmas, par_csr.lemmas, "node order must match");
+            assert_eq!(seq_csr.row_ptr, par_csr.row_ptr);
+            assert_eq!(seq_csr.col_idx, par_csr.col_idx);
+            assert_eq!(seq_csr.weights, par_csr.weights);
+        }
+    }
 }
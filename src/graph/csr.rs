@@ -2,15 +2,34 @@
 //!
 //! CSR is optimized for iteration over neighbors, which is exactly what
 //! PageRank needs during power iteration.
+//!
+//! Without the `std` feature, `CsrGraph::from_builder` is unavailable;
+//! build graphs with [`CsrGraph::from_edges`] instead.
+//!
+//! [`CsrView`] borrows just the adjacency arrays. The rankers' `run_view`
+//! methods take one, so a graph whose arrays live elsewhere — e.g. a
+//! memory-mapped file (see `graph::mmap`, `mmap` feature) — can be ranked
+//! without copying it into a [`CsrGraph`].
 
+#[cfg(feature = "std")]
 use super::builder::GraphBuilder;
+use alloc::{string::String, vec, vec::Vec};
+#[cfg(feature = "std")]
 use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+/// Lemma → node ID lookup table (a `BTreeMap` without `std`)
+#[cfg(feature = "std")]
+pub type LemmaIndex = FxHashMap<String, u32>;
+/// Lemma → node ID lookup table (a `BTreeMap` without `std`)
+#[cfg(not(feature = "std"))]
+pub type LemmaIndex = alloc::collections::BTreeMap<String, u32>;
 
 /// A graph in Compressed Sparse Row format
 ///
 /// CSR stores edges contiguously, making iteration over neighbors very fast.
 /// This is ideal for PageRank which repeatedly iterates over all edges.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CsrGraph {
     /// Number of nodes
     pub num_nodes: usize,
@@ -27,11 +46,89 @@ pub struct CsrGraph {
     /// Lemmas for each node
     pub lemmas: Vec<String>,
     /// Fast lemma → node_id lookup (O(1) instead of O(N) linear search)
-    pub lemma_to_id: FxHashMap<String, u32>,
+    pub lemma_to_id: LemmaIndex,
+}
+
+/// Borrowed adjacency arrays of a CSR graph
+///
+/// Same layout and meaning as the corresponding [`CsrGraph`] fields;
+/// lemmas are not included.
+#[derive(Debug, Clone, Copy)]
+pub struct CsrView<'a> {
+    /// Number of nodes
+    pub num_nodes: usize,
+    /// Row pointers: node i's edges are at indices row_ptr[i]..row_ptr[i+1]
+    pub row_ptr: &'a [usize],
+    /// Column indices (target nodes) for each edge
+    pub col_idx: &'a [u32],
+    /// Edge weights
+    pub weights: &'a [f64],
+    /// Out-degree for each node
+    pub out_degree: &'a [u32],
+    /// Total outgoing weight for each node
+    pub total_weight: &'a [f64],
+}
+
+impl<'a> CsrView<'a> {
+    /// Iterate over neighbors of a node
+    pub fn neighbors(&self, node: u32) -> impl Iterator<Item = (u32, f64)> + 'a {
+        let start = self.row_ptr[node as usize];
+        let end = self.row_ptr[node as usize + 1];
+        self.col_idx[start..end]
+            .iter()
+            .copied()
+            .zip(self.weights[start..end].iter().copied())
+    }
+
+    /// Get the out-degree of a node
+    pub fn degree(&self, node: u32) -> u32 {
+        self.out_degree[node as usize]
+    }
+
+    /// Get the total outgoing weight of a node
+    pub fn node_total_weight(&self, node: u32) -> f64 {
+        self.total_weight[node as usize]
+    }
+
+    /// Check if the graph is empty
+    pub fn is_empty(&self) -> bool {
+        self.num_nodes == 0
+    }
+
+    /// Get the total number of edges (counting each undirected edge twice)
+    pub fn num_edges(&self) -> usize {
+        self.col_idx.len()
+    }
+
+    /// Find dangling nodes (nodes with no outgoing edges)
+    pub fn dangling_nodes(&self) -> Vec<u32> {
+        (0..self.num_nodes as u32)
+            .filter(|&n| self.out_degree[n as usize] == 0)
+            .collect()
+    }
+}
+
+impl<'a> From<&'a CsrGraph> for CsrView<'a> {
+    fn from(graph: &'a CsrGraph) -> Self {
+        graph.view()
+    }
 }
 
 impl CsrGraph {
+    /// Borrow the adjacency arrays
+    pub fn view(&self) -> CsrView<'_> {
+        CsrView {
+            num_nodes: self.num_nodes,
+            row_ptr: &self.row_ptr,
+            col_idx: &self.col_idx,
+            weights: &self.weights,
+            out_degree: &self.out_degree,
+            total_weight: &self.total_weight,
+        }
+    }
+
     /// Convert a GraphBuilder into CSR format
+    #[cfg(feature = "std")]
     pub fn from_builder(builder: &GraphBuilder) -> Self {
         let num_nodes = builder.node_count();
         let mut row_ptr = Vec::with_capacity(num_nodes + 1);
@@ -75,6 +172,62 @@ impl CsrGraph {
         }
     }
 
+    /// Build a graph from node lemmas and directed `(source, target, weight)`
+    /// edges
+    ///
+    /// Node `i` is labelled `lemmas[i]`. Undirected graphs list each edge in
+    /// both directions; repeated `(source, target)` pairs are summed, as with
+    /// `GraphBuilder::increment_edge`. Edges naming a node outside `lemmas` are ignored.
+    pub fn from_edges(lemmas: Vec<String>, edges: &[(u32, u32, f64)]) -> Self {
+        let num_nodes = lemmas.len();
+        let mut sorted: Vec<(u32, u32, f64)> = edges
+            .iter()
+            .copied()
+            .filter(|&(s, t, _)| (s as usize) < num_nodes && (t as usize) < num_nodes)
+            .collect();
+        sorted.sort_by_key(|&(s, t, _)| (s, t));
+
+        let mut row_ptr = vec![0; num_nodes + 1];
+        let mut col_idx: Vec<u32> = Vec::with_capacity(sorted.len());
+        let mut weights: Vec<f64> = Vec::with_capacity(sorted.len());
+        let mut out_degree = vec![0u32; num_nodes];
+        let mut total_weight = vec![0.0; num_nodes];
+        let mut last = None;
+        for (source, target, weight) in sorted {
+            if last == Some((source, target)) {
+                *weights.last_mut().expect("repeated edge follows its first") += weight;
+            } else {
+                col_idx.push(target);
+                weights.push(weight);
+                out_degree[source as usize] += 1;
+                last = Some((source, target));
+            }
+            total_weight[source as usize] += weight;
+            row_ptr[source as usize + 1] = col_idx.len();
+        }
+        // Rows without edges end where the previous row did.
+        for node in 0..num_nodes {
+            row_ptr[node + 1] = row_ptr[node + 1].max(row_ptr[node]);
+        }
+
+        let lemma_to_id = lemmas
+            .iter()
+            .enumerate()
+            .map(|(id, lemma)| (lemma.clone(), id as u32))
+            .collect();
+
+        Self {
+            num_nodes,
+            row_ptr,
+            col_idx,
+            weights,
+            out_degree,
+            total_weight,
+            lemmas,
+            lemma_to_id,
+        }
+    }
+
     /// Iterate over neighbors of a node
     pub fn neighbors(&self, node: u32) -> impl Iterator<Item = (u32, f64)> + '_ {
         let start = self.row_ptr[node as usize];
@@ -107,17 +260,107 @@ impl CsrGraph {
         self.col_idx.len()
     }
 
+    /// Estimated heap size in bytes of the adjacency arrays and lemma tables
+    #[cfg(feature = "std")]
+    pub fn heap_bytes(&self) -> usize {
+        use core::mem::size_of;
+        let lemmas: usize = self.lemmas.iter().map(String::capacity).sum();
+        let keys: usize = self.lemma_to_id.keys().map(String::capacity).sum();
+        self.row_ptr.capacity() * size_of::<usize>()
+            + self.col_idx.capacity() * size_of::<u32>()
+            + self.weights.capacity() * size_of::<f64>()
+            + self.out_degree.capacity() * size_of::<u32>()
+            + self.total_weight.capacity() * size_of::<f64>()
+            + self.lemmas.capacity() * size_of::<String>()
+            + lemmas
+            + self.lemma_to_id.capacity() * (size_of::<(String, u32)>() + 1)
+            + keys
+    }
+
     /// Find dangling nodes (nodes with no outgoing edges)
     pub fn dangling_nodes(&self) -> Vec<u32> {
-        (0..self.num_nodes as u32)
-            .filter(|&n| self.out_degree[n as usize] == 0)
-            .collect()
+        self.view().dangling_nodes()
     }
 
     /// Get node ID by lemma (O(1) HashMap lookup)
     pub fn get_node_by_lemma(&self, lemma: &str) -> Option<u32> {
         self.lemma_to_id.get(lemma).copied()
     }
+
+    /// Drop every edge incident to a node where `keep[node]` is false
+    ///
+    /// Node IDs and lemmas are unchanged; dropped nodes become isolated
+    /// (dangling). Edge arrays are compacted and degrees recomputed.
+    pub fn retain_nodes(&mut self, keep: &[bool]) {
+        assert_eq!(keep.len(), self.num_nodes, "keep mask length != node count");
+        self.retain_edges(|source, target, _| keep[source] && keep[target as usize]);
+    }
+
+    /// Keep only the edges for which `f(source, target, weight)` is true
+    ///
+    /// Edge arrays are compacted in place and degrees recomputed; node IDs
+    /// are unchanged.
+    pub fn retain_edges(&mut self, mut f: impl FnMut(usize, u32, f64) -> bool) {
+        let mut write = 0;
+        let mut row_start = 0;
+        for node in 0..self.num_nodes {
+            let row_end = self.row_ptr[node + 1];
+            let mut degree = 0u32;
+            let mut total = 0.0;
+            for read in row_start..row_end {
+                let (target, weight) = (self.col_idx[read], self.weights[read]);
+                if f(node, target, weight) {
+                    self.col_idx[write] = target;
+                    self.weights[write] = weight;
+                    if weight > 0.0 {
+                        degree += 1;
+                        total += weight;
+                    }
+                    write += 1;
+                }
+            }
+            row_start = row_end;
+            self.row_ptr[node + 1] = write;
+            self.out_degree[node] = degree;
+            self.total_weight[node] = total;
+        }
+        self.col_idx.truncate(write);
+        self.weights.truncate(write);
+    }
+
+    /// Merge every node into `into[node]`
+    ///
+    /// Edges of a merged node move to its target, and edges that then
+    /// coincide are summed; edges inside a merged group are dropped. Merged
+    /// nodes keep their IDs and lemmas but lose all edges, and their lemma
+    /// resolves to the target in [`get_node_by_lemma`](Self::get_node_by_lemma).
+    /// Nodes with `into[node] == node` are merge targets or untouched; a
+    /// target must map to itself.
+    pub fn merge_nodes(&mut self, into: &[u32]) {
+        assert_eq!(into.len(), self.num_nodes, "merge map length != node count");
+        let mut edges = Vec::with_capacity(self.col_idx.len());
+        for source in 0..self.num_nodes {
+            let to = into[source];
+            debug_assert_eq!(into[to as usize], to, "merge target is itself merged");
+            for (target, weight) in self.neighbors(source as u32) {
+                let target_to = into[target as usize];
+                // Keep genuine self-loops, drop edges that a merge collapses.
+                if target_to == to && (source as u32 != target || to != target) {
+                    continue;
+                }
+                edges.push((to, target_to, weight));
+            }
+        }
+
+        let mut merged = Self::from_edges(core::mem::take(&mut self.lemmas), &edges);
+        for (node, &to) in into.iter().enumerate() {
+            if to as usize != node {
+                let lemma = merged.lemmas[node].clone();
+                merged.lemma_to_id.insert(lemma, to);
+            }
+        }
+        *self = merged;
+    }
 }
 
 impl Default for CsrGraph {
@@ -130,7 +373,7 @@ impl Default for CsrGraph {
             out_degree: Vec::new(),
             total_weight: Vec::new(),
             lemmas: Vec::new(),
-            lemma_to_id: FxHashMap::default(),
+            lemma_to_id: LemmaIndex::default(),
         }
     }
 }
@@ -216,6 +459,56 @@ mod tests {
         assert!(dangling.contains(&2)); // c is dangling
     }
 
+    #[test]
+    fn test_retain_nodes() {
+        let builder = build_test_graph();
+        let mut csr = CsrGraph::from_builder(&builder);
+
+        csr.retain_nodes(&[true, false, true]);
+
+        assert_eq!(csr.num_nodes, 3);
+        assert_eq!(csr.num_edges(), 2); // a-c in both directions
+        assert_eq!(csr.degree(1), 0);
+        assert!(csr.dangling_nodes().contains(&1));
+        assert!((csr.node_total_weight(0) - 1.5).abs() < 1e-10);
+        assert_eq!(csr.neighbors(2).collect::<Vec<_>>(), vec![(0, 1.5)]);
+    }
+
+    #[test]
+    fn test_retain_edges() {
+        let builder = build_test_graph();
+        let mut csr = CsrGraph::from_builder(&builder);
+
+        csr.retain_edges(|_, _, w| w >= 1.5);
+
+        assert_eq!(csr.num_edges(), 4);
+        assert_eq!(csr.degree(0), 1);
+        assert_eq!(csr.neighbors(1).collect::<Vec<_>>(), vec![(2, 2.0)]);
+        assert!((csr.node_total_weight(2) - 3.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_merge_nodes() {
+        let mut builder = build_test_graph();
+        let d = builder.get_or_create_node("d");
+        builder.increment_edge(1, d, 0.5);
+        let mut csr = CsrGraph::from_builder(&builder);
+
+        // Merge b into a: a-b disappears, b-c (2.0) joins a-c (1.5), b-d moves to a.
+        csr.merge_nodes(&[0, 0, 2, 3]);
+
+        assert_eq!(csr.num_nodes, 4);
+        assert_eq!(csr.degree(1), 0);
+        assert_eq!(
+            csr.neighbors(0).collect::<Vec<_>>(),
+            vec![(2, 3.5), (3, 0.5)]
+        );
+        assert_eq!(csr.neighbors(2).collect::<Vec<_>>(), vec![(0, 3.5)]);
+        assert_eq!(csr.lemma(1), "b");
+        assert_eq!(csr.get_node_by_lemma("b"), Some(0));
+        assert_eq!(csr.get_node_by_lemma("a"), Some(0));
+    }
+
     #[test]
     fn test_get_node_by_lemma() {
         let builder = build_test_graph();
@@ -225,4 +518,39 @@ mod tests {
         assert_eq!(csr.get_node_by_lemma("b"), Some(1));
         assert_eq!(csr.get_node_by_lemma("z"), None);
     }
+
+    #[test]
+    fn test_from_edges_matches_builder() {
+        let from_builder = CsrGraph::from_builder(&build_test_graph());
+        let lemmas = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        // Undirected edges in both directions, a-b split in two halves.
+        let edges = [
+            (2, 1, 2.0),
+            (0, 1, 0.5),
+            (1, 0, 1.0),
+            (0, 2, 1.5),
+            (2, 0, 1.5),
+            (1, 2, 2.0),
+            (0, 1, 0.5),
+            (0, 9, 1.0),
+        ];
+        let csr = CsrGraph::from_edges(lemmas, &edges);
+
+        assert_eq!(csr.row_ptr, from_builder.row_ptr);
+        assert_eq!(csr.col_idx, from_builder.col_idx);
+        assert_eq!(csr.weights, from_builder.weights);
+        assert_eq!(csr.out_degree, from_builder.out_degree);
+        assert_eq!(csr.total_weight, from_builder.total_weight);
+        assert_eq!(csr.get_node_by_lemma("c"), Some(2));
+    }
+
+    #[test]
+    fn test_from_edges_isolated_nodes() {
+        let lemmas = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let csr = CsrGraph::from_edges(lemmas, &[(0, 2, 1.0), (2, 0, 1.0)]);
+
+        assert_eq!(csr.row_ptr, vec![0, 1, 1, 2]);
+        assert_eq!(csr.dangling_nodes(), vec![1]);
+        assert!(CsrGraph::from_edges(Vec::new(), &[]).is_empty());
+    }
 }
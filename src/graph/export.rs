@@ -0,0 +1,200 @@
+//! Graph export to DOT (Graphviz) and GraphML (Gephi, yEd, NetworkX)
+//!
+//! Node labels are the graph keys: plain lemmas, or `lemma|POS` when
+//! `use_pos_in_nodes` is on, in which case the POS is also exported as a
+//! separate attribute. PageRank scores can be attached per node.
+//!
+//! Co-occurrence graphs store every edge in both directions; when all edges
+//! are symmetric the export is undirected with one edge per pair, otherwise
+//! (e.g. after an alpha-boost transform) it is directed.
+
+use super::csr::CsrGraph;
+use std::fmt::Write;
+
+impl CsrGraph {
+    /// Render the graph in Graphviz DOT format
+    ///
+    /// `scores`, if given, must be indexed by node ID (as in
+    /// [`PageRankResult::scores`](crate::pagerank::PageRankResult)).
+    pub fn to_dot(&self, scores: Option<&[f64]>) -> String {
+        let directed = !self.is_symmetric();
+        let mut out = String::new();
+        out.push_str(if directed { "digraph" } else { "graph" });
+        out.push_str(" textrank {\n");
+
+        for node in 0..self.num_nodes {
+            let (lemma, pos) = split_key(&self.lemmas[node]);
+            let _ = write!(out, "  n{} [label=\"{}\"", node, escape_dot(lemma));
+            if let Some(pos) = pos {
+                let _ = write!(out, ", pos=\"{}\"", escape_dot(pos));
+            }
+            if let Some(score) = node_score(scores, node) {
+                let _ = write!(out, ", score={}", score);
+            }
+            out.push_str("];\n");
+        }
+
+        let connector = if directed { "->" } else { "--" };
+        for (source, target, weight) in self.export_edges(directed) {
+            let _ = writeln!(
+                out,
+                "  n{} {} n{} [weight={}];",
+                source, connector, target, weight
+            );
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Render the graph as GraphML
+    ///
+    /// Nodes carry `label`, `pos` (when present) and `score` (when `scores`
+    /// is given) attributes; edges carry `weight`.
+    pub fn to_graphml(&self, scores: Option<&[f64]>) -> String {
+        let directed = !self.is_symmetric();
+        let mut out = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n\
+             \x20 <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n\
+             \x20 <key id=\"pos\" for=\"node\" attr.name=\"pos\" attr.type=\"string\"/>\n\
+             \x20 <key id=\"score\" for=\"node\" attr.name=\"score\" attr.type=\"double\"/>\n\
+             \x20 <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"double\"/>\n",
+        );
+        let _ = writeln!(
+            out,
+            "  <graph id=\"textrank\" edgedefault=\"{}\">",
+            if directed { "directed" } else { "undirected" }
+        );
+
+        for node in 0..self.num_nodes {
+            let (lemma, pos) = split_key(&self.lemmas[node]);
+            let _ = writeln!(out, "    <node id=\"n{}\">", node);
+            let _ = writeln!(
+                out,
+                "      <data key=\"label\">{}</data>",
+                escape_xml(lemma)
+            );
+            if let Some(pos) = pos {
+                let _ = writeln!(out, "      <data key=\"pos\">{}</data>", escape_xml(pos));
+            }
+            if let Some(score) = node_score(scores, node) {
+                let _ = writeln!(out, "      <data key=\"score\">{}</data>", score);
+            }
+            out.push_str("    </node>\n");
+        }
+
+        for (i, (source, target, weight)) in self.export_edges(directed).enumerate() {
+            let _ = writeln!(
+                out,
+                "    <edge id=\"e{}\" source=\"n{}\" target=\"n{}\">\n      <data key=\"weight\">{}</data>\n    </edge>",
+                i, source, target, weight
+            );
+        }
+
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+
+    /// Check whether every edge has a reverse edge of equal weight.
+    fn is_symmetric(&self) -> bool {
+        (0..self.num_nodes).all(|node| {
+            self.neighbors(node as u32).all(|(target, weight)| {
+                self.neighbors(target)
+                    .any(|(back, w)| back as usize == node && w == weight)
+            })
+        })
+    }
+
+    /// Positive-weight edges to export; one per pair when undirected.
+    fn export_edges(&self, directed: bool) -> impl Iterator<Item = (usize, u32, f64)> + '_ {
+        (0..self.num_nodes).flat_map(move |node| {
+            self.neighbors(node as u32)
+                .filter(move |&(target, weight)| {
+                    weight > 0.0 && (directed || node <= target as usize)
+                })
+                .map(move |(target, weight)| (node, target, weight))
+        })
+    }
+}
+
+/// Split a graph key into lemma and POS (`"lemma|NOUN"`).
+fn split_key(key: &str) -> (&str, Option<&str>) {
+    match key.rsplit_once('|') {
+        Some((lemma, pos)) if !lemma.is_empty() && !pos.is_empty() => (lemma, Some(pos)),
+        _ => (key, None),
+    }
+}
+
+fn node_score(scores: Option<&[f64]>, node: usize) -> Option<f64> {
+    scores.and_then(|s| s.get(node).copied())
+}
+
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::builder::GraphBuilder;
+
+    fn build_test_graph() -> CsrGraph {
+        let mut builder = GraphBuilder::new();
+        let a = builder.get_or_create_node("graph|NOUN");
+        let b = builder.get_or_create_node("rank|VERB");
+        let c = builder.get_or_create_node("a<b");
+        builder.increment_edge(a, b, 2.0);
+        builder.increment_edge(b, c, 1.0);
+        CsrGraph::from_builder(&builder)
+    }
+
+    #[test]
+    fn test_to_dot_undirected() {
+        let dot = build_test_graph().to_dot(Some(&[0.5, 0.3, 0.2]));
+
+        assert!(dot.starts_with("graph textrank {"));
+        assert!(dot.contains("n0 [label=\"graph\", pos=\"NOUN\", score=0.5];"));
+        assert!(dot.contains("n2 [label=\"a<b\", score=0.2];"));
+        assert!(dot.contains("n0 -- n1 [weight=2];"));
+        // One line per undirected pair.
+        assert_eq!(dot.matches(" -- ").count(), 2);
+    }
+
+    #[test]
+    fn test_to_dot_directed_when_asymmetric() {
+        let mut graph = build_test_graph();
+        graph.weights[0] = 5.0;
+        let dot = graph.to_dot(None);
+
+        assert!(dot.starts_with("digraph textrank {"));
+        assert_eq!(dot.matches(" -> ").count(), 4);
+        assert!(!dot.contains("score="));
+    }
+
+    #[test]
+    fn test_to_graphml() {
+        let xml = build_test_graph().to_graphml(Some(&[0.5, 0.3, 0.2]));
+
+        assert!(xml.contains("edgedefault=\"undirected\""));
+        assert!(xml.contains("<data key=\"label\">a&lt;b</data>"));
+        assert!(xml.contains("<data key=\"pos\">VERB</data>"));
+        assert!(xml.contains("<data key=\"score\">0.3</data>"));
+        assert_eq!(xml.matches("<edge ").count(), 2);
+        assert!(xml.trim_end().ends_with("</graphml>"));
+    }
+
+    #[test]
+    fn test_export_empty_graph() {
+        let graph = CsrGraph::default();
+        assert_eq!(graph.to_dot(None), "graph textrank {\n}\n");
+        assert!(graph.to_graphml(None).contains("<graph id=\"textrank\""));
+    }
+}
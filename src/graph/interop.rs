@@ -0,0 +1,150 @@
+//! petgraph interop
+//!
+//! Converts between [`CsrGraph`] and `petgraph::graph::UnGraph<String, f64>`
+//! so external graph algorithms (community detection, centralities, ...)
+//! can run on the co-occurrence graph and their output can be ranked.
+//!
+//! Node indices are preserved in both directions: petgraph node `i` is CSR
+//! node `i`, and node weights are the graph keys (lemmas).
+
+use super::csr::CsrGraph;
+use petgraph::graph::{NodeIndex, UnGraph};
+use rustc_hash::FxHashMap;
+use std::collections::BTreeMap;
+
+impl From<&CsrGraph> for UnGraph<String, f64> {
+    /// One undirected edge is emitted per node pair, carrying the weight
+    /// stored on the lower-indexed endpoint. Zero-weight edges are skipped.
+    fn from(csr: &CsrGraph) -> Self {
+        let mut graph = UnGraph::with_capacity(csr.num_nodes, csr.num_edges() / 2);
+        for lemma in &csr.lemmas {
+            graph.add_node(lemma.clone());
+        }
+        for node in 0..csr.num_nodes {
+            for (target, weight) in csr.neighbors(node as u32) {
+                if weight > 0.0 && node < target as usize {
+                    graph.add_edge(
+                        NodeIndex::new(node),
+                        NodeIndex::new(target as usize),
+                        weight,
+                    );
+                }
+            }
+        }
+        graph
+    }
+}
+
+impl From<&UnGraph<String, f64>> for CsrGraph {
+    /// Each undirected edge is stored in both directions. Parallel edges
+    /// are summed and self-loops dropped, matching
+    /// [`GraphBuilder::increment_edge`](super::builder::GraphBuilder::increment_edge).
+    fn from(graph: &UnGraph<String, f64>) -> Self {
+        let num_nodes = graph.node_count();
+        let mut adjacency: Vec<BTreeMap<u32, f64>> = vec![BTreeMap::new(); num_nodes];
+        for edge in graph.raw_edges() {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            if a == b {
+                continue;
+            }
+            *adjacency[a].entry(b as u32).or_insert(0.0) += edge.weight;
+            *adjacency[b].entry(a as u32).or_insert(0.0) += edge.weight;
+        }
+
+        let mut row_ptr = Vec::with_capacity(num_nodes + 1);
+        let mut col_idx = Vec::new();
+        let mut weights = Vec::new();
+        let mut out_degree = Vec::with_capacity(num_nodes);
+        let mut total_weight = Vec::with_capacity(num_nodes);
+        let mut lemmas = Vec::with_capacity(num_nodes);
+        let mut lemma_to_id = FxHashMap::with_capacity_and_hasher(num_nodes, Default::default());
+
+        row_ptr.push(0);
+        for (node, edges) in adjacency.into_iter().enumerate() {
+            let lemma = &graph[NodeIndex::new(node)];
+            lemma_to_id.entry(lemma.clone()).or_insert(node as u32);
+            lemmas.push(lemma.clone());
+
+            out_degree.push(edges.len() as u32);
+            total_weight.push(edges.values().sum());
+            for (target, weight) in edges {
+                col_idx.push(target);
+                weights.push(weight);
+            }
+            row_ptr.push(col_idx.len());
+        }
+
+        Self {
+            num_nodes,
+            row_ptr,
+            col_idx,
+            weights,
+            out_degree,
+            total_weight,
+            lemmas,
+            lemma_to_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::builder::GraphBuilder;
+    use crate::pagerank::standard::StandardPageRank;
+
+    fn build_test_graph() -> CsrGraph {
+        let mut builder = GraphBuilder::new();
+        let a = builder.get_or_create_node("a");
+        let b = builder.get_or_create_node("b");
+        let c = builder.get_or_create_node("c");
+        builder.increment_edge(a, b, 1.0);
+        builder.increment_edge(b, c, 2.0);
+        builder.increment_edge(a, c, 1.5);
+        CsrGraph::from_builder(&builder)
+    }
+
+    #[test]
+    fn test_csr_to_petgraph() {
+        let graph: UnGraph<String, f64> = (&build_test_graph()).into();
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph[NodeIndex::new(1)], "b");
+        let bc = graph
+            .find_edge(NodeIndex::new(1), NodeIndex::new(2))
+            .unwrap();
+        assert_eq!(graph[bc], 2.0);
+    }
+
+    #[test]
+    fn test_round_trip_preserves_ranking() {
+        let csr = build_test_graph();
+        let graph: UnGraph<String, f64> = (&csr).into();
+        let back = CsrGraph::from(&graph);
+
+        assert_eq!(back.lemmas, csr.lemmas);
+        assert_eq!(back.row_ptr, csr.row_ptr);
+        assert_eq!(back.col_idx, csr.col_idx);
+        assert_eq!(back.weights, csr.weights);
+        assert_eq!(back.get_node_by_lemma("c"), Some(2));
+
+        let pr = StandardPageRank::new();
+        assert_eq!(pr.run(&back).scores, pr.run(&csr).scores);
+    }
+
+    #[test]
+    fn test_petgraph_parallel_edges_and_self_loops() {
+        let mut graph = UnGraph::<String, f64>::new_undirected();
+        let a = graph.add_node("a".into());
+        let b = graph.add_node("b".into());
+        graph.add_edge(a, b, 1.0);
+        graph.add_edge(b, a, 0.5);
+        graph.add_edge(a, a, 3.0);
+
+        let csr = CsrGraph::from(&graph);
+        assert_eq!(csr.num_edges(), 2);
+        assert_eq!(csr.neighbors(0).collect::<Vec<_>>(), vec![(1, 1.5)]);
+        assert_eq!(csr.node_total_weight(1), 1.5);
+    }
+}
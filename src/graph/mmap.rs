@@ -0,0 +1,438 @@
+//! Memory-mapped CSR graph storage
+//!
+//! Corpus-scale co-occurrence graphs can be larger than the RAM available
+//! for ranking. [`write_graph`] stores a built [`CsrGraph`] in a flat
+//! little-endian file, and [`MappedCsrGraph::open`] maps it back without
+//! reading it into memory: the operating system pages adjacency arrays in
+//! as the ranker touches them. Rank it through the rankers' `run_view`
+//! methods with [`MappedCsrGraph::view`]:
+//!
+//! ```ignore
+//! use rapid_textrank::graph::mmap::{save_graph, MappedCsrGraph};
+//! use rapid_textrank::pagerank::standard::StandardPageRank;
+//!
+//! save_graph(&graph, "corpus.csr")?;
+//! let mapped = MappedCsrGraph::open("corpus.csr")?;
+//! let result = StandardPageRank::new().run_view(mapped.view());
+//! ```
+//!
+//! Only the score vectors (a few `f64`s per node) are allocated while
+//! ranking. The lemma lookup table is built on the first
+//! [`get_node_by_lemma`](MappedCsrGraph::get_node_by_lemma) call.
+//!
+//! # File format
+//!
+//! All integers are little-endian. After a 32-byte header — the magic
+//! `RTKCSR01`, then the node count `n`, edge count `m` and lemma byte
+//! count as `u64` — come, in order:
+//!
+//! | Section | Type | Length |
+//! |---------|------|--------|
+//! | `row_ptr` | `u64` | `n + 1` |
+//! | `weights` | `f64` | `m` |
+//! | `total_weight` | `f64` | `n` |
+//! | lemma offsets | `u64` | `n + 1` |
+//! | `col_idx` | `u32` | `m` |
+//! | `out_degree` | `u32` | `n` |
+//! | lemma node IDs | `u32` | `n` |
+//! | lemma text | UTF-8 | lemma byte count |
+//!
+//! Lemma `i` is `text[offsets[i]..offsets[i + 1]]`, and resolves to node
+//! `lemma_node[i]` (itself, unless merged by
+//! [`CsrGraph::merge_nodes`]). Every section is aligned for its element
+//! type, so the mapped arrays are used in place. Mapping requires a 64-bit
+//! little-endian target.
+//!
+//! Only available with the `mmap` feature.
+
+use super::csr::{CsrGraph, CsrView, LemmaIndex};
+use crate::errors::{Result, TextRankError};
+use memmap2::Mmap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// File magic and format version
+pub const MAGIC: [u8; 8] = *b"RTKCSR01";
+
+const HEADER_BYTES: usize = 32;
+
+/// Write `graph` in the mapped format to `writer`.
+pub fn write_graph(graph: &CsrGraph, writer: impl Write) -> Result<()> {
+    let mut w = BufWriter::new(writer);
+    let lemma_bytes: usize = graph.lemmas.iter().map(String::len).sum();
+
+    w.write_all(&MAGIC)?;
+    for len in [graph.num_nodes, graph.num_edges(), lemma_bytes] {
+        w.write_all(&(len as u64).to_le_bytes())?;
+    }
+    for &ptr in &graph.row_ptr {
+        w.write_all(&(ptr as u64).to_le_bytes())?;
+    }
+    for &weight in graph.weights.iter().chain(&graph.total_weight) {
+        w.write_all(&weight.to_le_bytes())?;
+    }
+    let mut offset = 0u64;
+    w.write_all(&offset.to_le_bytes())?;
+    for lemma in &graph.lemmas {
+        offset += lemma.len() as u64;
+        w.write_all(&offset.to_le_bytes())?;
+    }
+    for &v in graph.col_idx.iter().chain(&graph.out_degree) {
+        w.write_all(&v.to_le_bytes())?;
+    }
+    for (node, lemma) in graph.lemmas.iter().enumerate() {
+        let id = graph.get_node_by_lemma(lemma).unwrap_or(node as u32);
+        w.write_all(&id.to_le_bytes())?;
+    }
+    for lemma in &graph.lemmas {
+        w.write_all(lemma.as_bytes())?;
+    }
+    w.flush()?;
+    Ok(())
+}
+
+/// Write `graph` in the mapped format to the file at `path`.
+pub fn save_graph(graph: &CsrGraph, path: impl AsRef<Path>) -> Result<()> {
+    write_graph(graph, File::create(path)?)
+}
+
+/// Byte ranges of the sections of a mapped graph file
+#[derive(Debug, Clone, Copy)]
+struct Layout {
+    num_nodes: usize,
+    num_edges: usize,
+    row_ptr: usize,
+    weights: usize,
+    total_weight: usize,
+    lemma_offsets: usize,
+    col_idx: usize,
+    out_degree: usize,
+    lemma_node: usize,
+    lemma_text: usize,
+    end: usize,
+}
+
+impl Layout {
+    fn new(num_nodes: usize, num_edges: usize, lemma_bytes: usize) -> Option<Self> {
+        let (n, m) = (num_nodes, num_edges);
+        let mut at = HEADER_BYTES;
+        let mut section = |bytes: Option<usize>| -> Option<usize> {
+            let start = at;
+            at = at.checked_add(bytes?)?;
+            Some(start)
+        };
+        let row_ptr = section(n.checked_add(1)?.checked_mul(8))?;
+        let weights = section(m.checked_mul(8))?;
+        let total_weight = section(n.checked_mul(8))?;
+        let lemma_offsets = section(n.checked_add(1)?.checked_mul(8))?;
+        let col_idx = section(m.checked_mul(4))?;
+        let out_degree = section(n.checked_mul(4))?;
+        let lemma_node = section(n.checked_mul(4))?;
+        let lemma_text = section(Some(lemma_bytes))?;
+        Some(Self {
+            num_nodes,
+            num_edges,
+            row_ptr,
+            weights,
+            total_weight,
+            lemma_offsets,
+            col_idx,
+            out_degree,
+            lemma_node,
+            lemma_text,
+            end: at,
+        })
+    }
+}
+
+/// A [`CsrGraph`] file mapped into memory
+pub struct MappedCsrGraph {
+    mmap: Mmap,
+    layout: Layout,
+    lemma_index: OnceLock<LemmaIndex>,
+}
+
+impl MappedCsrGraph {
+    /// Map the graph file at `path`.
+    ///
+    /// The file is validated once (header, section sizes, row pointers,
+    /// edge targets and lemma text), so ranking it cannot index out of
+    /// bounds. It must not be modified while mapped.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        if cfg!(target_endian = "big") || usize::BITS != 64 {
+            return Err(TextRankError::internal(
+                "mapped graphs require a 64-bit little-endian target",
+            ));
+        }
+        let file = File::open(path)?;
+        // SAFETY: the map is read-only, and callers must not modify the file
+        // while it is mapped (documented above); every byte pattern of the
+        // section types is a valid value.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        let header = |i: usize| -> Result<usize> {
+            let bytes = mmap
+                .get(i * 8..i * 8 + 8)
+                .ok_or_else(|| invalid("truncated header"))?;
+            usize::try_from(u64::from_le_bytes(bytes.try_into().unwrap()))
+                .map_err(|_| invalid("section too large"))
+        };
+        if mmap.get(..8) != Some(&MAGIC[..]) {
+            return Err(invalid("not a mapped graph file (bad magic)"));
+        }
+        let layout = Layout::new(header(1)?, header(2)?, header(3)?)
+            .ok_or_else(|| invalid("section too large"))?;
+        if mmap.len() != layout.end {
+            return Err(invalid(format!(
+                "expected {} bytes, found {}",
+                layout.end,
+                mmap.len()
+            )));
+        }
+
+        let graph = Self {
+            mmap,
+            layout,
+            lemma_index: OnceLock::new(),
+        };
+        graph.validate()?;
+        Ok(graph)
+    }
+
+    fn validate(&self) -> Result<()> {
+        let (n, m) = (self.layout.num_nodes, self.layout.num_edges);
+        let view = self.view();
+        if view.row_ptr[0] != 0
+            || view.row_ptr[n] != m
+            || view.row_ptr.windows(2).any(|w| w[0] > w[1])
+        {
+            return Err(invalid("row pointers are not a valid CSR index"));
+        }
+        if view.col_idx.iter().any(|&t| t as usize >= n) {
+            return Err(invalid("edge target out of range"));
+        }
+        if self.lemma_nodes().iter().any(|&id| id as usize >= n) {
+            return Err(invalid("lemma node ID out of range"));
+        }
+
+        let text = std::str::from_utf8(self.section(self.layout.lemma_text, self.layout.end))
+            .map_err(|_| invalid("lemma text is not UTF-8"))?;
+        let offsets = self.lemma_offsets();
+        if offsets[0] != 0
+            || offsets[n] != text.len()
+            || offsets.windows(2).any(|w| w[0] > w[1])
+            || offsets.iter().any(|&o| !text.is_char_boundary(o))
+        {
+            return Err(invalid("lemma offsets are not valid"));
+        }
+        Ok(())
+    }
+
+    /// Borrow the mapped adjacency arrays, for the rankers' `run_view`.
+    pub fn view(&self) -> CsrView<'_> {
+        let l = &self.layout;
+        CsrView {
+            num_nodes: l.num_nodes,
+            row_ptr: self.cast(l.row_ptr, l.weights),
+            col_idx: self.cast(l.col_idx, l.out_degree),
+            weights: self.cast(l.weights, l.total_weight),
+            out_degree: self.cast(l.out_degree, l.lemma_node),
+            total_weight: self.cast(l.total_weight, l.lemma_offsets),
+        }
+    }
+
+    /// Number of nodes
+    pub fn num_nodes(&self) -> usize {
+        self.layout.num_nodes
+    }
+
+    /// Number of edges (counting each undirected edge twice)
+    pub fn num_edges(&self) -> usize {
+        self.layout.num_edges
+    }
+
+    /// Get the lemma for a node
+    pub fn lemma(&self, node: u32) -> &str {
+        let offsets = self.lemma_offsets();
+        let (start, end) = (offsets[node as usize], offsets[node as usize + 1]);
+        let text = self.section(self.layout.lemma_text, self.layout.end);
+        // Validated in `open`.
+        std::str::from_utf8(&text[start..end]).unwrap_or_default()
+    }
+
+    /// Get node ID by lemma; builds the lookup table on first use
+    pub fn get_node_by_lemma(&self, lemma: &str) -> Option<u32> {
+        self.lemma_index
+            .get_or_init(|| {
+                let nodes = self.lemma_nodes();
+                (0..self.num_nodes())
+                    .map(|i| (self.lemma(i as u32).to_string(), nodes[i]))
+                    .collect()
+            })
+            .get(lemma)
+            .copied()
+    }
+
+    /// Copy the mapped graph into an owned [`CsrGraph`].
+    pub fn to_csr_graph(&self) -> CsrGraph {
+        let view = self.view();
+        let lemmas: Vec<String> = (0..self.num_nodes())
+            .map(|i| self.lemma(i as u32).to_string())
+            .collect();
+        let nodes = self.lemma_nodes();
+        let lemma_to_id = lemmas.iter().cloned().zip(nodes.iter().copied()).collect();
+        CsrGraph {
+            num_nodes: view.num_nodes,
+            row_ptr: view.row_ptr.to_vec(),
+            col_idx: view.col_idx.to_vec(),
+            weights: view.weights.to_vec(),
+            out_degree: view.out_degree.to_vec(),
+            total_weight: view.total_weight.to_vec(),
+            lemmas,
+            lemma_to_id,
+        }
+    }
+
+    fn lemma_offsets(&self) -> &[usize] {
+        self.cast(self.layout.lemma_offsets, self.layout.col_idx)
+    }
+
+    fn lemma_nodes(&self) -> &[u32] {
+        self.cast(self.layout.lemma_node, self.layout.lemma_text)
+    }
+
+    fn section(&self, start: usize, end: usize) -> &[u8] {
+        &self.mmap[start..end]
+    }
+
+    /// Reinterpret a section in place. Sections are aligned for their type
+    /// because the map is page-aligned and every section starts at a
+    /// multiple of 8 (4 for the trailing `u32` sections).
+    fn cast<T: bytemuck::Pod>(&self, start: usize, end: usize) -> &[T] {
+        bytemuck::cast_slice(self.section(start, end))
+    }
+}
+
+impl std::fmt::Debug for MappedCsrGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MappedCsrGraph")
+            .field("num_nodes", &self.layout.num_nodes)
+            .field("num_edges", &self.layout.num_edges)
+            .field("bytes", &self.mmap.len())
+            .finish()
+    }
+}
+
+fn invalid(message: impl Into<String>) -> TextRankError {
+    TextRankError::serialization(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::builder::GraphBuilder;
+    use crate::pagerank::personalized::PersonalizedPageRank;
+    use crate::pagerank::standard::StandardPageRank;
+    use std::path::PathBuf;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "rapid_textrank_mmap_{name}_{}.csr",
+            std::process::id()
+        ))
+    }
+
+    fn build_graph() -> CsrGraph {
+        let mut builder = GraphBuilder::new();
+        let ids: Vec<u32> = ["graph", "rank", "café", "node", "edge"]
+            .iter()
+            .map(|l| builder.get_or_create_node(l))
+            .collect();
+        builder.increment_edge(ids[0], ids[1], 2.0);
+        builder.increment_edge(ids[1], ids[2], 1.0);
+        builder.increment_edge(ids[2], ids[0], 0.5);
+        builder.increment_directed_edge(ids[3], ids[0], 1.5);
+        // ids[4] is dangling.
+        CsrGraph::from_builder(&builder)
+    }
+
+    #[test]
+    fn test_round_trip_ranks_identically() {
+        let graph = build_graph();
+        let path = temp_path("round_trip");
+        save_graph(&graph, &path).unwrap();
+        let mapped = MappedCsrGraph::open(&path).unwrap();
+
+        assert_eq!(mapped.num_nodes(), graph.num_nodes);
+        assert_eq!(mapped.num_edges(), graph.num_edges());
+        assert_eq!(mapped.lemma(2), "café");
+        assert_eq!(mapped.get_node_by_lemma("rank"), Some(1));
+        assert_eq!(mapped.get_node_by_lemma("missing"), None);
+
+        let owned = StandardPageRank::new().run(&graph);
+        let borrowed = StandardPageRank::new().run_view(mapped.view());
+        assert_eq!(owned.scores, borrowed.scores);
+        assert_eq!(owned.iterations, borrowed.iterations);
+
+        let bias = vec![0.0, 1.0, 0.0, 2.0, 0.0];
+        let ppr = PersonalizedPageRank::new().with_personalization(bias);
+        assert_eq!(ppr.run(&graph).scores, ppr.run_view(mapped.view()).scores);
+
+        let copy = mapped.to_csr_graph();
+        assert_eq!(copy.row_ptr, graph.row_ptr);
+        assert_eq!(copy.lemmas, graph.lemmas);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_merged_lemmas_keep_their_target() {
+        let mut graph = build_graph();
+        graph.merge_nodes(&[0, 1, 2, 0, 4]);
+        let path = temp_path("merged");
+        save_graph(&graph, &path).unwrap();
+        let mapped = MappedCsrGraph::open(&path).unwrap();
+
+        assert_eq!(mapped.get_node_by_lemma("node"), Some(0));
+        assert_eq!(mapped.lemma(3), "node");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_files_are_rejected() {
+        let mut bytes = Vec::new();
+        write_graph(&build_graph(), &mut bytes).unwrap();
+        let path = temp_path("invalid");
+        let open = |bytes: &[u8]| {
+            std::fs::write(&path, bytes).unwrap();
+            MappedCsrGraph::open(&path)
+        };
+
+        assert!(open(&bytes).is_ok());
+        assert!(open(&bytes[..bytes.len() - 1]).is_err());
+        assert!(open(b"RTKCSR00").is_err());
+
+        // Point the first edge past the last node.
+        let graph = build_graph();
+        let lemma_bytes = graph.lemmas.iter().map(String::len).sum();
+        let layout = Layout::new(graph.num_nodes, graph.num_edges(), lemma_bytes).unwrap();
+        let mut corrupt = bytes.clone();
+        corrupt[layout.col_idx..layout.col_idx + 4].copy_from_slice(&99u32.to_le_bytes());
+        assert!(open(&corrupt).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let path = temp_path("empty");
+        save_graph(&CsrGraph::default(), &path).unwrap();
+        let mapped = MappedCsrGraph::open(&path).unwrap();
+        assert_eq!(mapped.num_nodes(), 0);
+        assert!(StandardPageRank::new()
+            .run_view(mapped.view())
+            .scores
+            .is_empty());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
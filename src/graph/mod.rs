@@ -1,7 +1,15 @@
 //! Graph construction and representation
 //!
 //! This module provides efficient graph building and storage
-//! for the TextRank co-occurrence graph.
+//! for the TextRank co-occurrence graph. [`csr`] is available without the
+//! `std` feature.
 
+#[cfg(feature = "std")]
 pub mod builder;
 pub mod csr;
+#[cfg(feature = "graph-export")]
+pub mod export;
+#[cfg(feature = "petgraph")]
+pub mod interop;
+#[cfg(feature = "mmap")]
+pub mod mmap;
@@ -0,0 +1,261 @@
+//! CoNLL-U reader
+//!
+//! Parses [CoNLL-U](https://universaldependencies.org/format.html) output
+//! (UDPipe, Stanza, spaCy-conll, ...) into [`Token`]s that any variant can
+//! consume directly:
+//!
+//! - `FORM` → `Token::text`, `LEMMA` → `Token::lemma` (lowercased form when
+//!   the lemma is `_`), `UPOS` → [`PosTag`].
+//! - Blank lines delimit sentences; `token_idx` runs across the document.
+//! - Multiword token ranges (`1-2`) are not emitted, but their component
+//!   words inherit the range's character span. Empty nodes (`5.1`) are
+//!   skipped.
+//!
+//! `Token::start`/`end` are byte offsets, as everywhere else in the crate,
+//! into the text reconstructed by laying the forms out with a single space
+//! between tokens, honoring `SpaceAfter=No`. When the MISC column carries
+//! character offsets, either as `TokenRange=start:end` (UDPipe) or
+//! `start_char=..|end_char=..` (Stanza), they place the token instead: the
+//! characters between tokens are taken to be single-byte whitespace, so the
+//! offsets match the original text whenever it separates tokens with ASCII
+//! whitespace.
+
+use crate::errors::{Result, TextRankError};
+use crate::types::{PosTag, Token};
+use std::path::Path;
+
+/// Parse a CoNLL-U document into tokens
+///
+/// Stopwords are not marked; use
+/// [`StopwordFilter`](crate::nlp::stopwords::StopwordFilter) afterwards if
+/// needed.
+pub fn parse_conllu(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut sentence_idx = 0;
+    let mut sentence_has_tokens = false;
+    // Position in the reconstructed text.
+    let mut cursor = Cursor::default();
+    // Active multiword token: (last word id, start, end).
+    let mut multiword: Option<(usize, usize, usize)> = None;
+
+    for (line_no, line) in input.lines().enumerate() {
+        let line_no = line_no + 1;
+        let line = line.trim_end_matches('\r');
+
+        if line.trim().is_empty() {
+            if sentence_has_tokens {
+                sentence_idx += 1;
+                sentence_has_tokens = false;
+            }
+            multiword = None;
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() != 10 {
+            return Err(TextRankError::parse(
+                line_no,
+                format!("expected 10 tab-separated fields, found {}", fields.len()),
+            ));
+        }
+        let (id, form, lemma, upos, misc) = (fields[0], fields[1], fields[2], fields[3], fields[9]);
+
+        if id.contains('.') {
+            // Empty node (enhanced dependencies only).
+            continue;
+        }
+
+        let span = misc_offsets(misc);
+        let space_after = !misc.split('|').any(|m| m == "SpaceAfter=No");
+
+        if let Some((first, last)) = id.split_once('-') {
+            let last: usize = parse_id(last, line_no)?;
+            parse_id(first, line_no)?;
+            let (start, end) = cursor.place(form, span, space_after);
+            multiword = Some((last, start, end));
+            continue;
+        }
+
+        let word_id = parse_id(id, line_no)?;
+        let (start, end) = match multiword {
+            Some((last, start, end)) if word_id <= last => (start, end),
+            _ => {
+                multiword = None;
+                cursor.place(form, span, space_after)
+            }
+        };
+
+        let lemma = if lemma == "_" && form != "_" {
+            form.to_lowercase()
+        } else {
+            lemma.to_string()
+        };
+
+        let token_idx = tokens.len();
+        tokens.push(Token::new(
+            form,
+            lemma,
+            PosTag::from_spacy(upos),
+            start,
+            end,
+            sentence_idx,
+            token_idx,
+        ));
+        sentence_has_tokens = true;
+    }
+
+    Ok(tokens)
+}
+
+/// Read and parse a CoNLL-U file
+pub fn read_conllu(path: impl AsRef<Path>) -> Result<Vec<Token>> {
+    let input = std::fs::read_to_string(path)?;
+    parse_conllu(&input)
+}
+
+/// Character and byte position in the reconstructed text.
+#[derive(Debug, Default)]
+struct Cursor {
+    chars: usize,
+    bytes: usize,
+}
+
+impl Cursor {
+    /// Byte span of `form`, placed at its MISC character span when given or
+    /// at the cursor otherwise, and advance past it.
+    fn place(
+        &mut self,
+        form: &str,
+        span: Option<(usize, usize)>,
+        space_after: bool,
+    ) -> (usize, usize) {
+        let (start, char_end) = match span {
+            Some((start, end)) => (self.bytes + start.saturating_sub(self.chars), end),
+            None => (self.bytes, self.chars + form.chars().count()),
+        };
+        let end = start + form.len();
+        self.chars = char_end + usize::from(space_after);
+        self.bytes = end + usize::from(space_after);
+        (start, end)
+    }
+}
+
+fn parse_id(id: &str, line_no: usize) -> Result<usize> {
+    id.parse()
+        .map_err(|_| TextRankError::parse(line_no, format!("invalid token ID '{}'", id)))
+}
+
+/// Extract character offsets from the MISC column, if present.
+fn misc_offsets(misc: &str) -> Option<(usize, usize)> {
+    let mut start = None;
+    let mut end = None;
+    for item in misc.split('|') {
+        if let Some(range) = item.strip_prefix("TokenRange=") {
+            let (s, e) = range.split_once(':')?;
+            return Some((s.parse().ok()?, e.parse().ok()?));
+        } else if let Some(s) = item.strip_prefix("start_char=") {
+            start = s.parse().ok();
+        } else if let Some(e) = item.strip_prefix("end_char=") {
+            end = e.parse().ok();
+        }
+    }
+    Some((start?, end?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+# sent_id = 1
+# text = Neural networks learn.
+1\tNeural\tneural\tADJ\tJJ\t_\t2\tamod\t_\t_
+2\tnetworks\tnetwork\tNOUN\tNNS\t_\t3\tnsubj\t_\t_
+3\tlearn\tlearn\tVERB\tVBP\t_\t0\troot\t_\tSpaceAfter=No
+4\t.\t.\tPUNCT\t.\t_\t3\tpunct\t_\t_
+
+# sent_id = 2
+1\tGraphs\t_\tPROPN\tNNP\t_\t0\troot\t_\t_
+";
+
+    #[test]
+    fn test_parse_fields_and_sentences() {
+        let tokens = parse_conllu(SAMPLE).unwrap();
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[1].text, "networks");
+        assert_eq!(tokens[1].lemma, "network");
+        assert_eq!(tokens[0].pos, PosTag::Adjective);
+        assert_eq!(tokens[3].pos, PosTag::Punctuation);
+        assert_eq!(tokens[4].sentence_idx, 1);
+        assert_eq!(tokens[4].token_idx, 4);
+        // Missing lemma falls back to the lowercased form.
+        assert_eq!(tokens[4].lemma, "graphs");
+        assert_eq!(tokens[4].pos, PosTag::ProperNoun);
+    }
+
+    #[test]
+    fn test_reconstructed_offsets_honor_space_after() {
+        let tokens = parse_conllu(SAMPLE).unwrap();
+        let text = "Neural networks learn. Graphs";
+        for token in &tokens {
+            assert_eq!(&text[token.start..token.end], token.text);
+        }
+    }
+
+    #[test]
+    fn test_misc_offsets_and_multiword_tokens() {
+        let input = "\
+1\tVa\taller\tVERB\t_\t_\t0\troot\t_\tstart_char=0|end_char=2
+2-3\tau\t_\t_\t_\t_\t_\t_\t_\tTokenRange=3:5
+2\tà\tà\tADP\t_\t_\t4\tcase\t_\t_
+3\tle\tle\tDET\t_\t_\t4\tdet\t_\t_
+3.1\tx\tx\tX\t_\t_\t_\t_\t_\t_
+4\tmarché\tmarché\tNOUN\t_\t_\t1\tobl\t_\tTokenRange=6:12
+";
+        let tokens = parse_conllu(input).unwrap();
+        assert_eq!(tokens.len(), 4);
+        assert_eq!((tokens[0].start, tokens[0].end), (0, 2));
+        assert_eq!((tokens[1].start, tokens[1].end), (3, 5));
+        assert_eq!((tokens[2].start, tokens[2].end), (3, 5));
+        // Character offsets 6:12 are bytes 6:13 ("é" is two bytes).
+        assert_eq!((tokens[3].start, tokens[3].end), (6, 13));
+        let text = "Va au marché";
+        assert_eq!(&text[tokens[3].start..tokens[3].end], "marché");
+    }
+
+    #[test]
+    fn test_mixed_offsets_are_bytes() {
+        // "Déjà vu" with MISC offsets on the first token only.
+        let input = "\
+1\tDéjà\tdéjà\tADV\t_\t_\t2\tadvmod\t_\tTokenRange=0:4
+2\tvu\tvoir\tVERB\t_\t_\t0\troot\t_\t_
+";
+        let tokens = parse_conllu(input).unwrap();
+        let text = "Déjà vu";
+        for token in &tokens {
+            assert_eq!(&text[token.start..token.end], token.text);
+        }
+    }
+
+    #[test]
+    fn test_malformed_line_reports_line_number() {
+        let input = "# comment\n1\tonly\ttwo\n";
+        match parse_conllu(input) {
+            Err(TextRankError::Parse { line, .. }) => assert_eq!(line, 2),
+            other => panic!("expected parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_tokens_feed_extraction() {
+        let tokens = parse_conllu(SAMPLE).unwrap();
+        let phrases = crate::phrase::extraction::extract_keyphrases(
+            &tokens,
+            &crate::types::TextRankConfig::default(),
+        );
+        assert!(phrases.iter().any(|p| p.lemma.contains("network")));
+    }
+}
@@ -0,0 +1,6 @@
+//! Input readers
+//!
+//! This module converts the output of external NLP tools into the crate's
+//! [`Token`](crate::types::Token) type.
+
+pub mod conllu;
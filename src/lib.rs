@@ -11,68 +11,157 @@
 //! - **Unicode-aware**: Proper handling of CJK, emoji, and other scripts
 //! - **Flexible**: Multiple algorithm variants and configuration options
 //! - **Python bindings**: Seamless integration with Python via PyO3
+//! - **no_std core**: Without the default `std` feature the crate builds as
+//!   `#![no_std]` + `alloc` and provides only [`graph::csr`] and the standard
+//!   and personalized PageRank kernels in [`pagerank`] (enable `libm` for
+//!   float math)
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("rapid_textrank needs either the `std` or the `libm` feature");
+
+#[cfg(feature = "std")]
 pub mod clustering;
+#[cfg(feature = "std")]
 pub mod errors;
+#[cfg(feature = "std")]
+pub mod eval;
 pub mod graph;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "std")]
 pub mod nlp;
 pub mod pagerank;
+#[cfg(feature = "std")]
 pub mod phrase;
+#[cfg(feature = "std")]
 pub mod pipeline;
+#[cfg(feature = "std")]
+pub mod similarity;
+#[cfg(feature = "std")]
 pub mod summarizer;
+#[cfg(feature = "std")]
 pub mod types;
+#[cfg(feature = "std")]
 pub mod variants;
 
 #[cfg(feature = "python")]
 pub mod python;
 
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "capi")]
+pub mod capi;
+
 // Re-export commonly used types
+#[cfg(feature = "std")]
 pub use errors::{Result, TextRankError};
+#[cfg(feature = "std")]
 pub use types::{
-    ChunkSpan, DeterminismMode, LemmaId, Phrase, ScoreAggregation, Sentence, StringPool,
-    TextRankConfig, Token,
+    CaseFolding, ChunkSpan, DeterminismMode, LemmaId, LemmaStrategy, MergeScore,
+    NearDuplicateMerge, NodeKey, NonConvergencePolicy, OverlapPolicy, Phrase, PhrasePattern,
+    PhraseSelection, RankPrecision, RankerAcceleration, ScoreAggregation, ScoreNormalization,
+    Section, Sentence, SharedStringPool, SpanMode, StemLanguage, StringDistance, StringPool,
+    SurfaceSelection, TextNormalization, TextRankConfig, Token, TokenFilter, TokenRef, UnicodeForm,
+    VariantLinking, CONFIG_PRESETS,
 };
 
 // Re-export main functionality
-pub use graph::{builder::GraphBuilder, csr::CsrGraph};
-pub use nlp::{stopwords::StopwordFilter, tokenizer::Tokenizer};
+#[cfg(feature = "std")]
+pub use graph::builder::GraphBuilder;
+pub use graph::csr::CsrGraph;
+#[cfg(feature = "std")]
+pub use io::conllu::{parse_conllu, read_conllu};
+#[cfg(feature = "cjk")]
+pub use nlp::cjk::Segmenter;
+#[cfg(feature = "pos-tagger")]
+pub use nlp::pos_tagger::PerceptronTagger;
+#[cfg(feature = "std")]
+pub use nlp::{
+    corpus::CorpusStats, lemma::Lemmatizer, stopwords::StopwordFilter, tokenizer::Tokenizer,
+};
 pub use pagerank::{
-    personalized::PersonalizedPageRank, standard::StandardPageRank, PageRankResult,
+    personalized::PersonalizedPageRank, standard::StandardPageRank, ConvergenceCriterion,
+    DanglingPolicy, PageRankResult,
+};
+#[cfg(feature = "arrow")]
+pub use phrase::arrow::extract_batch_arrow;
+#[cfg(feature = "std")]
+pub use phrase::batch::{
+    extract_batch, extract_batch_with_reports, extract_batch_with_stats, BatchDocument,
+};
+#[cfg(feature = "std")]
+pub use phrase::corpus::{
+    aggregate_phrase_sets, CorpusAggregator, CorpusPhrase, CorpusScore, DocumentHit,
 };
-pub use phrase::extraction::PhraseExtractor;
+#[cfg(feature = "std")]
+pub use phrase::extraction::{extract_from_text, extract_from_text_with_info, PhraseExtractor};
+#[cfg(feature = "std")]
+pub use phrase::grammar::PosGrammar;
+#[cfg(feature = "std")]
+pub use phrase::keywords::{extract_keywords, extract_keywords_from_text, Keyword};
+#[cfg(feature = "std")]
+pub use phrase::streaming::StreamingExtractor;
+#[cfg(feature = "std")]
 pub use pipeline::error_code::ErrorCode;
-pub use pipeline::errors::{PipelineRuntimeError, PipelineSpecError};
+#[cfg(feature = "std")]
+pub use pipeline::errors::{PipelineError, PipelineRuntimeError, PipelineSpecError};
+#[cfg(feature = "sentence-rank")]
+pub use pipeline::spec::SentenceSimilaritySpec;
+#[cfg(feature = "std")]
 pub use pipeline::spec::{
     merge_modules, resolve_preset, resolve_spec, CandidatesSpec, ClusteringSpec, EdgeWeightingSpec,
     ExposeSpec, FormatSpec, GraphSpec, GraphTransformSpec, ModuleSet, NodeScoresSpec,
     PageRankExposeSpec, PhraseGroupingSpec, PhraseSpec, PipelineSpec, PipelineSpecV1,
-    PreprocessSpec, RankSpec, RuntimeSpec, ScoreAggregationSpec, TeleportSpec,
+    PreprocessSpec, RankSpec, RuntimeSpec, ScoreAggregationSpec, TeleportSpec, WindowScopeSpec,
 };
+#[cfg(feature = "std")]
 pub use pipeline::validation::{ValidationEngine, ValidationReport};
+#[cfg(feature = "embeddings")]
+pub use pipeline::EmbeddingDedupPhraseBuilder;
+#[cfg(feature = "pos-tagger")]
+pub use pipeline::PosTagPreprocessor;
+#[cfg(feature = "std")]
 pub use pipeline::{
-    AlphaBoostWeighter, CandidateGraphBuilder, CandidateSelector, ChunkPhraseBuilder,
-    ClusterAssignments, Clusterer, CooccurrenceGraphBuilder, DebugLevel, DynPipeline,
-    EdgeWeightPolicy, FocusTermsTeleportBuilder, IntraTopicEdgeRemover, JaccardHacClusterer,
-    Linkage, MultipartitePhraseBuilder, MultipartiteRankPipeline, MultipartiteTransform,
-    NoopClusterer, NoopGraphTransform, NoopPreprocessor, PhraseBuilder, PhraseCandidateSelector,
-    PositionTeleportBuilder, Preprocessor, ResultFormatter, SpecPipelineBuilder,
-    StandardResultFormatter, TeleportBuilder, TeleportType, TeleportVector, TokenEntry,
-    TokenStream, TokenStreamRef, TopicGraphBuilder, TopicRankPipeline, TopicRepresentativeBuilder,
-    TopicWeightsTeleportBuilder, TopicalPageRankPipeline, UniformTeleportBuilder,
-    WindowGraphBuilder, WindowStrategy, WordNodeSelector, DEFAULT_WINDOW_SIZE,
+    AlphaBoostWeighter, CancellationToken, CandidateGraphBuilder, CandidateSelector,
+    ChunkPhraseBuilder, ClusterAssignments, Clusterer, CooccurrenceGraphBuilder, DebugLevel,
+    DegreeThresholdTransform, DynPipeline, EdgeThreshold, EdgeWeightCutoff, EdgeWeightPolicy,
+    ExpandGraphBuilder, ExpandRankPipeline, FocusTermsTeleportBuilder, GrammarCandidateSelector,
+    IntraTopicEdgeRemover, JaccardHacClusterer, KCoreTransform, LemmaPreprocessor, Linkage,
+    MetaVertexTransform, MultipartitePhraseBuilder, MultipartiteRankPipeline,
+    MultipartiteTransform, NoopClusterer, NoopGraphTransform, NoopPreprocessor, NounChunkSelector,
+    PhraseBuilder, PhraseCandidateSelector, PipelineCache, PipelinePlan, PlannedStage,
+    PosFallbackPreprocessor, PositionDecayTeleportBuilder, PositionTeleportBuilder, Preprocessor,
+    QueryBiasedTextRankPipeline, QuerySimilarity, QueryTeleportBuilder, ResultFormatter,
+    SectionTeleportBuilder, SimilarityHacClusterer, SpecPipelineBuilder, StandardResultFormatter,
+    TeleportBuilder, TeleportType, TeleportVector, TfIdfTeleportBuilder, TokenEntry,
+    TokenOverlapSimilarity, TokenStream, TokenStreamRef, TopicGraphBuilder, TopicRankPipeline,
+    TopicRepresentativeBuilder, TopicWeightsTeleportBuilder, TopicalPageRankPipeline,
+    UniformTeleportBuilder, WindowGraphBuilder, WindowStrategy, WordNodeSelector,
+    DEFAULT_WINDOW_SIZE,
 };
 #[cfg(feature = "sentence-rank")]
 pub use pipeline::{
     SentenceCandidateSelector, SentenceFormatter, SentenceGraphBuilder, SentencePhraseBuilder,
-    SentenceRankPipeline,
+    SentenceRankPipeline, SentenceSimilarity,
 };
 // Note: pipeline::GraphBuilder trait is NOT re-exported here to avoid
 // collision with graph::builder::GraphBuilder (the mutable builder struct).
 // Access the trait via `pipeline::GraphBuilder` or
 // `pipeline::traits::GraphBuilder`.
+#[cfg(feature = "std")]
+pub use similarity::{Embedder, EmbeddingSimilarity, LexicalSimilarity, Similarity, Unit};
+#[cfg(feature = "std")]
 pub use summarizer::selector::SentenceSelector;
+#[cfg(feature = "sentence-rank")]
+pub use summarizer::textrank::{RankedSentence, TextRankSummarizer};
+#[cfg(feature = "std")]
 pub use variants::{
-    biased_textrank::BiasedTextRank, multipartite_rank::MultipartiteRank,
+    biased_textrank::BiasedTextRank, expand_rank::ExpandRank, multipartite_rank::MultipartiteRank,
     position_rank::PositionRank, single_rank::SingleRank, topical_pagerank::TopicalPageRank,
 };
 
@@ -0,0 +1,156 @@
+//! Support for unsegmented scripts (Chinese, Japanese)
+//!
+//! Two pieces make CJK input usable:
+//!
+//! - **Untagged tokens.** Pre-segmented tokens usually arrive without POS
+//!   tags (`PosTag::Other`), which the POS-based candidate filters and the
+//!   noun-phrase chunker reject. [`apply_pos_fallback`] (or the
+//!   [`PosFallbackPreprocessor`](crate::pipeline::traits::PosFallbackPreprocessor)
+//!   stage) turns them into candidates with a stopword + length filter
+//!   instead.
+//! - **Segmentation.** UAX #29 splits Han text into single characters. With
+//!   the `cjk` feature, a `Segmenter` (e.g., a jieba or lindera wrapper)
+//!   can be plugged into [`Tokenizer`](crate::nlp::tokenizer::Tokenizer) so
+//!   [`extract_from_text`](crate::phrase::extraction::extract_from_text)
+//!   works on CJK text.
+
+use crate::types::{PosTag, Token, TokenRef};
+
+/// Default minimum length (in characters) for untagged candidates.
+///
+/// Single-character Chinese/Japanese words are overwhelmingly function
+/// words, so they are excluded by default.
+pub const DEFAULT_MIN_CANDIDATE_CHARS: usize = 2;
+
+/// Check whether a character is CJK: Han, kana, Hangul, or CJK punctuation
+///
+/// [`Tokenizer::is_cjk`](crate::nlp::tokenizer::Tokenizer::is_cjk) delegates
+/// here.
+pub fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{4E00}'..='\u{9FFF}' |   // CJK Unified Ideographs
+        '\u{3400}'..='\u{4DBF}' |   // CJK Extension A
+        '\u{20000}'..='\u{2FA1F}' | // CJK Extensions B–F, Compatibility Supplement
+        '\u{F900}'..='\u{FAFF}' |   // CJK Compatibility
+        '\u{3000}'..='\u{303F}' |   // CJK Punctuation
+        '\u{3040}'..='\u{309F}' |   // Hiragana
+        '\u{30A0}'..='\u{30FF}' |   // Katakana
+        '\u{AC00}'..='\u{D7AF}'     // Hangul
+    )
+}
+
+/// Check whether a token should be a candidate under the fallback filter
+pub fn is_fallback_candidate(token_text: &str, is_stopword: bool, min_chars: usize) -> bool {
+    !is_stopword
+        && token_text.chars().count() >= min_chars
+        && token_text.chars().any(|c| c.is_alphanumeric())
+}
+
+/// Make untagged tokens usable as candidates.
+///
+/// Tokens tagged `PosTag::Other` that pass [`is_fallback_candidate`] become
+/// `PosTag::Noun`, so runs of them form phrases; the rest are marked as
+/// stopwords so they break phrases and stay out of the graph. Tokens that
+/// already carry a real POS tag are left untouched.
+pub fn apply_pos_fallback(tokens: &mut [Token], min_chars: usize) {
+    for token in tokens.iter_mut().filter(|t| t.pos == PosTag::Other) {
+        (token.pos, token.is_stopword) =
+            fallback(&token.text, token.pos, token.is_stopword, min_chars);
+    }
+}
+
+/// [`apply_pos_fallback`] for borrowed tokens
+pub fn apply_pos_fallback_refs(tokens: &mut [TokenRef<'_>], min_chars: usize) {
+    for token in tokens.iter_mut().filter(|t| t.pos == PosTag::Other) {
+        (token.pos, token.is_stopword) =
+            fallback(token.text, token.pos, token.is_stopword, min_chars);
+    }
+}
+
+fn fallback(text: &str, pos: PosTag, is_stopword: bool, min_chars: usize) -> (PosTag, bool) {
+    if is_fallback_candidate(text, is_stopword, min_chars) {
+        (PosTag::Noun, is_stopword)
+    } else {
+        (pos, true)
+    }
+}
+
+/// Word segmentation callback for unsegmented scripts.
+///
+/// Returns the byte ranges of words within `text` (one sentence), in order.
+/// Ranges must lie on character boundaries; whitespace and punctuation may
+/// be omitted.
+#[cfg(feature = "cjk")]
+pub trait Segmenter: Send + Sync {
+    /// Segment a sentence into word byte ranges
+    fn segment(&self, text: &str) -> Vec<(usize, usize)>;
+}
+
+#[cfg(feature = "cjk")]
+impl<F> Segmenter for F
+where
+    F: Fn(&str) -> Vec<(usize, usize)> + Send + Sync,
+{
+    fn segment(&self, text: &str) -> Vec<(usize, usize)> {
+        self(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_cjk() {
+        assert!(is_cjk('机'));
+        assert!(is_cjk('の'));
+        assert!(is_cjk('カ'));
+        assert!(is_cjk('한'));
+        assert!(is_cjk('。'));
+        assert!(is_cjk('\u{2A700}')); // Extension C
+        assert!(!is_cjk('a'));
+    }
+
+    #[test]
+    fn test_apply_pos_fallback() {
+        let mut tokens = vec![
+            Token::new("机器", "机器", PosTag::Other, 0, 6, 0, 0),
+            Token::new("学习", "学习", PosTag::Other, 6, 12, 0, 1),
+            Token::new("的", "的", PosTag::Other, 12, 15, 0, 2),
+            Token::new("我们", "我们", PosTag::Other, 15, 21, 0, 3),
+            Token::new("模型", "模型", PosTag::Verb, 21, 27, 0, 4),
+        ];
+        tokens[3].is_stopword = true;
+
+        apply_pos_fallback(&mut tokens, DEFAULT_MIN_CANDIDATE_CHARS);
+
+        assert_eq!(tokens[0].pos, PosTag::Noun);
+        assert_eq!(tokens[1].pos, PosTag::Noun);
+        // Too short → treated as a stopword.
+        assert!(tokens[2].is_stopword);
+        assert_eq!(tokens[3].pos, PosTag::Other);
+        // Tagged tokens are left alone.
+        assert_eq!(tokens[4].pos, PosTag::Verb);
+    }
+
+    #[test]
+    fn test_untagged_tokens_yield_phrases() {
+        let mut tokens = vec![
+            Token::new("机器", "机器", PosTag::Other, 0, 6, 0, 0),
+            Token::new("学习", "学习", PosTag::Other, 6, 12, 0, 1),
+            Token::new("是", "是", PosTag::Other, 12, 15, 0, 2),
+            Token::new("人工", "人工", PosTag::Other, 15, 21, 0, 3),
+            Token::new("智能", "智能", PosTag::Other, 21, 27, 0, 4),
+            Token::new("的", "的", PosTag::Other, 27, 30, 0, 5),
+            Token::new("分支", "分支", PosTag::Other, 30, 36, 0, 6),
+        ];
+        apply_pos_fallback(&mut tokens, DEFAULT_MIN_CANDIDATE_CHARS);
+
+        let phrases = crate::phrase::extraction::extract_keyphrases(
+            &tokens,
+            &crate::types::TextRankConfig::default(),
+        );
+        assert!(phrases.iter().any(|p| p.lemma == "机器 学习"));
+        assert!(phrases.iter().all(|p| !p.lemma.contains('是')));
+    }
+}
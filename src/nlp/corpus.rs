@@ -0,0 +1,179 @@
+//! Corpus statistics
+//!
+//! [`CorpusStats`] counts document frequencies over lemmas across a corpus
+//! and turns them into IDF or TF-IDF weights. The weight maps plug into
+//! [`TopicalPageRank::with_topic_weights`](crate::variants::topical_pagerank::TopicalPageRank::with_topic_weights)
+//! or [`TopicWeightsTeleportBuilder`](crate::pipeline::TopicWeightsTeleportBuilder)
+//! to bias the random walk toward terms that are rare in the corpus;
+//! [`TfIdfTeleportBuilder`](crate::pipeline::TfIdfTeleportBuilder) does the
+//! per-document TF-IDF weighting inside a pipeline.
+//!
+//! IDF is smoothed as `ln((1 + N) / (1 + df)) + 1`, so it is always positive
+//! and lemmas never seen in the corpus get the largest weight.
+
+use crate::types::Token;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Document frequencies of lemmas over a corpus
+///
+/// ```
+/// use rapid_textrank::nlp::corpus::CorpusStats;
+/// use rapid_textrank::Tokenizer;
+///
+/// let tokenizer = Tokenizer::new();
+/// let mut stats = CorpusStats::new();
+/// for doc in ["Graphs have nodes.", "Graphs have edges.", "Trees are graphs."] {
+///     stats.add_document(&tokenizer.tokenize(doc).1);
+/// }
+/// assert_eq!(stats.num_docs(), 3);
+/// assert_eq!(stats.document_frequency("graph"), 3);
+/// assert!(stats.idf("edge") > stats.idf("graph"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CorpusStats {
+    num_docs: u64,
+    doc_freqs: FxHashMap<String, u64>,
+}
+
+impl CorpusStats {
+    /// Empty statistics
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count each distinct non-stopword lemma of a document once.
+    pub fn add_document(&mut self, tokens: &[Token]) {
+        self.add_lemmas(
+            tokens
+                .iter()
+                .filter(|t| !t.is_stopword)
+                .map(|t| t.lemma.as_str()),
+        );
+    }
+
+    /// Count each distinct lemma of a document once.
+    pub fn add_lemmas<'a>(&mut self, lemmas: impl IntoIterator<Item = &'a str>) {
+        let distinct: FxHashSet<&str> = lemmas.into_iter().collect();
+        for lemma in distinct {
+            match self.doc_freqs.get_mut(lemma) {
+                Some(df) => *df += 1,
+                None => {
+                    self.doc_freqs.insert(lemma.to_string(), 1);
+                }
+            }
+        }
+        self.num_docs += 1;
+    }
+
+    /// Add the counts of another accumulator (e.g. one per thread).
+    pub fn merge(&mut self, other: &CorpusStats) {
+        self.num_docs += other.num_docs;
+        for (lemma, df) in &other.doc_freqs {
+            *self.doc_freqs.entry(lemma.clone()).or_insert(0) += df;
+        }
+    }
+
+    /// Number of documents added
+    pub fn num_docs(&self) -> u64 {
+        self.num_docs
+    }
+
+    /// Number of distinct lemmas seen
+    pub fn vocabulary_size(&self) -> usize {
+        self.doc_freqs.len()
+    }
+
+    /// Number of documents containing `lemma`
+    pub fn document_frequency(&self, lemma: &str) -> u64 {
+        self.doc_freqs.get(lemma).copied().unwrap_or(0)
+    }
+
+    /// Smoothed inverse document frequency of `lemma`
+    pub fn idf(&self, lemma: &str) -> f64 {
+        let n = self.num_docs as f64;
+        let df = self.document_frequency(lemma) as f64;
+        ((1.0 + n) / (1.0 + df)).ln() + 1.0
+    }
+
+    /// IDF of every lemma in the corpus, as a `lemma → weight` map.
+    pub fn idf_weights(&self) -> HashMap<String, f64> {
+        self.doc_freqs
+            .keys()
+            .map(|lemma| (lemma.clone(), self.idf(lemma)))
+            .collect()
+    }
+
+    /// TF-IDF of every non-stopword lemma of one document, as a
+    /// `lemma → weight` map. TF is the raw count in `tokens`.
+    pub fn tfidf_weights(&self, tokens: &[Token]) -> HashMap<String, f64> {
+        let mut tf: FxHashMap<&str, u32> = FxHashMap::default();
+        for token in tokens.iter().filter(|t| !t.is_stopword) {
+            *tf.entry(token.lemma.as_str()).or_insert(0) += 1;
+        }
+        tf.into_iter()
+            .map(|(lemma, count)| (lemma.to_string(), count as f64 * self.idf(lemma)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PosTag;
+
+    fn doc(lemmas: &[&str]) -> Vec<Token> {
+        lemmas
+            .iter()
+            .enumerate()
+            .map(|(i, l)| Token::new(*l, *l, PosTag::Noun, i * 10, i * 10 + l.len(), 0, i))
+            .collect()
+    }
+
+    #[test]
+    fn test_document_frequency_and_idf() {
+        let mut stats = CorpusStats::new();
+        stats.add_document(&doc(&["graph", "graph", "node"]));
+        stats.add_document(&doc(&["graph", "edge"]));
+
+        assert_eq!(stats.num_docs(), 2);
+        assert_eq!(stats.vocabulary_size(), 3);
+        assert_eq!(stats.document_frequency("graph"), 2);
+        assert_eq!(stats.document_frequency("tree"), 0);
+        assert!((stats.idf("graph") - 1.0).abs() < 1e-12);
+        assert!((stats.idf("node") - (1.5f64.ln() + 1.0)).abs() < 1e-12);
+        assert!(stats.idf("tree") > stats.idf("node"));
+
+        let weights = stats.idf_weights();
+        assert_eq!(weights.len(), 3);
+        assert_eq!(weights["edge"], stats.idf("edge"));
+    }
+
+    #[test]
+    fn test_tfidf_skips_stopwords() {
+        let mut stats = CorpusStats::new();
+        stats.add_document(&doc(&["graph", "node"]));
+        let mut tokens = doc(&["node", "node", "the"]);
+        tokens[2].is_stopword = true;
+
+        let weights = stats.tfidf_weights(&tokens);
+        assert_eq!(weights.len(), 1);
+        assert!((weights["node"] - 2.0 * stats.idf("node")).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_merge_and_serde_round_trip() {
+        let mut a = CorpusStats::new();
+        a.add_lemmas(["graph", "node"]);
+        let mut b = CorpusStats::new();
+        b.add_lemmas(["graph"]);
+        a.merge(&b);
+        assert_eq!(a.num_docs(), 2);
+        assert_eq!(a.document_frequency("graph"), 2);
+
+        let json = serde_json::to_string(&a).unwrap();
+        let restored: CorpusStats = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, a);
+    }
+}
@@ -0,0 +1,529 @@
+//! Word vectors and embedding-based topic weights
+//!
+//! [`WordVectors`] loads pre-trained embeddings and turns them into the
+//! `lemma → weight` map that
+//! [`TopicalPageRank::with_topic_weights`](crate::variants::topical_pagerank::TopicalPageRank::with_topic_weights)
+//! expects: each document lemma is weighted by the cosine similarity of its
+//! vector to a topic vector (a seed phrase, or the document centroid).
+//!
+//! Supported formats:
+//!
+//! - word2vec / GloVe text: one `word v1 v2 ...` line per word, with or
+//!   without the word2vec `<count> <dim>` header line.
+//! - fastText `.bin` models (unquantized). In-vocabulary word vectors are
+//!   computed from their subword n-grams, as `fasttext print-word-vectors`
+//!   does; out-of-vocabulary words are not supported.
+//!
+//! Only available with the `embeddings` feature.
+
+use crate::errors::{Result, TextRankError};
+use crate::types::Token;
+use rustc_hash::FxHashMap;
+use std::collections::HashMap;
+use std::io::{BufRead, Read};
+use std::path::Path;
+
+/// A table of word vectors with a fixed dimension
+#[derive(Debug, Clone, Default)]
+pub struct WordVectors {
+    dim: usize,
+    index: FxHashMap<String, usize>,
+    data: Vec<f32>,
+}
+
+impl WordVectors {
+    /// Load a word2vec or GloVe text file.
+    pub fn from_text_file(path: impl AsRef<Path>) -> Result<Self> {
+        let file = std::fs::File::open(path)?;
+        Self::from_text_reader(std::io::BufReader::new(file))
+    }
+
+    /// Read word2vec or GloVe text format.
+    ///
+    /// A first line of exactly two integers is taken as the word2vec header.
+    /// Every vector must have the same dimension; repeated words keep their
+    /// first vector.
+    pub fn from_text_reader(reader: impl BufRead) -> Result<Self> {
+        let mut vectors = Self::default();
+        for (line_no, line) in reader.lines().enumerate() {
+            let line = line?;
+            let mut fields = line.split_whitespace();
+            let Some(word) = fields.next() else {
+                continue;
+            };
+            let values: Vec<&str> = fields.collect();
+            if line_no == 0
+                && values.len() == 1
+                && word.parse::<usize>().is_ok()
+                && values[0].parse::<usize>().is_ok()
+            {
+                continue;
+            }
+
+            let vector = values
+                .iter()
+                .map(|v| v.parse::<f32>())
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|e| TextRankError::parse(line_no + 1, e.to_string()))?;
+            if vectors.dim == 0 {
+                vectors.dim = vector.len();
+            }
+            if vector.is_empty() || vector.len() != vectors.dim {
+                return Err(TextRankError::parse(
+                    line_no + 1,
+                    format!(
+                        "expected {} vector components, found {}",
+                        vectors.dim,
+                        vector.len()
+                    ),
+                ));
+            }
+            vectors.insert(word, &vector);
+        }
+        Ok(vectors)
+    }
+
+    /// Load a fastText `.bin` model.
+    pub fn from_fasttext_file(path: impl AsRef<Path>) -> Result<Self> {
+        let file = std::fs::File::open(path)?;
+        Self::from_fasttext_reader(std::io::BufReader::new(file))
+    }
+
+    /// Read the word vectors of a fastText `.bin` model.
+    pub fn from_fasttext_reader(reader: impl Read) -> Result<Self> {
+        FastTextReader { inner: reader }.read()
+    }
+
+    /// Vector dimension (0 when empty)
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Number of words
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Whether there are no words
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Vector of `word`, falling back to its lowercase form
+    pub fn get(&self, word: &str) -> Option<&[f32]> {
+        let row = match self.index.get(word) {
+            Some(&row) => row,
+            None => *self.index.get(&word.to_lowercase())?,
+        };
+        Some(&self.data[row * self.dim..(row + 1) * self.dim])
+    }
+
+    /// Mean vector of the known `words`, or `None` if none are known.
+    pub fn centroid<'a>(&self, words: impl IntoIterator<Item = &'a str>) -> Option<Vec<f32>> {
+        let mut sum = vec![0.0f32; self.dim];
+        let mut n = 0;
+        for vector in words.into_iter().filter_map(|w| self.get(w)) {
+            for (s, v) in sum.iter_mut().zip(vector) {
+                *s += v;
+            }
+            n += 1;
+        }
+        (n > 0).then(|| sum.into_iter().map(|s| s / n as f32).collect())
+    }
+
+    /// Weight each lemma by the cosine similarity of its vector to `topic`.
+    ///
+    /// Negative similarities are clamped to zero (teleport weights must be
+    /// non-negative). Lemmas without a vector are left out of the map, so
+    /// they receive `TopicalPageRank`'s minimum weight.
+    pub fn topic_weights<'a>(
+        &self,
+        lemmas: impl IntoIterator<Item = &'a str>,
+        topic: &[f32],
+    ) -> HashMap<String, f64> {
+        let mut weights = HashMap::new();
+        for lemma in lemmas {
+            if weights.contains_key(lemma) {
+                continue;
+            }
+            if let Some(vector) = self.get(lemma) {
+                weights.insert(lemma.to_string(), cosine(vector, topic).max(0.0));
+            }
+        }
+        weights
+    }
+
+    /// Topic weights for the non-stopword lemmas of a document.
+    ///
+    /// The topic is the centroid of the words of `seed` when given, otherwise
+    /// the centroid of the document's own lemmas. Returns an empty map when
+    /// no topic vector can be formed.
+    pub fn topic_weights_for_tokens(
+        &self,
+        tokens: &[Token],
+        seed: Option<&str>,
+    ) -> HashMap<String, f64> {
+        let lemmas = || {
+            tokens
+                .iter()
+                .filter(|t| !t.is_stopword)
+                .map(|t| t.lemma.as_str())
+        };
+        let topic = match seed {
+            Some(seed) => self.centroid(seed.split_whitespace()),
+            None => self.centroid(lemmas()),
+        };
+        match topic {
+            Some(topic) => self.topic_weights(lemmas(), &topic),
+            None => HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, word: &str, vector: &[f32]) {
+        if self.index.contains_key(word) {
+            return;
+        }
+        self.index.insert(word.to_string(), self.index.len());
+        self.data.extend_from_slice(vector);
+    }
+}
+
+fn cosine(a: &[f32], b: &[f32]) -> f64 {
+    let (mut dot, mut na, mut nb) = (0.0f64, 0.0f64, 0.0f64);
+    for (&x, &y) in a.iter().zip(b) {
+        let (x, y) = (x as f64, y as f64);
+        dot += x * y;
+        na += x * x;
+        nb += y * y;
+    }
+    if na == 0.0 || nb == 0.0 {
+        0.0
+    } else {
+        dot / (na.sqrt() * nb.sqrt())
+    }
+}
+
+// ============================================================================
+// fastText binary format
+// ============================================================================
+
+const FASTTEXT_MAGIC: i32 = 793_712_314;
+const FASTTEXT_EOS: &str = "</s>";
+
+struct FastTextReader<R> {
+    inner: R,
+}
+
+impl<R: Read> FastTextReader<R> {
+    fn read(mut self) -> Result<WordVectors> {
+        if self.i32()? != FASTTEXT_MAGIC {
+            return Err(invalid_model("bad magic number"));
+        }
+        let _version = self.i32()?;
+
+        // Args: dim ws epoch minCount neg wordNgrams loss model bucket minn
+        // maxn lrUpdateRate (i32), then t (f64).
+        let dim = self.i32()?;
+        let args: Vec<i32> = (0..11).map(|_| self.i32()).collect::<Result<_>>()?;
+        let (bucket, minn, maxn) = (args[7], args[8], args[9]);
+        self.bytes(8)?;
+        if dim <= 0 {
+            return Err(invalid_model("dimension must be positive"));
+        }
+
+        // Dictionary.
+        let _size = self.i32()?;
+        let nwords = self.i32()?;
+        let nlabels = self.i32()?;
+        let _ntokens = self.i64()?;
+        let pruneidx_size = self.i64()?;
+        if nwords < 0 || nlabels < 0 {
+            return Err(invalid_model("negative dictionary size"));
+        }
+        // Sizes come from the file, so nothing is preallocated from them.
+        let mut words = Vec::new();
+        for _ in 0..(nwords as i64 + nlabels as i64) {
+            let word = self.cstring()?;
+            let _count = self.i64()?;
+            let _entry_type = self.bytes(1)?;
+            words.push(word);
+        }
+        words.truncate(nwords as usize);
+        let mut pruneidx = FxHashMap::default();
+        for _ in 0..pruneidx_size.max(0) {
+            let (from, to) = (self.i32()?, self.i32()?);
+            pruneidx.insert(from, to);
+        }
+
+        if self.bytes(1)?[0] != 0 {
+            return Err(invalid_model("quantized models are not supported"));
+        }
+        let rows = self.i64()?;
+        let cols = self.i64()?;
+        if cols != dim as i64 || rows < nwords as i64 {
+            return Err(invalid_model("input matrix does not match the dictionary"));
+        }
+        let dim = dim as usize;
+        let nrows = rows as usize;
+        let len = nrows
+            .checked_mul(dim)
+            .ok_or_else(|| invalid_model("input matrix is too large"))?;
+        let mut matrix = Vec::new();
+        for _ in 0..len {
+            matrix.push(f32::from_le_bytes(self.array()?));
+        }
+
+        let ngrams = NgramConfig {
+            nwords,
+            bucket,
+            minn,
+            maxn,
+            pruneidx: (pruneidx_size >= 0).then_some(pruneidx),
+        };
+        let mut vectors = WordVectors {
+            dim,
+            ..Default::default()
+        };
+        let mut vector = vec![0.0f32; dim];
+        for (id, word) in words.iter().enumerate() {
+            let rows = ngrams.subwords(id as i32, word);
+            vector.iter_mut().for_each(|v| *v = 0.0);
+            for &row in &rows {
+                let row = usize::try_from(row)
+                    .ok()
+                    .filter(|&row| row < nrows)
+                    .ok_or_else(|| invalid_model("subword row outside the input matrix"))?;
+                let row = &matrix[row * dim..(row + 1) * dim];
+                for (v, r) in vector.iter_mut().zip(row) {
+                    *v += r;
+                }
+            }
+            vector.iter_mut().for_each(|v| *v /= rows.len() as f32);
+            vectors.insert(word, &vector);
+        }
+        Ok(vectors)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut buf = [0u8; N];
+        self.fill(&mut buf)?;
+        Ok(buf)
+    }
+
+    fn bytes(&mut self, n: usize) -> Result<Vec<u8>> {
+        let mut buf = vec![0u8; n];
+        self.fill(&mut buf)?;
+        Ok(buf)
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.inner.read_exact(buf).map_err(|e| match e.kind() {
+            std::io::ErrorKind::UnexpectedEof => invalid_model("unexpected end of file"),
+            _ => e.into(),
+        })
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        Ok(i32::from_le_bytes(self.array()?))
+    }
+
+    fn i64(&mut self) -> Result<i64> {
+        Ok(i64::from_le_bytes(self.array()?))
+    }
+
+    fn cstring(&mut self) -> Result<String> {
+        let mut bytes = Vec::new();
+        loop {
+            match self.array::<1>()?[0] {
+                0 => break,
+                b => bytes.push(b),
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| invalid_model("dictionary word is not UTF-8"))
+    }
+}
+
+/// Subword settings of a fastText model.
+struct NgramConfig {
+    nwords: i32,
+    bucket: i32,
+    minn: i32,
+    maxn: i32,
+    /// Bucket remapping of pruned models (n-grams outside it are dropped);
+    /// `None` when the model is unpruned.
+    pruneidx: Option<FxHashMap<i32, i32>>,
+}
+
+impl NgramConfig {
+    /// Input-matrix rows averaged into the vector of word `id`: the word
+    /// itself followed by its character n-grams.
+    fn subwords(&self, id: i32, word: &str) -> Vec<i32> {
+        let mut rows = vec![id];
+        if word == FASTTEXT_EOS || self.maxn <= 0 || self.bucket <= 0 {
+            return rows;
+        }
+        let bracketed = format!("<{word}>");
+        let bytes = bracketed.as_bytes();
+        for i in 0..bytes.len() {
+            if bytes[i] & 0xC0 == 0x80 {
+                continue;
+            }
+            let mut j = i;
+            let mut n = 1;
+            while j < bytes.len() && n <= self.maxn {
+                j += 1;
+                while j < bytes.len() && bytes[j] & 0xC0 == 0x80 {
+                    j += 1;
+                }
+                if n >= self.minn && !(n == 1 && (i == 0 || j == bytes.len())) {
+                    let bucket = (fasttext_hash(&bytes[i..j]) % self.bucket as u32) as i32;
+                    match &self.pruneidx {
+                        None => rows.push(self.nwords + bucket),
+                        Some(map) => {
+                            if let Some(&pruned) = map.get(&bucket) {
+                                rows.push(self.nwords + pruned);
+                            }
+                        }
+                    }
+                }
+                n += 1;
+            }
+        }
+        rows
+    }
+}
+
+/// FNV-1a over sign-extended bytes, as in fastText's `Dictionary::hash`.
+fn fasttext_hash(bytes: &[u8]) -> u32 {
+    let mut h: u32 = 2_166_136_261;
+    for &b in bytes {
+        h ^= b as i8 as u32;
+        h = h.wrapping_mul(16_777_619);
+    }
+    h
+}
+
+fn invalid_model(message: &str) -> TextRankError {
+    TextRankError::serialization(format!("invalid fastText model: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PosTag;
+
+    const GLOVE: &str = "king 1.0 0.0 0.0\nqueen 0.9 0.1 0.0\nbanana 0.0 0.0 1.0\n";
+
+    #[test]
+    fn test_text_formats() {
+        let glove = WordVectors::from_text_reader(GLOVE.as_bytes()).unwrap();
+        assert_eq!((glove.len(), glove.dim()), (3, 3));
+        assert_eq!(glove.get("King"), Some(&[1.0, 0.0, 0.0][..]));
+
+        let word2vec = format!("3 3\n{GLOVE}");
+        let word2vec = WordVectors::from_text_reader(word2vec.as_bytes()).unwrap();
+        assert_eq!(word2vec.len(), 3);
+
+        let err = WordVectors::from_text_reader("a 1 2\nb 1\n".as_bytes()).unwrap_err();
+        assert!(matches!(err, TextRankError::Parse { line: 2, .. }));
+    }
+
+    #[test]
+    fn test_topic_weights() {
+        let vectors = WordVectors::from_text_reader(GLOVE.as_bytes()).unwrap();
+        let topic = vectors.centroid(["king"]).unwrap();
+        let weights = vectors.topic_weights(["king", "queen", "banana", "unknown"], &topic);
+        assert_eq!(weights.len(), 3);
+        assert!((weights["king"] - 1.0).abs() < 1e-9);
+        assert!(weights["queen"] > weights["banana"]);
+        assert_eq!(weights["banana"], 0.0);
+
+        let tokens = vec![
+            Token::new("kings", "king", PosTag::Noun, 0, 5, 0, 0),
+            Token::new("and", "and", PosTag::Conjunction, 6, 9, 0, 1),
+            Token::new("bananas", "banana", PosTag::Noun, 10, 17, 0, 2),
+        ];
+        let seeded = vectors.topic_weights_for_tokens(&tokens, Some("queen"));
+        assert!(seeded["king"] > seeded["banana"]);
+        let centroid = vectors.topic_weights_for_tokens(&tokens, None);
+        assert!((centroid["king"] - centroid["banana"]).abs() < 1e-9);
+    }
+
+    /// Serialize a tiny unquantized fastText model.
+    fn fasttext_model(words: &[&str], maxn: i32, bucket: i32, matrix: &[[f32; 2]]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let i32s = |out: &mut Vec<u8>, values: &[i32]| {
+            for v in values {
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+        };
+        i32s(&mut out, &[FASTTEXT_MAGIC, 12]);
+        // dim ws epoch minCount neg wordNgrams loss model bucket minn maxn lrUpdateRate
+        i32s(&mut out, &[2, 5, 5, 1, 5, 1, 1, 2, bucket, 3, maxn, 100]);
+        out.extend_from_slice(&1e-4f64.to_le_bytes());
+        i32s(&mut out, &[words.len() as i32, words.len() as i32, 0]);
+        out.extend_from_slice(&100i64.to_le_bytes());
+        out.extend_from_slice(&(-1i64).to_le_bytes());
+        for w in words {
+            out.extend_from_slice(w.as_bytes());
+            out.push(0);
+            out.extend_from_slice(&1i64.to_le_bytes());
+            out.push(0);
+        }
+        out.push(0); // not quantized
+        out.extend_from_slice(&(matrix.len() as i64).to_le_bytes());
+        out.extend_from_slice(&2i64.to_le_bytes());
+        for row in matrix {
+            for v in row {
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_fasttext_without_subwords() {
+        let model = fasttext_model(&["</s>", "graph"], 0, 0, &[[0.0, 0.0], [1.0, 2.0]]);
+        let vectors = WordVectors::from_fasttext_reader(model.as_slice()).unwrap();
+        assert_eq!(vectors.len(), 2);
+        assert_eq!(vectors.get("graph"), Some(&[1.0, 2.0][..]));
+    }
+
+    #[test]
+    fn test_fasttext_averages_subwords() {
+        // One bucket: every 3-gram of "<ab>" ("<ab", "ab>") lands in row 1.
+        let model = fasttext_model(&["ab"], 3, 1, &[[3.0, 0.0], [0.0, 3.0]]);
+        let vectors = WordVectors::from_fasttext_reader(model.as_slice()).unwrap();
+        assert_eq!(vectors.get("ab"), Some(&[1.0, 2.0][..]));
+
+        let mut bad = model.clone();
+        bad[0] ^= 1;
+        assert!(WordVectors::from_fasttext_reader(bad.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_fasttext_malformed_models_are_errors() {
+        let is_invalid = |model: &[u8]| {
+            matches!(
+                WordVectors::from_fasttext_reader(model),
+                Err(TextRankError::Serialization { .. })
+            )
+        };
+        let model = fasttext_model(&["ab"], 3, 1, &[[3.0, 0.0], [0.0, 3.0]]);
+        for len in 0..model.len() {
+            assert!(is_invalid(&model[..len]), "truncated to {len} bytes");
+        }
+
+        // Subword buckets past the end of the input matrix.
+        let short = fasttext_model(&["ab"], 3, 1000, &[[3.0, 0.0]]);
+        assert!(is_invalid(&short));
+
+        // Negative dimension and dictionary sizes.
+        let mut negative_dim = model.clone();
+        negative_dim[8..12].copy_from_slice(&(-2i32).to_le_bytes());
+        assert!(is_invalid(&negative_dim));
+        let mut negative_words = model.clone();
+        negative_words[68..72].copy_from_slice(&(-1i32).to_le_bytes());
+        assert!(is_invalid(&negative_words));
+    }
+}
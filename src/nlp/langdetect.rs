@@ -0,0 +1,378 @@
+//! Language detection (requires the `lang-detect` feature)
+//!
+//! A mixed-language corpus run with one English config produces junk on the
+//! non-English documents: their function words pass the stopword filter and
+//! become graph nodes. [`detect_language`] guesses a document's language so
+//! the stopword list, stemmer and POS filter can follow it, and
+//! [`config_for_text`] applies that guess to a [`TextRankConfig`] when
+//! [`detect_language`](TextRankConfig::detect_language) is set.
+//!
+//! Detection is deliberately small and dependency-free:
+//!
+//! - Text dominated by a non-Latin script is classified by script (Han,
+//!   kana, Hangul, Cyrillic, Greek, Arabic).
+//! - Latin-script text is classified by counting hits against a short list
+//!   of high-frequency function words per language.
+//!
+//! Only the first [`MAX_SAMPLE_CHARS`] characters are examined.
+
+use crate::nlp::language::built_in_include_pos;
+use crate::types::{LemmaStrategy, StemLanguage, TextRankConfig};
+use std::borrow::Cow;
+
+/// Characters of input examined by [`detect_language`].
+pub const MAX_SAMPLE_CHARS: usize = 10_000;
+
+/// Function-word hits required before a Latin-script guess is made.
+const MIN_HITS: usize = 2;
+
+/// High-frequency function words of each Latin-script language, in
+/// tie-breaking order.
+const FUNCTION_WORDS: &[(&str, &[&str])] = &[
+    (
+        "en",
+        &[
+            "the", "and", "of", "to", "is", "in", "that", "it", "was", "for", "with", "are",
+            "this", "be", "on", "as", "by", "have", "from", "which", "not", "or", "at", "an",
+            "were",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "der", "die", "und", "das", "ist", "nicht", "zu", "den", "mit", "von", "sich", "des",
+            "auf", "für", "ein", "eine", "dem", "auch", "es", "sind", "wird", "werden", "im",
+            "bei", "wie",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "la", "les", "et", "des", "est", "une", "du", "que", "dans", "pour", "qui",
+            "sur", "pas", "au", "avec", "sont", "ce", "par", "ne", "il", "aux", "cette", "mais",
+            "ou",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "la", "los", "las", "de", "que", "y", "en", "es", "por", "una", "del", "con",
+            "para", "se", "no", "al", "lo", "como", "más", "pero", "sus", "fue", "son", "está",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "il", "di", "che", "e", "la", "le", "gli", "è", "un", "una", "per", "non", "del",
+            "della", "con", "sono", "nel", "alla", "si", "anche", "come", "più", "ma", "dei",
+            "questo",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "o", "os", "as", "de", "que", "e", "do", "da", "em", "um", "uma", "para", "com", "não",
+            "dos", "das", "no", "na", "por", "mais", "se", "ao", "foi", "são", "está",
+        ],
+    ),
+    (
+        "nl",
+        &[
+            "de", "het", "een", "en", "van", "is", "dat", "op", "te", "niet", "zijn", "met",
+            "voor", "die", "er", "aan", "ook", "als", "bij", "door", "wordt", "naar", "maar",
+            "dit", "worden",
+        ],
+    ),
+    (
+        "sv",
+        &[
+            "och", "att", "det", "som", "en", "på", "är", "av", "för", "med", "till", "den", "har",
+            "inte", "om", "ett", "var", "jag", "de", "men", "sig", "från", "vid", "kan", "också",
+        ],
+    ),
+    (
+        "da",
+        &[
+            "og", "at", "det", "er", "en", "til", "på", "som", "med", "af", "for", "ikke", "den",
+            "har", "de", "et", "var", "der", "fra", "kan", "men", "om", "sig", "jeg", "blev",
+        ],
+    ),
+    (
+        "no",
+        &[
+            "og", "det", "er", "en", "til", "på", "som", "med", "av", "for", "ikke", "den", "har",
+            "de", "et", "var", "der", "fra", "kan", "men", "om", "seg", "jeg", "ble", "også",
+        ],
+    ),
+    (
+        "fi",
+        &[
+            "ja", "on", "ei", "että", "se", "hän", "oli", "ovat", "mutta", "kuin", "tai", "myös",
+            "joka", "tämä", "ole", "sen", "niin", "kun", "mukaan", "vain", "jo", "nyt", "sekä",
+            "jos", "voi",
+        ],
+    ),
+    (
+        "pl",
+        &[
+            "i", "w", "na", "z", "się", "nie", "do", "że", "jest", "to", "o", "a", "od", "po",
+            "jak", "przez", "dla", "ale", "są", "jego", "tak", "oraz", "czy", "być", "co",
+        ],
+    ),
+    (
+        "tr",
+        &[
+            "ve", "bir", "bu", "da", "de", "için", "ile", "çok", "olarak", "daha", "gibi", "en",
+            "olan", "ne", "ama", "kadar", "her", "sonra", "değil", "var", "ya", "veya", "o", "şu",
+            "mi",
+        ],
+    ),
+    (
+        "hu",
+        &[
+            "a", "az", "és", "hogy", "nem", "is", "egy", "van", "meg", "de", "ez", "csak", "már",
+            "ki", "mint", "volt", "vagy", "el", "még", "azt", "fel", "ha", "kell", "pedig", "lesz",
+        ],
+    ),
+];
+
+/// Result of [`detect_language`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DetectedLanguage {
+    /// ISO 639-1 code (e.g. `"de"`), usable as [`TextRankConfig::language`].
+    pub code: &'static str,
+    /// Rough confidence in `[0, 1]`: the margin over the runner-up language
+    /// for Latin-script text, the script's share of letters otherwise.
+    pub confidence: f64,
+}
+
+/// Guess the language of `text`.
+///
+/// Returns `None` when there is too little evidence (short text, or no
+/// known function words).
+pub fn detect_language(text: &str) -> Option<DetectedLanguage> {
+    let sample: String = text.chars().take(MAX_SAMPLE_CHARS).collect();
+    detect_by_script(&sample).or_else(|| detect_by_function_words(&sample))
+}
+
+/// Classify text dominated by a non-Latin script.
+fn detect_by_script(text: &str) -> Option<DetectedLanguage> {
+    let (mut letters, mut han, mut kana, mut hangul) = (0usize, 0usize, 0usize, 0usize);
+    let (mut cyrillic, mut greek, mut arabic) = (0usize, 0usize, 0usize);
+    for c in text.chars().filter(|c| c.is_alphabetic()) {
+        letters += 1;
+        match c as u32 {
+            0x3040..=0x30FF => kana += 1,
+            0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF | 0x20000..=0x2FA1F => han += 1,
+            0xAC00..=0xD7AF | 0x1100..=0x11FF => hangul += 1,
+            0x0400..=0x04FF => cyrillic += 1,
+            0x0370..=0x03FF => greek += 1,
+            0x0600..=0x06FF | 0x0750..=0x077F => arabic += 1,
+            _ => {}
+        }
+    }
+    if letters == 0 {
+        return None;
+    }
+    let (code, count) = [
+        // Japanese mixes kana into Han text; any kana decides it.
+        (if kana > 0 { "ja" } else { "zh" }, han + kana),
+        ("ko", hangul),
+        ("ru", cyrillic),
+        ("el", greek),
+        ("ar", arabic),
+    ]
+    .into_iter()
+    .max_by_key(|&(_, count)| count)?;
+    let share = count as f64 / letters as f64;
+    (share > 0.5).then_some(DetectedLanguage {
+        code,
+        confidence: share,
+    })
+}
+
+/// Classify Latin-script text by function-word hits.
+fn detect_by_function_words(text: &str) -> Option<DetectedLanguage> {
+    let mut hits = vec![0usize; FUNCTION_WORDS.len()];
+    for word in text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+    {
+        let word = word.to_lowercase();
+        for (count, (_, words)) in hits.iter_mut().zip(FUNCTION_WORDS) {
+            if words.contains(&word.as_str()) {
+                *count += 1;
+            }
+        }
+    }
+
+    // First language wins ties.
+    let mut best = 0;
+    for (i, &count) in hits.iter().enumerate() {
+        if count > hits[best] {
+            best = i;
+        }
+    }
+    let top = hits[best];
+    if top < MIN_HITS {
+        return None;
+    }
+    let runner_up = hits
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != best)
+        .map(|(_, &count)| count)
+        .max()
+        .unwrap_or(0);
+    Some(DetectedLanguage {
+        code: FUNCTION_WORDS[best].0,
+        confidence: (top - runner_up) as f64 / top as f64,
+    })
+}
+
+/// `cfg` adjusted for documents in `language`.
+///
+/// - `language` (and so the built-in stopword list) becomes `language`;
+///   extra `stopwords` are kept.
+/// - A [`LemmaStrategy::Stem`] strategy switches to that language's
+///   stemmer, when there is one. Other strategies are left alone.
+/// - `include_pos` is widened to all content tags, but only while it still
+///   has its default value: the built-in POS heuristics are English
+///   suffix rules, so other languages rely on stopwords instead. A
+///   customised `include_pos` always wins.
+pub fn config_for_language(cfg: &TextRankConfig, language: &str) -> TextRankConfig {
+    let mut out = cfg.clone();
+    out.language = language.to_string();
+    if let LemmaStrategy::Stem(_) = cfg.lemma_strategy {
+        if let Some(stemmer) = StemLanguage::from_code(language) {
+            out.lemma_strategy = LemmaStrategy::Stem(stemmer);
+        }
+    }
+    if cfg.include_pos == TextRankConfig::default().include_pos {
+        out.include_pos = built_in_include_pos(language);
+    }
+    out
+}
+
+/// `cfg` adjusted for the detected language of `text` when
+/// [`TextRankConfig::detect_language`] is set and detection succeeds;
+/// otherwise `cfg` unchanged.
+pub fn config_for_text<'a>(cfg: &'a TextRankConfig, text: &str) -> Cow<'a, TextRankConfig> {
+    if !cfg.detect_language {
+        return Cow::Borrowed(cfg);
+    }
+    match detect_language(text) {
+        Some(detected) => Cow::Owned(config_for_language(cfg, detected.code)),
+        None => Cow::Borrowed(cfg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PosTag;
+
+    #[test]
+    fn test_detects_latin_languages() {
+        let cases = [
+            (
+                "en",
+                "The model is trained on the data and it is evaluated with a test set.",
+            ),
+            (
+                "de",
+                "Das Modell wird mit den Daten trainiert und ist auch für die Auswertung geeignet.",
+            ),
+            (
+                "fr",
+                "Le modèle est entraîné sur les données et il est évalué avec une base de test.",
+            ),
+            (
+                "es",
+                "El modelo se entrena con los datos y es evaluado con un conjunto de prueba.",
+            ),
+            (
+                "nl",
+                "Het model wordt getraind op de gegevens en is ook geschikt voor een test.",
+            ),
+        ];
+        for (code, text) in cases {
+            assert_eq!(detect_language(text).map(|d| d.code), Some(code), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_detects_scripts_and_gives_up_on_too_little_text() {
+        assert_eq!(
+            detect_language("机器学习是人工智能的一个分支")
+                .unwrap()
+                .code,
+            "zh"
+        );
+        assert_eq!(
+            detect_language("機械学習は人工知能の一分野です")
+                .unwrap()
+                .code,
+            "ja"
+        );
+        assert_eq!(
+            detect_language("Машинное обучение — это раздел ИИ")
+                .unwrap()
+                .code,
+            "ru"
+        );
+        assert!(detect_language("Graph ranking").is_none());
+        assert!(detect_language("").is_none());
+    }
+
+    #[test]
+    fn test_config_for_text_applies_language_defaults() {
+        let german = "Die Daten werden mit dem Modell verarbeitet und sind nicht öffentlich.";
+        let base = TextRankConfig::default();
+        // Off by default.
+        assert_eq!(config_for_text(&base, german).language, "en");
+
+        let cfg = TextRankConfig {
+            detect_language: true,
+            lemma_strategy: LemmaStrategy::Stem(StemLanguage::English),
+            ..TextRankConfig::default()
+        };
+        let resolved = config_for_text(&cfg, german);
+        assert_eq!(resolved.language, "de");
+        assert_eq!(
+            resolved.lemma_strategy,
+            LemmaStrategy::Stem(StemLanguage::German)
+        );
+        assert!(resolved.include_pos.contains(&PosTag::Adverb));
+
+        // An explicit POS filter is kept.
+        let custom = TextRankConfig {
+            include_pos: vec![PosTag::Noun],
+            ..cfg
+        };
+        assert_eq!(config_for_text(&custom, german).include_pos, [PosTag::Noun]);
+    }
+
+    #[test]
+    fn test_extract_from_text_drops_detected_stopwords() {
+        use crate::phrase::extraction::extract_from_text;
+
+        let text = "Die Analyse der Daten ist wichtig. Die Analyse wird mit dem Modell \
+                    durchgeführt und die Daten werden nicht veröffentlicht.";
+        let lemmas = |cfg: &TextRankConfig| -> Vec<String> {
+            extract_from_text(text, cfg)
+                .into_iter()
+                .map(|p| p.lemma)
+                .collect()
+        };
+        let function_word = |l: &String| {
+            l.split(' ')
+                .any(|w| ["der", "die", "und", "ist", "nicht"].contains(&w))
+        };
+        assert!(lemmas(&TextRankConfig::default()).iter().any(function_word));
+
+        let detected = lemmas(&TextRankConfig::default().with_detect_language(true));
+        assert!(!detected.is_empty());
+        assert!(!detected.iter().any(function_word), "{detected:?}");
+    }
+}
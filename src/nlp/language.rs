@@ -0,0 +1,376 @@
+//! Per-language configuration
+//!
+//! A [`LanguageProfile`] bundles what changes from one language to the
+//! next: the stopword list, the stemmer, the default POS filter and the
+//! tokenizer rules. A [`LanguageRegistry`] maps language codes to profiles;
+//! it starts with a built-in profile for every language that has a
+//! stopword list, and custom profiles can be registered on top (a domain
+//! stopword list, a language the built-ins don't cover).
+//!
+//! [`LanguageRegistry::tokenize`] picks the profile for a document — the
+//! detected language when [`TextRankConfig::detect_language`] is set and
+//! the `lang-detect` feature is enabled, `cfg.language` otherwise — so one
+//! pipeline instance can serve a multilingual corpus:
+//!
+//! ```
+//! use rapid_textrank::nlp::language::{LanguageProfile, LanguageRegistry};
+//! use rapid_textrank::TextRankConfig;
+//!
+//! let registry = LanguageRegistry::default()
+//!     .with_profile(LanguageProfile::built_in("en").with_extra_stopwords(["figure"]));
+//! let (tokens, cfg) = registry.tokenize("See figure two.", &TextRankConfig::default());
+//! assert!(tokens.iter().find(|t| t.text == "figure").unwrap().is_stopword);
+//! assert_eq!(cfg.language, "en");
+//! ```
+
+use crate::nlp::lemma::Lemmatizer;
+use crate::nlp::stopwords::StopwordFilter;
+use crate::nlp::token_filter::JunkTokenFilter;
+use crate::nlp::tokenizer::Tokenizer;
+use crate::nlp::variants::link_variants;
+use crate::types::{LemmaStrategy, PosTag, StemLanguage, TextRankConfig, Token};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Language codes with a built-in profile.
+pub const BUILT_IN_LANGUAGES: &[&str] = &[
+    "en", "de", "fr", "es", "it", "pt", "nl", "ru", "sv", "no", "da", "fi", "hu", "tr", "pl", "ar",
+    "zh", "ja",
+];
+
+/// Tokenizer settings that vary by language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TokenizerRules {
+    /// Minimum token length in characters (default: 1).
+    pub min_token_length: usize,
+    /// Tag words with the English suffix heuristics (default: true). When
+    /// off, words become candidates by a stopword + length filter instead;
+    /// see [`Tokenizer::with_pos_heuristics`].
+    pub pos_heuristics: bool,
+}
+
+impl Default for TokenizerRules {
+    fn default() -> Self {
+        Self {
+            min_token_length: 1,
+            pos_heuristics: true,
+        }
+    }
+}
+
+/// The default POS filter for `code`: the config default for English,
+/// all content tags otherwise, since the heuristic tags are English suffix
+/// rules and other languages rely on stopwords instead.
+pub(crate) fn built_in_include_pos(code: &str) -> Vec<PosTag> {
+    if code == "en" {
+        TextRankConfig::default().include_pos
+    } else {
+        vec![
+            PosTag::Noun,
+            PosTag::ProperNoun,
+            PosTag::Adjective,
+            PosTag::Verb,
+            PosTag::Adverb,
+        ]
+    }
+}
+
+/// Everything language-specific about preparing a document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanguageProfile {
+    /// Language code, stored as [`TextRankConfig::language`] when applied.
+    pub code: String,
+    /// Full stopword list (`cfg.stopwords` are added on top).
+    pub stopwords: Vec<String>,
+    /// Stemmer used when the config asks for [`LemmaStrategy::Stem`].
+    pub stemmer: Option<StemLanguage>,
+    /// POS filter used while the config's `include_pos` is the default.
+    pub include_pos: Vec<PosTag>,
+    /// Tokenizer settings.
+    pub tokenizer: TokenizerRules,
+}
+
+impl LanguageProfile {
+    /// An empty profile: no stopwords, no stemmer, the default POS filter
+    /// and tokenizer rules.
+    pub fn new(code: impl Into<String>) -> Self {
+        Self {
+            code: code.into().to_lowercase(),
+            stopwords: Vec::new(),
+            stemmer: None,
+            include_pos: TextRankConfig::default().include_pos,
+            tokenizer: TokenizerRules::default(),
+        }
+    }
+
+    /// The built-in profile for `code`: its stopword list and Snowball
+    /// stemmer, and for languages other than English a POS filter widened
+    /// to all content tags, since the heuristic tags are English suffix
+    /// rules.
+    ///
+    /// Unknown codes get the English stopword list, as
+    /// [`StopwordFilter::new`] does.
+    pub fn built_in(code: &str) -> Self {
+        let code = code.to_lowercase();
+        Self {
+            stopwords: StopwordFilter::built_in_list(&code),
+            stemmer: StemLanguage::from_code(&code),
+            include_pos: built_in_include_pos(&code),
+            tokenizer: TokenizerRules::default(),
+            code,
+        }
+    }
+
+    /// Replace the stopword list.
+    pub fn with_stopwords<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.stopwords = words.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Add words to the stopword list.
+    pub fn with_extra_stopwords<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.stopwords.extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set the stemmer.
+    pub fn with_stemmer(mut self, stemmer: Option<StemLanguage>) -> Self {
+        self.stemmer = stemmer;
+        self
+    }
+
+    /// Set the default POS filter.
+    pub fn with_include_pos(mut self, include_pos: Vec<PosTag>) -> Self {
+        self.include_pos = include_pos;
+        self
+    }
+
+    /// Set the tokenizer rules.
+    pub fn with_tokenizer_rules(mut self, rules: TokenizerRules) -> Self {
+        self.tokenizer = rules;
+        self
+    }
+
+    /// `cfg` adjusted for this language.
+    ///
+    /// - `language` becomes this profile's code.
+    /// - A [`LemmaStrategy::Stem`] strategy switches to this profile's
+    ///   stemmer, when it has one. Other strategies are left alone.
+    /// - `include_pos` becomes this profile's, but only while it still has
+    ///   its default value: a customised `include_pos` always wins.
+    pub fn apply(&self, cfg: &TextRankConfig) -> TextRankConfig {
+        let mut out = cfg.clone();
+        out.language = self.code.clone();
+        if let (LemmaStrategy::Stem(_), Some(stemmer)) = (cfg.lemma_strategy, self.stemmer) {
+            out.lemma_strategy = LemmaStrategy::Stem(stemmer);
+        }
+        if cfg.include_pos == TextRankConfig::default().include_pos {
+            out.include_pos = self.include_pos.clone();
+        }
+        out
+    }
+
+    /// Stopword filter with this profile's list plus `cfg.stopwords`, minus
+    /// `cfg.keep_terms`.
+    pub fn stopword_filter(&self, cfg: &TextRankConfig) -> StopwordFilter {
+        let mut filter = StopwordFilter::from_strings(&self.stopwords);
+        let extra: Vec<&str> = cfg.stopwords.iter().map(String::as_str).collect();
+        filter.add_stopwords(&extra);
+        let keep: Vec<&str> = cfg.keep_terms.iter().map(String::as_str).collect();
+        filter.remove_stopwords(&keep);
+        filter
+    }
+
+    /// Tokenizer following this profile's rules.
+    pub fn tokenizer(&self) -> Tokenizer {
+        Tokenizer::new()
+            .with_min_length(self.tokenizer.min_token_length)
+            .with_pos_heuristics(self.tokenizer.pos_heuristics)
+    }
+
+    /// Tokenize `text` with this profile, as
+    /// [`Tokenizer::tokenize_with_config`] does with `cfg` (pass a config
+    /// already [`apply`](Self::apply)-ed to this profile).
+    pub fn tokenize(&self, text: &str, cfg: &TextRankConfig) -> Vec<Token> {
+        let mut tokens = self.tokenizer().tokenize_with(
+            text,
+            &Lemmatizer::for_config(cfg),
+            &self.stopword_filter(cfg),
+        );
+        JunkTokenFilter::for_config(cfg).apply(&mut tokens);
+        link_variants(text, &mut tokens, cfg.variant_linking);
+        tokens
+    }
+}
+
+/// Language profiles by code.
+///
+/// [`Default`] holds the built-in profiles ([`BUILT_IN_LANGUAGES`]);
+/// [`new`](Self::new) starts empty. Codes are case-insensitive.
+#[derive(Debug, Clone)]
+pub struct LanguageRegistry {
+    profiles: FxHashMap<String, LanguageProfile>,
+}
+
+impl Default for LanguageRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        for code in BUILT_IN_LANGUAGES {
+            registry.register(LanguageProfile::built_in(code));
+        }
+        registry
+    }
+}
+
+impl LanguageRegistry {
+    /// An empty registry. Lookups of unregistered codes still fall back to
+    /// [`LanguageProfile::built_in`].
+    pub fn new() -> Self {
+        Self {
+            profiles: FxHashMap::default(),
+        }
+    }
+
+    /// Register `profile`, replacing any profile with the same code.
+    /// Returns the replaced profile.
+    pub fn register(&mut self, profile: LanguageProfile) -> Option<LanguageProfile> {
+        self.profiles.insert(profile.code.to_lowercase(), profile)
+    }
+
+    /// Builder form of [`register`](Self::register).
+    pub fn with_profile(mut self, profile: LanguageProfile) -> Self {
+        self.register(profile);
+        self
+    }
+
+    /// The profile registered for `code`.
+    pub fn get(&self, code: &str) -> Option<&LanguageProfile> {
+        self.profiles.get(&code.to_lowercase())
+    }
+
+    /// Registered codes, sorted.
+    pub fn codes(&self) -> Vec<&str> {
+        let mut codes: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+        codes.sort_unstable();
+        codes
+    }
+
+    /// Number of registered profiles.
+    pub fn len(&self) -> usize {
+        self.profiles.len()
+    }
+
+    /// Whether no profiles are registered.
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+
+    /// The profile for `code`: the registered one, or the built-in one.
+    pub fn profile(&self, code: &str) -> Cow<'_, LanguageProfile> {
+        match self.get(code) {
+            Some(profile) => Cow::Borrowed(profile),
+            None => Cow::Owned(LanguageProfile::built_in(code)),
+        }
+    }
+
+    /// The profile for `text`: its detected language when
+    /// `cfg.detect_language` is set (with the `lang-detect` feature) and
+    /// detection succeeds, `cfg.language` otherwise.
+    pub fn profile_for_text(&self, text: &str, cfg: &TextRankConfig) -> Cow<'_, LanguageProfile> {
+        #[cfg(feature = "lang-detect")]
+        if cfg.detect_language {
+            if let Some(detected) = crate::nlp::langdetect::detect_language(text) {
+                return self.profile(detected.code);
+            }
+        }
+        #[cfg(not(feature = "lang-detect"))]
+        let _ = text;
+        self.profile(&cfg.language)
+    }
+
+    /// Tokenize `text` with the profile chosen by
+    /// [`profile_for_text`](Self::profile_for_text), returning the tokens
+    /// and the config adjusted to that profile (run the pipeline with it).
+    pub fn tokenize(&self, text: &str, cfg: &TextRankConfig) -> (Vec<Token>, TextRankConfig) {
+        let profile = self.profile_for_text(text, cfg);
+        let cfg = profile.apply(cfg);
+        (profile.tokenize(text, &cfg), cfg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_built_in_profiles() {
+        let registry = LanguageRegistry::default();
+        assert_eq!(registry.len(), BUILT_IN_LANGUAGES.len());
+        let de = registry.get("DE").unwrap();
+        assert_eq!(de.stemmer, Some(StemLanguage::German));
+        assert!(de.stopwords.iter().any(|w| w == "und"));
+        assert!(de.include_pos.contains(&PosTag::Adverb));
+        assert_eq!(
+            registry.get("en").unwrap().include_pos,
+            TextRankConfig::default().include_pos
+        );
+        // Unregistered codes fall back to the built-in profile.
+        assert_eq!(LanguageRegistry::new().profile("fr").code, "fr");
+    }
+
+    #[test]
+    fn test_apply_keeps_explicit_settings() {
+        let profile = LanguageProfile::built_in("de");
+        let cfg = TextRankConfig::default();
+        let applied = profile.apply(&cfg);
+        assert_eq!(applied.language, "de");
+        assert_eq!(applied.lemma_strategy, cfg.lemma_strategy);
+        assert_eq!(applied.include_pos, profile.include_pos);
+
+        let custom = TextRankConfig {
+            include_pos: vec![PosTag::Noun],
+            lemma_strategy: LemmaStrategy::Stem(StemLanguage::English),
+            ..TextRankConfig::default()
+        };
+        let applied = profile.apply(&custom);
+        assert_eq!(applied.include_pos, [PosTag::Noun]);
+        assert_eq!(
+            applied.lemma_strategy,
+            LemmaStrategy::Stem(StemLanguage::German)
+        );
+    }
+
+    #[test]
+    fn test_custom_profile_tokenization() {
+        let registry = LanguageRegistry::new().with_profile(
+            LanguageProfile::new("legal")
+                .with_stopwords(["the", "of", "plaintiff"])
+                .with_tokenizer_rules(TokenizerRules {
+                    min_token_length: 3,
+                    pos_heuristics: false,
+                }),
+        );
+        let cfg = TextRankConfig {
+            language: "legal".to_string(),
+            stopwords: vec!["court".to_string()],
+            ..TextRankConfig::default()
+        };
+        let (tokens, resolved) = registry.tokenize("The plaintiff of record at court.", &cfg);
+        assert_eq!(resolved.language, "legal");
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, ["The", "plaintiff", "record", "court"]);
+        let stop: Vec<bool> = tokens.iter().map(|t| t.is_stopword).collect();
+        assert_eq!(stop, [true, true, false, true]);
+        // Untagged words become noun candidates via the fallback filter.
+        assert_eq!(tokens[2].pos, PosTag::Noun);
+    }
+}
@@ -0,0 +1,202 @@
+//! Lemma derivation
+//!
+//! Applies a [`LemmaStrategy`] to tokens that arrive without (or with
+//! unwanted) lemmas, followed by the configured [`TextNormalization`].
+//! Snowball stemming is provided by `rust-stemmers` behind the `stemming`
+//! feature; without it, [`TextRankConfig::validate`] rejects `Stem`, and a
+//! `Lemmatizer` built for it directly only lowercases.
+
+use crate::nlp::normalize::normalize_text;
+#[cfg(feature = "stemming")]
+use crate::types::StemLanguage;
+use crate::types::{LemmaStrategy, TextNormalization, TextRankConfig, Token};
+use std::borrow::Cow;
+
+/// Derives token lemmas according to a [`LemmaStrategy`]
+pub struct Lemmatizer {
+    strategy: LemmaStrategy,
+    normalization: TextNormalization,
+    #[cfg(feature = "stemming")]
+    stemmer: Option<rust_stemmers::Stemmer>,
+}
+
+impl std::fmt::Debug for Lemmatizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lemmatizer")
+            .field("strategy", &self.strategy)
+            .field("normalization", &self.normalization)
+            .finish()
+    }
+}
+
+impl Lemmatizer {
+    /// Create a lemmatizer for the given strategy
+    pub fn new(strategy: LemmaStrategy) -> Self {
+        Self {
+            strategy,
+            normalization: TextNormalization::default(),
+            #[cfg(feature = "stemming")]
+            stemmer: match strategy {
+                LemmaStrategy::Stem(lang) => Some(rust_stemmers::Stemmer::create(algorithm(lang))),
+                _ => None,
+            },
+        }
+    }
+
+    /// Create a lemmatizer from `cfg.lemma_strategy` and `cfg.normalization`
+    pub fn for_config(cfg: &TextRankConfig) -> Self {
+        Self::new(cfg.lemma_strategy).with_normalization(cfg.normalization)
+    }
+
+    /// Normalize lemmas after deriving them
+    pub fn with_normalization(mut self, normalization: TextNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Get the strategy
+    pub fn strategy(&self) -> LemmaStrategy {
+        self.strategy
+    }
+
+    /// Returns `true` when every lemma is kept as provided.
+    pub fn is_identity(&self) -> bool {
+        self.strategy == LemmaStrategy::AsProvided && self.normalization.is_identity()
+    }
+
+    /// Derive the lemma for a token from its surface form and the lemma it
+    /// was provided with.
+    pub fn lemma(&self, text: &str, provided: &str) -> String {
+        match self.strategy {
+            LemmaStrategy::AsProvided => normalize_text(provided, self.normalization).into_owned(),
+            LemmaStrategy::Lowercase => normalize_text(text, self.normalization).to_lowercase(),
+            LemmaStrategy::Stem(_) => {
+                let lower = normalize_text(text, self.normalization).to_lowercase();
+                #[cfg(feature = "stemming")]
+                if let Some(stemmer) = &self.stemmer {
+                    return stemmer.stem(&lower).into_owned();
+                }
+                lower
+            }
+        }
+    }
+
+    /// Like [`lemma`](Self::lemma), borrowing from `text` or `provided`
+    /// when the lemma is unchanged from either.
+    pub fn lemma_cow<'a>(&self, text: &'a str, provided: Cow<'a, str>) -> Cow<'a, str> {
+        match self.strategy {
+            LemmaStrategy::AsProvided if self.normalization.is_identity() => provided,
+            LemmaStrategy::Lowercase => match normalize_text(text, self.normalization) {
+                Cow::Borrowed(normalized) => lowercase(normalized),
+                Cow::Owned(normalized) => Cow::Owned(normalized.to_lowercase()),
+            },
+            _ => Cow::Owned(self.lemma(text, &provided)),
+        }
+    }
+
+    /// Overwrite `Token::lemma` for every token
+    pub fn apply(&self, tokens: &mut [Token]) {
+        if self.is_identity() {
+            return;
+        }
+        for token in tokens {
+            token.lemma = self.lemma(&token.text, &token.lemma);
+        }
+    }
+}
+
+/// `s.to_lowercase()`, borrowing `s` when it is already lowercase
+pub(crate) fn lowercase(s: &str) -> Cow<'_, str> {
+    if s.chars().all(|c| c.to_lowercase().eq(std::iter::once(c))) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(s.to_lowercase())
+    }
+}
+
+#[cfg(feature = "stemming")]
+fn algorithm(lang: StemLanguage) -> rust_stemmers::Algorithm {
+    use rust_stemmers::Algorithm;
+    match lang {
+        StemLanguage::Arabic => Algorithm::Arabic,
+        StemLanguage::Danish => Algorithm::Danish,
+        StemLanguage::Dutch => Algorithm::Dutch,
+        StemLanguage::English => Algorithm::English,
+        StemLanguage::Finnish => Algorithm::Finnish,
+        StemLanguage::French => Algorithm::French,
+        StemLanguage::German => Algorithm::German,
+        StemLanguage::Greek => Algorithm::Greek,
+        StemLanguage::Hungarian => Algorithm::Hungarian,
+        StemLanguage::Italian => Algorithm::Italian,
+        StemLanguage::Norwegian => Algorithm::Norwegian,
+        StemLanguage::Portuguese => Algorithm::Portuguese,
+        StemLanguage::Romanian => Algorithm::Romanian,
+        StemLanguage::Russian => Algorithm::Russian,
+        StemLanguage::Spanish => Algorithm::Spanish,
+        StemLanguage::Swedish => Algorithm::Swedish,
+        StemLanguage::Tamil => Algorithm::Tamil,
+        StemLanguage::Turkish => Algorithm::Turkish,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PosTag, StemLanguage};
+
+    #[test]
+    fn test_as_provided_keeps_lemmas() {
+        let mut tokens = vec![Token::new("Running", "run", PosTag::Verb, 0, 7, 0, 0)];
+        Lemmatizer::new(LemmaStrategy::AsProvided).apply(&mut tokens);
+        assert_eq!(tokens[0].lemma, "run");
+    }
+
+    #[test]
+    fn test_lowercase() {
+        let lemmatizer = Lemmatizer::new(LemmaStrategy::Lowercase);
+        assert_eq!(lemmatizer.lemma("Networks", "network"), "networks");
+    }
+
+    #[cfg(feature = "stemming")]
+    #[test]
+    fn test_stem_english() {
+        let lemmatizer = Lemmatizer::new(LemmaStrategy::Stem(StemLanguage::English));
+        assert_eq!(lemmatizer.lemma("Connections", "_"), "connect");
+        assert_eq!(
+            lemmatizer.lemma("connected", "_"),
+            lemmatizer.lemma("connecting", "_")
+        );
+    }
+
+    #[cfg(not(feature = "stemming"))]
+    #[test]
+    fn test_stem_without_feature_lowercases() {
+        let lemmatizer = Lemmatizer::new(LemmaStrategy::Stem(StemLanguage::English));
+        assert_eq!(lemmatizer.lemma("Connections", "_"), "connections");
+    }
+
+    #[test]
+    fn test_normalization_applies_to_provided_lemmas() {
+        let normalization = TextNormalization {
+            unicode_form: crate::types::UnicodeForm::Nfc,
+            strip_diacritics: true,
+            ..TextNormalization::default()
+        };
+        let mut tokens = vec![
+            Token::new("café", "caf\u{e9}", PosTag::Noun, 0, 5, 0, 0),
+            Token::new("café", "cafe\u{301}", PosTag::Noun, 6, 12, 0, 1),
+        ];
+        Lemmatizer::new(LemmaStrategy::AsProvided)
+            .with_normalization(normalization)
+            .apply(&mut tokens);
+        assert_eq!(tokens[0].lemma, "cafe");
+        assert_eq!(tokens[1].lemma, "cafe");
+    }
+
+    #[test]
+    fn test_stem_language_from_code() {
+        assert_eq!(StemLanguage::from_code("EN"), Some(StemLanguage::English));
+        assert_eq!(StemLanguage::from_code("nb"), Some(StemLanguage::Norwegian));
+        assert_eq!(StemLanguage::from_code("xx"), None);
+    }
+}
@@ -1,6 +1,23 @@
 //! Natural Language Processing components
 //!
-//! This module provides tokenization and stopword filtering.
+//! This module provides tokenization, stopword and junk-token filtering,
+//! lemma derivation,
+//! Unicode normalization, acronym and hyphenation variant linking, corpus
+//! statistics, per-language profiles, and (with the `lang-detect` feature)
+//! language detection.
 
+pub mod cjk;
+pub mod corpus;
+#[cfg(feature = "embeddings")]
+pub mod embeddings;
+#[cfg(feature = "lang-detect")]
+pub mod langdetect;
+pub mod language;
+pub mod lemma;
+pub mod normalize;
+#[cfg(feature = "pos-tagger")]
+pub mod pos_tagger;
 pub mod stopwords;
+pub mod token_filter;
 pub mod tokenizer;
+pub mod variants;
@@ -0,0 +1,85 @@
+//! Unicode normalization and case folding
+//!
+//! Implements [`TextNormalization`]: case folding first, then diacritic
+//! stripping (NFD, drop combining marks, recompose), then the requested
+//! normalization form.
+
+use crate::types::{CaseFolding, TextNormalization, UnicodeForm};
+use std::borrow::Cow;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Normalize a string according to `options`
+pub fn normalize_text(text: &str, options: TextNormalization) -> Cow<'_, str> {
+    if options.is_identity() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out: Cow<'_, str> = match options.case_folding {
+        CaseFolding::None => Cow::Borrowed(text),
+        CaseFolding::Ascii => Cow::Owned(text.to_ascii_lowercase()),
+        CaseFolding::Full => Cow::Owned(caseless::default_case_fold_str(text)),
+    };
+
+    if options.strip_diacritics {
+        out = Cow::Owned(out.nfd().filter(|c| !is_combining_mark(*c)).nfc().collect());
+    }
+
+    match options.unicode_form {
+        UnicodeForm::None => out,
+        UnicodeForm::Nfc => Cow::Owned(out.nfc().collect()),
+        UnicodeForm::Nfkc => Cow::Owned(out.nfkc().collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opts(form: UnicodeForm, case: CaseFolding, strip: bool) -> TextNormalization {
+        TextNormalization {
+            unicode_form: form,
+            case_folding: case,
+            strip_diacritics: strip,
+        }
+    }
+
+    #[test]
+    fn test_identity_borrows() {
+        let out = normalize_text("Café", TextNormalization::default());
+        assert!(matches!(out, Cow::Borrowed("Café")));
+    }
+
+    #[test]
+    fn test_nfc_merges_decomposed_forms() {
+        let composed = "caf\u{e9}";
+        let decomposed = "cafe\u{301}";
+        let nfc = opts(UnicodeForm::Nfc, CaseFolding::None, false);
+        assert_eq!(
+            normalize_text(composed, nfc),
+            normalize_text(decomposed, nfc)
+        );
+    }
+
+    #[test]
+    fn test_nfkc_folds_compatibility_forms() {
+        let nfkc = opts(UnicodeForm::Nfkc, CaseFolding::None, false);
+        assert_eq!(normalize_text("\u{fb01}le", nfkc), "file");
+        assert_eq!(normalize_text("ＡＢＣ", nfkc), "ABC");
+    }
+
+    #[test]
+    fn test_case_folding_modes() {
+        let ascii = opts(UnicodeForm::None, CaseFolding::Ascii, false);
+        let full = opts(UnicodeForm::None, CaseFolding::Full, false);
+        assert_eq!(normalize_text("Straße ÉTÉ", ascii), "straße ÉtÉ");
+        assert_eq!(normalize_text("Straße", full), "strasse");
+    }
+
+    #[test]
+    fn test_strip_diacritics() {
+        let strip = opts(UnicodeForm::Nfc, CaseFolding::Full, true);
+        assert_eq!(normalize_text("Crème Brûlée", strip), "creme brulee");
+        assert_eq!(normalize_text("cafe\u{301}", strip), "cafe");
+    }
+}
@@ -0,0 +1,423 @@
+//! Averaged-perceptron part-of-speech tagger
+//!
+//! A greedy left-to-right tagger in the style of Honnibal's
+//! "A Good Part-of-Speech Tagger in about 200 Lines of Python": each word is
+//! scored with a linear model over surrounding words, affixes, and the two
+//! previous predicted tags. Weights are averaged over all training updates.
+//!
+//! No model is bundled; train one from annotated tokens (for example a UD
+//! treebank read with [`parse_conllu`](crate::io::conllu::parse_conllu)) and
+//! persist it with [`PerceptronTagger::save`].
+
+use crate::errors::Result;
+use crate::types::{PosTag, Token, TokenRef};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Tag set in weight-vector order
+const CLASSES: [PosTag; 15] = [
+    PosTag::Noun,
+    PosTag::Verb,
+    PosTag::Adjective,
+    PosTag::Adverb,
+    PosTag::Pronoun,
+    PosTag::Determiner,
+    PosTag::Preposition,
+    PosTag::Conjunction,
+    PosTag::Interjection,
+    PosTag::Numeral,
+    PosTag::Particle,
+    PosTag::Punctuation,
+    PosTag::Symbol,
+    PosTag::ProperNoun,
+    PosTag::Other,
+];
+
+const START: [&str; 2] = ["-START-", "-START2-"];
+const END: [&str; 2] = ["-END-", "-END2-"];
+
+/// Words seen at least this often with a single dominant tag skip scoring
+const TAGDICT_MIN_FREQ: usize = 20;
+const TAGDICT_MIN_RATIO: f64 = 0.97;
+
+/// Averaged-perceptron POS tagger
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct PerceptronTagger {
+    /// Feature → per-class weights (indexed like `CLASSES`)
+    weights: FxHashMap<String, Vec<f64>>,
+    /// Frequent, unambiguous words tagged without scoring
+    tagdict: FxHashMap<String, PosTag>,
+}
+
+impl std::fmt::Debug for PerceptronTagger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PerceptronTagger")
+            .field("features", &self.weights.len())
+            .field("tagdict", &self.tagdict.len())
+            .finish()
+    }
+}
+
+impl PerceptronTagger {
+    /// Create an untrained tagger
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check whether the tagger has no model
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty() && self.tagdict.is_empty()
+    }
+
+    /// Train a tagger from annotated tokens.
+    ///
+    /// Sentences are delimited by `Token::sentence_idx`; `Token::pos` is the
+    /// gold tag. Training is deterministic (no shuffling between passes).
+    pub fn train(tokens: &[Token], iterations: usize) -> Self {
+        let sentences: Vec<&[Token]> = sentence_runs(tokens).collect();
+        let mut tagger = Self {
+            weights: FxHashMap::default(),
+            tagdict: build_tagdict(tokens),
+        };
+        let mut trainer = Trainer::default();
+
+        for _ in 0..iterations {
+            for sentence in &sentences {
+                let words: Vec<&str> = sentence.iter().map(|t| t.text.as_str()).collect();
+                let context = context(&words);
+                let (mut prev, mut prev2) = (START[0], START[1]);
+                for (i, token) in sentence.iter().enumerate() {
+                    let guess = match tagger.tagdict.get(&normalize(words[i])) {
+                        Some(&tag) => tag,
+                        None => {
+                            let feats = features(i, words[i], &context, prev, prev2);
+                            let guess = tagger.predict(&feats);
+                            trainer.update(&mut tagger.weights, token.pos, guess, &feats);
+                            guess
+                        }
+                    };
+                    prev2 = prev;
+                    prev = guess.as_str();
+                }
+            }
+        }
+
+        trainer.average(&mut tagger.weights);
+        tagger
+    }
+
+    /// Tag a sentence of words
+    pub fn tag_words(&self, words: &[&str]) -> Vec<PosTag> {
+        let context = context(words);
+        let (mut prev, mut prev2) = (START[0], START[1]);
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                let tag = match self.tagdict.get(&normalize(word)) {
+                    Some(&tag) => tag,
+                    None => self.predict(&features(i, word, &context, prev, prev2)),
+                };
+                prev2 = prev;
+                prev = tag.as_str();
+                tag
+            })
+            .collect()
+    }
+
+    /// Overwrite `Token::pos` sentence by sentence
+    pub fn tag_tokens(&self, tokens: &mut [Token]) {
+        self.tag_sentences(tokens, |t| (t.sentence_idx, &t.text), |t, tag| t.pos = tag);
+    }
+
+    /// [`tag_tokens`](Self::tag_tokens) for borrowed tokens
+    pub fn tag_token_refs(&self, tokens: &mut [TokenRef<'_>]) {
+        self.tag_sentences(tokens, |t| (t.sentence_idx, t.text), |t, tag| t.pos = tag);
+    }
+
+    fn tag_sentences<T>(
+        &self,
+        tokens: &mut [T],
+        word: impl Fn(&T) -> (usize, &str),
+        set_pos: impl Fn(&mut T, PosTag),
+    ) {
+        let mut start = 0;
+        while start < tokens.len() {
+            let sentence_idx = word(&tokens[start]).0;
+            let end = tokens[start..]
+                .iter()
+                .position(|t| word(t).0 != sentence_idx)
+                .map_or(tokens.len(), |n| start + n);
+            let words: Vec<&str> = tokens[start..end].iter().map(|t| word(t).1).collect();
+            let tags = self.tag_words(&words);
+            for (token, tag) in tokens[start..end].iter_mut().zip(tags) {
+                set_pos(token, tag);
+            }
+            start = end;
+        }
+    }
+
+    /// Serialize the model to JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Load a model from JSON
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Write the model to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+
+    /// Load a model from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    fn predict(&self, features: &[String]) -> PosTag {
+        let mut scores = [0.0; CLASSES.len()];
+        for feat in features {
+            if let Some(weights) = self.weights.get(feat) {
+                for (score, w) in scores.iter_mut().zip(weights) {
+                    *score += w;
+                }
+            }
+        }
+        let best = scores
+            .iter()
+            .enumerate()
+            .fold(0, |best, (i, &s)| if s > scores[best] { i } else { best });
+        CLASSES[best]
+    }
+}
+
+/// Accumulators for weight averaging
+#[derive(Default)]
+struct Trainer {
+    totals: FxHashMap<(String, usize), f64>,
+    tstamps: FxHashMap<(String, usize), usize>,
+    instances: usize,
+}
+
+impl Trainer {
+    fn update(
+        &mut self,
+        weights: &mut FxHashMap<String, Vec<f64>>,
+        truth: PosTag,
+        guess: PosTag,
+        features: &[String],
+    ) {
+        self.instances += 1;
+        if truth == guess {
+            return;
+        }
+        let (truth, guess) = (class_index(truth), class_index(guess));
+        for feat in features {
+            let w = weights
+                .entry(feat.clone())
+                .or_insert_with(|| vec![0.0; CLASSES.len()]);
+            for (class, delta) in [(truth, 1.0), (guess, -1.0)] {
+                let key = (feat.clone(), class);
+                let since = self.instances - self.tstamps.get(&key).copied().unwrap_or(0);
+                *self.totals.entry(key.clone()).or_insert(0.0) += since as f64 * w[class];
+                self.tstamps.insert(key, self.instances);
+                w[class] += delta;
+            }
+        }
+    }
+
+    fn average(&self, weights: &mut FxHashMap<String, Vec<f64>>) {
+        if self.instances == 0 {
+            return;
+        }
+        for (feat, w) in weights.iter_mut() {
+            for (class, weight) in w.iter_mut().enumerate() {
+                let key = (feat.clone(), class);
+                let since = self.instances - self.tstamps.get(&key).copied().unwrap_or(0);
+                let total = self.totals.get(&key).copied().unwrap_or(0.0) + since as f64 * *weight;
+                *weight = total / self.instances as f64;
+            }
+        }
+        weights.retain(|_, w| w.iter().any(|&x| x != 0.0));
+    }
+}
+
+fn class_index(tag: PosTag) -> usize {
+    CLASSES
+        .iter()
+        .position(|&c| c == tag)
+        .unwrap_or(CLASSES.len() - 1)
+}
+
+/// Split tokens into runs sharing a `sentence_idx`
+fn sentence_runs(tokens: &[Token]) -> impl Iterator<Item = &[Token]> {
+    tokens.chunk_by(|a, b| a.sentence_idx == b.sentence_idx)
+}
+
+fn build_tagdict(tokens: &[Token]) -> FxHashMap<String, PosTag> {
+    let mut counts: FxHashMap<String, FxHashMap<PosTag, usize>> = FxHashMap::default();
+    for token in tokens {
+        *counts
+            .entry(normalize(&token.text))
+            .or_default()
+            .entry(token.pos)
+            .or_insert(0) += 1;
+    }
+
+    counts
+        .into_iter()
+        .filter_map(|(word, tags)| {
+            let total: usize = tags.values().sum();
+            let (&tag, &count) = tags
+                .iter()
+                .max_by_key(|(tag, &count)| (count, std::cmp::Reverse(class_index(**tag))))?;
+            (total >= TAGDICT_MIN_FREQ && count as f64 / total as f64 >= TAGDICT_MIN_RATIO)
+                .then_some((word, tag))
+        })
+        .collect()
+}
+
+/// Normalize a word for feature lookup
+fn normalize(word: &str) -> String {
+    let mut chars = word.chars();
+    let first = chars.next();
+    if word.contains('-') && first != Some('-') {
+        "!HYPHEN".to_string()
+    } else if word.len() == 4 && word.chars().all(|c| c.is_ascii_digit()) {
+        "!YEAR".to_string()
+    } else if first.is_some_and(|c| c.is_ascii_digit()) {
+        "!DIGITS".to_string()
+    } else {
+        word.to_lowercase()
+    }
+}
+
+/// Normalized words padded with start/end markers
+fn context(words: &[&str]) -> Vec<String> {
+    START
+        .iter()
+        .map(|s| s.to_string())
+        .chain(words.iter().map(|w| normalize(w)))
+        .chain(END.iter().map(|s| s.to_string()))
+        .collect()
+}
+
+fn suffix(word: &str) -> &str {
+    let start = word.char_indices().rev().nth(2).map_or(0, |(i, _)| i);
+    &word[start..]
+}
+
+fn features(i: usize, word: &str, context: &[String], prev: &str, prev2: &str) -> Vec<String> {
+    let i = i + START.len();
+    let prefix: String = word.chars().take(1).collect();
+    vec![
+        "bias".to_string(),
+        format!("i suffix {}", suffix(word)),
+        format!("i pref1 {}", prefix),
+        format!("i-1 tag {}", prev),
+        format!("i-2 tag {}", prev2),
+        format!("i tag+i-2 tag {} {}", prev, prev2),
+        format!("i word {}", context[i]),
+        format!("i-1 tag+i word {} {}", prev, context[i]),
+        format!("i-1 word {}", context[i - 1]),
+        format!("i-1 suffix {}", suffix(&context[i - 1])),
+        format!("i-2 word {}", context[i - 2]),
+        format!("i+1 word {}", context[i + 1]),
+        format!("i+1 suffix {}", suffix(&context[i + 1])),
+        format!("i+2 word {}", context[i + 2]),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corpus() -> Vec<Token> {
+        let sentences: [&[(&str, PosTag)]; 4] = [
+            &[
+                ("The", PosTag::Determiner),
+                ("model", PosTag::Noun),
+                ("ranks", PosTag::Verb),
+                ("keywords", PosTag::Noun),
+                (".", PosTag::Punctuation),
+            ],
+            &[
+                ("A", PosTag::Determiner),
+                ("graph", PosTag::Noun),
+                ("links", PosTag::Verb),
+                ("words", PosTag::Noun),
+                (".", PosTag::Punctuation),
+            ],
+            &[
+                ("The", PosTag::Determiner),
+                ("fast", PosTag::Adjective),
+                ("tagger", PosTag::Noun),
+                ("labels", PosTag::Verb),
+                ("tokens", PosTag::Noun),
+                (".", PosTag::Punctuation),
+            ],
+            &[
+                ("A", PosTag::Determiner),
+                ("small", PosTag::Adjective),
+                ("graph", PosTag::Noun),
+                ("ranks", PosTag::Verb),
+                ("nodes", PosTag::Noun),
+                (".", PosTag::Punctuation),
+            ],
+        ];
+
+        let mut tokens = Vec::new();
+        for (s, sentence) in sentences.iter().enumerate() {
+            for (word, pos) in sentence.iter() {
+                let idx = tokens.len();
+                tokens.push(Token::new(*word, word.to_lowercase(), *pos, 0, 0, s, idx));
+            }
+        }
+        tokens
+    }
+
+    #[test]
+    fn test_train_fits_corpus() {
+        let tokens = corpus();
+        let tagger = PerceptronTagger::train(&tokens, 5);
+        assert!(!tagger.is_empty());
+
+        let mut retagged = tokens.clone();
+        tagger.tag_tokens(&mut retagged);
+        for (gold, pred) in tokens.iter().zip(&retagged) {
+            assert_eq!(gold.pos, pred.pos, "mismatch on {}", gold.text);
+        }
+    }
+
+    #[test]
+    fn test_tag_unseen_sentence() {
+        let tagger = PerceptronTagger::train(&corpus(), 5);
+        let tags = tagger.tag_words(&["The", "small", "model", "links", "nodes", "."]);
+        assert_eq!(tags[0], PosTag::Determiner);
+        assert_eq!(tags[2], PosTag::Noun);
+        assert_eq!(tags[3], PosTag::Verb);
+        assert_eq!(tags[5], PosTag::Punctuation);
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let tagger = PerceptronTagger::train(&corpus(), 3);
+        let loaded = PerceptronTagger::from_json(&tagger.to_json().unwrap()).unwrap();
+        let words = ["A", "fast", "graph", "labels", "words", "."];
+        assert_eq!(tagger.tag_words(&words), loaded.tag_words(&words));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("Graph"), "graph");
+        assert_eq!(normalize("state-of-the-art"), "!HYPHEN");
+        assert_eq!(normalize("2024"), "!YEAR");
+        assert_eq!(normalize("3.14"), "!DIGITS");
+        assert_eq!(suffix("añadió"), "dió");
+    }
+}
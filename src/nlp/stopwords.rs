@@ -3,6 +3,7 @@
 //! This module provides multi-language stopword filtering using the `stop-words` crate
 //! with support for custom stopword lists.
 
+use crate::types::TextRankConfig;
 use rustc_hash::FxHashSet;
 use stop_words::{get, LANGUAGE};
 
@@ -71,6 +72,21 @@ impl StopwordFilter {
         }
     }
 
+    /// Create the stopword filter described by a [`TextRankConfig`]: the
+    /// built-in list for `config.language` plus any `config.stopwords`,
+    /// minus any `config.keep_terms`.
+    pub fn for_config(config: &TextRankConfig) -> Self {
+        let mut filter = if config.stopwords.is_empty() {
+            Self::new(&config.language)
+        } else {
+            Self::with_additional(&config.language, &config.stopwords)
+        };
+        for term in &config.keep_terms {
+            filter.stopwords.remove(&term.to_lowercase());
+        }
+        filter
+    }
+
     /// Return the built-in stopword list for a language (sorted).
     pub fn built_in_list(language: &str) -> Vec<String> {
         let mut list: Vec<String> = Self::load_stopwords(language).into_iter().collect();
@@ -0,0 +1,262 @@
+//! Junk-token filtering
+//!
+//! OCR output and scraped text are full of tokens that carry no meaning —
+//! page numbers, scan artifacts, part numbers — yet become graph nodes and
+//! slow everything down. [`JunkTokenFilter`] applies a [`TokenFilter`] from
+//! the config by marking matching tokens as stopwords, so they are neither
+//! graph nodes nor part of any phrase. Terms listed in
+//! `TextRankConfig::keep_terms` are never filtered.
+//!
+//! The tokenizer, batch extraction, the JSON interface and pipeline runs
+//! apply the filter automatically; callers passing their own tokens to
+//! [`extract_keyphrases`](crate::phrase::extraction::extract_keyphrases)
+//! call [`JunkTokenFilter::apply`] first.
+
+use crate::pipeline::artifacts::TokenStream;
+use crate::types::{TextRankConfig, Token, TokenFilter, TokenRef};
+use regex::Regex;
+
+/// Compiled [`TokenFilter`] of a [`TextRankConfig`]
+#[derive(Debug, Clone, Default)]
+pub struct JunkTokenFilter {
+    filter: TokenFilter,
+    exclude: Option<Regex>,
+    keep: Vec<String>,
+}
+
+impl JunkTokenFilter {
+    /// Compile `cfg.token_filter`, exempting `cfg.keep_terms`.
+    ///
+    /// An invalid `exclude_pattern` is ignored;
+    /// [`TextRankConfig::validate`] reports it.
+    pub fn for_config(cfg: &TextRankConfig) -> Self {
+        let filter = cfg.token_filter.clone();
+        let exclude = filter
+            .exclude_pattern
+            .as_deref()
+            .and_then(|re| Regex::new(re).ok());
+        let keep = if filter.is_noop() {
+            Vec::new()
+        } else {
+            cfg.keep_terms.iter().map(|t| t.to_lowercase()).collect()
+        };
+        Self {
+            filter,
+            exclude,
+            keep,
+        }
+    }
+
+    /// Returns `true` when no token is ever filtered.
+    pub fn is_identity(&self) -> bool {
+        self.filter.is_noop()
+    }
+
+    /// Whether a token with this surface form and lemma is junk.
+    pub fn is_junk(&self, text: &str, lemma: &str) -> bool {
+        if self.is_identity() {
+            return false;
+        }
+        let chars = text.chars().count();
+        let junk = self.filter.min_length.is_some_and(|min| chars < min)
+            || self.filter.max_length.is_some_and(|max| chars > max)
+            || (self.filter.drop_numerals && is_numeral(text))
+            || (self.filter.drop_alphanumeric_ids && is_alphanumeric_id(text))
+            || self.exclude.as_ref().is_some_and(|re| re.is_match(text));
+        junk && !self.is_kept(text, lemma)
+    }
+
+    fn is_kept(&self, text: &str, lemma: &str) -> bool {
+        !self.keep.is_empty()
+            && (self.keep.contains(&text.to_lowercase())
+                || self.keep.contains(&lemma.to_lowercase()))
+    }
+
+    /// Mark junk tokens as stopwords
+    pub fn apply(&self, tokens: &mut [Token]) {
+        if self.is_identity() {
+            return;
+        }
+        for token in tokens {
+            if self.is_junk(&token.text, &token.lemma) {
+                token.is_stopword = true;
+            }
+        }
+    }
+
+    /// Mark junk borrowed tokens as stopwords
+    pub fn apply_refs(&self, tokens: &mut [TokenRef<'_>]) {
+        if self.is_identity() {
+            return;
+        }
+        for token in tokens {
+            if self.is_junk(token.text, &token.lemma) {
+                token.is_stopword = true;
+            }
+        }
+    }
+
+    /// Mark junk tokens of a token stream as stopwords
+    pub fn apply_stream(&self, tokens: &mut TokenStream) {
+        if self.is_identity() {
+            return;
+        }
+        for i in 0..tokens.len() {
+            let entry = tokens.tokens()[i];
+            if self.is_junk(tokens.text(&entry), tokens.lemma(&entry)) {
+                tokens.tokens_mut()[i].is_stopword = true;
+            }
+        }
+    }
+}
+
+/// Digits with optional sign, decimal, grouping, fraction or percent
+/// punctuation, e.g. `"42"`, `"-3.5"`, `"1,000"`, `"12/31"`, `"99%"`.
+fn is_numeral(text: &str) -> bool {
+    text.chars().any(char::is_numeric)
+        && text
+            .chars()
+            .all(|c| c.is_numeric() || matches!(c, '.' | ',' | '-' | '+' | '/' | ':' | '%'))
+}
+
+/// Letters and digits mixed in one token, e.g. `"A12B"`, `"x86"`,
+/// `"INV-2024-07"`.
+fn is_alphanumeric_id(text: &str) -> bool {
+    text.chars().any(char::is_alphabetic) && text.chars().any(char::is_numeric)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PosTag;
+
+    fn filter(token_filter: TokenFilter) -> JunkTokenFilter {
+        JunkTokenFilter::for_config(&TextRankConfig::default().with_token_filter(token_filter))
+    }
+
+    #[test]
+    fn test_default_filters_nothing() {
+        let f = JunkTokenFilter::for_config(&TextRankConfig::default());
+        assert!(f.is_identity());
+        assert!(!f.is_junk("42", "42"));
+        assert!(!f.is_junk("x", "x"));
+    }
+
+    #[test]
+    fn test_length_bounds() {
+        let f = filter(TokenFilter {
+            min_length: Some(3),
+            max_length: Some(8),
+            ..Default::default()
+        });
+        assert!(f.is_junk("ab", "ab"));
+        assert!(!f.is_junk("abc", "abc"));
+        assert!(!f.is_junk("naïveté", "naïveté"));
+        assert!(f.is_junk("xqzvvkwlpt", "xqzvvkwlpt"));
+    }
+
+    #[test]
+    fn test_numerals_and_ids() {
+        let f = filter(TokenFilter {
+            drop_numerals: true,
+            ..Default::default()
+        });
+        for numeral in ["42", "3.14", "1,000", "-7", "12/31", "99%"] {
+            assert!(f.is_junk(numeral, numeral), "{numeral}");
+        }
+        assert!(!f.is_junk("A12B", "a12b"));
+        assert!(!f.is_junk("-", "-"));
+
+        let f = filter(TokenFilter {
+            drop_alphanumeric_ids: true,
+            ..Default::default()
+        });
+        assert!(f.is_junk("A12B", "a12b"));
+        assert!(f.is_junk("INV-2024-07", "inv-2024-07"));
+        assert!(!f.is_junk("42", "42"));
+        assert!(!f.is_junk("graph", "graph"));
+    }
+
+    #[test]
+    fn test_exclude_pattern_and_keep_terms() {
+        let cfg = TextRankConfig::default()
+            .with_token_filter(TokenFilter {
+                exclude_pattern: Some(r"^[|~^]+$|[^\p{L}\p{N}\-']".to_string()),
+                drop_alphanumeric_ids: true,
+                ..Default::default()
+            })
+            .with_keep_terms(["COVID19"]);
+        let f = JunkTokenFilter::for_config(&cfg);
+
+        assert!(f.is_junk("|~|", "|~|"));
+        assert!(f.is_junk("gr@ph", "gr@ph"));
+        assert!(!f.is_junk("graph", "graph"));
+        assert!(!f.is_junk("covid19", "covid19"));
+    }
+
+    #[test]
+    fn test_apply_marks_stopwords() {
+        let mut tokens = vec![
+            Token::new("Invoice", "invoice", PosTag::Noun, 0, 7, 0, 0),
+            Token::new("4471", "4471", PosTag::Noun, 8, 12, 0, 1),
+        ];
+        filter(TokenFilter {
+            drop_numerals: true,
+            ..Default::default()
+        })
+        .apply(&mut tokens);
+
+        assert!(!tokens[0].is_stopword);
+        assert!(tokens[1].is_stopword);
+    }
+
+    #[test]
+    fn test_junk_never_becomes_a_node() {
+        use crate::pipeline::runner::BaseTextRankPipeline;
+        use crate::pipeline::NoopObserver;
+
+        let text = "Invoice 4471 lists the A12B gasket. The gasket ships with invoice 4471.";
+        let cfg = TextRankConfig::default()
+            .with_top_n(0)
+            .with_token_filter(TokenFilter {
+                drop_numerals: true,
+                drop_alphanumeric_ids: true,
+                ..Default::default()
+            });
+        let has_junk = |phrases: &[crate::types::Phrase]| {
+            phrases
+                .iter()
+                .any(|p| p.text.contains("4471") || p.text.contains("A12B"))
+        };
+
+        let plain = crate::phrase::extraction::extract_from_text(text, &TextRankConfig::default());
+        assert!(has_junk(&plain));
+        let phrases = crate::phrase::extraction::extract_from_text(text, &cfg);
+        assert!(!phrases.is_empty());
+        assert!(!has_junk(&phrases));
+
+        // Pipelines filter pre-tokenized input too.
+        let tokens = crate::nlp::tokenizer::Tokenizer::new()
+            .tokenize_with_config(text, &TextRankConfig::default());
+        let result = BaseTextRankPipeline::base_textrank().run(
+            TokenStream::from_tokens(&tokens),
+            &cfg,
+            &mut NoopObserver,
+        );
+        assert!(!result.phrases.is_empty());
+        assert!(!has_junk(&result.phrases));
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_ignored_and_reported() {
+        let cfg = TextRankConfig::default().with_token_filter(TokenFilter {
+            exclude_pattern: Some("[".to_string()),
+            ..Default::default()
+        });
+        assert!(!JunkTokenFilter::for_config(&cfg).is_junk("graph", "graph"));
+        assert!(cfg
+            .validation_errors()
+            .iter()
+            .any(|e| { e.config_path().as_deref() == Some("/token_filter/exclude_pattern") }));
+    }
+}
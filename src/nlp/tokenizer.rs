@@ -3,14 +3,47 @@
 //! This module provides UAX #29 compliant word and sentence segmentation
 //! with support for CJK, emoji, contractions, and other Unicode scripts.
 
-use crate::types::{PosTag, Sentence, Token};
+#[cfg(feature = "cjk")]
+use crate::nlp::cjk::Segmenter;
+use crate::nlp::cjk::{apply_pos_fallback_refs, DEFAULT_MIN_CANDIDATE_CHARS};
+use crate::nlp::lemma::{lowercase, Lemmatizer};
+#[cfg(feature = "pos-tagger")]
+use crate::nlp::pos_tagger::PerceptronTagger;
+use crate::nlp::stopwords::StopwordFilter;
+use crate::nlp::token_filter::JunkTokenFilter;
+use crate::nlp::variants::link_variants;
+use crate::types::{PosTag, Sentence, TextRankConfig, Token, TokenRef};
+use std::borrow::Cow;
+#[cfg(any(feature = "pos-tagger", feature = "cjk"))]
+use std::sync::Arc;
 use unicode_segmentation::UnicodeSegmentation;
 
 /// A Unicode-aware tokenizer following UAX #29
-#[derive(Debug, Clone, Default)]
+#[derive(Clone, Default)]
 pub struct Tokenizer {
     /// Minimum token length to consider
     min_token_length: usize,
+    /// Leave words untagged instead of applying the English suffix heuristics
+    skip_pos_heuristics: bool,
+    /// Optional POS tagger replacing the suffix heuristics
+    #[cfg(feature = "pos-tagger")]
+    pos_tagger: Option<Arc<PerceptronTagger>>,
+    /// Optional word segmenter replacing UAX #29 word boundaries
+    #[cfg(feature = "cjk")]
+    segmenter: Option<Arc<dyn Segmenter>>,
+}
+
+impl std::fmt::Debug for Tokenizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = f.debug_struct("Tokenizer");
+        s.field("min_token_length", &self.min_token_length);
+        s.field("skip_pos_heuristics", &self.skip_pos_heuristics);
+        #[cfg(feature = "pos-tagger")]
+        s.field("pos_tagger", &self.pos_tagger);
+        #[cfg(feature = "cjk")]
+        s.field("segmenter", &self.segmenter.is_some());
+        s.finish()
+    }
 }
 
 impl Tokenizer {
@@ -18,6 +51,11 @@ impl Tokenizer {
     pub fn new() -> Self {
         Self {
             min_token_length: 1,
+            skip_pos_heuristics: false,
+            #[cfg(feature = "pos-tagger")]
+            pos_tagger: None,
+            #[cfg(feature = "cjk")]
+            segmenter: None,
         }
     }
 
@@ -27,11 +65,49 @@ impl Tokenizer {
         self
     }
 
+    /// Enable or disable the heuristic POS tags (default: enabled).
+    ///
+    /// The heuristics are English suffix rules. Disabled, words are left
+    /// untagged and [`tokenize_with_config`](Self::tokenize_with_config)
+    /// turns them into candidates with a stopword + length filter, which
+    /// suits languages the rules don't fit.
+    pub fn with_pos_heuristics(mut self, enabled: bool) -> Self {
+        self.skip_pos_heuristics = !enabled;
+        self
+    }
+
+    /// Tag tokens with a trained POS tagger instead of suffix heuristics
+    #[cfg(feature = "pos-tagger")]
+    pub fn with_pos_tagger(mut self, tagger: Arc<PerceptronTagger>) -> Self {
+        self.pos_tagger = Some(tagger);
+        self
+    }
+
+    /// Split words with a custom segmenter (e.g., for Chinese or Japanese).
+    ///
+    /// Segmented words carry no POS tag (`PosTag::Other`);
+    /// [`tokenize_with_config`](Self::tokenize_with_config) turns them into
+    /// candidates with a stopword + length filter.
+    #[cfg(feature = "cjk")]
+    pub fn with_segmenter(mut self, segmenter: Arc<dyn Segmenter>) -> Self {
+        self.segmenter = Some(segmenter);
+        self
+    }
+
     /// Tokenize text into sentences and tokens
     ///
     /// This performs basic tokenization without POS tagging.
     /// For full NLP features, use spaCy preprocessing via Python.
     pub fn tokenize(&self, text: &str) -> (Vec<Sentence>, Vec<Token>) {
+        let (sentences, tokens) = self.tokenize_borrowed(text);
+        (
+            sentences,
+            tokens.into_iter().map(TokenRef::into_owned).collect(),
+        )
+    }
+
+    /// Like [`tokenize`](Self::tokenize), with tokens borrowing from `text`
+    pub fn tokenize_borrowed<'a>(&self, text: &'a str) -> (Vec<Sentence>, Vec<TokenRef<'a>>) {
         let mut sentences = Vec::new();
         let mut tokens = Vec::new();
         let mut token_idx = 0;
@@ -45,7 +121,7 @@ impl Tokenizer {
             sentence.start_token = token_idx;
 
             // Tokenize words within sentence
-            for (word_start, word) in sent_text.unicode_word_indices() {
+            for (word_start, word, tagged) in self.words(sent_text) {
                 let abs_start = start + word_start;
                 let abs_end = abs_start + word.len();
 
@@ -60,12 +136,23 @@ impl Tokenizer {
                 }
 
                 // Create token with basic heuristic POS tagging
-                let pos = self.guess_pos(word);
-                let lemma = self.basic_lemmatize(word);
-
-                let token = Token::new(word, lemma, pos, abs_start, abs_end, sent_idx, token_idx);
-
-                tokens.push(token);
+                let pos = if tagged && !self.skip_pos_heuristics {
+                    self.guess_pos(word)
+                } else {
+                    PosTag::Other
+                };
+
+                tokens.push(TokenRef {
+                    text: word,
+                    lemma: self.basic_lemmatize(word),
+                    pos,
+                    start: abs_start,
+                    end: abs_end,
+                    sentence_idx: sent_idx,
+                    token_idx,
+                    is_stopword: false,
+                    section: None,
+                });
                 token_idx += 1;
             }
 
@@ -73,9 +160,82 @@ impl Tokenizer {
             sentences.push(sentence);
         }
 
+        #[cfg(feature = "pos-tagger")]
+        if let Some(tagger) = &self.pos_tagger {
+            tagger.tag_token_refs(&mut tokens);
+        }
+
         (sentences, tokens)
     }
 
+    /// Tokenize text and mark stopwords according to `config`
+    ///
+    /// Produces tokens ready for any extractor: stopwords are flagged using
+    /// the built-in list for `config.language` plus `config.stopwords`, and
+    /// lemmas follow `config.lemma_strategy` and `config.normalization`. Untagged tokens (from a
+    /// segmenter) fall back to a stopword + length candidate filter. Acronym
+    /// and hyphenation variants are linked per `config.variant_linking`.
+    pub fn tokenize_with_config(&self, text: &str, config: &TextRankConfig) -> Vec<Token> {
+        let mut tokens = self.tokenize_with(
+            text,
+            &Lemmatizer::for_config(config),
+            &StopwordFilter::for_config(config),
+        );
+        JunkTokenFilter::for_config(config).apply(&mut tokens);
+        link_variants(text, &mut tokens, config.variant_linking);
+        tokens
+    }
+
+    /// Like [`tokenize_with_config`](Self::tokenize_with_config), with a
+    /// prebuilt lemmatizer and stopword filter so batch callers can share
+    /// them across documents.
+    pub fn tokenize_with(
+        &self,
+        text: &str,
+        lemmatizer: &Lemmatizer,
+        stopwords: &StopwordFilter,
+    ) -> Vec<Token> {
+        self.tokenize_borrowed_with(text, lemmatizer, stopwords)
+            .into_iter()
+            .map(TokenRef::into_owned)
+            .collect()
+    }
+
+    /// Like [`tokenize_with`](Self::tokenize_with), with tokens borrowing
+    /// from `text`. Variant linking is not applied; it needs owned tokens.
+    pub fn tokenize_borrowed_with<'a>(
+        &self,
+        text: &'a str,
+        lemmatizer: &Lemmatizer,
+        stopwords: &StopwordFilter,
+    ) -> Vec<TokenRef<'a>> {
+        let (_, mut tokens) = self.tokenize_borrowed(text);
+        for token in &mut tokens {
+            if !lemmatizer.is_identity() {
+                token.lemma = lemmatizer.lemma_cow(token.text, std::mem::take(&mut token.lemma));
+            }
+            token.is_stopword = stopwords.is_stopword(token.text);
+        }
+        apply_pos_fallback_refs(&mut tokens, DEFAULT_MIN_CANDIDATE_CHARS);
+        tokens
+    }
+
+    /// Word spans within a sentence: `(byte offset, word, heuristic POS?)`
+    fn words<'a>(&self, sent_text: &'a str) -> Vec<(usize, &'a str, bool)> {
+        #[cfg(feature = "cjk")]
+        if let Some(segmenter) = &self.segmenter {
+            return segmenter
+                .segment(sent_text)
+                .into_iter()
+                .filter_map(|(s, e)| sent_text.get(s..e).map(|w| (s, w, false)))
+                .collect();
+        }
+        sent_text
+            .unicode_word_indices()
+            .map(|(i, w)| (i, w, true))
+            .collect()
+    }
+
     /// Find sentence boundaries in text
     fn sentence_boundaries(&self, text: &str) -> Vec<(usize, usize)> {
         let mut boundaries = Vec::new();
@@ -204,12 +364,17 @@ impl Tokenizer {
     /// Basic lemmatization (for when spaCy is not available)
     ///
     /// This handles simple English morphology. For accurate lemmas, use spaCy.
-    fn basic_lemmatize(&self, word: &str) -> String {
-        let lower = word.to_lowercase();
+    fn basic_lemmatize<'a>(&self, word: &'a str) -> Cow<'a, str> {
+        let lower = lowercase(word);
+        // A prefix of `lower`, still borrowing from `word` when `lower` does
+        let prefix = |len: usize| match &lower {
+            Cow::Borrowed(s) => Cow::Borrowed(&s[..len]),
+            Cow::Owned(s) => Cow::Owned(s[..len].to_string()),
+        };
 
         // Handle common English suffixes
         if lower.ends_with("ies") && lower.len() > 4 {
-            return format!("{}y", &lower[..lower.len() - 3]);
+            return Cow::Owned(format!("{}y", &lower[..lower.len() - 3]));
         }
         if lower.ends_with("es") && lower.len() > 3 {
             let stem = &lower[..lower.len() - 2];
@@ -219,51 +384,40 @@ impl Tokenizer {
                 || stem.ends_with('x')
                 || stem.ends_with('o')
             {
-                return stem.to_string();
+                return prefix(stem.len());
             }
         }
         if lower.ends_with('s') && lower.len() > 2 && !lower.ends_with("ss") {
-            return lower[..lower.len() - 1].to_string();
+            return prefix(lower.len() - 1);
         }
         if lower.ends_with("ing") && lower.len() > 5 {
             let stem = &lower[..lower.len() - 3];
             // Check for doubled consonant (running -> run)
             let chars: Vec<char> = stem.chars().collect();
             if chars.len() >= 2 && chars[chars.len() - 1] == chars[chars.len() - 2] {
-                return stem[..stem.len() - 1].to_string();
+                return prefix(stem.len() - chars[chars.len() - 1].len_utf8());
             }
-            return stem.to_string();
+            return prefix(stem.len());
         }
         if lower.ends_with("ed") && lower.len() > 4 {
             let stem = &lower[..lower.len() - 2];
             // Check for doubled consonant
             let chars: Vec<char> = stem.chars().collect();
             if chars.len() >= 2 && chars[chars.len() - 1] == chars[chars.len() - 2] {
-                return stem[..stem.len() - 1].to_string();
+                return prefix(stem.len() - chars[chars.len() - 1].len_utf8());
             }
-            if lower.ends_with("ied") {
-                return format!("{}y", &lower[..lower.len() - 3]);
+            if let Some(stem) = lower.strip_suffix("ied") {
+                return Cow::Owned(format!("{}y", stem));
             }
-            return stem.to_string();
+            return prefix(stem.len());
         }
 
         lower
     }
 
-    /// Check if a character is CJK
+    /// Check if a character is CJK (see [`cjk::is_cjk`](crate::nlp::cjk::is_cjk))
     pub fn is_cjk(c: char) -> bool {
-        matches!(c,
-            '\u{4E00}'..='\u{9FFF}' |   // CJK Unified Ideographs
-            '\u{3400}'..='\u{4DBF}' |   // CJK Extension A
-            '\u{20000}'..='\u{2A6DF}' | // CJK Extension B
-            '\u{2A700}'..='\u{2B73F}' | // CJK Extension C
-            '\u{2B740}'..='\u{2B81F}' | // CJK Extension D
-            '\u{F900}'..='\u{FAFF}' |   // CJK Compatibility
-            '\u{3000}'..='\u{303F}' |   // CJK Punctuation
-            '\u{3040}'..='\u{309F}' |   // Hiragana
-            '\u{30A0}'..='\u{30FF}' |   // Katakana
-            '\u{AC00}'..='\u{D7AF}'     // Hangul
-        )
+        crate::nlp::cjk::is_cjk(c)
     }
 }
 
@@ -280,6 +434,110 @@ mod tests {
         assert!(tokens.len() >= 6); // At least "Hello", "world", "This", "is", "a", "test"
     }
 
+    #[test]
+    fn test_tokenize_with_config_marks_stopwords() {
+        let tokenizer = Tokenizer::new();
+        let config = TextRankConfig {
+            stopwords: vec!["graph".to_string()],
+            ..TextRankConfig::default()
+        };
+        let tokens = tokenizer.tokenize_with_config("The graph is large. Ranking works.", &config);
+
+        let flag = |text: &str| tokens.iter().find(|t| t.text == text).unwrap().is_stopword;
+        assert!(flag("The"));
+        assert!(flag("graph")); // custom stopword
+        assert!(!flag("Ranking"));
+        assert_eq!(tokens.last().unwrap().sentence_idx, 1);
+        assert_eq!(
+            &"The graph is large. Ranking works."[tokens[1].start..tokens[1].end],
+            "graph"
+        );
+    }
+
+    #[test]
+    fn test_borrowed_tokens_match_owned() {
+        let text = "Graphs rank the running nodes. Search boxes studied edges.";
+        let tokenizer = Tokenizer::new();
+        let config = TextRankConfig::default();
+        let lemmatizer = Lemmatizer::for_config(&config);
+        let stopwords = StopwordFilter::for_config(&config);
+
+        let borrowed = tokenizer.tokenize_borrowed_with(text, &lemmatizer, &stopwords);
+        let owned: Vec<Token> = borrowed.iter().cloned().map(TokenRef::into_owned).collect();
+        assert_eq!(
+            serde_json::to_string(&owned).unwrap(),
+            serde_json::to_string(&tokenizer.tokenize_with_config(text, &config)).unwrap()
+        );
+
+        // Lowercase words and suffix stripping borrow; capitalized words allocate.
+        let lemma = |word: &str| &borrowed.iter().find(|t| t.text == word).unwrap().lemma;
+        assert!(matches!(lemma("rank"), Cow::Borrowed("rank")));
+        assert!(matches!(lemma("running"), Cow::Borrowed("run")));
+        assert!(matches!(lemma("boxes"), Cow::Borrowed("box")));
+        assert!(matches!(lemma("Graphs"), Cow::Owned(l) if l == "graph"));
+    }
+
+    #[test]
+    fn test_tokenize_with_config_applies_lemma_strategy() {
+        let tokenizer = Tokenizer::new();
+        let config =
+            TextRankConfig::default().with_lemma_strategy(crate::types::LemmaStrategy::Lowercase);
+        let tokens = tokenizer.tokenize_with_config("Graphs rank", &config);
+        assert_eq!(tokens[0].lemma, "graphs");
+    }
+
+    #[cfg(feature = "pos-tagger")]
+    #[test]
+    fn test_with_pos_tagger_overrides_heuristics() {
+        let (_, mut gold) = Tokenizer::new().tokenize("Dogs bark loudly");
+        gold[2].pos = PosTag::Verb; // deliberately unlike the heuristic guess
+        let tagger = Arc::new(PerceptronTagger::train(&gold, 5));
+
+        let (_, tokens) = Tokenizer::new()
+            .with_pos_tagger(tagger)
+            .tokenize("Dogs bark loudly");
+        assert_eq!(tokens[2].pos, PosTag::Verb);
+    }
+
+    #[cfg(feature = "cjk")]
+    #[test]
+    fn test_with_segmenter_extracts_cjk_phrases() {
+        // Toy dictionary segmenter: greedy two-character words.
+        let segmenter = |text: &str| -> Vec<(usize, usize)> {
+            let mut spans = Vec::new();
+            let chars: Vec<(usize, char)> = text
+                .char_indices()
+                .filter(|(_, c)| Tokenizer::is_cjk(*c) && c.is_alphanumeric())
+                .collect();
+            let mut i = 0;
+            while i < chars.len() {
+                let len = if chars[i].1 == '的' {
+                    1
+                } else {
+                    2.min(chars.len() - i)
+                };
+                let end = chars.get(i + len).map_or(chars[i + len - 1].0 + 3, |c| c.0);
+                spans.push((chars[i].0, end));
+                i += len;
+            }
+            spans
+        };
+        let tokenizer = Tokenizer::new().with_segmenter(Arc::new(segmenter));
+        let config = TextRankConfig {
+            language: "zh".to_string(),
+            ..TextRankConfig::default()
+        };
+
+        let text = "机器学习的模型。机器学习的方法。";
+        let tokens = tokenizer.tokenize_with_config(text, &config);
+        assert_eq!(tokens[0].text, "机器");
+        assert_eq!(&text[tokens[1].start..tokens[1].end], "学习");
+        assert!(tokens[2].is_stopword); // "的"
+
+        let phrases = crate::phrase::extraction::extract_keyphrases(&tokens, &config);
+        assert!(phrases.iter().any(|p| p.lemma == "机器 学习"));
+    }
+
     #[test]
     fn test_unicode_handling() {
         let tokenizer = Tokenizer::new();
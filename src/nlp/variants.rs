@@ -0,0 +1,325 @@
+//! Acronym and hyphenation variant linking
+//!
+//! Implements [`VariantLinking`] on tokenized text, so spelling variants of
+//! one term share a graph node instead of splitting its PageRank mass:
+//!
+//! - **Acronyms**: a definition such as "support vector machine (SVM)" merges
+//!   every occurrence of the expansion into one token, and gives every later
+//!   "SVM" the expansion's lemma. The parenthesized acronym of the definition
+//!   itself is dropped, since it restates the expansion in place.
+//! - **Hyphenation**: soft hyphens (U+00AD) are removed from lemmas, and a
+//!   hyphenated compound ("co-occurrence", split into two tokens by UAX #29)
+//!   is merged into one token when its joined form ("cooccurrence") also
+//!   occurs in the document.
+//!
+//! Merged tokens keep the original text span, take the POS of their last
+//! part, and are renumbered so `token_idx` stays contiguous.
+
+use crate::types::{PosTag, Token, VariantLinking};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+const SOFT_HYPHEN: char = '\u{AD}';
+const HYPHENS: [char; 3] = ['-', '\u{2010}', '\u{2011}'];
+const MAX_ACRONYM_CHARS: usize = 10;
+
+/// Link acronym and hyphenation variants in `tokens` tokenized from `text`.
+pub fn link_variants(text: &str, tokens: &mut Vec<Token>, options: VariantLinking) {
+    if options.is_identity() || tokens.is_empty() {
+        return;
+    }
+    if options.hyphenation {
+        link_hyphenated(text, tokens);
+    }
+    if options.acronyms {
+        link_acronyms(text, tokens);
+    }
+}
+
+fn link_hyphenated(text: &str, tokens: &mut Vec<Token>) {
+    for token in tokens.iter_mut() {
+        if token.lemma.contains(SOFT_HYPHEN) {
+            token.lemma.retain(|c| c != SOFT_HYPHEN);
+        }
+    }
+
+    let lemmas: FxHashSet<String> = tokens.iter().map(|t| t.lemma.to_lowercase()).collect();
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let mut j = i;
+        while j + 1 < tokens.len() && hyphen_joined(text, &tokens[j], &tokens[j + 1]) {
+            j += 1;
+        }
+        if j > i {
+            let joined: String = tokens[i..=j].iter().map(|t| t.lemma.as_str()).collect();
+            if lemmas.contains(&joined.to_lowercase()) {
+                spans.push((i..j + 1, joined));
+            }
+        }
+        i = j + 1;
+    }
+    merge_spans(text, tokens, spans);
+}
+
+fn link_acronyms(text: &str, tokens: &mut Vec<Token>) {
+    // (acronym surface form, lemmas of its expansion), in definition order
+    let mut expansions: Vec<(String, Vec<String>)> = Vec::new();
+    let mut definitions = FxHashSet::default();
+    for (i, token) in tokens.iter().enumerate() {
+        if !is_acronym(&token.text) || !parenthesized(text, token) {
+            continue;
+        }
+        if let Some(start) = expansion_start(tokens, i) {
+            definitions.insert(i);
+            if !expansions.iter().any(|(acronym, _)| *acronym == token.text) {
+                let lemmas = tokens[start..i].iter().map(|t| t.lemma.clone()).collect();
+                expansions.push((token.text.clone(), lemmas));
+            }
+        }
+    }
+    if expansions.is_empty() {
+        return;
+    }
+
+    let lookup: FxHashMap<&str, String> = expansions
+        .iter()
+        .map(|(acronym, lemmas)| (acronym.as_str(), lemmas.join(" ")))
+        .collect();
+    let mut kept = Vec::with_capacity(tokens.len());
+    for (i, mut token) in std::mem::take(tokens).into_iter().enumerate() {
+        if definitions.contains(&i) {
+            continue;
+        }
+        if let Some(lemma) = lookup.get(token.text.as_str()) {
+            token.lemma = lemma.clone();
+            token.pos = PosTag::ProperNoun;
+            token.is_stopword = false;
+        }
+        kept.push(token);
+    }
+    *tokens = kept;
+    renumber(tokens);
+
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let matched = expansions
+            .iter()
+            .map(|(_, lemmas)| lemmas)
+            .filter(|lemmas| {
+                lemmas.len() > 1
+                    && tokens.len() - i >= lemmas.len()
+                    && tokens[i..i + lemmas.len()]
+                        .iter()
+                        .zip(lemmas.iter())
+                        .all(|(t, l)| t.lemma == *l && t.sentence_idx == tokens[i].sentence_idx)
+            })
+            .max_by_key(|lemmas| lemmas.len());
+        match matched {
+            Some(lemmas) => {
+                spans.push((i..i + lemmas.len(), lemmas.join(" ")));
+                i += lemmas.len();
+            }
+            None => i += 1,
+        }
+    }
+    merge_spans(text, tokens, spans);
+}
+
+/// Index of the first expansion token for the acronym at `acronym_idx`, when
+/// the initials of the preceding tokens spell the acronym. Stopwords inside
+/// the expansion ("quality of service (QoS)") may be skipped.
+fn expansion_start(tokens: &[Token], acronym_idx: usize) -> Option<usize> {
+    let acronym = &tokens[acronym_idx];
+    let sentence = acronym.sentence_idx;
+    let mut letters = acronym
+        .text
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(char::to_lowercase)
+        .rev()
+        .peekable();
+    let mut i = acronym_idx;
+    while letters.peek().is_some() {
+        i = i.checked_sub(1)?;
+        let token = &tokens[i];
+        if token.sentence_idx != sentence {
+            return None;
+        }
+        let initial = token.text.chars().next()?.to_lowercase().next()?;
+        if letters.peek() == Some(&initial) {
+            letters.next();
+        } else if !token.is_stopword || i + 1 == acronym_idx {
+            return None;
+        }
+    }
+    (acronym_idx - i > 1).then_some(i)
+}
+
+/// 2–10 alphanumeric characters with at least two capitals, e.g. "SVM",
+/// "QoS", "GPT4"
+fn is_acronym(word: &str) -> bool {
+    let chars = word.chars().count();
+    (2..=MAX_ACRONYM_CHARS).contains(&chars)
+        && word.chars().all(char::is_alphanumeric)
+        && word.chars().filter(|c| c.is_uppercase()).count() >= 2
+        && word.chars().next().is_some_and(char::is_uppercase)
+}
+
+fn parenthesized(text: &str, token: &Token) -> bool {
+    let before = text.get(..token.start).unwrap_or("").trim_end();
+    let after = text.get(token.end..).unwrap_or("").trim_start();
+    before.ends_with('(') && after.starts_with(')')
+}
+
+fn hyphen_joined(text: &str, left: &Token, right: &Token) -> bool {
+    left.sentence_idx == right.sentence_idx
+        && text.get(left.end..right.start).is_some_and(|gap| {
+            let mut chars = gap.chars();
+            matches!((chars.next(), chars.next()), (Some(c), None) if HYPHENS.contains(&c))
+        })
+}
+
+/// Replace each `(range, lemma)` span of `tokens` (ascending, disjoint) with
+/// one token covering it.
+fn merge_spans(text: &str, tokens: &mut Vec<Token>, spans: Vec<(std::ops::Range<usize>, String)>) {
+    if spans.is_empty() {
+        return;
+    }
+    let mut merged = Vec::with_capacity(tokens.len());
+    let mut spans = spans.into_iter().peekable();
+    let mut parts = std::mem::take(tokens).into_iter().enumerate();
+    while let Some((i, token)) = parts.next() {
+        let Some((range, _)) = spans.peek() else {
+            merged.push(token);
+            continue;
+        };
+        if i != range.start {
+            merged.push(token);
+            continue;
+        }
+        let (range, lemma) = spans.next().expect("peeked");
+        let mut is_stopword = token.is_stopword;
+        let mut last = token.clone();
+        for _ in range.start + 1..range.end {
+            let (_, part) = parts.next().expect("span within tokens");
+            is_stopword &= part.is_stopword;
+            last = part;
+        }
+        merged.push(Token {
+            text: text
+                .get(token.start..last.end)
+                .map_or_else(|| token.text.clone(), str::to_string),
+            lemma,
+            pos: last.pos,
+            start: token.start,
+            end: last.end,
+            sentence_idx: token.sentence_idx,
+            token_idx: token.token_idx,
+            is_stopword,
+            section: token.section,
+        });
+    }
+    *tokens = merged;
+    renumber(tokens);
+}
+
+fn renumber(tokens: &mut [Token]) {
+    for (i, token) in tokens.iter_mut().enumerate() {
+        token.token_idx = i;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nlp::tokenizer::Tokenizer;
+    use crate::types::TextRankConfig;
+
+    fn tokenize(text: &str, options: VariantLinking) -> Vec<Token> {
+        let config = TextRankConfig::default().with_variant_linking(options);
+        Tokenizer::new().tokenize_with_config(text, &config)
+    }
+
+    fn lemmas(tokens: &[Token]) -> Vec<&str> {
+        tokens.iter().map(|t| t.lemma.as_str()).collect()
+    }
+
+    #[test]
+    fn test_acronym_links_to_expansion() {
+        let text = "A support vector machine (SVM) separates data. The SVM is fast.";
+        let tokens = tokenize(
+            text,
+            VariantLinking {
+                acronyms: true,
+                hyphenation: false,
+            },
+        );
+
+        let svm: Vec<&Token> = tokens
+            .iter()
+            .filter(|t| t.lemma == "support vector machine")
+            .collect();
+        assert_eq!(svm.len(), 2);
+        assert_eq!(svm[0].text, "support vector machine");
+        assert_eq!(svm[1].text, "SVM");
+        assert!(!tokens.iter().any(|t| t.lemma == "svm"));
+        assert!(tokens.iter().enumerate().all(|(i, t)| t.token_idx == i));
+    }
+
+    #[test]
+    fn test_acronym_skips_stopwords_and_rejects_mismatches() {
+        let text = "Quality of service (QoS) matters. Graph models (XYZ) differ.";
+        let tokens = tokenize(
+            text,
+            VariantLinking {
+                acronyms: true,
+                hyphenation: false,
+            },
+        );
+        assert!(lemmas(&tokens).contains(&"quality of service"));
+        assert!(tokens.iter().any(|t| t.text == "XYZ"));
+
+        assert!(is_acronym("SVM") && is_acronym("QoS") && is_acronym("GPT4"));
+        assert!(!is_acronym("Graph") && !is_acronym("A") && !is_acronym("svm"));
+    }
+
+    #[test]
+    fn test_hyphenated_variants_share_lemma() {
+        let text = "Word co-occurrence matters. Cooccurrence counts and well-known words.";
+        let tokens = tokenize(
+            text,
+            VariantLinking {
+                acronyms: false,
+                hyphenation: true,
+            },
+        );
+        let joined: Vec<&Token> = tokens
+            .iter()
+            .filter(|t| t.lemma == "cooccurrence")
+            .collect();
+        assert_eq!(joined.len(), 2);
+        assert_eq!(joined[0].text, "co-occurrence");
+        // No unhyphenated "wellknown" in the text: left as two tokens
+        assert!(tokens.iter().any(|t| t.text == "well"));
+
+        let soft = tokenize(
+            "co\u{AD}occurrence",
+            VariantLinking {
+                acronyms: false,
+                hyphenation: true,
+            },
+        );
+        assert_eq!(lemmas(&soft), ["cooccurrence"]);
+    }
+
+    #[test]
+    fn test_disabled_is_identity() {
+        let text = "A support vector machine (SVM) and co-occurrence cooccurrence.";
+        let plain = Tokenizer::new().tokenize_with_config(text, &TextRankConfig::default());
+        assert_eq!(
+            lemmas(&tokenize(text, VariantLinking::default())),
+            lemmas(&plain)
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! HITS (hubs and authorities)
+//!
+//! Kleinberg's HITS alternates two updates over the weighted adjacency
+//! matrix `A`: authorities `a ← Aᵀh` and hubs `h ← Aa`, normalizing both to
+//! sum to 1 after every step. A node is a good authority when good hubs
+//! point to it, and a good hub when it points to good authorities.
+//!
+//! On the symmetric co-occurrence graphs built by default the two scores
+//! coincide; they diverge on directed graphs (e.g. forward-only windows or
+//! after an alpha-boost transform). There is no teleportation, so nodes
+//! without positive-weight edges score 0.
+
+use super::kernels;
+use crate::graph::csr::CsrGraph;
+
+/// Which HITS score a ranker reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HitsScore {
+    /// Authority scores (nodes pointed to by good hubs)
+    #[default]
+    Authority,
+    /// Hub scores (nodes pointing to good authorities)
+    Hub,
+}
+
+/// Result of a HITS computation
+#[derive(Debug, Clone)]
+pub struct HitsResult {
+    /// Hub scores (indexed by node ID, summing to 1)
+    pub hubs: Vec<f64>,
+    /// Authority scores (indexed by node ID, summing to 1)
+    pub authorities: Vec<f64>,
+    /// Number of iterations performed
+    pub iterations: usize,
+    /// Final convergence delta (L1 change of the authority vector)
+    pub delta: f64,
+    /// Whether the algorithm converged
+    pub converged: bool,
+}
+
+impl HitsResult {
+    /// The requested score vector
+    pub fn scores(&self, score: HitsScore) -> &[f64] {
+        match score {
+            HitsScore::Authority => &self.authorities,
+            HitsScore::Hub => &self.hubs,
+        }
+    }
+}
+
+/// HITS implementation
+#[derive(Debug, Clone)]
+pub struct Hits {
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Convergence threshold
+    pub threshold: f64,
+}
+
+impl Default for Hits {
+    fn default() -> Self {
+        Self {
+            max_iterations: 100,
+            threshold: 1e-6,
+        }
+    }
+}
+
+impl Hits {
+    /// Create a new Hits with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum iterations
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Set the convergence threshold
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Run HITS on a graph
+    pub fn run(&self, graph: &CsrGraph) -> HitsResult {
+        let n = graph.num_nodes;
+        if n == 0 {
+            return HitsResult {
+                hubs: vec![],
+                authorities: vec![],
+                iterations: 0,
+                delta: 0.0,
+                converged: true,
+            };
+        }
+
+        let mut hubs = vec![1.0 / n as f64; n];
+        let mut authorities = vec![1.0 / n as f64; n];
+        let mut new_authorities = vec![0.0; n];
+        let mut iterations = 0;
+        let mut delta = f64::MAX;
+
+        while iterations < self.max_iterations && delta > self.threshold {
+            iterations += 1;
+
+            // a ← Aᵀh
+            new_authorities.fill(0.0);
+            for (node, &hub) in hubs.iter().enumerate() {
+                for (target, weight) in graph.neighbors(node as u32) {
+                    if weight > 0.0 {
+                        new_authorities[target as usize] += weight * hub;
+                    }
+                }
+            }
+            normalize(&mut new_authorities);
+
+            // h ← Aa
+            for (node, hub) in hubs.iter_mut().enumerate() {
+                *hub = graph
+                    .neighbors(node as u32)
+                    .filter(|&(_, weight)| weight > 0.0)
+                    .map(|(target, weight)| weight * new_authorities[target as usize])
+                    .sum();
+            }
+            normalize(&mut hubs);
+
+            delta = kernels::l1_distance(&authorities, &new_authorities);
+            std::mem::swap(&mut authorities, &mut new_authorities);
+        }
+
+        HitsResult {
+            hubs,
+            authorities,
+            iterations,
+            delta,
+            converged: delta <= self.threshold,
+        }
+    }
+}
+
+/// Scale to sum to 1 (left as zeros when there is no mass).
+fn normalize(scores: &mut [f64]) {
+    let sum: f64 = scores.iter().sum();
+    if sum > 0.0 {
+        scores.iter_mut().for_each(|s| *s /= sum);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::builder::GraphBuilder;
+
+    #[test]
+    fn test_directed_hub_and_authority() {
+        // Two hubs both point at the same two authorities.
+        let mut builder = GraphBuilder::new();
+        let ids: Vec<u32> = ["h1", "h2", "a1", "a2"]
+            .iter()
+            .map(|name| builder.get_or_create_node(name))
+            .collect();
+        builder.increment_directed_edge(ids[0], ids[2], 1.0);
+        builder.increment_directed_edge(ids[0], ids[3], 1.0);
+        builder.increment_directed_edge(ids[1], ids[2], 1.0);
+        let graph = CsrGraph::from_builder(&builder);
+
+        let result = Hits::new().run(&graph);
+        assert!(result.converged);
+        assert!(result.hubs[0] > result.hubs[1]);
+        assert!(result.authorities[2] > result.authorities[3]);
+        assert_eq!(result.hubs[2], 0.0);
+        assert_eq!(result.authorities[0], 0.0);
+        assert!((result.hubs.iter().sum::<f64>() - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_symmetric_graph_hubs_equal_authorities() {
+        let mut builder = GraphBuilder::new();
+        let ids: Vec<u32> = (0..4)
+            .map(|i| builder.get_or_create_node(&format!("n{}", i)))
+            .collect();
+        builder.increment_edge(ids[0], ids[1], 1.0);
+        builder.increment_edge(ids[0], ids[2], 2.0);
+        builder.increment_edge(ids[0], ids[3], 1.0);
+        builder.increment_edge(ids[2], ids[3], 1.0);
+        let graph = CsrGraph::from_builder(&builder);
+
+        let result = Hits::new().run(&graph);
+        for (h, a) in result.hubs.iter().zip(&result.authorities) {
+            assert!((h - a).abs() < 1e-5);
+        }
+        // The best-connected node dominates.
+        let top = result
+            .authorities
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .unwrap()
+            .0;
+        assert_eq!(top, 0);
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let result = Hits::new().run(&CsrGraph::default());
+        assert!(result.hubs.is_empty());
+        assert!(result.converged);
+    }
+}
@@ -1,10 +1,202 @@
 //! PageRank algorithms
 //!
-//! This module provides standard and personalized PageRank implementations.
+//! This module provides standard and personalized PageRank implementations,
+//! a rayon-parallel variant behind the `parallel` feature, a
+//! [`single_precision`] variant for large graphs, and an extrapolation-
+//! [`accelerated`] variant for slowly converging ones. [`hits`] provides
+//! hub and authority scores as an alternative to PageRank. The hot loops share the
+//! chunked kernels in [`kernels`].
+//!
+//! [`standard`], [`personalized`] and [`kernels`] only need `core` and
+//! `alloc`, and stay available when the crate is built without `std`.
 
+#[cfg(feature = "std")]
+pub mod accelerated;
+#[cfg(feature = "std")]
+pub mod hits;
+pub mod kernels;
+#[cfg(feature = "parallel")]
+pub mod parallel;
 pub mod personalized;
+#[cfg(feature = "std")]
+pub mod single_precision;
 pub mod standard;
 
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// Default node count at which the ranker switches to [`parallel`] PageRank
+/// (with the `parallel` feature enabled).
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 50_000;
+
+/// Where PageRank sends the score of dangling nodes (nodes without
+/// outgoing edges).
+///
+/// Reference implementations differ here, which is a common source of
+/// small ranking discrepancies (e.g. against PKE for personalized variants).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DanglingPolicy {
+    /// Redistribute according to the teleport (personalization) vector,
+    /// which is uniform for standard PageRank. Matches NetworkX's default.
+    #[default]
+    Personalization,
+    /// Redistribute uniformly over all nodes, even when personalized.
+    Uniform,
+    /// Keep the mass on the dangling node itself (an implicit self-loop).
+    SelfLoop,
+}
+
+/// Norm used to measure the change between PageRank iterations.
+///
+/// Iteration stops once the residual drops to `convergence_threshold`, and
+/// the final residual is reported as `final_delta` / `StageReport::residual`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConvergenceCriterion {
+    /// Sum of absolute changes, `‖xₖ₊₁ − xₖ‖₁`. Matches NetworkX.
+    #[default]
+    L1,
+    /// Euclidean norm of the change, `‖xₖ₊₁ − xₖ‖₂`.
+    L2,
+    /// Largest absolute change of any node, `‖xₖ₊₁ − xₖ‖∞`.
+    #[serde(rename = "linf")]
+    LInf,
+    /// Change relative to the new scores, `‖xₖ₊₁ − xₖ‖₂ / ‖xₖ₊₁‖₂`.
+    Relative,
+}
+
+/// Progress callback invoked with `(iteration, residual)`; returning
+/// `Break` stops the power iteration.
+pub(crate) type IterationHook<'a> = &'a mut dyn FnMut(u32, f64) -> core::ops::ControlFlow<()>;
+
+/// Residual between two iterates under `criterion`.
+///
+/// Sums go through [`kernels::chunked_sum`], which the parallel ranker
+/// reproduces exactly.
+pub(crate) fn residual(criterion: ConvergenceCriterion, old: &[f64], new: &[f64]) -> f64 {
+    let n = old.len();
+    let squared_distance =
+        || kernels::chunked_sum(n, |r| kernels::squared_distance(&old[r.clone()], &new[r]));
+    match criterion {
+        ConvergenceCriterion::L1 => {
+            kernels::chunked_sum(n, |r| kernels::l1_distance(&old[r.clone()], &new[r]))
+        }
+        ConvergenceCriterion::L2 => kernels::sqrt(squared_distance()),
+        ConvergenceCriterion::LInf => kernels::linf_distance(old, new),
+        ConvergenceCriterion::Relative => {
+            let norm = kernels::sqrt(kernels::chunked_sum(n, |r| kernels::squared_norm(&new[r])));
+            let diff = kernels::sqrt(squared_distance());
+            if norm > 0.0 {
+                diff / norm
+            } else {
+                diff
+            }
+        }
+    }
+}
+
+/// Sum of a score vector, as used to normalize the final scores.
+pub(crate) fn score_sum(scores: &[f64]) -> f64 {
+    kernels::chunked_sum(scores.len(), |r| scores[r].iter().sum())
+}
+
+/// Write the teleport and dangling-node terms of one power-iteration step
+/// into `out`; callers add edge propagation on top.
+///
+/// `personalization` must already be normalized (`None` means uniform).
+/// The [`DanglingPolicy::Personalization`] arithmetic matches the original
+/// standard and personalized loops exactly.
+pub(crate) fn fill_base_scores(
+    out: &mut [f64],
+    scores: &[f64],
+    dangling_nodes: &[u32],
+    personalization: Option<&[f64]>,
+    damping: f64,
+    policy: DanglingPolicy,
+) {
+    let n = out.len() as f64;
+    if policy == DanglingPolicy::SelfLoop {
+        match personalization {
+            Some(p) => {
+                for (o, &p) in out.iter_mut().zip(p) {
+                    *o = (1.0 - damping) * p;
+                }
+            }
+            None => out.fill((1.0 - damping) / n),
+        }
+        for &d in dangling_nodes {
+            out[d as usize] += damping * scores[d as usize];
+        }
+        return;
+    }
+
+    let dangling_mass: f64 = dangling_nodes.iter().map(|&d| scores[d as usize]).sum();
+    match (personalization, policy) {
+        (Some(p), DanglingPolicy::Personalization) => {
+            for (o, &p) in out.iter_mut().zip(p) {
+                *o = (1.0 - damping) * p + damping * dangling_mass * p;
+            }
+        }
+        (Some(p), _) => {
+            let dangling_contribution = damping * dangling_mass / n;
+            for (o, &p) in out.iter_mut().zip(p) {
+                *o = (1.0 - damping) * p + dangling_contribution;
+            }
+        }
+        (None, _) => out.fill((1.0 - damping) / n + damping * dangling_mass / n),
+    }
+}
+
+/// Teleport distribution over `n` nodes from an unnormalized
+/// `personalization` vector: truncated or zero-padded to `n` and normalized
+/// to sum to 1, or uniform when no positive mass remains.
+pub(crate) fn normalize_personalization(personalization: &[f64], n: usize) -> Vec<f64> {
+    let mut result: Vec<f64> = personalization
+        .iter()
+        .copied()
+        .chain(core::iter::repeat(0.0))
+        .take(n)
+        .collect();
+    let sum: f64 = result.iter().sum();
+    if sum > 0.0 {
+        for v in &mut result {
+            *v /= sum;
+        }
+    } else {
+        result = alloc::vec![1.0 / n as f64; n];
+    }
+    result
+}
+
+/// Fill `out` with the starting vector for power iteration.
+///
+/// Without `initial` this is the uniform distribution. Otherwise the given
+/// scores (indexed by node ID) are used as a warm start: negative or
+/// non-finite entries count as zero, nodes past the end of `initial` start
+/// at `1/n`, and the vector is normalized to sum to 1. Falls back to uniform
+/// if no positive mass remains.
+pub(crate) fn fill_initial_scores(out: &mut Vec<f64>, initial: Option<&[f64]>, n: usize) {
+    let uniform = 1.0 / n as f64;
+    out.clear();
+    if let Some(initial) = initial {
+        out.extend(
+            initial
+                .iter()
+                .take(n)
+                .map(|&s| if s.is_finite() && s > 0.0 { s } else { 0.0 }),
+        );
+        out.resize(n, uniform);
+        let sum: f64 = out.iter().sum();
+        if sum > 0.0 {
+            out.iter_mut().for_each(|s| *s /= sum);
+            return;
+        }
+        out.clear();
+    }
+    out.resize(n, uniform);
+}
+
 /// Result of a PageRank computation
 #[derive(Debug, Clone)]
 pub struct PageRankResult {
@@ -37,7 +229,11 @@ impl PageRankResult {
             .enumerate()
             .map(|(i, &s)| (i as u32, s))
             .collect();
-        indexed.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        indexed.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(core::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
         indexed.truncate(n);
         indexed
     }
@@ -47,3 +243,23 @@ impl PageRankResult {
         self.scores.get(node as usize).copied().unwrap_or(0.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_initial_scores() {
+        let mut out = Vec::new();
+        fill_initial_scores(&mut out, None, 4);
+        assert_eq!(out, vec![0.25; 4]);
+
+        // Missing nodes start at 1/n; invalid entries count as zero.
+        fill_initial_scores(&mut out, Some(&[0.5, -1.0, f64::NAN]), 4);
+        assert_eq!(out, vec![0.5 / 0.75, 0.0, 0.0, 0.25 / 0.75]);
+
+        // Extra entries are ignored; no positive mass falls back to uniform.
+        fill_initial_scores(&mut out, Some(&[0.0, 0.0, 0.0, 0.0, 9.0]), 4);
+        assert_eq!(out, vec![0.25; 4]);
+    }
+}
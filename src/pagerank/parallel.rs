@@ -0,0 +1,453 @@
+//! Parallel PageRank
+//!
+//! Rayon-parallel power iteration for large graphs. The serial
+//! implementations *push* each node's score to its neighbours, which needs
+//! a shared output vector; here each node instead *pulls* from its
+//! in-neighbours through a transposed CSR built once per run, so rows can
+//! be processed independently across threads.
+//!
+//! Results are bit-identical to [`StandardPageRank`](super::standard::StandardPageRank)
+//! (without personalization) and
+//! [`PersonalizedPageRank`](super::personalized::PersonalizedPageRank): each
+//! row adds its in-neighbour terms in the order the serial push loop does,
+//! and vector-wide sums use the fixed chunking of
+//! [`kernels::chunked_sum`], so neither the thread count nor work stealing
+//! changes the floating-point accumulation order.
+
+use super::{kernels, PageRankResult};
+use crate::graph::csr::{CsrGraph, CsrView};
+use crate::types::{ConvergenceCriterion, DanglingPolicy, TextRankConfig};
+use rayon::prelude::*;
+use std::ops::Range;
+
+/// Rows per rayon task; keeps scheduling overhead low on sparse graphs.
+const MIN_ROWS_PER_TASK: usize = 1024;
+
+/// Parallel (personalized) PageRank implementation
+#[derive(Debug, Clone)]
+pub struct ParallelPageRank {
+    /// Damping factor (typically 0.85)
+    pub damping: f64,
+    /// Maximum number of iterations
+    pub max_iterations: usize,
+    /// Convergence threshold
+    pub threshold: f64,
+    /// Personalization vector (uniform when `None`)
+    personalization: Option<Vec<f64>>,
+    /// Where dangling-node mass goes
+    pub dangling_policy: DanglingPolicy,
+    /// Norm of the per-iteration change compared against `threshold`
+    pub convergence_criterion: ConvergenceCriterion,
+    /// Starting score vector (uniform when `None`)
+    initial_scores: Option<Vec<f64>>,
+}
+
+impl Default for ParallelPageRank {
+    fn default() -> Self {
+        Self {
+            damping: 0.85,
+            max_iterations: 100,
+            threshold: 1e-6,
+            personalization: None,
+            dangling_policy: DanglingPolicy::default(),
+            convergence_criterion: ConvergenceCriterion::default(),
+            initial_scores: None,
+        }
+    }
+}
+
+impl ParallelPageRank {
+    /// Create a new ParallelPageRank with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create with the PageRank parameters of `cfg`: `damping`,
+    /// `max_iterations`, `convergence_threshold`, `dangling_policy` and
+    /// `convergence_criterion`
+    pub fn from_config(cfg: &TextRankConfig) -> Self {
+        Self::new()
+            .with_damping(cfg.damping)
+            .with_max_iterations(cfg.max_iterations)
+            .with_threshold(cfg.convergence_threshold)
+            .with_dangling_policy(cfg.dangling_policy)
+            .with_convergence_criterion(cfg.convergence_criterion)
+    }
+
+    /// Set the damping factor
+    pub fn with_damping(mut self, damping: f64) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Set the maximum iterations
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Set the convergence threshold
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Set where the score of dangling nodes goes
+    pub fn with_dangling_policy(mut self, policy: DanglingPolicy) -> Self {
+        self.dangling_policy = policy;
+        self
+    }
+
+    /// Set the norm used to decide convergence
+    pub fn with_convergence_criterion(mut self, criterion: ConvergenceCriterion) -> Self {
+        self.convergence_criterion = criterion;
+        self
+    }
+
+    /// Warm-start from a previous score vector (indexed by node ID)
+    ///
+    /// Starting near the solution, e.g. the scores of a slightly edited
+    /// document or of a neighbouring damping value, cuts the number of
+    /// iterations needed. See [`fill_initial_scores`](super::fill_initial_scores)
+    /// for how mismatched lengths and invalid entries are handled.
+    pub fn with_initial_scores(mut self, scores: Vec<f64>) -> Self {
+        self.initial_scores = Some(scores);
+        self
+    }
+
+    /// Set the personalization vector (bias distribution)
+    ///
+    /// Same semantics as
+    /// [`PersonalizedPageRank::with_personalization`](super::personalized::PersonalizedPageRank::with_personalization):
+    /// normalized internally, with dangling mass following the
+    /// personalization distribution.
+    pub fn with_personalization(mut self, personalization: Vec<f64>) -> Self {
+        self.personalization = Some(personalization);
+        self
+    }
+
+    /// Run PageRank on a graph
+    pub fn run(&self, graph: &CsrGraph) -> PageRankResult {
+        self.run_view(graph.view())
+    }
+
+    /// Run PageRank on borrowed adjacency arrays, e.g. a memory-mapped graph
+    pub fn run_view(&self, graph: CsrView<'_>) -> PageRankResult {
+        let n = graph.num_nodes;
+        if n == 0 {
+            return PageRankResult::new(vec![], 0, 0.0, true);
+        }
+
+        let personalization = self.prepare_personalization(n);
+        let (in_ptr, in_src, in_weight) = transpose(graph);
+        let dangling_nodes = graph.dangling_nodes();
+
+        let mut scores = Vec::with_capacity(n);
+        super::fill_initial_scores(&mut scores, self.initial_scores.as_deref(), n);
+        let mut new_scores = vec![0.0; n];
+        let mut scaled = vec![0.0; n];
+        let mut iterations = 0;
+        let mut delta = f64::MAX;
+
+        while iterations < self.max_iterations && delta > self.threshold {
+            iterations += 1;
+
+            // Teleport and dangling terms, exactly as the serial rankers
+            // compute them
+            super::fill_base_scores(
+                &mut new_scores,
+                &scores,
+                &dangling_nodes,
+                personalization.as_deref(),
+                self.damping,
+                self.dangling_policy,
+            );
+
+            scaled
+                .par_iter_mut()
+                .zip(scores.par_iter())
+                .for_each(|(s, &score)| *s = self.damping * score);
+
+            new_scores
+                .par_iter_mut()
+                .with_min_len(MIN_ROWS_PER_TASK)
+                .enumerate()
+                .for_each(|(node, out)| {
+                    let (start, end) = (in_ptr[node], in_ptr[node + 1]);
+                    *out = kernels::gather_add(
+                        *out,
+                        &scaled,
+                        graph.total_weight,
+                        &in_src[start..end],
+                        &in_weight[start..end],
+                    );
+                });
+
+            delta = residual(self.convergence_criterion, &scores, &new_scores);
+
+            std::mem::swap(&mut scores, &mut new_scores);
+        }
+
+        let sum = par_chunked_sum(n, |r| scores[r].iter().sum());
+        if sum > 0.0 {
+            scores.par_iter_mut().for_each(|score| *score /= sum);
+        }
+
+        PageRankResult::new(scores, iterations, delta, delta <= self.threshold)
+    }
+
+    /// Resize and normalize the personalization vector as
+    /// [`PersonalizedPageRank`](super::personalized::PersonalizedPageRank)
+    /// does; `None` means standard PageRank.
+    fn prepare_personalization(&self, n: usize) -> Option<Vec<f64>> {
+        self.personalization
+            .as_deref()
+            .map(|p| super::normalize_personalization(p, n))
+    }
+}
+
+/// Build the transposed adjacency (in-edges) of `graph` in CSR form.
+///
+/// Returns `(row_ptr, sources, weights)`: node `v`'s in-edges are at
+/// `row_ptr[v]..row_ptr[v + 1]`. Edges of sources without positive total
+/// weight are left out, as the serial rankers skip them. Sources within a
+/// row are in ascending order, so pulling a row adds the same terms in the
+/// same order as the serial push loop.
+fn transpose(graph: CsrView<'_>) -> (Vec<usize>, Vec<u32>, Vec<f64>) {
+    let n = graph.num_nodes;
+    let propagates = |source: usize| graph.total_weight[source] > 0.0;
+    let mut row_ptr = vec![0usize; n + 1];
+    for source in (0..n).filter(|&s| propagates(s)) {
+        for (target, _) in graph.neighbors(source as u32) {
+            row_ptr[target as usize + 1] += 1;
+        }
+    }
+    for i in 0..n {
+        row_ptr[i + 1] += row_ptr[i];
+    }
+
+    let mut next = row_ptr[..n].to_vec();
+    let mut sources = vec![0u32; row_ptr[n]];
+    let mut weights = vec![0.0; row_ptr[n]];
+    for source in (0..n).filter(|&s| propagates(s)) {
+        for (target, weight) in graph.neighbors(source as u32) {
+            let slot = &mut next[target as usize];
+            sources[*slot] = source as u32;
+            weights[*slot] = weight;
+            *slot += 1;
+        }
+    }
+    (row_ptr, sources, weights)
+}
+
+/// Parallel [`kernels::chunked_sum`]: the same chunks, summed on rayon
+/// threads and combined in the same fixed order.
+fn par_chunked_sum(len: usize, chunk_sum: impl Fn(Range<usize>) -> f64 + Sync) -> f64 {
+    if len <= kernels::REDUCTION_CHUNK {
+        return chunk_sum(0..len);
+    }
+    let mut partials: Vec<f64> = (0..len.div_ceil(kernels::REDUCTION_CHUNK))
+        .into_par_iter()
+        .map(|chunk| {
+            let start = chunk * kernels::REDUCTION_CHUNK;
+            chunk_sum(start..(start + kernels::REDUCTION_CHUNK).min(len))
+        })
+        .collect();
+    kernels::pairwise_sum(&mut partials)
+}
+
+/// Parallel counterpart of [`super::residual`], bit-identical to it.
+fn residual(criterion: ConvergenceCriterion, old: &[f64], new: &[f64]) -> f64 {
+    let n = old.len();
+    let squared_distance =
+        || par_chunked_sum(n, |r| kernels::squared_distance(&old[r.clone()], &new[r]));
+    match criterion {
+        ConvergenceCriterion::L1 => {
+            par_chunked_sum(n, |r| kernels::l1_distance(&old[r.clone()], &new[r]))
+        }
+        ConvergenceCriterion::L2 => squared_distance().sqrt(),
+        // Maximum is exact in any order.
+        ConvergenceCriterion::LInf => old
+            .par_iter()
+            .zip(new.par_iter())
+            .map(|(o, n)| (o - n).abs())
+            .reduce(|| 0.0, f64::max),
+        ConvergenceCriterion::Relative => {
+            let norm = par_chunked_sum(n, |r| kernels::squared_norm(&new[r])).sqrt();
+            let diff = squared_distance().sqrt();
+            if norm > 0.0 {
+                diff / norm
+            } else {
+                diff
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::builder::GraphBuilder;
+    use crate::pagerank::personalized::PersonalizedPageRank;
+    use crate::pagerank::standard::StandardPageRank;
+
+    fn build_test_graph() -> CsrGraph {
+        let mut builder = GraphBuilder::new();
+        let ids: Vec<u32> = (0..6)
+            .map(|i| builder.get_or_create_node(&format!("n{}", i)))
+            .collect();
+        builder.increment_edge(ids[0], ids[1], 1.0);
+        builder.increment_edge(ids[1], ids[2], 2.0);
+        builder.increment_edge(ids[2], ids[0], 1.0);
+        builder.increment_edge(ids[2], ids[3], 3.0);
+        builder.increment_directed_edge(ids[3], ids[4], 1.5);
+        // ids[5] is dangling.
+        CsrGraph::from_builder(&builder)
+    }
+
+    fn assert_scores_identical(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert_eq!(x.to_bits(), y.to_bits(), "{} vs {}", x, y);
+        }
+    }
+
+    /// A weighted graph spanning several reduction chunks, with dangling
+    /// nodes and irregular in-degrees.
+    fn build_large_graph() -> CsrGraph {
+        let n = 3 * kernels::REDUCTION_CHUNK + 123;
+        let mut builder = GraphBuilder::new();
+        let ids: Vec<u32> = (0..n)
+            .map(|i| builder.get_or_create_node(&format!("n{}", i)))
+            .collect();
+        for i in 0..n - 50 {
+            let weight = 1.0 + (i % 7) as f64 * 0.3;
+            builder.increment_edge(ids[i], ids[(i * 31 + 7) % (n - 50)], weight);
+            builder.increment_directed_edge(ids[i], ids[(i * i + 3) % n], 0.5);
+        }
+        CsrGraph::from_builder(&builder)
+    }
+
+    #[test]
+    fn test_matches_standard_pagerank() {
+        let graph = build_test_graph();
+        let serial = StandardPageRank::new().run(&graph);
+        let parallel = ParallelPageRank::new().run(&graph);
+
+        assert_scores_identical(&parallel.scores, &serial.scores);
+        assert_eq!(parallel.iterations, serial.iterations);
+        assert!(parallel.converged);
+    }
+
+    #[test]
+    fn test_matches_personalized_pagerank() {
+        let graph = build_test_graph();
+        let bias = vec![0.0, 3.0, 0.0, 1.0, 0.0, 0.0];
+        let serial = PersonalizedPageRank::new()
+            .with_personalization(bias.clone())
+            .run(&graph);
+        let parallel = ParallelPageRank::new()
+            .with_personalization(bias)
+            .run(&graph);
+
+        assert_scores_identical(&parallel.scores, &serial.scores);
+    }
+
+    #[test]
+    fn test_dangling_policies_match_serial() {
+        let graph = build_test_graph();
+        let bias = vec![0.0, 3.0, 0.0, 1.0, 0.0, 0.0];
+        for policy in [DanglingPolicy::Uniform, DanglingPolicy::SelfLoop] {
+            let serial = PersonalizedPageRank::new()
+                .with_personalization(bias.clone())
+                .with_dangling_policy(policy)
+                .run(&graph);
+            let parallel = ParallelPageRank::new()
+                .with_personalization(bias.clone())
+                .with_dangling_policy(policy)
+                .run(&graph);
+            assert_scores_identical(&parallel.scores, &serial.scores);
+
+            let serial = StandardPageRank::new()
+                .with_dangling_policy(policy)
+                .run(&graph);
+            let parallel = ParallelPageRank::new()
+                .with_dangling_policy(policy)
+                .run(&graph);
+            assert_scores_identical(&parallel.scores, &serial.scores);
+        }
+    }
+
+    #[test]
+    fn test_bit_identical_across_chunks_and_threads() {
+        let graph = build_large_graph();
+        let n = graph.num_nodes;
+        let bias: Vec<f64> = (0..n).map(|i| (i % 5) as f64).collect();
+
+        for criterion in [
+            ConvergenceCriterion::L1,
+            ConvergenceCriterion::L2,
+            ConvergenceCriterion::LInf,
+            ConvergenceCriterion::Relative,
+        ] {
+            let serial = StandardPageRank::new()
+                .with_convergence_criterion(criterion)
+                .run(&graph);
+            let personalized = PersonalizedPageRank::new()
+                .with_personalization(bias.clone())
+                .with_convergence_criterion(criterion)
+                .run(&graph);
+
+            for threads in [1, 3, 8] {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .unwrap();
+                let (parallel, parallel_personalized) = pool.install(|| {
+                    let pr = ParallelPageRank::new().with_convergence_criterion(criterion);
+                    (
+                        pr.run(&graph),
+                        pr.with_personalization(bias.clone()).run(&graph),
+                    )
+                });
+
+                assert_scores_identical(&parallel.scores, &serial.scores);
+                assert_eq!(parallel.iterations, serial.iterations);
+                assert_eq!(parallel.delta.to_bits(), serial.delta.to_bits());
+                assert_scores_identical(&parallel_personalized.scores, &personalized.scores);
+                assert_eq!(parallel_personalized.iterations, personalized.iterations);
+            }
+        }
+    }
+
+    #[test]
+    fn test_zero_personalization_matches_serial() {
+        let graph = build_test_graph();
+        let zeros = vec![0.0; graph.num_nodes];
+        let serial = PersonalizedPageRank::new()
+            .with_personalization(zeros.clone())
+            .run(&graph);
+        let parallel = ParallelPageRank::new()
+            .with_personalization(zeros)
+            .run(&graph);
+        assert_scores_identical(&parallel.scores, &serial.scores);
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let result = ParallelPageRank::new().run(&CsrGraph::default());
+        assert!(result.scores.is_empty());
+        assert!(result.converged);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let graph = build_test_graph();
+        let (ptr, src, w) = transpose(graph.view());
+
+        // Node 4 only has the directed in-edge from node 3.
+        assert_eq!(&src[ptr[4]..ptr[5]], &[3]);
+        assert_eq!(&w[ptr[4]..ptr[5]], &[1.5]);
+        assert_eq!(ptr[6], graph.num_edges());
+    }
+}
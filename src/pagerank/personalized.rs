@@ -4,8 +4,10 @@
 //! This allows biasing the ranking towards specific nodes (e.g., for
 //! PositionRank or BiasedTextRank).
 
-use super::PageRankResult;
-use crate::graph::csr::CsrGraph;
+use super::{kernels, ConvergenceCriterion, DanglingPolicy, PageRankResult};
+use crate::graph::csr::{CsrGraph, CsrView};
+use alloc::{vec, vec::Vec};
+use core::ops::ControlFlow;
 
 /// Personalized PageRank implementation
 #[derive(Debug, Clone)]
@@ -18,6 +20,12 @@ pub struct PersonalizedPageRank {
     pub threshold: f64,
     /// Personalization vector (bias distribution)
     personalization: Option<Vec<f64>>,
+    /// Where dangling-node mass goes
+    pub dangling_policy: DanglingPolicy,
+    /// Norm of the per-iteration change compared against `threshold`
+    pub convergence_criterion: ConvergenceCriterion,
+    /// Starting score vector (uniform when `None`)
+    initial_scores: Option<Vec<f64>>,
 }
 
 impl Default for PersonalizedPageRank {
@@ -27,6 +35,9 @@ impl Default for PersonalizedPageRank {
             max_iterations: 100,
             threshold: 1e-6,
             personalization: None,
+            dangling_policy: DanglingPolicy::default(),
+            convergence_criterion: ConvergenceCriterion::default(),
+            initial_scores: None,
         }
     }
 }
@@ -37,6 +48,19 @@ impl PersonalizedPageRank {
         Self::default()
     }
 
+    /// Create with the PageRank parameters of `cfg`: `damping`,
+    /// `max_iterations`, `convergence_threshold`, `dangling_policy` and
+    /// `convergence_criterion`
+    #[cfg(feature = "std")]
+    pub fn from_config(cfg: &crate::types::TextRankConfig) -> Self {
+        Self::new()
+            .with_damping(cfg.damping)
+            .with_max_iterations(cfg.max_iterations)
+            .with_threshold(cfg.convergence_threshold)
+            .with_dangling_policy(cfg.dangling_policy)
+            .with_convergence_criterion(cfg.convergence_criterion)
+    }
+
     /// Set the damping factor
     pub fn with_damping(mut self, damping: f64) -> Self {
         self.damping = damping;
@@ -55,6 +79,29 @@ impl PersonalizedPageRank {
         self
     }
 
+    /// Set where the score of dangling nodes goes
+    pub fn with_dangling_policy(mut self, policy: DanglingPolicy) -> Self {
+        self.dangling_policy = policy;
+        self
+    }
+
+    /// Set the norm used to decide convergence
+    pub fn with_convergence_criterion(mut self, criterion: ConvergenceCriterion) -> Self {
+        self.convergence_criterion = criterion;
+        self
+    }
+
+    /// Warm-start from a previous score vector (indexed by node ID)
+    ///
+    /// Starting near the solution, e.g. the scores of a slightly edited
+    /// document or of a neighbouring damping value, cuts the number of
+    /// iterations needed. See [`fill_initial_scores`](super::fill_initial_scores)
+    /// for how mismatched lengths and invalid entries are handled.
+    pub fn with_initial_scores(mut self, scores: Vec<f64>) -> Self {
+        self.initial_scores = Some(scores);
+        self
+    }
+
     /// Set the personalization vector (bias distribution)
     ///
     /// The vector should have one entry per node. It will be normalized internally.
@@ -81,6 +128,35 @@ impl PersonalizedPageRank {
 
     /// Run Personalized PageRank on a graph
     pub fn run(&self, graph: &CsrGraph) -> PageRankResult {
+        self.run_impl(graph.view(), None)
+    }
+
+    /// Run Personalized PageRank on borrowed adjacency arrays, e.g. a memory-mapped
+    /// graph
+    pub fn run_view(&self, graph: CsrView<'_>) -> PageRankResult {
+        self.run_impl(graph, None)
+    }
+
+    /// Run Personalized PageRank, calling `progress(iteration, residual)` every `every`
+    /// iterations
+    ///
+    /// Returning [`ControlFlow::Break`] from `progress` stops iterating; the
+    /// result then has `converged=false` unless the residual already met the
+    /// threshold.
+    pub fn run_with_progress(
+        &self,
+        graph: &CsrGraph,
+        every: u32,
+        progress: &mut dyn FnMut(u32, f64) -> ControlFlow<()>,
+    ) -> PageRankResult {
+        self.run_impl(graph.view(), Some((every.max(1), progress)))
+    }
+
+    fn run_impl(
+        &self,
+        graph: CsrView<'_>,
+        mut progress: Option<(u32, super::IterationHook<'_>)>,
+    ) -> PageRankResult {
         let n = graph.num_nodes;
         if n == 0 {
             return PageRankResult::new(vec![], 0, 0.0, true);
@@ -89,9 +165,9 @@ impl PersonalizedPageRank {
         // Prepare personalization vector
         let personalization = self.prepare_personalization(n);
 
-        // Initialize scores uniformly
-        let initial_score = 1.0 / n as f64;
-        let mut scores = vec![initial_score; n];
+        // Initialize scores (uniform unless warm-started)
+        let mut scores = Vec::with_capacity(n);
+        super::fill_initial_scores(&mut scores, self.initial_scores.as_deref(), n);
         let mut new_scores = vec![0.0; n];
 
         let dangling_nodes = graph.dangling_nodes();
@@ -101,39 +177,48 @@ impl PersonalizedPageRank {
         while iterations < self.max_iterations && delta > self.threshold {
             iterations += 1;
 
-            // Calculate dangling mass (goes to personalization distribution)
-            let dangling_mass: f64 = dangling_nodes.iter().map(|&d| scores[d as usize]).sum();
-
-            // Initialize with teleport probability based on personalization
-            for i in 0..n {
-                new_scores[i] = (1.0 - self.damping) * personalization[i]
-                    + self.damping * dangling_mass * personalization[i];
-            }
+            // Initialize with teleport probability based on personalization,
+            // plus dangling mass per the dangling policy
+            super::fill_base_scores(
+                &mut new_scores,
+                &scores,
+                &dangling_nodes,
+                Some(&personalization),
+                self.damping,
+                self.dangling_policy,
+            );
 
             // Propagate scores through edges
             for (node, &node_score) in scores.iter().enumerate() {
                 let total_weight = graph.node_total_weight(node as u32);
 
                 if total_weight > 0.0 {
-                    for (neighbor, weight) in graph.neighbors(node as u32) {
-                        let contribution = self.damping * node_score * weight / total_weight;
-                        new_scores[neighbor as usize] += contribution;
-                    }
+                    let (start, end) = (graph.row_ptr[node], graph.row_ptr[node + 1]);
+                    kernels::scatter_add(
+                        &mut new_scores,
+                        &graph.col_idx[start..end],
+                        &graph.weights[start..end],
+                        self.damping * node_score,
+                        total_weight,
+                    );
                 }
             }
 
             // Calculate convergence delta
-            delta = scores
-                .iter()
-                .zip(new_scores.iter())
-                .map(|(old, new)| (old - new).abs())
-                .sum();
+            delta = super::residual(self.convergence_criterion, &scores, &new_scores);
 
-            std::mem::swap(&mut scores, &mut new_scores);
+            core::mem::swap(&mut scores, &mut new_scores);
+
+            if let Some((every, progress)) = progress.as_mut() {
+                let iteration = iterations as u32;
+                if iteration.is_multiple_of(*every) && progress(iteration, delta).is_break() {
+                    break;
+                }
+            }
         }
 
         // Normalize scores
-        let sum: f64 = scores.iter().sum();
+        let sum = super::score_sum(&scores);
         if sum > 0.0 {
             for score in &mut scores {
                 *score /= sum;
@@ -162,9 +247,7 @@ impl PersonalizedPageRank {
 
         let personalization = self.prepare_personalization(n);
 
-        let initial_score = 1.0 / n as f64;
-        score_buf.clear();
-        score_buf.resize(n, initial_score);
+        super::fill_initial_scores(score_buf, self.initial_scores.as_deref(), n);
         norm_buf.clear();
         norm_buf.resize(n, 0.0);
 
@@ -175,33 +258,35 @@ impl PersonalizedPageRank {
         while iterations < self.max_iterations && delta > self.threshold {
             iterations += 1;
 
-            let dangling_mass: f64 = dangling_nodes.iter().map(|&d| score_buf[d as usize]).sum();
-
-            for i in 0..n {
-                norm_buf[i] = (1.0 - self.damping) * personalization[i]
-                    + self.damping * dangling_mass * personalization[i];
-            }
+            super::fill_base_scores(
+                norm_buf,
+                score_buf,
+                &dangling_nodes,
+                Some(&personalization),
+                self.damping,
+                self.dangling_policy,
+            );
 
             for (node, &node_score) in score_buf.iter().enumerate() {
                 let total_weight = graph.node_total_weight(node as u32);
                 if total_weight > 0.0 {
-                    for (neighbor, weight) in graph.neighbors(node as u32) {
-                        let contribution = self.damping * node_score * weight / total_weight;
-                        norm_buf[neighbor as usize] += contribution;
-                    }
+                    let (start, end) = (graph.row_ptr[node], graph.row_ptr[node + 1]);
+                    kernels::scatter_add(
+                        norm_buf,
+                        &graph.col_idx[start..end],
+                        &graph.weights[start..end],
+                        self.damping * node_score,
+                        total_weight,
+                    );
                 }
             }
 
-            delta = score_buf
-                .iter()
-                .zip(norm_buf.iter())
-                .map(|(old, new)| (old - new).abs())
-                .sum();
+            delta = super::residual(self.convergence_criterion, score_buf, norm_buf);
 
-            std::mem::swap(score_buf, norm_buf);
+            core::mem::swap(score_buf, norm_buf);
         }
 
-        let sum: f64 = score_buf.iter().sum();
+        let sum = super::score_sum(score_buf);
         if sum > 0.0 {
             for score in score_buf.iter_mut() {
                 *score /= sum;
@@ -219,36 +304,9 @@ impl PersonalizedPageRank {
     /// Prepare and normalize the personalization vector
     fn prepare_personalization(&self, n: usize) -> Vec<f64> {
         match &self.personalization {
-            Some(p) if p.len() == n => {
-                // Normalize the provided personalization
-                let sum: f64 = p.iter().sum();
-                if sum > 0.0 {
-                    p.iter().map(|&x| x / sum).collect()
-                } else {
-                    // Fall back to uniform if sum is 0
-                    vec![1.0 / n as f64; n]
-                }
-            }
-            Some(p) => {
-                // Resize to match graph size
-                let mut result = vec![0.0; n];
-                for (i, &v) in p.iter().enumerate().take(n) {
-                    result[i] = v;
-                }
-                let sum: f64 = result.iter().sum();
-                if sum > 0.0 {
-                    for v in &mut result {
-                        *v /= sum;
-                    }
-                } else {
-                    result = vec![1.0 / n as f64; n];
-                }
-                result
-            }
-            None => {
-                // Uniform distribution (equivalent to standard PageRank)
-                vec![1.0 / n as f64; n]
-            }
+            Some(p) => super::normalize_personalization(p, n),
+            // Uniform distribution (equivalent to standard PageRank)
+            None => vec![1.0 / n as f64; n],
         }
     }
 }
@@ -291,22 +349,26 @@ pub fn focus_based_personalization(
 /// Each node's teleport probability is proportional to its topic weight.
 /// Words not in `topic_weights` receive `min_weight` (PKE uses 0.0 for OOV).
 ///
-/// When `use_pos_in_nodes` is true, the graph keys are `"lemma|POS"`. Each
-/// lemma weight is applied to all POS variants present in the graph.
+/// `node_key` is a [`NodeKey`](crate::types::NodeKey), or `use_pos_in_nodes`
+/// as a `bool`. Under [`NodeKey::LemmaPos`](crate::types::NodeKey::LemmaPos)
+/// the graph keys are `"lemma|POS"`, and each lemma weight is applied to all
+/// POS variants present in the graph. Under the other strategies
+/// `topic_weights` are looked up by graph key.
 ///
 /// The returned vector is **not** normalized — `PersonalizedPageRank::run`
 /// normalizes internally.
+#[cfg(feature = "std")]
 pub fn topic_weight_personalization(
     topic_weights: &std::collections::HashMap<String, f64>,
     graph: &CsrGraph,
     include_pos: &[crate::types::PosTag],
-    use_pos_in_nodes: bool,
+    node_key: impl Into<crate::types::NodeKey>,
     min_weight: f64,
 ) -> Vec<f64> {
     let num_nodes = graph.num_nodes;
     let mut personalization = vec![min_weight; num_nodes];
 
-    if use_pos_in_nodes {
+    if matches!(node_key.into(), crate::types::NodeKey::LemmaPos) {
         let default_pos = [
             crate::types::PosTag::Noun,
             crate::types::PosTag::Adjective,
@@ -443,6 +505,52 @@ mod tests {
         assert!((personalization[2] - 5.0).abs() < 1e-6);
     }
 
+    #[test]
+    fn test_dangling_policy_uniform_vs_personalization() {
+        // a → b → c, with c dangling and all teleport mass on a.
+        let mut builder = GraphBuilder::new();
+        let a = builder.get_or_create_node("a");
+        let b = builder.get_or_create_node("b");
+        let c = builder.get_or_create_node("c");
+        builder.increment_directed_edge(a, b, 1.0);
+        builder.increment_directed_edge(b, c, 1.0);
+        let graph = CsrGraph::from_builder(&builder);
+
+        let ppr = PersonalizedPageRank::new().with_personalization(vec![1.0, 0.0, 0.0]);
+        let personalized = ppr.run(&graph);
+        let uniform = ppr
+            .clone()
+            .with_dangling_policy(DanglingPolicy::Uniform)
+            .run(&graph);
+        let self_loop = ppr
+            .with_dangling_policy(DanglingPolicy::SelfLoop)
+            .run(&graph);
+
+        // Uniform redistribution leaks dangling mass to c directly.
+        assert!(uniform.scores[0] < personalized.scores[0]);
+        assert!(uniform.scores[2] > personalized.scores[2]);
+        assert!(self_loop.scores[2] > uniform.scores[2]);
+        for result in [&personalized, &uniform, &self_loop] {
+            assert!((result.scores.iter().sum::<f64>() - 1.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_warm_start_from_solution() {
+        let graph = build_line_graph();
+        let ppr = PersonalizedPageRank::new().with_personalization(vec![3.0, 1.0]);
+        let cold = ppr.run(&graph);
+        let warm = ppr
+            .clone()
+            .with_initial_scores(cold.scores.clone())
+            .run(&graph);
+
+        assert!(warm.iterations < cold.iterations);
+        for (w, c) in warm.scores.iter().zip(&cold.scores) {
+            assert!((w - c).abs() < 1e-6);
+        }
+    }
+
     #[test]
     fn test_empty_graph() {
         let graph = CsrGraph::default();
@@ -555,4 +663,20 @@ mod tests {
         let uniform_result = PersonalizedPageRank::new().run(&graph);
         assert!(result.scores[2] > uniform_result.scores[2]);
     }
+
+    #[test]
+    fn test_convergence_criterion_residual() {
+        let graph = build_line_graph();
+        let ppr = PersonalizedPageRank::new().with_personalization(vec![1.0, 0.0, 0.0]);
+        let l1 = ppr.run(&graph);
+        let l2 = ppr
+            .clone()
+            .with_convergence_criterion(ConvergenceCriterion::L2)
+            .run(&graph);
+
+        assert!(l2.converged);
+        assert!(l2.delta <= 1e-6);
+        assert!(l2.iterations <= l1.iterations);
+        assert_eq!(l1.scores.len(), l2.scores.len());
+    }
 }
@@ -3,8 +3,10 @@
 //! Implements the classic PageRank with power iteration and proper
 //! handling of dangling nodes.
 
-use super::PageRankResult;
-use crate::graph::csr::CsrGraph;
+use super::{kernels, ConvergenceCriterion, DanglingPolicy, PageRankResult};
+use crate::graph::csr::{CsrGraph, CsrView};
+use alloc::{vec, vec::Vec};
+use core::ops::ControlFlow;
 
 /// Standard PageRank implementation
 #[derive(Debug, Clone)]
@@ -15,6 +17,13 @@ pub struct StandardPageRank {
     pub max_iterations: usize,
     /// Convergence threshold
     pub threshold: f64,
+    /// Where dangling-node mass goes
+    pub dangling_policy: DanglingPolicy,
+    /// Norm of the per-iteration change compared against `threshold`
+    pub convergence_criterion: ConvergenceCriterion,
+    /// Starting score vector, indexed by node ID (uniform when `None`);
+    /// see [`with_initial_scores`](Self::with_initial_scores)
+    pub initial_scores: Option<Vec<f64>>,
 }
 
 impl Default for StandardPageRank {
@@ -23,6 +32,9 @@ impl Default for StandardPageRank {
             damping: 0.85,
             max_iterations: 100,
             threshold: 1e-6,
+            dangling_policy: DanglingPolicy::default(),
+            convergence_criterion: ConvergenceCriterion::default(),
+            initial_scores: None,
         }
     }
 }
@@ -33,6 +45,19 @@ impl StandardPageRank {
         Self::default()
     }
 
+    /// Create with the PageRank parameters of `cfg`: `damping`,
+    /// `max_iterations`, `convergence_threshold`, `dangling_policy` and
+    /// `convergence_criterion`
+    #[cfg(feature = "std")]
+    pub fn from_config(cfg: &crate::types::TextRankConfig) -> Self {
+        Self::new()
+            .with_damping(cfg.damping)
+            .with_max_iterations(cfg.max_iterations)
+            .with_threshold(cfg.convergence_threshold)
+            .with_dangling_policy(cfg.dangling_policy)
+            .with_convergence_criterion(cfg.convergence_criterion)
+    }
+
     /// Set the damping factor
     pub fn with_damping(mut self, damping: f64) -> Self {
         self.damping = damping;
@@ -51,62 +76,123 @@ impl StandardPageRank {
         self
     }
 
+    /// Set where the score of dangling nodes goes
+    pub fn with_dangling_policy(mut self, policy: DanglingPolicy) -> Self {
+        self.dangling_policy = policy;
+        self
+    }
+
+    /// Set the norm used to decide convergence
+    pub fn with_convergence_criterion(mut self, criterion: ConvergenceCriterion) -> Self {
+        self.convergence_criterion = criterion;
+        self
+    }
+
+    /// Warm-start from a previous score vector (indexed by node ID)
+    ///
+    /// Starting near the solution, e.g. the scores of a slightly edited
+    /// document or of a neighbouring damping value, cuts the number of
+    /// iterations needed. See [`fill_initial_scores`](super::fill_initial_scores)
+    /// for how mismatched lengths and invalid entries are handled.
+    pub fn with_initial_scores(mut self, scores: Vec<f64>) -> Self {
+        self.initial_scores = Some(scores);
+        self
+    }
+
     /// Run PageRank on a graph
     ///
     /// Returns the result even if convergence wasn't achieved, with `converged=false`.
     pub fn run(&self, graph: &CsrGraph) -> PageRankResult {
+        self.run_impl(graph.view(), None)
+    }
+
+    /// Run PageRank on borrowed adjacency arrays, e.g. a memory-mapped
+    /// graph
+    pub fn run_view(&self, graph: CsrView<'_>) -> PageRankResult {
+        self.run_impl(graph, None)
+    }
+
+    /// Run PageRank, calling `progress(iteration, residual)` every `every`
+    /// iterations
+    ///
+    /// Returning [`ControlFlow::Break`] from `progress` stops iterating; the
+    /// result then has `converged=false` unless the residual already met the
+    /// threshold.
+    pub fn run_with_progress(
+        &self,
+        graph: &CsrGraph,
+        every: u32,
+        progress: &mut dyn FnMut(u32, f64) -> ControlFlow<()>,
+    ) -> PageRankResult {
+        self.run_impl(graph.view(), Some((every.max(1), progress)))
+    }
+
+    fn run_impl(
+        &self,
+        graph: CsrView<'_>,
+        mut progress: Option<(u32, super::IterationHook<'_>)>,
+    ) -> PageRankResult {
         let n = graph.num_nodes;
         if n == 0 {
             return PageRankResult::new(vec![], 0, 0.0, true);
         }
 
-        // Initialize scores uniformly
-        let initial_score = 1.0 / n as f64;
-        let mut scores = vec![initial_score; n];
+        // Initialize scores (uniform unless warm-started)
+        let mut scores = Vec::with_capacity(n);
+        super::fill_initial_scores(&mut scores, self.initial_scores.as_deref(), n);
         let mut new_scores = vec![0.0; n];
 
         // Precompute dangling node mass contribution
         let dangling_nodes = graph.dangling_nodes();
 
-        let teleport = (1.0 - self.damping) / n as f64;
         let mut iterations = 0;
         let mut delta = f64::MAX;
 
         while iterations < self.max_iterations && delta > self.threshold {
             iterations += 1;
 
-            // Calculate dangling mass
-            let dangling_mass: f64 = dangling_nodes.iter().map(|&d| scores[d as usize]).sum();
-            let dangling_contribution = self.damping * dangling_mass / n as f64;
-
-            // Reset new scores
-            new_scores.fill(teleport + dangling_contribution);
+            // Reset new scores to the teleport + dangling-node terms
+            super::fill_base_scores(
+                &mut new_scores,
+                &scores,
+                &dangling_nodes,
+                None,
+                self.damping,
+                self.dangling_policy,
+            );
 
             // Propagate scores through edges
             for (node, &node_score) in scores.iter().enumerate() {
                 let total_weight = graph.node_total_weight(node as u32);
 
                 if total_weight > 0.0 {
-                    for (neighbor, weight) in graph.neighbors(node as u32) {
-                        let contribution = self.damping * node_score * weight / total_weight;
-                        new_scores[neighbor as usize] += contribution;
-                    }
+                    let (start, end) = (graph.row_ptr[node], graph.row_ptr[node + 1]);
+                    kernels::scatter_add(
+                        &mut new_scores,
+                        &graph.col_idx[start..end],
+                        &graph.weights[start..end],
+                        self.damping * node_score,
+                        total_weight,
+                    );
                 }
             }
 
-            // Calculate convergence delta (L1 norm)
-            delta = scores
-                .iter()
-                .zip(new_scores.iter())
-                .map(|(old, new)| (old - new).abs())
-                .sum();
+            // Calculate convergence delta under `convergence_criterion`
+            delta = super::residual(self.convergence_criterion, &scores, &new_scores);
 
             // Swap buffers
-            std::mem::swap(&mut scores, &mut new_scores);
+            core::mem::swap(&mut scores, &mut new_scores);
+
+            if let Some((every, progress)) = progress.as_mut() {
+                let iteration = iterations as u32;
+                if iteration.is_multiple_of(*every) && progress(iteration, delta).is_break() {
+                    break;
+                }
+            }
         }
 
         // Normalize scores (they should already sum to ~1, but ensure numerical stability)
-        let sum: f64 = scores.iter().sum();
+        let sum = super::score_sum(&scores);
         if sum > 0.0 {
             for score in &mut scores {
                 *score /= sum;
@@ -133,45 +219,46 @@ impl StandardPageRank {
             return PageRankResult::new(vec![], 0, 0.0, true);
         }
 
-        let initial_score = 1.0 / n as f64;
-        score_buf.clear();
-        score_buf.resize(n, initial_score);
+        super::fill_initial_scores(score_buf, self.initial_scores.as_deref(), n);
         norm_buf.clear();
         norm_buf.resize(n, 0.0);
 
         let dangling_nodes = graph.dangling_nodes();
-        let teleport = (1.0 - self.damping) / n as f64;
         let mut iterations = 0;
         let mut delta = f64::MAX;
 
         while iterations < self.max_iterations && delta > self.threshold {
             iterations += 1;
 
-            let dangling_mass: f64 = dangling_nodes.iter().map(|&d| score_buf[d as usize]).sum();
-            let dangling_contribution = self.damping * dangling_mass / n as f64;
-
-            norm_buf.fill(teleport + dangling_contribution);
+            super::fill_base_scores(
+                norm_buf,
+                score_buf,
+                &dangling_nodes,
+                None,
+                self.damping,
+                self.dangling_policy,
+            );
 
             for (node, &node_score) in score_buf.iter().enumerate() {
                 let total_weight = graph.node_total_weight(node as u32);
                 if total_weight > 0.0 {
-                    for (neighbor, weight) in graph.neighbors(node as u32) {
-                        let contribution = self.damping * node_score * weight / total_weight;
-                        norm_buf[neighbor as usize] += contribution;
-                    }
+                    let (start, end) = (graph.row_ptr[node], graph.row_ptr[node + 1]);
+                    kernels::scatter_add(
+                        norm_buf,
+                        &graph.col_idx[start..end],
+                        &graph.weights[start..end],
+                        self.damping * node_score,
+                        total_weight,
+                    );
                 }
             }
 
-            delta = score_buf
-                .iter()
-                .zip(norm_buf.iter())
-                .map(|(old, new)| (old - new).abs())
-                .sum();
+            delta = super::residual(self.convergence_criterion, score_buf, norm_buf);
 
-            std::mem::swap(score_buf, norm_buf);
+            core::mem::swap(score_buf, norm_buf);
         }
 
-        let sum: f64 = score_buf.iter().sum();
+        let sum = super::score_sum(score_buf);
         if sum > 0.0 {
             for score in score_buf.iter_mut() {
                 *score /= sum;
@@ -201,22 +288,25 @@ impl StandardPageRank {
             return PageRankResult::new(vec![], 0, 0.0, true);
         }
 
-        let initial_score = 1.0 / n as f64;
-        let mut scores = vec![initial_score; n];
+        let mut scores = Vec::with_capacity(n);
+        super::fill_initial_scores(&mut scores, self.initial_scores.as_deref(), n);
         let mut new_scores = vec![0.0; n];
 
         let dangling_nodes = graph.dangling_nodes();
-        let teleport = (1.0 - self.damping) / n as f64;
         let mut iterations = 0;
         let mut delta = f64::MAX;
 
         while iterations < self.max_iterations && delta > self.threshold {
             iterations += 1;
 
-            let dangling_mass: f64 = dangling_nodes.iter().map(|&d| scores[d as usize]).sum();
-            let dangling_contribution = self.damping * dangling_mass / n as f64;
-
-            new_scores.fill(teleport + dangling_contribution);
+            super::fill_base_scores(
+                &mut new_scores,
+                &scores,
+                &dangling_nodes,
+                None,
+                self.damping,
+                self.dangling_policy,
+            );
 
             for (node, &node_score) in scores.iter().enumerate() {
                 let degree = graph.degree(node as u32);
@@ -229,16 +319,12 @@ impl StandardPageRank {
                 }
             }
 
-            delta = scores
-                .iter()
-                .zip(new_scores.iter())
-                .map(|(old, new)| (old - new).abs())
-                .sum();
+            delta = super::residual(self.convergence_criterion, &scores, &new_scores);
 
-            std::mem::swap(&mut scores, &mut new_scores);
+            core::mem::swap(&mut scores, &mut new_scores);
         }
 
-        let sum: f64 = scores.iter().sum();
+        let sum = super::score_sum(&scores);
         if sum > 0.0 {
             for score in &mut scores {
                 *score /= sum;
@@ -367,6 +453,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_run_with_progress() {
+        let graph = build_star_graph();
+        let pr = StandardPageRank::new();
+        let expected = pr.run(&graph);
+        assert!(expected.iterations > 4);
+
+        let mut seen = Vec::new();
+        let result = pr.run_with_progress(&graph, 2, &mut |iteration, residual| {
+            seen.push((iteration, residual));
+            ControlFlow::Continue(())
+        });
+        assert_eq!(result.scores, expected.scores);
+        assert_eq!(seen.len(), expected.iterations / 2);
+        assert!(seen.iter().all(|&(iteration, _)| iteration % 2 == 0));
+
+        // Stop after the first report.
+        let stopped = pr.run_with_progress(&graph, 2, &mut |_, _| ControlFlow::Break(()));
+        assert_eq!(stopped.iterations, 2);
+        assert!(!stopped.converged);
+    }
+
     #[test]
     fn test_run_reusing_buffers_retain_capacity() {
         let graph = build_star_graph();
@@ -419,4 +527,103 @@ mod tests {
         // Hub should be first
         assert_eq!(top_2[0].0, 0);
     }
+
+    #[test]
+    fn test_warm_start_converges_faster() {
+        let graph = build_star_graph();
+        let previous = StandardPageRank::new().with_damping(0.85).run(&graph);
+
+        let pr = StandardPageRank::new().with_damping(0.86);
+        let cold = pr.run(&graph);
+        let warm = pr.clone().with_initial_scores(previous.scores).run(&graph);
+
+        assert!(warm.converged);
+        assert!(warm.iterations < cold.iterations);
+        for (w, c) in warm.scores.iter().zip(&cold.scores) {
+            assert!((w - c).abs() < 1e-5);
+        }
+
+        // run_reusing honours the warm start too.
+        let (mut score_buf, mut norm_buf) = (Vec::new(), Vec::new());
+        let reused = pr.with_initial_scores(warm.scores.clone()).run_reusing(
+            &graph,
+            &mut score_buf,
+            &mut norm_buf,
+        );
+        assert!(reused.iterations <= 2);
+    }
+
+    #[test]
+    fn test_dangling_policies() {
+        // a → b → c, with c dangling.
+        let mut builder = GraphBuilder::new();
+        let a = builder.get_or_create_node("a");
+        let b = builder.get_or_create_node("b");
+        let c = builder.get_or_create_node("c");
+        builder.increment_directed_edge(a, b, 1.0);
+        builder.increment_directed_edge(b, c, 1.0);
+        let graph = CsrGraph::from_builder(&builder);
+
+        let run = |policy| {
+            StandardPageRank::new()
+                .with_dangling_policy(policy)
+                .run(&graph)
+        };
+        let personalization = run(DanglingPolicy::Personalization);
+        let uniform = run(DanglingPolicy::Uniform);
+        let self_loop = run(DanglingPolicy::SelfLoop);
+
+        // Without personalization the teleport distribution is uniform.
+        assert_eq!(personalization.scores, uniform.scores);
+        // Keeping c's mass on c makes it a sink.
+        assert!(self_loop.scores[2] > uniform.scores[2]);
+        assert!(self_loop.scores[0] < uniform.scores[0]);
+        assert!((self_loop.scores.iter().sum::<f64>() - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_convergence_criteria() {
+        let graph = build_star_graph();
+        let run = |criterion| {
+            StandardPageRank::new()
+                .with_convergence_criterion(criterion)
+                .run(&graph)
+        };
+        let l1 = run(ConvergenceCriterion::L1);
+        let linf = run(ConvergenceCriterion::LInf);
+
+        // L∞ ≤ L2 ≤ L1, so the smaller norms never need more iterations.
+        for criterion in [ConvergenceCriterion::L2, ConvergenceCriterion::LInf] {
+            let result = run(criterion);
+            assert!(result.converged);
+            assert!(result.iterations <= l1.iterations);
+        }
+        let relative = run(ConvergenceCriterion::Relative);
+        assert!(relative.converged);
+        assert!(relative.delta <= 1e-6);
+        for (a, b) in relative.scores.iter().zip(&l1.scores) {
+            assert!((a - b).abs() < 1e-5);
+        }
+
+        // The default is L1 and run_reusing reports the same residual.
+        assert_eq!(StandardPageRank::new().run(&graph).delta, l1.delta);
+        let (mut score_buf, mut norm_buf) = (Vec::new(), Vec::new());
+        let reused = StandardPageRank::new()
+            .with_convergence_criterion(ConvergenceCriterion::LInf)
+            .run_reusing(&graph, &mut score_buf, &mut norm_buf);
+        assert_eq!(reused.delta, linf.delta);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_config() {
+        let cfg = crate::types::TextRankConfig::default()
+            .with_damping(0.7)
+            .with_max_iterations(7);
+        let pr = StandardPageRank::from_config(&cfg);
+        assert_eq!(pr.damping, 0.7);
+        assert_eq!(pr.max_iterations, 7);
+        assert_eq!(pr.threshold, cfg.convergence_threshold);
+        assert_eq!(pr.dangling_policy, cfg.dangling_policy);
+    }
 }
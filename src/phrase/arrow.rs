@@ -0,0 +1,230 @@
+//! Arrow output for batch extraction
+//!
+//! [`extract_batch_arrow`] runs [`extract_batch`] and returns every phrase of
+//! every document as one Arrow [`RecordBatch`], one row per phrase, ready to
+//! hand to Polars, DataFusion or pyarrow without a per-phrase conversion:
+//!
+//! | column   | type                                       |
+//! |----------|--------------------------------------------|
+//! | `doc_id` | `UInt32` — index into the input slice      |
+//! | `rank`   | `UInt32`                                   |
+//! | `phrase` | `Utf8`                                     |
+//! | `lemma`  | `Utf8`                                     |
+//! | `score`  | `Float64`                                  |
+//! | `count`  | `UInt32`                                   |
+//! | `spans`  | `List<Struct<start: UInt64, end: UInt64>>` |
+//!
+//! Rows are in input order, and within a document in rank order. Documents
+//! with no phrases contribute no rows.
+
+use super::batch::{extract_batch, BatchDocument};
+use super::extraction::ExtractionResult;
+use crate::types::TextRankConfig;
+use arrow_array::builder::StringBuilder;
+use arrow_array::{
+    ArrayRef, Float64Array, ListArray, RecordBatch, StructArray, UInt32Array, UInt64Array,
+};
+use arrow_buffer::{OffsetBuffer, ScalarBuffer};
+use arrow_schema::{ArrowError, DataType, Field, Fields, Schema, SchemaRef};
+use std::sync::{Arc, OnceLock};
+
+/// Fields of the span struct (`start`, `end` byte offsets).
+fn span_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("start", DataType::UInt64, false),
+        Field::new("end", DataType::UInt64, false),
+    ])
+}
+
+fn span_item_field() -> Arc<Field> {
+    Arc::new(Field::new("item", DataType::Struct(span_fields()), false))
+}
+
+/// Schema of the batches built by [`extract_batch_arrow`] and
+/// [`results_to_record_batch`].
+pub fn phrase_schema() -> SchemaRef {
+    static SCHEMA: OnceLock<SchemaRef> = OnceLock::new();
+    SCHEMA
+        .get_or_init(|| {
+            Arc::new(Schema::new(vec![
+                Field::new("doc_id", DataType::UInt32, false),
+                Field::new("rank", DataType::UInt32, false),
+                Field::new("phrase", DataType::Utf8, false),
+                Field::new("lemma", DataType::Utf8, false),
+                Field::new("score", DataType::Float64, false),
+                Field::new("count", DataType::UInt32, false),
+                Field::new("spans", DataType::List(span_item_field()), false),
+            ]))
+        })
+        .clone()
+}
+
+/// Extract keyphrases from every document in parallel and collect them into
+/// a single [`RecordBatch`] with the [`phrase_schema`].
+///
+/// ```
+/// use rapid_textrank::phrase::arrow::extract_batch_arrow;
+/// use rapid_textrank::phrase::batch::BatchDocument;
+/// use rapid_textrank::TextRankConfig;
+///
+/// let docs = [
+///     BatchDocument::from("Rust is a systems programming language."),
+///     BatchDocument::from("Graphs have nodes and edges."),
+/// ];
+/// let batch = extract_batch_arrow(&docs, &TextRankConfig::default()).unwrap();
+/// assert_eq!(batch.num_columns(), 7);
+/// assert!(batch.num_rows() > 0);
+/// ```
+pub fn extract_batch_arrow(
+    docs: &[BatchDocument<'_>],
+    config: &TextRankConfig,
+) -> Result<RecordBatch, ArrowError> {
+    results_to_record_batch(&extract_batch(docs, config))
+}
+
+/// Convert batch results to a [`RecordBatch`]; `doc_id` is the position of
+/// each result in `results`.
+pub fn results_to_record_batch(results: &[ExtractionResult]) -> Result<RecordBatch, ArrowError> {
+    let rows: usize = results.iter().map(|r| r.phrases.len()).sum();
+    let phrases = || results.iter().flat_map(|r| &r.phrases);
+    let num_spans: usize = phrases().map(|p| p.spans.len()).sum();
+    let text_bytes: usize = phrases().map(|p| p.text.len()).sum();
+    let lemma_bytes: usize = phrases().map(|p| p.lemma.len()).sum();
+
+    let mut doc_ids = Vec::with_capacity(rows);
+    let mut ranks = Vec::with_capacity(rows);
+    let mut text = StringBuilder::with_capacity(rows, text_bytes);
+    let mut lemma = StringBuilder::with_capacity(rows, lemma_bytes);
+    let mut scores = Vec::with_capacity(rows);
+    let mut counts = Vec::with_capacity(rows);
+    let mut span_offsets = Vec::with_capacity(rows + 1);
+    let mut starts = Vec::with_capacity(num_spans);
+    let mut ends = Vec::with_capacity(num_spans);
+    span_offsets.push(0i32);
+
+    for (doc_id, result) in results.iter().enumerate() {
+        for phrase in &result.phrases {
+            doc_ids.push(to_u32(doc_id, "doc_id")?);
+            ranks.push(to_u32(phrase.rank, "rank")?);
+            text.append_value(&phrase.text);
+            lemma.append_value(&phrase.lemma);
+            scores.push(phrase.score);
+            counts.push(to_u32(phrase.count, "count")?);
+            for &(start, end) in &phrase.spans {
+                starts.push(start as u64);
+                ends.push(end as u64);
+            }
+            let offset = i32::try_from(starts.len())
+                .map_err(|_| ArrowError::ComputeError("spans column overflows i32".into()))?;
+            span_offsets.push(offset);
+        }
+    }
+
+    let spans = StructArray::try_new(
+        span_fields(),
+        vec![
+            Arc::new(UInt64Array::from(starts)) as ArrayRef,
+            Arc::new(UInt64Array::from(ends)),
+        ],
+        None,
+    )?;
+    let spans = ListArray::try_new(
+        span_item_field(),
+        OffsetBuffer::new(ScalarBuffer::from(span_offsets)),
+        Arc::new(spans),
+        None,
+    )?;
+
+    RecordBatch::try_new(
+        phrase_schema(),
+        vec![
+            Arc::new(UInt32Array::from(doc_ids)),
+            Arc::new(UInt32Array::from(ranks)),
+            Arc::new(text.finish()),
+            Arc::new(lemma.finish()),
+            Arc::new(Float64Array::from(scores)),
+            Arc::new(UInt32Array::from(counts)),
+            Arc::new(spans),
+        ],
+    )
+}
+
+fn to_u32(value: usize, column: &str) -> Result<u32, ArrowError> {
+    u32::try_from(value)
+        .map_err(|_| ArrowError::ComputeError(format!("{column} {value} overflows u32")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Phrase, TextRankConfig};
+    use arrow_array::{Array, StringArray};
+
+    fn result(phrases: Vec<Phrase>) -> ExtractionResult {
+        ExtractionResult {
+            phrases,
+            converged: true,
+            iterations: 1,
+            debug: None,
+        }
+    }
+
+    #[test]
+    fn test_results_to_record_batch() {
+        let mut graph = Phrase::new("graph ranking", "graph rank", 0.5, 2);
+        graph.rank = 1;
+        graph.spans = vec![(0, 13), (20, 33)];
+        let mut rust = Phrase::new("Rust", "rust", 0.25, 1);
+        rust.rank = 1;
+        rust.spans = vec![(4, 8)];
+        let results = [result(vec![graph]), result(vec![]), result(vec![rust])];
+
+        let batch = results_to_record_batch(&results).unwrap();
+        assert_eq!(batch.schema(), phrase_schema());
+        assert_eq!(batch.num_rows(), 2);
+
+        let column = |name: &str| batch.column_by_name(name).unwrap().clone();
+        let doc_id = column("doc_id");
+        let doc_id = doc_id.as_any().downcast_ref::<UInt32Array>().unwrap();
+        assert_eq!(doc_id.values(), &[0, 2]);
+        let lemma = column("lemma");
+        let lemma = lemma.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(lemma.value(1), "rust");
+
+        let spans = column("spans");
+        let spans = spans.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(spans.value_length(0), 2);
+        let first = spans.value(0);
+        let first = first.as_any().downcast_ref::<StructArray>().unwrap();
+        let ends = first
+            .column(1)
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap();
+        assert_eq!(ends.values(), &[13, 33]);
+    }
+
+    #[test]
+    fn test_extract_batch_arrow_matches_extract_batch() {
+        let config = TextRankConfig::default();
+        let docs = [
+            BatchDocument::from(
+                "Graph algorithms rank nodes. Ranking nodes in graphs uses random walks.",
+            ),
+            BatchDocument::from(""),
+        ];
+        let batch = extract_batch_arrow(&docs, &config).unwrap();
+        let expected = extract_batch(&docs, &config);
+        assert_eq!(batch.num_rows(), expected[0].phrases.len());
+
+        let phrase = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        for (i, p) in expected[0].phrases.iter().enumerate() {
+            assert_eq!(phrase.value(i), p.text);
+        }
+        assert_eq!(results_to_record_batch(&[]).unwrap().num_rows(), 0);
+    }
+}
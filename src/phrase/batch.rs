@@ -0,0 +1,336 @@
+//! Batch keyphrase extraction across many documents
+//!
+//! [`extract_batch`] runs the standard TextRank extraction of
+//! [`extract_keyphrases_with_info`](super::extraction::extract_keyphrases_with_info)
+//! over a slice of documents in parallel with rayon. Raw-text documents are
+//! tokenized with a shared [`Lemmatizer`] and [`StopwordFilter`], so
+//! stopword lists are built once per language rather than once per
+//! document. With the `lang-detect` feature and `config.detect_language`
+//! set, each raw-text document is tokenized and extracted with the config
+//! for its detected language, as
+//! [`extract_from_text_with_info`](super::extraction::extract_from_text_with_info)
+//! does. Results come back in input order and are identical to running each
+//! document on its own.
+//!
+//! The batch runs on the current rayon pool; wrap the call in
+//! `ThreadPool::install` to bound the number of threads.
+
+use super::extraction::{extract_keyphrases_observed, lemmatized, ExtractionResult};
+use crate::nlp::lemma::Lemmatizer;
+use crate::nlp::stopwords::StopwordFilter;
+use crate::nlp::token_filter::JunkTokenFilter;
+use crate::nlp::tokenizer::Tokenizer;
+use crate::nlp::variants::link_variants;
+use crate::pipeline::observer::{
+    BatchStatsObserver, NoopObserver, PipelineObserver, StageClock, StageReport,
+    StageTimingObserver, STAGE_PREPROCESS,
+};
+use crate::types::{TextRankConfig, Token};
+use rayon::prelude::*;
+use std::borrow::Cow;
+
+/// One document in a batch: pre-tokenized input or raw text
+#[derive(Debug, Clone, Copy)]
+pub enum BatchDocument<'a> {
+    /// Tokens from an external NLP pipeline
+    Tokens(&'a [Token]),
+    /// Raw text, tokenized with the built-in [`Tokenizer`]
+    Text(&'a str),
+}
+
+impl<'a> From<&'a [Token]> for BatchDocument<'a> {
+    fn from(tokens: &'a [Token]) -> Self {
+        BatchDocument::Tokens(tokens)
+    }
+}
+
+impl<'a> From<&'a Vec<Token>> for BatchDocument<'a> {
+    fn from(tokens: &'a Vec<Token>) -> Self {
+        BatchDocument::Tokens(tokens)
+    }
+}
+
+impl<'a> From<&'a str> for BatchDocument<'a> {
+    fn from(text: &'a str) -> Self {
+        BatchDocument::Text(text)
+    }
+}
+
+impl<'a> From<&'a String> for BatchDocument<'a> {
+    fn from(text: &'a String) -> Self {
+        BatchDocument::Text(text)
+    }
+}
+
+/// Extract keyphrases from every document in parallel.
+///
+/// ```
+/// use rapid_textrank::phrase::batch::{extract_batch, BatchDocument};
+/// use rapid_textrank::TextRankConfig;
+///
+/// let docs: Vec<BatchDocument> = ["Rust is a systems programming language.", "Graphs have nodes."]
+///     .iter()
+///     .map(|&text| text.into())
+///     .collect();
+/// let results = extract_batch(&docs, &TextRankConfig::default());
+/// assert_eq!(results.len(), 2);
+/// ```
+pub fn extract_batch(docs: &[BatchDocument<'_>], config: &TextRankConfig) -> Vec<ExtractionResult> {
+    let shared = SharedState::new(docs, config);
+    docs.par_iter()
+        .enumerate()
+        .map(|(i, doc)| shared.extract(i, *doc, config, &mut NoopObserver))
+        .collect()
+}
+
+/// Like [`extract_batch`], also returning the per-document stage timings.
+///
+/// Each document's [`StageTimingObserver`] holds a `preprocess` report
+/// (tokenization; raw-text documents only) followed by `graph`, `rank` and
+/// `phrases`.
+pub fn extract_batch_with_reports(
+    docs: &[BatchDocument<'_>],
+    config: &TextRankConfig,
+) -> Vec<(ExtractionResult, StageTimingObserver)> {
+    let shared = SharedState::new(docs, config);
+    docs.par_iter()
+        .enumerate()
+        .map(|(i, doc)| {
+            let mut observer = StageTimingObserver::new();
+            let result = shared.extract(i, *doc, config, &mut observer);
+            (result, observer)
+        })
+        .collect()
+}
+
+/// Like [`extract_batch`], also returning stage statistics aggregated over
+/// the whole batch (duration percentiles, mean graph size, convergence
+/// failure rate).
+pub fn extract_batch_with_stats(
+    docs: &[BatchDocument<'_>],
+    config: &TextRankConfig,
+) -> (Vec<ExtractionResult>, BatchStatsObserver) {
+    let shared = SharedState::new(docs, config);
+    let per_doc: Vec<(ExtractionResult, BatchStatsObserver)> = docs
+        .par_iter()
+        .enumerate()
+        .map(|(i, doc)| {
+            let mut observer = BatchStatsObserver::new();
+            let result = shared.extract(i, *doc, config, &mut observer);
+            (result, observer)
+        })
+        .collect();
+
+    let mut stats = BatchStatsObserver::new();
+    let results = per_doc
+        .into_iter()
+        .map(|(result, observer)| {
+            stats.merge(&observer);
+            result
+        })
+        .collect();
+    (results, stats)
+}
+
+/// Tokenization state for one language, shared by every raw-text document
+/// in that language.
+struct TextState {
+    config: TextRankConfig,
+    tokenizer: Tokenizer,
+    lemmatizer: Lemmatizer,
+    stopwords: StopwordFilter,
+    junk: JunkTokenFilter,
+}
+
+impl TextState {
+    fn new(config: TextRankConfig) -> Self {
+        Self {
+            tokenizer: Tokenizer::new(),
+            lemmatizer: Lemmatizer::for_config(&config),
+            stopwords: StopwordFilter::for_config(&config),
+            junk: JunkTokenFilter::for_config(&config),
+            config,
+        }
+    }
+}
+
+/// Tokenization state shared by the raw-text documents of a batch: one
+/// [`TextState`] per document language, and the index of each document's
+/// state.
+struct SharedState {
+    states: Vec<TextState>,
+    doc_state: Vec<Option<usize>>,
+}
+
+impl SharedState {
+    fn new(docs: &[BatchDocument<'_>], config: &TextRankConfig) -> Self {
+        let doc_configs: Vec<Option<Cow<'_, TextRankConfig>>> = docs
+            .par_iter()
+            .map(|doc| match doc {
+                BatchDocument::Tokens(_) => None,
+                BatchDocument::Text(text) => Some(document_config(config, text)),
+            })
+            .collect();
+
+        let mut states: Vec<TextState> = Vec::new();
+        let doc_state = doc_configs
+            .into_iter()
+            .map(|doc_config| {
+                let doc_config = doc_config?;
+                let idx = match states
+                    .iter()
+                    .position(|s| s.config.language == doc_config.language)
+                {
+                    Some(idx) => idx,
+                    None => {
+                        states.push(TextState::new(doc_config.into_owned()));
+                        states.len() - 1
+                    }
+                };
+                Some(idx)
+            })
+            .collect();
+        Self { states, doc_state }
+    }
+
+    fn extract(
+        &self,
+        idx: usize,
+        doc: BatchDocument<'_>,
+        config: &TextRankConfig,
+        observer: &mut impl PipelineObserver,
+    ) -> ExtractionResult {
+        match (doc, self.doc_state[idx]) {
+            (BatchDocument::Tokens(tokens), _) => {
+                extract_keyphrases_observed(&lemmatized(tokens, config), config, observer)
+            }
+            (BatchDocument::Text(text), Some(state)) => {
+                let state = &self.states[state];
+                observer.on_stage_start(STAGE_PREPROCESS);
+                let clock = StageClock::start();
+                let mut tokens =
+                    state
+                        .tokenizer
+                        .tokenize_with(text, &state.lemmatizer, &state.stopwords);
+                state.junk.apply(&mut tokens);
+                link_variants(text, &mut tokens, state.config.variant_linking);
+                observer.on_stage_end(STAGE_PREPROCESS, &StageReport::new(clock.elapsed()));
+                extract_keyphrases_observed(&tokens, &state.config, observer)
+            }
+            (BatchDocument::Text(_), None) => unreachable!("shared state built for text docs"),
+        }
+    }
+}
+
+/// The config a raw-text document is extracted with: adjusted to its
+/// detected language when `config.detect_language` is set.
+fn document_config<'a>(config: &'a TextRankConfig, text: &str) -> Cow<'a, TextRankConfig> {
+    #[cfg(feature = "lang-detect")]
+    return crate::nlp::langdetect::config_for_text(config, text);
+    #[cfg(not(feature = "lang-detect"))]
+    {
+        let _ = text;
+        Cow::Borrowed(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phrase::extraction::{extract_from_text_with_info, extract_keyphrases_with_info};
+    use crate::pipeline::observer::{STAGE_GRAPH, STAGE_PHRASES, STAGE_RANK};
+    use crate::types::{DeterminismMode, PosTag};
+
+    fn make_tokens() -> Vec<Token> {
+        vec![
+            Token::new("Machine", "machine", PosTag::Noun, 0, 7, 0, 0),
+            Token::new("learning", "learning", PosTag::Noun, 8, 16, 0, 1),
+            Token::new("uses", "use", PosTag::Verb, 17, 21, 0, 2),
+            Token::new("neural", "neural", PosTag::Adjective, 22, 28, 0, 3),
+            Token::new("networks", "network", PosTag::Noun, 29, 37, 0, 4),
+        ]
+    }
+
+    #[test]
+    fn test_batch_matches_single_document_runs() {
+        let config = TextRankConfig::default().with_determinism(DeterminismMode::Deterministic);
+        let tokens = make_tokens();
+        let text = "Graph algorithms rank nodes. Ranking nodes in graphs uses random walks.";
+        let docs = [
+            BatchDocument::from(&tokens),
+            BatchDocument::from(text),
+            BatchDocument::Tokens(&[]),
+        ];
+
+        let results = extract_batch(&docs, &config);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], extract_keyphrases_with_info(&tokens, &config));
+        assert_eq!(results[1], extract_from_text_with_info(text, &config));
+        assert!(results[2].phrases.is_empty());
+    }
+
+    #[cfg(feature = "lang-detect")]
+    #[test]
+    fn test_batch_detects_language_per_document() {
+        let config = TextRankConfig::default()
+            .with_determinism(DeterminismMode::Deterministic)
+            .with_detect_language(true);
+        let english = "The model is trained on the data and it is evaluated with a test set.";
+        let german =
+            "Das Modell wird mit den Daten trainiert und ist auch für die Auswertung geeignet.";
+        let docs = [
+            BatchDocument::from(english),
+            BatchDocument::from(german),
+            BatchDocument::from(english),
+        ];
+
+        let results = extract_batch(&docs, &config);
+        assert_eq!(results[0], extract_from_text_with_info(english, &config));
+        assert_eq!(results[1], extract_from_text_with_info(german, &config));
+        assert_eq!(results[2], results[0]);
+        assert!(results[1]
+            .phrases
+            .iter()
+            .all(|p| !["das", "und", "die"].contains(&p.lemma.as_str())));
+    }
+
+    #[test]
+    fn test_batch_with_reports() {
+        let config = TextRankConfig::default();
+        let tokens = make_tokens();
+        let docs = [
+            BatchDocument::from(&tokens),
+            BatchDocument::from("Rust is fast."),
+        ];
+
+        let results = extract_batch_with_reports(&docs, &config);
+        let names = |obs: &StageTimingObserver| -> Vec<&str> {
+            obs.reports().iter().map(|(name, _)| *name).collect()
+        };
+        assert_eq!(
+            names(&results[0].1),
+            [STAGE_GRAPH, STAGE_RANK, STAGE_PHRASES]
+        );
+        assert_eq!(names(&results[1].1)[0], STAGE_PREPROCESS);
+        assert_eq!(results[0].1.reports()[1].1.converged(), Some(true));
+        assert_eq!(results[0].0, extract_batch(&docs[..1], &config)[0]);
+    }
+
+    #[test]
+    fn test_batch_with_stats() {
+        let config = TextRankConfig::default();
+        let tokens = make_tokens();
+        let docs = [
+            BatchDocument::from(&tokens),
+            BatchDocument::from("Rust is fast."),
+            BatchDocument::from(&tokens),
+        ];
+
+        let (results, stats) = extract_batch_with_stats(&docs, &config);
+        assert_eq!(results, extract_batch(&docs, &config));
+        assert_eq!(stats.stage_stats(STAGE_RANK).unwrap().count, 3);
+        assert_eq!(stats.stage_stats(STAGE_PREPROCESS).unwrap().count, 1);
+        assert_eq!(stats.convergence_failure_rate(), Some(0.0));
+        assert!(stats.mean_nodes().unwrap() > 0.0);
+    }
+}
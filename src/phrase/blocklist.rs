@@ -0,0 +1,194 @@
+//! Phrase blocklist and keep-term allowlist
+//!
+//! Domain deployments need boilerplate ("figure 1", "plaintiff", running
+//! headers) removed from results without rebuilding. [`PhraseFilter`]
+//! compiles `TextRankConfig::phrase_blocklist` and
+//! `TextRankConfig::keep_terms` and is applied twice: to scored candidates
+//! before overlap resolution and `top_n` selection (in the built-in phrase
+//! builders), so a blocked phrase never displaces another one, and to the
+//! final phrases in
+//! [`StandardResultFormatter`](crate::pipeline::StandardResultFormatter), so
+//! custom phrase builders are covered too.
+//!
+//! Compiling the regexes is the expensive part, so each thread keeps the
+//! last filter it compiled and reuses it while the config's patterns and
+//! keep terms stay the same.
+//!
+//! Exact patterns and keep terms compare case-insensitively with runs of
+//! whitespace collapsed; regex patterns are searched in the phrase text and
+//! lemma as given. A phrase matching a keep term is never blocked. Keep
+//! terms additionally come off the stopword list (see
+//! [`StopwordFilter::for_config`](crate::nlp::stopwords::StopwordFilter::for_config)).
+
+use crate::types::{Phrase, PhrasePattern, TextRankConfig};
+use regex::Regex;
+use std::cell::RefCell;
+
+/// Compiled blocklist and keep terms of a [`TextRankConfig`]
+#[derive(Debug, Clone, Default)]
+pub struct PhraseFilter {
+    exact: Vec<String>,
+    regexes: Vec<Regex>,
+    keep: Vec<String>,
+}
+
+impl PhraseFilter {
+    /// Compile `config.phrase_blocklist` and `config.keep_terms`.
+    ///
+    /// Invalid regexes are skipped;
+    /// [`TextRankConfig::validate`] reports them. Repeated calls with the
+    /// same patterns and keep terms reuse the compiled filter.
+    pub fn for_config(config: &TextRankConfig) -> Self {
+        type Compiled = (Vec<PhrasePattern>, Vec<String>, PhraseFilter);
+        thread_local! {
+            static LAST: RefCell<Option<Compiled>> = const { RefCell::new(None) };
+        }
+
+        if config.phrase_blocklist.is_empty() {
+            return Self::default();
+        }
+        LAST.with(|last| {
+            let mut last = last.borrow_mut();
+            if let Some((patterns, keep, filter)) = last.as_ref() {
+                if *patterns == config.phrase_blocklist && *keep == config.keep_terms {
+                    return filter.clone();
+                }
+            }
+            let filter = Self::compile(config);
+            *last = Some((
+                config.phrase_blocklist.clone(),
+                config.keep_terms.clone(),
+                filter.clone(),
+            ));
+            filter
+        })
+    }
+
+    fn compile(config: &TextRankConfig) -> Self {
+        let mut filter = Self::default();
+        for pattern in &config.phrase_blocklist {
+            match pattern {
+                PhrasePattern::Exact(text) => filter.exact.push(normalize(text)),
+                PhrasePattern::Regex(re) => {
+                    if let Ok(re) = Regex::new(re) {
+                        filter.regexes.push(re);
+                    }
+                }
+            }
+        }
+        if !filter.is_empty() {
+            filter.keep = config.keep_terms.iter().map(|t| normalize(t)).collect();
+        }
+        filter
+    }
+
+    /// Whether the filter blocks nothing.
+    pub fn is_empty(&self) -> bool {
+        self.exact.is_empty() && self.regexes.is_empty()
+    }
+
+    /// Whether a phrase with this text and lemma is blocked.
+    pub fn is_blocked(&self, text: &str, lemma: &str) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        let (text_key, lemma_key) = (normalize(text), normalize(lemma));
+        if self.keep.iter().any(|k| *k == text_key || *k == lemma_key) {
+            return false;
+        }
+        self.exact.iter().any(|e| *e == text_key || *e == lemma_key)
+            || self
+                .regexes
+                .iter()
+                .any(|re| re.is_match(text) || re.is_match(lemma))
+    }
+
+    /// Drop blocked phrases, keeping the order of the rest.
+    pub fn retain_phrases(&self, phrases: &mut Vec<Phrase>) {
+        if !self.is_empty() {
+            phrases.retain(|p| !self.is_blocked(&p.text, &p.lemma));
+        }
+    }
+}
+
+/// Lowercase and collapse whitespace runs to single spaces.
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(patterns: Vec<PhrasePattern>, keep: &[&str]) -> PhraseFilter {
+        let cfg = TextRankConfig::default()
+            .with_phrase_blocklist(patterns)
+            .with_keep_terms(keep.iter().copied());
+        PhraseFilter::for_config(&cfg)
+    }
+
+    #[test]
+    fn test_exact_and_regex_patterns() {
+        let f = filter(
+            vec![
+                PhrasePattern::Exact("Figure  1".to_string()),
+                PhrasePattern::Regex(r"^table \d+$".to_string()),
+            ],
+            &[],
+        );
+
+        assert!(f.is_blocked("figure 1", "figure 1"));
+        assert!(f.is_blocked("FIGURE 1", "figure 1"));
+        assert!(f.is_blocked("tables", "table 2"));
+        assert!(!f.is_blocked("figure 12", "figure 12"));
+        assert!(!f.is_blocked("table of contents", "table of content"));
+    }
+
+    #[test]
+    fn test_reused_filter_tracks_config() {
+        let table = || vec![PhrasePattern::Regex(r"^table \d+$".to_string())];
+        assert!(filter(table(), &[]).is_blocked("table 2", "table 2"));
+        assert!(!filter(table(), &["table 2"]).is_blocked("table 2", "table 2"));
+        let figure = vec![PhrasePattern::Regex(r"^figure \d+$".to_string())];
+        assert!(!filter(figure, &[]).is_blocked("table 2", "table 2"));
+        assert!(filter(table(), &[]).is_blocked("table 2", "table 2"));
+    }
+
+    #[test]
+    fn test_keep_terms_override_blocklist() {
+        let f = filter(
+            vec![PhrasePattern::Regex("plaintiff".to_string())],
+            &["Plaintiff"],
+        );
+
+        assert!(!f.is_blocked("plaintiff", "plaintiff"));
+        assert!(f.is_blocked("plaintiff counsel", "plaintiff counsel"));
+    }
+
+    #[test]
+    fn test_invalid_regex_is_skipped_and_reported() {
+        let cfg = TextRankConfig::default()
+            .with_phrase_blocklist([PhrasePattern::Regex("(".to_string())]);
+        assert!(PhraseFilter::for_config(&cfg).is_empty());
+        assert!(cfg
+            .validation_errors()
+            .iter()
+            .any(|e| e.config_path().as_deref() == Some("/phrase_blocklist")));
+    }
+
+    #[test]
+    fn test_retain_phrases() {
+        let f = filter(vec![PhrasePattern::Exact("boilerplate".to_string())], &[]);
+        let mut phrases = vec![
+            Phrase::new("Boilerplate", "boilerplate", 0.9, 1),
+            Phrase::new("contract law", "contract law", 0.5, 1),
+        ];
+        f.retain_phrases(&mut phrases);
+
+        assert_eq!(phrases.len(), 1);
+        assert_eq!(phrases[0].text, "contract law");
+    }
+}
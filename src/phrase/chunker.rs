@@ -15,6 +15,9 @@ pub struct ChunkerConfig {
     pub max_length: usize,
     /// Whether to include determiners in chunks
     pub include_determiners: bool,
+    /// Lowercased words (e.g. "of") allowed to join two noun phrases into
+    /// one chunk, as in "quality of service". Empty disables bridging.
+    pub bridge_words: Vec<String>,
 }
 
 impl Default for ChunkerConfig {
@@ -23,6 +26,7 @@ impl Default for ChunkerConfig {
             min_length: 1,
             max_length: 5,
             include_determiners: false,
+            bridge_words: Vec::new(),
         }
     }
 }
@@ -64,6 +68,13 @@ impl NounChunker {
         self
     }
 
+    /// Set the words allowed to bridge two noun phrases (matched
+    /// case-insensitively against the token text)
+    pub fn with_bridge_words<S: AsRef<str>>(mut self, words: &[S]) -> Self {
+        self.config.bridge_words = words.iter().map(|w| w.as_ref().to_lowercase()).collect();
+        self
+    }
+
     /// Extract noun chunks from tokens.
     ///
     /// Pattern: (DET)? (ADJ)* (NOUN|PROPN)+
@@ -208,8 +219,47 @@ impl NounChunker {
 
     /// Try to match a noun phrase pattern starting at position i
     ///
-    /// Pattern: (DET)? (ADJ)* (NOUN|PROPN)+
+    /// Pattern: (DET)? (ADJ)* (NOUN|PROPN)+, optionally followed by
+    /// (BRIDGE (DET)? (ADJ)* (NOUN|PROPN)+)* when bridge words are set.
     fn match_noun_phrase(&self, tokens: &[&Token], start: usize) -> Option<ChunkSpan> {
+        let mut end = self.match_simple_noun_phrase(tokens, start)?;
+
+        // Join following noun phrases across bridge words while the chunk
+        // stays within the maximum length.
+        while end + 1 < tokens.len() && self.is_bridge_word(tokens[end]) {
+            match self.match_simple_noun_phrase(tokens, end + 1) {
+                Some(next_end)
+                    if tokens[next_end - 1].token_idx + 1 - tokens[start].token_idx
+                        <= self.config.max_length =>
+                {
+                    end = next_end
+                }
+                _ => break,
+            }
+        }
+
+        let first_token = tokens[start];
+        let last_token = tokens[end - 1];
+        Some(ChunkSpan {
+            start_token: first_token.token_idx,
+            end_token: last_token.token_idx + 1,
+            start_char: first_token.start,
+            end_char: last_token.end,
+            sentence_idx: first_token.sentence_idx,
+        })
+    }
+
+    fn is_bridge_word(&self, token: &Token) -> bool {
+        if self.config.bridge_words.is_empty() {
+            return false;
+        }
+        let text = token.text.to_lowercase();
+        self.config.bridge_words.contains(&text)
+    }
+
+    /// Match `(DET)? (ADJ)* (NOUN|PROPN)+` at `start`, returning the
+    /// exclusive end index within `tokens`.
+    fn match_simple_noun_phrase(&self, tokens: &[&Token], start: usize) -> Option<usize> {
         if start >= tokens.len() {
             return None;
         }
@@ -218,7 +268,6 @@ impl NounChunker {
         if tokens[end].is_stopword {
             return None;
         }
-        let first_token = tokens[start];
 
         // Optional determiner
         if self.config.include_determiners && tokens[end].pos == PosTag::Determiner {
@@ -248,26 +297,12 @@ impl NounChunker {
         if end == noun_start {
             // No nouns found - check if we're on a standalone noun
             if start < tokens.len() && !tokens[start].is_stopword && tokens[start].pos.is_noun() {
-                return Some(ChunkSpan {
-                    start_token: tokens[start].token_idx,
-                    end_token: tokens[start].token_idx + 1,
-                    start_char: tokens[start].start,
-                    end_char: tokens[start].end,
-                    sentence_idx: tokens[start].sentence_idx,
-                });
+                return Some(start + 1);
             }
             return None;
         }
 
-        let last_token = tokens[end - 1];
-
-        Some(ChunkSpan {
-            start_token: first_token.token_idx,
-            end_token: tokens[end - 1].token_idx + 1,
-            start_char: first_token.start,
-            end_char: last_token.end,
-            sentence_idx: first_token.sentence_idx,
-        })
+        Some(end)
     }
 }
 
@@ -491,4 +526,41 @@ mod tests {
             diags
         );
     }
+
+    #[test]
+    fn test_bridge_words_join_noun_phrases() {
+        // "Quality of service and speed of light"
+        let mut tokens = vec![
+            Token::new("Quality", "quality", PosTag::Noun, 0, 7, 0, 0),
+            Token::new("of", "of", PosTag::Preposition, 8, 10, 0, 1),
+            Token::new("service", "service", PosTag::Noun, 11, 18, 0, 2),
+            Token::new("and", "and", PosTag::Conjunction, 19, 22, 0, 3),
+            Token::new("speed", "speed", PosTag::Noun, 23, 28, 0, 4),
+            Token::new("of", "of", PosTag::Preposition, 29, 31, 0, 5),
+            Token::new("light", "light", PosTag::Noun, 32, 37, 0, 6),
+        ];
+        tokens[1].is_stopword = true;
+        tokens[3].is_stopword = true;
+        tokens[5].is_stopword = true;
+
+        // Without bridge words, stopwords always split.
+        assert_eq!(NounChunker::new().extract_chunks(&tokens).len(), 4);
+
+        let chunks = NounChunker::new()
+            .with_bridge_words(&["OF"])
+            .extract_chunks(&tokens);
+        let spans: Vec<_> = chunks
+            .iter()
+            .map(|c| (c.start_token, c.end_token))
+            .collect();
+        assert_eq!(spans, [(0, 3), (4, 7)]);
+        assert_eq!(chunk_text(&tokens, &chunks[0]), "Quality of service");
+
+        // A bridged chunk over the maximum length is not joined.
+        let chunks = NounChunker::new()
+            .with_max_length(2)
+            .with_bridge_words(&["of"])
+            .extract_chunks(&tokens);
+        assert_eq!(chunks.len(), 4);
+    }
 }
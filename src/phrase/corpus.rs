@@ -0,0 +1,308 @@
+//! Corpus-level keyphrase aggregation
+//!
+//! [`CorpusAggregator`] merges the per-document [`PhraseSet`]s of a
+//! collection into corpus keyphrases. Phrases are grouped by their
+//! whitespace-normalized lemma (falling back to the lowercased surface form
+//! when a phrase's lemma is missing or empty), scored by a [`CorpusScore`] strategy, and
+//! keep the list of documents they were found in.
+//!
+//! Documents are numbered in the order they are added, so feeding the output
+//! of [`extract_batch`](super::batch::extract_batch) keeps batch indices.
+
+use crate::pipeline::artifacts::PhraseSet;
+use crate::types::Phrase;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+/// How per-document scores combine into a corpus score
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CorpusScore {
+    /// Sum of the phrase's scores over the documents it occurs in
+    #[default]
+    Sum,
+    /// Mean of the phrase's scores over the documents it occurs in
+    Mean,
+    /// Mean score times `ln(1 + document frequency)`, favouring phrases
+    /// that recur across documents without letting frequency dominate
+    DocFrequencyWeighted,
+}
+
+/// One document a corpus phrase was found in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentHit {
+    /// Index of the document, in the order documents were added
+    pub doc: usize,
+    /// The phrase's score in that document
+    pub score: f64,
+    /// The phrase's occurrence count in that document
+    pub count: usize,
+}
+
+/// A keyphrase merged across documents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CorpusPhrase {
+    /// Surface form from the document where the phrase scored highest
+    pub text: String,
+    /// Normalized lemma the phrase is grouped by
+    pub lemma: String,
+    /// Corpus score per the aggregator's [`CorpusScore`]
+    pub score: f64,
+    /// Total occurrences across all documents
+    pub count: usize,
+    /// Documents containing the phrase, in document order
+    pub documents: Vec<DocumentHit>,
+}
+
+impl CorpusPhrase {
+    /// Number of documents containing the phrase.
+    pub fn doc_frequency(&self) -> usize {
+        self.documents.len()
+    }
+}
+
+/// Accumulates per-document phrases into corpus keyphrases
+#[derive(Debug, Clone, Default)]
+pub struct CorpusAggregator {
+    scoring: CorpusScore,
+    num_docs: usize,
+    index: FxHashMap<String, usize>,
+    phrases: Vec<CorpusPhrase>,
+}
+
+impl CorpusAggregator {
+    /// Create an empty aggregator using `scoring`.
+    pub fn new(scoring: CorpusScore) -> Self {
+        Self {
+            scoring,
+            ..Self::default()
+        }
+    }
+
+    /// Number of documents added so far.
+    pub fn num_docs(&self) -> usize {
+        self.num_docs
+    }
+
+    /// Add one document's phrase set and return its document index.
+    ///
+    /// Entries with an empty lemma are grouped by their surface form; entries
+    /// with neither are skipped.
+    pub fn add_phrase_set(&mut self, set: &PhraseSet) -> usize {
+        let doc = self.num_docs;
+        self.num_docs += 1;
+        for entry in set.entries() {
+            let Some(text) = entry.surface.as_deref().or(entry.lemma_text.as_deref()) else {
+                continue;
+            };
+            let lemma = entry.lemma_text.as_deref().unwrap_or(text);
+            self.add_hit(doc, text, lemma, entry.score, entry.count as usize);
+        }
+        doc
+    }
+
+    /// Add one document's extracted phrases and return its document index.
+    ///
+    /// Phrases with an empty lemma are grouped by their surface form.
+    pub fn add_phrases(&mut self, phrases: &[Phrase]) -> usize {
+        let doc = self.num_docs;
+        self.num_docs += 1;
+        for phrase in phrases {
+            self.add_hit(doc, &phrase.text, &phrase.lemma, phrase.score, phrase.count);
+        }
+        doc
+    }
+
+    fn add_hit(&mut self, doc: usize, text: &str, lemma: &str, score: f64, count: usize) {
+        let mut key = normalize_lemma(lemma);
+        if key.is_empty() {
+            key = normalize_lemma(text);
+            if key.is_empty() {
+                return;
+            }
+        }
+        let hit = DocumentHit { doc, score, count };
+        match self.index.get(&key) {
+            Some(&i) => {
+                let phrase = &mut self.phrases[i];
+                phrase.count += count;
+                // A document can yield the same lemma twice (e.g. with
+                // grouping disabled): keep one hit with the best score.
+                match phrase.documents.last_mut() {
+                    Some(last) if last.doc == doc => {
+                        last.count += count;
+                        last.score = last.score.max(score);
+                    }
+                    _ => phrase.documents.push(hit),
+                }
+                if score > phrase.score {
+                    phrase.score = score;
+                    phrase.text = text.to_string();
+                }
+            }
+            None => {
+                self.index.insert(key.clone(), self.phrases.len());
+                self.phrases.push(CorpusPhrase {
+                    text: text.to_string(),
+                    lemma: key,
+                    // Best per-document score until `finish` rescores.
+                    score,
+                    count,
+                    documents: vec![hit],
+                });
+            }
+        }
+    }
+
+    /// Score the merged phrases and return them best first (ties broken by
+    /// lemma).
+    pub fn finish(self) -> Vec<CorpusPhrase> {
+        let scoring = self.scoring;
+        let mut phrases = self.phrases;
+        for phrase in &mut phrases {
+            let sum: f64 = phrase.documents.iter().map(|h| h.score).sum();
+            let df = phrase.documents.len() as f64;
+            phrase.score = match scoring {
+                CorpusScore::Sum => sum,
+                CorpusScore::Mean => sum / df,
+                CorpusScore::DocFrequencyWeighted => sum / df * df.ln_1p(),
+            };
+        }
+        phrases.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.lemma.cmp(&b.lemma))
+        });
+        phrases
+    }
+}
+
+/// Merge per-document phrase sets into corpus keyphrases, best first.
+pub fn aggregate_phrase_sets(sets: &[PhraseSet], scoring: CorpusScore) -> Vec<CorpusPhrase> {
+    let mut aggregator = CorpusAggregator::new(scoring);
+    for set in sets {
+        aggregator.add_phrase_set(set);
+    }
+    aggregator.finish()
+}
+
+fn normalize_lemma(lemma: &str) -> String {
+    lemma
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::artifacts::PhraseEntry;
+
+    fn entry(surface: &str, lemma: &str, score: f64, count: u32) -> PhraseEntry {
+        PhraseEntry {
+            lemma_ids: Vec::new(),
+            score,
+            count,
+            surface: Some(surface.to_string()),
+            lemma_text: Some(lemma.to_string()),
+            spans: None,
+            text_spans: None,
+        }
+    }
+
+    fn corpus() -> Vec<PhraseSet> {
+        vec![
+            PhraseSet::from_entries(vec![
+                entry("Neural networks", "neural network", 0.6, 2),
+                entry("training data", "training data", 0.4, 1),
+            ]),
+            PhraseSet::from_entries(vec![entry("neural network", "neural  network", 0.8, 1)]),
+            PhraseSet::from_entries(vec![
+                entry("neural nets", "neural network", 0.2, 1),
+                entry("gradient descent", "gradient descent", 0.9, 3),
+            ]),
+        ]
+    }
+
+    #[test]
+    fn test_groups_by_lemma_with_document_hits() {
+        let phrases = aggregate_phrase_sets(&corpus(), CorpusScore::Sum);
+        let neural = phrases
+            .iter()
+            .find(|p| p.lemma == "neural network")
+            .unwrap();
+
+        assert_eq!(neural.doc_frequency(), 3);
+        assert_eq!(neural.count, 4);
+        assert_eq!(neural.text, "neural network");
+        assert_eq!(
+            neural.documents.iter().map(|h| h.doc).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert!((neural.score - 1.6).abs() < 1e-12);
+        assert_eq!(phrases[0].lemma, "neural network");
+    }
+
+    #[test]
+    fn test_scoring_strategies() {
+        let score = |scoring, lemma: &str| {
+            aggregate_phrase_sets(&corpus(), scoring)
+                .into_iter()
+                .find(|p| p.lemma == lemma)
+                .unwrap()
+                .score
+        };
+
+        assert!((score(CorpusScore::Mean, "neural network") - 1.6 / 3.0).abs() < 1e-12);
+        let weighted = score(CorpusScore::DocFrequencyWeighted, "neural network");
+        assert!((weighted - 1.6 / 3.0 * 4f64.ln()).abs() < 1e-12);
+
+        // Under the mean, one strong document outranks three weaker ones.
+        let mean = aggregate_phrase_sets(&corpus(), CorpusScore::Mean);
+        assert_eq!(mean[0].lemma, "gradient descent");
+    }
+
+    #[test]
+    fn test_repeated_lemma_in_one_document_is_one_hit() {
+        let set = PhraseSet::from_entries(vec![
+            entry("model", "model", 0.5, 1),
+            entry("models", "model", 0.3, 2),
+        ]);
+        let phrases = aggregate_phrase_sets(&[set], CorpusScore::Sum);
+
+        assert_eq!(phrases.len(), 1);
+        assert_eq!(phrases[0].doc_frequency(), 1);
+        assert_eq!(phrases[0].count, 3);
+        assert_eq!(phrases[0].documents[0].score, 0.5);
+    }
+
+    #[test]
+    fn test_add_phrases_numbers_documents() {
+        let mut aggregator = CorpusAggregator::new(CorpusScore::Sum);
+        assert_eq!(aggregator.add_phrases(&[]), 0);
+        let phrase = Phrase::new("Rust", "rust", 1.0, 1);
+        assert_eq!(aggregator.add_phrases(&[phrase]), 1);
+        assert_eq!(aggregator.num_docs(), 2);
+
+        let phrases = aggregator.finish();
+        assert_eq!(phrases[0].documents[0].doc, 1);
+    }
+
+    #[test]
+    fn test_empty_lemma_falls_back_to_surface() {
+        let mut aggregator = CorpusAggregator::new(CorpusScore::Sum);
+        aggregator.add_phrases(&[Phrase::new("Graph  Ranking", "", 1.0, 1)]);
+        aggregator.add_phrase_set(&PhraseSet::from_entries(vec![entry(
+            "graph ranking",
+            "",
+            0.5,
+            1,
+        )]));
+
+        let phrases = aggregator.finish();
+        assert_eq!(phrases.len(), 1);
+        assert_eq!(phrases[0].lemma, "graph ranking");
+        assert_eq!(phrases[0].doc_frequency(), 2);
+    }
+}
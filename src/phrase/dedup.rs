@@ -1,10 +1,12 @@
 //! Overlap resolution for phrase extraction
 //!
 //! When multiple noun chunks overlap, we need to select the best ones.
-//! Strategy: sort by position, then by score descending, keep non-overlapping.
+//! [`TextRankConfig::overlap_policy`](crate::types::TextRankConfig::overlap_policy)
+//! picks the strategy: keep the highest-scored chunk (default), keep the
+//! longest, keep all, or merge overlapping spans into their maximal union.
 
-use crate::pipeline::artifacts::DroppedCandidate;
-use crate::types::ChunkSpan;
+use crate::pipeline::artifacts::{DropReason, DroppedCandidate};
+use crate::types::{ChunkSpan, OverlapPolicy};
 
 /// A chunk with an associated score
 #[derive(Debug, Clone)]
@@ -57,31 +59,8 @@ pub fn resolve_overlaps(mut chunks: Vec<ScoredChunk>) -> Vec<ScoredChunk> {
 ///
 /// 1. Sort all chunks by score descending
 /// 2. Greedily select chunks that don't overlap with already selected ones
-pub fn resolve_overlaps_greedy(mut chunks: Vec<ScoredChunk>) -> Vec<ScoredChunk> {
-    if chunks.is_empty() {
-        return chunks;
-    }
-
-    // Sort by score descending
-    chunks.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
-
-    let mut result = Vec::new();
-
-    for chunk in chunks {
-        // Check if this chunk overlaps with any already selected chunk
-        let overlaps = result
-            .iter()
-            .any(|selected: &ScoredChunk| chunk.chunk.overlaps(&selected.chunk));
-
-        if !overlaps {
-            result.push(chunk);
-        }
-    }
-
-    // Sort result by position for natural reading order
-    result.sort_by_key(|c| c.chunk.start_char);
-
-    result
+pub fn resolve_overlaps_greedy(chunks: Vec<ScoredChunk>) -> Vec<ScoredChunk> {
+    resolve_greedy(chunks, OverlapPolicy::KeepHighestScored, None)
 }
 
 /// Greedy overlap resolution with diagnostics.
@@ -89,44 +68,123 @@ pub fn resolve_overlaps_greedy(mut chunks: Vec<ScoredChunk>) -> Vec<ScoredChunk>
 /// Returns the kept chunks **and** a record of every chunk that was dropped
 /// (with the reason: overlapped a higher-scored chunk).
 pub fn resolve_overlaps_greedy_with_diagnostics(
-    mut chunks: Vec<ScoredChunk>,
+    chunks: Vec<ScoredChunk>,
+) -> (Vec<ScoredChunk>, Vec<DroppedCandidate>) {
+    resolve_overlaps_with_policy_and_diagnostics(chunks, OverlapPolicy::KeepHighestScored)
+}
+
+/// Resolve overlapping chunks according to `policy`
+///
+/// Returns the kept chunks in document order. [`OverlapPolicy::MergeMaximal`]
+/// expects spans already merged with [`merge_overlapping_spans`] before
+/// scoring; any overlaps left are resolved as with
+/// [`OverlapPolicy::KeepHighestScored`].
+pub fn resolve_overlaps_with_policy(
+    chunks: Vec<ScoredChunk>,
+    policy: OverlapPolicy,
+) -> Vec<ScoredChunk> {
+    resolve_greedy(chunks, policy, None)
+}
+
+/// [`resolve_overlaps_with_policy`] with a record of every dropped chunk.
+pub fn resolve_overlaps_with_policy_and_diagnostics(
+    chunks: Vec<ScoredChunk>,
+    policy: OverlapPolicy,
 ) -> (Vec<ScoredChunk>, Vec<DroppedCandidate>) {
     let mut dropped = Vec::new();
+    let kept = resolve_greedy(chunks, policy, Some(&mut dropped));
+    (kept, dropped)
+}
+
+/// Merge overlapping spans into their maximal union, in document order
+///
+/// Used by [`OverlapPolicy::MergeMaximal`] before scoring, so a merged span
+/// is scored over all of its tokens.
+pub fn merge_overlapping_spans(spans: &[ChunkSpan]) -> Vec<ChunkSpan> {
+    let mut sorted = spans.to_vec();
+    sorted.sort_by_key(|s| (s.start_char, s.end_char));
+
+    let mut merged: Vec<ChunkSpan> = Vec::with_capacity(sorted.len());
+    for span in sorted {
+        match merged.last_mut() {
+            Some(last) if last.overlaps(&span) => {
+                last.end_token = last.end_token.max(span.end_token);
+                last.end_char = last.end_char.max(span.end_char);
+            }
+            _ => merged.push(span),
+        }
+    }
+    merged
+}
+
+/// Greedily keep chunks in `policy` order, skipping any that overlap an
+/// already kept one.
+fn resolve_greedy(
+    mut chunks: Vec<ScoredChunk>,
+    policy: OverlapPolicy,
+    mut dropped: Option<&mut Vec<DroppedCandidate>>,
+) -> Vec<ScoredChunk> {
     if chunks.is_empty() {
-        return (chunks, dropped);
+        return chunks;
+    }
+    if policy == OverlapPolicy::KeepAll {
+        chunks.sort_by_key(|c| c.chunk.start_char);
+        return chunks;
     }
 
-    // Sort by score descending
-    chunks.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+    // Sort by preference, earlier chunks first on ties
+    let by_score = |a: &ScoredChunk, b: &ScoredChunk| b.score.partial_cmp(&a.score).unwrap();
+    chunks.sort_by(|a, b| {
+        let preference = match policy {
+            OverlapPolicy::KeepLongest => b
+                .chunk
+                .token_len()
+                .cmp(&a.chunk.token_len())
+                .then_with(|| by_score(a, b)),
+            _ => by_score(a, b),
+        };
+        preference
+            .then_with(|| a.chunk.start_char.cmp(&b.chunk.start_char))
+            .then_with(|| a.chunk.end_char.cmp(&b.chunk.end_char))
+    });
 
-    let mut result = Vec::new();
+    let mut result: Vec<ScoredChunk> = Vec::new();
 
     for chunk in chunks {
         // Find the first selected chunk that overlaps with this one
-        let blocking = result
+        let blocker = result
             .iter()
-            .find(|selected: &&ScoredChunk| chunk.chunk.overlaps(&selected.chunk));
-
-        if let Some(blocker) = blocking {
-            dropped.push(DroppedCandidate {
-                text: chunk.text.clone(),
-                lemma: chunk.lemma.clone(),
-                score: chunk.score,
-                token_range: (chunk.chunk.start_token, chunk.chunk.end_token),
-                reason: crate::pipeline::artifacts::DropReason::OverlapWithHigherScored {
-                    kept_text: blocker.text.clone(),
-                    kept_score: blocker.score,
-                },
-            });
-        } else {
-            result.push(chunk);
+            .find(|selected| chunk.chunk.overlaps(&selected.chunk));
+
+        match (blocker, dropped.as_deref_mut()) {
+            (None, _) => result.push(chunk),
+            (Some(blocker), Some(dropped)) => {
+                let (kept_text, kept_score) = (blocker.text.clone(), blocker.score);
+                dropped.push(DroppedCandidate {
+                    text: chunk.text,
+                    lemma: chunk.lemma,
+                    score: chunk.score,
+                    token_range: (chunk.chunk.start_token, chunk.chunk.end_token),
+                    reason: match policy {
+                        OverlapPolicy::KeepLongest => DropReason::OverlapWithLonger {
+                            kept_text,
+                            kept_score,
+                        },
+                        _ => DropReason::OverlapWithHigherScored {
+                            kept_text,
+                            kept_score,
+                        },
+                    },
+                });
+            }
+            (Some(_), None) => {}
         }
     }
 
     // Sort result by position for natural reading order
     result.sort_by_key(|c| c.chunk.start_char);
 
-    (result, dropped)
+    result
 }
 
 /// Merge adjacent chunks that share the same lemma pattern
@@ -350,4 +408,107 @@ mod tests {
             }
         }
     }
+
+    // ─── Policy tests ───────────────────────────────────────────────
+
+    fn make_span_chunk(
+        start_token: usize,
+        end_token: usize,
+        score: f64,
+        text: &str,
+    ) -> ScoredChunk {
+        ScoredChunk {
+            chunk: ChunkSpan {
+                start_token,
+                end_token,
+                start_char: start_token * 10,
+                end_char: end_token * 10 - 1,
+                sentence_idx: 0,
+            },
+            score,
+            text: text.to_string(),
+            lemma: text.to_lowercase(),
+        }
+    }
+
+    #[test]
+    fn test_keep_longest_policy() {
+        let chunks = vec![
+            make_span_chunk(0, 2, 3.0, "short"),
+            make_span_chunk(1, 4, 1.0, "longest"),
+            make_span_chunk(5, 6, 0.5, "apart"),
+        ];
+
+        let (kept, dropped) =
+            resolve_overlaps_with_policy_and_diagnostics(chunks, OverlapPolicy::KeepLongest);
+
+        let texts: Vec<_> = kept.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["longest", "apart"]);
+        assert_eq!(dropped.len(), 1);
+        match &dropped[0].reason {
+            DropReason::OverlapWithLonger {
+                kept_text,
+                kept_score,
+            } => {
+                assert_eq!(kept_text, "longest");
+                assert_eq!(*kept_score, 1.0);
+            }
+            other => panic!("Expected OverlapWithLonger, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_keep_all_policy() {
+        let chunks = vec![
+            make_span_chunk(1, 3, 1.0, "b"),
+            make_span_chunk(0, 2, 2.0, "a"),
+        ];
+
+        let (kept, dropped) =
+            resolve_overlaps_with_policy_and_diagnostics(chunks, OverlapPolicy::KeepAll);
+
+        let texts: Vec<_> = kept.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, vec!["a", "b"]);
+        assert!(dropped.is_empty());
+    }
+
+    #[test]
+    fn test_highest_scored_policy_matches_greedy() {
+        let chunks = || {
+            vec![
+                make_span_chunk(0, 2, 1.0, "a"),
+                make_span_chunk(1, 3, 2.0, "b"),
+                make_span_chunk(2, 5, 1.5, "c"),
+            ]
+        };
+        let greedy: Vec<_> = resolve_overlaps_greedy(chunks())
+            .into_iter()
+            .map(|c| c.text)
+            .collect();
+        let policy: Vec<_> =
+            resolve_overlaps_with_policy(chunks(), OverlapPolicy::KeepHighestScored)
+                .into_iter()
+                .map(|c| c.text)
+                .collect();
+        assert_eq!(greedy, vec!["b"]);
+        assert_eq!(policy, greedy);
+    }
+
+    #[test]
+    fn test_merge_overlapping_spans() {
+        let spans: Vec<ChunkSpan> = [(3, 5), (0, 2), (1, 3), (7, 8)]
+            .iter()
+            .map(|&(s, e)| make_span_chunk(s, e, 1.0, "x").chunk)
+            .collect();
+
+        let merged = merge_overlapping_spans(&spans);
+
+        let ranges: Vec<_> = merged
+            .iter()
+            .map(|s| (s.start_token, s.end_token))
+            .collect();
+        // (0, 2) and (1, 3) overlap; (3, 5) only touches (1, 3).
+        assert_eq!(ranges, vec![(0, 3), (3, 5), (7, 8)]);
+        assert_eq!(merged[0].end_char, 29);
+    }
 }
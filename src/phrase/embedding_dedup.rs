@@ -0,0 +1,219 @@
+//! Embedding-based phrase deduplication
+//!
+//! Lexical deduplication ([`near_duplicates`](super::near_duplicates), MMR
+//! over lemma overlap) misses paraphrases: "car sales" and "automobile
+//! sales" share no misspelling and only half their words, so both reach the
+//! top of the list. [`EmbeddingDedup`] drops a phrase when its embedding
+//! cosine similarity to an already-kept, higher-ranked phrase exceeds a
+//! threshold.
+//!
+//! Selection is greedy in score order and, unlike near-duplicate merging,
+//! the dropped phrase's occurrences are not folded into the kept one: a
+//! paraphrase is a different phrase, only a redundant one.
+//!
+//! [`PhraseExtractor::with_embedding_dedup`](super::extraction::PhraseExtractor::with_embedding_dedup)
+//! and, in pipelines,
+//! [`EmbeddingDedupPhraseBuilder`](crate::pipeline::EmbeddingDedupPhraseBuilder)
+//! apply the pass before the `top_n` cutoff, so dropped phrases are
+//! backfilled from further down the list. Each phrase is embedded once, and
+//! checking stops once `top_n` phrases are kept.
+//!
+//! Only available with the `embeddings` feature.
+
+use crate::nlp::embeddings::WordVectors;
+use crate::pipeline::artifacts::{DropReason, DroppedCandidate};
+use crate::similarity::{cosine, Embedder, Unit};
+use crate::types::Phrase;
+use std::sync::Arc;
+
+/// Default cosine similarity above which a phrase is dropped.
+pub const DEFAULT_THRESHOLD: f64 = 0.85;
+
+/// Drops phrases that are paraphrases of higher-ranked ones
+#[derive(Clone)]
+pub struct EmbeddingDedup {
+    embedder: Arc<dyn Embedder>,
+    threshold: f64,
+}
+
+impl EmbeddingDedup {
+    /// Compare phrases by the centroid of their lemma word vectors, dropping
+    /// those above [`DEFAULT_THRESHOLD`].
+    pub fn new(vectors: WordVectors) -> Self {
+        Self::with_embedder(Arc::new(vectors))
+    }
+
+    /// Compare phrases by any [`Embedder`] (e.g. a sentence-embedding
+    /// model).
+    pub fn with_embedder(embedder: Arc<dyn Embedder>) -> Self {
+        Self {
+            embedder,
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Builder method: set the similarity above which a phrase is dropped
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Similarity above which a phrase is dropped
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Drop paraphrases from score-sorted `phrases`, stopping once `limit`
+    /// phrases are kept (`None`: check them all).
+    ///
+    /// Phrases after the `limit`-th kept one are passed through unchecked,
+    /// for the caller's cutoff to drop. The kept phrases stay in their
+    /// original order; ranks are not touched.
+    pub fn apply(&self, phrases: Vec<Phrase>, limit: Option<usize>) -> Vec<Phrase> {
+        self.apply_with_diagnostics(phrases, limit).0
+    }
+
+    /// [`apply`](Self::apply) that also records every dropped phrase.
+    pub fn apply_with_diagnostics(
+        &self,
+        phrases: Vec<Phrase>,
+        limit: Option<usize>,
+    ) -> (Vec<Phrase>, Vec<DroppedCandidate>) {
+        let mut kept: Vec<Phrase> = Vec::with_capacity(phrases.len());
+        // Embedding of each kept phrase, computed once.
+        let mut kept_embeddings: Vec<Option<Vec<f32>>> = Vec::new();
+        let mut dropped = Vec::new();
+
+        let mut phrases = phrases.into_iter();
+        if limit == Some(0) {
+            return (phrases.collect(), dropped);
+        }
+        for phrase in phrases.by_ref() {
+            let embedding = self.embedder.embed(&Unit::from(&phrase));
+            let duplicate = embedding.as_deref().and_then(|e| {
+                kept.iter()
+                    .zip(&kept_embeddings)
+                    .find_map(|(k, k_embedding)| {
+                        let similarity = cosine(e, k_embedding.as_deref()?).max(0.0) as f64;
+                        (similarity > self.threshold).then_some((k, similarity))
+                    })
+            });
+
+            match duplicate {
+                Some((k, similarity)) => dropped.push(DroppedCandidate {
+                    text: phrase.text.clone(),
+                    lemma: phrase.lemma.clone(),
+                    score: phrase.score,
+                    token_range: phrase.offsets.first().copied().unwrap_or((0, 0)),
+                    reason: DropReason::SemanticDuplicateOf {
+                        kept_text: k.text.clone(),
+                        similarity,
+                    },
+                }),
+                None => {
+                    kept.push(phrase);
+                    kept_embeddings.push(embedding);
+                    if limit.is_some_and(|limit| kept.len() >= limit) {
+                        break;
+                    }
+                }
+            }
+        }
+        kept.extend(phrases);
+
+        (kept, dropped)
+    }
+}
+
+impl std::fmt::Debug for EmbeddingDedup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmbeddingDedup")
+            .field("threshold", &self.threshold)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vectors() -> WordVectors {
+        let text = "car 1.0 0.0 0.0\n\
+                    automobile 0.95 0.1 0.0\n\
+                    sales 0.0 0.0 1.0\n\
+                    engine 0.0 1.0 0.0\n";
+        WordVectors::from_text_reader(text.as_bytes()).unwrap()
+    }
+
+    fn phrase(lemma: &str, score: f64) -> Phrase {
+        Phrase::new(lemma, lemma, score, 1)
+    }
+
+    #[test]
+    fn test_drops_paraphrase_of_higher_ranked_phrase() {
+        let phrases = vec![
+            phrase("car sales", 0.9),
+            phrase("engine", 0.7),
+            phrase("automobile sales", 0.6),
+        ];
+        let (kept, dropped) = EmbeddingDedup::new(vectors()).apply_with_diagnostics(phrases, None);
+
+        let texts: Vec<&str> = kept.iter().map(|p| p.text.as_str()).collect();
+        assert_eq!(texts, ["car sales", "engine"]);
+        assert_eq!(dropped.len(), 1);
+        assert!(matches!(
+            &dropped[0].reason,
+            DropReason::SemanticDuplicateOf { kept_text, similarity }
+                if kept_text == "car sales" && *similarity > 0.99
+        ));
+    }
+
+    #[test]
+    fn test_threshold_and_unknown_words() {
+        let phrases = || vec![phrase("car sales", 0.9), phrase("automobile sales", 0.6)];
+        let strict = EmbeddingDedup::new(vectors()).with_threshold(1.0);
+        assert_eq!(strict.apply(phrases(), None).len(), 2);
+
+        // Phrases without any known word are never duplicates.
+        let unknown = vec![phrase("zzz", 0.9), phrase("zzz", 0.5)];
+        assert_eq!(EmbeddingDedup::new(vectors()).apply(unknown, None).len(), 2);
+    }
+
+    #[test]
+    fn test_stops_checking_at_limit() {
+        let phrases = vec![
+            phrase("car sales", 0.9),
+            phrase("engine", 0.7),
+            phrase("automobile sales", 0.6),
+        ];
+        let (kept, dropped) =
+            EmbeddingDedup::new(vectors()).apply_with_diagnostics(phrases.clone(), Some(2));
+        assert_eq!(kept.len(), 3);
+        assert!(dropped.is_empty());
+
+        let kept = EmbeddingDedup::new(vectors()).apply(phrases, Some(3));
+        assert_eq!(kept.len(), 2);
+    }
+
+    struct CountingEmbedder(std::sync::atomic::AtomicUsize, WordVectors);
+
+    impl Embedder for CountingEmbedder {
+        fn embed(&self, unit: &Unit<'_>) -> Option<Vec<f32>> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            self.1.embed(unit)
+        }
+    }
+
+    #[test]
+    fn test_embeds_each_phrase_once() {
+        let embedder = Arc::new(CountingEmbedder(Default::default(), vectors()));
+        let phrases = vec![
+            phrase("car sales", 0.9),
+            phrase("engine", 0.7),
+            phrase("automobile sales", 0.6),
+            phrase("sales", 0.5),
+        ];
+        EmbeddingDedup::with_embedder(embedder.clone()).apply(phrases, None);
+        assert_eq!(embedder.0.load(std::sync::atomic::Ordering::Relaxed), 4);
+    }
+}
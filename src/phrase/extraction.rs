@@ -1,17 +1,28 @@
 //! Phrase extraction with canonical form selection
 //!
 //! Groups noun chunks by their lemmatized form, tracks variant frequencies,
-//! and selects the most common surface form as canonical.
+//! and selects a canonical surface form: the most common one by default, or
+//! the one chosen by [`TextRankConfig::surface_selection`].
 
+use super::blocklist::PhraseFilter;
 use super::chunker::{chunk_lemma, chunk_text, NounChunker};
 use super::dedup::{
-    resolve_overlaps_greedy, resolve_overlaps_greedy_with_diagnostics, ScoredChunk,
+    merge_overlapping_spans, resolve_overlaps_with_policy,
+    resolve_overlaps_with_policy_and_diagnostics, ScoredChunk,
 };
+use super::mmr::diversify;
+use super::near_duplicates::{merge_near_duplicates, merge_near_duplicates_with_diagnostics};
 use crate::graph::csr::CsrGraph;
 use crate::pagerank::PageRankResult;
 use crate::pipeline::artifacts::{DroppedCandidate, ExtractionDiagnostics};
-use crate::types::{Phrase, PhraseGrouping, ScoreAggregation, TextRankConfig, Token};
+use crate::similarity::{LexicalSimilarity, Similarity};
+use crate::types::{
+    ChunkSpan, OverlapPolicy, Phrase, PhraseGrouping, PhraseSelection, ScoreAggregation,
+    SurfaceSelection, TextRankConfig, Token,
+};
 use rustc_hash::FxHashMap;
+use std::borrow::Cow;
+use std::sync::Arc;
 
 fn scrub_phrase_text(text: &str) -> String {
     let mut out = String::with_capacity(text.len());
@@ -32,10 +43,84 @@ fn scrub_phrase_text(text: &str) -> String {
     out.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
+/// The variant whose surface form `selection` reports. Non-empty `variants`.
+fn select_surface(variants: &[ScoredChunk], selection: SurfaceSelection) -> &ScoredChunk {
+    let keys: Vec<(&str, usize)> = variants
+        .iter()
+        .map(|v| (v.text.as_str(), v.chunk.start_char))
+        .collect();
+    &variants[select_surface_index(&keys, selection)]
+}
+
+/// Index of the variant whose surface form `selection` reports, given each
+/// variant's text and document position. Non-empty `variants`.
+pub(crate) fn select_surface_index(
+    variants: &[(&str, usize)],
+    selection: SurfaceSelection,
+) -> usize {
+    let position = |&i: &usize| variants[i].1;
+    match selection {
+        SurfaceSelection::FirstOccurrence | SurfaceSelection::Lemma => {
+            (0..variants.len()).min_by_key(position).unwrap()
+        }
+        SurfaceSelection::MostFrequent => {
+            let mut counts: FxHashMap<&str, usize> = FxHashMap::default();
+            for &(text, _) in variants {
+                *counts.entry(text).or_insert(0) += 1;
+            }
+            let (text, _) = counts
+                .into_iter()
+                .max_by(|(text_a, count_a), (text_b, count_b)| {
+                    count_a.cmp(count_b).then_with(|| text_b.cmp(text_a))
+                })
+                .unwrap();
+            variants.iter().position(|&(t, _)| t == text).unwrap()
+        }
+        SurfaceSelection::Longest => {
+            let chars = |i: usize| variants[i].0.chars().count();
+            (0..variants.len())
+                .min_by(|&a, &b| {
+                    chars(b)
+                        .cmp(&chars(a))
+                        .then_with(|| position(&a).cmp(&position(&b)))
+                })
+                .unwrap()
+        }
+    }
+}
+
+/// The surface form a group of `(text, position)` variants reports:
+/// the one `selection` picks, `lemma` for [`SurfaceSelection::Lemma`], or
+/// the builder's own choice `variants[default]` when `selection` is unset.
+pub(crate) fn choose_surface<'a>(
+    variants: &[(&'a str, usize)],
+    lemma: &'a str,
+    default: usize,
+    selection: Option<SurfaceSelection>,
+) -> &'a str {
+    match selection {
+        None => variants[default].0,
+        Some(SurfaceSelection::Lemma) => lemma,
+        Some(selection) => variants[select_surface_index(variants, selection)].0,
+    }
+}
+
 /// Phrase extractor that combines chunking, scoring, and deduplication
-#[derive(Debug)]
 pub struct PhraseExtractor {
     config: TextRankConfig,
+    similarity: Option<Arc<dyn Similarity>>,
+    filter: PhraseFilter,
+    #[cfg(feature = "embeddings")]
+    embedding_dedup: Option<super::embedding_dedup::EmbeddingDedup>,
+}
+
+impl std::fmt::Debug for PhraseExtractor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhraseExtractor")
+            .field("config", &self.config)
+            .field("custom_similarity", &self.similarity.is_some())
+            .finish()
+    }
 }
 
 impl Default for PhraseExtractor {
@@ -47,14 +132,33 @@ impl Default for PhraseExtractor {
 impl PhraseExtractor {
     /// Create a new phrase extractor with default config
     pub fn new() -> Self {
-        Self {
-            config: TextRankConfig::default(),
-        }
+        Self::with_config(TextRankConfig::default())
     }
 
     /// Create with custom config
     pub fn with_config(config: TextRankConfig) -> Self {
-        Self { config }
+        Self {
+            filter: PhraseFilter::for_config(&config),
+            config,
+            similarity: None,
+            #[cfg(feature = "embeddings")]
+            embedding_dedup: None,
+        }
+    }
+
+    /// Compare phrases with `similarity` during MMR diversification
+    /// (`mmr_lambda`) instead of lemma token overlap.
+    pub fn with_similarity(mut self, similarity: Arc<dyn Similarity>) -> Self {
+        self.similarity = Some(similarity);
+        self
+    }
+
+    /// Drop phrases whose embedding is too similar to a higher-ranked
+    /// phrase's, before the `top_n` cutoff.
+    #[cfg(feature = "embeddings")]
+    pub fn with_embedding_dedup(mut self, dedup: super::embedding_dedup::EmbeddingDedup) -> Self {
+        self.embedding_dedup = Some(dedup);
+        self
     }
 
     /// Extract phrases from tokens using PageRank scores
@@ -67,45 +171,150 @@ impl PhraseExtractor {
         // Extract noun chunks
         let chunker = NounChunker::new()
             .with_min_length(self.config.min_phrase_length)
-            .with_max_length(self.config.max_phrase_length);
+            .with_max_length(self.config.max_phrase_length)
+            .with_bridge_words(&self.config.phrase_bridge_words);
         let chunks = chunker.extract_chunks(tokens);
+        let chunks = self.candidate_spans(&chunks);
 
         // Score each chunk
         let scored_chunks = self.score_chunks(tokens, &chunks, graph, pagerank);
 
+        self.finish(scored_chunks)
+    }
+
+    /// Extract phrases from caller-chosen chunks (e.g. POS-grammar matches)
+    /// instead of running the noun chunker.
+    pub fn extract_with_chunks(
+        &self,
+        tokens: &[Token],
+        chunks: &[ChunkSpan],
+        graph: &CsrGraph,
+        pagerank: &PageRankResult,
+    ) -> Vec<Phrase> {
+        let chunks = self.candidate_spans(chunks);
+        let scored_chunks = self.score_chunks(tokens, &chunks, graph, pagerank);
+        self.finish(scored_chunks)
+    }
+
+    /// Candidate spans to score: merged into maximal spans under
+    /// [`OverlapPolicy::MergeMaximal`], as given otherwise.
+    fn candidate_spans<'a>(&self, chunks: &'a [ChunkSpan]) -> Cow<'a, [ChunkSpan]> {
+        match self.config.overlap_policy {
+            OverlapPolicy::MergeMaximal => Cow::Owned(merge_overlapping_spans(chunks)),
+            _ => Cow::Borrowed(chunks),
+        }
+    }
+
+    /// Resolve overlaps, group variants, sort, rank and truncate scored
+    /// chunks into the final phrase list.
+    pub(crate) fn finish(&self, mut scored_chunks: Vec<ScoredChunk>) -> Vec<Phrase> {
+        scored_chunks.retain(|sc| {
+            self.word_count_in_range(&sc.text).is_ok()
+                && !self.filter.is_blocked(&sc.text, &sc.lemma)
+        });
+
         // Resolve overlaps
-        let deduped = resolve_overlaps_greedy(scored_chunks);
+        let deduped = resolve_overlaps_with_policy(scored_chunks, self.config.overlap_policy);
 
         // Group variants and create phrases with canonical forms
         let mut phrases = self.group_phrases(deduped);
+        phrases.retain(|p| self.frequent_enough(p));
+
+        self.sort_phrases(&mut phrases);
 
-        // Sort by score descending (with stable tie-breakers in deterministic mode).
-        if self.config.determinism.is_deterministic() {
-            phrases.sort_by(|a, b| a.stable_cmp(b));
-        } else {
-            phrases.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        // Merge near-duplicate lemmas
+        if let Some(merge) = &self.config.near_duplicates {
+            phrases = merge_near_duplicates(phrases, merge);
+            self.apply_span_mode(&mut phrases);
+            self.sort_phrases(&mut phrases);
+        }
+
+        // Drop paraphrases of higher-ranked phrases
+        #[cfg(feature = "embeddings")]
+        if let Some(dedup) = &self.embedding_dedup {
+            phrases = dedup.apply(phrases, self.dedup_limit());
         }
 
         // Assign ranks
         for (i, phrase) in phrases.iter_mut().enumerate() {
             phrase.rank = i + 1;
         }
+        let limit = self.limit(&phrases);
+        let mut phrases = self.diversify(phrases, limit);
 
-        // Limit to top_n if specified
-        if self.config.top_n > 0 && phrases.len() > self.config.top_n {
-            phrases.truncate(self.config.top_n);
+        // Limit to top_n, or the `phrase_selection` cutoff
+        if let Some(limit) = limit {
+            phrases.truncate(limit);
         }
 
         phrases
     }
 
+    /// Check a candidate's word count against `min_phrase_words` /
+    /// `max_phrase_words`, returning the count when it is out of range.
+    fn word_count_in_range(&self, text: &str) -> Result<(), usize> {
+        word_count_in_range(text, &self.config)
+    }
+
+    /// Whether a grouped phrase meets `min_phrase_frequency`. `count` is the
+    /// number of candidate occurrences grouped into it.
+    fn frequent_enough(&self, phrase: &Phrase) -> bool {
+        phrase.count >= self.config.min_phrase_frequency.unwrap_or(0)
+    }
+
+    /// Sort by score descending, breaking ties with [`Phrase::stable_cmp`].
+    fn sort_phrases(&self, phrases: &mut [Phrase]) {
+        phrases.sort_by(|a, b| a.stable_cmp(b));
+    }
+
+    /// Trim merged spans back to what `span_mode` reports.
+    fn apply_span_mode(&self, phrases: &mut [Phrase]) {
+        for phrase in phrases {
+            self.config.span_mode.apply(&mut phrase.spans);
+        }
+    }
+
+    /// Number of sorted phrases `phrase_selection` keeps (`None`: all).
+    fn limit(&self, phrases: &[Phrase]) -> Option<usize> {
+        let scores: Vec<f64> = phrases.iter().map(|p| p.score).collect();
+        self.config
+            .phrase_selection
+            .limit(self.config.top_n, &scores)
+    }
+
+    /// Number of phrases embedding dedup must keep before it can stop:
+    /// `top_n`, unless the cutoff depends on the scores or MMR picks from
+    /// further down the list.
+    #[cfg(feature = "embeddings")]
+    fn dedup_limit(&self) -> Option<usize> {
+        match (self.config.phrase_selection, self.config.mmr_lambda) {
+            (PhraseSelection::TopN, None) => (self.config.top_n > 0).then_some(self.config.top_n),
+            _ => None,
+        }
+    }
+
+    /// Re-rank sorted phrases with MMR when `mmr_lambda` is set, picking
+    /// `limit` of them first.
+    fn diversify(&self, phrases: Vec<Phrase>, limit: Option<usize>) -> Vec<Phrase> {
+        let k = limit.unwrap_or(0);
+        match self.config.mmr_lambda {
+            Some(lambda) => match &self.similarity {
+                Some(similarity) => diversify(phrases, lambda, k, similarity.as_ref()),
+                None => diversify(phrases, lambda, k, &LexicalSimilarity),
+            },
+            None => phrases,
+        }
+    }
+
     /// Extract phrases with full diagnostics.
     ///
     /// Only called when `debug_level >= Full`. Records:
     /// - Chunk formation events (stopword splits, POS rejections, etc.)
     /// - Overlap dedup drops
+    /// - Embedding dedup drops (with `with_embedding_dedup`)
     /// - Zero-score drops
-    /// - BelowTopN drops
+    /// - Blocklist drops
+    /// - BelowTopN / BelowCutoff drops
     pub fn extract_with_diagnostics(
         &self,
         tokens: &[Token],
@@ -117,8 +326,10 @@ impl PhraseExtractor {
         // Extract noun chunks with diagnostic recording
         let chunker = NounChunker::new()
             .with_min_length(self.config.min_phrase_length)
-            .with_max_length(self.config.max_phrase_length);
+            .with_max_length(self.config.max_phrase_length)
+            .with_bridge_words(&self.config.phrase_bridge_words);
         let chunks = chunker.extract_chunks_into(tokens, Some(&mut chunk_events));
+        let chunks = self.candidate_spans(&chunks);
 
         // Score each chunk (including zero-score for diagnostics)
         let all_scored = self.score_chunks_all(tokens, &chunks, graph, pagerank);
@@ -143,36 +354,112 @@ impl PhraseExtractor {
             })
             .collect();
 
+        // Record word-count drops
+        let scored_chunks: Vec<ScoredChunk> = scored_chunks
+            .into_iter()
+            .filter(|sc| match self.word_count_in_range(&sc.text) {
+                Ok(()) => true,
+                Err(words) => {
+                    dropped_candidates.push(DroppedCandidate {
+                        text: sc.text.clone(),
+                        lemma: sc.lemma.clone(),
+                        score: sc.score,
+                        token_range: (sc.chunk.start_token, sc.chunk.end_token),
+                        reason: crate::pipeline::artifacts::DropReason::WordCount { words },
+                    });
+                    false
+                }
+            })
+            .collect();
+
+        // Record blocklist drops
+        let scored_chunks: Vec<ScoredChunk> = scored_chunks
+            .into_iter()
+            .filter(|sc| {
+                if self.filter.is_blocked(&sc.text, &sc.lemma) {
+                    dropped_candidates.push(DroppedCandidate {
+                        text: sc.text.clone(),
+                        lemma: sc.lemma.clone(),
+                        score: sc.score,
+                        token_range: (sc.chunk.start_token, sc.chunk.end_token),
+                        reason: crate::pipeline::artifacts::DropReason::Blocklisted,
+                    });
+                    false
+                } else {
+                    true
+                }
+            })
+            .collect();
+
         // Resolve overlaps with diagnostics
-        let (deduped, overlap_drops) = resolve_overlaps_greedy_with_diagnostics(scored_chunks);
+        let (deduped, overlap_drops) =
+            resolve_overlaps_with_policy_and_diagnostics(scored_chunks, self.config.overlap_policy);
         dropped_candidates.extend(overlap_drops);
 
-        // Group variants and create phrases with canonical forms
+        // Group variants and create phrases with canonical forms, recording
+        // infrequent ones
         let mut phrases = self.group_phrases(deduped);
+        if let Some(min_frequency) = self.config.min_phrase_frequency {
+            phrases.retain(|phrase| {
+                let keep = phrase.count >= min_frequency;
+                if !keep {
+                    dropped_candidates.push(DroppedCandidate {
+                        text: phrase.text.clone(),
+                        lemma: phrase.lemma.clone(),
+                        score: phrase.score,
+                        token_range: phrase.offsets.first().copied().unwrap_or((0, 0)),
+                        reason: crate::pipeline::artifacts::DropReason::BelowMinFrequency {
+                            count: phrase.count,
+                            min_frequency,
+                        },
+                    });
+                }
+                keep
+            });
+        }
 
-        // Sort by score descending (with stable tie-breakers in deterministic mode).
-        if self.config.determinism.is_deterministic() {
-            phrases.sort_by(|a, b| a.stable_cmp(b));
-        } else {
-            phrases.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        self.sort_phrases(&mut phrases);
+
+        // Merge near-duplicate lemmas, recording the absorbed phrases
+        if let Some(merge) = &self.config.near_duplicates {
+            let (merged, merge_drops) = merge_near_duplicates_with_diagnostics(phrases, merge);
+            phrases = merged;
+            dropped_candidates.extend(merge_drops);
+            self.apply_span_mode(&mut phrases);
+            self.sort_phrases(&mut phrases);
+        }
+
+        // Drop paraphrases of higher-ranked phrases, recording them
+        #[cfg(feature = "embeddings")]
+        if let Some(dedup) = &self.embedding_dedup {
+            let (kept, dedup_drops) = dedup.apply_with_diagnostics(phrases, self.dedup_limit());
+            phrases = kept;
+            dropped_candidates.extend(dedup_drops);
         }
 
         // Assign ranks
         for (i, phrase) in phrases.iter_mut().enumerate() {
             phrase.rank = i + 1;
         }
-
-        // Limit to top_n if specified, recording BelowTopN drops
-        if self.config.top_n > 0 && phrases.len() > self.config.top_n {
-            for phrase in phrases.drain(self.config.top_n..) {
+        let limit = self.limit(&phrases);
+        let mut phrases = self.diversify(phrases, limit);
+
+        // Limit to top_n or the `phrase_selection` cutoff, recording
+        // BelowTopN / BelowCutoff drops
+        if let Some(limit) = limit.filter(|&limit| phrases.len() > limit) {
+            let reason = match self.config.phrase_selection {
+                PhraseSelection::TopN => crate::pipeline::artifacts::DropReason::BelowTopN {
+                    top_n: self.config.top_n,
+                },
+                _ => crate::pipeline::artifacts::DropReason::BelowCutoff { kept: limit },
+            };
+            for phrase in phrases.drain(limit..) {
                 dropped_candidates.push(DroppedCandidate {
                     text: phrase.text,
                     lemma: phrase.lemma,
                     score: phrase.score,
                     token_range: phrase.offsets.first().copied().unwrap_or((0, 0)),
-                    reason: crate::pipeline::artifacts::DropReason::BelowTopN {
-                        top_n: self.config.top_n,
-                    },
+                    reason: reason.clone(),
                 });
             }
         }
@@ -192,6 +479,7 @@ impl PhraseExtractor {
         graph: &CsrGraph,
         pagerank: &PageRankResult,
     ) -> Vec<ScoredChunk> {
+        let node_key = self.config.effective_node_key();
         chunks
             .iter()
             .map(|chunk| {
@@ -203,7 +491,7 @@ impl PhraseExtractor {
                     .iter()
                     .filter_map(|t| {
                         graph
-                            .get_node_by_lemma(&t.graph_key(self.config.use_pos_in_nodes))
+                            .get_node_by_lemma(&node_key.key(t))
                             .map(|node_id| pagerank.score(node_id))
                     })
                     .collect();
@@ -247,47 +535,38 @@ impl PhraseExtractor {
             groups.entry(key).or_default().push(chunk);
         }
 
-        let groups_iter: Vec<_> = if self.config.determinism.is_deterministic() {
-            let mut sorted: Vec<_> = groups.into_iter().collect();
-            sorted.sort_by(|(a, _), (b, _)| a.cmp(b));
-            sorted
-        } else {
-            groups.into_iter().collect()
-        };
+        // Sorted so output never depends on hash iteration order
+        let mut groups_iter: Vec<_> = groups.into_iter().collect();
+        groups_iter.sort_by(|(a, _), (b, _)| a.cmp(b));
 
         groups_iter
             .into_iter()
             .map(|(group_key, variants)| {
                 let mut offsets = Vec::new();
+                let mut spans = Vec::new();
                 for variant in &variants {
                     offsets.push((variant.chunk.start_token, variant.chunk.end_token));
+                    spans.push((variant.chunk.start_char, variant.chunk.end_char));
                 }
+                self.config.span_mode.apply(&mut spans);
 
-                let (canonical_text, canonical_lemma) = match self.config.phrase_grouping {
-                    PhraseGrouping::Lemma => {
-                        let mut variant_counts: FxHashMap<String, usize> = FxHashMap::default();
-                        for variant in &variants {
-                            *variant_counts.entry(variant.text.clone()).or_insert(0) += 1;
-                        }
-                        let canonical = variant_counts
-                            .into_iter()
-                            .max_by(|(text_a, count_a), (text_b, count_b)| {
-                                count_a.cmp(count_b).then_with(|| text_b.cmp(text_a))
-                            })
-                            .map(|(text, _)| text)
-                            .unwrap_or_else(|| group_key.clone());
-                        (canonical, group_key)
-                    }
-                    PhraseGrouping::ScrubbedText => {
-                        let canonical_variant = variants
-                            .iter()
-                            .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap())
-                            .unwrap();
-                        (
-                            canonical_variant.text.clone(),
-                            canonical_variant.lemma.clone(),
-                        )
+                let canonical = match (self.config.surface_selection, self.config.phrase_grouping) {
+                    (Some(selection), _) => select_surface(&variants, selection),
+                    (None, PhraseGrouping::Lemma) => {
+                        select_surface(&variants, SurfaceSelection::MostFrequent)
                     }
+                    (None, PhraseGrouping::ScrubbedText) => variants
+                        .iter()
+                        .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap())
+                        .unwrap(),
+                };
+                let canonical_lemma = match self.config.phrase_grouping {
+                    PhraseGrouping::Lemma => group_key,
+                    PhraseGrouping::ScrubbedText => canonical.lemma.clone(),
+                };
+                let canonical_text = match self.config.surface_selection {
+                    Some(SurfaceSelection::Lemma) => canonical_lemma.clone(),
+                    _ => canonical.text.clone(),
                 };
 
                 let score = match self.config.phrase_grouping {
@@ -317,6 +596,7 @@ impl PhraseExtractor {
                     score,
                     count: variants.len(),
                     offsets,
+                    spans,
                     rank: 0, // Will be assigned after sorting
                 }
             })
@@ -337,6 +617,18 @@ pub struct ExtractionResult {
     pub debug: Option<crate::pipeline::artifacts::DebugPayload>,
 }
 
+impl ExtractionResult {
+    /// Explain `phrase`'s score from the graph nodes behind its words.
+    ///
+    /// Requires `debug_level = Full` (see [`DebugPayload::explain`]);
+    /// returns `None` otherwise.
+    ///
+    /// [`DebugPayload::explain`]: crate::pipeline::artifacts::DebugPayload::explain
+    pub fn explain(&self, phrase: &Phrase) -> Option<crate::pipeline::PhraseExplanation> {
+        self.debug.as_ref()?.explain(phrase)
+    }
+}
+
 /// Manual `PartialEq` that ignores `debug` — the payload contains `f64` fields
 /// and is irrelevant for golden-test comparisons.
 impl PartialEq for ExtractionResult {
@@ -347,6 +639,17 @@ impl PartialEq for ExtractionResult {
     }
 }
 
+impl From<crate::pipeline::artifacts::FormattedResult> for ExtractionResult {
+    fn from(result: crate::pipeline::artifacts::FormattedResult) -> Self {
+        Self {
+            phrases: result.phrases,
+            converged: result.converged,
+            iterations: result.iterations as usize,
+            debug: result.debug,
+        }
+    }
+}
+
 /// Extract phrases using the full TextRank pipeline
 pub fn extract_keyphrases(tokens: &[Token], config: &TextRankConfig) -> Vec<Phrase> {
     extract_keyphrases_with_info(tokens, config).phrases
@@ -362,15 +665,47 @@ pub fn extract_keyphrases(tokens: &[Token], config: &TextRankConfig) -> Vec<Phra
 /// 3. Direct [`PhraseExtractor`] invocation (avoids `to_legacy_tokens()` round-trip)
 ///
 /// The `use_edge_weights` config field controls edge weight policy:
-/// `true` → count-accumulating, `false` → binary.
+/// `true` → count-accumulating, `false` → binary. Lemmas are first
+/// rederived according to `lemma_strategy` and `normalization` (a no-op
+/// for the defaults).
 ///
 /// [`GraphBuilder::from_tokens_with_pos`]: crate::graph::builder::GraphBuilder::from_tokens_with_pos
 /// [`PageRankRanker`]: crate::pipeline::PageRankRanker
 pub fn extract_keyphrases_with_info(tokens: &[Token], config: &TextRankConfig) -> ExtractionResult {
+    let tokens = lemmatized(tokens, config);
+    extract_keyphrases_observed(&tokens, config, &mut crate::pipeline::NoopObserver)
+}
+
+/// `tokens` with lemmas rederived by [`Lemmatizer::for_config`], borrowed
+/// when the config keeps them as provided.
+///
+/// [`Lemmatizer::for_config`]: crate::nlp::lemma::Lemmatizer::for_config
+pub(crate) fn lemmatized<'a>(tokens: &'a [Token], config: &TextRankConfig) -> Cow<'a, [Token]> {
+    let lemmatizer = crate::nlp::lemma::Lemmatizer::for_config(config);
+    if lemmatizer.is_identity() {
+        return Cow::Borrowed(tokens);
+    }
+    let mut tokens = tokens.to_vec();
+    lemmatizer.apply(&mut tokens);
+    Cow::Owned(tokens)
+}
+
+/// [`extract_keyphrases_with_info`] reporting the `graph`, `rank` and
+/// `phrases` stages to `observer`.
+pub(crate) fn extract_keyphrases_observed(
+    tokens: &[Token],
+    config: &TextRankConfig,
+    observer: &mut impl crate::pipeline::PipelineObserver,
+) -> ExtractionResult {
     use crate::graph::builder::GraphBuilder;
+    use crate::pipeline::observer::{
+        StageClock, StageReport, StageReportBuilder, STAGE_GRAPH, STAGE_PHRASES, STAGE_RANK,
+    };
     use crate::pipeline::{Graph, Ranker};
 
     // Stage 1+2: fused candidate selection + graph building.
+    observer.on_stage_start(STAGE_GRAPH);
+    let clock = StageClock::start();
     // Bypasses TokenStream interning for zero-copy token access.
     let include_pos = if config.include_pos.is_empty() {
         None
@@ -382,10 +717,11 @@ pub fn extract_keyphrases_with_info(tokens: &[Token], config: &TextRankConfig) -
         config.window_size,
         config.use_edge_weights,
         include_pos,
-        config.use_pos_in_nodes,
+        config.effective_node_key(),
     );
 
     if builder.is_empty() {
+        observer.on_stage_end(STAGE_GRAPH, &StageReport::new(clock.elapsed()));
         return ExtractionResult {
             phrases: Vec::new(),
             converged: true,
@@ -396,11 +732,25 @@ pub fn extract_keyphrases_with_info(tokens: &[Token], config: &TextRankConfig) -
 
     // Wrap in pipeline artifact for stage interop.
     let graph = Graph::from_builder(&builder);
+    let report = StageReportBuilder::new(clock.elapsed())
+        .nodes(graph.num_nodes())
+        .edges(graph.num_edges())
+        .build();
+    observer.on_stage_end(STAGE_GRAPH, &report);
 
     // Stage 3: ranking via pipeline stage.
+    observer.on_stage_start(STAGE_RANK);
+    let clock = StageClock::start();
     let rank_output = crate::pipeline::PageRankRanker.rank(&graph, None, config);
     let converged = rank_output.converged();
     let iterations = rank_output.iterations() as usize;
+    let report = StageReportBuilder::new(clock.elapsed())
+        .iterations(rank_output.iterations())
+        .converged(converged)
+        .residual(rank_output.final_delta())
+        .acceleration(config.acceleration)
+        .build();
+    observer.on_stage_end(STAGE_RANK, &report);
 
     // Build debug payload BEFORE the consuming move of rank_output.
     let mut debug = crate::pipeline::artifacts::DebugPayload::build(
@@ -412,10 +762,12 @@ pub fn extract_keyphrases_with_info(tokens: &[Token], config: &TextRankConfig) -
 
     // Stage 4: phrase extraction — use original &[Token] directly,
     // avoiding the to_legacy_tokens() round-trip.
+    observer.on_stage_start(STAGE_PHRASES);
+    let clock = StageClock::start();
     let pagerank_result = rank_output.into_pagerank_result();
     let extractor = PhraseExtractor::with_config(config.clone());
 
-    let phrases = if config.debug_level.includes_full() {
+    let mut phrases = if config.debug_level.includes_full() {
         let (phrases, diags) =
             extractor.extract_with_diagnostics(tokens, graph.csr(), &pagerank_result);
         if let Some(ref mut dbg) = debug {
@@ -426,6 +778,8 @@ pub fn extract_keyphrases_with_info(tokens: &[Token], config: &TextRankConfig) -
     } else {
         extractor.extract(tokens, graph.csr(), &pagerank_result)
     };
+    config.score_normalization.apply(&mut phrases);
+    observer.on_stage_end(STAGE_PHRASES, &StageReport::new(clock.elapsed()));
 
     ExtractionResult {
         phrases,
@@ -435,6 +789,49 @@ pub fn extract_keyphrases_with_info(tokens: &[Token], config: &TextRankConfig) -
     }
 }
 
+/// Extract phrases from raw text, end-to-end.
+///
+/// Runs the built-in tokenizer and sentence splitter (see
+/// [`Tokenizer::tokenize_with_config`]) and then the standard TextRank
+/// pipeline. POS tags and lemmas are heuristic; for best quality, supply
+/// tokens from a full NLP pipeline via [`extract_keyphrases`] instead.
+///
+/// [`Tokenizer::tokenize_with_config`]: crate::nlp::tokenizer::Tokenizer::tokenize_with_config
+pub fn extract_from_text(text: &str, config: &TextRankConfig) -> Vec<Phrase> {
+    extract_from_text_with_info(text, config).phrases
+}
+
+/// Extract phrases from raw text with PageRank convergence information.
+///
+/// With the `lang-detect` feature and `config.detect_language` set, the
+/// config is first adjusted to the detected language of `text`.
+pub fn extract_from_text_with_info(text: &str, config: &TextRankConfig) -> ExtractionResult {
+    #[cfg(feature = "lang-detect")]
+    let config = &*crate::nlp::langdetect::config_for_text(config, text);
+    let tokens = crate::nlp::tokenizer::Tokenizer::new().tokenize_with_config(text, config);
+    extract_keyphrases_observed(&tokens, config, &mut crate::pipeline::NoopObserver)
+}
+
+/// Check a phrase's surface text against `config.min_phrase_words` /
+/// `config.max_phrase_words`, returning its word count when it is out of
+/// range. Words are whitespace-separated pieces with at least one
+/// alphanumeric character.
+pub(crate) fn word_count_in_range(text: &str, config: &TextRankConfig) -> Result<(), usize> {
+    let (min, max) = (config.min_phrase_words, config.max_phrase_words);
+    if min.is_none() && max.is_none() {
+        return Ok(());
+    }
+    let words = text
+        .split_whitespace()
+        .filter(|w| w.chars().any(char::is_alphanumeric))
+        .count();
+    if (min.unwrap_or(0)..=max.unwrap_or(usize::MAX)).contains(&words) {
+        Ok(())
+    } else {
+        Err(words)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -453,6 +850,33 @@ mod tests {
         ]
     }
 
+    #[test]
+    fn test_extract_from_text() {
+        let text = "Machine learning is a subset of artificial intelligence. \
+                    Machine learning models learn from data.";
+        let config = TextRankConfig::default();
+        let result = extract_from_text_with_info(text, &config);
+
+        assert!(!result.phrases.is_empty());
+        assert!(result.converged);
+        // Offsets point back into the original text.
+        for phrase in &result.phrases {
+            for &(start, end) in &phrase.offsets {
+                assert!(end <= text.len() && start < end);
+            }
+        }
+        // Stopwords from the built-in list are not phrases on their own.
+        assert!(result
+            .phrases
+            .iter()
+            .all(|p| p.lemma != "is" && p.lemma != "of"));
+    }
+
+    #[test]
+    fn test_extract_from_text_empty() {
+        assert!(extract_from_text("", &TextRankConfig::default()).is_empty());
+    }
+
     #[test]
     fn test_extract_keyphrases() {
         let tokens = make_tokens();
@@ -514,6 +938,286 @@ mod tests {
         assert!(phrases.len() <= 2);
     }
 
+    #[test]
+    fn test_mmr_diversifies_top_n() {
+        use crate::similarity::Unit;
+
+        let text = "Neural network models need data. Deep neural network models need \
+                    compute. Neural network models win benchmarks. Search engines index pages.";
+        let config = TextRankConfig::default().with_top_n(3);
+        let plain = extract_from_text_with_info(text, &config).phrases;
+        assert_eq!(
+            extract_from_text_with_info(text, &config.clone().with_mmr(1.0)).phrases,
+            plain
+        );
+
+        let diverse = extract_from_text_with_info(text, &config.with_mmr(0.3)).phrases;
+        assert_eq!(diverse.len(), plain.len());
+        assert_eq!(diverse[0], plain[0]);
+        let redundancy = |phrases: &[Phrase]| {
+            phrases[1..]
+                .iter()
+                .map(|p| LexicalSimilarity.sim(&Unit::from(&phrases[0]), &Unit::from(p)))
+                .sum::<f32>()
+        };
+        assert!(redundancy(&diverse) < redundancy(&plain));
+        assert_eq!(
+            diverse.iter().map(|p| p.rank).collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_mmr_uses_custom_similarity() {
+        use crate::similarity::Unit;
+
+        let phrase = |lemma: &str, score: f64| Phrase {
+            text: lemma.to_string(),
+            lemma: lemma.to_string(),
+            score,
+            count: 1,
+            offsets: Vec::new(),
+            spans: Vec::new(),
+            rank: 0,
+        };
+        let phrases = vec![
+            phrase("car", 1.0),
+            phrase("automobile", 0.9),
+            phrase("banana", 0.8),
+        ];
+        let config = TextRankConfig::default().with_mmr(0.5);
+
+        // No shared tokens: lexical MMR keeps the score order.
+        let lexical = PhraseExtractor::with_config(config.clone()).diversify(phrases.clone(), None);
+        assert_eq!(lexical[1].lemma, "automobile");
+
+        // A synonym-aware similarity demotes the near-duplicate.
+        let synonyms = |a: &Unit<'_>, b: &Unit<'_>| {
+            let pair = [a.lemma, b.lemma];
+            if pair == ["car", "automobile"] || pair == ["automobile", "car"] {
+                1.0
+            } else {
+                0.0
+            }
+        };
+        let diverse = PhraseExtractor::with_config(config)
+            .with_similarity(Arc::new(synonyms))
+            .diversify(phrases, None);
+        assert_eq!(diverse[1].lemma, "banana");
+    }
+
+    #[test]
+    fn test_extract_keyphrases_applies_lemma_strategy() {
+        use crate::types::LemmaStrategy;
+
+        // Lemmas that disagree with the surface form.
+        let tokens: Vec<Token> = make_tokens()
+            .into_iter()
+            .map(|mut t| {
+                t.lemma = t.lemma.to_uppercase();
+                t
+            })
+            .collect();
+        let plain = extract_keyphrases(&tokens, &TextRankConfig::default());
+        assert!(plain.iter().any(|p| p.lemma == "MACHINE LEARNING"));
+
+        let config = TextRankConfig::default().with_lemma_strategy(LemmaStrategy::Lowercase);
+        let phrases = extract_keyphrases(&tokens, &config);
+        assert_eq!(phrases, extract_keyphrases(&make_tokens(), &config));
+        assert!(phrases.iter().any(|p| p.lemma == "machine learning"));
+    }
+
+    #[test]
+    fn test_explain_reports_phrase_nodes() {
+        use crate::pipeline::artifacts::DebugLevel;
+
+        let tokens = make_tokens();
+        let plain = extract_keyphrases_with_info(&tokens, &TextRankConfig::default());
+        assert!(plain.explain(&plain.phrases[0]).is_none());
+
+        let config = TextRankConfig::default().with_debug_level(DebugLevel::Full);
+        let result = extract_keyphrases_with_info(&tokens, &config);
+        let top = &result.phrases[0];
+        let explanation = result.explain(top).unwrap();
+        assert_eq!(explanation.lemma, top.lemma);
+        assert_eq!(explanation.rank, 1);
+        assert!(!explanation.nodes.is_empty());
+        for node in &explanation.nodes {
+            let lemma = node.key.split('|').next().unwrap();
+            assert!(top.lemma.split_whitespace().any(|w| w == lemma));
+            assert!(node.score > 0.0);
+            assert!(!node.neighbors.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_phrase_word_and_frequency_limits() {
+        let text = "Neural network models need data. Deep neural network models need \
+                    compute. Neural network models win benchmarks. Search engines index pages.";
+        let config = TextRankConfig::default().with_top_n(0);
+        let plain = extract_from_text_with_info(text, &config).phrases;
+        let words = |p: &Phrase| p.text.split_whitespace().count();
+        assert!(plain.iter().any(|p| words(p) == 1));
+        assert!(plain.iter().any(|p| words(p) >= 3));
+
+        let bounded =
+            extract_from_text_with_info(text, &config.clone().with_phrase_words(2, 2)).phrases;
+        assert!(!bounded.is_empty());
+        assert!(bounded.iter().all(|p| words(p) == 2));
+
+        let frequent =
+            extract_from_text_with_info(text, &config.clone().with_min_phrase_frequency(2)).phrases;
+        assert!(!frequent.is_empty());
+        assert!(frequent.iter().all(|p| p.count >= 2));
+        assert!(frequent.len() < plain.len());
+        assert_eq!(
+            frequent.iter().map(|p| p.rank).collect::<Vec<_>>(),
+            (1..=frequent.len()).collect::<Vec<_>>()
+        );
+
+        // Full diagnostics report the filtered candidates.
+        let debug_config = TextRankConfig {
+            debug_level: crate::pipeline::artifacts::DebugLevel::Full,
+            ..config.with_phrase_words(2, 2).with_min_phrase_frequency(2)
+        };
+        let result = extract_from_text_with_info(text, &debug_config);
+        let dropped = result.debug.unwrap().dropped_candidates.unwrap();
+        use crate::pipeline::artifacts::DropReason;
+        assert!(dropped
+            .iter()
+            .any(|d| matches!(d.reason, DropReason::WordCount { words } if words != 2)));
+        assert!(dropped.iter().any(|d| matches!(
+            d.reason,
+            DropReason::BelowMinFrequency {
+                count: 1,
+                min_frequency: 2
+            }
+        )));
+    }
+
+    #[test]
+    fn test_phrase_selection_cutoffs() {
+        let text = "Neural network models need data. Deep neural network models need \
+                    compute. Neural network models win benchmarks. Search engines index pages.";
+        let config = TextRankConfig::default().with_top_n(0);
+        let all = extract_from_text_with_info(text, &config).phrases;
+        assert!(all.len() > 2);
+
+        let min_score = all[1].score;
+        let selection = PhraseSelection::ScoreThreshold { min_score };
+        let above = extract_from_text(text, &config.clone().with_phrase_selection(selection));
+        assert_eq!(
+            above.len(),
+            all.iter().filter(|p| p.score >= min_score).count()
+        );
+
+        let selection = PhraseSelection::Proportion { fraction: 0.5 };
+        let half = extract_from_text(text, &config.clone().with_phrase_selection(selection));
+        assert_eq!(half.len(), all.len().div_ceil(2));
+
+        // top_n no longer applies once a cutoff mode is chosen.
+        let debug_config = TextRankConfig {
+            debug_level: crate::pipeline::artifacts::DebugLevel::Full,
+            ..config.with_top_n(1).with_phrase_selection(selection)
+        };
+        let result = extract_from_text_with_info(text, &debug_config);
+        assert_eq!(result.phrases.len(), half.len());
+        let dropped = result.debug.unwrap().dropped_candidates.unwrap();
+        use crate::pipeline::artifacts::DropReason;
+        assert!(dropped
+            .iter()
+            .any(|d| matches!(d.reason, DropReason::BelowCutoff { kept } if kept == half.len())));
+    }
+
+    #[test]
+    fn test_phrase_bridge_words() {
+        let text = "Quality of service is rare, and quality of service is costly.";
+        let config = TextRankConfig::default().with_top_n(0);
+        let has = |phrases: &[Phrase], text: &str| phrases.iter().any(|p| p.text == text);
+
+        let plain = extract_from_text_with_info(text, &config).phrases;
+        assert!(!has(&plain, "quality of service"));
+
+        let bridged =
+            extract_from_text_with_info(text, &config.with_phrase_bridge_words(["of"])).phrases;
+        let phrase = bridged
+            .iter()
+            .find(|p| p.text.eq_ignore_ascii_case("quality of service"))
+            .expect("bridged phrase");
+        assert_eq!(phrase.count, 2);
+    }
+
+    #[test]
+    fn test_near_duplicate_merge() {
+        use crate::types::{MergeScore, NearDuplicateMerge};
+
+        let text = "Experts trust the colour model. Critics doubt the color model. \
+                    Search engines index pages.";
+        let config = TextRankConfig::default().with_top_n(0);
+        let plain = extract_from_text_with_info(text, &config).phrases;
+        let has = |phrases: &[Phrase], text: &str| phrases.iter().any(|p| p.text == text);
+        assert!(has(&plain, "colour model") && has(&plain, "color model"));
+
+        let merge = NearDuplicateMerge::new(0.1).with_score(MergeScore::Sum);
+        let merged =
+            extract_from_text_with_info(text, &config.with_near_duplicate_merge(merge)).phrases;
+        assert_eq!(merged.len(), plain.len() - 1);
+        let kept = merged.iter().find(|p| p.lemma.starts_with("colo")).unwrap();
+        let expected: f64 = plain
+            .iter()
+            .filter(|p| p.lemma.starts_with("colo"))
+            .map(|p| p.score)
+            .sum();
+        assert!((kept.score - expected).abs() < 1e-12);
+        assert_eq!(kept.count, 2);
+        assert_eq!(
+            merged.iter().map(|p| p.rank).collect::<Vec<_>>(),
+            (1..=merged.len()).collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "embeddings")]
+    #[test]
+    fn test_embedding_dedup_backfills_top_n() {
+        use crate::nlp::embeddings::WordVectors;
+        use crate::phrase::embedding_dedup::EmbeddingDedup;
+
+        let scored = |lemma: &str, score: f64, start: usize| ScoredChunk {
+            chunk: ChunkSpan {
+                start_token: start,
+                end_token: start + 2,
+                start_char: start * 10,
+                end_char: start * 10 + 5,
+                sentence_idx: 0,
+            },
+            score,
+            text: lemma.to_string(),
+            lemma: lemma.to_string(),
+        };
+        let chunks = || {
+            vec![
+                scored("car sales", 0.9, 0),
+                scored("automobile sales", 0.8, 2),
+                scored("engine", 0.5, 4),
+            ]
+        };
+        let vectors = WordVectors::from_text_reader(
+            "car 1 0 0\nautomobile 0.95 0.1 0\nsales 0 0 1\nengine 0 1 0\n".as_bytes(),
+        )
+        .unwrap();
+        let config = TextRankConfig::default().with_top_n(2);
+
+        let plain = PhraseExtractor::with_config(config.clone()).finish(chunks());
+        assert_eq!(plain[1].text, "automobile sales");
+
+        let extractor =
+            PhraseExtractor::with_config(config).with_embedding_dedup(EmbeddingDedup::new(vectors));
+        let deduped = extractor.finish(chunks());
+        let texts: Vec<&str> = deduped.iter().map(|p| p.text.as_str()).collect();
+        assert_eq!(texts, ["car sales", "engine"]);
+        assert_eq!(deduped[1].rank, 2);
+    }
+
     // ================================================================
     // Golden test helpers
     // ================================================================
@@ -708,6 +1412,121 @@ mod tests {
         assert!(!result.phrases.is_empty());
     }
 
+    #[test]
+    fn test_overlap_policy() {
+        use crate::graph::builder::GraphBuilder;
+        use crate::pagerank::standard::StandardPageRank;
+
+        let tokens = vec![
+            Token::new("Deep", "deep", PosTag::Adjective, 0, 4, 0, 0),
+            Token::new("neural", "neural", PosTag::Adjective, 5, 11, 0, 1),
+            Token::new("networks", "network", PosTag::Noun, 12, 20, 0, 2),
+            Token::new("learn", "learn", PosTag::Verb, 21, 26, 0, 3),
+            Token::new("features", "feature", PosTag::Noun, 27, 35, 0, 4),
+        ];
+        let span = |start: usize, end: usize| ChunkSpan {
+            start_token: start,
+            end_token: end,
+            start_char: tokens[start].start,
+            end_char: tokens[end - 1].end,
+            sentence_idx: 0,
+        };
+        // "deep neural" and "neural networks" share "neural".
+        let chunks = [span(0, 2), span(1, 3), span(4, 5)];
+        let builder = GraphBuilder::from_tokens_with_pos(&tokens, 3, true, None, true);
+        let graph = CsrGraph::from_builder(&builder);
+        let pagerank = StandardPageRank::new().run(&graph);
+
+        let texts = |policy: OverlapPolicy| {
+            let config = TextRankConfig::default().with_overlap_policy(policy);
+            let mut texts: Vec<String> = PhraseExtractor::with_config(config)
+                .extract_with_chunks(&tokens, &chunks, &graph, &pagerank)
+                .into_iter()
+                .map(|p| p.text)
+                .collect();
+            texts.sort();
+            texts
+        };
+
+        assert_eq!(
+            texts(OverlapPolicy::KeepAll),
+            vec!["Deep neural", "features", "neural networks"]
+        );
+        assert_eq!(
+            texts(OverlapPolicy::MergeMaximal),
+            vec!["Deep neural networks", "features"]
+        );
+        assert_eq!(texts(OverlapPolicy::KeepHighestScored).len(), 2);
+        assert!(texts(OverlapPolicy::KeepHighestScored).contains(&"features".to_string()));
+
+        // Equal lengths: the higher-scored of the two wins either way.
+        assert_eq!(
+            texts(OverlapPolicy::KeepLongest),
+            texts(OverlapPolicy::KeepHighestScored)
+        );
+        let longer = [span(0, 3), span(1, 2)];
+        let config = TextRankConfig::default().with_overlap_policy(OverlapPolicy::KeepLongest);
+        let phrases = PhraseExtractor::with_config(config)
+            .extract_with_chunks(&tokens, &longer, &graph, &pagerank);
+        assert_eq!(phrases.len(), 1);
+        assert_eq!(phrases[0].text, "Deep neural networks");
+    }
+
+    #[test]
+    fn test_phrase_blocklist_and_keep_terms() {
+        use crate::pipeline::artifacts::DropReason;
+        use crate::types::PhrasePattern;
+
+        let text = "The plaintiff filed a motion against the defendant. \
+            The plaintiff cites the contract. See figure 1 for the contract terms.";
+        let texts = |config: &TextRankConfig| -> Vec<String> {
+            extract_from_text(text, config)
+                .into_iter()
+                .map(|p| p.lemma)
+                .collect()
+        };
+        let base = TextRankConfig::default().with_top_n(0);
+        assert!(texts(&base).iter().any(|t| t == "plaintiff"));
+
+        let blocked = base.clone().with_phrase_blocklist([
+            PhrasePattern::Exact("Plaintiff".to_string()),
+            PhrasePattern::Regex("^figure".to_string()),
+        ]);
+        let lemmas = texts(&blocked);
+        assert!(!lemmas
+            .iter()
+            .any(|t| t == "plaintiff" || t.starts_with("figure")));
+        assert!(lemmas.iter().any(|t| t == "defendant"));
+
+        // A keep term overrides the blocklist.
+        let kept = blocked.clone().with_keep_terms(["plaintiff"]);
+        assert!(texts(&kept).iter().any(|t| t == "plaintiff"));
+
+        // ... and the stopword list.
+        let stopped = base.clone().with_stopwords(["defendant"]);
+        assert!(!texts(&stopped).iter().any(|t| t == "defendant"));
+        let kept = stopped.with_keep_terms(["Defendant"]);
+        assert!(texts(&kept).iter().any(|t| t == "defendant"));
+
+        // Diagnostics record the drop.
+        let tokens = crate::nlp::tokenizer::Tokenizer::new().tokenize_with_config(text, &blocked);
+        let builder = crate::graph::builder::GraphBuilder::from_tokens_with_pos(
+            &tokens,
+            blocked.window_size,
+            blocked.use_edge_weights,
+            Some(&blocked.include_pos),
+            blocked.use_pos_in_nodes,
+        );
+        let graph = CsrGraph::from_builder(&builder);
+        let pagerank = crate::pagerank::standard::StandardPageRank::new().run(&graph);
+        let (_, diagnostics) = PhraseExtractor::with_config(blocked)
+            .extract_with_diagnostics(&tokens, &graph, &pagerank);
+        assert!(diagnostics
+            .dropped_candidates
+            .iter()
+            .any(|d| matches!(d.reason, DropReason::Blocklisted) && d.lemma == "plaintiff"));
+    }
+
     // ================================================================
     // Determinism tests
     // ================================================================
@@ -755,6 +1574,54 @@ mod tests {
         assert_eq!(phrases[0].text, "Networks");
     }
 
+    #[test]
+    fn test_surface_selection() {
+        fn variant(start: usize, text: &str) -> ScoredChunk {
+            ScoredChunk {
+                chunk: ChunkSpan {
+                    start_token: start * 2,
+                    end_token: start * 2 + 2,
+                    start_char: start * 20,
+                    end_char: start * 20 + text.len(),
+                    sentence_idx: start,
+                },
+                score: 1.0,
+                text: text.to_string(),
+                lemma: "neural network".to_string(),
+            }
+        }
+        let variants = || {
+            vec![
+                variant(0, "Neural network"),
+                variant(1, "neural networks"),
+                variant(2, "neural networks"),
+            ]
+        };
+        let text_for = |selection: Option<SurfaceSelection>| {
+            let config = TextRankConfig {
+                phrase_grouping: PhraseGrouping::Lemma,
+                surface_selection: selection,
+                ..TextRankConfig::default()
+            };
+            let phrases = PhraseExtractor::with_config(config).group_phrases(variants());
+            assert_eq!(phrases.len(), 1);
+            assert_eq!(phrases[0].lemma, "neural network");
+            phrases[0].text.clone()
+        };
+
+        assert_eq!(text_for(None), "neural networks");
+        assert_eq!(
+            text_for(Some(SurfaceSelection::MostFrequent)),
+            "neural networks"
+        );
+        assert_eq!(
+            text_for(Some(SurfaceSelection::FirstOccurrence)),
+            "Neural network"
+        );
+        assert_eq!(text_for(Some(SurfaceSelection::Longest)), "neural networks");
+        assert_eq!(text_for(Some(SurfaceSelection::Lemma)), "neural network");
+    }
+
     // ================================================================
     // Debug wiring tests
     // ================================================================
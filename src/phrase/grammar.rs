@@ -0,0 +1,526 @@
+//! POS-pattern grammars for candidate selection
+//!
+//! A [`PosGrammar`] is a regular expression over part-of-speech tags. It
+//! replaces the fixed `(DET)? (ADJ)* (NOUN|PROPN)+` noun-chunk pattern when
+//! the candidates should follow a different shape, such as PKE's
+//! `grammar_selection` or domain patterns like `Noun Prep Noun`.
+//!
+//! # Syntax
+//!
+//! - A tag name matches one token with that tag. Names are case-insensitive
+//!   and accept both spaCy tags (`NOUN`, `ADJ`, `ADP`, ...) and spelled-out
+//!   names (`Noun`, `Adjective`, `Prep`, ...). `.` or `Any` matches any tag.
+//! - `<NOUN|PROPN>` is a tag class matching one token with any listed tag,
+//!   as in NLTK `RegexpParser` rules.
+//! - Juxtaposition is sequence, `|` is alternation, `( ... )` groups, and the
+//!   postfix operators `*`, `+` and `?` repeat.
+//! - An NLTK-style rule wrapper `NP: {<ADJ>*<NOUN|PROPN>+}` is accepted and
+//!   the label is ignored.
+//!
+//! Matching is by tag only (stopwords may match), never crosses a sentence
+//! boundary, and is leftmost-longest and non-overlapping.
+
+use crate::errors::{Result, TextRankError};
+use crate::types::{ChunkSpan, PosTag, Token};
+use std::ops::Range;
+
+/// The grammar used when none is given: PKE's default noun-phrase grammar.
+pub const DEFAULT_GRAMMAR: &str = "<ADJ>*<NOUN|PROPN>+";
+
+/// A compiled POS-pattern grammar
+///
+/// ```
+/// use rapid_textrank::phrase::grammar::PosGrammar;
+/// use rapid_textrank::types::PosTag;
+///
+/// let grammar = PosGrammar::parse("Noun Prep Noun | (Adj)* (Noun)+").unwrap();
+/// let tags = [PosTag::Noun, PosTag::Preposition, PosTag::Noun, PosTag::Verb];
+/// assert_eq!(grammar.find_spans(&tags, 1, usize::MAX), vec![0..3]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PosGrammar {
+    pattern: String,
+    program: Vec<Inst>,
+}
+
+/// One instruction of the compiled Thompson NFA.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Inst {
+    /// Consume one token whose tag bit is set in the mask.
+    Tag(u16),
+    /// Fork into two threads.
+    Split(usize, usize),
+    Jump(usize),
+    Match,
+}
+
+/// Parsed pattern before compilation.
+#[derive(Debug)]
+enum Node {
+    Class(u16),
+    Seq(Vec<Node>),
+    Alt(Vec<Node>),
+    Star(Box<Node>),
+    Plus(Box<Node>),
+    Opt(Box<Node>),
+}
+
+impl Default for PosGrammar {
+    fn default() -> Self {
+        Self::parse(DEFAULT_GRAMMAR).expect("default grammar is valid")
+    }
+}
+
+impl PosGrammar {
+    /// Parse and compile a grammar.
+    ///
+    /// Returns [`TextRankError::InvalidConfig`] for unknown tag names,
+    /// unbalanced brackets, dangling operators or an empty pattern.
+    pub fn parse(pattern: &str) -> Result<Self> {
+        let body = strip_rule_wrapper(pattern)?;
+        let mut parser = Parser {
+            chars: body.char_indices().peekable(),
+            src: body,
+        };
+        let node = parser.alternation()?;
+        if let Some((pos, c)) = parser.chars.next() {
+            return Err(grammar_error(pattern, format!("unexpected '{c}' at {pos}")));
+        }
+
+        let mut program = Vec::new();
+        compile(&node, &mut program);
+        program.push(Inst::Match);
+        Ok(Self {
+            pattern: pattern.to_string(),
+            program,
+        })
+    }
+
+    /// The source pattern
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Leftmost-longest, non-overlapping matches over one sentence's tags,
+    /// as token ranges of `min_len..=max_len` tokens.
+    ///
+    /// Matches are capped at `max_len` tokens (the longest match that fits
+    /// wins); matches shorter than `min_len` are skipped.
+    pub fn find_spans(&self, tags: &[PosTag], min_len: usize, max_len: usize) -> Vec<Range<usize>> {
+        let mut spans = Vec::new();
+        let mut start = 0;
+        while start < tags.len() {
+            let limit = tags.len().min(start.saturating_add(max_len));
+            match self.longest_match(&tags[start..limit]) {
+                Some(len) => {
+                    if len >= min_len {
+                        spans.push(start..start + len);
+                    }
+                    start += len;
+                }
+                None => start += 1,
+            }
+        }
+        spans
+    }
+
+    /// Match the grammar within each sentence of `tokens`, returning the
+    /// matches as chunk spans (token indices into `tokens`).
+    pub fn extract_chunks(
+        &self,
+        tokens: &[Token],
+        min_len: usize,
+        max_len: usize,
+    ) -> Vec<ChunkSpan> {
+        let mut chunks = Vec::new();
+        let mut sentence_start = 0;
+        while sentence_start < tokens.len() {
+            let sentence_idx = tokens[sentence_start].sentence_idx;
+            let sentence_end = tokens[sentence_start..]
+                .iter()
+                .position(|t| t.sentence_idx != sentence_idx)
+                .map_or(tokens.len(), |n| sentence_start + n);
+            let tags: Vec<PosTag> = tokens[sentence_start..sentence_end]
+                .iter()
+                .map(|t| t.pos)
+                .collect();
+
+            for span in self.find_spans(&tags, min_len, max_len) {
+                let (start, end) = (sentence_start + span.start, sentence_start + span.end);
+                chunks.push(ChunkSpan {
+                    start_token: start,
+                    end_token: end,
+                    start_char: tokens[start].start,
+                    end_char: tokens[end - 1].end,
+                    sentence_idx,
+                });
+            }
+            sentence_start = sentence_end;
+        }
+        chunks
+    }
+
+    /// Length of the longest non-empty match at the start of `tags`.
+    fn longest_match(&self, tags: &[PosTag]) -> Option<usize> {
+        let mut current = Vec::new();
+        let mut next = Vec::new();
+        let mut seen = vec![false; self.program.len()];
+        self.add_thread(&mut current, &mut seen, 0);
+
+        let mut longest = None;
+        for (i, &tag) in tags.iter().enumerate() {
+            let bit = tag_bit(tag);
+            seen.iter_mut().for_each(|s| *s = false);
+            for &pc in &current {
+                if let Inst::Tag(mask) = self.program[pc] {
+                    if mask & bit != 0 {
+                        self.add_thread(&mut next, &mut seen, pc + 1);
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            if next.iter().any(|&pc| self.program[pc] == Inst::Match) {
+                longest = Some(i + 1);
+            }
+            std::mem::swap(&mut current, &mut next);
+            next.clear();
+        }
+        longest
+    }
+
+    /// Add `pc` and everything reachable from it without consuming a token.
+    fn add_thread(&self, threads: &mut Vec<usize>, seen: &mut [bool], pc: usize) {
+        if seen[pc] {
+            return;
+        }
+        seen[pc] = true;
+        match self.program[pc] {
+            Inst::Split(a, b) => {
+                self.add_thread(threads, seen, a);
+                self.add_thread(threads, seen, b);
+            }
+            Inst::Jump(target) => self.add_thread(threads, seen, target),
+            Inst::Tag(_) | Inst::Match => threads.push(pc),
+        }
+    }
+}
+
+fn grammar_error(pattern: &str, message: impl std::fmt::Display) -> TextRankError {
+    TextRankError::invalid_config(format!("invalid POS grammar '{pattern}': {message}"))
+}
+
+/// Strip an NLTK-style `LABEL: { ... }` wrapper, if present.
+fn strip_rule_wrapper(pattern: &str) -> Result<&str> {
+    let Some(open) = pattern.find('{') else {
+        return Ok(pattern);
+    };
+    let close = pattern
+        .rfind('}')
+        .filter(|&close| close > open)
+        .ok_or_else(|| grammar_error(pattern, "unclosed '{'"))?;
+    let body = &pattern[open + 1..close];
+    if body.contains(['{', '}']) || !pattern[close + 1..].trim().is_empty() {
+        return Err(grammar_error(pattern, "only a single rule is supported"));
+    }
+    Ok(body)
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    fn peek(&mut self) -> Option<char> {
+        while let Some(&(_, c)) = self.chars.peek() {
+            if !c.is_whitespace() {
+                return Some(c);
+            }
+            self.chars.next();
+        }
+        None
+    }
+
+    /// `sequence ('|' sequence)*`
+    fn alternation(&mut self) -> Result<Node> {
+        let mut branches = vec![self.sequence()?];
+        while self.peek() == Some('|') {
+            self.chars.next();
+            branches.push(self.sequence()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.pop().unwrap()
+        } else {
+            Node::Alt(branches)
+        })
+    }
+
+    /// `repeat+`
+    fn sequence(&mut self) -> Result<Node> {
+        let mut items = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            items.push(self.repeat()?);
+        }
+        if items.is_empty() {
+            return Err(grammar_error(self.src, "empty pattern or alternative"));
+        }
+        Ok(if items.len() == 1 {
+            items.pop().unwrap()
+        } else {
+            Node::Seq(items)
+        })
+    }
+
+    /// `atom ('*' | '+' | '?')*`
+    fn repeat(&mut self) -> Result<Node> {
+        let mut node = self.atom()?;
+        loop {
+            node = match self.peek() {
+                Some('*') => Node::Star(Box::new(node)),
+                Some('+') => Node::Plus(Box::new(node)),
+                Some('?') => Node::Opt(Box::new(node)),
+                _ => return Ok(node),
+            };
+            self.chars.next();
+        }
+    }
+
+    /// `'(' alternation ')' | '<' name ('|' name)* '>' | '.' | name`
+    fn atom(&mut self) -> Result<Node> {
+        match self.peek() {
+            Some('(') => {
+                self.chars.next();
+                let node = self.alternation()?;
+                if self.peek() != Some(')') {
+                    return Err(grammar_error(self.src, "unclosed '('"));
+                }
+                self.chars.next();
+                Ok(node)
+            }
+            Some('<') => {
+                self.chars.next();
+                let mut mask = self.tag_name()?;
+                while self.peek() == Some('|') {
+                    self.chars.next();
+                    mask |= self.tag_name()?;
+                }
+                if self.peek() != Some('>') {
+                    return Err(grammar_error(self.src, "unclosed '<'"));
+                }
+                self.chars.next();
+                Ok(Node::Class(mask))
+            }
+            Some('.') => {
+                self.chars.next();
+                Ok(Node::Class(ANY))
+            }
+            Some(_) => Ok(Node::Class(self.tag_name()?)),
+            None => Err(grammar_error(self.src, "unexpected end of pattern")),
+        }
+    }
+
+    fn tag_name(&mut self) -> Result<u16> {
+        self.peek();
+        let mut name = String::new();
+        while let Some(&(_, c)) = self.chars.peek() {
+            if !(c.is_ascii_alphanumeric() || c == '_') {
+                break;
+            }
+            name.push(c);
+            self.chars.next();
+        }
+        if name.is_empty() {
+            let found = self
+                .chars
+                .peek()
+                .map_or("end of pattern".to_string(), |&(pos, c)| {
+                    format!("'{c}' at {pos}")
+                });
+            return Err(grammar_error(
+                self.src,
+                format!("expected a tag name, found {found}"),
+            ));
+        }
+        tag_mask(&name).ok_or_else(|| grammar_error(self.src, format!("unknown tag '{name}'")))
+    }
+}
+
+fn compile(node: &Node, program: &mut Vec<Inst>) {
+    match node {
+        Node::Class(mask) => program.push(Inst::Tag(*mask)),
+        Node::Seq(items) => items.iter().for_each(|item| compile(item, program)),
+        Node::Alt(branches) => {
+            // split L1, next; L1: branch; jump end; next: ...
+            let mut jumps = Vec::new();
+            for (i, branch) in branches.iter().enumerate() {
+                if i + 1 < branches.len() {
+                    let split = program.len();
+                    program.push(Inst::Split(split + 1, 0));
+                    compile(branch, program);
+                    jumps.push(program.len());
+                    program.push(Inst::Jump(0));
+                    program[split] = Inst::Split(split + 1, program.len());
+                } else {
+                    compile(branch, program);
+                }
+            }
+            let end = program.len();
+            for jump in jumps {
+                program[jump] = Inst::Jump(end);
+            }
+        }
+        Node::Star(inner) => {
+            let split = program.len();
+            program.push(Inst::Split(split + 1, 0));
+            compile(inner, program);
+            program.push(Inst::Jump(split));
+            program[split] = Inst::Split(split + 1, program.len());
+        }
+        Node::Plus(inner) => {
+            let start = program.len();
+            compile(inner, program);
+            program.push(Inst::Split(start, program.len() + 1));
+        }
+        Node::Opt(inner) => {
+            let split = program.len();
+            program.push(Inst::Split(split + 1, 0));
+            compile(inner, program);
+            program[split] = Inst::Split(split + 1, program.len());
+        }
+    }
+}
+
+const ANY: u16 = (1 << 15) - 1;
+
+fn tag_bit(tag: PosTag) -> u16 {
+    1 << match tag {
+        PosTag::Noun => 0,
+        PosTag::Verb => 1,
+        PosTag::Adjective => 2,
+        PosTag::Adverb => 3,
+        PosTag::Pronoun => 4,
+        PosTag::Determiner => 5,
+        PosTag::Preposition => 6,
+        PosTag::Conjunction => 7,
+        PosTag::Interjection => 8,
+        PosTag::Numeral => 9,
+        PosTag::Particle => 10,
+        PosTag::Punctuation => 11,
+        PosTag::Symbol => 12,
+        PosTag::ProperNoun => 13,
+        PosTag::Other => 14,
+    }
+}
+
+fn tag_mask(name: &str) -> Option<u16> {
+    let tag = match name.to_ascii_lowercase().as_str() {
+        "any" => return Some(ANY),
+        "noun" => PosTag::Noun,
+        "propn" | "propernoun" => PosTag::ProperNoun,
+        "verb" => PosTag::Verb,
+        "adj" | "adjective" => PosTag::Adjective,
+        "adv" | "adverb" => PosTag::Adverb,
+        "pron" | "pronoun" => PosTag::Pronoun,
+        "det" | "determiner" => PosTag::Determiner,
+        "adp" | "prep" | "preposition" => PosTag::Preposition,
+        "cconj" | "sconj" | "conj" | "conjunction" => PosTag::Conjunction,
+        "intj" | "interjection" => PosTag::Interjection,
+        "num" | "numeral" => PosTag::Numeral,
+        "part" | "particle" => PosTag::Particle,
+        "punct" | "punctuation" => PosTag::Punctuation,
+        "sym" | "symbol" => PosTag::Symbol,
+        "x" | "other" => PosTag::Other,
+        _ => return None,
+    };
+    Some(tag_bit(tag))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PosTag::*;
+
+    fn spans(pattern: &str, tags: &[PosTag]) -> Vec<Range<usize>> {
+        PosGrammar::parse(pattern)
+            .unwrap()
+            .find_spans(tags, 1, usize::MAX)
+    }
+
+    #[test]
+    fn test_default_noun_phrase_grammar() {
+        let tags = [
+            Determiner, Adjective, Noun, Noun, Verb, Adjective, Verb, ProperNoun,
+        ];
+        assert_eq!(
+            PosGrammar::default().find_spans(&tags, 1, usize::MAX),
+            vec![1..4, 7..8]
+        );
+        assert_eq!(spans("NP: {<ADJ>*<NOUN|PROPN>+}", &tags), vec![1..4, 7..8]);
+    }
+
+    #[test]
+    fn test_alternation_groups_and_longest_match() {
+        let tags = [Noun, Preposition, Noun, Verb, Noun];
+        assert_eq!(spans("Noun Prep Noun | Noun", &tags), vec![0..3, 4..5]);
+        assert_eq!(spans("(Noun (Prep Noun)?)", &tags), vec![0..3, 4..5]);
+        assert_eq!(spans("noun .* noun", &tags), vec![0..5]);
+        // Nullable repeats must not loop.
+        assert_eq!(spans("(Adj*)* Noun", &[Adjective, Noun]), vec![0..2]);
+    }
+
+    #[test]
+    fn test_length_bounds() {
+        let grammar = PosGrammar::parse("Adj* Noun+").unwrap();
+        let tags = [Adjective, Noun, Noun, Noun, Verb, Noun];
+        assert_eq!(grammar.find_spans(&tags, 1, 2), vec![0..2, 2..4, 5..6]);
+        assert_eq!(grammar.find_spans(&tags, 2, 5), vec![0..4]);
+    }
+
+    #[test]
+    fn test_extract_chunks_respects_sentences() {
+        let tokens: Vec<Token> = [("graph", Noun, 0), ("ranking", Noun, 1)]
+            .iter()
+            .enumerate()
+            .map(|(i, &(w, pos, s))| Token::new(w, w, pos, i * 10, i * 10 + w.len(), s, i))
+            .collect();
+        let chunks = PosGrammar::parse("Noun+")
+            .unwrap()
+            .extract_chunks(&tokens, 1, 5);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(
+            (
+                chunks[1].start_token,
+                chunks[1].start_char,
+                chunks[1].sentence_idx
+            ),
+            (1, 10, 1)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for bad in [
+            "",
+            "Noun |",
+            "(Noun",
+            "<Noun",
+            "Nouns",
+            "* Noun",
+            "Noun)",
+            "NP: {Noun",
+        ] {
+            assert!(
+                matches!(
+                    PosGrammar::parse(bad),
+                    Err(TextRankError::InvalidConfig { .. })
+                ),
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Single-word keyword extraction
+//!
+//! Tag clouds and query expansion want ranked words, not phrases. The
+//! functions here skip noun chunking and phrase assembly and read the top
+//! words straight off the ranked word graph: one [`Keyword`] per graph node,
+//! with its most common surface form and POS tag and every occurrence in the
+//! document.
+//!
+//! The pipeline equivalent is
+//! [`KeywordPhraseBuilder`](crate::pipeline::KeywordPhraseBuilder)
+//! (`"phrases": { "type": "keywords" }` in a pipeline spec).
+
+use super::blocklist::PhraseFilter;
+use super::extraction::choose_surface;
+use crate::pipeline::artifacts::{Graph, RankOutput, TokenStream, TokenStreamRef};
+use crate::types::{PosTag, TextRankConfig, Token};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+/// A ranked word with its occurrences
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Keyword {
+    /// The most common surface form, or the one chosen by
+    /// [`TextRankConfig::surface_selection`]
+    pub text: String,
+    /// The lemma
+    pub lemma: String,
+    /// The most common POS tag
+    pub pos: PosTag,
+    /// The word's PageRank score
+    pub score: f64,
+    /// Number of occurrences in the document
+    pub count: usize,
+    /// Token offsets `[start, end)` of each occurrence
+    pub offsets: Vec<(usize, usize)>,
+    /// Byte spans `[start, end)` of each occurrence in the original text
+    pub spans: Vec<(usize, usize)>,
+    /// The rank (1-indexed, based on score)
+    pub rank: usize,
+}
+
+/// Keywords for the ranked word-graph nodes of `graph`, best first.
+///
+/// Occurrences are the candidate tokens (non-stopwords with an included POS
+/// tag) whose graph key names a node; nodes without occurrences and words
+/// matching `cfg.phrase_blocklist` are skipped. The list is cut by
+/// `cfg.top_n` / `cfg.phrase_selection` and ties are broken by first
+/// occurrence. Scores are the raw ranks.
+pub fn rank_keywords(
+    tokens: TokenStreamRef<'_>,
+    graph: &Graph,
+    ranks: &RankOutput,
+    cfg: &TextRankConfig,
+) -> Vec<Keyword> {
+    let is_candidate = |pos: PosTag| {
+        if cfg.include_pos.is_empty() {
+            pos.is_content_word()
+        } else {
+            cfg.include_pos.contains(&pos)
+        }
+    };
+
+    let node_key = cfg.effective_node_key();
+    // Node → keyword index, in order of first occurrence.
+    let mut by_node: FxHashMap<u32, usize> = FxHashMap::default();
+    let mut keywords: Vec<Keyword> = Vec::new();
+    let mut surfaces: Vec<Vec<(&str, usize)>> = Vec::new();
+    let mut tags: Vec<Vec<(PosTag, usize)>> = Vec::new();
+    for (i, entry) in tokens.tokens().iter().enumerate() {
+        if entry.is_stopword || !is_candidate(entry.pos) {
+            continue;
+        }
+        let key = entry.node_key(tokens.pool(), &node_key);
+        let Some(node) = graph.get_node_by_lemma(&key) else {
+            continue;
+        };
+        let k = *by_node.entry(node).or_insert_with(|| {
+            keywords.push(Keyword {
+                text: String::new(),
+                lemma: tokens.lemma(entry).to_string(),
+                pos: entry.pos,
+                score: ranks.score(node),
+                count: 0,
+                offsets: Vec::new(),
+                spans: Vec::new(),
+                rank: 0,
+            });
+            surfaces.push(Vec::new());
+            tags.push(Vec::new());
+            keywords.len() - 1
+        });
+        let keyword = &mut keywords[k];
+        keyword.count += 1;
+        keyword.offsets.push((i, i + 1));
+        keyword
+            .spans
+            .push((entry.start as usize, entry.end as usize));
+        bump(&mut surfaces[k], tokens.text(entry));
+        bump(&mut tags[k], entry.pos);
+    }
+
+    for ((keyword, surfaces), tags) in keywords.iter_mut().zip(surfaces).zip(tags) {
+        keyword.text = most_common(surfaces).unwrap_or_default().to_string();
+        keyword.pos = most_common(tags).unwrap_or(keyword.pos);
+        if cfg.surface_selection.is_some() {
+            let variants: Vec<(&str, usize)> = keyword
+                .offsets
+                .iter()
+                .map(|&(i, _)| (tokens.text(&tokens.tokens()[i]), i))
+                .collect();
+            keyword.text =
+                choose_surface(&variants, &keyword.lemma, 0, cfg.surface_selection).to_string();
+        }
+    }
+
+    let filter = PhraseFilter::for_config(cfg);
+    keywords.retain(|k| !filter.is_blocked(&k.text, &k.lemma));
+
+    // Stable sort: equal scores keep first-occurrence order.
+    keywords.sort_by(|a, b| b.score.total_cmp(&a.score));
+    let scores: Vec<f64> = keywords.iter().map(|k| k.score).collect();
+    if let Some(limit) = cfg.phrase_selection.limit(cfg.top_n, &scores) {
+        keywords.truncate(limit);
+    }
+    for (i, keyword) in keywords.iter_mut().enumerate() {
+        keyword.rank = i + 1;
+    }
+    keywords
+}
+
+/// Extract the top-ranked single words from pre-tokenized input.
+///
+/// Builds and ranks the same word graph as
+/// [`extract_keyphrases`](super::extraction::extract_keyphrases), then
+/// returns [`rank_keywords`] instead of assembling phrases.
+pub fn extract_keywords(tokens: &[Token], config: &TextRankConfig) -> Vec<Keyword> {
+    use crate::graph::builder::GraphBuilder;
+    use crate::pipeline::Ranker;
+
+    let include_pos = if config.include_pos.is_empty() {
+        None
+    } else {
+        Some(config.include_pos.as_slice())
+    };
+    let builder = GraphBuilder::from_tokens_with_pos(
+        tokens,
+        config.window_size,
+        config.use_edge_weights,
+        include_pos,
+        config.effective_node_key(),
+    );
+    if builder.is_empty() {
+        return Vec::new();
+    }
+
+    let graph = Graph::from_builder(&builder);
+    let ranks = crate::pipeline::PageRankRanker.rank(&graph, None, config);
+    let stream = TokenStream::from_tokens(tokens);
+    rank_keywords(stream.as_ref(), &graph, &ranks, config)
+}
+
+/// Extract the top-ranked single words from raw text, end-to-end.
+///
+/// Tokenizes like [`extract_from_text`](super::extraction::extract_from_text).
+pub fn extract_keywords_from_text(text: &str, config: &TextRankConfig) -> Vec<Keyword> {
+    #[cfg(feature = "lang-detect")]
+    let config = &*crate::nlp::langdetect::config_for_text(config, text);
+    let tokens = crate::nlp::tokenizer::Tokenizer::new().tokenize_with_config(text, config);
+    extract_keywords(&tokens, config)
+}
+
+fn bump<T: PartialEq>(counts: &mut Vec<(T, usize)>, value: T) {
+    match counts.iter_mut().find(|(v, _)| *v == value) {
+        Some((_, n)) => *n += 1,
+        None => counts.push((value, 1)),
+    }
+}
+
+/// The most frequent value, the earliest seen on ties.
+fn most_common<T>(counts: Vec<(T, usize)>) -> Option<T> {
+    let mut best: Option<(T, usize)> = None;
+    for (value, n) in counts {
+        if best.as_ref().is_none_or(|&(_, m)| n > m) {
+            best = Some((value, n));
+        }
+    }
+    best.map(|(value, _)| value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_tokens() -> Vec<Token> {
+        vec![
+            Token::new("Machine", "machine", PosTag::Noun, 0, 7, 0, 0),
+            Token::new("learning", "learning", PosTag::Noun, 8, 16, 0, 1),
+            Token::new("is", "be", PosTag::Verb, 17, 19, 0, 2),
+            Token::new("fun", "fun", PosTag::Adjective, 20, 23, 0, 3),
+            Token::new("machine", "machine", PosTag::Noun, 25, 32, 1, 4),
+            Token::new("learning", "learning", PosTag::Noun, 33, 41, 1, 5),
+            Token::new("machines", "machine", PosTag::Noun, 42, 50, 1, 6),
+        ]
+    }
+
+    #[test]
+    fn test_extract_keywords_ranks_words_with_occurrences() {
+        let mut tokens = make_tokens();
+        tokens[2].is_stopword = true;
+        let keywords = extract_keywords(&tokens, &TextRankConfig::default());
+
+        let machine = keywords.iter().find(|k| k.lemma == "machine").unwrap();
+        assert_eq!(machine.count, 3);
+        // Three surface forms once each: the first one wins.
+        assert_eq!(machine.text, "Machine");
+        assert_eq!(machine.pos, PosTag::Noun);
+        assert_eq!(machine.offsets, vec![(0, 1), (4, 5), (6, 7)]);
+        assert_eq!(machine.spans, vec![(0, 7), (25, 32), (42, 50)]);
+
+        assert!(keywords.iter().all(|k| k.lemma != "be"));
+        assert!(keywords.windows(2).all(|w| w[0].score >= w[1].score));
+        assert_eq!(
+            keywords.iter().map(|k| k.rank).collect::<Vec<_>>(),
+            (1..=keywords.len()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_extract_keywords_surface_selection() {
+        use crate::types::SurfaceSelection;
+
+        let text_for = |selection| {
+            let cfg = TextRankConfig::default().with_surface_selection(selection);
+            let keywords = extract_keywords(&make_tokens(), &cfg);
+            keywords
+                .into_iter()
+                .find(|k| k.lemma == "machine")
+                .unwrap()
+                .text
+        };
+        assert_eq!(text_for(SurfaceSelection::FirstOccurrence), "Machine");
+        assert_eq!(text_for(SurfaceSelection::Longest), "machines");
+        assert_eq!(text_for(SurfaceSelection::MostFrequent), "Machine");
+        assert_eq!(text_for(SurfaceSelection::Lemma), "machine");
+    }
+
+    #[test]
+    fn test_extract_keywords_respects_top_n() {
+        let cfg = TextRankConfig {
+            top_n: 1,
+            ..Default::default()
+        };
+        let keywords = extract_keywords(&make_tokens(), &cfg);
+        let all = extract_keywords(&make_tokens(), &TextRankConfig::default());
+
+        assert_eq!(keywords.len(), 1);
+        assert_eq!(keywords[0], all[0]);
+    }
+
+    #[test]
+    fn test_extract_keywords_empty() {
+        assert!(extract_keywords(&[], &TextRankConfig::default()).is_empty());
+        assert!(extract_keywords_from_text("", &TextRankConfig::default()).is_empty());
+    }
+}
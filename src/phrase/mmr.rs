@@ -0,0 +1,169 @@
+//! Maximal Marginal Relevance (MMR) diversification
+//!
+//! The top of a score-sorted phrase list often holds several variations of
+//! the same phrase ("neural network", "deep neural network", "neural
+//! networks"). MMR re-ranks it greedily: each pick maximizes
+//!
+//! ```text
+//! lambda * relevance(p) - (1 - lambda) * max(similarity(p, q) for q already picked)
+//! ```
+//!
+//! where relevance is the phrase score divided by the top score. `lambda = 1`
+//! keeps the score order; lower values trade score for diversity.
+//!
+//! Similarity is any [`Similarity`]; the built-in extraction path uses
+//! [`LexicalSimilarity`](crate::similarity::LexicalSimilarity) (lemma word
+//! overlap) unless the extractor is given
+//! another one.
+
+use crate::similarity::{Similarity, Unit};
+use crate::types::Phrase;
+
+/// MMR selection order over `(score, unit)` candidates.
+///
+/// Returns the indices of up to `k` candidates (all of them when `k == 0`)
+/// in pick order. Ties go to the earlier candidate, so the result is
+/// deterministic for a given input order.
+pub fn mmr_order(
+    candidates: &[(f64, Unit<'_>)],
+    lambda: f64,
+    k: usize,
+    similarity: &dyn Similarity,
+) -> Vec<usize> {
+    let k = if k == 0 {
+        candidates.len()
+    } else {
+        k.min(candidates.len())
+    };
+    let top = candidates.iter().map(|c| c.0).fold(0.0, f64::max);
+    let relevance = |i: usize| {
+        if top > 0.0 {
+            candidates[i].0 / top
+        } else {
+            0.0
+        }
+    };
+
+    let mut picked = Vec::with_capacity(k);
+    // Highest similarity of each candidate to anything picked so far.
+    let mut redundancy = vec![0.0f64; candidates.len()];
+    let mut remaining: Vec<usize> = (0..candidates.len()).collect();
+
+    while picked.len() < k {
+        let mut best = 0;
+        let mut best_value = f64::NEG_INFINITY;
+        for (pos, &i) in remaining.iter().enumerate() {
+            let value = lambda * relevance(i) - (1.0 - lambda) * redundancy[i];
+            if value > best_value {
+                best = pos;
+                best_value = value;
+            }
+        }
+        let chosen = remaining.remove(best);
+        for &i in &remaining {
+            let sim = similarity.sim(&candidates[chosen].1, &candidates[i].1) as f64;
+            redundancy[i] = redundancy[i].max(sim);
+        }
+        picked.push(chosen);
+    }
+    picked
+}
+
+/// Re-rank score-sorted `phrases` with MMR.
+///
+/// The first `k` phrases (all when `k == 0`) come out in MMR order, followed
+/// by the rest in their original order; scores are unchanged, ranks are
+/// reassigned.
+pub fn diversify(
+    phrases: Vec<Phrase>,
+    lambda: f64,
+    k: usize,
+    similarity: &dyn Similarity,
+) -> Vec<Phrase> {
+    let candidates: Vec<(f64, Unit<'_>)> =
+        phrases.iter().map(|p| (p.score, Unit::from(p))).collect();
+    let order = mmr_order(&candidates, lambda, k, similarity);
+
+    let mut slots: Vec<Option<Phrase>> = phrases.into_iter().map(Some).collect();
+    let mut out: Vec<Phrase> = order.iter().filter_map(|&i| slots[i].take()).collect();
+    out.extend(slots.into_iter().flatten());
+    for (i, phrase) in out.iter_mut().enumerate() {
+        phrase.rank = i + 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::similarity::LexicalSimilarity;
+
+    fn phrase(lemma: &str, score: f64) -> Phrase {
+        Phrase {
+            text: lemma.to_string(),
+            lemma: lemma.to_string(),
+            score,
+            count: 1,
+            offsets: Vec::new(),
+            spans: Vec::new(),
+            rank: 0,
+        }
+    }
+
+    #[test]
+    fn test_diversify_demotes_near_duplicates() {
+        let phrases = vec![
+            phrase("neural network", 1.0),
+            phrase("deep neural network", 0.9),
+            phrase("neural network model", 0.85),
+            phrase("search engine", 0.5),
+        ];
+
+        let kept = diversify(phrases.clone(), 1.0, 0, &LexicalSimilarity);
+        let lemmas: Vec<&str> = kept.iter().map(|p| p.lemma.as_str()).collect();
+        assert_eq!(
+            lemmas,
+            [
+                "neural network",
+                "deep neural network",
+                "neural network model",
+                "search engine"
+            ]
+        );
+
+        let diverse = diversify(phrases, 0.5, 2, &LexicalSimilarity);
+        let lemmas: Vec<&str> = diverse.iter().map(|p| p.lemma.as_str()).collect();
+        assert_eq!(
+            lemmas,
+            [
+                "neural network",
+                "search engine",
+                "deep neural network",
+                "neural network model"
+            ]
+        );
+        assert_eq!(
+            diverse.iter().map(|p| p.rank).collect::<Vec<_>>(),
+            [1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_custom_similarity() {
+        let candidates = [
+            (1.0, Unit::from_lemma("apple")),
+            (0.9, Unit::from_lemma("avocado")),
+            (0.5, Unit::from_lemma("banana")),
+        ];
+        assert_eq!(
+            mmr_order(&candidates, 0.5, 0, &LexicalSimilarity),
+            [0, 1, 2]
+        );
+
+        // Phrases sharing a first letter count as duplicates.
+        let initial = |a: &Unit<'_>, b: &Unit<'_>| {
+            f32::from(u8::from(a.lemma.chars().next() == b.lemma.chars().next()))
+        };
+        assert_eq!(mmr_order(&candidates, 0.5, 0, &initial), [0, 2, 1]);
+    }
+}
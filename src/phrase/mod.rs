@@ -3,6 +3,18 @@
 //! This module provides noun chunk detection, overlap resolution,
 //! and phrase extraction with canonical form selection.
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod batch;
+pub mod blocklist;
 pub mod chunker;
+pub mod corpus;
 pub mod dedup;
+#[cfg(feature = "embeddings")]
+pub mod embedding_dedup;
 pub mod extraction;
+pub mod grammar;
+pub mod keywords;
+pub mod mmr;
+pub mod near_duplicates;
+pub mod streaming;
@@ -0,0 +1,220 @@
+//! Near-duplicate phrase merging
+//!
+//! Grouping only merges phrases whose lemma (or scrubbed text) is identical,
+//! so spelling variants ("colour model" / "color model") and plurals the
+//! lemmatizer missed survive as separate phrases. This step merges phrases
+//! whose lemmas are within a normalized string distance of each other.
+//!
+//! Merging is greedy in score order: each phrase is absorbed by the first
+//! higher-scored kept phrase within `max_distance`. The kept phrase keeps its
+//! surface form and lemma, gains the absorbed occurrences, and takes the
+//! maximum or the sum of the scores per [`MergeScore`].
+
+use crate::pipeline::artifacts::{DropReason, DroppedCandidate};
+use crate::types::{MergeScore, NearDuplicateMerge, Phrase, StringDistance};
+
+/// Levenshtein distance divided by the longer string's length in characters.
+///
+/// Returns 0 for two empty strings.
+pub fn normalized_levenshtein(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 0.0;
+    }
+
+    // Single-row dynamic programming over `b`.
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()] as f64 / longest as f64
+}
+
+/// Jaro-Winkler similarity in `[0, 1]` (prefix scale 0.1, up to 4 chars).
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0usize;
+    for (i, ca) in a.iter().enumerate() {
+        let lo = i.saturating_sub(window);
+        let hi = (i + window + 1).min(b.len());
+        for j in lo..hi {
+            if !b_matched[j] && b[j] == *ca {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // Half the number of matched characters that appear in a different order.
+    let a_seq = a.iter().zip(&a_matched).filter(|(_, &m)| m).map(|(c, _)| c);
+    let b_seq = b.iter().zip(&b_matched).filter(|(_, &m)| m).map(|(c, _)| c);
+    let transpositions = a_seq.zip(b_seq).filter(|(x, y)| x != y).count() / 2;
+
+    let m = matches as f64;
+    let jaro = (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0;
+
+    let prefix = a.iter().zip(&b).take(4).take_while(|(x, y)| x == y).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+impl StringDistance {
+    /// Normalized distance between `a` and `b`, in `[0, 1]` (0 = identical).
+    pub fn distance(&self, a: &str, b: &str) -> f64 {
+        match self {
+            StringDistance::Levenshtein => normalized_levenshtein(a, b),
+            StringDistance::JaroWinkler => 1.0 - jaro_winkler(a, b),
+        }
+    }
+}
+
+/// Merge near-duplicate phrases.
+///
+/// `phrases` should be sorted by score descending; the result keeps that
+/// order, but [`MergeScore::Sum`] can raise a kept phrase's score, so callers
+/// that need a strict score order must re-sort. Ranks are not touched.
+pub fn merge_near_duplicates(phrases: Vec<Phrase>, merge: &NearDuplicateMerge) -> Vec<Phrase> {
+    merge_near_duplicates_with_diagnostics(phrases, merge).0
+}
+
+/// [`merge_near_duplicates`] that also records every absorbed phrase.
+pub fn merge_near_duplicates_with_diagnostics(
+    phrases: Vec<Phrase>,
+    merge: &NearDuplicateMerge,
+) -> (Vec<Phrase>, Vec<DroppedCandidate>) {
+    let mut kept: Vec<Phrase> = Vec::with_capacity(phrases.len());
+    let mut dropped = Vec::new();
+
+    for phrase in phrases {
+        let target = kept.iter().enumerate().find_map(|(i, k)| {
+            let distance = merge.distance.distance(&k.lemma, &phrase.lemma);
+            (distance <= merge.max_distance).then_some((i, distance))
+        });
+
+        let Some((i, distance)) = target else {
+            kept.push(phrase);
+            continue;
+        };
+
+        let into = &mut kept[i];
+        dropped.push(DroppedCandidate {
+            text: phrase.text.clone(),
+            lemma: phrase.lemma.clone(),
+            score: phrase.score,
+            token_range: phrase.offsets.first().copied().unwrap_or((0, 0)),
+            reason: DropReason::NearDuplicateOf {
+                kept_text: into.text.clone(),
+                distance,
+            },
+        });
+        into.score = match merge.score {
+            MergeScore::Max => into.score.max(phrase.score),
+            MergeScore::Sum => into.score + phrase.score,
+        };
+        into.count += phrase.count;
+        into.offsets.extend(phrase.offsets);
+        into.offsets.sort_unstable();
+        into.spans.extend(phrase.spans);
+        into.spans.sort_unstable();
+    }
+
+    (kept, dropped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phrase(lemma: &str, score: f64, start: usize) -> Phrase {
+        Phrase {
+            text: lemma.to_string(),
+            lemma: lemma.to_string(),
+            score,
+            count: 1,
+            offsets: vec![(start, start + 2)],
+            spans: Vec::new(),
+            rank: 0,
+        }
+    }
+
+    #[test]
+    fn test_normalized_levenshtein() {
+        assert_eq!(normalized_levenshtein("", ""), 0.0);
+        assert_eq!(normalized_levenshtein("graph", "graph"), 0.0);
+        assert_eq!(normalized_levenshtein("abc", ""), 1.0);
+        assert!((normalized_levenshtein("kitten", "sitting") - 3.0 / 7.0).abs() < 1e-12);
+        assert!((normalized_levenshtein("colour", "color") - 1.0 / 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        assert_eq!(jaro_winkler("graph", "graph"), 1.0);
+        assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+        assert!((jaro_winkler("martha", "marhta") - 0.961_111).abs() < 1e-6);
+        assert!((jaro_winkler("dixon", "dicksonx") - 0.813_333).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_merge_keeps_higher_scored_form() {
+        let phrases = vec![
+            phrase("colour model", 0.6, 0),
+            phrase("color model", 0.4, 10),
+            phrase("search engine", 0.3, 5),
+        ];
+
+        let merged = merge_near_duplicates(phrases.clone(), &NearDuplicateMerge::new(0.1));
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].text, "colour model");
+        assert_eq!(merged[0].score, 0.6);
+        assert_eq!(merged[0].count, 2);
+        assert_eq!(merged[0].offsets, [(0, 2), (10, 12)]);
+
+        let summed = merge_near_duplicates(
+            phrases.clone(),
+            &NearDuplicateMerge::new(0.1).with_score(MergeScore::Sum),
+        );
+        assert!((summed[0].score - 1.0).abs() < 1e-12);
+
+        let (kept, dropped) = merge_near_duplicates_with_diagnostics(
+            phrases,
+            &NearDuplicateMerge::new(0.1).with_distance(StringDistance::JaroWinkler),
+        );
+        assert_eq!(kept.len(), 2);
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(dropped[0].text, "color model");
+        assert!(matches!(
+            &dropped[0].reason,
+            DropReason::NearDuplicateOf { kept_text, .. } if kept_text == "colour model"
+        ));
+    }
+
+    #[test]
+    fn test_zero_distance_merges_only_identical_lemmas() {
+        let phrases = vec![phrase("graph", 0.5, 0), phrase("graphs", 0.4, 3)];
+        let merged = merge_near_duplicates(phrases, &NearDuplicateMerge::new(0.0));
+        assert_eq!(merged.len(), 2);
+    }
+}
@@ -0,0 +1,358 @@
+//! Streaming keyphrase extraction for very large documents
+//!
+//! [`StreamingExtractor`] takes a document's tokens in chunks via
+//! [`push_tokens`](StreamingExtractor::push_tokens) and accumulates
+//! co-occurrence counts and phrase candidates incrementally, so the full
+//! token vector never has to be materialized. Only the trailing, possibly
+//! incomplete sentence is buffered between pushes; every complete sentence
+//! is folded into the graph and dropped. [`finish`](StreamingExtractor::finish)
+//! ranks the graph and builds phrases, giving the same result as
+//! [`extract_keyphrases_with_info`](super::extraction::extract_keyphrases_with_info)
+//! over the concatenated tokens.
+//!
+//! For a growing document (e.g. a live transcript),
+//! [`snapshot`](StreamingExtractor::snapshot) ranks what has arrived so far
+//! without ending the session: appended tokens only add co-occurrences for
+//! their own window positions, and each snapshot warm-starts PageRank from
+//! the previous scores.
+//!
+//! Memory grows with the vocabulary, the number of noun-chunk candidates
+//! and the longest sentence — not with the token count. Chunks may split a
+//! sentence anywhere; tokens must arrive in document order, with
+//! `sentence_idx` marking sentence runs as in the one-shot path.
+//!
+//! ```
+//! use rapid_textrank::nlp::tokenizer::Tokenizer;
+//! use rapid_textrank::phrase::streaming::StreamingExtractor;
+//! use rapid_textrank::TextRankConfig;
+//!
+//! let config = TextRankConfig::default();
+//! let text = "Graphs have nodes. Nodes in graphs have edges.";
+//! let tokens = Tokenizer::new().tokenize_with_config(text, &config);
+//! let mut stream = StreamingExtractor::new(config);
+//! for chunk in tokens.chunks(4) {
+//!     stream.push_tokens(chunk);
+//! }
+//! let result = stream.finish();
+//! assert!(!result.phrases.is_empty());
+//! ```
+
+use super::chunker::{chunk_lemma, chunk_text, NounChunker};
+use super::dedup::ScoredChunk;
+use super::extraction::{ExtractionResult, PhraseExtractor};
+use crate::graph::builder::GraphBuilder;
+use crate::pipeline::artifacts::{DebugPayload, Graph};
+use crate::pipeline::{PageRankRanker, Ranker};
+use crate::types::{ChunkSpan, TextRankConfig, Token};
+use rustc_hash::FxHashMap;
+
+/// Incremental TextRank extractor fed with token chunks.
+///
+/// Phrase-level diagnostics (`debug_level = full`) are not recorded in
+/// streaming mode; the graph and ranking parts of the debug payload are.
+#[derive(Debug)]
+pub struct StreamingExtractor {
+    config: TextRankConfig,
+    chunker: NounChunker,
+    builder: GraphBuilder,
+    /// Tokens of the trailing sentence, not yet folded in
+    pending: Vec<Token>,
+    /// Number of tokens folded in so far
+    consumed: usize,
+    /// Noun-chunk candidates seen so far, in document order
+    candidates: Vec<StreamedChunk>,
+    /// Interned graph keys of candidate tokens
+    keys: FxHashMap<String, u32>,
+    /// Node scores from the last snapshot, for warm starts
+    previous_scores: Option<Vec<f64>>,
+}
+
+/// A phrase candidate awaiting its score.
+#[derive(Debug)]
+struct StreamedChunk {
+    chunk: ChunkSpan,
+    text: String,
+    lemma: String,
+    /// Interned graph keys of the chunk's tokens
+    keys: Vec<u32>,
+}
+
+impl StreamingExtractor {
+    /// Create an extractor for one document.
+    pub fn new(config: TextRankConfig) -> Self {
+        let chunker = NounChunker::new()
+            .with_min_length(config.min_phrase_length)
+            .with_max_length(config.max_phrase_length)
+            .with_bridge_words(&config.phrase_bridge_words);
+        Self {
+            config,
+            chunker,
+            builder: GraphBuilder::new(),
+            pending: Vec::new(),
+            consumed: 0,
+            candidates: Vec::new(),
+            keys: FxHashMap::default(),
+            previous_scores: None,
+        }
+    }
+
+    /// Feed the next chunk of the document's tokens.
+    pub fn push_tokens(&mut self, tokens: &[Token]) {
+        let Some(last) = tokens.last().map(|t| t.sentence_idx) else {
+            return;
+        };
+        // Tokens before `split` belong to sentences that are now complete.
+        let split = tokens
+            .iter()
+            .rposition(|t| t.sentence_idx != last)
+            .map_or(0, |i| i + 1);
+
+        if split > 0 {
+            if self.pending.is_empty() {
+                self.fold(&tokens[..split]);
+            } else {
+                let mut complete = std::mem::take(&mut self.pending);
+                complete.extend_from_slice(&tokens[..split]);
+                self.fold(&complete);
+            }
+        } else if self.pending.last().is_some_and(|t| t.sentence_idx != last) {
+            let complete = std::mem::take(&mut self.pending);
+            self.fold(&complete);
+        }
+        self.pending.extend_from_slice(&tokens[split..]);
+    }
+
+    /// Number of tokens pushed so far.
+    pub fn token_count(&self) -> usize {
+        self.consumed + self.pending.len()
+    }
+
+    /// Mark the trailing sentence as complete, folding it in now rather than
+    /// when the next sentence starts.
+    ///
+    /// Call this when a live source (e.g. a transcript) is known to have
+    /// ended a sentence, so the next [`snapshot`](Self::snapshot) includes
+    /// it. Tokens pushed afterwards must start a new sentence.
+    pub fn end_sentence(&mut self) {
+        let pending = std::mem::take(&mut self.pending);
+        self.fold(&pending);
+    }
+
+    /// Rank the document so far without ending the session.
+    ///
+    /// Covers every complete sentence; the trailing sentence is included
+    /// once it completes (or after [`end_sentence`](Self::end_sentence)).
+    /// Each snapshot warm-starts PageRank from the previous one's scores, so
+    /// tracking a growing document re-converges in a few iterations; scores
+    /// agree with a cold run to within `convergence_threshold`.
+    pub fn snapshot(&mut self) -> ExtractionResult {
+        self.rank()
+    }
+
+    /// Rank the accumulated graph and build the final phrases.
+    ///
+    /// Warm-starts from the last [`snapshot`](Self::snapshot), if any;
+    /// without snapshots the result is identical to the one-shot path.
+    pub fn finish(mut self) -> ExtractionResult {
+        self.end_sentence();
+        self.rank()
+    }
+
+    fn rank(&mut self) -> ExtractionResult {
+        if self.builder.is_empty() {
+            return ExtractionResult {
+                phrases: Vec::new(),
+                converged: true,
+                iterations: 0,
+                debug: None,
+            };
+        }
+
+        let config = &self.config;
+        let graph = Graph::from_builder(&self.builder);
+        // Node IDs are stable as the graph grows, so previous scores line up.
+        let rank_output = match &self.previous_scores {
+            Some(previous) => PageRankRanker.rank_warm(&graph, None, config, previous),
+            None => PageRankRanker.rank(&graph, None, config),
+        };
+        let converged = rank_output.converged();
+        let iterations = rank_output.iterations() as usize;
+        let debug =
+            DebugPayload::build(config.debug_level, &graph, &rank_output, config.debug_top_k);
+        let pagerank = rank_output.into_pagerank_result();
+
+        // Interned key -> graph node (keys of non-candidate tokens may be absent).
+        let mut key_nodes = vec![None; self.keys.len()];
+        for (key, &id) in &self.keys {
+            key_nodes[id as usize] = graph.csr().get_node_by_lemma(key);
+        }
+
+        let scored: Vec<ScoredChunk> = self
+            .candidates
+            .iter()
+            .filter_map(|c| {
+                let scores: Vec<f64> = c
+                    .keys
+                    .iter()
+                    .filter_map(|&k| key_nodes[k as usize])
+                    .map(|node| pagerank.score(node))
+                    .collect();
+                let score = config.score_aggregation.aggregate(&scores);
+                (score > 0.0).then(|| ScoredChunk {
+                    chunk: c.chunk.clone(),
+                    score,
+                    text: c.text.clone(),
+                    lemma: c.lemma.clone(),
+                })
+            })
+            .collect();
+
+        let mut phrases = PhraseExtractor::with_config(config.clone()).finish(scored);
+        config.score_normalization.apply(&mut phrases);
+        self.previous_scores = Some(pagerank.scores);
+        ExtractionResult {
+            phrases,
+            converged,
+            iterations,
+            debug,
+        }
+    }
+
+    /// Fold complete sentences into the graph and candidate list.
+    fn fold(&mut self, tokens: &[Token]) {
+        if tokens.is_empty() {
+            return;
+        }
+        let config = &self.config;
+        let node_key = config.effective_node_key();
+        let include_pos = if config.include_pos.is_empty() {
+            None
+        } else {
+            Some(config.include_pos.as_slice())
+        };
+        self.builder.add_tokens(
+            tokens,
+            config.window_size,
+            config.use_edge_weights,
+            include_pos,
+            node_key.clone(),
+            true,
+        );
+
+        // Chunk spans carry document token indices; `base` maps them into
+        // this slice.
+        let base = tokens[0].token_idx;
+        for chunk in self.chunker.extract_chunks(tokens) {
+            let local = ChunkSpan {
+                start_token: chunk.start_token - base,
+                end_token: chunk.end_token - base,
+                ..chunk.clone()
+            };
+            let keys = tokens[local.start_token..local.end_token]
+                .iter()
+                .map(|t| {
+                    let next = self.keys.len() as u32;
+                    *self
+                        .keys
+                        .entry(node_key.key(t).into_owned())
+                        .or_insert(next)
+                })
+                .collect();
+            self.candidates.push(StreamedChunk {
+                text: chunk_text(tokens, &local),
+                lemma: chunk_lemma(tokens, &local),
+                chunk,
+                keys,
+            });
+        }
+        self.consumed += tokens.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nlp::tokenizer::Tokenizer;
+    use crate::phrase::extraction::extract_keyphrases_with_info;
+    use crate::types::ScoreAggregation;
+
+    const TEXT: &str = "Machine learning is a subset of artificial intelligence. \
+        Deep learning models learn representations from data. \
+        Machine learning models power modern search engines. \
+        Artificial intelligence research builds on machine learning.";
+
+    fn stream(tokens: &[Token], chunk_size: usize, config: &TextRankConfig) -> ExtractionResult {
+        let mut stream = StreamingExtractor::new(config.clone());
+        for chunk in tokens.chunks(chunk_size) {
+            stream.push_tokens(chunk);
+        }
+        assert_eq!(stream.token_count(), tokens.len());
+        stream.finish()
+    }
+
+    #[test]
+    fn test_streaming_matches_one_shot() {
+        let mut binary = TextRankConfig::default().with_score_aggregation(ScoreAggregation::Mean);
+        binary.use_edge_weights = false;
+        for config in [TextRankConfig::default(), binary] {
+            let tokens = Tokenizer::new().tokenize_with_config(TEXT, &config);
+            let expected = extract_keyphrases_with_info(&tokens, &config);
+            assert!(!expected.phrases.is_empty());
+            // Chunk sizes that split sentences, align with none, and cover all.
+            for chunk_size in [1, 3, 7, tokens.len()] {
+                assert_eq!(stream(&tokens, chunk_size, &config), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_streaming_empty_input() {
+        let mut stream = StreamingExtractor::new(TextRankConfig::default());
+        stream.push_tokens(&[]);
+        let result = stream.finish();
+        assert!(result.phrases.is_empty());
+        assert!(result.converged);
+    }
+
+    fn assert_close(warm: &ExtractionResult, cold: &ExtractionResult) {
+        assert!(warm.converged);
+        assert_eq!(warm.phrases.len(), cold.phrases.len());
+        for (w, c) in warm.phrases.iter().zip(&cold.phrases) {
+            assert_eq!(w.lemma, c.lemma);
+            assert!((w.score - c.score).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_snapshot_tracks_growing_document() {
+        let config = TextRankConfig::default();
+        let tokens = Tokenizer::new().tokenize_with_config(TEXT, &config);
+        let half = tokens
+            .iter()
+            .position(|t| t.sentence_idx == 2)
+            .expect("at least three sentences");
+
+        let mut stream = StreamingExtractor::new(config.clone());
+        stream.push_tokens(&tokens[..half]);
+        // The second sentence is still pending until it is ended.
+        let before = stream.snapshot();
+        let first_sentence = tokens.iter().take_while(|t| t.sentence_idx == 0).count();
+        assert_eq!(
+            before,
+            extract_keyphrases_with_info(&tokens[..first_sentence], &config)
+        );
+
+        stream.end_sentence();
+        let first = stream.snapshot();
+        assert_close(
+            &first,
+            &extract_keyphrases_with_info(&tokens[..half], &config),
+        );
+
+        stream.push_tokens(&tokens[half..]);
+        assert_close(
+            &stream.finish(),
+            &extract_keyphrases_with_info(&tokens, &config),
+        );
+    }
+}
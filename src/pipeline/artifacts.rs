@@ -6,12 +6,33 @@
 //!
 //! **Owned vs Borrowed**: Hot-path stage interfaces accept `*Ref<'a>` borrows;
 //! the pipeline retains ownership of the corresponding owned artifacts.
+//!
+//! **Persistence**: owned artifacts implement serde `Serialize`/`Deserialize`
+//! so they can be cached, inspected as JSON, or used as golden files. With
+//! the `bincode` feature, [`to_bincode`] / [`from_bincode`] provide a compact
+//! binary encoding.
 
-use crate::types::{PosTag, StringPool, Token};
+use crate::types::{NodeKey, PosTag, Section, SharedStringPool, StringPool, Token, TokenRef};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 use super::errors::PipelineRuntimeError;
 
+/// Heap bytes reserved by a vector's buffer (not counting nested heap data).
+#[inline]
+fn vec_bytes<T>(v: &Vec<T>) -> usize {
+    v.capacity() * std::mem::size_of::<T>()
+}
+
+/// Whether the text between two sentences contains a blank line.
+fn is_paragraph_break(gap: &str) -> bool {
+    let lines: Vec<&str> = gap.split('\n').collect();
+    lines.len() > 2
+        && lines[1..lines.len() - 1]
+            .iter()
+            .any(|l| l.trim().is_empty())
+}
+
 // ============================================================================
 // TokenStream — interned, compact token representation
 // ============================================================================
@@ -21,7 +42,7 @@ use super::errors::PipelineRuntimeError;
 /// All string data lives in the parent [`TokenStream`]'s [`StringPool`]; this
 /// struct stores only `u32` handles.  At 28 bytes per entry (plus padding to
 /// 32) it fits two entries per 64-byte cache line.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TokenEntry {
     /// Interned ID for the surface form in the parent pool.
     pub text_id: u32,
@@ -56,6 +77,47 @@ impl TokenEntry {
         }
     }
 
+    /// Build the graph-node key under `key`, mirroring [`NodeKey::key`].
+    ///
+    /// [`NodeKey::Custom`] functions see the token without its section (see
+    /// [`NodeKeyFn`](crate::types::NodeKeyFn)).
+    pub fn node_key(&self, pool: &StringPool, key: &NodeKey) -> String {
+        match key {
+            NodeKey::Lemma | NodeKey::LemmaPos => {
+                self.graph_key(pool, matches!(key, NodeKey::LemmaPos))
+            }
+            NodeKey::Surface => pool.get(self.text_id).unwrap_or("").to_owned(),
+            NodeKey::LowercaseSurface => pool.get(self.text_id).unwrap_or("").to_lowercase(),
+            NodeKey::Custom(f) => f(&self.to_token(pool)).into_owned(),
+        }
+    }
+
+    /// Identity of this token's graph node under `key`, without building
+    /// the key string for the interned strategies.
+    pub(crate) fn node_identity(&self, pool: &StringPool, key: &NodeKey) -> NodeIdentity {
+        match key {
+            NodeKey::Lemma => NodeIdentity::Interned(self.lemma_id, None),
+            NodeKey::LemmaPos => NodeIdentity::Interned(self.lemma_id, Some(self.pos)),
+            NodeKey::Surface => NodeIdentity::Interned(self.text_id, None),
+            _ => NodeIdentity::Key(self.node_key(pool, key)),
+        }
+    }
+
+    /// Materialize as a legacy [`Token`] (without a section).
+    pub fn to_token(&self, pool: &StringPool) -> Token {
+        let mut t = Token::new(
+            pool.get(self.text_id).unwrap_or(""),
+            pool.get(self.lemma_id).unwrap_or(""),
+            self.pos,
+            self.start as usize,
+            self.end as usize,
+            self.sentence_idx as usize,
+            self.token_idx as usize,
+        );
+        t.is_stopword = self.is_stopword;
+        t
+    }
+
     /// Whether this token is a content-word candidate for the graph.
     #[inline]
     pub fn is_graph_candidate(&self) -> bool {
@@ -63,18 +125,31 @@ impl TokenEntry {
     }
 }
 
+/// Hashable identity of a graph node under a [`NodeKey`].
+///
+/// Lemma and surface keys compare by interned ID (plus POS under
+/// [`NodeKey::LemmaPos`]); only the other strategies materialize the key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum NodeIdentity {
+    Interned(u32, Option<PosTag>),
+    Key(String),
+}
+
 /// Canonical token stream produced by the preprocessor stage.
 ///
 /// Stores tokens as interned [`TokenEntry`] values backed by a shared
 /// [`StringPool`].  Sentence boundaries use a CSR-style offset array:
 /// `sentence_offsets[i]..sentence_offsets[i+1]` gives the token index range
-/// for sentence `i`.
+/// for sentence `i`. Paragraphs are runs of sentences; a stream without
+/// paragraph information is one paragraph. Document sections (title,
+/// abstract, ...) are a per-token side table, empty when no token is
+/// labeled.
 ///
 /// # Construction
 ///
 /// Use [`TokenStream::from_tokens`] to convert from the legacy `&[Token]`
 /// representation (used by the existing tokenizer and JSON input path).
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenStream {
     /// Interned string storage for text and lemma values.
     pool: StringPool,
@@ -84,6 +159,13 @@ pub struct TokenStream {
     ///
     /// Length = `num_sentences + 1`.  The last element equals `tokens.len()`.
     sentence_offsets: Vec<u32>,
+    /// Paragraph index of each sentence; empty when the whole stream is one
+    /// paragraph.
+    #[serde(default)]
+    sentence_paragraphs: Vec<u32>,
+    /// Section label of each token; empty when no token is labeled.
+    #[serde(default)]
+    token_sections: Vec<Option<Section>>,
 }
 
 impl TokenStream {
@@ -93,6 +175,8 @@ impl TokenStream {
             pool,
             tokens,
             sentence_offsets: Vec::new(),
+            sentence_paragraphs: Vec::new(),
+            token_sections: Vec::new(),
         }
     }
 
@@ -103,6 +187,126 @@ impl TokenStream {
     /// all text and lemma strings into a single pool and computes sentence
     /// boundary offsets in a single pass.
     pub fn from_tokens(tokens: &[Token]) -> Self {
+        Self::build(tokens, |t| TokenRef::from(t), |pool, s| pool.intern(s))
+    }
+
+    /// Build a `TokenStream` from borrowed tokens, interning straight from
+    /// the source text.
+    pub fn from_token_refs(tokens: &[TokenRef<'_>]) -> Self {
+        Self::build(tokens, TokenRef::reborrow, |pool, s| pool.intern(s))
+    }
+
+    /// Tokenize `text` with the built-in [`Tokenizer`] per `cfg` and build
+    /// the stream without intermediate owned tokens (unless
+    /// `cfg.variant_linking` needs them).
+    ///
+    /// Produces the same stream as
+    /// `TokenStream::from_tokens(&Tokenizer::new().tokenize_with_config(text, cfg))`,
+    /// with paragraphs marked by
+    /// [`with_paragraphs_from_text`](Self::with_paragraphs_from_text).
+    ///
+    /// [`Tokenizer`]: crate::nlp::tokenizer::Tokenizer
+    pub fn from_text(text: &str, cfg: &crate::types::TextRankConfig) -> Self {
+        use crate::nlp::lemma::Lemmatizer;
+        use crate::nlp::stopwords::StopwordFilter;
+        use crate::nlp::token_filter::JunkTokenFilter;
+        use crate::nlp::tokenizer::Tokenizer;
+
+        let tokenizer = Tokenizer::new();
+        let stream = if !cfg.variant_linking.is_identity() {
+            Self::from_tokens(&tokenizer.tokenize_with_config(text, cfg))
+        } else {
+            let mut tokens = tokenizer.tokenize_borrowed_with(
+                text,
+                &Lemmatizer::for_config(cfg),
+                &StopwordFilter::for_config(cfg),
+            );
+            JunkTokenFilter::for_config(cfg).apply_refs(&mut tokens);
+            Self::from_token_refs(&tokens)
+        };
+        stream.with_paragraphs_from_text(text)
+    }
+
+    /// Mark paragraphs as starting at the given sentence indices.
+    ///
+    /// Sentence 0 always starts a paragraph; out-of-range indices are
+    /// ignored.
+    pub fn with_paragraph_starts(mut self, starts: impl IntoIterator<Item = usize>) -> Self {
+        let num_sentences = self.num_sentences();
+        let mut is_start = vec![false; num_sentences];
+        for start in starts {
+            if let Some(flag) = is_start.get_mut(start) {
+                *flag = true;
+            }
+        }
+        let mut paragraph = 0;
+        self.sentence_paragraphs = is_start
+            .iter()
+            .enumerate()
+            .map(|(i, &start)| {
+                if start && i > 0 {
+                    paragraph += 1;
+                }
+                paragraph
+            })
+            .collect();
+        self
+    }
+
+    /// Label tokens with document sections, one entry per token in order.
+    ///
+    /// Missing trailing entries are unlabeled and extra entries are
+    /// ignored; labels already on the stream are replaced.
+    pub fn with_sections(mut self, sections: impl IntoIterator<Item = Option<Section>>) -> Self {
+        let mut labels: Vec<Option<Section>> = sections.into_iter().take(self.len()).collect();
+        if labels.iter().all(Option::is_none) {
+            labels.clear();
+        } else {
+            labels.resize(self.len(), None);
+        }
+        self.token_sections = labels;
+        self
+    }
+
+    /// Mark paragraphs from the source `text` the token offsets point into:
+    /// a sentence starts a new paragraph when a blank line separates it
+    /// from the previous one.
+    pub fn with_paragraphs_from_text(self, text: &str) -> Self {
+        let starts: Vec<usize> = (1..self.num_sentences())
+            .filter(|&i| {
+                let (Some(prev), Some(next)) = (
+                    self.sentence_token_range(i - 1),
+                    self.sentence_token_range(i),
+                ) else {
+                    return false;
+                };
+                if prev.is_empty() || next.is_empty() {
+                    return false;
+                }
+                let gap_start = self.tokens[prev.end - 1].end as usize;
+                let gap_end = self.tokens[next.start].start as usize;
+                text.get(gap_start..gap_end).is_some_and(is_paragraph_break)
+            })
+            .collect();
+        self.with_paragraph_starts(starts)
+    }
+
+    /// Like [`from_tokens`](Self::from_tokens), but taking string storage
+    /// from `shared`, so streams built for every document in a batch share
+    /// one copy of each distinct text and lemma string.
+    pub fn from_tokens_shared(tokens: &[Token], shared: &SharedStringPool) -> Self {
+        Self::build(
+            tokens,
+            |t| TokenRef::from(t),
+            |pool, s| pool.intern_shared(s, shared),
+        )
+    }
+
+    fn build<T>(
+        tokens: &[T],
+        view: impl Fn(&T) -> TokenRef<'_>,
+        mut intern: impl FnMut(&mut StringPool, &str) -> u32,
+    ) -> Self {
         let mut pool = StringPool::with_capacity(tokens.len());
         let mut entries = Vec::with_capacity(tokens.len());
 
@@ -111,26 +315,29 @@ impl TokenStream {
                 pool,
                 tokens: entries,
                 sentence_offsets: Vec::new(),
+                sentence_paragraphs: Vec::new(),
+                token_sections: Vec::new(),
             };
         }
 
         // Tokens are in document order, so the last token has the highest
         // sentence index — O(1) instead of a full scan.
-        let num_sentences = tokens.last().map_or(0, |t| t.sentence_idx + 1);
+        let num_sentences = tokens.last().map_or(0, |t| view(t).sentence_idx + 1);
         let mut sentence_offsets = Vec::with_capacity(num_sentences + 1);
 
         let mut current_sentence: usize = 0;
         sentence_offsets.push(0); // sentence 0 starts at token 0
+        let mut token_sections = Vec::new();
 
-        for (i, t) in tokens.iter().enumerate() {
+        for (i, t) in tokens.iter().map(view).enumerate() {
             // Emit boundary markers for any new sentences.
             while t.sentence_idx > current_sentence {
                 sentence_offsets.push(i as u32);
                 current_sentence += 1;
             }
 
-            let text_id = pool.intern(&t.text);
-            let lemma_id = pool.intern(&t.lemma);
+            let text_id = intern(&mut pool, t.text);
+            let lemma_id = intern(&mut pool, &t.lemma);
 
             entries.push(TokenEntry {
                 text_id,
@@ -142,6 +349,12 @@ impl TokenStream {
                 token_idx: t.token_idx as u32,
                 is_stopword: t.is_stopword,
             });
+
+            // Sections stay empty until the first labeled token.
+            if t.section.is_some() || !token_sections.is_empty() {
+                token_sections.resize(i, None);
+                token_sections.push(t.section);
+            }
         }
 
         // Sentinel: marks the end of the last sentence.
@@ -151,6 +364,8 @@ impl TokenStream {
             pool,
             tokens: entries,
             sentence_offsets,
+            sentence_paragraphs: Vec::new(),
+            token_sections,
         }
     }
 
@@ -161,6 +376,8 @@ impl TokenStream {
             pool: &self.pool,
             tokens: &self.tokens,
             sentence_offsets: &self.sentence_offsets,
+            sentence_paragraphs: &self.sentence_paragraphs,
+            token_sections: &self.token_sections,
         }
     }
 
@@ -176,6 +393,15 @@ impl TokenStream {
         self.tokens.is_empty()
     }
 
+    /// Estimated heap size in bytes, including the string pool.
+    pub fn heap_bytes(&self) -> usize {
+        self.pool.heap_bytes()
+            + vec_bytes(&self.tokens)
+            + vec_bytes(&self.sentence_offsets)
+            + vec_bytes(&self.sentence_paragraphs)
+            + vec_bytes(&self.token_sections)
+    }
+
     /// Number of sentences.
     #[inline]
     pub fn num_sentences(&self) -> usize {
@@ -201,6 +427,18 @@ impl TokenStream {
         &self.sentence_offsets
     }
 
+    /// Paragraph index of each sentence (empty: a single paragraph).
+    #[inline]
+    pub fn sentence_paragraphs(&self) -> &[u32] {
+        &self.sentence_paragraphs
+    }
+
+    /// Section label of each token (empty: no token is labeled).
+    #[inline]
+    pub fn token_sections(&self) -> &[Option<Section>] {
+        &self.token_sections
+    }
+
     /// Get the token index range for sentence `idx`.
     ///
     /// Returns `None` if `idx >= num_sentences()`.
@@ -257,6 +495,8 @@ pub struct TokenStreamRef<'a> {
     pool: &'a StringPool,
     tokens: &'a [TokenEntry],
     sentence_offsets: &'a [u32],
+    sentence_paragraphs: &'a [u32],
+    token_sections: &'a [Option<Section>],
 }
 
 impl<'a> TokenStreamRef<'a> {
@@ -296,6 +536,29 @@ impl<'a> TokenStreamRef<'a> {
         self.sentence_offsets
     }
 
+    /// Whether paragraphs were marked on the stream (streams built from
+    /// [`Token`](crate::types::Token)s carry none).
+    #[inline]
+    pub fn has_paragraphs(&self) -> bool {
+        !self.sentence_paragraphs.is_empty()
+    }
+
+    /// Paragraph index of sentence `sentence_idx` (0 without paragraph
+    /// information).
+    #[inline]
+    pub fn paragraph_of(&self, sentence_idx: usize) -> u32 {
+        self.sentence_paragraphs
+            .get(sentence_idx)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Section label of token `token_index`, if any.
+    #[inline]
+    pub fn section_of(&self, token_index: usize) -> Option<Section> {
+        self.token_sections.get(token_index).copied().flatten()
+    }
+
     /// Get the token index range for sentence `idx`.
     #[inline]
     pub fn sentence_token_range(&self, idx: usize) -> Option<std::ops::Range<usize>> {
@@ -329,19 +592,10 @@ impl<'a> TokenStreamRef<'a> {
     pub fn to_legacy_tokens(&self) -> Vec<Token> {
         self.tokens
             .iter()
-            .map(|e| {
-                let text = self.pool.get(e.text_id).unwrap_or("").to_string();
-                let lemma = self.pool.get(e.lemma_id).unwrap_or("").to_string();
-                let mut t = Token::new(
-                    text,
-                    lemma,
-                    e.pos,
-                    e.start as usize,
-                    e.end as usize,
-                    e.sentence_idx as usize,
-                    e.token_idx as usize,
-                );
-                t.is_stopword = e.is_stopword;
+            .enumerate()
+            .map(|(i, e)| {
+                let mut t = e.to_token(self.pool);
+                t.section = self.section_of(i);
                 t
             })
             .collect()
@@ -356,7 +610,7 @@ impl<'a> TokenStreamRef<'a> {
 /// / SingleRank / TopicalPageRank families).
 ///
 /// One entry per unique graph key (`lemma` or `lemma|POS`).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WordCandidate {
     /// Interned lemma ID in the parent [`TokenStream`]'s pool.
     pub lemma_id: u32,
@@ -377,13 +631,52 @@ impl WordCandidate {
             lemma.to_owned()
         }
     }
+
+    /// Build the graph-node key under `key`, mirroring
+    /// [`TokenEntry::node_key`].
+    ///
+    /// Strategies other than the lemma ones key the first occurrence in
+    /// `tokens`.
+    pub fn node_key(&self, tokens: TokenStreamRef<'_>, key: &NodeKey) -> String {
+        match key {
+            NodeKey::Lemma | NodeKey::LemmaPos => {
+                self.graph_key(tokens.pool(), matches!(key, NodeKey::LemmaPos))
+            }
+            _ => match self.first_entry(tokens.tokens()) {
+                Some(entry) => entry.node_key(tokens.pool(), key),
+                None => String::new(),
+            },
+        }
+    }
+
+    /// Identity of this candidate's graph node, mirroring
+    /// [`TokenEntry::node_identity`].
+    pub(crate) fn node_identity(&self, tokens: TokenStreamRef<'_>, key: &NodeKey) -> NodeIdentity {
+        match key {
+            NodeKey::Lemma => NodeIdentity::Interned(self.lemma_id, None),
+            NodeKey::LemmaPos => NodeIdentity::Interned(self.lemma_id, Some(self.pos)),
+            _ => match self.first_entry(tokens.tokens()) {
+                Some(entry) => entry.node_identity(tokens.pool(), key),
+                None => NodeIdentity::Key(String::new()),
+            },
+        }
+    }
+
+    /// The token at `first_position` (token indices increase through the
+    /// stream).
+    fn first_entry<'a>(&self, tokens: &'a [TokenEntry]) -> Option<&'a TokenEntry> {
+        let i = tokens
+            .binary_search_by_key(&self.first_position, |e| e.token_idx)
+            .ok()?;
+        Some(&tokens[i])
+    }
 }
 
 /// A single phrase-level candidate (TopicRank / MultipartiteRank families).
 ///
 /// Represents a noun chunk with its token span, surface forms, and the term
 /// set used for Jaccard-based clustering.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PhraseCandidate {
     /// Start token index (inclusive) in the parent token stream.
     pub start_token: u32,
@@ -400,7 +693,8 @@ pub struct PhraseCandidate {
     /// Used for Jaccard similarity during clustering.  Ordered to match the
     /// token sequence; equality/hashing uses the set of unique IDs.
     pub lemma_ids: Vec<u32>,
-    /// Interned IDs for non-stopword surface forms (the "term set").
+    /// Interned IDs for non-stopword lemmas (the "term set"), so that
+    /// inflected variants such as "model" and "models" overlap.
     ///
     /// This mirrors the `FxHashSet<String>` in the legacy `PhraseCandidate`
     /// but uses interned IDs for cheaper set operations.
@@ -419,7 +713,7 @@ impl PhraseCandidate {
 ///
 /// Each sentence in the document becomes a candidate node for sentence-level
 /// TextRank (extractive summarization).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SentenceCandidate {
     /// 0-based sentence index in the parent token stream.
     pub sentence_idx: u32,
@@ -447,7 +741,7 @@ impl SentenceCandidate {
 ///
 /// Downstream stages (GraphBuilder, TeleportBuilder, etc.) can match on this
 /// to select the appropriate processing strategy.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CandidateKind {
     /// Word-level candidates (one per unique graph key).
     Words(Vec<WordCandidate>),
@@ -467,9 +761,13 @@ pub enum CandidateKind {
 ///
 /// Use [`CandidateSet::from_word_tokens`] to build from a token stream
 /// (word-level), or [`CandidateSet::from_phrase_chunks`] for phrase-level.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CandidateSet {
     kind: CandidateKind,
+    /// Phrase spans chosen by the selector (e.g. grammar matches).  When
+    /// `None`, the phrase builder runs its own noun chunker.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    phrase_spans: Option<Vec<crate::types::ChunkSpan>>,
 }
 
 impl CandidateSet {
@@ -477,6 +775,7 @@ impl CandidateSet {
     pub fn empty() -> Self {
         Self {
             kind: CandidateKind::Words(Vec::new()),
+            phrase_spans: None,
         }
     }
 
@@ -485,7 +784,23 @@ impl CandidateSet {
     /// This is the low-level constructor used by [`CandidateSelector`]
     /// implementations that build candidates from [`TokenStreamRef`].
     pub fn from_kind(kind: CandidateKind) -> Self {
-        Self { kind }
+        Self {
+            kind,
+            phrase_spans: None,
+        }
+    }
+
+    /// Attach the phrase spans the phrase builder should score instead of
+    /// running its own noun chunker.
+    pub fn with_phrase_spans(mut self, spans: Vec<crate::types::ChunkSpan>) -> Self {
+        self.phrase_spans = Some(spans);
+        self
+    }
+
+    /// Phrase spans chosen by the selector, if any.
+    #[inline]
+    pub fn phrase_spans(&self) -> Option<&[crate::types::ChunkSpan]> {
+        self.phrase_spans.as_deref()
     }
 
     /// Build a word-level candidate set from a token stream.
@@ -493,15 +808,18 @@ impl CandidateSet {
     /// Filters tokens by `include_pos` (or default content-word check when
     /// empty) and stopword flag, deduplicates by graph key, and records the
     /// first occurrence position for each unique key.
+    ///
+    /// `node_key` is a [`NodeKey`], or `use_pos_in_nodes` as a `bool`.
     pub fn from_word_tokens(
         stream: &TokenStream,
         include_pos: &[PosTag],
-        use_pos_in_nodes: bool,
+        node_key: impl Into<NodeKey>,
     ) -> Self {
         use rustc_hash::FxHashMap;
 
-        // Key: (lemma_id, optional POS discriminant) → index into `words`.
-        let mut seen: FxHashMap<(u32, Option<PosTag>), usize> = FxHashMap::default();
+        let node_key = node_key.into();
+        // Node identity → index into `words`.
+        let mut seen: FxHashMap<NodeIdentity, usize> = FxHashMap::default();
         let mut words = Vec::new();
 
         for entry in stream.tokens() {
@@ -517,12 +835,7 @@ impl CandidateSet {
                 continue;
             }
 
-            let key = if use_pos_in_nodes {
-                (entry.lemma_id, Some(entry.pos))
-            } else {
-                (entry.lemma_id, None)
-            };
-
+            let key = entry.node_identity(&stream.pool, &node_key);
             if let std::collections::hash_map::Entry::Vacant(e) = seen.entry(key) {
                 e.insert(words.len());
                 words.push(WordCandidate {
@@ -535,6 +848,7 @@ impl CandidateSet {
 
         Self {
             kind: CandidateKind::Words(words),
+            phrase_spans: None,
         }
     }
 
@@ -555,9 +869,9 @@ impl CandidateSet {
             for &entry in &stream.tokens()[start..end] {
                 lemma_ids.push(entry.lemma_id);
                 if !entry.is_stopword {
-                    // Use text_id for term set (matches legacy `t.text.clone()`)
-                    if !term_ids.contains(&entry.text_id) {
-                        term_ids.push(entry.text_id);
+                    // Use lemma_id for term set (matches legacy `t.lemma.clone()`)
+                    if !term_ids.contains(&entry.lemma_id) {
+                        term_ids.push(entry.lemma_id);
                     }
                 }
             }
@@ -575,6 +889,7 @@ impl CandidateSet {
 
         Self {
             kind: CandidateKind::Phrases(phrases),
+            phrase_spans: None,
         }
     }
 
@@ -584,6 +899,12 @@ impl CandidateSet {
         &self.kind
     }
 
+    /// Mutable access to the candidates, e.g. for filtering in a custom stage.
+    #[inline]
+    pub fn kind_mut(&mut self) -> &mut CandidateKind {
+        &mut self.kind
+    }
+
     /// Number of candidates.
     #[inline]
     pub fn len(&self) -> usize {
@@ -600,10 +921,30 @@ impl CandidateSet {
         self.len() == 0
     }
 
+    /// Estimated heap size in bytes.
+    pub fn heap_bytes(&self) -> usize {
+        self.phrase_spans.as_ref().map_or(0, vec_bytes)
+            + match &self.kind {
+                CandidateKind::Words(w) => vec_bytes(w),
+                CandidateKind::Phrases(p) => {
+                    vec_bytes(p)
+                        + p.iter()
+                            .map(|c| vec_bytes(&c.lemma_ids) + vec_bytes(&c.term_ids))
+                            .sum::<usize>()
+                }
+                CandidateKind::Sentences(s) => {
+                    vec_bytes(s) + s.iter().map(|c| vec_bytes(&c.lemma_ids)).sum::<usize>()
+                }
+            }
+    }
+
     /// Borrow as a [`CandidateSetRef`].
     #[inline]
     pub fn as_ref(&self) -> CandidateSetRef<'_> {
-        CandidateSetRef { kind: &self.kind }
+        CandidateSetRef {
+            kind: &self.kind,
+            phrase_spans: self.phrase_spans.as_deref(),
+        }
     }
 
     /// Access word candidates (panics on wrong variant).
@@ -672,6 +1013,7 @@ impl CandidateSet {
 
         Self {
             kind: CandidateKind::Sentences(sentences),
+            phrase_spans: None,
         }
     }
 }
@@ -682,6 +1024,7 @@ impl CandidateSet {
 #[derive(Debug, Clone, Copy)]
 pub struct CandidateSetRef<'a> {
     kind: &'a CandidateKind,
+    phrase_spans: Option<&'a [crate::types::ChunkSpan]>,
 }
 
 impl<'a> CandidateSetRef<'a> {
@@ -691,6 +1034,12 @@ impl<'a> CandidateSetRef<'a> {
         self.kind
     }
 
+    /// Phrase spans chosen by the selector, if any.
+    #[inline]
+    pub fn phrase_spans(&self) -> Option<&'a [crate::types::ChunkSpan]> {
+        self.phrase_spans
+    }
+
     /// Number of candidates.
     #[inline]
     pub fn len(&self) -> usize {
@@ -762,7 +1111,7 @@ impl<'a> CandidateSetRef<'a> {
 /// An empty `ClusterAssignments` (no candidates) has `num_clusters == 0`
 /// and an empty assignment vector.  This is the result of the
 /// [`NoopClusterer`](crate::pipeline::traits::NoopClusterer).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClusterAssignments {
     /// `assignments[i]` = cluster ID for candidate `i`.
     assignments: Vec<u32>,
@@ -853,7 +1202,7 @@ impl ClusterAssignments {
 ///
 /// Use [`Graph::from_builder`] to convert from an existing [`GraphBuilder`],
 /// or [`Graph::from_csr`] if you already have a [`CsrGraph`].
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Graph {
     /// The underlying CSR graph.
     csr: crate::graph::csr::CsrGraph,
@@ -995,6 +1344,15 @@ impl Graph {
         self.csr.is_empty()
     }
 
+    /// Estimated heap size in bytes, including cluster assignments.
+    pub fn heap_bytes(&self) -> usize {
+        self.csr.heap_bytes()
+            + self
+                .cluster_assignments
+                .as_ref()
+                .map_or(0, |c| vec_bytes(&c.assignments))
+    }
+
     /// Look up a node by its graph key (lemma or `lemma|POS`).
     #[inline]
     pub fn get_node_by_lemma(&self, lemma: &str) -> Option<u32> {
@@ -1025,7 +1383,10 @@ impl Graph {
 // ============================================================================
 
 /// The kind of teleport (personalization) strategy that produced this vector.
+///
+/// New strategies may add variants; match with a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TeleportType {
     /// Uniform distribution — equivalent to standard (non-personalized) PageRank.
     Uniform,
@@ -1035,6 +1396,11 @@ pub enum TeleportType {
     Focus,
     /// Topic-weighted — per-lemma weights from an external topic model (TopicalPageRank).
     Topic,
+    /// Caller-supplied — weights from a user function or node-keyed map.
+    Custom,
+    /// Section-weighted — words in boosted document sections (title, abstract)
+    /// get higher teleport probability.
+    Section,
 }
 
 impl std::fmt::Display for TeleportType {
@@ -1044,6 +1410,8 @@ impl std::fmt::Display for TeleportType {
             Self::Position => write!(f, "position"),
             Self::Focus => write!(f, "focus"),
             Self::Topic => write!(f, "topic"),
+            Self::Custom => write!(f, "custom"),
+            Self::Section => write!(f, "section"),
         }
     }
 }
@@ -1146,6 +1514,11 @@ impl TeleportVector {
         self.values.is_empty()
     }
 
+    /// Estimated heap size in bytes.
+    pub fn heap_bytes(&self) -> usize {
+        vec_bytes(&self.values) + self.debug_source.as_ref().map_or(0, String::capacity)
+    }
+
     /// Borrow the underlying slice.
     #[inline]
     pub fn as_slice(&self) -> &[f64] {
@@ -1263,7 +1636,7 @@ impl std::ops::IndexMut<usize> for TeleportVector {
 ///
 /// These are only populated when debug/expose mode is enabled, so the hot
 /// path incurs zero allocation overhead.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RankDiagnostics {
     /// Per-iteration residual (L1 norm of score delta).
     ///
@@ -1282,7 +1655,13 @@ pub struct RankDiagnostics {
 ///
 /// Use [`RankOutput::from_pagerank_result`] to bridge from the existing
 /// `PageRankResult` type.
-#[derive(Debug, Clone)]
+///
+/// # Lookup
+///
+/// Scores are positional; [`RankOutput::nodes`] pairs them with the node
+/// keys of the [`Graph`] they were computed on, for word-level lookups
+/// such as `rank.nodes(&graph).score_of("learning")`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RankOutput {
     /// Per-node scores indexed by CSR node ID.
     scores: Vec<f64>,
@@ -1331,6 +1710,15 @@ impl RankOutput {
         &self.scores
     }
 
+    /// Estimated heap size in bytes, including diagnostics.
+    pub fn heap_bytes(&self) -> usize {
+        vec_bytes(&self.scores)
+            + self
+                .diagnostics
+                .as_ref()
+                .map_or(0, |d| vec_bytes(&d.residuals))
+    }
+
     /// Score for a specific node.
     #[inline]
     pub fn score(&self, node_id: u32) -> f64 {
@@ -1367,6 +1755,36 @@ impl RankOutput {
         self.scores.len()
     }
 
+    /// Iterate over `(node_id, score)` pairs in node-ID order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, f64)> + '_ {
+        self.scores
+            .iter()
+            .enumerate()
+            .map(|(id, &score)| (id as u32, score))
+    }
+
+    /// The `k` highest-scoring nodes as `(node_id, score)`, best first
+    /// (all nodes when `k == 0`).  Ties go to the lower node ID.
+    pub fn top_k_nodes(&self, k: usize) -> Vec<(u32, f64)> {
+        let mut ranked: Vec<(u32, f64)> = self.iter().collect();
+        ranked.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        if k > 0 {
+            ranked.truncate(k);
+        }
+        ranked
+    }
+
+    /// View these scores keyed by the node keys of `graph`, the graph they
+    /// were computed on.
+    #[inline]
+    pub fn nodes<'a>(&'a self, graph: &'a Graph) -> RankedNodes<'a> {
+        RankedNodes { rank: self, graph }
+    }
+
     /// Convert to the legacy [`PageRankResult`] type.
     ///
     /// This is the bridge for pipeline stages that delegate to existing code
@@ -1393,6 +1811,73 @@ impl RankOutput {
     }
 }
 
+/// [`RankOutput`] scores keyed by graph node (see [`RankOutput::nodes`]).
+///
+/// Node keys are lemmas, or `lemma|POS` when `use_pos_in_nodes` is set.
+/// Nodes beyond the end of the score vector score `0.0`.
+#[derive(Debug, Clone, Copy)]
+pub struct RankedNodes<'a> {
+    rank: &'a RankOutput,
+    graph: &'a Graph,
+}
+
+impl<'a> RankedNodes<'a> {
+    /// Score of the node keyed `key`.
+    ///
+    /// A bare lemma also matches POS-keyed nodes (`lemma|NOUN`, ...); with
+    /// several such nodes, the highest score wins.  `None` when no node
+    /// matches.
+    pub fn score_of(&self, key: &str) -> Option<f64> {
+        if let Some(id) = self.graph.get_node_by_lemma(key) {
+            return Some(self.rank.score(id));
+        }
+        if key.contains('|') {
+            return None;
+        }
+        self.iter()
+            .filter(|(node_key, _)| {
+                node_key
+                    .split_once('|')
+                    .is_some_and(|(lemma, _)| lemma == key)
+            })
+            .map(|(_, score)| score)
+            .reduce(f64::max)
+    }
+
+    /// Iterate over `(node_key, score)` pairs in node-ID order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, f64)> + 'a {
+        let (rank, graph) = (self.rank, self.graph);
+        (0..graph.num_nodes() as u32).map(move |id| (graph.lemma(id), rank.score(id)))
+    }
+
+    /// The `k` highest-scoring nodes as `(node_key, score)`, best first
+    /// (all nodes when `k == 0`).  Ties are broken by key, ascending.
+    pub fn top_k_nodes(&self, k: usize) -> Vec<(&'a str, f64)> {
+        let mut ranked: Vec<(&'a str, f64)> = self.iter().collect();
+        ranked.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(b.0))
+        });
+        if k > 0 {
+            ranked.truncate(k);
+        }
+        ranked
+    }
+
+    /// Number of nodes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.graph.num_nodes()
+    }
+
+    /// Whether the graph has no nodes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+}
+
 // ============================================================================
 // PhraseSet — pre-format phrase collection
 // ============================================================================
@@ -1401,7 +1886,7 @@ impl RankOutput {
 ///
 /// Uses interned IDs where possible; surface forms are optional and lazily
 /// materialized only when needed for formatting.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PhraseEntry {
     /// Interned lemma IDs for the tokens in this phrase (ordered).
     pub lemma_ids: Vec<u32>,
@@ -1415,6 +1900,9 @@ pub struct PhraseEntry {
     pub lemma_text: Option<String>,
     /// Optional token-span pairs for each occurrence (debug only).
     pub spans: Option<Vec<(u32, u32)>>,
+    /// Optional byte spans in the source text for each occurrence.
+    #[serde(default)]
+    pub text_spans: Option<Vec<(u32, u32)>>,
 }
 
 /// Pre-format phrase collection: scored phrases with interned lemma IDs.
@@ -1426,7 +1914,7 @@ pub struct PhraseEntry {
 /// # Construction
 ///
 /// Use [`PhraseSet::from_phrases`] to bridge from the existing `Vec<Phrase>`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PhraseSet {
     entries: Vec<PhraseEntry>,
 }
@@ -1460,6 +1948,11 @@ impl PhraseSet {
                             .collect(),
                     )
                 };
+                let text_spans = if p.spans.is_empty() {
+                    None
+                } else {
+                    Some(p.spans.iter().map(|&(s, e)| (s as u32, e as u32)).collect())
+                };
                 PhraseEntry {
                     lemma_ids,
                     score: p.score,
@@ -1467,6 +1960,7 @@ impl PhraseSet {
                     surface: Some(p.text.clone()),
                     lemma_text: Some(p.lemma.clone()),
                     spans,
+                    text_spans,
                 }
             })
             .collect();
@@ -1496,6 +1990,21 @@ impl PhraseSet {
         self.entries.is_empty()
     }
 
+    /// Estimated heap size in bytes, including materialized strings.
+    pub fn heap_bytes(&self) -> usize {
+        vec_bytes(&self.entries)
+            + self
+                .entries
+                .iter()
+                .map(|e| {
+                    vec_bytes(&e.lemma_ids)
+                        + e.surface.as_ref().map_or(0, String::capacity)
+                        + e.lemma_text.as_ref().map_or(0, String::capacity)
+                        + e.spans.as_ref().map_or(0, vec_bytes)
+                })
+                .sum::<usize>()
+    }
+
     /// Borrow as a [`PhraseSetRef`].
     #[inline]
     pub fn as_ref(&self) -> PhraseSetRef<'_> {
@@ -1677,6 +2186,10 @@ impl DebugLevel {
 /// | `node_scores`         |         |     ✓      |   ✓    |
 /// | `residuals`           |         |            |   ✓    |
 /// | `cluster_memberships` |         |            |   ✓    |
+/// | `node_details`        |         |            |   ✓    |
+///
+/// At `Full` level, [`DebugPayload::explain`] breaks a phrase's score down
+/// into its graph nodes.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DebugPayload {
     /// Top-K node scores (node lemma → score), sorted by score descending.
@@ -1703,6 +2216,46 @@ pub struct DebugPayload {
     /// Candidates dropped by overlap resolution, zero-score, or top-N (Full level only).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dropped_candidates: Option<Vec<DroppedCandidate>>,
+    /// Every graph node with its score, teleport weight and strongest
+    /// neighbors, indexed by node ID (Full level only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_details: Option<Vec<NodeDetail>>,
+}
+
+/// Maximum neighbors listed per node in [`DebugPayload::node_details`].
+pub const DEBUG_MAX_NEIGHBORS: usize = 5;
+
+/// A graph node's contribution to the ranking (Full debug level only).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeDetail {
+    /// Graph key (lemma, or `lemma|POS` with `use_pos_in_nodes`).
+    pub key: String,
+    /// PageRank score.
+    pub score: f64,
+    /// Teleport probability, for personalized variants.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub teleport: Option<f64>,
+    /// Up to [`DEBUG_MAX_NEIGHBORS`] neighbors as `(key, edge weight)`,
+    /// heaviest first.
+    pub neighbors: Vec<(String, f64)>,
+}
+
+/// Why a phrase scored what it did: the graph nodes behind its words.
+///
+/// Returned by [`DebugPayload::explain`].  How the node scores combine into
+/// `score` depends on `score_aggregation` (and any score normalization).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhraseExplanation {
+    /// The phrase lemma.
+    pub lemma: String,
+    /// The phrase score.
+    pub score: f64,
+    /// The phrase rank.
+    pub rank: usize,
+    /// Nodes for the phrase's words, in word order.  A word matches the
+    /// node keyed by it and, with POS-keyed nodes, every `word|POS` node.
+    /// Words without a node (e.g. bridging stopwords) are absent.
+    pub nodes: Vec<NodeDetail>,
 }
 
 /// Summary statistics for the co-occurrence graph.
@@ -1770,15 +2323,34 @@ pub struct DroppedCandidate {
 }
 
 /// Reason a scored candidate was dropped.
+///
+/// New filters may add variants; match with a wildcard arm.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum DropReason {
     /// Overlapped with a higher-scored phrase that was kept.
     OverlapWithHigherScored { kept_text: String, kept_score: f64 },
+    /// Overlapped with a longer phrase that was kept
+    /// ([`OverlapPolicy::KeepLongest`](crate::types::OverlapPolicy::KeepLongest)).
+    OverlapWithLonger { kept_text: String, kept_score: f64 },
     /// Score was zero (no graph nodes matched).
     ZeroScore,
     /// Fell below the top-N cutoff.
     BelowTopN { top_n: usize },
+    /// Fell below the `phrase_selection` cutoff, which kept `kept` phrases.
+    BelowCutoff { kept: usize },
+    /// Merged into a higher-scored phrase with a near-identical lemma.
+    NearDuplicateOf { kept_text: String, distance: f64 },
+    /// Embedding similarity to a higher-ranked kept phrase exceeded the
+    /// threshold (`embeddings` feature).
+    SemanticDuplicateOf { kept_text: String, similarity: f64 },
+    /// Word count outside `min_phrase_words..=max_phrase_words`.
+    WordCount { words: usize },
+    /// Occurred fewer than `min_phrase_frequency` times.
+    BelowMinFrequency { count: usize, min_frequency: usize },
+    /// Matched `phrase_blocklist`.
+    Blocklisted,
 }
 
 /// Enriched cluster member with text metadata.
@@ -1797,7 +2369,8 @@ pub struct ClusterMember {
 pub struct ExtractionDiagnostics {
     /// Events from the chunking stage (stopword splits, POS rejections, etc.).
     pub chunk_events: Vec<PhraseSplitEvent>,
-    /// Candidates dropped during overlap resolution, zero-score filter, or top-N.
+    /// Candidates dropped during overlap resolution, the zero-score,
+    /// word-count, blocklist and frequency filters, or top-N.
     pub dropped_candidates: Vec<DroppedCandidate>,
 }
 
@@ -1845,19 +2418,14 @@ impl DebugPayload {
 
         // --- TopNodes level: top-K node scores ---
         if level.includes_node_scores() {
-            let num_nodes = ranks.num_nodes();
-            let mut scored: Vec<(String, f64)> = (0..num_nodes as u32)
-                .map(|id| (graph.lemma(id).to_string(), ranks.score(id)))
+            // Score descending, then lemma ascending for stability.
+            let scored: Vec<(String, f64)> = ranks
+                .nodes(graph)
+                .top_k_nodes(0)
+                .into_iter()
+                .take(max_top_k)
+                .map(|(lemma, score)| (lemma.to_string(), score))
                 .collect();
-
-            // Sort by score descending, then by lemma ascending for stability.
-            scored.sort_by(|a, b| {
-                b.1.partial_cmp(&a.1)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-                    .then_with(|| a.0.cmp(&b.0))
-            });
-
-            scored.truncate(max_top_k);
             payload.node_scores = Some(scored);
         }
 
@@ -1877,6 +2445,8 @@ impl DebugPayload {
                 }
                 payload.cluster_memberships = Some(memberships);
             }
+
+            payload.node_details = Some(node_details(graph.csr(), |id| ranks.score(id)));
         }
 
         Some(payload)
@@ -1941,9 +2511,87 @@ impl DebugPayload {
 
         // --- Full level ---
         // Note: residuals unavailable via legacy PageRankResult path.
+        if level.includes_full() {
+            payload.node_details = Some(node_details(csr, |id| pr.score(id)));
+        }
 
         Some(payload)
     }
+
+    /// Record the teleport (personalization) vector in
+    /// [`node_details`](Self::node_details), indexed by node ID and
+    /// normalized to sum to 1.
+    ///
+    /// No-op below `Full` level.
+    pub fn set_teleport(&mut self, teleport: &[f64]) {
+        let total: f64 = teleport.iter().sum();
+        if total <= 0.0 {
+            return;
+        }
+        for (detail, &weight) in self.node_details.iter_mut().flatten().zip(teleport) {
+            detail.teleport = Some(weight / total);
+        }
+    }
+
+    /// Explain `phrase`'s score from its graph nodes.
+    ///
+    /// Returns `None` unless the payload was built at [`DebugLevel::Full`].
+    /// For topic-family variants, whose graph nodes are clusters or
+    /// candidates rather than words, the explanation has no nodes.
+    pub fn explain(&self, phrase: &crate::types::Phrase) -> Option<PhraseExplanation> {
+        let details = self.node_details.as_ref()?;
+        let mut by_lemma: rustc_hash::FxHashMap<&str, Vec<usize>> = Default::default();
+        for (id, detail) in details.iter().enumerate() {
+            let lemma = detail
+                .key
+                .split_once('|')
+                .map_or(detail.key.as_str(), |k| k.0);
+            by_lemma.entry(lemma).or_default().push(id);
+        }
+
+        let mut seen = rustc_hash::FxHashSet::default();
+        let nodes = phrase
+            .lemma
+            .split_whitespace()
+            .filter_map(|word| by_lemma.get(word))
+            .flatten()
+            .filter(|&&id| seen.insert(id))
+            .map(|&id| details[id].clone())
+            .collect();
+
+        Some(PhraseExplanation {
+            lemma: phrase.lemma.clone(),
+            score: phrase.score,
+            rank: phrase.rank,
+            nodes,
+        })
+    }
+}
+
+/// Per-node details for a [`DebugPayload`]: score plus the heaviest
+/// [`DEBUG_MAX_NEIGHBORS`] neighbors (ties by key).
+fn node_details(csr: &crate::graph::csr::CsrGraph, score: impl Fn(u32) -> f64) -> Vec<NodeDetail> {
+    (0..csr.num_nodes as u32)
+        .map(|id| {
+            let mut neighbors: Vec<(&str, f64)> =
+                csr.neighbors(id).map(|(n, w)| (csr.lemma(n), w)).collect();
+            neighbors.sort_by(|a, b| {
+                b.1.partial_cmp(&a.1)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| a.0.cmp(b.0))
+            });
+            neighbors.truncate(DEBUG_MAX_NEIGHBORS);
+            NodeDetail {
+                key: csr.lemma(id).to_string(),
+                score: score(id),
+                teleport: None,
+                neighbors: neighbors
+                    .into_iter()
+                    .map(|(key, w)| (key.to_string(), w))
+                    .collect(),
+            }
+        })
+        .collect()
 }
 
 impl FormattedResult {
@@ -1974,6 +2622,12 @@ impl FormattedResult {
         self.debug = Some(debug);
         self
     }
+
+    /// Explain `phrase`'s score; requires [`DebugLevel::Full`] (see
+    /// [`DebugPayload::explain`]).
+    pub fn explain(&self, phrase: &crate::types::Phrase) -> Option<PhraseExplanation> {
+        self.debug.as_ref()?.explain(phrase)
+    }
 }
 
 // ============================================================================
@@ -2001,6 +2655,15 @@ pub struct PipelineWorkspace {
     pub norm_buf: Vec<f64>,
     /// Scratch buffer for phrase grouping keys.
     pub group_keys: Vec<String>,
+    /// Corpus-wide string storage for token streams, shared between the
+    /// workspaces of one batch.  Kept across [`clear`](Self::clear).
+    pub shared_pool: Option<Arc<SharedStringPool>>,
+    /// Bump arena for per-document scratch data; see [`arena`](Self::arena).
+    /// The mutex only makes the workspace `Sync`: the arena is reached
+    /// through `&mut self`, and only [`capacity_bytes`](Self::capacity_bytes)
+    /// (which has `&self`) locks it, uncontended, to read its size.
+    #[cfg(feature = "arena")]
+    arena: parking_lot::Mutex<bumpalo::Bump>,
 }
 
 impl PipelineWorkspace {
@@ -2011,6 +2674,9 @@ impl PipelineWorkspace {
             score_buf: Vec::new(),
             norm_buf: Vec::new(),
             group_keys: Vec::new(),
+            shared_pool: None,
+            #[cfg(feature = "arena")]
+            arena: Default::default(),
         }
     }
 
@@ -2023,6 +2689,23 @@ impl PipelineWorkspace {
             score_buf: Vec::with_capacity(node_cap),
             norm_buf: Vec::with_capacity(node_cap),
             group_keys: Vec::with_capacity(phrase_cap),
+            shared_pool: None,
+            #[cfg(feature = "arena")]
+            arena: Default::default(),
+        }
+    }
+
+    /// Intern token strings into `pool`, shared with other workspaces.
+    pub fn with_shared_pool(mut self, pool: Arc<SharedStringPool>) -> Self {
+        self.shared_pool = Some(pool);
+        self
+    }
+
+    /// Build a [`TokenStream`], through the shared pool when one is set.
+    pub fn token_stream(&self, tokens: &[Token]) -> TokenStream {
+        match &self.shared_pool {
+            Some(shared) => TokenStream::from_tokens_shared(tokens, shared),
+            None => TokenStream::from_tokens(tokens),
         }
     }
 
@@ -2035,6 +2718,8 @@ impl PipelineWorkspace {
         self.score_buf.clear();
         self.norm_buf.clear();
         self.group_keys.clear();
+        #[cfg(feature = "arena")]
+        self.arena.get_mut().reset();
     }
 
     /// Total heap capacity held by all buffers (in bytes, approximate).
@@ -2043,6 +2728,27 @@ impl PipelineWorkspace {
             + self.score_buf.capacity() * std::mem::size_of::<f64>()
             + self.norm_buf.capacity() * std::mem::size_of::<f64>()
             + self.group_keys.capacity() * std::mem::size_of::<String>()
+            + self.arena_bytes()
+    }
+
+    /// Bump arena for per-document scratch data (e.g. graph-stage
+    /// occurrence lists).  [`clear`](Self::clear) frees everything allocated
+    /// in it at once, keeping its largest chunk for the next document.
+    #[cfg(feature = "arena")]
+    pub fn arena(&mut self) -> &bumpalo::Bump {
+        self.arena.get_mut()
+    }
+
+    #[cfg(feature = "arena")]
+    fn arena_bytes(&self) -> usize {
+        // `&self` cannot reach the arena without locking; callers hold the
+        // only reference while a pipeline runs, so this never blocks.
+        self.arena.lock().allocated_bytes()
+    }
+
+    #[cfg(not(feature = "arena"))]
+    fn arena_bytes(&self) -> usize {
+        0
     }
 }
 
@@ -2052,6 +2758,24 @@ impl Default for PipelineWorkspace {
     }
 }
 
+// ============================================================================
+// Binary persistence (bincode feature)
+// ============================================================================
+
+/// Encode an artifact (or any serializable value) with bincode.
+#[cfg(feature = "bincode")]
+pub fn to_bincode<T: Serialize>(artifact: &T) -> crate::errors::Result<Vec<u8>> {
+    bincode::serialize(artifact)
+        .map_err(|e| crate::errors::TextRankError::serialization(e.to_string()))
+}
+
+/// Decode an artifact previously encoded with [`to_bincode`].
+#[cfg(feature = "bincode")]
+pub fn from_bincode<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> crate::errors::Result<T> {
+    bincode::deserialize(bytes)
+        .map_err(|e| crate::errors::TextRankError::serialization(e.to_string()))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -2086,6 +2810,96 @@ mod tests {
         assert_eq!(stream.num_sentences(), 2);
     }
 
+    #[test]
+    fn test_from_tokens_shared_matches_owned() {
+        let tokens = sample_tokens();
+        let shared = Arc::new(SharedStringPool::new());
+        let ws = PipelineWorkspace::new().with_shared_pool(shared.clone());
+
+        let owned = serde_json::to_string(&TokenStream::from_tokens(&tokens)).unwrap();
+        let first = ws.token_stream(&tokens);
+        let second = ws.token_stream(&tokens);
+        assert_eq!(serde_json::to_string(&first).unwrap(), owned);
+        assert_eq!(serde_json::to_string(&second).unwrap(), owned);
+        // "Machine", "machine", ..., "is", "be" — each stored once.
+        assert_eq!(shared.len(), first.pool().len());
+    }
+
+    #[test]
+    fn test_from_text_matches_from_tokens() {
+        use crate::nlp::tokenizer::Tokenizer;
+        use crate::types::{TextRankConfig, VariantLinking};
+
+        let text = "Machine learning models learn. Support vector machines (SVM) classify.";
+        for cfg in [
+            TextRankConfig::default(),
+            TextRankConfig::default().with_variant_linking(VariantLinking {
+                acronyms: true,
+                hyphenation: true,
+            }),
+        ] {
+            let tokens = Tokenizer::new().tokenize_with_config(text, &cfg);
+            assert_eq!(
+                serde_json::to_string(&TokenStream::from_text(text, &cfg)).unwrap(),
+                serde_json::to_string(
+                    &TokenStream::from_tokens(&tokens).with_paragraph_starts([0])
+                )
+                .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_paragraphs_from_text() {
+        let text = "Rust is fast.\nIt is safe.\n\n  \nGraphs rank words.";
+        let stream = TokenStream::from_text(text, &crate::types::TextRankConfig::default());
+
+        assert_eq!(stream.num_sentences(), 3);
+        assert_eq!(stream.sentence_paragraphs(), &[0, 0, 1]);
+        let view = stream.as_ref();
+        assert_eq!(view.paragraph_of(1), 0);
+        assert_eq!(view.paragraph_of(2), 1);
+    }
+
+    #[test]
+    fn test_with_paragraph_starts() {
+        let stream = TokenStream::from_tokens(&sample_tokens());
+        assert!(stream.sentence_paragraphs().is_empty());
+        assert!(!stream.as_ref().has_paragraphs());
+        assert_eq!(stream.as_ref().paragraph_of(1), 0);
+
+        let single = stream.clone().with_paragraph_starts([0]);
+        assert_eq!(single.sentence_paragraphs(), &[0, 0]);
+        assert!(single.as_ref().has_paragraphs());
+
+        let stream = stream.with_paragraph_starts([1]);
+        assert_eq!(stream.sentence_paragraphs(), &[0, 1]);
+        assert_eq!(stream.as_ref().paragraph_of(1), 1);
+    }
+
+    #[test]
+    fn test_token_sections() {
+        let stream = TokenStream::from_tokens(&sample_tokens());
+        assert!(stream.token_sections().is_empty());
+        assert_eq!(stream.as_ref().section_of(0), None);
+
+        let mut tokens = sample_tokens();
+        tokens[1].section = Some(Section::Title);
+        let stream = TokenStream::from_tokens(&tokens);
+        assert_eq!(stream.token_sections().len(), stream.len());
+        assert_eq!(stream.as_ref().section_of(0), None);
+        assert_eq!(stream.as_ref().section_of(1), Some(Section::Title));
+        assert_eq!(
+            stream.as_ref().to_legacy_tokens()[1].section,
+            Some(Section::Title)
+        );
+
+        let stream = stream.with_sections([Some(Section::Abstract)]);
+        assert_eq!(stream.as_ref().section_of(0), Some(Section::Abstract));
+        assert_eq!(stream.as_ref().section_of(1), None);
+        assert!(stream.with_sections([None]).token_sections().is_empty());
+    }
+
     #[test]
     fn test_from_tokens_empty() {
         let stream = TokenStream::from_tokens(&[]);
@@ -2095,6 +2909,19 @@ mod tests {
         assert_eq!(stream.num_sentences(), 0);
     }
 
+    #[test]
+    fn test_heap_bytes_counts_entries_and_pool() {
+        let stream = TokenStream::from_tokens(&sample_tokens());
+        let entries = stream.len() * std::mem::size_of::<TokenEntry>();
+
+        assert!(stream.heap_bytes() >= entries + stream.pool().heap_bytes());
+        assert!(stream.pool().heap_bytes() > 0);
+        assert_eq!(
+            TokenStream::new(Vec::new(), StringPool::new()).heap_bytes(),
+            0
+        );
+    }
+
     #[test]
     fn test_string_interning_deduplicates() {
         let tokens = sample_tokens();
@@ -2390,7 +3217,7 @@ mod tests {
         assert_eq!(p.token_len(), 2);
         // lemma_ids: "machine", "learning"
         assert_eq!(p.lemma_ids.len(), 2);
-        // Both tokens are non-stopword, so term_ids has 2 unique text_ids.
+        // Both tokens are non-stopword, so term_ids has 2 unique lemma_ids.
         assert_eq!(p.term_ids.len(), 2);
     }
 
@@ -2886,6 +3713,38 @@ mod tests {
         assert_eq!(ro.iterations(), 100);
     }
 
+    #[test]
+    fn test_rank_output_top_k_nodes_and_iter() {
+        let ro = RankOutput::new(vec![0.2, 0.5, 0.2, 0.1], true, 10, 0.0);
+
+        assert_eq!(ro.top_k_nodes(2), vec![(1, 0.5), (0, 0.2)]);
+        assert_eq!(ro.top_k_nodes(0).len(), 4);
+        assert_eq!(ro.iter().nth(3), Some((3, 0.1)));
+    }
+
+    #[test]
+    fn test_ranked_nodes_lookup_by_key() {
+        let mut builder = crate::graph::builder::GraphBuilder::new();
+        for key in ["learn|VERB", "machine|NOUN", "learn|NOUN", "model|NOUN"] {
+            builder.get_or_create_node(key);
+        }
+        let graph = Graph::from_builder(&builder);
+        let ro = RankOutput::new(vec![0.1, 0.4, 0.3, 0.2], true, 10, 0.0);
+        let nodes = ro.nodes(&graph);
+
+        assert_eq!(nodes.len(), 4);
+        assert_eq!(nodes.score_of("machine|NOUN"), Some(0.4));
+        // A bare lemma matches its POS-keyed nodes; the best one wins.
+        assert_eq!(nodes.score_of("learn"), Some(0.3));
+        assert_eq!(nodes.score_of("learn|ADJ"), None);
+        assert_eq!(nodes.score_of("graph"), None);
+        assert_eq!(
+            nodes.top_k_nodes(2),
+            vec![("machine|NOUN", 0.4), ("learn|NOUN", 0.3)]
+        );
+        assert_eq!(nodes.iter().next(), Some(("learn|VERB", 0.1)));
+    }
+
     // ================================================================
     // PhraseSet tests
     // ================================================================
@@ -2898,6 +3757,7 @@ mod tests {
                 score: 0.85,
                 count: 3,
                 offsets: vec![(0, 2), (10, 12), (20, 22)],
+                spans: vec![(0, 16), (60, 76), (120, 136)],
                 rank: 1,
             },
             crate::types::Phrase {
@@ -2906,6 +3766,7 @@ mod tests {
                 score: 0.72,
                 count: 2,
                 offsets: vec![(5, 7)],
+                spans: vec![(30, 44)],
                 rank: 2,
             },
         ]
@@ -2975,6 +3836,7 @@ mod tests {
             surface: None,
             lemma_text: None,
             spans: None,
+            text_spans: None,
         };
         let ps = PhraseSet::from_entries(vec![entry]);
 
@@ -3030,6 +3892,7 @@ mod tests {
             cluster_details: None,
             phrase_diagnostics: None,
             dropped_candidates: None,
+            node_details: None,
         });
 
         let d = fr.debug.as_ref().unwrap();
@@ -3480,6 +4343,37 @@ mod tests {
         assert!(payload.cluster_memberships.is_none());
     }
 
+    #[test]
+    fn test_debug_build_full_node_details_and_explain() {
+        let (graph, ranks) = debug_test_graph_and_ranks();
+        let mut payload = DebugPayload::build(DebugLevel::Full, &graph, &ranks, 50).unwrap();
+        payload.set_teleport(&[2.0, 1.0, 1.0]);
+
+        let details = payload.node_details.as_ref().unwrap();
+        assert_eq!(details.len(), 3);
+        assert_eq!(details[0].key, "machine|NOUN");
+        assert_eq!(details[0].teleport, Some(0.5));
+        assert_eq!(
+            details[0].neighbors,
+            vec![
+                ("great|ADJ".to_string(), 1.0),
+                ("learning|NOUN".to_string(), 1.0)
+            ]
+        );
+
+        let phrase = crate::types::Phrase::new("Machine learning", "machine learning", 0.8, 1);
+        let explanation = payload.explain(&phrase).unwrap();
+        assert_eq!(explanation.score, 0.8);
+        let keys: Vec<&str> = explanation.nodes.iter().map(|n| n.key.as_str()).collect();
+        assert_eq!(keys, ["machine|NOUN", "learning|NOUN"]);
+        assert_eq!(explanation.nodes[1].score, 0.3);
+
+        // Below Full level there is nothing to explain from.
+        let top = DebugPayload::build(DebugLevel::TopNodes, &graph, &ranks, 50).unwrap();
+        assert!(top.node_details.is_none());
+        assert!(top.explain(&phrase).is_none());
+    }
+
     #[test]
     fn test_debug_build_node_scores_tiebreak_by_lemma() {
         // Two nodes with identical scores — should sort by lemma ascending.
@@ -3709,4 +4603,75 @@ mod tests {
         let cs = CandidateSet::from_sentence_boundaries(&stream);
         let _ = cs.words(); // should panic
     }
+
+    // ================================================================
+    // Serialization
+    // ================================================================
+
+    #[test]
+    fn test_token_stream_serde_round_trip() {
+        let stream = TokenStream::from_tokens(&sample_tokens());
+        let json = serde_json::to_string(&stream).unwrap();
+        let back: TokenStream = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(back.tokens(), stream.tokens());
+        assert_eq!(back.num_sentences(), stream.num_sentences());
+        assert_eq!(back.pool().len(), stream.pool().len());
+        assert_eq!(back.lemma(&back.tokens()[4]), "rust");
+    }
+
+    #[test]
+    fn test_string_pool_rejects_duplicates() {
+        let result: Result<StringPool, _> = serde_json::from_str(r#"["a", "b", "a"]"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_graph_and_rank_output_serde_round_trip() {
+        let stream = TokenStream::from_tokens(&sample_tokens());
+        let candidates = CandidateSet::from_word_tokens(&stream, &[PosTag::Noun], false);
+        let mut builder = crate::graph::builder::GraphBuilder::new();
+        let a = builder.get_or_create_node("machine");
+        let b = builder.get_or_create_node("learning");
+        builder.increment_edge(a, b, 2.0);
+        let graph = Graph::from_builder(&builder);
+        let pr = crate::pagerank::standard::StandardPageRank::new().run(graph.csr());
+        let rank = RankOutput::from_pagerank_result(&pr);
+
+        let candidates_back: CandidateSet =
+            serde_json::from_str(&serde_json::to_string(&candidates).unwrap()).unwrap();
+        assert_eq!(candidates_back.len(), candidates.len());
+
+        let graph_back: Graph =
+            serde_json::from_str(&serde_json::to_string(&graph).unwrap()).unwrap();
+        assert_eq!(graph_back.num_edges(), 2);
+        assert_eq!(graph_back.csr().get_node_by_lemma("learning"), Some(1));
+
+        let rank_back: RankOutput =
+            serde_json::from_str(&serde_json::to_string(&rank).unwrap()).unwrap();
+        assert_eq!(rank_back.scores(), rank.scores());
+        assert_eq!(rank_back.converged(), rank.converged());
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_bincode_round_trip() {
+        let stream = TokenStream::from_tokens(&sample_tokens());
+        let bytes = to_bincode(&stream).unwrap();
+        let back: TokenStream = from_bincode(&bytes).unwrap();
+        assert_eq!(back.tokens(), stream.tokens());
+
+        let mut pool = StringPool::new();
+        let phrases = vec![crate::types::Phrase::new(
+            "machine learning",
+            "machine learning",
+            0.5,
+            2,
+        )];
+        let set = PhraseSet::from_phrases(&phrases, &mut pool);
+        let back: PhraseSet = from_bincode(&to_bincode(&set).unwrap()).unwrap();
+        assert_eq!(back.len(), 1);
+
+        assert!(from_bincode::<TokenStream>(&bytes[..bytes.len() / 2]).is_err());
+    }
 }
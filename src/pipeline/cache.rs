@@ -0,0 +1,202 @@
+//! Upstream artifact cache for parameter sweeps.
+//!
+//! When only downstream parameters change between runs — damping, teleport,
+//! convergence settings, `top_n`, aggregation — the token stream, candidates
+//! and graph are identical. [`PipelineCache`] stores those artifacts keyed by
+//! a hash of the input tokens and every *upstream* config field, so
+//! [`Pipeline::run_cached`](super::runner::Pipeline::run_cached) can skip the
+//! preprocess, candidates, graph and graph-transform stages on a repeat.
+//!
+//! Fields listed in [`DOWNSTREAM_FIELDS`] are excluded from the key; every
+//! other field (including ones added later) is treated as upstream, so an
+//! unknown field can cause a miss but never a stale hit.
+//!
+//! The key also covers the pipeline's concrete type, but not its stage
+//! parameters or custom stages: use one cache per pipeline instance. A
+//! [`NodeKey::Custom`](crate::types::NodeKey::Custom) key function is
+//! compared by identity.
+
+use crate::pipeline::artifacts::{CandidateSet, Graph, TokenStream};
+use crate::pipeline::spec::config_fields;
+use crate::types::{NodeKey, TextRankConfig};
+use rustc_hash::{FxHashMap, FxHasher};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Config fields read only by the teleport, rank, phrase and format stages
+/// (or by the runner itself), and so excluded from the cache key.
+pub const DOWNSTREAM_FIELDS: &[&str] = &[
+    "damping",
+    "max_iterations",
+    "convergence_threshold",
+    "convergence_criterion",
+    "non_convergence",
+    "dangling_policy",
+    "acceleration",
+    "rank_precision",
+    "parallel_threshold",
+    "top_n",
+    "min_phrase_words",
+    "max_phrase_words",
+    "min_phrase_frequency",
+    "score_aggregation",
+    "phrase_grouping",
+    "mmr_lambda",
+    "near_duplicates",
+    "phrase_selection",
+    "span_mode",
+    "surface_selection",
+    "overlap_policy",
+    "phrase_blocklist",
+    "score_normalization",
+    "debug_level",
+    "debug_top_k",
+    "max_nodes",
+    "max_edges",
+    "stage_timeout_ms",
+    "pipeline_timeout_ms",
+];
+
+/// Artifacts produced by the stages up to and including `graph_transform`.
+#[derive(Debug, Clone)]
+pub(crate) struct UpstreamArtifacts {
+    pub(crate) tokens: TokenStream,
+    pub(crate) candidates: CandidateSet,
+    pub(crate) graph: Graph,
+}
+
+/// Cache of upstream pipeline artifacts, keyed by document and upstream
+/// config.
+#[derive(Debug, Default)]
+pub struct PipelineCache {
+    entries: FxHashMap<u64, UpstreamArtifacts>,
+    hits: usize,
+    misses: usize,
+}
+
+impl PipelineCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of runs that reused cached artifacts.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of runs that had to compute upstream artifacts.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Drop all entries and reset the hit/miss counters.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// Look up `key`, counting a hit or miss.
+    pub(crate) fn get(&mut self, key: u64) -> Option<UpstreamArtifacts> {
+        let entry = self.entries.get(&key).cloned();
+        if entry.is_some() {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        entry
+    }
+
+    pub(crate) fn insert(&mut self, key: u64, artifacts: UpstreamArtifacts) {
+        self.entries.insert(key, artifacts);
+    }
+}
+
+/// Cache key for running `pipeline` (a type name) over `tokens` with `cfg`.
+pub(crate) fn upstream_key(pipeline: &str, tokens: &TokenStream, cfg: &TextRankConfig) -> u64 {
+    let mut hasher = FxHasher::default();
+    pipeline.hash(&mut hasher);
+
+    let pool = tokens.pool();
+    for id in 0..pool.len() as u32 {
+        pool.get(id).hash(&mut hasher);
+    }
+    tokens.tokens().hash(&mut hasher);
+    tokens.sentence_offsets().hash(&mut hasher);
+    tokens.sentence_paragraphs().hash(&mut hasher);
+    tokens.token_sections().hash(&mut hasher);
+
+    let mut fields = config_fields(cfg);
+    fields.retain(|name, _| !DOWNSTREAM_FIELDS.contains(&name.as_str()));
+    serde_json::Value::Object(fields)
+        .to_string()
+        .hash(&mut hasher);
+    // Custom key functions all serialize as "custom".
+    if let Some(NodeKey::Custom(f)) = &cfg.node_key {
+        (Arc::as_ptr(f) as *const () as usize).hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PosTag, Token};
+
+    fn stream(lemma: &str) -> TokenStream {
+        TokenStream::from_tokens(&[Token::new(lemma, lemma, PosTag::Noun, 0, 4, 0, 0)])
+    }
+
+    #[test]
+    fn test_upstream_key_ignores_downstream_fields() {
+        let tokens = stream("rust");
+        let base = TextRankConfig::default();
+        let key = upstream_key("p", &tokens, &base);
+
+        let mut downstream = base.clone().with_damping(0.5);
+        downstream.top_n = 3;
+        assert_eq!(upstream_key("p", &tokens, &downstream), key);
+
+        let mut upstream = base.clone();
+        upstream.window_size += 1;
+        assert_ne!(upstream_key("p", &tokens, &upstream), key);
+        assert_ne!(upstream_key("p", &stream("graph"), &base), key);
+        assert_ne!(upstream_key("q", &tokens, &base), key);
+
+        let surface = base.clone().with_node_key(NodeKey::Surface);
+        assert_ne!(upstream_key("p", &tokens, &surface), key);
+        let custom = |cfg: &TextRankConfig| {
+            cfg.clone()
+                .with_node_key(NodeKey::custom(|t| t.text.as_str().into()))
+        };
+        let (a, b) = (custom(&base), custom(&base));
+        assert_eq!(
+            upstream_key("p", &tokens, &a),
+            upstream_key("p", &tokens, &a.clone())
+        );
+        assert_ne!(
+            upstream_key("p", &tokens, &a),
+            upstream_key("p", &tokens, &b)
+        );
+    }
+
+    #[test]
+    fn test_downstream_fields_are_config_fields() {
+        let fields = config_fields(&TextRankConfig::default());
+        for name in DOWNSTREAM_FIELDS {
+            assert!(fields.contains_key(*name), "unknown config field {name}");
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Cooperative cancellation and wall-clock budgets for pipeline runs.
+//!
+//! A [`CancellationToken`] is a cheap, clonable flag shared between the
+//! caller and a running pipeline. The runner checks it — together with the
+//! per-stage and whole-pipeline budgets from
+//! [`TextRankConfig::stage_timeout_ms`] and
+//! [`TextRankConfig::pipeline_timeout_ms`] — at every stage boundary, and
+//! also from the progress hooks of graph construction and ranking, so a
+//! slow graph build or a long power iteration is interrupted mid-stage.
+//! Other stages are never interrupted mid-way: an abort happens at the end
+//! of the stage that was running when the token was cancelled or the budget
+//! ran out.
+//!
+//! [`TextRankConfig::stage_timeout_ms`]: crate::types::TextRankConfig::stage_timeout_ms
+//! [`TextRankConfig::pipeline_timeout_ms`]: crate::types::TextRankConfig::pipeline_timeout_ms
+
+use crate::pipeline::clock::Instant;
+use crate::pipeline::error_code::ErrorCode;
+use crate::pipeline::errors::PipelineRuntimeError;
+use crate::types::TextRankConfig;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Shared flag used to cancel an in-flight pipeline run.
+///
+/// Clones share the same flag, so one clone can be handed to the pipeline
+/// and another kept by a request handler or watchdog thread.
+///
+/// ```
+/// # use rapid_textrank::pipeline::CancellationToken;
+/// let token = CancellationToken::new();
+/// let handle = token.clone();
+/// assert!(!token.is_cancelled());
+/// handle.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a token that is not yet cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation. Idempotent.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [`cancel`](Self::cancel) has been called on any clone.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Per-run cancellation and timeout state checked by the runner between
+/// stages.
+pub(crate) struct RunBudget<'a> {
+    token: Option<&'a CancellationToken>,
+    started: Instant,
+    stage_timeout: Option<Duration>,
+    pipeline_timeout: Option<Duration>,
+}
+
+impl<'a> RunBudget<'a> {
+    /// Start the whole-pipeline clock.
+    pub(crate) fn start(cfg: &TextRankConfig, token: Option<&'a CancellationToken>) -> Self {
+        Self {
+            token,
+            started: Instant::now(),
+            stage_timeout: cfg.stage_timeout_ms.map(Duration::from_millis),
+            pipeline_timeout: cfg.pipeline_timeout_ms.map(Duration::from_millis),
+        }
+    }
+
+    /// Whether there is a token or timeout to check at all.
+    pub(crate) fn is_limited(&self) -> bool {
+        self.token.is_some() || self.stage_timeout.is_some() || self.pipeline_timeout.is_some()
+    }
+
+    /// Check the token and budgets after `stage` ran for `stage_elapsed`.
+    ///
+    /// Returns the error to abort with, or `None` to continue.
+    pub(crate) fn check(
+        &self,
+        stage: &str,
+        stage_elapsed: Duration,
+    ) -> Option<PipelineRuntimeError> {
+        if self.token.is_some_and(CancellationToken::is_cancelled) {
+            return Some(
+                PipelineRuntimeError::new(
+                    ErrorCode::Cancelled,
+                    "/runtime",
+                    stage,
+                    format!("pipeline cancelled during stage '{stage}'"),
+                )
+                .with_hint("The caller's cancellation token was triggered"),
+            );
+        }
+        if let Some(limit) = self.stage_timeout {
+            if stage_elapsed > limit {
+                return Some(
+                    PipelineRuntimeError::new(
+                        ErrorCode::Timeout,
+                        "/runtime/stage_timeout_ms",
+                        stage,
+                        format!(
+                            "stage '{stage}' took {} ms, exceeding the limit of {} ms",
+                            stage_elapsed.as_millis(),
+                            limit.as_millis()
+                        ),
+                    )
+                    .with_hint("Increase runtime.stage_timeout_ms or reduce input size"),
+                );
+            }
+        }
+        if let Some(limit) = self.pipeline_timeout {
+            let total = self.started.elapsed();
+            if total > limit {
+                return Some(
+                    PipelineRuntimeError::new(
+                        ErrorCode::Timeout,
+                        "/runtime/timeout_ms",
+                        stage,
+                        format!(
+                            "pipeline ran for {} ms, exceeding the limit of {} ms",
+                            total.as_millis(),
+                            limit.as_millis()
+                        ),
+                    )
+                    .with_hint("Increase runtime.timeout_ms or reduce input size"),
+                );
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_clones_share_state() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        clone.cancel();
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_budget_checks() {
+        let cfg = TextRankConfig::default();
+        assert!(!RunBudget::start(&cfg, None).is_limited());
+        assert!(RunBudget::start(&cfg, None)
+            .check("rank", Duration::from_secs(60))
+            .is_none());
+
+        let cfg = TextRankConfig::default().with_stage_timeout_ms(10);
+        let err = RunBudget::start(&cfg, None)
+            .check("rank", Duration::from_millis(11))
+            .unwrap();
+        assert_eq!(err.code, ErrorCode::Timeout);
+        assert_eq!(err.stage, "rank");
+        assert_eq!(err.path, "/runtime/stage_timeout_ms");
+
+        let cfg = TextRankConfig::default().with_pipeline_timeout_ms(0);
+        let budget = RunBudget::start(&cfg, None);
+        std::thread::sleep(Duration::from_millis(2));
+        let err = budget.check("graph", Duration::ZERO).unwrap();
+        assert_eq!(err.path, "/runtime/timeout_ms");
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let err = RunBudget::start(&TextRankConfig::default(), Some(&token))
+            .check("preprocess", Duration::ZERO)
+            .unwrap();
+        assert_eq!(err.code, ErrorCode::Cancelled);
+    }
+}
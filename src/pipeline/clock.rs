@@ -0,0 +1,44 @@
+//! Monotonic clock for stage timings and wall-clock budgets.
+//!
+//! `std::time::Instant::now()` panics on `wasm32-unknown-unknown`, which has
+//! no clock of its own. On that target [`Instant`] reads `Date.now()`
+//! through `js-sys` when the `wasm` feature is enabled; without it the clock
+//! stands still, so stage timings read zero and timeouts never fire.
+//! Everywhere else it is `std::time::Instant`.
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+pub(crate) use std::time::Instant;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+pub(crate) use self::wasm::Instant;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+mod wasm {
+    use std::time::Duration;
+
+    /// Milliseconds since the epoch, as reported by the host.
+    fn now_ms() -> f64 {
+        #[cfg(feature = "wasm")]
+        {
+            js_sys::Date::now()
+        }
+        #[cfg(not(feature = "wasm"))]
+        {
+            0.0
+        }
+    }
+
+    /// Millisecond-resolution stand-in for `std::time::Instant`.
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    pub(crate) struct Instant(f64);
+
+    impl Instant {
+        pub(crate) fn now() -> Self {
+            Self(now_ms())
+        }
+
+        pub(crate) fn elapsed(&self) -> Duration {
+            Duration::from_secs_f64((now_ms() - self.0).max(0.0) / 1000.0)
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! User-defined pipeline stages.
+//!
+//! A [`Stage`] is an object-safe hook that runs at a fixed [`StagePoint`]
+//! between the built-in stages — e.g. a candidate filter between
+//! `candidates` and `graph` — and may mutate whichever artifacts exist at
+//! that point. Stages are registered on a [`Pipeline`](super::runner::Pipeline)
+//! with [`with_stage`](super::runner::Pipeline::with_stage) (or
+//! [`PipelineBuilder::stage`](super::runner::PipelineBuilder::stage)) and run
+//! in registration order. The runner times them and notifies observers under
+//! the stage's own [`name`](Stage::name), exactly like a built-in stage.
+//!
+//! ```
+//! use rapid_textrank::pipeline::artifacts::CandidateKind;
+//! use rapid_textrank::pipeline::custom_stage::{Stage, StageArtifacts, StagePoint};
+//! use rapid_textrank::pipeline::runner::BaseTextRankPipeline;
+//! use rapid_textrank::types::TextRankConfig;
+//!
+//! /// Drop single-character word candidates before the graph is built.
+//! struct ShortWordFilter;
+//!
+//! impl Stage for ShortWordFilter {
+//!     fn name(&self) -> &'static str {
+//!         "short_word_filter"
+//!     }
+//!
+//!     fn run(&self, artifacts: &mut StageArtifacts<'_>, _cfg: &TextRankConfig) {
+//!         let tokens = &*artifacts.tokens;
+//!         if let Some(CandidateKind::Words(words)) =
+//!             artifacts.candidates.as_mut().map(|c| c.kind_mut())
+//!         {
+//!             words.retain(|w| tokens.pool().get(w.lemma_id).is_some_and(|l| l.len() > 1));
+//!         }
+//!     }
+//! }
+//!
+//! let pipeline = BaseTextRankPipeline::base_textrank()
+//!     .with_stage(StagePoint::AfterCandidates, ShortWordFilter);
+//! assert_eq!(pipeline.custom_stages.len(), 1);
+//! ```
+
+use crate::pipeline::artifacts::{CandidateSet, Graph, PhraseSet, RankOutput, TokenStream};
+use crate::pipeline::cancellation::RunBudget;
+use crate::pipeline::errors::{PipelineError, PipelineRuntimeError};
+use crate::pipeline::observer::{PipelineObserver, StageClock, StageReport};
+use crate::types::TextRankConfig;
+use std::sync::Arc;
+
+/// Where a custom stage runs, relative to the built-in stages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StagePoint {
+    /// After `preprocess`, before candidate selection. Tokens only.
+    AfterPreprocess,
+    /// After `candidates`, before graph construction.
+    AfterCandidates,
+    /// After `graph_transform`, before the teleport vector and ranking.
+    AfterGraph,
+    /// After `rank`, before phrase building.
+    AfterRank,
+    /// After `phrases`, before formatting.
+    AfterPhrases,
+}
+
+/// Artifacts available to a custom stage.
+///
+/// Fields are `None` until the built-in stage producing them has run, so a
+/// stage at [`StagePoint::AfterCandidates`] sees `tokens` and `candidates`
+/// but no `graph`. Mutating an earlier artifact (e.g. tokens after
+/// candidates exist) is allowed but must keep later artifacts consistent.
+pub struct StageArtifacts<'a> {
+    /// The token stream.
+    pub tokens: &'a mut TokenStream,
+    /// Selected candidates.
+    pub candidates: Option<&'a mut CandidateSet>,
+    /// The (transformed) co-occurrence graph.
+    pub graph: Option<&'a mut Graph>,
+    /// Ranking output.
+    pub rank: Option<&'a mut RankOutput>,
+    /// Built phrases.
+    pub phrases: Option<&'a mut PhraseSet>,
+}
+
+impl<'a> StageArtifacts<'a> {
+    /// Artifacts with only the token stream populated.
+    pub fn new(tokens: &'a mut TokenStream) -> Self {
+        Self {
+            tokens,
+            candidates: None,
+            graph: None,
+            rank: None,
+            phrases: None,
+        }
+    }
+}
+
+/// A user-defined pipeline stage.
+///
+/// Object-safe so heterogeneous stages can be registered on one pipeline.
+pub trait Stage: Send + Sync {
+    /// Stage name reported to observers (`on_stage_start` / `on_stage_end`)
+    /// and in runtime errors.
+    fn name(&self) -> &'static str;
+
+    /// Run the stage, mutating the available artifacts in place.
+    fn run(&self, artifacts: &mut StageArtifacts<'_>, cfg: &TextRankConfig);
+
+    /// Run the stage, reporting failure.
+    ///
+    /// The runner calls this rather than [`run`](Self::run); an `Err` ends
+    /// the run with the error (converted to a [`PipelineRuntimeError`]) in
+    /// the result. The default runs [`run`](Self::run) and succeeds. Stages
+    /// that can fail override this and have `run` ignore the error.
+    fn try_run(
+        &self,
+        artifacts: &mut StageArtifacts<'_>,
+        cfg: &TextRankConfig,
+    ) -> Result<(), PipelineError> {
+        self.run(artifacts, cfg);
+        Ok(())
+    }
+}
+
+/// Custom stages registered on a pipeline, in registration order.
+#[derive(Clone, Default)]
+pub struct CustomStages {
+    stages: Vec<(StagePoint, Arc<dyn Stage>)>,
+}
+
+impl CustomStages {
+    /// Register `stage` to run at `point`.
+    pub fn push(&mut self, point: StagePoint, stage: impl Stage + 'static) {
+        self.stages.push((point, Arc::new(stage)));
+    }
+
+    /// Number of registered stages.
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Whether no stages are registered.
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// Names of the stages registered at `point`, in run order.
+    pub fn names_at(&self, point: StagePoint) -> impl Iterator<Item = &'static str> + '_ {
+        self.stages
+            .iter()
+            .filter(move |(p, _)| *p == point)
+            .map(|(_, stage)| stage.name())
+    }
+
+    /// Run every stage registered at `point`, reporting each to `observer`
+    /// and checking the run budget after it.
+    pub(crate) fn run_at(
+        &self,
+        point: StagePoint,
+        artifacts: &mut StageArtifacts<'_>,
+        cfg: &TextRankConfig,
+        observer: &mut impl PipelineObserver,
+        budget: &RunBudget<'_>,
+    ) -> Option<PipelineRuntimeError> {
+        for (_, stage) in self.stages.iter().filter(|(p, _)| *p == point) {
+            let name = stage.name();
+            observer.on_stage_start(name);
+            let clock = StageClock::start();
+            let outcome = stage.try_run(artifacts, cfg);
+            let report = StageReport::new(clock.elapsed());
+            observer.on_stage_end(name, &report);
+            if let Err(err) = outcome {
+                return Some(err.into());
+            }
+            if let Some(err) = budget.check(name, report.duration()) {
+                return Some(err);
+            }
+        }
+        None
+    }
+}
+
+impl std::fmt::Debug for CustomStages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(
+                self.stages
+                    .iter()
+                    .map(|(point, stage)| (point, stage.name())),
+            )
+            .finish()
+    }
+}
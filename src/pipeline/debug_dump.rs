@@ -0,0 +1,403 @@
+//! Artifact-dump observer writing JSON Lines.
+//!
+//! [`DebugDumpObserver`] writes every intermediate artifact of each run to
+//! one JSON Lines file per artifact in a target directory, so a bad result
+//! can be diffed stage by stage against a known-good run:
+//!
+//! | File               | One line per                                             |
+//! |--------------------|----------------------------------------------------------|
+//! | `tokens.jsonl`     | token: `index`, `text`, `lemma`, `pos`, `sentence`, `start`, `end`, `stopword` |
+//! | `candidates.jsonl` | candidate: `kind` (`word`/`phrase`/`sentence`) plus its fields |
+//! | `graph.jsonl`      | run: `nodes`, `edges`, `transformed`, `dangling_nodes`, `max_degree`, `total_weight` |
+//! | `rank.jsonl`       | graph node: `node`, `lemma`, `score`                     |
+//! | `phrases.jsonl`    | phrase: `rank`, `text`, `lemma`, `score`, `count`        |
+//!
+//! Every line carries a `doc` field counting runs observed so far (0-based),
+//! so one observer can be reused across a batch. Each file is capped at
+//! [`with_max_bytes_per_file`](DebugDumpObserver::with_max_bytes_per_file)
+//! bytes; once a file is full a single `{"truncated":true}` line is appended
+//! and further lines for it are dropped.
+
+use crate::pipeline::artifacts::{CandidateKind, CandidateSet, Graph, PhraseSet, RankOutput};
+use crate::pipeline::observer::PipelineObserver;
+use crate::pipeline::TokenStream;
+use crate::types::StringPool;
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Default per-file size cap: 64 MiB.
+pub const DEFAULT_MAX_BYTES_PER_FILE: u64 = 64 * 1024 * 1024;
+
+/// One capped JSON Lines output file.
+#[derive(Debug)]
+struct DumpFile {
+    writer: BufWriter<File>,
+    written: u64,
+    truncated: bool,
+}
+
+impl DumpFile {
+    fn create(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            written: 0,
+            truncated: false,
+        })
+    }
+
+    fn write_line(&mut self, line: &Value, max_bytes: u64) -> io::Result<()> {
+        if self.truncated {
+            return Ok(());
+        }
+        let mut bytes = serde_json::to_vec(line).map_err(io::Error::from)?;
+        bytes.push(b'\n');
+        if self.written + bytes.len() as u64 > max_bytes {
+            self.truncated = true;
+            return self.writer.write_all(b"{\"truncated\":true}\n");
+        }
+        self.written += bytes.len() as u64;
+        self.writer.write_all(&bytes)
+    }
+}
+
+/// Observer dumping each run's artifacts to JSON Lines files.
+///
+/// I/O errors cannot be returned from observer callbacks; the first one is
+/// kept and reported by [`finish`](Self::finish), and later writes are
+/// skipped.
+///
+/// ```no_run
+/// use rapid_textrank::pipeline::debug_dump::DebugDumpObserver;
+/// use rapid_textrank::pipeline::runner::BaseTextRankPipeline;
+/// use rapid_textrank::TextRankConfig;
+///
+/// let mut dump = DebugDumpObserver::new("/tmp/textrank-dump")?;
+/// let cfg = TextRankConfig::default();
+/// BaseTextRankPipeline::base_textrank().run_text("Graphs have nodes.", &cfg, &mut dump);
+/// dump.finish()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct DebugDumpObserver {
+    dir: PathBuf,
+    max_bytes_per_file: u64,
+    tokens: DumpFile,
+    candidates: DumpFile,
+    graph: DumpFile,
+    rank: DumpFile,
+    phrases: DumpFile,
+    /// Index of the current run; advanced by each `on_tokens`.
+    doc: Option<u64>,
+    /// String pool of the current run, for resolving interned IDs.
+    pool: StringPool,
+    /// Node lemmas of the current run's graph, for labelling scores.
+    node_lemmas: Vec<String>,
+    error: Option<io::Error>,
+}
+
+impl DebugDumpObserver {
+    /// Create `dir` if needed and open (truncating) the five dump files.
+    pub fn new(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+        let open = |name: &str| DumpFile::create(&dir.join(name));
+        Ok(Self {
+            tokens: open("tokens.jsonl")?,
+            candidates: open("candidates.jsonl")?,
+            graph: open("graph.jsonl")?,
+            rank: open("rank.jsonl")?,
+            phrases: open("phrases.jsonl")?,
+            dir,
+            max_bytes_per_file: DEFAULT_MAX_BYTES_PER_FILE,
+            doc: None,
+            pool: StringPool::new(),
+            node_lemmas: Vec::new(),
+            error: None,
+        })
+    }
+
+    /// Cap each dump file at `max_bytes` (default
+    /// [`DEFAULT_MAX_BYTES_PER_FILE`]).
+    pub fn with_max_bytes_per_file(mut self, max_bytes: u64) -> Self {
+        self.max_bytes_per_file = max_bytes;
+        self
+    }
+
+    /// Directory the files are written to.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Names of the files that hit the size cap.
+    pub fn truncated_files(&self) -> Vec<&'static str> {
+        [
+            ("tokens.jsonl", &self.tokens),
+            ("candidates.jsonl", &self.candidates),
+            ("graph.jsonl", &self.graph),
+            ("rank.jsonl", &self.rank),
+            ("phrases.jsonl", &self.phrases),
+        ]
+        .into_iter()
+        .filter(|(_, file)| file.truncated)
+        .map(|(name, _)| name)
+        .collect()
+    }
+
+    /// Flush all files, returning the first I/O error seen, if any.
+    pub fn finish(mut self) -> io::Result<()> {
+        for file in [
+            &mut self.tokens,
+            &mut self.candidates,
+            &mut self.graph,
+            &mut self.rank,
+            &mut self.phrases,
+        ] {
+            if let Err(err) = file.writer.flush() {
+                self.error.get_or_insert(err);
+            }
+        }
+        self.error.map_or(Ok(()), Err)
+    }
+
+    fn doc(&self) -> u64 {
+        self.doc.unwrap_or(0)
+    }
+
+    /// Write `lines` to the file chosen by `pick`, stopping at the first error.
+    fn write(&mut self, pick: fn(&mut Self) -> &mut DumpFile, lines: Vec<Value>) {
+        if self.error.is_some() {
+            return;
+        }
+        let max_bytes = self.max_bytes_per_file;
+        let file = pick(self);
+        for line in &lines {
+            if let Err(err) = file.write_line(line, max_bytes) {
+                self.error = Some(err);
+                return;
+            }
+        }
+    }
+
+    fn lemma(&self, id: u32) -> &str {
+        self.pool.get(id).unwrap_or("")
+    }
+
+    fn lemmas(&self, ids: &[u32]) -> String {
+        ids.iter()
+            .map(|&id| self.lemma(id))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl PipelineObserver for DebugDumpObserver {
+    fn on_tokens(&mut self, tokens: &TokenStream) {
+        let doc = self.doc.map_or(0, |d| d + 1);
+        self.doc = Some(doc);
+        self.pool = tokens.pool().clone();
+        self.node_lemmas.clear();
+
+        let lines = tokens
+            .tokens()
+            .iter()
+            .enumerate()
+            .map(|(i, t)| {
+                json!({
+                    "doc": doc,
+                    "index": i,
+                    "text": self.pool.get(t.text_id).unwrap_or(""),
+                    "lemma": self.lemma(t.lemma_id),
+                    "pos": t.pos.as_str(),
+                    "sentence": t.sentence_idx,
+                    "start": t.start,
+                    "end": t.end,
+                    "stopword": t.is_stopword,
+                })
+            })
+            .collect();
+        self.write(|s| &mut s.tokens, lines);
+    }
+
+    fn on_candidates(&mut self, candidates: &CandidateSet) {
+        let doc = self.doc();
+        let lines = match candidates.kind() {
+            CandidateKind::Words(words) => words
+                .iter()
+                .map(|w| {
+                    json!({
+                        "doc": doc,
+                        "kind": "word",
+                        "lemma": self.lemma(w.lemma_id),
+                        "pos": w.pos.as_str(),
+                        "first_position": w.first_position,
+                    })
+                })
+                .collect(),
+            CandidateKind::Phrases(phrases) => phrases
+                .iter()
+                .map(|p| {
+                    json!({
+                        "doc": doc,
+                        "kind": "phrase",
+                        "lemma": self.lemmas(&p.lemma_ids),
+                        "start_token": p.start_token,
+                        "end_token": p.end_token,
+                        "sentence": p.sentence_idx,
+                    })
+                })
+                .collect(),
+            CandidateKind::Sentences(sentences) => sentences
+                .iter()
+                .map(|s| {
+                    json!({
+                        "doc": doc,
+                        "kind": "sentence",
+                        "sentence": s.sentence_idx,
+                        "start_token": s.start_token,
+                        "end_token": s.end_token,
+                    })
+                })
+                .collect(),
+        };
+        self.write(|s| &mut s.candidates, lines);
+    }
+
+    fn on_graph(&mut self, graph: &Graph) {
+        let csr = graph.csr();
+        self.node_lemmas = csr.lemmas.clone();
+        let max_degree = (0..graph.num_nodes() as u32)
+            .map(|n| graph.neighbors(n).count())
+            .max()
+            .unwrap_or(0);
+        let line = json!({
+            "doc": self.doc(),
+            "nodes": graph.num_nodes(),
+            "edges": graph.num_edges(),
+            "transformed": graph.is_transformed(),
+            "dangling_nodes": graph.dangling_nodes().len(),
+            "max_degree": max_degree,
+            "total_weight": csr.weights.iter().sum::<f64>(),
+        });
+        self.write(|s| &mut s.graph, vec![line]);
+    }
+
+    fn on_rank(&mut self, rank: &RankOutput) {
+        let doc = self.doc();
+        let lines = rank
+            .scores()
+            .iter()
+            .enumerate()
+            .map(|(node, score)| {
+                json!({
+                    "doc": doc,
+                    "node": node,
+                    "lemma": self.node_lemmas.get(node).map_or("", String::as_str),
+                    "score": score,
+                })
+            })
+            .collect();
+        self.write(|s| &mut s.rank, lines);
+    }
+
+    fn on_phrases(&mut self, phrases: &PhraseSet) {
+        let doc = self.doc();
+        let lines = phrases
+            .entries()
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let lemma = p
+                    .lemma_text
+                    .clone()
+                    .unwrap_or_else(|| self.lemmas(&p.lemma_ids));
+                json!({
+                    "doc": doc,
+                    "rank": i + 1,
+                    "text": p.surface.as_deref().unwrap_or(&lemma),
+                    "lemma": lemma,
+                    "score": p.score,
+                    "count": p.count,
+                })
+            })
+            .collect();
+        self.write(|s| &mut s.phrases, lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::runner::BaseTextRankPipeline;
+    use crate::types::TextRankConfig;
+
+    const TEXT: &str = "Machine learning models learn from data. \
+        Deep learning is a kind of machine learning.";
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("rapid_textrank_dump_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn read_lines(dir: &Path, file: &str) -> Vec<Value> {
+        std::fs::read_to_string(dir.join(file))
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_dump_writes_every_artifact() {
+        let dir = temp_dir("all");
+        let pipeline = BaseTextRankPipeline::base_textrank();
+        let cfg = TextRankConfig::default();
+        let mut dump = DebugDumpObserver::new(&dir).unwrap();
+        let result = pipeline.run_text(TEXT, &cfg, &mut dump);
+        pipeline.run_text(TEXT, &cfg, &mut dump);
+        assert!(dump.truncated_files().is_empty());
+        dump.finish().unwrap();
+
+        let tokens = read_lines(&dir, "tokens.jsonl");
+        assert_eq!(tokens[0]["doc"], 0);
+        assert_eq!(tokens[0]["text"], "Machine");
+        assert_eq!(tokens.last().unwrap()["doc"], 1);
+
+        assert!(read_lines(&dir, "candidates.jsonl")
+            .iter()
+            .all(|c| c["kind"] == "word"));
+
+        let graph = read_lines(&dir, "graph.jsonl");
+        assert_eq!(graph.len(), 2);
+        let nodes = graph[0]["nodes"].as_u64().unwrap() as usize;
+        let rank = read_lines(&dir, "rank.jsonl");
+        assert_eq!(rank.len(), 2 * nodes);
+
+        let phrases = read_lines(&dir, "phrases.jsonl");
+        assert_eq!(phrases[0]["doc"], 0);
+        assert_eq!(phrases[0]["lemma"], result.phrases[0].lemma.as_str());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_dump_respects_size_cap() {
+        let dir = temp_dir("cap");
+        let cfg = TextRankConfig::default();
+        let mut dump = DebugDumpObserver::new(&dir)
+            .unwrap()
+            .with_max_bytes_per_file(300);
+        BaseTextRankPipeline::base_textrank().run_text(TEXT, &cfg, &mut dump);
+        assert!(dump.truncated_files().contains(&"tokens.jsonl"));
+        dump.finish().unwrap();
+
+        let contents = std::fs::read_to_string(dir.join("tokens.jsonl")).unwrap();
+        assert!(contents.len() <= 300 + "{\"truncated\":true}\n".len());
+        assert!(contents.ends_with("{\"truncated\":true}\n"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,228 @@
+//! CSV/TSV output.
+//!
+//! [`DelimitedWriter`] flattens results into one row per phrase, ready for
+//! bulk loading into a warehouse:
+//!
+//! ```text
+//! doc_id,rank,phrase,lemma,score,first_offset
+//! doc-1,1,Machine learning,machine learning,0.42,0
+//! ```
+//!
+//! `first_offset` is the start of the phrase's first span (a byte offset for
+//! the built-in tokenizer), or empty when the phrase has no spans. CSV fields
+//! are quoted per RFC 4180 when needed; TSV has no quoting, so tabs and line
+//! breaks inside fields are replaced with spaces.
+//!
+//! In a pipeline spec, select it with `"format": { "type": "csv" }` or
+//! `{ "type": "tsv" }`.
+//!
+//! # Example
+//!
+//! ```
+//! use rapid_textrank::pipeline::delimited_output::DelimitedWriter;
+//! use rapid_textrank::pipeline::FormattedResult;
+//! use rapid_textrank::Phrase;
+//!
+//! let mut phrase = Phrase::new("graph ranking", "graph ranking", 0.5, 1);
+//! phrase.rank = 1;
+//! phrase.spans = vec![(4, 17)];
+//! let result = FormattedResult::new(vec![phrase], true, 12);
+//!
+//! let csv = DelimitedWriter::csv().write_batch([("doc-1", &result)]);
+//! assert_eq!(
+//!     csv,
+//!     "doc_id,rank,phrase,lemma,score,first_offset\n\
+//!      doc-1,1,graph ranking,graph ranking,0.5,4\n"
+//! );
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use super::artifacts::FormattedResult;
+
+/// Column names, in output order.
+pub const DELIMITED_COLUMNS: [&str; 6] =
+    ["doc_id", "rank", "phrase", "lemma", "score", "first_offset"];
+
+/// Field separator of a [`DelimitedWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Delimiter {
+    /// Comma-separated values with RFC 4180 quoting (default)
+    #[default]
+    Comma,
+    /// Tab-separated values
+    Tab,
+}
+
+impl Delimiter {
+    fn as_char(self) -> char {
+        match self {
+            Delimiter::Comma => ',',
+            Delimiter::Tab => '\t',
+        }
+    }
+}
+
+/// Writes results as CSV or TSV rows, one per phrase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DelimitedWriter {
+    delimiter: Delimiter,
+    header: bool,
+}
+
+impl Default for DelimitedWriter {
+    fn default() -> Self {
+        Self::csv()
+    }
+}
+
+impl DelimitedWriter {
+    /// Comma-separated output with a header row.
+    pub fn csv() -> Self {
+        Self {
+            delimiter: Delimiter::Comma,
+            header: true,
+        }
+    }
+
+    /// Tab-separated output with a header row.
+    pub fn tsv() -> Self {
+        Self {
+            delimiter: Delimiter::Tab,
+            header: true,
+        }
+    }
+
+    /// Whether [`write_batch`](Self::write_batch) starts with a header row.
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// The field separator.
+    pub fn delimiter(&self) -> Delimiter {
+        self.delimiter
+    }
+
+    /// Append the header row to `out`.
+    pub fn write_header(&self, out: &mut String) {
+        self.write_row(out, &DELIMITED_COLUMNS);
+    }
+
+    /// Append one row per phrase of `result` to `out`.
+    pub fn write_result(&self, out: &mut String, doc_id: &str, result: &FormattedResult) {
+        for phrase in &result.phrases {
+            let rank = phrase.rank.to_string();
+            let score = phrase.score.to_string();
+            let first_offset = phrase
+                .spans
+                .first()
+                .map(|&(start, _)| start.to_string())
+                .unwrap_or_default();
+            self.write_row(
+                out,
+                &[
+                    doc_id,
+                    &rank,
+                    &phrase.text,
+                    &phrase.lemma,
+                    &score,
+                    &first_offset,
+                ],
+            );
+        }
+    }
+
+    /// Format `(doc_id, result)` pairs, preceded by the header row if enabled.
+    pub fn write_batch<'a>(
+        &self,
+        docs: impl IntoIterator<Item = (&'a str, &'a FormattedResult)>,
+    ) -> String {
+        let mut out = String::new();
+        if self.header {
+            self.write_header(&mut out);
+        }
+        for (doc_id, result) in docs {
+            self.write_result(&mut out, doc_id, result);
+        }
+        out
+    }
+
+    fn write_row(&self, out: &mut String, fields: &[&str]) {
+        let sep = self.delimiter.as_char();
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                out.push(sep);
+            }
+            match self.delimiter {
+                Delimiter::Comma => push_csv_field(out, field),
+                Delimiter::Tab => out.extend(field.chars().map(|c| match c {
+                    '\t' | '\n' | '\r' => ' ',
+                    c => c,
+                })),
+            }
+        }
+        out.push('\n');
+    }
+}
+
+/// Append `field`, quoting it if it contains a comma, quote or line break.
+fn push_csv_field(out: &mut String, field: &str) {
+    if field.contains([',', '"', '\n', '\r']) {
+        out.push('"');
+        out.push_str(&field.replace('"', "\"\""));
+        out.push('"');
+    } else {
+        out.push_str(field);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Phrase;
+
+    fn result(phrases: &[(&str, f64, Option<usize>)]) -> FormattedResult {
+        let phrases = phrases
+            .iter()
+            .enumerate()
+            .map(|(i, &(text, score, start))| {
+                let mut p = Phrase::new(text, text.to_lowercase(), score, 1);
+                p.rank = i + 1;
+                p.spans = start.map(|s| (s, s + text.len())).into_iter().collect();
+                p
+            })
+            .collect();
+        FormattedResult::new(phrases, true, 10)
+    }
+
+    #[test]
+    fn test_csv_quotes_special_fields() {
+        let doc = result(&[
+            ("Rust, the language", 0.5, Some(3)),
+            ("say \"hi\"", 0.25, None),
+        ]);
+        let csv = DelimitedWriter::csv().write_batch([("d1", &doc)]);
+        assert_eq!(
+            csv,
+            "doc_id,rank,phrase,lemma,score,first_offset\n\
+             d1,1,\"Rust, the language\",\"rust, the language\",0.5,3\n\
+             d1,2,\"say \"\"hi\"\"\",\"say \"\"hi\"\"\",0.25,\n"
+        );
+    }
+
+    #[test]
+    fn test_tsv_batch_without_header() {
+        let a = result(&[("graph\tranking", 0.5, Some(0))]);
+        let b = result(&[("keyword", 0.75, Some(8))]);
+        let tsv = DelimitedWriter::tsv()
+            .with_header(false)
+            .write_batch([("a", &a), ("b", &b)]);
+        assert_eq!(
+            tsv,
+            "a\t1\tgraph ranking\tgraph ranking\t0.5\t0\n\
+             b\t1\tkeyword\tkeyword\t0.75\t8\n"
+        );
+    }
+}
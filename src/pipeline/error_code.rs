@@ -55,6 +55,12 @@ pub enum ErrorCode {
 
     /// PageRank iteration did not converge within the allowed iterations.
     ConvergenceFailed,
+
+    /// A stage or the whole pipeline exceeded its wall-clock budget.
+    Timeout,
+
+    /// The run was aborted through a cancellation token.
+    Cancelled,
 }
 
 impl ErrorCode {
@@ -74,6 +80,8 @@ impl ErrorCode {
             Self::ValidationFailed => "validation_failed",
             Self::StageFailed => "stage_failed",
             Self::ConvergenceFailed => "convergence_failed",
+            Self::Timeout => "timeout",
+            Self::Cancelled => "cancelled",
         }
     }
 }
@@ -102,6 +110,8 @@ mod tests {
             ErrorCode::ValidationFailed,
             ErrorCode::StageFailed,
             ErrorCode::ConvergenceFailed,
+            ErrorCode::Timeout,
+            ErrorCode::Cancelled,
         ];
 
         for code in &codes {
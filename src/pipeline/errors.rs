@@ -10,8 +10,15 @@
 //! Both types carry a stable [`ErrorCode`] for programmatic matching, a JSON
 //! pointer `path` for locating the problem in the spec, a human-readable
 //! `message`, and an optional `hint` suggesting a fix.
+//!
+//! [`PipelineError`] is the error a stage itself raises: it names the stage
+//! and chains the underlying cause (an I/O error, a model failure) through
+//! [`std::error::Error::source`]. The runner turns it into the
+//! [`PipelineRuntimeError`] reported on the result.
+
+use std::sync::Arc;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use thiserror::Error;
 
 use super::error_code::ErrorCode;
@@ -138,6 +145,115 @@ impl PipelineRuntimeError {
     }
 }
 
+// ─── Stage errors ───────────────────────────────────────────────────────────
+
+/// Underlying cause of a [`PipelineError`].
+pub type ErrorSource = Arc<dyn std::error::Error + Send + Sync>;
+
+/// An error raised while running a stage, with the stage it came from and
+/// the error that caused it.
+///
+/// # Display format
+///
+/// ```text
+/// [stage_failed] graph: could not load embeddings
+/// ```
+///
+/// The cause is not part of the message; walk it with
+/// [`source`](std::error::Error::source).
+///
+/// # JSON format
+///
+/// ```json
+/// {
+///   "code": "stage_failed",
+///   "stage": "graph",
+///   "message": "could not load embeddings",
+///   "source": "No such file or directory (os error 2)"
+/// }
+/// ```
+///
+/// `source` is the cause chain joined with `": "`, and is absent when there
+/// is no cause.
+#[derive(Error, Debug, Clone, Serialize)]
+#[error("[{code}] {stage}: {message}")]
+pub struct PipelineError {
+    /// Stable error code for programmatic matching.
+    pub code: ErrorCode,
+
+    /// Stage the error occurred in: one of the `STAGE_*` constants, or a
+    /// custom stage's [`name`](crate::pipeline::Stage::name).
+    pub stage: &'static str,
+
+    /// Human-readable description of the failure.
+    pub message: String,
+
+    /// The error that caused this one.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_source"
+    )]
+    pub source: Option<ErrorSource>,
+}
+
+impl PipelineError {
+    /// Create a new stage error.
+    pub fn new(code: ErrorCode, stage: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            stage,
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    /// Attach the error that caused this one.
+    pub fn with_source(mut self, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
+    /// The message followed by every cause in the chain, joined with `": "`.
+    pub fn message_with_causes(&self) -> String {
+        let mut out = self.message.clone();
+        if let Some(source) = &self.source {
+            out.push_str(": ");
+            out.push_str(&chain(source.as_ref()));
+        }
+        out
+    }
+}
+
+/// `From` conversion used by the runner: the stage name carries over, the
+/// cause chain is folded into the message, and `path` is left empty since
+/// the failure is not tied to a spec location.
+impl From<PipelineError> for PipelineRuntimeError {
+    fn from(err: PipelineError) -> Self {
+        PipelineRuntimeError::new(err.code, "", err.stage, err.message_with_causes())
+    }
+}
+
+fn chain(err: &(dyn std::error::Error + 'static)) -> String {
+    let mut out = err.to_string();
+    let mut next = err.source();
+    while let Some(cause) = next {
+        out.push_str(": ");
+        out.push_str(&cause.to_string());
+        next = cause.source();
+    }
+    out
+}
+
+fn serialize_source<S: Serializer>(
+    source: &Option<ErrorSource>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match source {
+        Some(source) => serializer.serialize_str(&chain(source.as_ref())),
+        None => serializer.serialize_none(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -312,4 +428,52 @@ mod tests {
         assert_eq!(spec.code, runtime.code);
         assert_eq!(spec.code, ErrorCode::InvalidValue);
     }
+
+    // ─── PipelineError ──────────────────────────────────────────────────
+
+    #[derive(Debug, Error)]
+    #[error("model file unreadable")]
+    struct ModelError(#[source] std::io::Error);
+
+    #[test]
+    fn test_pipeline_error_source_chain() {
+        use std::error::Error as _;
+
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "missing.bin");
+        let err = PipelineError::new(ErrorCode::StageFailed, "graph", "could not load embeddings")
+            .with_source(ModelError(io));
+
+        assert_eq!(
+            err.to_string(),
+            "[stage_failed] graph: could not load embeddings"
+        );
+        let source = err.source().unwrap();
+        assert_eq!(source.to_string(), "model file unreadable");
+        assert_eq!(source.source().unwrap().to_string(), "missing.bin");
+        assert_eq!(
+            err.message_with_causes(),
+            "could not load embeddings: model file unreadable: missing.bin"
+        );
+    }
+
+    #[test]
+    fn test_pipeline_error_json_and_runtime_conversion() {
+        let bare = PipelineError::new(ErrorCode::Cancelled, "rank", "stopped");
+        let value = serde_json::to_value(&bare).unwrap();
+        assert_eq!(value["code"], "cancelled");
+        assert_eq!(value["stage"], "rank");
+        assert!(value.get("source").is_none());
+
+        let err = bare.clone().with_source(std::io::Error::new(
+            std::io::ErrorKind::BrokenPipe,
+            "pipe closed",
+        ));
+        let value = serde_json::to_value(&err).unwrap();
+        assert_eq!(value["source"], "pipe closed");
+
+        let runtime = PipelineRuntimeError::from(err);
+        assert_eq!(runtime.code, ErrorCode::Cancelled);
+        assert_eq!(runtime.stage, "rank");
+        assert_eq!(runtime.message, "stopped: pipe closed");
+    }
 }
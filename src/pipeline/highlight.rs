@@ -0,0 +1,193 @@
+//! Keyphrase highlighting.
+//!
+//! [`Highlighter`] returns the source text with every occurrence of the
+//! extracted phrases wrapped in markup, using the byte spans carried on
+//! [`Phrase::spans`]. It is meant for demo and review tooling.
+//!
+//! Occurrences that overlap (e.g. "neural network" and "network models"
+//! over "neural network models") are merged into one highlighted region, so
+//! the markup never nests or interleaves. Spans that fall outside the text or
+//! off a character boundary are skipped, which happens when the phrases were
+//! extracted from a different text or from tokens with character offsets.
+//!
+//! # Example
+//!
+//! ```
+//! use rapid_textrank::pipeline::highlight::{Highlighter, HighlightMarkup};
+//! use rapid_textrank::extract_from_text;
+//!
+//! let text = "Graph ranking is fast. Graph ranking scales.";
+//! let phrases = extract_from_text(text, &Default::default());
+//!
+//! let html = Highlighter::new().highlight(text, &phrases);
+//! assert!(html.starts_with("<mark>Graph</mark> ranking"));
+//!
+//! let md = Highlighter::new()
+//!     .with_markup(HighlightMarkup::Markdown)
+//!     .highlight(text, &phrases);
+//! assert!(md.starts_with("**Graph** ranking"));
+//! ```
+
+use crate::types::Phrase;
+
+/// Markup wrapped around highlighted regions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum HighlightMarkup {
+    /// `<mark>…</mark>`; the rest of the text is HTML-escaped (default)
+    #[default]
+    Html,
+    /// `**…**`
+    Markdown,
+    /// Caller-supplied opening and closing strings, inserted verbatim
+    Custom { open: String, close: String },
+}
+
+impl HighlightMarkup {
+    fn delimiters(&self) -> (&str, &str) {
+        match self {
+            HighlightMarkup::Html => ("<mark>", "</mark>"),
+            HighlightMarkup::Markdown => ("**", "**"),
+            HighlightMarkup::Custom { open, close } => (open, close),
+        }
+    }
+}
+
+/// Wraps phrase occurrences in markup.
+#[derive(Debug, Clone, Default)]
+pub struct Highlighter {
+    markup: HighlightMarkup,
+    top_n: Option<usize>,
+}
+
+impl Highlighter {
+    /// HTML highlighter over all phrases.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the markup.
+    pub fn with_markup(mut self, markup: HighlightMarkup) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    /// Only highlight the first `n` phrases.
+    pub fn with_top_n(mut self, n: usize) -> Self {
+        self.top_n = Some(n);
+        self
+    }
+
+    /// Merged, sorted byte regions to highlight in `text`.
+    pub fn regions(&self, text: &str, phrases: &[Phrase]) -> Vec<(usize, usize)> {
+        let n = self.top_n.unwrap_or(phrases.len());
+        let mut spans: Vec<(usize, usize)> = phrases
+            .iter()
+            .take(n)
+            .flat_map(|p| p.spans.iter().copied())
+            .filter(|&(start, end)| start < end && text.get(start..end).is_some())
+            .collect();
+        spans.sort_unstable();
+
+        let mut regions: Vec<(usize, usize)> = Vec::with_capacity(spans.len());
+        for (start, end) in spans {
+            match regions.last_mut() {
+                Some(last) if start < last.1 => last.1 = last.1.max(end),
+                _ => regions.push((start, end)),
+            }
+        }
+        regions
+    }
+
+    /// Return `text` with the occurrences of `phrases` wrapped in markup.
+    pub fn highlight(&self, text: &str, phrases: &[Phrase]) -> String {
+        let (open, close) = self.markup.delimiters();
+        let escape = matches!(self.markup, HighlightMarkup::Html);
+        let push = |out: &mut String, s: &str| {
+            if escape {
+                push_html_escaped(out, s);
+            } else {
+                out.push_str(s);
+            }
+        };
+
+        let mut out = String::with_capacity(text.len());
+        let mut cursor = 0;
+        for (start, end) in self.regions(text, phrases) {
+            push(&mut out, &text[cursor..start]);
+            out.push_str(open);
+            push(&mut out, &text[start..end]);
+            out.push_str(close);
+            cursor = end;
+        }
+        push(&mut out, &text[cursor..]);
+        out
+    }
+}
+
+fn push_html_escaped(out: &mut String, s: &str) {
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phrase(text: &str, spans: &[(usize, usize)]) -> Phrase {
+        let mut p = Phrase::new(text, text, 1.0, spans.len());
+        p.spans = spans.to_vec();
+        p
+    }
+
+    #[test]
+    fn test_overlapping_and_repeated_spans() {
+        let text = "neural network models beat network models";
+        let phrases = [
+            phrase("neural network", &[(0, 14)]),
+            phrase("network models", &[(7, 21), (27, 41)]),
+        ];
+
+        let md = Highlighter::new()
+            .with_markup(HighlightMarkup::Markdown)
+            .highlight(text, &phrases);
+        assert_eq!(md, "**neural network models** beat **network models**");
+
+        let top1 = Highlighter::new()
+            .with_markup(HighlightMarkup::Markdown)
+            .with_top_n(1)
+            .highlight(text, &phrases);
+        assert_eq!(top1, "**neural network** models beat network models");
+    }
+
+    #[test]
+    fn test_html_escapes_and_skips_invalid_spans() {
+        let text = "R&D <café> budgets";
+        let phrases = [
+            phrase("R&D", &[(0, 3)]),
+            // Ends inside the two-byte 'é'.
+            phrase("caf", &[(5, 9)]),
+            phrase("budgets", &[(12, 19), (40, 47)]),
+        ];
+        let html = Highlighter::new().highlight(text, &phrases);
+        assert_eq!(
+            html,
+            "<mark>R&amp;D</mark> &lt;café&gt; <mark>budgets</mark>"
+        );
+
+        let custom = Highlighter::new()
+            .with_markup(HighlightMarkup::Custom {
+                open: "[".into(),
+                close: "]".into(),
+            })
+            .highlight(text, &phrases);
+        assert_eq!(custom, "[R&D] <café> [budgets]");
+    }
+}
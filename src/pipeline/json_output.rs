@@ -0,0 +1,250 @@
+//! Versioned JSON output.
+//!
+//! [`JsonOutput`] is a stable, documented serialization of a
+//! [`FormattedResult`] for downstream parsers. Select it in a pipeline spec
+//! with `"format": { "type": "versioned_json" }`, or build it directly with
+//! [`JsonOutput::from_result`].
+//!
+//! # Schema (version 1)
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "phrases": [
+//!     { "rank": 1, "surface": "Machine learning", "lemma": "machine learning",
+//!       "score": 0.42, "count": 2, "spans": [[0, 16], [60, 76]] }
+//!   ],
+//!   "document": {
+//!     "phrases": 1, "converged": true, "iterations": 23,
+//!     "tokens": 120, "candidates": 48, "nodes": 40, "edges": 95
+//!   },
+//!   "stage_timings": [ { "stage": "preprocess", "duration_us": 112 } ]
+//! }
+//! ```
+//!
+//! `spans` are byte offsets into the source text. The optional `document`
+//! counts are present only when known (see [`JsonOutput::with_stage_reports`]
+//! and [`DebugLevel::Stats`](super::artifacts::DebugLevel::Stats)), and
+//! `stage_timings` only when stage reports were attached.
+//!
+//! # Compatibility
+//!
+//! Within a schema version, fields are only ever added, never renamed,
+//! removed, or given a new meaning; parsers should ignore unknown fields.
+//! Any breaking change bumps [`JSON_SCHEMA_VERSION`].
+
+use serde::{Deserialize, Serialize};
+
+use super::artifacts::FormattedResult;
+use super::errors::PipelineRuntimeError;
+use super::observer::{StageReport, STAGE_CANDIDATES, STAGE_PREPROCESS};
+
+/// Current version of the [`JsonOutput`] schema.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Versioned JSON representation of a pipeline result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonOutput {
+    /// Schema version; see the [module docs](self) for guarantees.
+    pub schema_version: u32,
+    /// Phrases in rank order.
+    pub phrases: Vec<JsonOutputPhrase>,
+    /// Document-level statistics.
+    pub document: DocumentStats,
+    /// Per-stage wall-clock durations in execution order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage_timings: Option<Vec<StageTiming>>,
+    /// Structured error, when the pipeline failed (phrases are then empty).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<PipelineRuntimeError>,
+}
+
+/// One phrase in a [`JsonOutput`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonOutputPhrase {
+    /// 1-indexed rank.
+    pub rank: usize,
+    /// Canonical surface form.
+    pub surface: String,
+    /// Lemmatized form.
+    pub lemma: String,
+    /// Final score.
+    pub score: f64,
+    /// Number of occurrences.
+    pub count: usize,
+    /// Byte spans `[start, end)` of the occurrences in the source text.
+    pub spans: Vec<(usize, usize)>,
+}
+
+/// Document-level statistics in a [`JsonOutput`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentStats {
+    /// Number of returned phrases.
+    pub phrases: usize,
+    /// Whether ranking converged.
+    pub converged: bool,
+    /// Ranking iterations.
+    pub iterations: u32,
+    /// Tokens after preprocessing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<usize>,
+    /// Selected candidates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub candidates: Option<usize>,
+    /// Graph nodes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<usize>,
+    /// Graph edges.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<usize>,
+}
+
+/// Wall-clock duration of one stage in a [`JsonOutput`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageTiming {
+    /// Stage name (see the `STAGE_*` constants in [`super::observer`]).
+    pub stage: String,
+    /// Duration in microseconds.
+    pub duration_us: u64,
+}
+
+impl JsonOutput {
+    /// Build from a pipeline result.
+    ///
+    /// Graph node and edge counts are taken from the debug payload's graph
+    /// stats when present.
+    pub fn from_result(result: &FormattedResult) -> Self {
+        let graph_stats = result.debug.as_ref().and_then(|d| d.graph_stats.as_ref());
+        Self {
+            schema_version: JSON_SCHEMA_VERSION,
+            phrases: result
+                .phrases
+                .iter()
+                .map(|p| JsonOutputPhrase {
+                    rank: p.rank,
+                    surface: p.text.clone(),
+                    lemma: p.lemma.clone(),
+                    score: p.score,
+                    count: p.count,
+                    spans: p.spans.clone(),
+                })
+                .collect(),
+            document: DocumentStats {
+                phrases: result.phrases.len(),
+                converged: result.converged,
+                iterations: result.iterations,
+                tokens: None,
+                candidates: None,
+                nodes: graph_stats.map(|g| g.num_nodes),
+                edges: graph_stats.map(|g| g.num_edges),
+            },
+            stage_timings: None,
+            error: result.error.clone(),
+        }
+    }
+
+    /// Attach stage timings and fill document counts from stage reports,
+    /// e.g. [`StageTimingObserver::reports`](super::StageTimingObserver::reports).
+    ///
+    /// Node and edge counts come from the last report that carries them, so
+    /// a graph transform's output wins over the built graph.
+    pub fn with_stage_reports(mut self, reports: &[(&'static str, StageReport)]) -> Self {
+        let doc = &mut self.document;
+        for (stage, report) in reports {
+            match *stage {
+                STAGE_PREPROCESS => doc.tokens = report.tokens().or(doc.tokens),
+                STAGE_CANDIDATES => doc.candidates = report.candidates().or(doc.candidates),
+                _ => {}
+            }
+            doc.nodes = report.nodes().or(doc.nodes);
+            doc.edges = report.edges().or(doc.edges);
+        }
+        self.stage_timings = Some(
+            reports
+                .iter()
+                .map(|(stage, report)| StageTiming {
+                    stage: stage.to_string(),
+                    duration_us: report.duration_us(),
+                })
+                .collect(),
+        );
+        self
+    }
+
+    /// Serialize to a compact JSON string.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::observer::{StageReportBuilder, STAGE_GRAPH, STAGE_RANK};
+    use crate::types::Phrase;
+    use std::time::Duration;
+
+    fn sample_result() -> FormattedResult {
+        let mut phrase = Phrase::new("Machine learning", "machine learning", 0.42, 2);
+        phrase.rank = 1;
+        phrase.spans = vec![(0, 16), (60, 76)];
+        FormattedResult::new(vec![phrase], true, 23)
+    }
+
+    #[test]
+    fn test_from_result_schema() {
+        let output = JsonOutput::from_result(&sample_result());
+        let value: serde_json::Value = serde_json::from_str(&output.to_json().unwrap()).unwrap();
+
+        assert_eq!(value["schema_version"], JSON_SCHEMA_VERSION);
+        assert_eq!(value["phrases"][0]["surface"], "Machine learning");
+        assert_eq!(value["phrases"][0]["lemma"], "machine learning");
+        assert_eq!(value["phrases"][0]["rank"], 1);
+        assert_eq!(value["phrases"][0]["spans"][1][0], 60);
+        assert_eq!(value["document"]["phrases"], 1);
+        assert_eq!(value["document"]["iterations"], 23);
+        // Unknown counts and timings are omitted, not null.
+        assert!(value["document"].get("tokens").is_none());
+        assert!(value.get("stage_timings").is_none());
+        assert!(value.get("error").is_none());
+    }
+
+    #[test]
+    fn test_with_stage_reports() {
+        let reports = [
+            (
+                STAGE_PREPROCESS,
+                StageReportBuilder::new(Duration::from_micros(10))
+                    .tokens(120)
+                    .build(),
+            ),
+            (
+                STAGE_CANDIDATES,
+                StageReportBuilder::new(Duration::from_micros(20))
+                    .candidates(48)
+                    .build(),
+            ),
+            (
+                STAGE_GRAPH,
+                StageReportBuilder::new(Duration::from_micros(30))
+                    .nodes(40)
+                    .edges(95)
+                    .build(),
+            ),
+            (STAGE_RANK, StageReport::new(Duration::from_micros(40))),
+        ];
+        let output = JsonOutput::from_result(&sample_result()).with_stage_reports(&reports);
+
+        assert_eq!(output.document.tokens, Some(120));
+        assert_eq!(output.document.candidates, Some(48));
+        assert_eq!(output.document.nodes, Some(40));
+        assert_eq!(output.document.edges, Some(95));
+        let timings = output.stage_timings.as_ref().unwrap();
+        assert_eq!(timings.len(), 4);
+        assert_eq!(timings[3].stage, STAGE_RANK);
+        assert_eq!(timings[3].duration_us, 40);
+
+        let round_trip: JsonOutput = serde_json::from_str(&output.to_json().unwrap()).unwrap();
+        assert_eq!(round_trip, output);
+    }
+}
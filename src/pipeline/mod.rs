@@ -9,39 +9,93 @@
 //! - [`traits`] — Stage trait definitions (E3)
 //! - [`runner`] — Pipeline orchestration and artifact threading (E4)
 //! - [`observer`] — Logging, profiling, and debug hooks (E4)
+//! - [`custom_stage`] — User-defined stages inserted between built-in ones
+//! - [`plan`] — Dry-run description of a pipeline's stages and config
+//! - [`cancellation`] — Cancellation token and wall-clock budgets
+//! - [`cache`] — Upstream artifact cache for parameter sweeps
+//! - [`teleport`] — Reusable teleport weight functions
+//! - [`debug_dump`] — Observer dumping artifacts to JSON Lines
+//! - [`replay`] — Recording runs and replaying single stages against them
+//! - [`json_output`] — Versioned JSON output schema
+//! - [`delimited_output`] — CSV/TSV output, one row per phrase
+//! - [`highlight`] — Source text with keyphrase occurrences marked up
+//! - [`visualization`] — Ranked-graph JSON snapshots for D3/Gephi
 
 pub mod artifacts;
+pub mod cache;
+pub mod cancellation;
+pub(crate) mod clock;
+pub mod custom_stage;
+pub mod debug_dump;
+pub mod delimited_output;
 pub mod error_code;
 pub mod errors;
+pub mod highlight;
+pub mod json_output;
 pub mod observer;
+pub mod plan;
+pub mod replay;
 pub mod runner;
 pub mod spec;
 pub mod spec_builder;
+pub mod teleport;
 pub mod traits;
 pub mod validation;
+pub mod visualization;
 
 // Re-export artifact types for convenient access.
+#[cfg(feature = "bincode")]
+pub use artifacts::{from_bincode, to_bincode};
 pub use artifacts::{
     CandidateKind, CandidateSet, CandidateSetRef, ClusterAssignments, ConvergenceSummary,
-    DebugLevel, DebugPayload, FormattedResult, Graph, GraphStats, PhraseCandidate, PhraseEntry,
-    PhraseSet, PhraseSetRef, PipelineWorkspace, RankDiagnostics, RankOutput, SentenceCandidate,
-    TeleportType, TeleportVector, TokenEntry, TokenStream, TokenStreamRef, WordCandidate,
+    DebugLevel, DebugPayload, FormattedResult, Graph, GraphStats, NodeDetail, PhraseCandidate,
+    PhraseEntry, PhraseExplanation, PhraseSet, PhraseSetRef, PipelineWorkspace, RankDiagnostics,
+    RankOutput, RankedNodes, SentenceCandidate, TeleportType, TeleportVector, TokenEntry,
+    TokenStream, TokenStreamRef, WordCandidate,
+};
+
+// Re-export cache, cancellation token and custom stage types.
+pub use cache::PipelineCache;
+pub use cancellation::CancellationToken;
+pub use custom_stage::{CustomStages, Stage, StageArtifacts, StagePoint};
+
+// Re-export output writers.
+pub use delimited_output::{DelimitedWriter, Delimiter};
+pub use highlight::{HighlightMarkup, Highlighter};
+
+// Re-export versioned JSON output types.
+pub use json_output::{
+    DocumentStats, JsonOutput, JsonOutputPhrase, StageTiming, JSON_SCHEMA_VERSION,
 };
 
 // Re-export observer types.
+pub use debug_dump::DebugDumpObserver;
+#[cfg(feature = "metrics")]
+pub use observer::MetricsObserver;
+#[cfg(feature = "tracing")]
+pub use observer::TracingObserver;
 pub use observer::{
-    NoopObserver, PipelineObserver, StageClock, StageReport, StageReportBuilder,
-    StageTimingObserver, STAGE_CANDIDATES, STAGE_FORMAT, STAGE_GRAPH, STAGE_GRAPH_TRANSFORM,
-    STAGE_PHRASES, STAGE_PREPROCESS, STAGE_RANK, STAGE_TELEPORT,
+    BatchStatsObserver, NoopObserver, PipelineObserver, ProgressInterval, StageClock, StageReport,
+    StageReportBuilder, StageStats, StageTimingObserver, STAGE_CANDIDATES, STAGE_FORMAT,
+    STAGE_GRAPH, STAGE_GRAPH_TRANSFORM, STAGE_PHRASES, STAGE_PREPROCESS, STAGE_RANK,
+    STAGE_TELEPORT,
 };
+pub use replay::{ArtifactDiff, PipelineRecorder, PipelineReplayer};
+pub use visualization::{GraphSnapshot, SnapshotEdge, SnapshotNode, VisualizationObserver};
+
+// Re-export teleport weight functions.
+pub use teleport::{position_weights, PositionDecay};
+
+// Re-export dry-run plan types.
+pub use plan::{PipelinePlan, PlannedStage};
 
 // Re-export runner types (Pipeline, builder, type alias).
 #[cfg(feature = "sentence-rank")]
 pub use runner::SentenceRankPipeline;
 pub use runner::{
-    BaseTextRankPipeline, BiasedTextRankPipeline, MultipartiteRankPipeline, Pipeline,
-    PipelineBuilder, PositionRankPipeline, SingleRankPipeline, TopicRankPipeline,
-    TopicalPageRankPipeline,
+    BaseTextRankPipeline, BiasedTextRankPipeline, ExpandRankPipeline, MultipartiteRankPipeline,
+    Pipeline, PipelineBuilder, PositionRankPipeline, QueryBiasedTextRankPipeline,
+    SingleRankPipeline, TopicRankPipeline, TopicalPageRankPipeline,
 };
 
 // Re-export preset resolution, spec resolution, and module merging.
@@ -51,17 +105,28 @@ pub use spec::{merge_modules, resolve_preset, resolve_spec};
 pub use spec_builder::{DynPipeline, SpecPipelineBuilder};
 
 // Re-export stage traits and default implementations.
+#[cfg(feature = "embeddings")]
+pub use traits::EmbeddingDedupPhraseBuilder;
+#[cfg(feature = "pos-tagger")]
+pub use traits::PosTagPreprocessor;
 pub use traits::{
     AlphaBoostWeighter, CandidateGraphBuilder, CandidateSelector, ChunkPhraseBuilder, Clusterer,
-    CooccurrenceGraphBuilder, EdgeWeightPolicy, FocusTermsTeleportBuilder, GraphBuilder,
-    GraphTransform, IntraTopicEdgeRemover, JaccardHacClusterer, Linkage, MultipartitePhraseBuilder,
-    MultipartiteTransform, NoopClusterer, NoopGraphTransform, NoopPreprocessor, PageRankRanker,
-    PhraseBuilder, PhraseCandidateSelector, PositionTeleportBuilder, Preprocessor, Ranker,
-    ResultFormatter, StandardResultFormatter, TeleportBuilder, TopicGraphBuilder,
-    TopicRepresentativeBuilder, TopicWeightsTeleportBuilder, UniformTeleportBuilder,
-    WindowGraphBuilder, WindowStrategy, WordNodeSelector, DEFAULT_WINDOW_SIZE,
+    CooccurrenceGraphBuilder, DegreeRanker, DegreeThresholdTransform, EdgeThreshold,
+    EdgeWeightCutoff, EdgeWeightPolicy, ExpandGraphBuilder, FnTeleportBuilder,
+    FocusTermsTeleportBuilder, GrammarCandidateSelector, GraphBuilder, GraphTransform, HitsRanker,
+    IntraTopicEdgeRemover, JaccardHacClusterer, KCoreTransform, KeywordPhraseBuilder,
+    LemmaPreprocessor, Linkage, MetaVertexTransform, MultipartitePhraseBuilder,
+    MultipartiteTransform, NoopClusterer, NoopGraphTransform, NoopPreprocessor, NounChunkSelector,
+    PageRankRanker, PhraseBuilder, PhraseCandidateSelector, PosFallbackPreprocessor,
+    PositionDecayTeleportBuilder, PositionTeleportBuilder, Preprocessor, QuerySimilarity,
+    QueryTeleportBuilder, Ranker, ResultFormatter, SectionTeleportBuilder, SimilarityHacClusterer,
+    StandardResultFormatter, TeleportBuilder, TfIdfTeleportBuilder, TokenOverlapSimilarity,
+    TopicGraphBuilder, TopicRepresentativeBuilder, TopicWeightsTeleportBuilder,
+    UniformTeleportBuilder, WindowGraphBuilder, WindowStrategy, WordNodeSelector,
+    DEFAULT_WINDOW_SIZE,
 };
 #[cfg(feature = "sentence-rank")]
 pub use traits::{
     SentenceCandidateSelector, SentenceFormatter, SentenceGraphBuilder, SentencePhraseBuilder,
+    SentenceSimilarity,
 };
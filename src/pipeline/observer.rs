@@ -11,7 +11,11 @@
 //! are `Option` because different stages produce different metrics.
 
 use crate::pipeline::artifacts::{CandidateSet, Graph, PhraseSet, RankOutput, TokenStream};
-use std::time::{Duration, Instant};
+use crate::pipeline::clock::Instant;
+use crate::pipeline::errors::PipelineError;
+use crate::types::{RankerAcceleration, TextRankConfig};
+use std::ops::ControlFlow;
+use std::time::Duration;
 
 // ============================================================================
 // StageReport — per-stage metrics collected by the runner
@@ -22,13 +26,25 @@ use std::time::{Duration, Instant};
 /// Every stage produces a `duration_us`. The remaining fields are populated
 /// only by stages that have the relevant information:
 ///
-/// | Field        | Populated by            |
-/// |--------------|-------------------------|
-/// | `nodes`      | GraphBuilder            |
-/// | `edges`      | GraphBuilder            |
-/// | `iterations` | Ranker                  |
-/// | `converged`  | Ranker                  |
-/// | `residual`   | Ranker                  |
+/// | Field            | Populated by            |
+/// |------------------|-------------------------|
+/// | `tokens`         | Preprocessor            |
+/// | `candidates`     | CandidateSelector       |
+/// | `phrases`        | PhraseBuilder           |
+/// | `nodes`          | GraphBuilder            |
+/// | `edges`          | GraphBuilder            |
+/// | `iterations`     | Ranker                  |
+/// | `converged`      | Ranker                  |
+/// | `residual`       | Ranker                  |
+/// | `acceleration`   | Ranker                  |
+/// | `artifact_bytes` | Every built-in stage    |
+/// | `peak_bytes`     | Caller (allocator hook) |
+///
+/// `artifact_bytes` is an estimate of the heap held by the stage's output
+/// artifact (token stream, candidate set, graph, ...), computed from buffer
+/// capacities. `peak_bytes` needs an allocation-tracking global allocator,
+/// which the library does not install; custom runners can record it with
+/// [`StageReportBuilder::peak_bytes`].
 ///
 /// # Construction
 ///
@@ -50,6 +66,12 @@ use std::time::{Duration, Instant};
 pub struct StageReport {
     /// Wall-clock duration of the stage in microseconds.
     duration_us: u64,
+    /// Number of tokens after preprocessing (Preprocessor).
+    tokens: Option<usize>,
+    /// Number of candidates selected (CandidateSelector).
+    candidates: Option<usize>,
+    /// Number of phrases built (PhraseBuilder).
+    phrases: Option<usize>,
     /// Number of graph nodes produced (GraphBuilder).
     nodes: Option<usize>,
     /// Number of graph edges produced (GraphBuilder).
@@ -58,8 +80,14 @@ pub struct StageReport {
     iterations: Option<u32>,
     /// Whether the ranker converged within threshold (Ranker).
     converged: Option<bool>,
-    /// Final convergence residual / L1-norm delta (Ranker).
+    /// Final convergence residual in the configured norm (Ranker).
     residual: Option<f64>,
+    /// Convergence acceleration scheme requested from the ranker (Ranker).
+    acceleration: Option<RankerAcceleration>,
+    /// Peak bytes allocated while the stage ran.
+    peak_bytes: Option<u64>,
+    /// Estimated heap size of the stage's output artifact in bytes.
+    artifact_bytes: Option<u64>,
 }
 
 impl StageReport {
@@ -68,11 +96,17 @@ impl StageReport {
     pub fn new(duration: Duration) -> Self {
         Self {
             duration_us: duration.as_micros() as u64,
+            tokens: None,
+            candidates: None,
+            phrases: None,
             nodes: None,
             edges: None,
             iterations: None,
             converged: None,
             residual: None,
+            acceleration: None,
+            peak_bytes: None,
+            artifact_bytes: None,
         }
     }
 
@@ -94,6 +128,24 @@ impl StageReport {
         self.duration_us as f64 / 1000.0
     }
 
+    /// Number of tokens, if reported.
+    #[inline]
+    pub fn tokens(&self) -> Option<usize> {
+        self.tokens
+    }
+
+    /// Number of candidates, if reported.
+    #[inline]
+    pub fn candidates(&self) -> Option<usize> {
+        self.candidates
+    }
+
+    /// Number of phrases, if reported.
+    #[inline]
+    pub fn phrases(&self) -> Option<usize> {
+        self.phrases
+    }
+
     /// Number of graph nodes, if reported.
     #[inline]
     pub fn nodes(&self) -> Option<usize> {
@@ -118,11 +170,32 @@ impl StageReport {
         self.converged
     }
 
-    /// Final convergence residual (L1-norm delta), if reported.
+    /// Final convergence residual, if reported.
+    ///
+    /// Measured with the configured
+    /// [`ConvergenceCriterion`](crate::types::ConvergenceCriterion) (L1 by default).
     #[inline]
     pub fn residual(&self) -> Option<f64> {
         self.residual
     }
+
+    /// Convergence acceleration scheme, if reported.
+    #[inline]
+    pub fn acceleration(&self) -> Option<RankerAcceleration> {
+        self.acceleration
+    }
+
+    /// Peak bytes allocated during the stage, if reported.
+    #[inline]
+    pub fn peak_bytes(&self) -> Option<u64> {
+        self.peak_bytes
+    }
+
+    /// Estimated heap size of the stage's output artifact, if reported.
+    #[inline]
+    pub fn artifact_bytes(&self) -> Option<u64> {
+        self.artifact_bytes
+    }
 }
 
 // ============================================================================
@@ -159,6 +232,27 @@ impl StageReportBuilder {
         }
     }
 
+    /// Record the number of tokens.
+    #[inline]
+    pub fn tokens(mut self, n: usize) -> Self {
+        self.report.tokens = Some(n);
+        self
+    }
+
+    /// Record the number of candidates.
+    #[inline]
+    pub fn candidates(mut self, n: usize) -> Self {
+        self.report.candidates = Some(n);
+        self
+    }
+
+    /// Record the number of phrases.
+    #[inline]
+    pub fn phrases(mut self, n: usize) -> Self {
+        self.report.phrases = Some(n);
+        self
+    }
+
     /// Record the number of graph nodes.
     #[inline]
     pub fn nodes(mut self, n: usize) -> Self {
@@ -194,6 +288,27 @@ impl StageReportBuilder {
         self
     }
 
+    /// Record the convergence acceleration scheme.
+    #[inline]
+    pub fn acceleration(mut self, a: RankerAcceleration) -> Self {
+        self.report.acceleration = Some(a);
+        self
+    }
+
+    /// Record the peak bytes allocated during the stage.
+    #[inline]
+    pub fn peak_bytes(mut self, n: u64) -> Self {
+        self.report.peak_bytes = Some(n);
+        self
+    }
+
+    /// Record the estimated heap size of the stage's output artifact.
+    #[inline]
+    pub fn artifact_bytes(mut self, n: u64) -> Self {
+        self.report.artifact_bytes = Some(n);
+        self
+    }
+
     /// Consume the builder and return the finished [`StageReport`].
     #[inline]
     pub fn build(self) -> StageReport {
@@ -245,6 +360,10 @@ pub trait PipelineObserver {
     /// Called after a stage completes, with its [`StageReport`] metrics.
     fn on_stage_end(&mut self, _stage: &'static str, _report: &StageReport) {}
 
+    /// Called once at the start of each run with the config the run uses,
+    /// after per-document adjustments such as language detection.
+    fn on_config(&mut self, _cfg: &TextRankConfig) {}
+
     /// Called after the Preprocessor stage with the (possibly mutated) token stream.
     fn on_tokens(&mut self, _tokens: &TokenStream) {}
 
@@ -259,6 +378,55 @@ pub trait PipelineObserver {
 
     /// Called after the PhraseBuilder stage with the assembled phrases.
     fn on_phrases(&mut self, _phrases: &PhraseSet) {}
+
+    /// How often the intra-stage progress hooks below fire.
+    ///
+    /// `None` (the default) disables them, so the graph and rank loops run
+    /// without callbacks.
+    fn progress_interval(&self) -> Option<ProgressInterval> {
+        None
+    }
+
+    /// Called during graph construction with the number of tokens walked so
+    /// far and the total, about every [`ProgressInterval::graph_tokens`]
+    /// tokens and once at the end.
+    fn on_graph_progress(&mut self, _tokens_done: usize, _tokens_total: usize) {}
+
+    /// Called during ranking every [`ProgressInterval::rank_iterations`]
+    /// iterations with the 1-based iteration number and current residual.
+    ///
+    /// Returning [`ControlFlow::Break`] stops the ranker after this
+    /// iteration; the scores so far are used and the rank stage reports
+    /// `converged = false` unless the residual is already under threshold.
+    /// Rankers without per-iteration hooks call this once, after they finish.
+    fn on_rank_iteration(&mut self, _iteration: u32, _residual: f64) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    /// Called for a non-fatal problem the run recovered from, e.g. PageRank
+    /// not converging under
+    /// [`NonConvergencePolicy::WarnAndContinue`](crate::types::NonConvergencePolicy::WarnAndContinue).
+    fn on_warning(&mut self, _warning: &PipelineError) {}
+}
+
+/// Granularity of the intra-stage progress hooks, returned by
+/// [`PipelineObserver::progress_interval`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgressInterval {
+    /// Report every this many rank iterations (0 is treated as 1).
+    pub rank_iterations: u32,
+    /// Report after roughly this many tokens of graph construction (0 is
+    /// treated as 1).
+    pub graph_tokens: usize,
+}
+
+impl Default for ProgressInterval {
+    fn default() -> Self {
+        Self {
+            rank_iterations: 1,
+            graph_tokens: 10_000,
+        }
+    }
 }
 
 /// Well-known stage name constants used in observer callbacks.
@@ -349,6 +517,288 @@ impl PipelineObserver for StageTimingObserver {
     }
 }
 
+// ============================================================================
+// BatchStatsObserver — distributions of stage metrics across documents
+// ============================================================================
+
+/// Duration distribution of one stage across a batch, from
+/// [`BatchStatsObserver::stage_stats`].
+///
+/// Percentiles use the nearest-rank method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StageStats {
+    /// Number of reports for the stage.
+    pub count: usize,
+    /// Mean duration.
+    pub mean: Duration,
+    /// Median duration.
+    pub p50: Duration,
+    /// 95th-percentile duration.
+    pub p95: Duration,
+    /// 99th-percentile duration.
+    pub p99: Duration,
+    /// Longest duration.
+    pub max: Duration,
+}
+
+/// Aggregates [`StageReport`]s across many documents into distributions.
+///
+/// Keeps each stage's durations (for percentiles), graph sizes and rank
+/// convergence outcomes rather than a report per document. Reuse one
+/// observer across sequential runs, or give each worker its own and
+/// [`merge`](Self::merge) them.
+///
+/// ```
+/// # use rapid_textrank::pipeline::observer::{BatchStatsObserver, PipelineObserver, StageReport, STAGE_RANK};
+/// # use std::time::Duration;
+/// let mut stats = BatchStatsObserver::new();
+/// for ms in 1..=100 {
+///     stats.on_stage_end(STAGE_RANK, &StageReport::new(Duration::from_millis(ms)));
+/// }
+///
+/// let rank = stats.stage_stats(STAGE_RANK).unwrap();
+/// assert_eq!(rank.p50, Duration::from_millis(50));
+/// assert_eq!(rank.p99, Duration::from_millis(99));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BatchStatsObserver {
+    /// Per-stage durations in microseconds, stages in first-seen order.
+    durations: Vec<(&'static str, Vec<u64>)>,
+    nodes: Vec<usize>,
+    edges: Vec<usize>,
+    /// Rank stages reporting convergence.
+    ranked: usize,
+    /// Rank stages reporting non-convergence.
+    not_converged: usize,
+}
+
+impl BatchStatsObserver {
+    /// Create an empty observer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold in the reports collected by a [`StageTimingObserver`].
+    pub fn record_timings(&mut self, timings: &StageTimingObserver) {
+        for (stage, report) in timings.reports() {
+            self.on_stage_end(stage, report);
+        }
+    }
+
+    /// Fold in the statistics of another observer.
+    pub fn merge(&mut self, other: &Self) {
+        for (stage, durations) in &other.durations {
+            self.stage_durations(stage).extend_from_slice(durations);
+        }
+        self.nodes.extend_from_slice(&other.nodes);
+        self.edges.extend_from_slice(&other.edges);
+        self.ranked += other.ranked;
+        self.not_converged += other.not_converged;
+    }
+
+    /// Stage names seen, in first-seen order.
+    pub fn stages(&self) -> Vec<&'static str> {
+        self.durations.iter().map(|(stage, _)| *stage).collect()
+    }
+
+    /// Duration distribution of `stage`, or `None` if it never ran.
+    pub fn stage_stats(&self, stage: &str) -> Option<StageStats> {
+        let (_, durations) = self.durations.iter().find(|(s, _)| *s == stage)?;
+        let mut sorted = durations.clone();
+        sorted.sort_unstable();
+        let count = sorted.len();
+        let percentile = |p: f64| {
+            let rank = (p / 100.0 * count as f64).ceil() as usize;
+            Duration::from_micros(sorted[rank.clamp(1, count) - 1])
+        };
+        Some(StageStats {
+            count,
+            mean: Duration::from_micros(sorted.iter().sum::<u64>() / count as u64),
+            p50: percentile(50.0),
+            p95: percentile(95.0),
+            p99: percentile(99.0),
+            max: Duration::from_micros(sorted[count - 1]),
+        })
+    }
+
+    /// Mean number of graph nodes, or `None` before any graph stage.
+    pub fn mean_nodes(&self) -> Option<f64> {
+        mean(&self.nodes)
+    }
+
+    /// Mean number of graph edges, or `None` before any graph stage.
+    pub fn mean_edges(&self) -> Option<f64> {
+        mean(&self.edges)
+    }
+
+    /// Number of rank stages that reported convergence either way.
+    pub fn ranked(&self) -> usize {
+        self.ranked
+    }
+
+    /// Fraction of rank stages that did not converge, or `None` before any.
+    pub fn convergence_failure_rate(&self) -> Option<f64> {
+        (self.ranked > 0).then(|| self.not_converged as f64 / self.ranked as f64)
+    }
+
+    fn stage_durations(&mut self, stage: &'static str) -> &mut Vec<u64> {
+        let i = match self.durations.iter().position(|(s, _)| *s == stage) {
+            Some(i) => i,
+            None => {
+                self.durations.push((stage, Vec::new()));
+                self.durations.len() - 1
+            }
+        };
+        &mut self.durations[i].1
+    }
+}
+
+fn mean(values: &[usize]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<usize>() as f64 / values.len() as f64)
+}
+
+impl PipelineObserver for BatchStatsObserver {
+    fn on_stage_end(&mut self, stage: &'static str, report: &StageReport) {
+        self.stage_durations(stage).push(report.duration_us());
+        if let Some(nodes) = report.nodes() {
+            self.nodes.push(nodes);
+        }
+        if let Some(edges) = report.edges() {
+            self.edges.push(edges);
+        }
+        if let Some(converged) = report.converged() {
+            self.ranked += 1;
+            if !converged {
+                self.not_converged += 1;
+            }
+        }
+    }
+}
+
+// ============================================================================
+// TracingObserver — spans and events via `tracing`
+// ============================================================================
+
+/// Emits a `tracing` span per stage and an event carrying its [`StageReport`].
+///
+/// Each stage runs inside an INFO-level `stage` span (field `stage`), so
+/// anything the stage itself logs nests under it. When the stage ends, an
+/// INFO event with target `rapid_textrank::stage` is emitted inside the
+/// span with `stage`, `duration_us` and whichever optional report fields are
+/// set (`tokens`, `candidates`, `phrases`, `nodes`, `edges`, `iterations`,
+/// `converged`, `residual`, `acceleration`, `peak_bytes`, `artifact_bytes`). Any subscriber — `tracing-subscriber`, an OpenTelemetry
+/// layer — picks these up without extra glue.
+///
+/// The entered span is held between `on_stage_start` and `on_stage_end`, so
+/// this observer is not `Send`; create one per thread.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Default)]
+pub struct TracingObserver {
+    current: Option<tracing::span::EnteredSpan>,
+}
+
+#[cfg(feature = "tracing")]
+impl TracingObserver {
+    /// Create an observer with no stage in progress.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl PipelineObserver for TracingObserver {
+    fn on_stage_start(&mut self, stage: &'static str) {
+        self.current = Some(tracing::info_span!("stage", stage).entered());
+    }
+
+    fn on_stage_end(&mut self, stage: &'static str, report: &StageReport) {
+        tracing::info!(
+            target: "rapid_textrank::stage",
+            stage,
+            duration_us = report.duration_us(),
+            tokens = report.tokens(),
+            candidates = report.candidates(),
+            phrases = report.phrases(),
+            nodes = report.nodes(),
+            edges = report.edges(),
+            iterations = report.iterations(),
+            converged = report.converged(),
+            residual = report.residual(),
+            acceleration = report.acceleration().map(tracing::field::debug),
+            peak_bytes = report.peak_bytes(),
+            artifact_bytes = report.artifact_bytes(),
+        );
+        self.current = None;
+    }
+
+    fn on_warning(&mut self, warning: &PipelineError) {
+        tracing::warn!(
+            target: "rapid_textrank::stage",
+            stage = warning.stage,
+            code = %warning.code,
+            "{}",
+            warning.message,
+        );
+    }
+}
+
+// ============================================================================
+// MetricsObserver — dashboards via the `metrics` facade
+// ============================================================================
+
+/// Records per-stage metrics through the [`metrics`] facade.
+///
+/// Whatever recorder the host service installs (Prometheus exporter,
+/// StatsD, ...) receives:
+///
+/// | Metric | Kind | Labels | Source |
+/// |--------|------|--------|--------|
+/// | `rapid_textrank_stage_duration_seconds` | histogram | `stage` | every stage |
+/// | `rapid_textrank_stage_artifact_bytes` | gauge | `stage` | `artifact_bytes` |
+/// | `rapid_textrank_graph_nodes` | gauge | — | graph stage |
+/// | `rapid_textrank_graph_edges` | gauge | — | graph stage |
+/// | `rapid_textrank_rank_iterations` | histogram | — | rank stage |
+/// | `rapid_textrank_rank_total` | counter | `converged` | rank stage |
+///
+/// Without an installed recorder every call is a no-op. The observer holds
+/// no state, so one value can be shared by copy across threads.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsObserver;
+
+#[cfg(feature = "metrics")]
+impl MetricsObserver {
+    /// Create the observer.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl PipelineObserver for MetricsObserver {
+    fn on_stage_end(&mut self, stage: &'static str, report: &StageReport) {
+        metrics::histogram!("rapid_textrank_stage_duration_seconds", "stage" => stage)
+            .record(report.duration().as_secs_f64());
+        if let Some(bytes) = report.artifact_bytes() {
+            metrics::gauge!("rapid_textrank_stage_artifact_bytes", "stage" => stage)
+                .set(bytes as f64);
+        }
+        if let Some(nodes) = report.nodes() {
+            metrics::gauge!("rapid_textrank_graph_nodes").set(nodes as f64);
+        }
+        if let Some(edges) = report.edges() {
+            metrics::gauge!("rapid_textrank_graph_edges").set(edges as f64);
+        }
+        if let Some(iterations) = report.iterations() {
+            metrics::histogram!("rapid_textrank_rank_iterations").record(iterations);
+        }
+        if let Some(converged) = report.converged() {
+            let label = if converged { "true" } else { "false" };
+            metrics::counter!("rapid_textrank_rank_total", "converged" => label).increment(1);
+        }
+    }
+}
+
 // ============================================================================
 // StageClock — lightweight timer helper
 // ============================================================================
@@ -404,6 +854,7 @@ mod tests {
         assert!(report.iterations().is_none());
         assert!(report.converged().is_none());
         assert!(report.residual().is_none());
+        assert!(report.acceleration().is_none());
     }
 
     #[test]
@@ -440,14 +891,42 @@ mod tests {
             .iterations(42)
             .converged(true)
             .residual(1e-7)
+            .acceleration(RankerAcceleration::Aitken)
             .build();
 
         assert_eq!(report.iterations(), Some(42));
         assert_eq!(report.converged(), Some(true));
         assert!((report.residual().unwrap() - 1e-7).abs() < f64::EPSILON);
+        assert_eq!(report.acceleration(), Some(RankerAcceleration::Aitken));
         assert!(report.nodes().is_none());
     }
 
+    #[test]
+    fn test_builder_count_fields() {
+        let report = StageReportBuilder::new(Duration::from_micros(10))
+            .tokens(120)
+            .candidates(40)
+            .phrases(0)
+            .build();
+
+        assert_eq!(report.tokens(), Some(120));
+        assert_eq!(report.candidates(), Some(40));
+        assert_eq!(report.phrases(), Some(0));
+        assert!(report.nodes().is_none());
+    }
+
+    #[test]
+    fn test_builder_memory_fields() {
+        let report = StageReportBuilder::new(Duration::from_micros(10))
+            .peak_bytes(4096)
+            .artifact_bytes(1024)
+            .build();
+
+        assert_eq!(report.peak_bytes(), Some(4096));
+        assert_eq!(report.artifact_bytes(), Some(1024));
+        assert!(StageReport::new(Duration::ZERO).artifact_bytes().is_none());
+    }
+
     #[test]
     fn test_builder_all_fields() {
         let report = StageReportBuilder::new(Duration::from_micros(999))
@@ -571,6 +1050,64 @@ mod tests {
         assert_eq!(obs.total_duration(), Duration::ZERO);
     }
 
+    // -- BatchStatsObserver tests -------------------------------------------
+
+    #[test]
+    fn test_batch_stats_percentiles_and_means() {
+        let mut stats = BatchStatsObserver::new();
+        for ms in 1..=20 {
+            stats.on_stage_end(STAGE_GRAPH, &StageReport::new(Duration::from_millis(ms)));
+        }
+        stats.on_stage_end(
+            STAGE_GRAPH,
+            &StageReportBuilder::new(Duration::from_millis(1))
+                .nodes(10)
+                .edges(30)
+                .build(),
+        );
+        stats.on_stage_end(
+            STAGE_GRAPH,
+            &StageReportBuilder::new(Duration::from_millis(1))
+                .nodes(20)
+                .edges(50)
+                .build(),
+        );
+
+        let graph = stats.stage_stats(STAGE_GRAPH).unwrap();
+        assert_eq!(graph.count, 22);
+        assert_eq!(graph.p50, Duration::from_millis(9));
+        assert_eq!(graph.p95, Duration::from_millis(19));
+        assert_eq!(graph.max, Duration::from_millis(20));
+        assert_eq!(stats.mean_nodes(), Some(15.0));
+        assert_eq!(stats.mean_edges(), Some(40.0));
+        assert!(stats.stage_stats(STAGE_RANK).is_none());
+        assert_eq!(stats.convergence_failure_rate(), None);
+    }
+
+    #[test]
+    fn test_batch_stats_convergence_and_merge() {
+        let rank = |converged| {
+            StageReportBuilder::new(Duration::from_micros(5))
+                .converged(converged)
+                .build()
+        };
+        let mut a = BatchStatsObserver::new();
+        a.on_stage_end(STAGE_RANK, &rank(true));
+        a.on_stage_end(STAGE_RANK, &rank(false));
+        let mut timings = StageTimingObserver::new();
+        timings.on_stage_end(STAGE_PHRASES, &StageReport::new(Duration::from_micros(7)));
+        timings.on_stage_end(STAGE_RANK, &rank(true));
+        let mut b = BatchStatsObserver::new();
+        b.record_timings(&timings);
+        b.on_stage_end(STAGE_RANK, &rank(true));
+
+        a.merge(&b);
+        assert_eq!(a.stages(), vec![STAGE_RANK, STAGE_PHRASES]);
+        assert_eq!(a.ranked(), 4);
+        assert_eq!(a.convergence_failure_rate(), Some(0.25));
+        assert_eq!(a.stage_stats(STAGE_RANK).unwrap().count, 4);
+    }
+
     /// Custom observer that records stage names for testing generic dispatch.
     struct RecordingObserver {
         started: Vec<&'static str>,
@@ -649,4 +1186,173 @@ mod tests {
             "Stage name constants must be unique"
         );
     }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_tracing_observer_emits_span_and_event() {
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// Records span names and the fields of every event.
+        #[derive(Default)]
+        struct Capture {
+            spans: Mutex<Vec<&'static str>>,
+            events: Mutex<Vec<Vec<(String, String)>>>,
+        }
+
+        struct Fields(Vec<(String, String)>);
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0
+                    .push((field.name().to_string(), format!("{value:?}")));
+            }
+        }
+
+        struct Sub(Arc<Capture>);
+        impl Subscriber for Sub {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+                let mut spans = self.0.spans.lock().unwrap();
+                spans.push(attrs.metadata().name());
+                Id::from_u64(spans.len() as u64)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, event: &Event<'_>) {
+                let mut fields = Fields(Vec::new());
+                event.record(&mut fields);
+                self.0.events.lock().unwrap().push(fields.0);
+            }
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let capture = Arc::new(Capture::default());
+        tracing::subscriber::with_default(Sub(capture.clone()), || {
+            let mut obs = TracingObserver::new();
+            obs.on_stage_start(STAGE_RANK);
+            let report = StageReportBuilder::new(Duration::from_micros(7))
+                .iterations(3)
+                .converged(true)
+                .build();
+            obs.on_stage_end(STAGE_RANK, &report);
+        });
+
+        assert_eq!(*capture.spans.lock().unwrap(), vec!["stage"]);
+        let events = capture.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let get = |name: &str| {
+            events[0]
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(get("stage"), Some("\"rank\""));
+        assert_eq!(get("duration_us"), Some("7"));
+        assert_eq!(get("iterations"), Some("3"));
+        assert_eq!(get("converged"), Some("true"));
+        // Unset optional fields are omitted.
+        assert_eq!(get("nodes"), None);
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_metrics_observer_records_stage_metrics() {
+        use metrics::{
+            Counter, CounterFn, Gauge, GaugeFn, Histogram, HistogramFn, Key, KeyName, Metadata,
+            Recorder, SharedString, Unit,
+        };
+        use std::sync::{Arc, Mutex};
+
+        type Log = Arc<Mutex<Vec<(String, f64)>>>;
+
+        /// Logs every update as `name{labels}` and value.
+        struct Handle(String, Log);
+        impl CounterFn for Handle {
+            fn increment(&self, value: u64) {
+                self.1.lock().unwrap().push((self.0.clone(), value as f64));
+            }
+            fn absolute(&self, value: u64) {
+                CounterFn::increment(self, value);
+            }
+        }
+        impl GaugeFn for Handle {
+            fn increment(&self, value: f64) {
+                self.set(value);
+            }
+            fn decrement(&self, value: f64) {
+                self.set(-value);
+            }
+            fn set(&self, value: f64) {
+                self.1.lock().unwrap().push((self.0.clone(), value));
+            }
+        }
+        impl HistogramFn for Handle {
+            fn record(&self, value: f64) {
+                self.1.lock().unwrap().push((self.0.clone(), value));
+            }
+        }
+
+        struct Capture(Log);
+        impl Capture {
+            fn handle(&self, key: &Key) -> Arc<Handle> {
+                let labels: Vec<String> = key
+                    .labels()
+                    .map(|l| format!("{}={}", l.key(), l.value()))
+                    .collect();
+                let name = format!("{}{{{}}}", key.name(), labels.join(","));
+                Arc::new(Handle(name, self.0.clone()))
+            }
+        }
+        impl Recorder for Capture {
+            fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+            fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+            fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+            fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+                Counter::from_arc(self.handle(key))
+            }
+            fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+                Gauge::from_arc(self.handle(key))
+            }
+            fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+                Histogram::from_arc(self.handle(key))
+            }
+        }
+
+        let recorder = Capture(Log::default());
+        metrics::with_local_recorder(&recorder, || {
+            let mut obs = MetricsObserver::new();
+            let graph = StageReportBuilder::new(Duration::from_millis(2))
+                .nodes(10)
+                .edges(24)
+                .artifact_bytes(512)
+                .build();
+            obs.on_stage_end(STAGE_GRAPH, &graph);
+            let rank = StageReportBuilder::new(Duration::from_millis(1))
+                .iterations(17)
+                .converged(false)
+                .build();
+            obs.on_stage_end(STAGE_RANK, &rank);
+        });
+
+        let log = recorder.0.lock().unwrap();
+        let expected = [
+            ("rapid_textrank_stage_duration_seconds{stage=graph}", 0.002),
+            ("rapid_textrank_stage_artifact_bytes{stage=graph}", 512.0),
+            ("rapid_textrank_graph_nodes{}", 10.0),
+            ("rapid_textrank_graph_edges{}", 24.0),
+            ("rapid_textrank_stage_duration_seconds{stage=rank}", 0.001),
+            ("rapid_textrank_rank_iterations{}", 17.0),
+            ("rapid_textrank_rank_total{converged=false}", 1.0),
+        ];
+        assert_eq!(log.len(), expected.len());
+        for ((name, value), (want_name, want_value)) in log.iter().zip(expected) {
+            assert_eq!(name, want_name);
+            assert!((value - want_value).abs() < 1e-12, "{name}: {value}");
+        }
+    }
 }
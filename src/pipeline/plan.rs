@@ -0,0 +1,77 @@
+//! Dry-run description of a pipeline.
+//!
+//! [`Pipeline::explain`](crate::pipeline::Pipeline::explain) resolves what a
+//! run *would* do without touching any tokens: the stages in execution
+//! order (custom stages included), the implementation and parameters behind
+//! each one, and the full effective [`TextRankConfig`] with every default
+//! filled in. [`SpecPipelineBuilder::explain`](crate::pipeline::SpecPipelineBuilder::explain)
+//! does the same for a spec and also records the resolved spec.
+//!
+//! The plan serializes to JSON, so it can be logged next to a result to
+//! audit exactly which configuration produced it.
+//!
+//! # Example
+//!
+//! ```
+//! use rapid_textrank::pipeline::{BaseTextRankPipeline, STAGE_RANK};
+//! use rapid_textrank::TextRankConfig;
+//!
+//! let plan = BaseTextRankPipeline::base_textrank().explain(&TextRankConfig::default());
+//! assert_eq!(plan.stage(STAGE_RANK).unwrap().implementation, "PageRankRanker");
+//! assert_eq!(plan.config.damping, 0.85);
+//! ```
+
+use crate::pipeline::spec::PipelineSpecV1;
+use crate::types::TextRankConfig;
+use serde::Serialize;
+use std::fmt;
+
+/// What a pipeline will run, in order, and with which configuration.
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelinePlan {
+    /// Stages in execution order.
+    pub stages: Vec<PlannedStage>,
+    /// Effective configuration, defaults included.
+    pub config: TextRankConfig,
+    /// The resolved spec (preset expanded, modules merged), when the plan
+    /// came from a spec.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<PipelineSpecV1>,
+}
+
+/// One stage of a [`PipelinePlan`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedStage {
+    /// Stage name: one of the `STAGE_*` constants for built-in stages, or
+    /// [`Stage::name`](crate::pipeline::Stage::name) for custom ones.
+    pub name: &'static str,
+    /// The implementation and its parameters (see e.g.
+    /// [`GraphBuilder::describe`](crate::pipeline::GraphBuilder::describe)).
+    pub implementation: String,
+    /// Whether this is a user-registered custom stage.
+    pub custom: bool,
+}
+
+impl PipelinePlan {
+    /// The first stage called `name`.
+    pub fn stage(&self, name: &str) -> Option<&PlannedStage> {
+        self.stages.iter().find(|s| s.name == name)
+    }
+
+    /// Plan as a JSON string.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+}
+
+/// One `name: implementation` line per stage, then the config as JSON.
+impl fmt::Display for PipelinePlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for stage in &self.stages {
+            let marker = if stage.custom { " (custom)" } else { "" };
+            writeln!(f, "{}{}: {}", stage.name, marker, stage.implementation)?;
+        }
+        let config = serde_json::to_string(&self.config).map_err(|_| fmt::Error)?;
+        write!(f, "config: {config}")
+    }
+}
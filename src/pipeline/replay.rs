@@ -0,0 +1,487 @@
+//! Record/replay of pipeline runs for golden testing.
+//!
+//! [`PipelineRecorder`] is an observer that captures every intermediate
+//! artifact of each run, plus the config it ran with (as passed to
+//! [`PipelineObserver::on_config`], so per-document adjustments such as
+//! language detection are included), as JSON files in one directory per
+//! run:
+//!
+//! | File              | Artifact                                          |
+//! |-------------------|---------------------------------------------------|
+//! | `config.json`     | [`TextRankConfig`]                                |
+//! | `tokens.json`     | [`TokenStream`] after preprocessing               |
+//! | `candidates.json` | [`CandidateSet`]                                  |
+//! | `graph.json`      | [`Graph`] after graph transforms                  |
+//! | `rank.json`       | [`RankOutput`]                                    |
+//! | `phrases.json`    | [`PhraseSet`]                                     |
+//!
+//! [`PipelineReplayer`] loads a recorded run, re-runs a single stage against
+//! the recorded inputs, and diffs the output against the recorded artifact,
+//! so a stage refactor can be checked against golden runs recorded from
+//! production documents.
+
+use crate::pipeline::artifacts::{CandidateSet, Graph, PhraseSet, RankOutput, TokenStream};
+use crate::pipeline::observer::PipelineObserver;
+use crate::pipeline::traits::{
+    CandidateSelector, GraphBuilder, GraphTransform, PhraseBuilder, Ranker, TeleportBuilder,
+};
+use crate::types::TextRankConfig;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Default absolute tolerance for comparing numbers in [`ArtifactDiff`]s.
+pub const DEFAULT_REPLAY_TOLERANCE: f64 = 1e-9;
+
+/// Differences listed per [`ArtifactDiff`] before the rest are only counted.
+const MAX_LISTED_DIFFERENCES: usize = 50;
+
+/// Observer recording each run's config and artifacts to a directory.
+///
+/// Run `n` (0-based, advanced by each `on_tokens`) is written to
+/// `<dir>/run_<n>/`. I/O errors cannot be returned from observer callbacks;
+/// the first one is kept and reported by [`finish`](Self::finish), and later
+/// writes are skipped. A config with a [`NodeKey::Custom`](crate::NodeKey::Custom)
+/// key cannot be serialized, so recording it fails the same way.
+///
+/// ```no_run
+/// use rapid_textrank::pipeline::replay::PipelineRecorder;
+/// use rapid_textrank::pipeline::runner::BaseTextRankPipeline;
+/// use rapid_textrank::TextRankConfig;
+///
+/// let cfg = TextRankConfig::default();
+/// let mut recorder = PipelineRecorder::new("/tmp/textrank-golden")?;
+/// BaseTextRankPipeline::base_textrank().run_text("Graphs have nodes.", &cfg, &mut recorder);
+/// recorder.finish()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct PipelineRecorder {
+    dir: PathBuf,
+    /// Config of the current run, written with its tokens.
+    config: Option<TextRankConfig>,
+    /// Index of the current run; advanced by each `on_tokens`.
+    doc: Option<usize>,
+    error: Option<io::Error>,
+}
+
+impl PipelineRecorder {
+    /// Create `dir` if needed; runs are recorded into it.
+    pub fn new(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            config: None,
+            doc: None,
+            error: None,
+        })
+    }
+
+    /// Directory the runs are recorded into.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Directories of the runs recorded so far, in run order.
+    pub fn run_dirs(&self) -> Vec<PathBuf> {
+        let runs = self.doc.map_or(0, |d| d + 1);
+        (0..runs).map(|doc| self.run_dir(doc)).collect()
+    }
+
+    /// Return the first I/O error seen, if any.
+    pub fn finish(self) -> io::Result<()> {
+        self.error.map_or(Ok(()), Err)
+    }
+
+    fn run_dir(&self, doc: usize) -> PathBuf {
+        self.dir.join(format!("run_{doc}"))
+    }
+
+    /// Write `artifact` to `name` in the current run's directory.
+    fn record(&mut self, name: &str, artifact: &impl Serialize) {
+        if self.error.is_some() {
+            return;
+        }
+        let path = self.run_dir(self.doc.unwrap_or(0)).join(name);
+        if let Err(err) = write_json(&path, artifact) {
+            self.error = Some(err);
+        }
+    }
+}
+
+impl PipelineObserver for PipelineRecorder {
+    fn on_config(&mut self, cfg: &TextRankConfig) {
+        self.config = Some(cfg.clone());
+    }
+
+    fn on_tokens(&mut self, tokens: &TokenStream) {
+        let doc = self.doc.map_or(0, |d| d + 1);
+        self.doc = Some(doc);
+        if self.error.is_none() {
+            if let Err(err) = std::fs::create_dir_all(self.run_dir(doc)) {
+                self.error = Some(err);
+            }
+        }
+        if let Some(config) = self.config.take() {
+            self.record("config.json", &config);
+        }
+        self.record("tokens.json", tokens);
+    }
+
+    fn on_candidates(&mut self, candidates: &CandidateSet) {
+        self.record("candidates.json", candidates);
+    }
+
+    fn on_graph(&mut self, graph: &Graph) {
+        self.record("graph.json", graph);
+    }
+
+    fn on_rank(&mut self, rank: &RankOutput) {
+        self.record("rank.json", rank);
+    }
+
+    fn on_phrases(&mut self, phrases: &PhraseSet) {
+        self.record("phrases.json", phrases);
+    }
+}
+
+/// Differences between a replayed stage's output and the recorded artifact.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArtifactDiff {
+    /// Stage that was replayed (one of the `STAGE_*` names).
+    pub stage: &'static str,
+    /// Up to 50 differences as `"<json path>: <recorded> != <replayed>"`.
+    pub differences: Vec<String>,
+    /// Total number of differences, including unlisted ones.
+    pub total: usize,
+}
+
+impl ArtifactDiff {
+    /// Whether the replayed output matches the recording.
+    pub fn is_match(&self) -> bool {
+        self.total == 0
+    }
+
+    fn report(&mut self, path: &str, recorded: &Value, replayed: &Value) {
+        self.total += 1;
+        if self.differences.len() < MAX_LISTED_DIFFERENCES {
+            let path = if path.is_empty() { "/" } else { path };
+            self.differences
+                .push(format!("{path}: {recorded} != {replayed}"));
+        }
+    }
+}
+
+impl std::fmt::Display for ArtifactDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_match() {
+            return write!(f, "{}: matches recording", self.stage);
+        }
+        writeln!(f, "{}: {} difference(s)", self.stage, self.total)?;
+        for difference in &self.differences {
+            writeln!(f, "  {difference}")?;
+        }
+        if self.total > self.differences.len() {
+            writeln!(f, "  ... {} more", self.total - self.differences.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// A recorded run, loaded for replaying single stages.
+///
+/// Each `replay_*` method runs one stage on the recorded inputs with the
+/// recorded config and diffs its output against the recorded artifact.
+#[derive(Debug, Clone)]
+pub struct PipelineReplayer {
+    config: TextRankConfig,
+    tokens: TokenStream,
+    candidates: CandidateSet,
+    graph: Graph,
+    rank: RankOutput,
+    phrases: PhraseSet,
+    tolerance: f64,
+}
+
+impl PipelineReplayer {
+    /// Load the run recorded in `run_dir` (a `run_<n>` directory).
+    pub fn open(run_dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = run_dir.as_ref();
+        Ok(Self {
+            config: read_json(&dir.join("config.json"))?,
+            tokens: read_json(&dir.join("tokens.json"))?,
+            candidates: read_json(&dir.join("candidates.json"))?,
+            graph: read_json(&dir.join("graph.json"))?,
+            rank: read_json(&dir.join("rank.json"))?,
+            phrases: read_json(&dir.join("phrases.json"))?,
+            tolerance: DEFAULT_REPLAY_TOLERANCE,
+        })
+    }
+
+    /// Compare numbers with absolute tolerance `tolerance` (default
+    /// [`DEFAULT_REPLAY_TOLERANCE`]).
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// The recorded config.
+    pub fn config(&self) -> &TextRankConfig {
+        &self.config
+    }
+
+    /// The recorded token stream.
+    pub fn tokens(&self) -> &TokenStream {
+        &self.tokens
+    }
+
+    /// The recorded candidates.
+    pub fn candidates(&self) -> &CandidateSet {
+        &self.candidates
+    }
+
+    /// The recorded (transformed) graph.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// The recorded rank output.
+    pub fn rank(&self) -> &RankOutput {
+        &self.rank
+    }
+
+    /// The recorded phrases.
+    pub fn phrases(&self) -> &PhraseSet {
+        &self.phrases
+    }
+
+    /// Re-select candidates from the recorded tokens.
+    pub fn replay_candidates(&self, selector: &impl CandidateSelector) -> ArtifactDiff {
+        let candidates = selector.select(self.tokens.as_ref(), &self.config);
+        self.diff(
+            crate::pipeline::STAGE_CANDIDATES,
+            &self.candidates,
+            &candidates,
+        )
+    }
+
+    /// Rebuild and transform the graph from the recorded tokens and
+    /// candidates.
+    pub fn replay_graph(
+        &self,
+        builder: &impl GraphBuilder,
+        transform: &impl GraphTransform,
+    ) -> ArtifactDiff {
+        let (tokens, candidates) = (self.tokens.as_ref(), self.candidates.as_ref());
+        let mut graph = builder.build(tokens, candidates, &self.config);
+        transform.transform(&mut graph, tokens, candidates, &self.config);
+        self.diff(crate::pipeline::STAGE_GRAPH, &self.graph, &graph)
+    }
+
+    /// Re-rank the recorded graph, with a teleport vector from `teleport`.
+    pub fn replay_rank(
+        &self,
+        teleport: &impl TeleportBuilder,
+        ranker: &impl Ranker,
+    ) -> ArtifactDiff {
+        let vector = teleport.build_for_graph(
+            self.tokens.as_ref(),
+            self.candidates.as_ref(),
+            &self.graph,
+            &self.config,
+        );
+        let rank = ranker.rank(&self.graph, vector.as_ref(), &self.config);
+        self.diff(crate::pipeline::STAGE_RANK, &self.rank, &rank)
+    }
+
+    /// Rebuild phrases from the recorded tokens, candidates, rank and graph.
+    pub fn replay_phrases(&self, builder: &impl PhraseBuilder) -> ArtifactDiff {
+        let phrases = builder.build(
+            self.tokens.as_ref(),
+            self.candidates.as_ref(),
+            &self.rank,
+            &self.graph,
+            &self.config,
+        );
+        self.diff(crate::pipeline::STAGE_PHRASES, &self.phrases, &phrases)
+    }
+
+    fn diff(
+        &self,
+        stage: &'static str,
+        recorded: &impl Serialize,
+        replayed: &impl Serialize,
+    ) -> ArtifactDiff {
+        let mut diff = ArtifactDiff {
+            stage,
+            differences: Vec::new(),
+            total: 0,
+        };
+        match (
+            serde_json::to_value(recorded),
+            serde_json::to_value(replayed),
+        ) {
+            (Ok(a), Ok(b)) => diff_values("", &a, &b, self.tolerance, &mut diff),
+            (Err(err), _) | (_, Err(err)) => {
+                diff.report("", &Value::Null, &Value::String(err.to_string()));
+            }
+        }
+        diff
+    }
+}
+
+fn write_json(path: &Path, value: &impl Serialize) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer(&mut writer, value).map_err(io::Error::from)?;
+    writer.flush()
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> io::Result<T> {
+    let reader = BufReader::new(File::open(path)?);
+    serde_json::from_reader(reader).map_err(io::Error::from)
+}
+
+/// Record the differences between `a` and `b` under JSON pointer `path`.
+fn diff_values(path: &str, a: &Value, b: &Value, tolerance: f64, diff: &mut ArtifactDiff) {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => {
+            let (x, y) = (
+                x.as_f64().unwrap_or(f64::NAN),
+                y.as_f64().unwrap_or(f64::NAN),
+            );
+            if (x - y).abs() > tolerance {
+                diff.report(path, a, b);
+            }
+        }
+        (Value::Array(xs), Value::Array(ys)) => {
+            if xs.len() != ys.len() {
+                diff.report(
+                    &format!("{path}/length"),
+                    &xs.len().into(),
+                    &ys.len().into(),
+                );
+            }
+            for (i, (x, y)) in xs.iter().zip(ys).enumerate() {
+                diff_values(&format!("{path}/{i}"), x, y, tolerance, diff);
+            }
+        }
+        (Value::Object(xs), Value::Object(ys)) => {
+            for (key, x) in xs {
+                let child = format!("{path}/{key}");
+                match ys.get(key) {
+                    Some(y) => diff_values(&child, x, y, tolerance, diff),
+                    None => diff.report(&child, x, &Value::Null),
+                }
+            }
+            for (key, y) in ys {
+                if !xs.contains_key(key) {
+                    diff.report(&format!("{path}/{key}"), &Value::Null, y);
+                }
+            }
+        }
+        _ if a != b => diff.report(path, a, b),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::runner::BaseTextRankPipeline;
+    use crate::pipeline::traits::{
+        ChunkPhraseBuilder, CooccurrenceGraphBuilder, KeywordPhraseBuilder, NoopGraphTransform,
+        PageRankRanker, UniformTeleportBuilder, WindowStrategy, WordNodeSelector,
+    };
+
+    const TEXT: &str = "Machine learning models learn from data. \
+        Deep learning is a kind of machine learning.";
+
+    fn record(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "rapid_textrank_replay_{name}_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        let cfg = TextRankConfig::default();
+        let mut recorder = PipelineRecorder::new(&dir).unwrap();
+        let pipeline = BaseTextRankPipeline::base_textrank();
+        pipeline.run_text(TEXT, &cfg, &mut recorder);
+        pipeline.run_text(
+            "Graphs have nodes and edges.",
+            &cfg.clone().with_top_n(3),
+            &mut recorder,
+        );
+        assert_eq!(recorder.run_dirs().len(), 2);
+        recorder.finish().unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_replay_matches_recorded_stages() {
+        let dir = record("match");
+        let replayer = PipelineReplayer::open(dir.join("run_0")).unwrap();
+
+        assert!(replayer.replay_candidates(&WordNodeSelector).is_match());
+        let graph = replayer.replay_graph(
+            &CooccurrenceGraphBuilder::base_textrank(),
+            &NoopGraphTransform,
+        );
+        assert!(graph.is_match(), "{graph}");
+        assert!(replayer
+            .replay_rank(&UniformTeleportBuilder, &PageRankRanker)
+            .is_match());
+        assert!(replayer.replay_phrases(&ChunkPhraseBuilder).is_match());
+        let run_1 = PipelineReplayer::open(dir.join("run_1")).unwrap();
+        assert_eq!(run_1.config.top_n, 3);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_replay_reports_differences() {
+        let dir = record("diff");
+        let replayer = PipelineReplayer::open(dir.join("run_0")).unwrap();
+
+        let graph = replayer.replay_graph(
+            &CooccurrenceGraphBuilder {
+                window_strategy: WindowStrategy::CrossSentence { window_size: 5 },
+                ..Default::default()
+            },
+            &NoopGraphTransform,
+        );
+        assert!(!graph.is_match());
+        assert_eq!(graph.stage, crate::pipeline::STAGE_GRAPH);
+        assert!(graph.to_string().contains("difference"));
+
+        let phrases = replayer.replay_phrases(&KeywordPhraseBuilder);
+        assert!(!phrases.is_match());
+        assert!(phrases.differences.len() <= phrases.total);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_recording_custom_node_key_fails() {
+        let dir = std::env::temp_dir().join(format!(
+            "rapid_textrank_replay_custom_{}",
+            std::process::id()
+        ));
+        let cfg = TextRankConfig::default()
+            .with_node_key(crate::NodeKey::custom(|t| t.lemma.as_str().into()));
+        let mut recorder = PipelineRecorder::new(&dir).unwrap();
+        BaseTextRankPipeline::base_textrank().run_text(TEXT, &cfg, &mut recorder);
+        assert!(recorder.finish().is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_open_missing_run_fails() {
+        let dir = std::env::temp_dir().join("rapid_textrank_replay_missing");
+        assert!(PipelineReplayer::open(dir).is_err());
+    }
+}
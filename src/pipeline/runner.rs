@@ -17,28 +17,35 @@
 //! Use [`Pipeline::base_textrank()`] (and friends) to build pipelines for
 //! known algorithm variants without spelling out the generics manually.
 
+use crate::nlp::token_filter::JunkTokenFilter;
 use crate::pipeline::artifacts::{FormattedResult, PipelineWorkspace, TokenStream};
+use crate::pipeline::cache::{upstream_key, PipelineCache, UpstreamArtifacts};
+use crate::pipeline::cancellation::{CancellationToken, RunBudget};
+use crate::pipeline::custom_stage::{CustomStages, Stage, StageArtifacts, StagePoint};
 use crate::pipeline::error_code::ErrorCode;
-use crate::pipeline::errors::PipelineRuntimeError;
+use crate::pipeline::errors::{PipelineError, PipelineRuntimeError};
 use crate::pipeline::observer::{
     PipelineObserver, StageClock, StageReport, StageReportBuilder, STAGE_CANDIDATES, STAGE_FORMAT,
     STAGE_GRAPH, STAGE_GRAPH_TRANSFORM, STAGE_PHRASES, STAGE_PREPROCESS, STAGE_RANK,
     STAGE_TELEPORT,
 };
+use crate::pipeline::plan::{PipelinePlan, PlannedStage};
 use crate::pipeline::traits::{
-    CandidateGraphBuilder, CandidateSelector, ChunkPhraseBuilder, FocusTermsTeleportBuilder,
-    GraphBuilder, GraphTransform, JaccardHacClusterer, MultipartitePhraseBuilder,
-    MultipartiteTransform, NoopGraphTransform, NoopPreprocessor, PageRankRanker, PhraseBuilder,
-    PhraseCandidateSelector, PositionTeleportBuilder, Preprocessor, Ranker, ResultFormatter,
-    StandardResultFormatter, TeleportBuilder, TopicGraphBuilder, TopicRepresentativeBuilder,
-    TopicWeightsTeleportBuilder, UniformTeleportBuilder, WindowGraphBuilder, WordNodeSelector,
+    CandidateGraphBuilder, CandidateSelector, ChunkPhraseBuilder, ExpandGraphBuilder,
+    FocusTermsTeleportBuilder, GraphBuilder, GraphTransform, JaccardHacClusterer,
+    MultipartitePhraseBuilder, MultipartiteTransform, NoopGraphTransform, NoopPreprocessor,
+    PageRankRanker, PhraseBuilder, PhraseCandidateSelector, PositionTeleportBuilder, Preprocessor,
+    QueryTeleportBuilder, Ranker, ResultFormatter, StandardResultFormatter, TeleportBuilder,
+    TopicGraphBuilder, TopicRepresentativeBuilder, TopicWeightsTeleportBuilder,
+    UniformTeleportBuilder, WindowGraphBuilder, WordNodeSelector,
 };
 #[cfg(feature = "sentence-rank")]
 use crate::pipeline::traits::{
     SentenceCandidateSelector, SentenceFormatter, SentenceGraphBuilder, SentencePhraseBuilder,
 };
-use crate::types::TextRankConfig;
+use crate::types::{NonConvergencePolicy, TextRankConfig, Token};
 use std::collections::HashMap;
+use std::ops::ControlFlow;
 
 // ---------------------------------------------------------------------------
 // Conditional tracing support
@@ -84,6 +91,8 @@ pub struct Pipeline<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> {
     pub ranker: Rnk,
     pub phrase_builder: PB,
     pub formatter: Fmt,
+    /// User-defined stages run between the built-in ones.
+    pub custom_stages: CustomStages,
 }
 
 /// Type alias for the default BaseTextRank pipeline.
@@ -120,6 +129,7 @@ impl BaseTextRankPipeline {
             ranker: PageRankRanker,
             phrase_builder: ChunkPhraseBuilder,
             formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
         }
     }
 }
@@ -154,6 +164,7 @@ impl PositionRankPipeline {
             ranker: PageRankRanker,
             phrase_builder: ChunkPhraseBuilder,
             formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
         }
     }
 }
@@ -194,6 +205,7 @@ impl SingleRankPipeline {
             ranker: PageRankRanker,
             phrase_builder: ChunkPhraseBuilder,
             formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
         }
     }
 }
@@ -230,6 +242,74 @@ impl BiasedTextRankPipeline {
             ranker: PageRankRanker,
             phrase_builder: ChunkPhraseBuilder,
             formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
+        }
+    }
+}
+
+/// Pipeline alias for query-focused BiasedTextRank: base pipeline +
+/// query-similarity teleportation.
+pub type QueryBiasedTextRankPipeline = Pipeline<
+    NoopPreprocessor,
+    WordNodeSelector,
+    WindowGraphBuilder,
+    NoopGraphTransform,
+    QueryTeleportBuilder,
+    PageRankRanker,
+    ChunkPhraseBuilder,
+    StandardResultFormatter,
+>;
+
+impl QueryBiasedTextRankPipeline {
+    /// Build a BiasedTextRank pipeline whose teleport vector comes from the
+    /// similarity between each candidate and a query (see
+    /// [`QueryTeleportBuilder`]).
+    pub fn query_biased(teleport_builder: QueryTeleportBuilder) -> Self {
+        Pipeline {
+            preprocessor: NoopPreprocessor,
+            selector: WordNodeSelector,
+            graph_builder: WindowGraphBuilder::base_textrank(),
+            graph_transform: NoopGraphTransform,
+            teleport_builder,
+            ranker: PageRankRanker,
+            phrase_builder: ChunkPhraseBuilder,
+            formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// ExpandRankPipeline — neighborhood-document graph expansion
+// ---------------------------------------------------------------------------
+
+/// Pipeline alias for ExpandRank: SingleRank pipeline whose co-occurrence
+/// graph also includes similarity-weighted counts from neighbor documents.
+pub type ExpandRankPipeline = Pipeline<
+    NoopPreprocessor,
+    WordNodeSelector,
+    ExpandGraphBuilder,
+    NoopGraphTransform,
+    UniformTeleportBuilder,
+    PageRankRanker,
+    ChunkPhraseBuilder,
+    StandardResultFormatter,
+>;
+
+impl ExpandRankPipeline {
+    /// Build a pipeline for ExpandRank from `(tokens, similarity)` neighbor
+    /// documents.
+    pub fn expand_rank(neighbors: Vec<(Vec<Token>, f64)>) -> Self {
+        Pipeline {
+            preprocessor: NoopPreprocessor,
+            selector: WordNodeSelector,
+            graph_builder: ExpandGraphBuilder::new(neighbors),
+            graph_transform: NoopGraphTransform,
+            teleport_builder: UniformTeleportBuilder,
+            ranker: PageRankRanker,
+            phrase_builder: ChunkPhraseBuilder,
+            formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
         }
     }
 }
@@ -271,6 +351,7 @@ impl TopicalPageRankPipeline {
             ranker: PageRankRanker,
             phrase_builder: ChunkPhraseBuilder,
             formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
         }
     }
 }
@@ -312,6 +393,7 @@ impl TopicRankPipeline {
             ranker: PageRankRanker,
             phrase_builder: TopicRepresentativeBuilder,
             formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
         }
     }
 
@@ -332,6 +414,7 @@ impl TopicRankPipeline {
             ranker: PageRankRanker,
             phrase_builder: TopicRepresentativeBuilder,
             formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
         }
     }
 }
@@ -375,6 +458,7 @@ impl MultipartiteRankPipeline {
             ranker: PageRankRanker,
             phrase_builder: MultipartitePhraseBuilder,
             formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
         }
     }
 
@@ -396,6 +480,7 @@ impl MultipartiteRankPipeline {
             ranker: PageRankRanker,
             phrase_builder: MultipartitePhraseBuilder,
             formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
         }
     }
 }
@@ -435,6 +520,7 @@ impl SentenceRankPipeline {
             ranker: PageRankRanker,
             phrase_builder: SentencePhraseBuilder,
             formatter: SentenceFormatter::default(),
+            custom_stages: CustomStages::default(),
         }
     }
 
@@ -452,6 +538,7 @@ impl SentenceRankPipeline {
             formatter: SentenceFormatter {
                 sort_by_position: true,
             },
+            custom_stages: CustomStages::default(),
         }
     }
 }
@@ -471,10 +558,106 @@ where
     PB: PhraseBuilder,
     Fmt: ResultFormatter,
 {
+    /// Register a custom [`Stage`] to run at `point`.
+    ///
+    /// Stages at the same point run in registration order.
+    pub fn with_stage(mut self, point: StagePoint, stage: impl Stage + 'static) -> Self {
+        self.custom_stages.push(point, stage);
+        self
+    }
+
+    /// Replace the ranking stage, keeping every other stage.
+    ///
+    /// Swaps the algorithm of a preset, e.g. degree centrality instead of
+    /// PageRank on the PositionRank graph:
+    ///
+    /// ```
+    /// use rapid_textrank::pipeline::runner::PositionRankPipeline;
+    /// use rapid_textrank::pipeline::DegreeRanker;
+    ///
+    /// let pipeline = PositionRankPipeline::position_rank().with_ranker(DegreeRanker);
+    /// ```
+    pub fn with_ranker<R: Ranker>(self, ranker: R) -> Pipeline<Pre, Sel, GB, GT, TB, R, PB, Fmt> {
+        Pipeline {
+            preprocessor: self.preprocessor,
+            selector: self.selector,
+            graph_builder: self.graph_builder,
+            graph_transform: self.graph_transform,
+            teleport_builder: self.teleport_builder,
+            ranker,
+            phrase_builder: self.phrase_builder,
+            formatter: self.formatter,
+            custom_stages: self.custom_stages,
+        }
+    }
+
+    /// Describe what [`run`](Self::run) would do with `cfg`, without running
+    /// anything.
+    ///
+    /// The plan lists the stages in execution order, custom stages at their
+    /// [`StagePoint`]s included, each with its implementation and parameters
+    /// from `describe()`, and carries `cfg` with every default filled in.
+    /// Log it next to a result to record exactly what produced it.
+    pub fn explain(&self, cfg: &TextRankConfig) -> PipelinePlan {
+        let builtin = [
+            (
+                STAGE_PREPROCESS,
+                self.preprocessor.describe(),
+                Some(StagePoint::AfterPreprocess),
+            ),
+            (
+                STAGE_CANDIDATES,
+                self.selector.describe(),
+                Some(StagePoint::AfterCandidates),
+            ),
+            (STAGE_GRAPH, self.graph_builder.describe(), None),
+            (
+                STAGE_GRAPH_TRANSFORM,
+                self.graph_transform.describe(),
+                Some(StagePoint::AfterGraph),
+            ),
+            (STAGE_TELEPORT, self.teleport_builder.describe(), None),
+            (
+                STAGE_RANK,
+                self.ranker.describe(),
+                Some(StagePoint::AfterRank),
+            ),
+            (
+                STAGE_PHRASES,
+                self.phrase_builder.describe(),
+                Some(StagePoint::AfterPhrases),
+            ),
+            (STAGE_FORMAT, self.formatter.describe(), None),
+        ];
+
+        let mut stages = Vec::with_capacity(builtin.len() + self.custom_stages.len());
+        for (name, implementation, after) in builtin {
+            stages.push(PlannedStage {
+                name,
+                implementation,
+                custom: false,
+            });
+            if let Some(point) = after {
+                stages.extend(self.custom_stages.names_at(point).map(|name| PlannedStage {
+                    name,
+                    implementation: "custom".to_string(),
+                    custom: true,
+                }));
+            }
+        }
+
+        PipelinePlan {
+            stages,
+            config: cfg.clone(),
+            spec: None,
+        }
+    }
+
     /// Execute the pipeline, producing a [`FormattedResult`].
     ///
     /// Stages run in order:
-    /// 1. Preprocess (mutate tokens in place)
+    /// 1. Preprocess (mutate tokens in place), then mark the junk tokens of
+    ///    `cfg.token_filter` as stopwords
     /// 2. Select candidates
     /// 3. Build graph
     /// 4. Transform graph (optional no-op)
@@ -485,13 +668,97 @@ where
     ///
     /// The `observer` receives callbacks at each stage boundary. Pass
     /// [`NoopObserver`] for zero-overhead execution.
+    ///
+    /// If `cfg.stage_timeout_ms` or `cfg.pipeline_timeout_ms` is set, the
+    /// budget is checked after every stage, and during graph construction
+    /// and ranking, and an overrun ends the run with an
+    /// [`ErrorCode::Timeout`] error in [`FormattedResult::error`].
     pub fn run(
         &self,
         tokens: TokenStream,
         cfg: &TextRankConfig,
         observer: &mut impl PipelineObserver,
     ) -> FormattedResult {
-        self.run_inner(tokens, cfg, observer, None)
+        self.run_inner(RunInput::Tokens(tokens), cfg, observer, None, None, None)
+    }
+
+    /// Execute the pipeline, aborting once `token` is cancelled.
+    ///
+    /// The token is checked at every stage boundary and from the progress
+    /// hooks of graph construction and ranking (see
+    /// [`GraphBuilder::build_with_progress`] and
+    /// [`Ranker::rank_with_progress`]), so those stages stop mid-way.
+    ///
+    /// An aborted run returns an empty result whose `error` carries
+    /// [`ErrorCode::Cancelled`] (or [`ErrorCode::Timeout`] for an exceeded
+    /// budget) and the name of the stage that was running. The observer has
+    /// already received `on_stage_end` reports for every stage that ran,
+    /// including the one that triggered the abort.
+    pub fn run_cancellable(
+        &self,
+        tokens: TokenStream,
+        cfg: &TextRankConfig,
+        observer: &mut impl PipelineObserver,
+        token: &CancellationToken,
+    ) -> FormattedResult {
+        self.run_inner(
+            RunInput::Tokens(tokens),
+            cfg,
+            observer,
+            None,
+            Some(token),
+            None,
+        )
+    }
+
+    /// Execute the pipeline, reusing upstream artifacts from `cache`.
+    ///
+    /// On a cache hit the preprocess, candidates, graph and graph-transform
+    /// stages (and custom stages between them) are skipped: the observer
+    /// receives no stage reports for them, only the `on_tokens`,
+    /// `on_candidates` and `on_graph` artifact callbacks. A miss runs the
+    /// stages as in [`run`](Self::run) and stores their output. See
+    /// [`PipelineCache`] for which config fields make up the key.
+    pub fn run_cached(
+        &self,
+        tokens: TokenStream,
+        cfg: &TextRankConfig,
+        observer: &mut impl PipelineObserver,
+        cache: &mut PipelineCache,
+    ) -> FormattedResult {
+        self.run_inner(
+            RunInput::Tokens(tokens),
+            cfg,
+            observer,
+            None,
+            None,
+            Some(cache),
+        )
+    }
+
+    /// Execute the pipeline on raw text.
+    ///
+    /// Tokenization is part of the preprocess stage: the preprocessor's
+    /// [`tokenize`](Preprocessor::tokenize) turns `text` into a token
+    /// stream — by default with the built-in Unicode [`Tokenizer`]
+    /// (sentence splitting, heuristic POS tags and lemmas, stopwords for
+    /// `cfg.language` plus `cfg.stopwords`) — and then all stages run as in
+    /// [`run`](Self::run). The preprocess stage report covers both.
+    ///
+    /// With the `lang-detect` feature and `cfg.detect_language` set, the
+    /// document's language is detected first and the config adjusted by
+    /// [`config_for_text`](crate::nlp::langdetect::config_for_text).
+    ///
+    /// [`Tokenizer`]: crate::nlp::tokenizer::Tokenizer
+    pub fn run_text(
+        &self,
+        text: &str,
+        cfg: &TextRankConfig,
+        observer: &mut impl PipelineObserver,
+    ) -> FormattedResult {
+        #[cfg(feature = "lang-detect")]
+        let cfg = &*crate::nlp::langdetect::config_for_text(cfg, text);
+        self.run_inner(RunInput::Text(text), cfg, observer, None, None, None)
     }
 
     /// Execute the pipeline, reusing workspace buffers for PageRank.
@@ -508,7 +775,14 @@ where
         observer: &mut impl PipelineObserver,
         ws: &mut PipelineWorkspace,
     ) -> FormattedResult {
-        self.run_inner(tokens, cfg, observer, Some(ws))
+        self.run_inner(
+            RunInput::Tokens(tokens),
+            cfg,
+            observer,
+            Some(ws),
+            None,
+            None,
+        )
     }
 
     /// Execute the pipeline over multiple documents, reusing a single
@@ -531,94 +805,289 @@ where
             .collect()
     }
 
-    /// Shared orchestration logic for [`run`] and [`run_with_workspace`].
+    /// Shared orchestration logic for [`run`] and its variants.
     fn run_inner(
         &self,
-        mut tokens: TokenStream,
+        input: RunInput<'_>,
         cfg: &TextRankConfig,
         observer: &mut impl PipelineObserver,
-        ws: Option<&mut PipelineWorkspace>,
+        mut ws: Option<&mut PipelineWorkspace>,
+        cancel: Option<&CancellationToken>,
+        mut cache: Option<&mut PipelineCache>,
     ) -> FormattedResult {
-        // Stage 0: Preprocess
-        trace_stage!(STAGE_PREPROCESS);
-        observer.on_stage_start(STAGE_PREPROCESS);
-        let clock = StageClock::start();
-        self.preprocessor.preprocess(&mut tokens, cfg);
-        let report = StageReport::new(clock.elapsed());
-        observer.on_stage_end(STAGE_PREPROCESS, &report);
-        observer.on_tokens(&tokens);
-
-        // Stage 1: Select candidates
-        trace_stage!(STAGE_CANDIDATES);
-        observer.on_stage_start(STAGE_CANDIDATES);
-        let clock = StageClock::start();
-        let candidates = self.selector.select(tokens.as_ref(), cfg);
-        let report = StageReport::new(clock.elapsed());
-        observer.on_stage_end(STAGE_CANDIDATES, &report);
-        observer.on_candidates(&candidates);
+        observer.on_config(cfg);
+        let budget = RunBudget::start(cfg, cancel);
+        macro_rules! check_budget {
+            ($stage:expr, $report:expr) => {
+                if let Some(err) = budget.check($stage, $report.duration()) {
+                    return aborted(err);
+                }
+            };
+        }
+        macro_rules! custom_stages {
+            ($point:expr, $artifacts:expr) => {
+                if !self.custom_stages.is_empty() {
+                    if let Some(err) =
+                        self.custom_stages
+                            .run_at($point, &mut $artifacts, cfg, observer, &budget)
+                    {
+                        return aborted(err);
+                    }
+                }
+            };
+        }
 
-        // Stage 2: Build graph
-        trace_stage!(STAGE_GRAPH);
-        observer.on_stage_start(STAGE_GRAPH);
-        let clock = StageClock::start();
-        let mut graph = self
-            .graph_builder
-            .build(tokens.as_ref(), candidates.as_ref(), cfg);
-        let report = StageReportBuilder::new(clock.elapsed())
-            .nodes(graph.num_nodes())
-            .edges(graph.num_edges())
-            .build();
-        observer.on_stage_end(STAGE_GRAPH, &report);
+        // Raw text is only run uncached.
+        let key = match (&cache, &input) {
+            (Some(_), RunInput::Tokens(tokens)) => {
+                Some(upstream_key(std::any::type_name::<Self>(), tokens, cfg))
+            }
+            _ => None,
+        };
+        let hit = match (cache.as_deref_mut(), key) {
+            (Some(cache), Some(key)) => cache.get(key),
+            _ => None,
+        };
 
-        // Stage 2a: Transform graph
-        trace_stage!(STAGE_GRAPH_TRANSFORM);
-        observer.on_stage_start(STAGE_GRAPH_TRANSFORM);
-        let clock = StageClock::start();
-        self.graph_transform
-            .transform(&mut graph, tokens.as_ref(), candidates.as_ref(), cfg);
-        let report = StageReport::new(clock.elapsed());
-        observer.on_stage_end(STAGE_GRAPH_TRANSFORM, &report);
-        observer.on_graph(&graph);
+        let (mut tokens, mut candidates, mut graph) = if let Some(hit) = hit {
+            observer.on_tokens(&hit.tokens);
+            observer.on_candidates(&hit.candidates);
+            observer.on_graph(&hit.graph);
+            (hit.tokens, hit.candidates, hit.graph)
+        } else {
+            // Stage 0: Preprocess
+            trace_stage!(STAGE_PREPROCESS);
+            observer.on_stage_start(STAGE_PREPROCESS);
+            let clock = StageClock::start();
+            let mut tokens = match input {
+                RunInput::Tokens(tokens) => tokens,
+                RunInput::Text(text) => self.preprocessor.tokenize(text, cfg),
+            };
+            self.preprocessor.preprocess(&mut tokens, cfg);
+            JunkTokenFilter::for_config(cfg).apply_stream(&mut tokens);
+            let report = StageReportBuilder::new(clock.elapsed())
+                .tokens(tokens.len())
+                .artifact_bytes(tokens.heap_bytes() as u64)
+                .build();
+            observer.on_stage_end(STAGE_PREPROCESS, &report);
+            check_budget!(STAGE_PREPROCESS, report);
+            custom_stages!(
+                StagePoint::AfterPreprocess,
+                StageArtifacts::new(&mut tokens)
+            );
+            observer.on_tokens(&tokens);
+
+            // Stage 1: Select candidates
+            trace_stage!(STAGE_CANDIDATES);
+            observer.on_stage_start(STAGE_CANDIDATES);
+            let clock = StageClock::start();
+            let mut candidates = self.selector.select(tokens.as_ref(), cfg);
+            let report = StageReportBuilder::new(clock.elapsed())
+                .candidates(candidates.len())
+                .artifact_bytes(candidates.heap_bytes() as u64)
+                .build();
+            observer.on_stage_end(STAGE_CANDIDATES, &report);
+            check_budget!(STAGE_CANDIDATES, report);
+            custom_stages!(
+                StagePoint::AfterCandidates,
+                StageArtifacts {
+                    candidates: Some(&mut candidates),
+                    ..StageArtifacts::new(&mut tokens)
+                }
+            );
+            observer.on_candidates(&candidates);
+
+            // Stage 2: Build graph
+            trace_stage!(STAGE_GRAPH);
+            observer.on_stage_start(STAGE_GRAPH);
+            let clock = StageClock::start();
+            let observed = observer.progress_interval();
+            let mut interrupted = None;
+            let mut graph = match (observed, ws.as_deref_mut()) {
+                _ if observed.is_some() || budget.is_limited() => {
+                    let every = observed.unwrap_or_default().graph_tokens;
+                    self.graph_builder.build_with_progress(
+                        tokens.as_ref(),
+                        candidates.as_ref(),
+                        cfg,
+                        every,
+                        &mut |done, total| {
+                            if observed.is_some() {
+                                observer.on_graph_progress(done, total);
+                            }
+                            poll_budget(&budget, STAGE_GRAPH, &clock, &mut interrupted)
+                        },
+                    )
+                }
+                (_, Some(ws)) => {
+                    self.graph_builder
+                        .build_reusing(tokens.as_ref(), candidates.as_ref(), cfg, ws)
+                }
+                (_, None) => self
+                    .graph_builder
+                    .build(tokens.as_ref(), candidates.as_ref(), cfg),
+            };
+            let report = StageReportBuilder::new(clock.elapsed())
+                .nodes(graph.num_nodes())
+                .edges(graph.num_edges())
+                .artifact_bytes(graph.heap_bytes() as u64)
+                .build();
+            observer.on_stage_end(STAGE_GRAPH, &report);
+            if let Some(err) = interrupted {
+                return aborted(err);
+            }
+            check_budget!(STAGE_GRAPH, report);
+
+            // Stage 2a: Transform graph
+            trace_stage!(STAGE_GRAPH_TRANSFORM);
+            observer.on_stage_start(STAGE_GRAPH_TRANSFORM);
+            let clock = StageClock::start();
+            self.graph_transform
+                .transform(&mut graph, tokens.as_ref(), candidates.as_ref(), cfg);
+            let report = StageReportBuilder::new(clock.elapsed())
+                .artifact_bytes(graph.heap_bytes() as u64)
+                .build();
+            observer.on_stage_end(STAGE_GRAPH_TRANSFORM, &report);
+            check_budget!(STAGE_GRAPH_TRANSFORM, report);
+            custom_stages!(
+                StagePoint::AfterGraph,
+                StageArtifacts {
+                    candidates: Some(&mut candidates),
+                    graph: Some(&mut graph),
+                    ..StageArtifacts::new(&mut tokens)
+                }
+            );
+            observer.on_graph(&graph);
+
+            if let (Some(cache), Some(key)) = (cache, key) {
+                cache.insert(
+                    key,
+                    UpstreamArtifacts {
+                        tokens: tokens.clone(),
+                        candidates: candidates.clone(),
+                        graph: graph.clone(),
+                    },
+                );
+            }
+            (tokens, candidates, graph)
+        };
 
         // Stage 3a: Build teleport vector
         trace_stage!(STAGE_TELEPORT);
         observer.on_stage_start(STAGE_TELEPORT);
         let clock = StageClock::start();
-        let teleport = self
-            .teleport_builder
-            .build(tokens.as_ref(), candidates.as_ref(), cfg);
-        let report = StageReport::new(clock.elapsed());
+        let teleport = self.teleport_builder.build_for_graph(
+            tokens.as_ref(),
+            candidates.as_ref(),
+            &graph,
+            cfg,
+        );
+        let mut report = StageReportBuilder::new(clock.elapsed());
+        if let Some(teleport) = &teleport {
+            report = report.artifact_bytes(teleport.heap_bytes() as u64);
+        }
+        let report = report.build();
         observer.on_stage_end(STAGE_TELEPORT, &report);
+        check_budget!(STAGE_TELEPORT, report);
 
         // Stage 3: Rank
         trace_stage!(STAGE_RANK);
         observer.on_stage_start(STAGE_RANK);
         let clock = StageClock::start();
-        let rank_output = match ws {
-            Some(ws) => self.ranker.rank_reusing(&graph, teleport.as_ref(), cfg, ws),
-            None => self.ranker.rank(&graph, teleport.as_ref(), cfg),
+        let observed = observer.progress_interval();
+        let mut interrupted = None;
+        let mut rank_output = match (observed, ws) {
+            _ if observed.is_some() || budget.is_limited() => {
+                let every = observed.unwrap_or_default().rank_iterations;
+                self.ranker.rank_with_progress(
+                    &graph,
+                    teleport.as_ref(),
+                    cfg,
+                    every,
+                    &mut |iteration, residual| {
+                        let flow = match observed {
+                            Some(_) => observer.on_rank_iteration(iteration, residual),
+                            None => ControlFlow::Continue(()),
+                        };
+                        poll_budget(&budget, STAGE_RANK, &clock, &mut interrupted)?;
+                        flow
+                    },
+                )
+            }
+            (_, Some(ws)) => self.ranker.rank_reusing(&graph, teleport.as_ref(), cfg, ws),
+            (_, None) => self.ranker.rank(&graph, teleport.as_ref(), cfg),
         };
         let report = StageReportBuilder::new(clock.elapsed())
             .iterations(rank_output.iterations())
             .converged(rank_output.converged())
             .residual(rank_output.final_delta())
+            .acceleration(cfg.acceleration)
+            .artifact_bytes(rank_output.heap_bytes() as u64)
             .build();
         observer.on_stage_end(STAGE_RANK, &report);
+        if let Some(err) = interrupted {
+            return aborted(err);
+        }
+        check_budget!(STAGE_RANK, report);
+        if !rank_output.converged() && rank_output.iterations() as usize >= cfg.max_iterations {
+            let problem = PipelineError::new(
+                ErrorCode::ConvergenceFailed,
+                STAGE_RANK,
+                format!(
+                    "ranking did not converge after {} iterations (final delta {:e})",
+                    rank_output.iterations(),
+                    rank_output.final_delta()
+                ),
+            );
+            match cfg.non_convergence {
+                NonConvergencePolicy::Continue => {}
+                NonConvergencePolicy::WarnAndContinue => observer.on_warning(&problem),
+                NonConvergencePolicy::Fail => {
+                    let mut err = PipelineRuntimeError::from(problem)
+                        .with_hint("Increase max_iterations or relax the convergence threshold");
+                    err.path = "/modules/rank".to_string();
+                    return aborted(err);
+                }
+            }
+        }
+        custom_stages!(
+            StagePoint::AfterRank,
+            StageArtifacts {
+                candidates: Some(&mut candidates),
+                graph: Some(&mut graph),
+                rank: Some(&mut rank_output),
+                ..StageArtifacts::new(&mut tokens)
+            }
+        );
         observer.on_rank(&rank_output);
 
         // Stage 4: Build phrases
         trace_stage!(STAGE_PHRASES);
         observer.on_stage_start(STAGE_PHRASES);
         let clock = StageClock::start();
-        let phrases = self.phrase_builder.build(
+        let mut phrases = self.phrase_builder.build(
             tokens.as_ref(),
             candidates.as_ref(),
             &rank_output,
             &graph,
             cfg,
         );
-        let report = StageReport::new(clock.elapsed());
+        let report = StageReportBuilder::new(clock.elapsed())
+            .phrases(phrases.len())
+            .artifact_bytes(phrases.heap_bytes() as u64)
+            .build();
         observer.on_stage_end(STAGE_PHRASES, &report);
+        check_budget!(STAGE_PHRASES, report);
+        custom_stages!(
+            StagePoint::AfterPhrases,
+            StageArtifacts {
+                candidates: Some(&mut candidates),
+                graph: Some(&mut graph),
+                rank: Some(&mut rank_output),
+                phrases: Some(&mut phrases),
+                ..StageArtifacts::new(&mut tokens)
+            }
+        );
         observer.on_phrases(&phrases);
 
         // Check graph size limits (pipeline runtime safety bounds).
@@ -674,8 +1143,11 @@ where
         }
 
         // Build debug payload (opt-in via cfg.debug_level).
-        let debug_payload =
+        let mut debug_payload =
             super::DebugPayload::build(cfg.debug_level, &graph, &rank_output, cfg.debug_top_k);
+        if let (Some(payload), Some(teleport)) = (&mut debug_payload, &teleport) {
+            payload.set_teleport(teleport.as_slice());
+        }
 
         // Stage 5: Format result
         trace_stage!(STAGE_FORMAT);
@@ -691,6 +1163,41 @@ where
     }
 }
 
+/// Input to [`Pipeline::run_inner`]: a token stream, or raw text tokenized
+/// by the preprocess stage.
+enum RunInput<'t> {
+    Tokens(TokenStream),
+    Text(&'t str),
+}
+
+/// Check `budget` from inside `stage`, recording the error in `interrupted`
+/// and breaking out of the stage once it is exhausted.
+fn poll_budget(
+    budget: &RunBudget<'_>,
+    stage: &str,
+    clock: &StageClock,
+    interrupted: &mut Option<PipelineRuntimeError>,
+) -> ControlFlow<()> {
+    match budget.check(stage, clock.elapsed()) {
+        Some(err) => {
+            *interrupted = Some(err);
+            ControlFlow::Break(())
+        }
+        None => ControlFlow::Continue(()),
+    }
+}
+
+/// Empty result carrying the error that ended a run early.
+fn aborted(error: PipelineRuntimeError) -> FormattedResult {
+    FormattedResult {
+        phrases: Vec::new(),
+        converged: false,
+        iterations: 0,
+        debug: None,
+        error: Some(error),
+    }
+}
+
 // ============================================================================
 // PipelineBuilder — fluent construction with custom stages
 // ============================================================================
@@ -725,6 +1232,7 @@ pub struct PipelineBuilder<
     ranker: Rnk,
     phrase_builder: PB,
     formatter: Fmt,
+    custom_stages: CustomStages,
 }
 
 impl PipelineBuilder {
@@ -739,6 +1247,7 @@ impl PipelineBuilder {
             ranker: PageRankRanker,
             phrase_builder: ChunkPhraseBuilder,
             formatter: StandardResultFormatter,
+            custom_stages: CustomStages::default(),
         }
     }
 }
@@ -764,6 +1273,7 @@ impl<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> PipelineBuilder<Pre, Sel, GB, GT, TB, R
             ranker: self.ranker,
             phrase_builder: self.phrase_builder,
             formatter: self.formatter,
+            custom_stages: self.custom_stages,
         }
     }
 
@@ -781,6 +1291,7 @@ impl<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> PipelineBuilder<Pre, Sel, GB, GT, TB, R
             ranker: self.ranker,
             phrase_builder: self.phrase_builder,
             formatter: self.formatter,
+            custom_stages: self.custom_stages,
         }
     }
 
@@ -798,6 +1309,7 @@ impl<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> PipelineBuilder<Pre, Sel, GB, GT, TB, R
             ranker: self.ranker,
             phrase_builder: self.phrase_builder,
             formatter: self.formatter,
+            custom_stages: self.custom_stages,
         }
     }
 
@@ -815,6 +1327,7 @@ impl<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> PipelineBuilder<Pre, Sel, GB, GT, TB, R
             ranker: self.ranker,
             phrase_builder: self.phrase_builder,
             formatter: self.formatter,
+            custom_stages: self.custom_stages,
         }
     }
 
@@ -832,6 +1345,7 @@ impl<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> PipelineBuilder<Pre, Sel, GB, GT, TB, R
             ranker: self.ranker,
             phrase_builder: self.phrase_builder,
             formatter: self.formatter,
+            custom_stages: self.custom_stages,
         }
     }
 
@@ -846,6 +1360,7 @@ impl<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> PipelineBuilder<Pre, Sel, GB, GT, TB, R
             ranker: r,
             phrase_builder: self.phrase_builder,
             formatter: self.formatter,
+            custom_stages: self.custom_stages,
         }
     }
 
@@ -863,6 +1378,7 @@ impl<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> PipelineBuilder<Pre, Sel, GB, GT, TB, R
             ranker: self.ranker,
             phrase_builder: p,
             formatter: self.formatter,
+            custom_stages: self.custom_stages,
         }
     }
 
@@ -880,9 +1396,16 @@ impl<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> PipelineBuilder<Pre, Sel, GB, GT, TB, R
             ranker: self.ranker,
             phrase_builder: self.phrase_builder,
             formatter: f,
+            custom_stages: self.custom_stages,
         }
     }
 
+    /// Register a custom [`Stage`] to run at `point`.
+    pub fn stage(mut self, point: StagePoint, stage: impl Stage + 'static) -> Self {
+        self.custom_stages.push(point, stage);
+        self
+    }
+
     /// Consume the builder and produce a [`Pipeline`].
     pub fn build(self) -> Pipeline<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> {
         Pipeline {
@@ -894,6 +1417,7 @@ impl<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> PipelineBuilder<Pre, Sel, GB, GT, TB, R
             ranker: self.ranker,
             phrase_builder: self.phrase_builder,
             formatter: self.formatter,
+            custom_stages: self.custom_stages,
         }
     }
 }
@@ -905,8 +1429,10 @@ impl<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt> PipelineBuilder<Pre, Sel, GB, GT, TB, R
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::pipeline::artifacts::{CandidateSet, DebugLevel, Graph, PhraseSet, RankOutput};
-    use crate::pipeline::observer::{NoopObserver, StageTimingObserver};
+    use crate::pipeline::artifacts::{
+        CandidateSet, DebugLevel, Graph, PhraseSet, RankOutput, TeleportVector,
+    };
+    use crate::pipeline::observer::{NoopObserver, ProgressInterval, StageTimingObserver};
     use crate::types::{PosTag, Token};
 
     fn sample_tokens() -> Vec<Token> {
@@ -921,6 +1447,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 0,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "is".into(),
@@ -931,6 +1458,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 1,
                 is_stopword: true,
+                section: None,
             },
             Token {
                 text: "a".into(),
@@ -941,6 +1469,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 2,
                 is_stopword: true,
+                section: None,
             },
             Token {
                 text: "systems".into(),
@@ -951,6 +1480,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 3,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "programming".into(),
@@ -961,6 +1491,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 4,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "language".into(),
@@ -971,6 +1502,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 5,
                 is_stopword: false,
+                section: None,
             },
         ]
     }
@@ -1071,6 +1603,106 @@ mod tests {
         assert!(rank_report.residual().is_some());
     }
 
+    #[test]
+    fn test_pipeline_observer_receives_counts() {
+        let pipeline = BaseTextRankPipeline::base_textrank();
+        let tokens = make_token_stream();
+        let num_tokens = tokens.len();
+        let cfg = TextRankConfig::default();
+        let mut obs = StageTimingObserver::new();
+
+        let result = pipeline.run(tokens, &cfg, &mut obs);
+
+        let report = |stage: &str| &obs.reports().iter().find(|(n, _)| *n == stage).unwrap().1;
+        assert_eq!(report(STAGE_PREPROCESS).tokens(), Some(num_tokens));
+        assert!(report(STAGE_CANDIDATES).candidates().is_some_and(|n| n > 0));
+        assert_eq!(report(STAGE_PHRASES).phrases(), Some(result.phrases.len()));
+        assert!(report(STAGE_RANK).tokens().is_none());
+    }
+
+    #[test]
+    fn test_pipeline_observer_receives_artifact_sizes() {
+        let pipeline = BaseTextRankPipeline::base_textrank();
+        let tokens = make_token_stream();
+        let cfg = TextRankConfig::default();
+        let mut obs = StageTimingObserver::new();
+
+        let _result = pipeline.run(tokens, &cfg, &mut obs);
+
+        let bytes = |stage: &str| {
+            obs.reports()
+                .iter()
+                .find(|(name, _)| *name == stage)
+                .and_then(|(_, report)| report.artifact_bytes())
+        };
+        for stage in [STAGE_PREPROCESS, STAGE_CANDIDATES, STAGE_GRAPH, STAGE_RANK] {
+            assert!(bytes(stage).is_some_and(|b| b > 0), "{stage}");
+        }
+        assert!(bytes(STAGE_FORMAT).is_none());
+        // Peak memory needs an allocator hook the runner does not install.
+        assert!(obs.reports().iter().all(|(_, r)| r.peak_bytes().is_none()));
+    }
+
+    /// Records intra-stage progress and stops ranking after `stop_after`
+    /// iterations.
+    struct ProgressObserver {
+        stop_after: u32,
+        graph: Vec<(usize, usize)>,
+        rank: Vec<u32>,
+    }
+
+    impl PipelineObserver for ProgressObserver {
+        fn progress_interval(&self) -> Option<ProgressInterval> {
+            Some(ProgressInterval {
+                rank_iterations: 1,
+                graph_tokens: 2,
+            })
+        }
+
+        fn on_graph_progress(&mut self, tokens_done: usize, tokens_total: usize) {
+            self.graph.push((tokens_done, tokens_total));
+        }
+
+        fn on_rank_iteration(&mut self, iteration: u32, _residual: f64) -> ControlFlow<()> {
+            self.rank.push(iteration);
+            if iteration >= self.stop_after {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_pipeline_progress_callbacks() {
+        let pipeline = BaseTextRankPipeline::base_textrank();
+        let cfg = TextRankConfig::default();
+        let expected = pipeline.run(make_token_stream(), &cfg, &mut NoopObserver);
+
+        let mut obs = ProgressObserver {
+            stop_after: u32::MAX,
+            graph: Vec::new(),
+            rank: Vec::new(),
+        };
+        let result = pipeline.run(make_token_stream(), &cfg, &mut obs);
+        assert_eq!(result.phrases, expected.phrases);
+        assert_eq!(obs.rank, (1..=expected.iterations).collect::<Vec<_>>());
+        let total = make_token_stream().len();
+        assert!(obs.graph.len() > 1);
+        assert!(obs.graph.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(obs.graph.last(), Some(&(total, total)));
+
+        let mut obs = ProgressObserver {
+            stop_after: 1,
+            graph: Vec::new(),
+            rank: Vec::new(),
+        };
+        let result = pipeline.run(make_token_stream(), &cfg, &mut obs);
+        assert_eq!(result.iterations, 1);
+        assert!(!result.converged);
+        assert_eq!(obs.rank, vec![1]);
+    }
+
     #[test]
     fn test_pipeline_run_empty_input() {
         let pipeline = BaseTextRankPipeline::base_textrank();
@@ -1094,6 +1726,85 @@ mod tests {
         assert!(!result.phrases.is_empty());
     }
 
+    #[test]
+    fn test_pipeline_run_text_matches_extract_from_text() {
+        let text = "Rust is a systems programming language. Rust programs are fast.";
+        let cfg = TextRankConfig::default();
+        let mut obs = NoopObserver;
+
+        let result = BaseTextRankPipeline::base_textrank().run_text(text, &cfg, &mut obs);
+        let expected = crate::phrase::extraction::extract_from_text(text, &cfg);
+
+        assert!(!result.phrases.is_empty());
+        let lemmas: Vec<&str> = result.phrases.iter().map(|p| p.lemma.as_str()).collect();
+        let expected: Vec<&str> = expected.iter().map(|p| p.lemma.as_str()).collect();
+        assert_eq!(lemmas, expected);
+    }
+
+    #[test]
+    fn test_pipeline_run_text_tokenizes_in_preprocess_stage() {
+        // Splits on whitespace only and tags every word as a noun.
+        struct WhitespacePreprocessor;
+        impl Preprocessor for WhitespacePreprocessor {
+            fn preprocess(&self, _tokens: &mut TokenStream, _cfg: &TextRankConfig) {}
+
+            fn tokenize(&self, text: &str, _cfg: &TextRankConfig) -> TokenStream {
+                let tokens: Vec<Token> = text
+                    .split_whitespace()
+                    .enumerate()
+                    .map(|(i, word)| {
+                        Token::new(word, word.to_lowercase(), PosTag::Noun, 0, 0, 0, i)
+                    })
+                    .collect();
+                TokenStream::from_tokens(&tokens)
+            }
+        }
+
+        let text = "Rust-lang crates, Rust-lang crates.";
+        let cfg = TextRankConfig::default();
+
+        let mut obs = StageTimingObserver::new();
+        let result = PipelineBuilder::new()
+            .preprocessor(WhitespacePreprocessor)
+            .build()
+            .run_text(text, &cfg, &mut obs);
+        // Punctuation stays attached, so the custom tokenizer was used.
+        assert!(result.phrases.iter().any(|p| p.lemma.contains("crates,")));
+        let (stage, report) = &obs.reports()[0];
+        assert_eq!(*stage, STAGE_PREPROCESS);
+        assert_eq!(report.tokens(), Some(4));
+
+        // The default preprocessor reports the built-in tokenizer's output.
+        let mut obs = StageTimingObserver::new();
+        BaseTextRankPipeline::base_textrank().run_text(text, &cfg, &mut obs);
+        let expected = TokenStream::from_text(text, &cfg).len();
+        assert_eq!(obs.reports()[0].1.tokens(), Some(expected));
+    }
+
+    #[test]
+    fn test_pipeline_phrase_spans() {
+        use crate::types::SpanMode;
+
+        let text = "Café owners need data. Neural network models need data. \
+                    Neural network models win benchmarks.";
+        let cfg = TextRankConfig::default().with_top_n(0);
+        let mut obs = NoopObserver;
+
+        let result = BaseTextRankPipeline::base_textrank().run_text(text, &cfg, &mut obs);
+        for phrase in &result.phrases {
+            assert_eq!(phrase.spans.len(), phrase.count);
+            for &(start, end) in &phrase.spans {
+                assert_eq!(text[start..end].to_lowercase(), phrase.text.to_lowercase());
+            }
+        }
+        let data = result.phrases.iter().find(|p| p.text == "data").unwrap();
+        assert_eq!(data.spans, [(18, 22), (51, 55)]);
+
+        let cfg = cfg.with_span_mode(SpanMode::First);
+        let result = BaseTextRankPipeline::base_textrank().run_text(text, &cfg, &mut obs);
+        assert!(result.phrases.iter().all(|p| p.spans.len() == 1));
+    }
+
     /// Custom observer that captures artifact snapshots.
     struct ArtifactObserver {
         saw_tokens: bool,
@@ -1996,6 +2707,144 @@ mod tests {
         assert!(result.phrases.is_empty());
     }
 
+    #[test]
+    fn test_topic_pipelines_backfill_blocked_phrases() {
+        use crate::types::PhrasePattern;
+
+        let run = |pipeline: &dyn Fn(&TextRankConfig) -> FormattedResult| {
+            let top = pipeline(&TextRankConfig::default()).phrases[0].text.clone();
+            let cfg = TextRankConfig::default()
+                .with_top_n(1)
+                .with_phrase_blocklist([PhrasePattern::Exact(top.clone())]);
+            let result = pipeline(&cfg);
+            assert_eq!(result.phrases.len(), 1, "blocked {top:?}");
+            assert_ne!(result.phrases[0].text, top);
+        };
+        run(&|cfg| {
+            TopicRankPipeline::topic_rank(topic_rank_chunks()).run(
+                TokenStream::from_tokens(&topic_rank_tokens()),
+                cfg,
+                &mut NoopObserver,
+            )
+        });
+        run(&|cfg| {
+            MultipartiteRankPipeline::multipartite_rank(topic_rank_chunks()).run(
+                TokenStream::from_tokens(&topic_rank_tokens()),
+                cfg,
+                &mut NoopObserver,
+            )
+        });
+    }
+
+    #[test]
+    fn test_topic_pipelines_apply_phrase_limits() {
+        use crate::nlp::tokenizer::Tokenizer;
+        use crate::phrase::chunker::NounChunker;
+
+        let text = "Deep neural networks learn features. Neural networks need data. \
+                    Neural networks generalize. Large datasets help deep neural networks. \
+                    Gradient descent trains models.";
+        let tokens = Tokenizer::new().tokenize_with_config(text, &TextRankConfig::default());
+        let chunks = NounChunker::new().extract_chunks(&tokens);
+        let run = |cfg: &TextRankConfig| {
+            let topic = TopicRankPipeline::topic_rank(chunks.clone()).run(
+                TokenStream::from_tokens(&tokens),
+                cfg,
+                &mut NoopObserver,
+            );
+            let multipartite = MultipartiteRankPipeline::multipartite_rank(chunks.clone()).run(
+                TokenStream::from_tokens(&tokens),
+                cfg,
+                &mut NoopObserver,
+            );
+            [topic.phrases, multipartite.phrases]
+        };
+
+        let words = |p: &crate::types::Phrase| p.text.split_whitespace().count();
+        for phrases in run(&TextRankConfig::default()) {
+            assert!(phrases.iter().any(|p| words(p) != 2));
+            assert!(phrases.iter().any(|p| p.count < 2));
+        }
+        for phrases in run(&TextRankConfig::default().with_phrase_words(2, 2)) {
+            assert!(!phrases.is_empty());
+            assert!(phrases.iter().all(|p| words(p) == 2), "{phrases:?}");
+        }
+        for phrases in run(&TextRankConfig::default().with_min_phrase_frequency(2)) {
+            assert!(!phrases.is_empty());
+            assert!(phrases.iter().all(|p| p.count >= 2), "{phrases:?}");
+        }
+    }
+
+    #[test]
+    fn test_topic_pipelines_apply_surface_selection() {
+        use crate::types::{ChunkSpan, SurfaceSelection};
+
+        // "Neural networks learn. neural networks generalize. neural
+        // networks need data."
+        let tokens = vec![
+            Token::new("Neural", "neural", PosTag::Adjective, 0, 6, 0, 0),
+            Token::new("networks", "network", PosTag::Noun, 7, 15, 0, 1),
+            Token::new("learn", "learn", PosTag::Verb, 16, 21, 0, 2),
+            Token::new("neural", "neural", PosTag::Adjective, 23, 29, 1, 3),
+            Token::new("networks", "network", PosTag::Noun, 30, 38, 1, 4),
+            Token::new("generalize", "generalize", PosTag::Verb, 39, 49, 1, 5),
+            Token::new("neural", "neural", PosTag::Adjective, 51, 57, 2, 6),
+            Token::new("networks", "network", PosTag::Noun, 58, 66, 2, 7),
+            Token::new("need", "need", PosTag::Verb, 67, 71, 2, 8),
+            Token::new("data", "data", PosTag::Noun, 72, 76, 2, 9),
+        ];
+        let chunk = |start_token, end_token, start_char, end_char, sentence_idx| ChunkSpan {
+            start_token,
+            end_token,
+            start_char,
+            end_char,
+            sentence_idx,
+        };
+        let chunks = vec![
+            chunk(0, 2, 0, 15, 0),
+            chunk(3, 5, 23, 38, 1),
+            chunk(6, 8, 51, 66, 2),
+            chunk(9, 10, 72, 76, 2),
+        ];
+        let network = |cfg: &TextRankConfig| {
+            let topic = TopicRankPipeline::topic_rank(chunks.clone()).run(
+                TokenStream::from_tokens(&tokens),
+                cfg,
+                &mut NoopObserver,
+            );
+            let multipartite = MultipartiteRankPipeline::multipartite_rank(chunks.clone()).run(
+                TokenStream::from_tokens(&tokens),
+                cfg,
+                &mut NoopObserver,
+            );
+            [topic, multipartite].map(|result| {
+                let phrase = result
+                    .phrases
+                    .into_iter()
+                    .find(|p| p.lemma == "neural network");
+                phrase.expect("neural network phrase").text
+            })
+        };
+
+        let [topic, _] = network(&TextRankConfig::default());
+        assert_eq!(topic, "Neural networks");
+        let cfg = TextRankConfig::default();
+        for selection in [
+            SurfaceSelection::FirstOccurrence,
+            SurfaceSelection::MostFrequent,
+            SurfaceSelection::Longest,
+            SurfaceSelection::Lemma,
+        ] {
+            let expected = match selection {
+                SurfaceSelection::MostFrequent => "neural networks",
+                SurfaceSelection::Lemma => "neural network",
+                _ => "Neural networks",
+            };
+            let texts = network(&cfg.clone().with_surface_selection(selection));
+            assert_eq!(texts, [expected; 2], "{selection:?}");
+        }
+    }
+
     #[test]
     fn test_topic_rank_pipeline_deterministic() {
         let tokens = topic_rank_tokens();
@@ -2693,6 +3542,28 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "arena")]
+    #[test]
+    fn test_run_with_workspace_arena_matches_run() {
+        let cfg = TextRankConfig::default().with_window_size(4);
+        let mut obs = NoopObserver;
+        let mut ws = crate::pipeline::artifacts::PipelineWorkspace::new();
+
+        for pipeline in [
+            BaseTextRankPipeline::base_textrank(),
+            BaseTextRankPipeline::single_rank(),
+        ] {
+            let expected = pipeline.run(make_token_stream(), &cfg, &mut obs);
+            ws.clear();
+            let result = pipeline.run_with_workspace(make_token_stream(), &cfg, &mut obs, &mut ws);
+            assert!(ws.arena().allocated_bytes() > 0);
+            assert_eq!(
+                serde_json::to_string(&result.phrases).unwrap(),
+                serde_json::to_string(&expected.phrases).unwrap()
+            );
+        }
+    }
+
     #[test]
     fn test_run_batch_matches_sequential_runs() {
         let pipeline = BaseTextRankPipeline::base_textrank();
@@ -2757,6 +3628,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 0,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "is".into(),
@@ -2767,6 +3639,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 1,
                 is_stopword: true,
+                section: None,
             },
             Token {
                 text: "a".into(),
@@ -2777,6 +3650,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 2,
                 is_stopword: true,
+                section: None,
             },
             Token {
                 text: "systems".into(),
@@ -2787,6 +3661,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 3,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "programming".into(),
@@ -2797,6 +3672,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 4,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "language".into(),
@@ -2807,6 +3683,7 @@ mod tests {
                 sentence_idx: 0,
                 token_idx: 5,
                 is_stopword: false,
+                section: None,
             },
             // Sentence 1: "Python is popular for data science"
             Token {
@@ -2818,6 +3695,7 @@ mod tests {
                 sentence_idx: 1,
                 token_idx: 6,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "is".into(),
@@ -2828,6 +3706,7 @@ mod tests {
                 sentence_idx: 1,
                 token_idx: 7,
                 is_stopword: true,
+                section: None,
             },
             Token {
                 text: "popular".into(),
@@ -2838,6 +3717,7 @@ mod tests {
                 sentence_idx: 1,
                 token_idx: 8,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "for".into(),
@@ -2848,6 +3728,7 @@ mod tests {
                 sentence_idx: 1,
                 token_idx: 9,
                 is_stopword: true,
+                section: None,
             },
             Token {
                 text: "data".into(),
@@ -2858,6 +3739,7 @@ mod tests {
                 sentence_idx: 1,
                 token_idx: 10,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "science".into(),
@@ -2868,6 +3750,7 @@ mod tests {
                 sentence_idx: 1,
                 token_idx: 11,
                 is_stopword: false,
+                section: None,
             },
             // Sentence 2: "Both languages support machine learning"
             Token {
@@ -2879,6 +3762,7 @@ mod tests {
                 sentence_idx: 2,
                 token_idx: 12,
                 is_stopword: true,
+                section: None,
             },
             Token {
                 text: "languages".into(),
@@ -2889,6 +3773,7 @@ mod tests {
                 sentence_idx: 2,
                 token_idx: 13,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "support".into(),
@@ -2899,6 +3784,7 @@ mod tests {
                 sentence_idx: 2,
                 token_idx: 14,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "machine".into(),
@@ -2909,6 +3795,7 @@ mod tests {
                 sentence_idx: 2,
                 token_idx: 15,
                 is_stopword: false,
+                section: None,
             },
             Token {
                 text: "learning".into(),
@@ -2919,6 +3806,7 @@ mod tests {
                 sentence_idx: 2,
                 token_idx: 16,
                 is_stopword: false,
+                section: None,
             },
         ]
     }
@@ -2964,4 +3852,475 @@ mod tests {
         let result = pipeline.run(stream, &cfg, &mut obs);
         assert!(result.phrases.is_empty());
     }
+
+    #[test]
+    fn test_run_cancellable_aborts_after_current_stage() {
+        let pipeline = BaseTextRankPipeline::base_textrank();
+        let cfg = TextRankConfig::default();
+        let token = CancellationToken::new();
+
+        let mut obs = StageTimingObserver::new();
+        let result = pipeline.run_cancellable(make_token_stream(), &cfg, &mut obs, &token);
+        assert!(result.error.is_none());
+        assert!(!result.phrases.is_empty());
+
+        token.cancel();
+        let mut obs = StageTimingObserver::new();
+        let result = pipeline.run_cancellable(make_token_stream(), &cfg, &mut obs, &token);
+        let err = result.error.unwrap();
+        assert_eq!(err.code, ErrorCode::Cancelled);
+        assert_eq!(err.stage, STAGE_PREPROCESS);
+        assert!(result.phrases.is_empty());
+        // The stage that was running still reported.
+        assert_eq!(obs.reports().len(), 1);
+    }
+
+    #[test]
+    fn test_stage_timeout_reports_partial_stages() {
+        struct SlowPreprocessor;
+        impl Preprocessor for SlowPreprocessor {
+            fn preprocess(&self, _tokens: &mut TokenStream, _cfg: &TextRankConfig) {
+                std::thread::sleep(std::time::Duration::from_millis(5));
+            }
+        }
+
+        let pipeline = PipelineBuilder::new()
+            .preprocessor(SlowPreprocessor)
+            .build();
+        let cfg = TextRankConfig::default().with_stage_timeout_ms(1);
+        let mut obs = StageTimingObserver::new();
+        let result = pipeline.run(make_token_stream(), &cfg, &mut obs);
+
+        let err = result.error.unwrap();
+        assert_eq!(err.code, ErrorCode::Timeout);
+        assert_eq!(err.stage, STAGE_PREPROCESS);
+        assert_eq!(err.path, "/runtime/stage_timeout_ms");
+        assert_eq!(obs.reports().len(), 1);
+
+        // A generous budget lets the same pipeline finish.
+        let cfg = TextRankConfig::default()
+            .with_stage_timeout_ms(60_000)
+            .with_pipeline_timeout_ms(60_000);
+        let result = pipeline.run(make_token_stream(), &cfg, &mut NoopObserver);
+        assert!(result.error.is_none());
+    }
+
+    /// Iterates for up to `max` rounds, sleeping 1 ms per round and
+    /// reporting every round; cancels `cancel_at` after round 3.
+    struct SlowRanker {
+        max: u32,
+        rounds: std::sync::atomic::AtomicU32,
+        cancel_at: Option<CancellationToken>,
+    }
+
+    impl SlowRanker {
+        fn new(max: u32, cancel_at: Option<CancellationToken>) -> Self {
+            Self {
+                max,
+                rounds: Default::default(),
+                cancel_at,
+            }
+        }
+
+        fn rounds(&self) -> u32 {
+            self.rounds.load(std::sync::atomic::Ordering::Relaxed)
+        }
+    }
+
+    impl Ranker for SlowRanker {
+        fn rank(
+            &self,
+            graph: &Graph,
+            teleport: Option<&TeleportVector>,
+            cfg: &TextRankConfig,
+        ) -> RankOutput {
+            self.rank_with_progress(graph, teleport, cfg, 1, &mut |_, _| {
+                ControlFlow::Continue(())
+            })
+        }
+
+        fn rank_with_progress(
+            &self,
+            graph: &Graph,
+            _teleport: Option<&TeleportVector>,
+            _cfg: &TextRankConfig,
+            _every: u32,
+            progress: &mut dyn FnMut(u32, f64) -> ControlFlow<()>,
+        ) -> RankOutput {
+            let n = graph.num_nodes();
+            for round in 1..=self.max {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+                self.rounds
+                    .store(round, std::sync::atomic::Ordering::Relaxed);
+                if round == 3 {
+                    if let Some(token) = &self.cancel_at {
+                        token.cancel();
+                    }
+                }
+                if progress(round, 1.0).is_break() {
+                    return RankOutput::new(vec![1.0 / n as f64; n], false, round, 1.0);
+                }
+            }
+            RankOutput::new(vec![1.0 / n as f64; n], true, self.max, 0.0)
+        }
+    }
+
+    #[test]
+    fn test_budget_interrupts_slow_ranker() {
+        // Stage timeout: aborts after a few rounds, not after all 10,000.
+        let pipeline = PipelineBuilder::new()
+            .ranker(SlowRanker::new(10_000, None))
+            .build();
+        let cfg = TextRankConfig::default().with_stage_timeout_ms(5);
+        let mut obs = StageTimingObserver::new();
+        let result = pipeline.run(make_token_stream(), &cfg, &mut obs);
+        let err = result.error.unwrap();
+        assert_eq!(err.code, ErrorCode::Timeout);
+        assert_eq!(err.stage, STAGE_RANK);
+        assert!(pipeline.ranker.rounds() < 1_000);
+        assert_eq!(obs.reports().last().map(|(s, _)| *s), Some(STAGE_RANK));
+
+        // Cancellation mid-rank stops at the next progress report.
+        let token = CancellationToken::new();
+        let pipeline = PipelineBuilder::new()
+            .ranker(SlowRanker::new(10_000, Some(token.clone())))
+            .build();
+        let cfg = TextRankConfig::default();
+        let result = pipeline.run_cancellable(make_token_stream(), &cfg, &mut NoopObserver, &token);
+        let err = result.error.unwrap();
+        assert_eq!(err.code, ErrorCode::Cancelled);
+        assert_eq!(err.stage, STAGE_RANK);
+        assert_eq!(pipeline.ranker.rounds(), 3);
+    }
+
+    #[test]
+    fn test_cancellation_interrupts_graph_build() {
+        /// Cancels the token on the first graph progress report.
+        struct CancelOnGraphProgress {
+            token: CancellationToken,
+            reports: usize,
+        }
+        impl PipelineObserver for CancelOnGraphProgress {
+            fn progress_interval(&self) -> Option<ProgressInterval> {
+                Some(ProgressInterval {
+                    rank_iterations: 1,
+                    graph_tokens: 1,
+                })
+            }
+            fn on_graph_progress(&mut self, _done: usize, _total: usize) {
+                self.reports += 1;
+                self.token.cancel();
+            }
+        }
+
+        let token = CancellationToken::new();
+        let mut obs = CancelOnGraphProgress {
+            token: token.clone(),
+            reports: 0,
+        };
+        let pipeline = BaseTextRankPipeline::base_textrank();
+        let cfg = TextRankConfig::default();
+        let result = pipeline.run_cancellable(make_token_stream(), &cfg, &mut obs, &token);
+        let err = result.error.unwrap();
+        assert_eq!(err.code, ErrorCode::Cancelled);
+        assert_eq!(err.stage, STAGE_GRAPH);
+        assert_eq!(obs.reports, 1);
+    }
+
+    #[test]
+    fn test_custom_stage_filters_candidates_and_reports() {
+        use crate::pipeline::artifacts::CandidateKind;
+
+        /// Keeps only the candidate with the given lemma.
+        struct KeepOnly(&'static str);
+        impl Stage for KeepOnly {
+            fn name(&self) -> &'static str {
+                "keep_only"
+            }
+            fn run(&self, artifacts: &mut StageArtifacts<'_>, _cfg: &TextRankConfig) {
+                assert!(artifacts.graph.is_none());
+                let pool = artifacts.tokens.pool();
+                if let Some(CandidateKind::Words(words)) =
+                    artifacts.candidates.as_mut().map(|c| c.kind_mut())
+                {
+                    words.retain(|w| pool.get(w.lemma_id) == Some(self.0));
+                }
+            }
+        }
+
+        /// Checks that rank output is visible after ranking.
+        struct SeesRank;
+        impl Stage for SeesRank {
+            fn name(&self) -> &'static str {
+                "sees_rank"
+            }
+            fn run(&self, artifacts: &mut StageArtifacts<'_>, _cfg: &TextRankConfig) {
+                assert!(artifacts.graph.is_some());
+                assert!(artifacts.rank.is_some());
+                assert!(artifacts.phrases.is_none());
+            }
+        }
+
+        let pipeline = BaseTextRankPipeline::base_textrank()
+            .with_stage(StagePoint::AfterRank, SeesRank)
+            .with_stage(StagePoint::AfterCandidates, KeepOnly("rust"));
+        let cfg = TextRankConfig::default();
+        let mut obs = StageTimingObserver::new();
+        let result = pipeline.run(make_token_stream(), &cfg, &mut obs);
+
+        assert_eq!(result.phrases.len(), 1);
+        assert_eq!(result.phrases[0].lemma, "rust");
+
+        let names: Vec<&str> = obs.reports().iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            vec![
+                STAGE_PREPROCESS,
+                STAGE_CANDIDATES,
+                "keep_only",
+                STAGE_GRAPH,
+                STAGE_GRAPH_TRANSFORM,
+                STAGE_TELEPORT,
+                STAGE_RANK,
+                "sees_rank",
+                STAGE_PHRASES,
+                STAGE_FORMAT,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_failing_custom_stage_aborts_with_chained_error() {
+        use crate::pipeline::errors::PipelineError;
+
+        struct Lookup;
+        impl Stage for Lookup {
+            fn name(&self) -> &'static str {
+                "lookup"
+            }
+            fn run(&self, artifacts: &mut StageArtifacts<'_>, cfg: &TextRankConfig) {
+                let _ = self.try_run(artifacts, cfg);
+            }
+            fn try_run(
+                &self,
+                _artifacts: &mut StageArtifacts<'_>,
+                _cfg: &TextRankConfig,
+            ) -> Result<(), PipelineError> {
+                let cause = std::io::Error::new(std::io::ErrorKind::NotFound, "terms.txt");
+                Err(
+                    PipelineError::new(ErrorCode::StageFailed, self.name(), "no term list")
+                        .with_source(cause),
+                )
+            }
+        }
+
+        let pipeline =
+            BaseTextRankPipeline::base_textrank().with_stage(StagePoint::AfterCandidates, Lookup);
+        let mut obs = StageTimingObserver::new();
+        let result = pipeline.run(make_token_stream(), &TextRankConfig::default(), &mut obs);
+
+        assert!(result.phrases.is_empty());
+        let err = result.error.unwrap();
+        assert_eq!(err.code, ErrorCode::StageFailed);
+        assert_eq!(err.stage, "lookup");
+        assert_eq!(err.message, "no term list: terms.txt");
+        // The failing stage is still reported; nothing after it runs.
+        let names: Vec<&str> = obs.reports().iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, [STAGE_PREPROCESS, STAGE_CANDIDATES, "lookup"]);
+    }
+
+    #[test]
+    fn test_non_convergence_policy() {
+        #[derive(Default)]
+        struct Warnings(Vec<PipelineError>);
+        impl PipelineObserver for Warnings {
+            fn on_warning(&mut self, warning: &PipelineError) {
+                self.0.push(warning.clone());
+            }
+        }
+
+        let pipeline = BaseTextRankPipeline::base_textrank();
+        let run = |policy| {
+            let cfg = TextRankConfig {
+                max_iterations: 1,
+                ..Default::default()
+            }
+            .with_non_convergence(policy);
+            let mut obs = Warnings::default();
+            let result = pipeline.run(make_token_stream(), &cfg, &mut obs);
+            (result, obs.0)
+        };
+
+        let (silent, warnings) = run(NonConvergencePolicy::Continue);
+        assert!(!silent.converged && !silent.phrases.is_empty());
+        assert!(silent.error.is_none() && warnings.is_empty());
+
+        let (warned, warnings) = run(NonConvergencePolicy::WarnAndContinue);
+        assert_eq!(warned.phrases, silent.phrases);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, ErrorCode::ConvergenceFailed);
+        assert_eq!(warnings[0].stage, STAGE_RANK);
+
+        let (failed, _) = run(NonConvergencePolicy::Fail);
+        assert!(failed.phrases.is_empty());
+        let err = failed.error.unwrap();
+        assert_eq!(err.code, ErrorCode::ConvergenceFailed);
+        assert_eq!(
+            (err.stage.as_str(), err.path.as_str()),
+            (STAGE_RANK, "/modules/rank")
+        );
+
+        // A converged run never warns or fails.
+        let cfg = TextRankConfig::default().with_non_convergence(NonConvergencePolicy::Fail);
+        let result = pipeline.run(make_token_stream(), &cfg, &mut NoopObserver);
+        assert!(result.converged && result.error.is_none());
+    }
+
+    #[test]
+    fn test_builder_registers_custom_stage() {
+        struct Nothing;
+        impl Stage for Nothing {
+            fn name(&self) -> &'static str {
+                "nothing"
+            }
+            fn run(&self, _artifacts: &mut StageArtifacts<'_>, _cfg: &TextRankConfig) {}
+        }
+
+        let pipeline = PipelineBuilder::new()
+            .stage(StagePoint::AfterPhrases, Nothing)
+            .build();
+        let names: Vec<_> = pipeline
+            .custom_stages
+            .names_at(StagePoint::AfterPhrases)
+            .collect();
+        assert_eq!(names, ["nothing"]);
+        assert_eq!(
+            format!("{:?}", pipeline.custom_stages),
+            "[(AfterPhrases, \"nothing\")]"
+        );
+    }
+
+    #[test]
+    fn test_run_cached_skips_upstream_stages_on_hit() {
+        let pipeline = BaseTextRankPipeline::base_textrank();
+        let mut cache = PipelineCache::new();
+        let names = |obs: &StageTimingObserver| -> Vec<&str> {
+            obs.reports().iter().map(|(name, _)| *name).collect()
+        };
+
+        let cfg = TextRankConfig::default();
+        let mut obs = StageTimingObserver::new();
+        let first = pipeline.run_cached(make_token_stream(), &cfg, &mut obs, &mut cache);
+        assert_eq!(
+            first.phrases,
+            pipeline
+                .run(make_token_stream(), &cfg, &mut NoopObserver)
+                .phrases
+        );
+        assert_eq!(names(&obs).len(), 8);
+        assert_eq!((cache.hits(), cache.misses(), cache.len()), (0, 1, 1));
+
+        // A ranker-only change reuses the graph.
+        let swept = TextRankConfig::default().with_damping(0.7);
+        let mut obs = StageTimingObserver::new();
+        let second = pipeline.run_cached(make_token_stream(), &swept, &mut obs, &mut cache);
+        assert_eq!(
+            second.phrases,
+            pipeline
+                .run(make_token_stream(), &swept, &mut NoopObserver)
+                .phrases
+        );
+        assert_eq!(
+            names(&obs),
+            vec![STAGE_TELEPORT, STAGE_RANK, STAGE_PHRASES, STAGE_FORMAT]
+        );
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+        // An upstream change misses.
+        let wider = TextRankConfig {
+            window_size: 5,
+            ..Default::default()
+        };
+        pipeline.run_cached(make_token_stream(), &wider, &mut NoopObserver, &mut cache);
+        assert_eq!((cache.hits(), cache.misses(), cache.len()), (1, 2, 2));
+    }
+
+    #[test]
+    fn test_run_cached_misses_when_determinism_changes() {
+        use crate::types::DeterminismMode;
+
+        // Fast mode may build the graph in parallel, so a deterministic
+        // run must not reuse it.
+        let pipeline = BaseTextRankPipeline::base_textrank();
+        let mut cache = PipelineCache::new();
+        let fast = TextRankConfig::default();
+        let deterministic = fast
+            .clone()
+            .with_determinism(DeterminismMode::Deterministic);
+
+        pipeline.run_cached(make_token_stream(), &fast, &mut NoopObserver, &mut cache);
+        let mut obs = StageTimingObserver::new();
+        let result = pipeline.run_cached(make_token_stream(), &deterministic, &mut obs, &mut cache);
+        assert_eq!((cache.hits(), cache.misses(), cache.len()), (0, 2, 2));
+        assert_eq!(obs.reports()[0].0, STAGE_PREPROCESS);
+        assert_eq!(
+            result.phrases,
+            pipeline
+                .run(make_token_stream(), &deterministic, &mut NoopObserver)
+                .phrases
+        );
+    }
+
+    #[test]
+    fn test_explain_lists_stages_and_effective_config() {
+        struct Audit;
+        impl Stage for Audit {
+            fn name(&self) -> &'static str {
+                "audit"
+            }
+            fn run(&self, _artifacts: &mut StageArtifacts<'_>, _cfg: &TextRankConfig) {}
+        }
+
+        let pipeline =
+            BaseTextRankPipeline::base_textrank().with_stage(StagePoint::AfterRank, Audit);
+        let cfg = TextRankConfig::default().with_damping(0.7);
+        let plan = pipeline.explain(&cfg);
+
+        let names: Vec<&str> = plan.stages.iter().map(|s| s.name).collect();
+        assert_eq!(
+            names,
+            [
+                STAGE_PREPROCESS,
+                STAGE_CANDIDATES,
+                STAGE_GRAPH,
+                STAGE_GRAPH_TRANSFORM,
+                STAGE_TELEPORT,
+                STAGE_RANK,
+                "audit",
+                STAGE_PHRASES,
+                STAGE_FORMAT,
+            ]
+        );
+        assert!(plan.stage("audit").unwrap().custom);
+        assert_eq!(
+            plan.stage(STAGE_CANDIDATES).unwrap().implementation,
+            "WordNodeSelector"
+        );
+        assert_eq!(
+            plan.stage(STAGE_GRAPH).unwrap().implementation,
+            "WindowGraphBuilder { window_strategy: SentenceBounded { window_size: 3 }, edge_weight_policy: CountAccumulating }"
+        );
+        assert_eq!(plan.config.damping, 0.7);
+        assert_eq!(
+            plan.config.window_size,
+            TextRankConfig::default().window_size
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&plan.to_json().unwrap()).unwrap();
+        assert_eq!(json["stages"][5]["implementation"], "PageRankRanker");
+        assert!(json.get("spec").is_none());
+        assert!(plan
+            .to_string()
+            .starts_with("preprocess: NoopPreprocessor\n"));
+    }
 }
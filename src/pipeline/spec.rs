@@ -26,13 +26,15 @@
 //! }
 //! ```
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use serde::{Deserialize, Serialize};
 
 use super::artifacts::DebugLevel;
 use super::error_code::ErrorCode;
 use super::errors::PipelineSpecError;
+use crate::errors::TextRankError;
+use crate::types::{Section, TextRankConfig};
 
 // ─── PipelineSpec (untagged enum) ──────────────────────────────────────────
 
@@ -49,6 +51,25 @@ pub enum PipelineSpec {
 }
 
 impl PipelineSpec {
+    /// Parse a spec from a JSON string.
+    ///
+    /// Malformed JSON and values of the wrong type are reported as
+    /// [`ErrorCode::InvalidValue`]. Unknown fields are captured rather than
+    /// rejected; [`ValidationEngine`](super::validation::ValidationEngine)
+    /// reports them.
+    ///
+    /// Other formats work through serde directly, e.g.
+    /// `serde_yaml::from_str::<PipelineSpec>(yaml)`.
+    pub fn from_json(json: &str) -> Result<Self, PipelineSpecError> {
+        serde_json::from_str(json).map_err(|e| {
+            PipelineSpecError::new(
+                ErrorCode::InvalidValue,
+                "",
+                format!("invalid pipeline spec: {e}"),
+            )
+        })
+    }
+
     /// Returns a reference to the inner `PipelineSpecV1` if this is a `V1` variant.
     pub fn as_v1(&self) -> Option<&PipelineSpecV1> {
         match self {
@@ -95,6 +116,13 @@ pub struct PipelineSpecV1 {
     #[serde(default)]
     pub expose: Option<ExposeSpec>,
 
+    /// [`TextRankConfig`](crate::types::TextRankConfig) field overrides,
+    /// keyed by field name (e.g. `"damping"`, `"top_n"`).
+    ///
+    /// Applied over the caller's base config by [`apply_config`](Self::apply_config).
+    #[serde(default)]
+    pub config: HashMap<String, serde_json::Value>,
+
     /// If `true`, unrecognized fields are errors; if `false`, warnings.
     #[serde(default)]
     pub strict: bool,
@@ -140,6 +168,56 @@ pub struct ModuleSet {
     pub unknown_fields: HashMap<String, serde_json::Value>,
 }
 
+impl PipelineSpecV1 {
+    /// Apply the [`config`](Self::config) overrides on top of `base`.
+    ///
+    /// Keys that are not `TextRankConfig` fields are skipped — the
+    /// validation engine reports them as [`ErrorCode::UnknownField`]. A value
+    /// of the wrong type fails with [`ErrorCode::InvalidValue`], and the
+    /// merged config must pass [`TextRankConfig::validate`]; its error is
+    /// reported at `/config/<field>`.
+    pub fn apply_config(&self, base: &TextRankConfig) -> Result<TextRankConfig, PipelineSpecError> {
+        let cfg = if self.config.is_empty() {
+            base.clone()
+        } else {
+            let mut fields = config_fields(base);
+            for (key, value) in &self.config {
+                if let Some(slot) = fields.get_mut(key) {
+                    *slot = value.clone();
+                }
+            }
+            serde_json::from_value(serde_json::Value::Object(fields)).map_err(|e| {
+                PipelineSpecError::new(
+                    ErrorCode::InvalidValue,
+                    "/config",
+                    format!("invalid config override: {e}"),
+                )
+            })?
+        };
+        cfg.validate().map_err(|e| config_spec_error(&e))?;
+        Ok(cfg)
+    }
+}
+
+/// A [`TextRankConfig::validate`] error as a spec error at `/config/<field>`.
+pub(crate) fn config_spec_error(err: &TextRankError) -> PipelineSpecError {
+    let code = err.config_code().unwrap_or(ErrorCode::ValidationFailed);
+    let path = format!("/config{}", err.config_path().unwrap_or_default());
+    let message = match err {
+        TextRankError::InvalidConfig { message, .. } => message.clone(),
+        other => other.to_string(),
+    };
+    PipelineSpecError::new(code, path, message)
+}
+
+/// `cfg` as a JSON object keyed by field name.
+pub(crate) fn config_fields(cfg: &TextRankConfig) -> serde_json::Map<String, serde_json::Value> {
+    match serde_json::to_value(cfg) {
+        Ok(serde_json::Value::Object(fields)) => fields,
+        _ => serde_json::Map::new(),
+    }
+}
+
 // ─── Preset resolution ──────────────────────────────────────────────────────
 
 /// Valid canonical preset names, used in error hints and capability discovery.
@@ -187,7 +265,10 @@ pub fn resolve_preset(name: &str) -> Result<ModuleSet, PipelineSpecError> {
 
         // ── PositionRank ────────────────────────────────────────────
         "position_rank" | "positionrank" | "position" => Ok(ModuleSet {
-            teleport: Some(TeleportSpec::Position { shape: None }),
+            teleport: Some(TeleportSpec::Position {
+                shape: None,
+                half_life: None,
+            }),
             ..Default::default()
         }),
 
@@ -203,6 +284,7 @@ pub fn resolve_preset(name: &str) -> Result<ModuleSet, PipelineSpecError> {
                 window_size: None,
                 cross_sentence: Some(true),
                 edge_weighting: None,
+                scope: None,
             }),
             ..Default::default()
         }),
@@ -213,6 +295,7 @@ pub fn resolve_preset(name: &str) -> Result<ModuleSet, PipelineSpecError> {
                 window_size: None,
                 cross_sentence: Some(true),
                 edge_weighting: None,
+                scope: None,
             }),
             teleport: Some(TeleportSpec::TopicWeights),
             ..Default::default()
@@ -246,6 +329,8 @@ pub fn resolve_preset(name: &str) -> Result<ModuleSet, PipelineSpecError> {
             candidates: Some(CandidatesSpec::SentenceCandidates),
             graph: Some(GraphSpec::SentenceGraph {
                 min_similarity: None,
+                similarity: None,
+                binary_edges: None,
             }),
             phrases: Some(PhraseSpec::SentencePhrases),
             format: Some(FormatSpec::SentenceJson {
@@ -293,6 +378,7 @@ pub fn resolve_spec(spec: &PipelineSpec) -> Result<PipelineSpecV1, PipelineSpecE
                 modules,
                 runtime: RuntimeSpec::default(),
                 expose: None,
+                config: HashMap::new(),
                 strict: false,
                 unknown_fields: HashMap::new(),
             })
@@ -307,6 +393,7 @@ pub fn resolve_spec(spec: &PipelineSpec) -> Result<PipelineSpecV1, PipelineSpecE
                     modules: merged,
                     runtime: v1.runtime.clone(),
                     expose: v1.expose.clone(),
+                    config: v1.config.clone(),
                     strict: v1.strict,
                     unknown_fields: v1.unknown_fields.clone(),
                 })
@@ -402,6 +489,16 @@ pub enum CandidatesSpec {
     WordNodes,
     /// Noun-phrase chunks as candidates (TopicRank/MultipartiteRank family).
     PhraseCandidates,
+    /// Maximal adjective+noun chunks built from the tokens, as phrase-level
+    /// candidates (no pre-computed chunks needed).
+    NounChunks,
+    /// Words and phrases matched by a POS-pattern grammar, e.g.
+    /// `"(Adj)* (Noun)+"` or `"Noun Prep Noun"` (defaults to
+    /// `<ADJ>*<NOUN|PROPN>+`).
+    Grammar {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pattern: Option<String>,
+    },
     /// Whole sentences as candidates (SentenceRank / extractive summarization).
     #[cfg(feature = "sentence-rank")]
     SentenceCandidates,
@@ -412,6 +509,8 @@ impl CandidatesSpec {
         match self {
             Self::WordNodes => "word_nodes",
             Self::PhraseCandidates => "phrase_candidates",
+            Self::NounChunks => "noun_chunks",
+            Self::Grammar { .. } => "grammar",
             #[cfg(feature = "sentence-rank")]
             Self::SentenceCandidates => "sentence_candidates",
         }
@@ -430,16 +529,22 @@ pub enum GraphSpec {
         cross_sentence: Option<bool>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         edge_weighting: Option<EdgeWeightingSpec>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        scope: Option<WindowScopeSpec>,
     },
     /// Topic-level graph where nodes are phrase clusters (TopicRank).
     TopicGraph,
     /// Candidate-level graph with inter-cluster edges (MultipartiteRank).
     CandidateGraph,
-    /// Sentence-level graph with Jaccard-similarity edges (SentenceRank).
+    /// Sentence-level graph with similarity-weighted edges (SentenceRank).
     #[cfg(feature = "sentence-rank")]
     SentenceGraph {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         min_similarity: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        similarity: Option<SentenceSimilaritySpec>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        binary_edges: Option<bool>,
     },
 }
 
@@ -468,25 +573,36 @@ impl GraphSpec {
                     window_size,
                     cross_sentence,
                     edge_weighting,
+                    scope,
                 },
                 Self::CooccurrenceWindow {
                     window_size: fb_ws,
                     cross_sentence: fb_cs,
                     edge_weighting: fb_ew,
+                    scope: fb_sc,
                 },
             ) => Self::CooccurrenceWindow {
                 window_size: window_size.or(*fb_ws),
                 cross_sentence: cross_sentence.or(*fb_cs),
                 edge_weighting: (*edge_weighting).or(*fb_ew),
+                scope: scope.or(*fb_sc),
             },
             #[cfg(feature = "sentence-rank")]
             (
-                Self::SentenceGraph { min_similarity },
+                Self::SentenceGraph {
+                    min_similarity,
+                    similarity,
+                    binary_edges,
+                },
                 Self::SentenceGraph {
                     min_similarity: fb_ms,
+                    similarity: fb_sim,
+                    binary_edges: fb_be,
                 },
             ) => Self::SentenceGraph {
                 min_similarity: min_similarity.or(*fb_ms),
+                similarity: (*similarity).or(*fb_sim),
+                binary_edges: binary_edges.or(*fb_be),
             },
             // Different variants — user wins entirely.
             _ => self.clone(),
@@ -495,21 +611,84 @@ impl GraphSpec {
 }
 
 /// Edge weighting strategy for co-occurrence graphs.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EdgeWeightingSpec {
     Binary,
     Count,
+    /// Count weighted by `1 / token distance`.
+    DistanceDecay,
+    /// Count weighted by `exp(-token distance / tau)`.
+    ExponentialDecay {
+        tau: f64,
+    },
+    /// Positive pointwise mutual information.
+    Pmi,
+    /// Dice coefficient.
+    Dice,
+}
+
+/// Co-occurrence scope for window graphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowScopeSpec {
+    /// Sliding window of `window_size` tokens.
+    Window,
+    /// Every pair of candidates in the same sentence.
+    Sentence,
+    /// Every pair of candidates in the same paragraph; in the same sentence
+    /// for token input, which carries no paragraph information.
+    Paragraph,
+}
+
+/// Sentence similarity function for sentence graphs.
+#[cfg(feature = "sentence-rank")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SentenceSimilaritySpec {
+    /// Jaccard similarity of lemma sets.
+    Jaccard,
+    /// Word overlap normalized by log sentence length (original TextRank).
+    Overlap,
+    /// Cosine over sentence TF-IDF vectors (LexRank).
+    TfIdfCosine,
+    /// Okapi BM25 with IDF over the document's sentences.
+    Bm25,
 }
 
 /// Graph post-processing transforms.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum GraphTransformSpec {
     /// Remove edges between candidates in the same cluster.
     RemoveIntraClusterEdges,
     /// Apply alpha-boost weighting to first-occurring cluster members.
     AlphaBoost,
+    /// Prune the graph to its k-core (default `k`: 2).
+    KCore {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        k: Option<usize>,
+    },
+    /// Drop edges below an absolute weight or a weight quantile.
+    EdgeThreshold {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_weight: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        quantile: Option<f64>,
+    },
+    /// Prune nodes below a degree and/or total edge weight threshold.
+    DegreeThreshold {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_degree: Option<u32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_weight: Option<f64>,
+    },
+    /// Merge frequent adjacent word pairs into meta-vertices (default
+    /// `min_count`: 2).
+    MetaVertex {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_count: Option<u32>,
+    },
 }
 
 impl GraphTransformSpec {
@@ -521,25 +700,42 @@ impl GraphTransformSpec {
         match self {
             Self::RemoveIntraClusterEdges => "remove_intra_cluster_edges",
             Self::AlphaBoost => "alpha_boost",
+            Self::KCore { .. } => "k_core",
+            Self::EdgeThreshold { .. } => "edge_threshold",
+            Self::DegreeThreshold { .. } => "degree_threshold",
+            Self::MetaVertex { .. } => "meta_vertex",
         }
     }
 }
 
 /// Teleport (personalization) strategy for PageRank.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum TeleportSpec {
     /// Uniform distribution (equivalent to no personalization).
     Uniform,
     /// Position-weighted: earlier tokens get higher teleport probability.
     Position {
+        /// Decay function: `"first_occurrence"` (default), `"inverse"` or
+        /// `"exponential"` (see [`PositionDecay`](super::teleport::PositionDecay)).
         #[serde(default, skip_serializing_if = "Option::is_none")]
         shape: Option<String>,
+        /// Half-life in tokens for the `"exponential"` shape.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        half_life: Option<f64>,
     },
     /// Focus-terms-biased: specified terms get boosted teleport probability.
     FocusTerms,
     /// Topic-weighted: per-lemma weights from external topic model.
     TopicWeights,
+    /// Section-weighted: words in boosted document sections (title,
+    /// abstract, heading) get higher teleport probability.
+    Section {
+        /// Multiplier per section; unset sections keep the defaults
+        /// (title 3.0, abstract 2.0, heading 1.5, body 1.0).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        boosts: Option<BTreeMap<Section, f64>>,
+    },
 }
 
 impl TeleportSpec {
@@ -549,14 +745,32 @@ impl TeleportSpec {
             Self::Position { .. } => "position",
             Self::FocusTerms => "focus_terms",
             Self::TopicWeights => "topic_weights",
+            Self::Section { .. } => "section",
         }
     }
 
     /// Deep-merge optional parameters when both sides are the same variant.
     pub fn merge_with(&self, fallback: &Self) -> Self {
         match (self, fallback) {
-            (Self::Position { shape }, Self::Position { shape: fb_shape }) => Self::Position {
+            (
+                Self::Position { shape, half_life },
+                Self::Position {
+                    shape: fb_shape,
+                    half_life: fb_half_life,
+                },
+            ) => Self::Position {
                 shape: shape.clone().or_else(|| fb_shape.clone()),
+                half_life: half_life.or(*fb_half_life),
+            },
+            (Self::Section { boosts }, Self::Section { boosts: fb_boosts }) => Self::Section {
+                boosts: match (boosts, fb_boosts) {
+                    (Some(b), Some(fb)) => {
+                        let mut merged = fb.clone();
+                        merged.extend(b.iter().map(|(s, w)| (*s, *w)));
+                        Some(merged)
+                    }
+                    _ => boosts.clone().or_else(|| fb_boosts.clone()),
+                },
             },
             _ => self.clone(),
         }
@@ -607,6 +821,13 @@ pub enum RankSpec {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         convergence_threshold: Option<f64>,
     },
+    /// HITS hub or authority scores (default: authority).
+    Hits {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        score: Option<HitsScoreSpec>,
+    },
+    /// Weighted degree centrality.
+    Degree,
 }
 
 impl RankSpec {
@@ -614,6 +835,8 @@ impl RankSpec {
         match self {
             Self::StandardPagerank => "standard_pagerank",
             Self::PersonalizedPagerank { .. } => "personalized_pagerank",
+            Self::Hits { .. } => "hits",
+            Self::Degree => "degree",
         }
     }
 
@@ -636,11 +859,22 @@ impl RankSpec {
                 max_iterations: max_iterations.or(*fb_mi),
                 convergence_threshold: convergence_threshold.or(*fb_ct),
             },
+            (Self::Hits { score }, Self::Hits { score: fb_score }) => Self::Hits {
+                score: score.or(*fb_score),
+            },
             _ => self.clone(),
         }
     }
 }
 
+/// Which HITS score feeds phrase scoring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HitsScoreSpec {
+    Authority,
+    Hub,
+}
+
 /// Phrase assembly strategy.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -656,6 +890,8 @@ pub enum PhraseSpec {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         phrase_grouping: Option<PhraseGroupingSpec>,
     },
+    /// Top-ranked single words, without phrase assembly.
+    Keywords,
     /// Sentence-level phrase assembly (SentenceRank / extractive summarization).
     #[cfg(feature = "sentence-rank")]
     SentencePhrases,
@@ -665,6 +901,7 @@ impl PhraseSpec {
     pub fn type_name(&self) -> &'static str {
         match self {
             Self::ChunkPhrases { .. } => "chunk_phrases",
+            Self::Keywords => "keywords",
             #[cfg(feature = "sentence-rank")]
             Self::SentencePhrases => "sentence_phrases",
         }
@@ -692,8 +929,8 @@ impl PhraseSpec {
                 score_aggregation: score_aggregation.or(*fb_sa),
                 phrase_grouping: phrase_grouping.or(*fb_pg),
             },
-            // Different variants or SentencePhrases — no deep merge needed.
-            #[cfg(feature = "sentence-rank")]
+            // Different variants, Keywords or SentencePhrases — no deep
+            // merge needed.
             _ => self.clone(),
         }
     }
@@ -727,6 +964,19 @@ pub enum FormatSpec {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         debug_key: Option<String>,
     },
+    /// Versioned JSON output ([`JsonOutput`](super::json_output::JsonOutput)).
+    VersionedJson,
+    /// Comma-separated rows, one per phrase
+    /// ([`DelimitedWriter`](super::delimited_output::DelimitedWriter)).
+    Csv {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        header: Option<bool>,
+    },
+    /// Tab-separated rows, one per phrase.
+    Tsv {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        header: Option<bool>,
+    },
     /// Sentence-level JSON output with optional position-based sorting.
     #[cfg(feature = "sentence-rank")]
     SentenceJson {
@@ -736,10 +986,27 @@ pub enum FormatSpec {
 }
 
 impl FormatSpec {
+    /// The CSV/TSV writer for delimited formats, `None` for JSON formats.
+    pub fn delimited_writer(&self) -> Option<super::delimited_output::DelimitedWriter> {
+        use super::delimited_output::DelimitedWriter;
+        match *self {
+            Self::Csv { header } => {
+                Some(DelimitedWriter::csv().with_header(header.unwrap_or(true)))
+            }
+            Self::Tsv { header } => {
+                Some(DelimitedWriter::tsv().with_header(header.unwrap_or(true)))
+            }
+            _ => None,
+        }
+    }
+
     pub fn type_name(&self) -> &'static str {
         match self {
             Self::StandardJson => "standard_json",
             Self::StandardJsonWithDebug { .. } => "standard_json_with_debug",
+            Self::VersionedJson => "versioned_json",
+            Self::Csv { .. } => "csv",
+            Self::Tsv { .. } => "tsv",
             #[cfg(feature = "sentence-rank")]
             Self::SentenceJson { .. } => "sentence_json",
         }
@@ -894,6 +1161,14 @@ pub struct RuntimeSpec {
     #[serde(default)]
     pub max_edges: Option<usize>,
 
+    /// Wall-clock budget for the whole pipeline run, in milliseconds.
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+
+    /// Wall-clock budget for any single stage, in milliseconds.
+    #[serde(default)]
+    pub stage_timeout_ms: Option<u64>,
+
     /// Maximum number of Rayon threads for parallel work.
     /// `None` uses Rayon's default (all logical cores).
     #[serde(default)]
@@ -934,8 +1209,13 @@ impl RuntimeSpec {
 
     /// Build a scoped Rayon thread pool matching this config.
     ///
-    /// Returns `None` when no thread limit is set (use global pool).
+    /// Returns `None` when no thread limit is set (use global pool), and
+    /// always on WebAssembly without the `parallel` feature, where threads
+    /// cannot be spawned.
     pub fn build_thread_pool(&self) -> Option<rayon::ThreadPool> {
+        if cfg!(all(target_family = "wasm", not(feature = "parallel"))) {
+            return None;
+        }
         self.effective_threads().map(|n| {
             rayon::ThreadPoolBuilder::new()
                 .num_threads(n)
@@ -1047,6 +1327,53 @@ mod tests {
         assert!(spec.strict);
     }
 
+    #[cfg(feature = "sentence-rank")]
+    #[test]
+    fn test_deserialize_sentence_graph_lexrank() {
+        let json = r#"{
+            "v": 1,
+            "preset": "sentence_rank",
+            "modules": {
+                "graph": {
+                    "type": "sentence_graph",
+                    "similarity": "tf_idf_cosine",
+                    "min_similarity": 0.1,
+                    "binary_edges": true
+                }
+            }
+        }"#;
+        let spec: PipelineSpecV1 = serde_json::from_str(json).unwrap();
+
+        match &spec.modules.graph {
+            Some(GraphSpec::SentenceGraph {
+                min_similarity,
+                similarity,
+                binary_edges,
+            }) => {
+                assert_eq!(*min_similarity, Some(0.1));
+                assert_eq!(*similarity, Some(SentenceSimilaritySpec::TfIdfCosine));
+                assert_eq!(*binary_edges, Some(true));
+            }
+            other => panic!("expected SentenceGraph, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_window_scope() {
+        let json = r#"{
+            "v": 1,
+            "modules": { "graph": { "type": "cooccurrence_window", "scope": "paragraph" } }
+        }"#;
+        let spec: PipelineSpecV1 = serde_json::from_str(json).unwrap();
+
+        match &spec.modules.graph {
+            Some(GraphSpec::CooccurrenceWindow { scope, .. }) => {
+                assert_eq!(*scope, Some(WindowScopeSpec::Paragraph));
+            }
+            other => panic!("expected CooccurrenceWindow, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_deserialize_parameterized_modules() {
         let json = r#"{
@@ -1093,7 +1420,7 @@ mod tests {
         }
 
         match &spec.modules.teleport {
-            Some(TeleportSpec::Position { shape }) => {
+            Some(TeleportSpec::Position { shape, .. }) => {
                 assert_eq!(shape.as_deref(), Some("exponential"));
             }
             other => panic!("expected Position, got {:?}", other),
@@ -1127,6 +1454,49 @@ mod tests {
         assert!(spec.modules.unknown_fields.contains_key("bogus_module"));
     }
 
+    #[test]
+    fn test_from_json_and_apply_config() {
+        let spec = PipelineSpec::from_json(r#""textrank""#).unwrap();
+        assert!(spec.is_preset());
+
+        let spec = PipelineSpec::from_json(
+            r#"{ "v": 1, "preset": "textrank",
+                 "config": { "damping": 0.7, "window_size": 3, "bogus": 1 } }"#,
+        )
+        .unwrap();
+        let v1 = resolve_spec(&spec).unwrap();
+        assert_eq!(v1.config.len(), 3);
+        let cfg = v1
+            .apply_config(&TextRankConfig::default().with_top_n(7))
+            .unwrap();
+        assert_eq!(cfg.damping, 0.7);
+        assert_eq!(cfg.window_size, 3);
+        // Fields not overridden keep the base value.
+        assert_eq!(cfg.top_n, 7);
+
+        let bad_type =
+            PipelineSpec::from_json(r#"{ "v": 1, "config": { "damping": "high" } }"#).unwrap();
+        let err = bad_type
+            .as_v1()
+            .unwrap()
+            .apply_config(&TextRankConfig::default())
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidValue);
+
+        let out_of_range =
+            PipelineSpec::from_json(r#"{ "v": 1, "config": { "damping": 1.5 } }"#).unwrap();
+        let err = out_of_range
+            .as_v1()
+            .unwrap()
+            .apply_config(&TextRankConfig::default())
+            .unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidValue);
+        assert_eq!(err.path, "/config/damping");
+
+        let err = PipelineSpec::from_json(r#"{ "v": 1, "#).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidValue);
+    }
+
     #[test]
     fn test_serde_roundtrip() {
         let json = r#"{
@@ -1517,7 +1887,9 @@ mod tests {
         #[cfg(feature = "sentence-rank")]
         assert_eq!(
             GraphSpec::SentenceGraph {
-                min_similarity: None
+                min_similarity: None,
+                similarity: None,
+                binary_edges: None,
             }
             .type_name(),
             "sentence_graph"
@@ -1527,6 +1899,7 @@ mod tests {
                 window_size: None,
                 cross_sentence: None,
                 edge_weighting: None,
+                scope: None,
             }
             .type_name(),
             "cooccurrence_window"
@@ -1536,9 +1909,26 @@ mod tests {
             "remove_intra_cluster_edges"
         );
         assert_eq!(GraphTransformSpec::AlphaBoost.type_name(), "alpha_boost");
+        assert_eq!(
+            GraphTransformSpec::KCore { k: Some(2) }.type_name(),
+            "k_core"
+        );
+        let t: GraphTransformSpec =
+            serde_json::from_str(r#"{"type": "degree_threshold", "min_weight": 1.5}"#).unwrap();
+        assert_eq!(
+            t,
+            GraphTransformSpec::DegreeThreshold {
+                min_degree: None,
+                min_weight: Some(1.5),
+            }
+        );
         assert_eq!(TeleportSpec::Uniform.type_name(), "uniform");
         assert_eq!(
-            TeleportSpec::Position { shape: None }.type_name(),
+            TeleportSpec::Position {
+                shape: None,
+                half_life: None,
+            }
+            .type_name(),
             "position"
         );
         assert_eq!(TeleportSpec::FocusTerms.type_name(), "focus_terms");
@@ -1571,6 +1961,9 @@ mod tests {
             FormatSpec::StandardJsonWithDebug { debug_key: None }.type_name(),
             "standard_json_with_debug"
         );
+        assert_eq!(FormatSpec::VersionedJson.type_name(), "versioned_json");
+        assert_eq!(FormatSpec::Csv { header: None }.type_name(), "csv");
+        assert_eq!(FormatSpec::Tsv { header: None }.type_name(), "tsv");
         #[cfg(feature = "sentence-rank")]
         assert_eq!(
             FormatSpec::SentenceJson {
@@ -1819,7 +2212,10 @@ mod tests {
     fn test_merge_user_overrides_preset() {
         // User sets teleport; preset has graph. Both should appear in result.
         let user = ModuleSet {
-            teleport: Some(TeleportSpec::Position { shape: None }),
+            teleport: Some(TeleportSpec::Position {
+                shape: None,
+                half_life: None,
+            }),
             ..Default::default()
         };
         let preset = ModuleSet {
@@ -1827,6 +2223,7 @@ mod tests {
                 window_size: None,
                 cross_sentence: Some(true),
                 edge_weighting: None,
+                scope: None,
             }),
             ..Default::default()
         };
@@ -1849,7 +2246,10 @@ mod tests {
             ..Default::default()
         };
         let preset = ModuleSet {
-            teleport: Some(TeleportSpec::Position { shape: None }),
+            teleport: Some(TeleportSpec::Position {
+                shape: None,
+                half_life: None,
+            }),
             ..Default::default()
         };
         let merged = merge_modules(&user, &preset);
@@ -1874,6 +2274,7 @@ mod tests {
                 window_size: Some(5),
                 cross_sentence: None,
                 edge_weighting: None,
+                scope: None,
             }),
             ..Default::default()
         };
@@ -1882,6 +2283,7 @@ mod tests {
                 window_size: None,
                 cross_sentence: Some(true),
                 edge_weighting: None,
+                scope: None,
             }),
             ..Default::default()
         };
@@ -1907,6 +2309,7 @@ mod tests {
                 window_size: None,
                 cross_sentence: Some(false),
                 edge_weighting: None,
+                scope: None,
             }),
             ..Default::default()
         };
@@ -1915,6 +2318,7 @@ mod tests {
                 window_size: Some(3),
                 cross_sentence: Some(true),
                 edge_weighting: None,
+                scope: None,
             }),
             ..Default::default()
         };
@@ -1945,6 +2349,7 @@ mod tests {
                 window_size: Some(3),
                 cross_sentence: Some(true),
                 edge_weighting: None,
+                scope: None,
             }),
             ..Default::default()
         };
@@ -2104,24 +2509,56 @@ mod tests {
     #[test]
     fn test_merge_teleport_position_deep_params() {
         let user = ModuleSet {
-            teleport: Some(TeleportSpec::Position { shape: None }),
+            teleport: Some(TeleportSpec::Position {
+                shape: None,
+                half_life: None,
+            }),
             ..Default::default()
         };
         let preset = ModuleSet {
             teleport: Some(TeleportSpec::Position {
                 shape: Some("exponential".to_string()),
+                half_life: Some(20.0),
             }),
             ..Default::default()
         };
         let merged = merge_modules(&user, &preset);
         match &merged.teleport {
-            Some(TeleportSpec::Position { shape }) => {
+            Some(TeleportSpec::Position { shape, half_life }) => {
                 assert_eq!(shape.as_deref(), Some("exponential"));
+                assert_eq!(*half_life, Some(20.0));
             }
             other => panic!("expected Position, got {:?}", other),
         }
     }
 
+    #[test]
+    fn test_merge_teleport_section_boosts() {
+        let user = ModuleSet {
+            teleport: Some(TeleportSpec::Section {
+                boosts: Some(BTreeMap::from([(Section::Title, 5.0)])),
+            }),
+            ..Default::default()
+        };
+        let preset = ModuleSet {
+            teleport: Some(TeleportSpec::Section {
+                boosts: Some(BTreeMap::from([
+                    (Section::Title, 3.0),
+                    (Section::Abstract, 2.0),
+                ])),
+            }),
+            ..Default::default()
+        };
+        let merged = merge_modules(&user, &preset);
+        let expected = BTreeMap::from([(Section::Title, 5.0), (Section::Abstract, 2.0)]);
+        assert_eq!(
+            merged.teleport,
+            Some(TeleportSpec::Section {
+                boosts: Some(expected)
+            })
+        );
+    }
+
     #[test]
     fn test_merge_realistic_single_rank_with_overrides() {
         // Start from single_rank preset, override window_size.
@@ -2131,6 +2568,7 @@ mod tests {
                 window_size: Some(5),
                 cross_sentence: None,
                 edge_weighting: Some(EdgeWeightingSpec::Binary),
+                scope: None,
             }),
             ..Default::default()
         };
@@ -2140,6 +2578,7 @@ mod tests {
                 window_size,
                 cross_sentence,
                 edge_weighting,
+                ..
             }) => {
                 assert_eq!(*window_size, Some(5)); // user
                 assert_eq!(*cross_sentence, Some(true)); // preset
@@ -2186,11 +2625,15 @@ mod tests {
             v: 1,
             preset: Some("single_rank".into()),
             modules: ModuleSet {
-                teleport: Some(TeleportSpec::Position { shape: None }),
+                teleport: Some(TeleportSpec::Position {
+                    shape: None,
+                    half_life: None,
+                }),
                 ..Default::default()
             },
             runtime: Default::default(),
             expose: None,
+            config: HashMap::new(),
             strict: false,
             unknown_fields: HashMap::new(),
         }));
@@ -2220,6 +2663,7 @@ mod tests {
             },
             runtime: Default::default(),
             expose: None,
+            config: HashMap::new(),
             strict: true,
             unknown_fields: HashMap::new(),
         };
@@ -2249,6 +2693,7 @@ mod tests {
             modules: Default::default(),
             runtime: Default::default(),
             expose: None,
+            config: HashMap::new(),
             strict: false,
             unknown_fields: HashMap::new(),
         }));
@@ -2271,6 +2716,7 @@ mod tests {
                 graph_stats: true,
                 ..Default::default()
             }),
+            config: HashMap::new(),
             strict: true,
             unknown_fields: HashMap::new(),
         }));
@@ -2294,11 +2740,13 @@ mod tests {
                     window_size: Some(5),
                     cross_sentence: None,
                     edge_weighting: None,
+                    scope: None,
                 }),
                 ..Default::default()
             },
             runtime: Default::default(),
             expose: None,
+            config: HashMap::new(),
             strict: false,
             unknown_fields: HashMap::new(),
         }));
@@ -2329,7 +2777,9 @@ mod tests {
         assert!(matches!(
             ms.graph,
             Some(GraphSpec::SentenceGraph {
-                min_similarity: None
+                min_similarity: None,
+                similarity: None,
+                binary_edges: None,
             })
         ));
         assert!(matches!(ms.phrases, Some(PhraseSpec::SentencePhrases)));
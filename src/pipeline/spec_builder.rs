@@ -19,26 +19,36 @@
 
 use std::collections::HashMap;
 
+use crate::pagerank::hits::HitsScore;
+use crate::phrase::grammar::PosGrammar;
 use crate::pipeline::artifacts::{CandidateSetRef, Graph, TokenStreamRef};
 use crate::pipeline::error_code::ErrorCode;
 use crate::pipeline::errors::PipelineSpecError;
+use crate::pipeline::plan::PipelinePlan;
 use crate::pipeline::runner::Pipeline;
+#[cfg(feature = "sentence-rank")]
+use crate::pipeline::spec::SentenceSimilaritySpec;
 use crate::pipeline::spec::{
     resolve_spec, CandidatesSpec, ClusteringSpec, EdgeWeightingSpec, GraphSpec, GraphTransformSpec,
-    PipelineSpec, PipelineSpecV1, TeleportSpec,
+    HitsScoreSpec, PipelineSpec, PipelineSpecV1, RankSpec, TeleportSpec, WindowScopeSpec,
 };
+use crate::pipeline::teleport::PositionDecay;
 use crate::pipeline::traits::{
-    CandidateGraphBuilder, CandidateSelector, ChunkPhraseBuilder, Clusterer, EdgeWeightPolicy,
-    FocusTermsTeleportBuilder, GraphBuilder, GraphTransform, JaccardHacClusterer,
-    MultipartitePhraseBuilder, MultipartiteTransform, NoopGraphTransform, NoopPreprocessor,
-    PageRankRanker, PhraseBuilder, PhraseCandidateSelector, PositionTeleportBuilder, Preprocessor,
-    Ranker, ResultFormatter, StandardResultFormatter, TeleportBuilder, TopicGraphBuilder,
-    TopicRepresentativeBuilder, TopicWeightsTeleportBuilder, UniformTeleportBuilder,
-    WindowGraphBuilder, WindowStrategy, WordNodeSelector,
+    CandidateGraphBuilder, CandidateSelector, ChunkPhraseBuilder, Clusterer, DegreeRanker,
+    DegreeThresholdTransform, EdgeThreshold, EdgeWeightPolicy, FocusTermsTeleportBuilder,
+    GrammarCandidateSelector, GraphBuilder, GraphTransform, HitsRanker, JaccardHacClusterer,
+    KCoreTransform, KeywordPhraseBuilder, LemmaPreprocessor, MetaVertexTransform,
+    MultipartitePhraseBuilder, MultipartiteTransform, NoopGraphTransform, NounChunkSelector,
+    PageRankRanker, PhraseBuilder, PhraseCandidateSelector, PositionDecayTeleportBuilder,
+    PositionTeleportBuilder, Preprocessor, Ranker, ResultFormatter, SectionTeleportBuilder,
+    StandardResultFormatter, TeleportBuilder, TopicGraphBuilder, TopicRepresentativeBuilder,
+    TopicWeightsTeleportBuilder, UniformTeleportBuilder, WindowGraphBuilder, WindowStrategy,
+    WordNodeSelector,
 };
 #[cfg(feature = "sentence-rank")]
 use crate::pipeline::traits::{
     SentenceCandidateSelector, SentenceFormatter, SentenceGraphBuilder, SentencePhraseBuilder,
+    SentenceSimilarity,
 };
 use crate::pipeline::validation::ValidationEngine;
 use crate::types::{ChunkSpan, TextRankConfig};
@@ -84,6 +94,11 @@ impl GraphTransform for ChainedGraphTransform {
             t.transform(graph, tokens, candidates, cfg);
         }
     }
+
+    fn describe(&self) -> String {
+        let inner: Vec<String> = self.transforms.iter().map(|t| t.describe()).collect();
+        format!("[{}]", inner.join(", "))
+    }
 }
 
 // ─── SpecPipelineBuilder ───────────────────────────────────────────────────
@@ -152,7 +167,7 @@ impl SpecPipelineBuilder {
         let modules = &spec.modules;
 
         // ── Preprocessor ──────────────────────────────────────────────
-        let preprocessor: Box<dyn Preprocessor + Send + Sync> = Box::new(NoopPreprocessor);
+        let preprocessor: Box<dyn Preprocessor + Send + Sync> = Box::new(LemmaPreprocessor);
 
         // ── Candidates ────────────────────────────────────────────────
         let selector: Box<dyn CandidateSelector + Send + Sync> = match &modules.candidates {
@@ -168,6 +183,20 @@ impl SpecPipelineBuilder {
                 }
                 Box::new(PhraseCandidateSelector::new(self.chunks.clone()))
             }
+            Some(CandidatesSpec::NounChunks) => Box::new(NounChunkSelector),
+            Some(CandidatesSpec::Grammar { pattern }) => {
+                let grammar = match pattern {
+                    Some(pattern) => PosGrammar::parse(pattern).map_err(|e| {
+                        PipelineSpecError::new(
+                            ErrorCode::InvalidValue,
+                            "/modules/candidates/pattern",
+                            e.to_string(),
+                        )
+                    })?,
+                    None => PosGrammar::default(),
+                };
+                Box::new(GrammarCandidateSelector::new(grammar))
+            }
             #[cfg(feature = "sentence-rank")]
             Some(CandidatesSpec::SentenceCandidates) => Box::new(SentenceCandidateSelector),
         };
@@ -201,18 +230,30 @@ impl SpecPipelineBuilder {
                 window_size,
                 cross_sentence,
                 edge_weighting,
+                scope,
             }) => {
                 let ws = window_size.unwrap_or(cfg.window_size);
-                let strategy = if cross_sentence.unwrap_or(false) {
-                    WindowStrategy::CrossSentence { window_size: ws }
-                } else {
-                    WindowStrategy::SentenceBounded { window_size: ws }
+                let strategy = match scope {
+                    Some(WindowScopeSpec::Sentence) => WindowStrategy::Sentence,
+                    Some(WindowScopeSpec::Paragraph) => WindowStrategy::Paragraph,
+                    Some(WindowScopeSpec::Window) | None if cross_sentence.unwrap_or(false) => {
+                        WindowStrategy::CrossSentence { window_size: ws }
+                    }
+                    Some(WindowScopeSpec::Window) | None => {
+                        WindowStrategy::SentenceBounded { window_size: ws }
+                    }
                 };
                 // Default to CountAccumulating (not Binary) to match
                 // base_textrank() and the library's practical default.
                 let policy = match edge_weighting {
                     Some(EdgeWeightingSpec::Binary) => EdgeWeightPolicy::Binary,
                     Some(EdgeWeightingSpec::Count) | None => EdgeWeightPolicy::CountAccumulating,
+                    Some(EdgeWeightingSpec::DistanceDecay) => EdgeWeightPolicy::DistanceDecay,
+                    Some(EdgeWeightingSpec::ExponentialDecay { tau }) => {
+                        EdgeWeightPolicy::ExponentialDecay { tau: *tau }
+                    }
+                    Some(EdgeWeightingSpec::Pmi) => EdgeWeightPolicy::Pmi,
+                    Some(EdgeWeightingSpec::Dice) => EdgeWeightPolicy::Dice,
                 };
                 Box::new(WindowGraphBuilder {
                     window_strategy: strategy,
@@ -228,11 +269,26 @@ impl SpecPipelineBuilder {
                 Box::new(CandidateGraphBuilder::new(make_clusterer(clust_spec)))
             }
             #[cfg(feature = "sentence-rank")]
-            Some(GraphSpec::SentenceGraph { min_similarity }) => {
+            Some(GraphSpec::SentenceGraph {
+                min_similarity,
+                similarity,
+                binary_edges,
+            }) => {
                 let mut b = SentenceGraphBuilder::default();
                 if let Some(ms) = min_similarity {
                     b = b.with_min_similarity(*ms);
                 }
+                if let Some(sim) = similarity {
+                    b = b.with_similarity(match sim {
+                        SentenceSimilaritySpec::Jaccard => SentenceSimilarity::Jaccard,
+                        SentenceSimilaritySpec::Overlap => SentenceSimilarity::Overlap,
+                        SentenceSimilaritySpec::TfIdfCosine => SentenceSimilarity::TfIdfCosine,
+                        SentenceSimilaritySpec::Bm25 => SentenceSimilarity::Bm25,
+                    });
+                }
+                if let Some(binary) = binary_edges {
+                    b = b.with_binary_edges(*binary);
+                }
                 Box::new(b)
             }
         };
@@ -255,7 +311,21 @@ impl SpecPipelineBuilder {
         // ── Teleport ──────────────────────────────────────────────────
         let teleport_builder: Box<dyn TeleportBuilder + Send + Sync> = match &modules.teleport {
             None | Some(TeleportSpec::Uniform) => Box::new(UniformTeleportBuilder),
-            Some(TeleportSpec::Position { .. }) => Box::new(PositionTeleportBuilder),
+            Some(TeleportSpec::Position { shape, half_life }) => {
+                let shape = shape.as_deref().unwrap_or("first_occurrence");
+                let Some(decay) = PositionDecay::from_shape(shape, *half_life) else {
+                    return Err(PipelineSpecError::new(
+                        ErrorCode::InvalidValue,
+                        "/modules/teleport/shape",
+                        format!("unknown position teleport shape '{shape}'"),
+                    )
+                    .with_hint(format!("Use one of: {}", PositionDecay::SHAPES.join(", "))));
+                };
+                match decay {
+                    PositionDecay::FirstOccurrence => Box::new(PositionTeleportBuilder),
+                    _ => Box::new(PositionDecayTeleportBuilder::new(decay)),
+                }
+            }
             Some(TeleportSpec::FocusTerms) => {
                 if self.focus_terms.is_empty() {
                     return Err(PipelineSpecError::new(
@@ -284,16 +354,29 @@ impl SpecPipelineBuilder {
                     self.topic_min_weight,
                 ))
             }
+            Some(TeleportSpec::Section { boosts }) => {
+                let mut all = SectionTeleportBuilder::default_boosts();
+                all.extend(boosts.iter().flatten().map(|(s, w)| (*s, *w)));
+                Box::new(SectionTeleportBuilder::new(all))
+            }
         };
 
         // ── Ranker ────────────────────────────────────────────────────
-        let ranker: Box<dyn Ranker + Send + Sync> = Box::new(PageRankRanker);
+        let ranker: Box<dyn Ranker + Send + Sync> = match &modules.rank {
+            Some(RankSpec::Hits { score }) => Box::new(HitsRanker::new(match score {
+                Some(HitsScoreSpec::Hub) => HitsScore::Hub,
+                Some(HitsScoreSpec::Authority) | None => HitsScore::Authority,
+            })),
+            Some(RankSpec::Degree) => Box::new(DegreeRanker),
+            _ => Box::new(PageRankRanker),
+        };
 
         // ── Phrases ───────────────────────────────────────────────────
         let phrase_builder: Box<dyn PhraseBuilder + Send + Sync> = match &modules.phrases {
             Some(crate::pipeline::spec::PhraseSpec::ChunkPhrases { .. }) => {
                 Box::new(ChunkPhraseBuilder)
             }
+            Some(crate::pipeline::spec::PhraseSpec::Keywords) => Box::new(KeywordPhraseBuilder),
             #[cfg(feature = "sentence-rank")]
             Some(crate::pipeline::spec::PhraseSpec::SentencePhrases) => {
                 Box::new(SentencePhraseBuilder)
@@ -318,8 +401,9 @@ impl SpecPipelineBuilder {
                     sort_by_position: sort_by_position.unwrap_or(false),
                 })
             }
-            // StandardJsonWithDebug uses the same formatter; the debug_key
-            // renaming is handled at serialization time in json.rs.
+            // StandardJsonWithDebug, VersionedJson, Csv and Tsv use the same
+            // formatter; the debug_key renaming, versioned schema and rows are
+            // produced at serialization time in json.rs.
             Some(crate::pipeline::spec::FormatSpec::StandardJsonWithDebug { .. }) => {
                 Box::new(StandardResultFormatter)
             }
@@ -335,6 +419,7 @@ impl SpecPipelineBuilder {
             ranker,
             phrase_builder,
             formatter,
+            custom_stages: Default::default(),
         })
     }
 
@@ -377,6 +462,60 @@ impl SpecPipelineBuilder {
         self.build(&effective, cfg)
     }
 
+    /// Like [`build_from_spec`](Self::build_from_spec), but also applies the
+    /// spec's `config` overrides on top of `base`.
+    ///
+    /// Returns the pipeline together with the effective config to run it
+    /// with. This is the entry point for fully file-driven pipelines:
+    ///
+    /// ```
+    /// # use rapid_textrank::pipeline::spec::PipelineSpec;
+    /// # use rapid_textrank::pipeline::spec_builder::SpecPipelineBuilder;
+    /// # use rapid_textrank::types::TextRankConfig;
+    /// let spec = PipelineSpec::from_json(
+    ///     r#"{ "v": 1, "preset": "position_rank", "config": { "damping": 0.9, "top_n": 5 } }"#,
+    /// )
+    /// .unwrap();
+    /// let (_pipeline, cfg) = SpecPipelineBuilder::new()
+    ///     .build_with_config(&spec, &TextRankConfig::default())
+    ///     .unwrap();
+    /// assert_eq!(cfg.top_n, 5);
+    /// ```
+    pub fn build_with_config(
+        &self,
+        spec: &PipelineSpec,
+        base: &TextRankConfig,
+    ) -> Result<(DynPipeline, TextRankConfig), PipelineSpecError> {
+        let effective = resolve_spec(spec)?;
+
+        let report = ValidationEngine::with_defaults().validate(&effective);
+        if let Some(err) = report.errors().next() {
+            return Err(err.clone());
+        }
+
+        let cfg = effective.apply_config(base)?;
+        let pipeline = self.build(&effective, &cfg)?;
+        Ok((pipeline, cfg))
+    }
+
+    /// Resolve `spec` against `base` exactly as
+    /// [`build_with_config`](Self::build_with_config) does, and return the
+    /// resulting [`PipelinePlan`] instead of the pipeline.
+    ///
+    /// Nothing runs. The plan's `spec` is the resolved spec (preset expanded,
+    /// modules merged) and its `config` the effective config the pipeline
+    /// would run with.
+    pub fn explain(
+        &self,
+        spec: &PipelineSpec,
+        base: &TextRankConfig,
+    ) -> Result<PipelinePlan, PipelineSpecError> {
+        let (pipeline, cfg) = self.build_with_config(spec, base)?;
+        let mut plan = pipeline.explain(&cfg);
+        plan.spec = Some(resolve_spec(spec)?);
+        Ok(plan)
+    }
+
     /// Map a single `GraphTransformSpec` to a boxed impl.
     fn make_graph_transform(
         &self,
@@ -395,6 +534,24 @@ impl SpecPipelineBuilder {
                 // so we use it standalone only when paired with removal in a chain.
                 Box::new(MultipartiteTransform::new())
             }
+            GraphTransformSpec::KCore { k } => Box::new(KCoreTransform::new(k.unwrap_or(2))),
+            GraphTransformSpec::EdgeThreshold {
+                min_weight,
+                quantile,
+            } => Box::new(match quantile {
+                Some(q) => EdgeThreshold::quantile(*q),
+                None => EdgeThreshold::absolute(min_weight.unwrap_or(0.0)),
+            }),
+            GraphTransformSpec::DegreeThreshold {
+                min_degree,
+                min_weight,
+            } => Box::new(
+                DegreeThresholdTransform::new(min_degree.unwrap_or(0))
+                    .with_min_weight(min_weight.unwrap_or(0.0)),
+            ),
+            GraphTransformSpec::MetaVertex { min_count } => {
+                Box::new(MetaVertexTransform::new(min_count.unwrap_or(2)))
+            }
         }
     }
 }
@@ -410,10 +567,10 @@ mod tests {
     use super::*;
     use crate::pipeline::artifacts::TokenStream;
     use crate::pipeline::error_code::ErrorCode;
-    use crate::pipeline::observer::NoopObserver;
+    use crate::pipeline::observer::{NoopObserver, STAGE_GRAPH, STAGE_GRAPH_TRANSFORM, STAGE_RANK};
     use crate::pipeline::runner::{BaseTextRankPipeline, SingleRankPipeline, TopicRankPipeline};
     use crate::pipeline::spec::{ModuleSet, PipelineSpec, PipelineSpecV1};
-    use crate::types::{DeterminismMode, PosTag, Token};
+    use crate::types::{DeterminismMode, PosTag, Section, Token};
 
     // ── Helpers ──────────────────────────────────────────────────────
 
@@ -424,6 +581,7 @@ mod tests {
             modules: ModuleSet::default(),
             runtime: Default::default(),
             expose: None,
+            config: HashMap::new(),
             strict: false,
             unknown_fields: HashMap::new(),
         }
@@ -524,7 +682,10 @@ mod tests {
     #[test]
     fn test_build_position_rank() {
         let mut spec = minimal_spec();
-        spec.modules.teleport = Some(TeleportSpec::Position { shape: None });
+        spec.modules.teleport = Some(TeleportSpec::Position {
+            shape: None,
+            half_life: None,
+        });
         let cfg = deterministic_config();
         let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
         let tokens = golden_tokens();
@@ -533,6 +694,78 @@ mod tests {
         assert!(!result.phrases.is_empty());
     }
 
+    #[test]
+    fn test_build_position_rank_shapes() {
+        let cfg = deterministic_config();
+        let mut spec = minimal_spec();
+        spec.modules.teleport = Some(TeleportSpec::Position {
+            shape: Some("inverse".to_string()),
+            half_life: None,
+        });
+        let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
+        let result = pipeline.run(
+            TokenStream::from_tokens(&golden_tokens()),
+            &cfg,
+            &mut NoopObserver,
+        );
+        assert!(!result.phrases.is_empty());
+
+        spec.modules.teleport = Some(TeleportSpec::Position {
+            shape: Some("linear".to_string()),
+            half_life: None,
+        });
+        let err = match SpecPipelineBuilder::new().build(&spec, &cfg) {
+            Err(e) => e,
+            Ok(_) => panic!("expected error for an unknown shape"),
+        };
+        assert_eq!(err.code, ErrorCode::InvalidValue);
+        assert_eq!(err.path, "/modules/teleport/shape");
+    }
+
+    #[test]
+    fn test_build_meta_vertex_transform() {
+        let cfg = deterministic_config();
+        let mut spec = minimal_spec();
+        spec.modules.graph_transforms = vec![GraphTransformSpec::MetaVertex { min_count: None }];
+        let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
+        let result = pipeline.run(
+            TokenStream::from_tokens(&golden_tokens()),
+            &cfg,
+            &mut NoopObserver,
+        );
+
+        // "machine learning" occurs twice, so both words share one score.
+        let phrase = result
+            .phrases
+            .iter()
+            .find(|p| p.lemma == "machine learning")
+            .unwrap();
+        assert!(phrase.score > 0.0);
+    }
+
+    #[test]
+    fn test_build_section_teleport() {
+        let cfg = deterministic_config();
+        let mut tokens = golden_tokens();
+        tokens[14].section = Some(Section::Title); // "data"
+        let data_score = |spec: &PipelineSpecV1| {
+            let pipeline = SpecPipelineBuilder::new().build(spec, &cfg).unwrap();
+            let stream = TokenStream::from_tokens(&tokens);
+            let result = pipeline.run(stream, &cfg, &mut NoopObserver);
+            result
+                .phrases
+                .iter()
+                .find(|p| p.lemma == "data")
+                .map(|p| p.score)
+                .unwrap()
+        };
+
+        let mut spec = minimal_spec();
+        let uniform = data_score(&spec);
+        spec.modules.teleport = Some(TeleportSpec::Section { boosts: None });
+        assert!(data_score(&spec) > uniform);
+    }
+
     #[test]
     fn test_build_single_rank() {
         let mut spec = minimal_spec();
@@ -540,6 +773,7 @@ mod tests {
             window_size: None,
             cross_sentence: Some(true),
             edge_weighting: Some(EdgeWeightingSpec::Count),
+            scope: None,
         });
         let cfg = deterministic_config();
         let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
@@ -571,6 +805,7 @@ mod tests {
             window_size: None,
             cross_sentence: Some(true),
             edge_weighting: Some(EdgeWeightingSpec::Count),
+            scope: None,
         });
         spec.modules.teleport = Some(TeleportSpec::TopicWeights);
         let cfg = deterministic_config();
@@ -606,6 +841,20 @@ mod tests {
         assert!(!result.phrases.is_empty());
     }
 
+    #[test]
+    fn test_build_topic_rank_from_noun_chunks() {
+        let mut spec = minimal_spec();
+        spec.modules.candidates = Some(CandidatesSpec::NounChunks);
+        spec.modules.graph = Some(GraphSpec::TopicGraph);
+        let cfg = deterministic_config();
+        // No with_chunks(): the selector chunks the tokens itself.
+        let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
+        let tokens = golden_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let result = pipeline.run(stream, &cfg, &mut NoopObserver);
+        assert!(!result.phrases.is_empty());
+    }
+
     #[test]
     fn test_build_multipartite_rank() {
         let mut spec = minimal_spec();
@@ -633,6 +882,7 @@ mod tests {
             window_size: Some(5),
             cross_sentence: Some(false),
             edge_weighting: Some(EdgeWeightingSpec::Binary),
+            scope: None,
         });
         let cfg = deterministic_config();
         let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
@@ -642,6 +892,111 @@ mod tests {
         assert!(!result.phrases.is_empty());
     }
 
+    #[test]
+    fn test_build_with_keywords_phrases() {
+        let spec: PipelineSpecV1 = serde_json::from_value(serde_json::json!({
+            "v": 1,
+            "modules": { "phrases": { "type": "keywords" } }
+        }))
+        .unwrap();
+        let cfg = deterministic_config();
+        let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
+        let stream = TokenStream::from_tokens(&golden_tokens());
+        let result = pipeline.run(stream, &cfg, &mut NoopObserver);
+
+        assert!(!result.phrases.is_empty());
+        assert!(result.phrases.iter().all(|p| !p.text.contains(' ')));
+    }
+
+    #[test]
+    fn test_build_with_window_scope() {
+        for scope in [WindowScopeSpec::Sentence, WindowScopeSpec::Paragraph] {
+            let mut spec = minimal_spec();
+            spec.modules.graph = Some(GraphSpec::CooccurrenceWindow {
+                window_size: None,
+                cross_sentence: None,
+                edge_weighting: None,
+                scope: Some(scope),
+            });
+            let cfg = deterministic_config();
+            let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
+            let stream = TokenStream::from_tokens(&golden_tokens());
+            let result = pipeline.run(stream, &cfg, &mut NoopObserver);
+            assert!(!result.phrases.is_empty(), "{:?}", scope);
+        }
+    }
+
+    #[test]
+    fn test_build_with_association_edge_weighting() {
+        for weighting in [
+            EdgeWeightingSpec::DistanceDecay,
+            EdgeWeightingSpec::ExponentialDecay { tau: 2.0 },
+            EdgeWeightingSpec::Pmi,
+            EdgeWeightingSpec::Dice,
+        ] {
+            let mut spec = minimal_spec();
+            spec.modules.graph = Some(GraphSpec::CooccurrenceWindow {
+                window_size: Some(3),
+                cross_sentence: Some(true),
+                edge_weighting: Some(weighting),
+                scope: None,
+            });
+            let cfg = deterministic_config();
+            let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
+            let stream = TokenStream::from_tokens(&golden_tokens());
+            let result = pipeline.run(stream, &cfg, &mut NoopObserver);
+            assert!(!result.phrases.is_empty(), "{:?}", weighting);
+            assert!(result.phrases.iter().all(|p| p.score.is_finite()));
+        }
+    }
+
+    #[test]
+    fn test_build_with_pruning_transforms() {
+        let mut spec = minimal_spec();
+        spec.modules.graph_transforms = vec![
+            GraphTransformSpec::KCore { k: Some(2) },
+            GraphTransformSpec::DegreeThreshold {
+                min_degree: Some(2),
+                min_weight: None,
+            },
+            GraphTransformSpec::EdgeThreshold {
+                min_weight: None,
+                quantile: Some(0.25),
+            },
+        ];
+        let cfg = deterministic_config();
+        let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
+        let stream = TokenStream::from_tokens(&golden_tokens());
+        let result = pipeline.run(stream, &cfg, &mut NoopObserver);
+        assert!(!result.phrases.is_empty());
+    }
+
+    #[test]
+    fn test_build_hits_ranker() {
+        let mut spec = minimal_spec();
+        spec.modules.rank = Some(RankSpec::Hits {
+            score: Some(HitsScoreSpec::Hub),
+        });
+        let cfg = deterministic_config();
+        let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
+        let stream = TokenStream::from_tokens(&golden_tokens());
+        let result = pipeline.run(stream, &cfg, &mut NoopObserver);
+        assert!(result.converged);
+        assert!(!result.phrases.is_empty());
+    }
+
+    #[test]
+    fn test_build_degree_ranker() {
+        let mut spec = minimal_spec();
+        spec.modules.rank = Some(RankSpec::Degree);
+        let cfg = deterministic_config();
+        let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
+        let stream = TokenStream::from_tokens(&golden_tokens());
+        let result = pipeline.run(stream, &cfg, &mut NoopObserver);
+        assert_eq!(result.iterations, 0);
+        assert!(!result.phrases.is_empty());
+    }
+
     #[test]
     fn test_build_chained_transforms() {
         let mut spec = minimal_spec();
@@ -704,6 +1059,35 @@ mod tests {
         assert!(err.message.contains("chunks"));
     }
 
+    #[test]
+    fn test_build_grammar_candidates() {
+        let spec: PipelineSpecV1 = serde_json::from_str(
+            r#"{ "v": 1, "modules": { "candidates": { "type": "grammar", "pattern": "NP: {<ADJ>*<NOUN|PROPN>+}" } } }"#,
+        )
+        .unwrap();
+        let cfg = deterministic_config();
+        let pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
+        let tokens = golden_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let result = pipeline.run(stream, &cfg, &mut NoopObserver);
+        assert!(!result.phrases.is_empty());
+    }
+
+    #[test]
+    fn test_error_grammar_invalid_pattern() {
+        let mut spec = minimal_spec();
+        spec.modules.candidates = Some(CandidatesSpec::Grammar {
+            pattern: Some("(Adj Noun".to_string()),
+        });
+        let cfg = TextRankConfig::default();
+        let err = match SpecPipelineBuilder::new().build(&spec, &cfg) {
+            Err(e) => e,
+            Ok(_) => panic!("expected error for an unbalanced grammar"),
+        };
+        assert_eq!(err.code, ErrorCode::InvalidValue);
+        assert_eq!(err.path, "/modules/candidates/pattern");
+    }
+
     // ── Golden tests (DynPipeline vs static pipeline bit-exact) ─────
 
     #[test]
@@ -752,6 +1136,7 @@ mod tests {
             window_size: None,
             cross_sentence: Some(true),
             edge_weighting: Some(EdgeWeightingSpec::Count),
+            scope: None,
         });
         let dyn_pipeline = SpecPipelineBuilder::new().build(&spec, &cfg).unwrap();
         let stream2 = TokenStream::from_tokens(&tokens);
@@ -829,6 +1214,74 @@ mod tests {
         assert!(!result.phrases.is_empty());
     }
 
+    #[test]
+    fn test_build_with_config_applies_overrides() {
+        let spec = PipelineSpec::from_json(
+            r#"{ "v": 1, "preset": "textrank", "config": { "top_n": 1 } }"#,
+        )
+        .unwrap();
+        let (pipeline, cfg) = SpecPipelineBuilder::new()
+            .build_with_config(&spec, &deterministic_config())
+            .unwrap();
+        assert_eq!(cfg.top_n, 1);
+        assert_eq!(cfg.determinism, deterministic_config().determinism);
+
+        let tokens = golden_tokens();
+        let result = pipeline.run(TokenStream::from_tokens(&tokens), &cfg, &mut NoopObserver);
+        assert_eq!(result.phrases.len(), 1);
+
+        let invalid = PipelineSpec::from_json(r#"{ "v": 1, "config": { "top_n": -1 } }"#).unwrap();
+        let err = SpecPipelineBuilder::new()
+            .build_with_config(&invalid, &deterministic_config())
+            .err()
+            .unwrap();
+        assert_eq!(err.code, ErrorCode::InvalidValue);
+        assert_eq!(err.path, "/config/top_n");
+    }
+
+    #[test]
+    fn test_explain_resolves_spec_without_running() {
+        let spec = PipelineSpec::from_json(
+            r#"{
+                "v": 1,
+                "preset": "textrank",
+                "modules": {
+                    "graph": { "type": "cooccurrence_window", "edge_weighting": "binary" },
+                    "graph_transforms": [{ "type": "k_core", "k": 2 }, { "type": "k_core", "k": 3 }]
+                },
+                "config": { "top_n": 4 }
+            }"#,
+        )
+        .unwrap();
+        let plan = SpecPipelineBuilder::new()
+            .explain(&spec, &deterministic_config())
+            .unwrap();
+
+        assert_eq!(plan.stages.len(), 8);
+        assert!(plan
+            .stage(STAGE_GRAPH)
+            .unwrap()
+            .implementation
+            .contains("edge_weight_policy: Binary"));
+        assert_eq!(
+            plan.stage(STAGE_GRAPH_TRANSFORM).unwrap().implementation,
+            "[KCoreTransform { k: 2 }, KCoreTransform { k: 3 }]"
+        );
+        assert_eq!(
+            plan.stage(STAGE_RANK).unwrap().implementation,
+            "PageRankRanker"
+        );
+        assert_eq!(plan.config.top_n, 4);
+        let resolved = plan.spec.as_ref().unwrap();
+        assert_eq!(resolved.preset.as_deref(), Some("textrank"));
+        assert_eq!(resolved.modules.graph_transforms.len(), 2);
+
+        let invalid = PipelineSpec::from_json(r#"{ "v": 1, "config": { "top_n": -1 } }"#).unwrap();
+        assert!(SpecPipelineBuilder::new()
+            .explain(&invalid, &deterministic_config())
+            .is_err());
+    }
+
     #[test]
     fn test_build_from_spec_preset_position_rank() {
         let spec = PipelineSpec::Preset("position_rank".into());
@@ -854,11 +1307,13 @@ mod tests {
                     window_size: Some(5),
                     cross_sentence: None, // inherits from preset
                     edge_weighting: None,
+                    scope: None,
                 }),
                 ..Default::default()
             },
             runtime: Default::default(),
             expose: None,
+            config: HashMap::new(),
             strict: false,
             unknown_fields: HashMap::new(),
         }));
@@ -914,6 +1369,7 @@ mod tests {
             },
             runtime: Default::default(),
             expose: None,
+            config: HashMap::new(),
             strict: false,
             unknown_fields: HashMap::new(),
         }));
@@ -0,0 +1,176 @@
+//! Reusable teleport weight functions.
+//!
+//! [`position_weights`] computes the position bias behind PositionRank as a
+//! plain map from graph node key (e.g. `lemma` or `lemma|POS`) to weight, so
+//! any personalized variant can use it — e.g. as the weights of a
+//! [`TopicWeightsTeleportBuilder`](super::TopicWeightsTeleportBuilder), or
+//! multiplied into its own teleport vector.
+//! [`PositionTeleportBuilder`](super::PositionTeleportBuilder) applies it
+//! directly.
+
+use crate::pipeline::artifacts::TokenStreamRef;
+use crate::types::NodeKey;
+use std::collections::HashMap;
+
+/// Default half-life of [`PositionDecay::Exponential`], in tokens.
+pub const DEFAULT_HALF_LIFE: f64 = 50.0;
+
+/// How a word's teleport weight decays with the positions it occurs at.
+///
+/// Positions are 0-based token indices.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PositionDecay {
+    /// `1 / (position + 1)` of the first occurrence only.
+    #[default]
+    FirstOccurrence,
+    /// Sum of `1 / (position + 1)` over all occurrences, as in the
+    /// PositionRank paper: frequent early words weigh most.
+    Inverse,
+    /// Sum of `0.5^(position / half_life)` over all occurrences.
+    Exponential {
+        /// Tokens after which an occurrence counts half as much.
+        half_life: f64,
+    },
+}
+
+impl PositionDecay {
+    /// All shape names accepted by [`from_shape`](Self::from_shape).
+    pub const SHAPES: [&'static str; 3] = ["first_occurrence", "inverse", "exponential"];
+
+    /// Parse a pipeline-spec `shape` name; `half_life` applies to
+    /// `"exponential"` (default [`DEFAULT_HALF_LIFE`]).
+    pub fn from_shape(shape: &str, half_life: Option<f64>) -> Option<Self> {
+        match shape {
+            "first_occurrence" => Some(Self::FirstOccurrence),
+            "inverse" => Some(Self::Inverse),
+            "exponential" => Some(Self::Exponential {
+                half_life: half_life.unwrap_or(DEFAULT_HALF_LIFE),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Shape name, as used in pipeline specs.
+    pub fn shape(&self) -> &'static str {
+        match self {
+            Self::FirstOccurrence => "first_occurrence",
+            Self::Inverse => "inverse",
+            Self::Exponential { .. } => "exponential",
+        }
+    }
+
+    /// Weight contributed by one occurrence at `position`.
+    pub fn weight(&self, position: u32) -> f64 {
+        match self {
+            Self::FirstOccurrence | Self::Inverse => 1.0 / (position as f64 + 1.0),
+            Self::Exponential { half_life } => 0.5f64.powf(position as f64 / half_life),
+        }
+    }
+
+    /// Whether every occurrence contributes, rather than the first only.
+    pub fn sums_occurrences(&self) -> bool {
+        !matches!(self, Self::FirstOccurrence)
+    }
+}
+
+/// Position weight of every non-stopword in `tokens`, keyed by graph node
+/// key under `node_key` (a [`NodeKey`], or `use_pos_in_nodes` as a `bool`:
+/// `"lemma|POS"` when `true`, otherwise the lemma).
+///
+/// Weights are not normalized.
+pub fn position_weights(
+    tokens: TokenStreamRef<'_>,
+    decay: PositionDecay,
+    node_key: impl Into<NodeKey>,
+) -> HashMap<String, f64> {
+    let pool = tokens.pool();
+    let node_key = node_key.into();
+    let mut weights: HashMap<String, f64> = HashMap::new();
+    for entry in tokens.tokens().iter().filter(|e| !e.is_stopword) {
+        let weight = decay.weight(entry.token_idx);
+        let key = entry.node_key(pool, &node_key);
+        match weights.get_mut(&key) {
+            Some(total) if decay.sums_occurrences() => *total += weight,
+            Some(_) => {}
+            None => {
+                weights.insert(key, weight);
+            }
+        }
+    }
+    weights
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::artifacts::TokenStream;
+    use crate::types::{PosTag, Token};
+
+    fn stream() -> TokenStream {
+        // "graph rank graph"
+        TokenStream::from_tokens(&[
+            Token::new("graph", "graph", PosTag::Noun, 0, 5, 0, 0),
+            Token::new("rank", "rank", PosTag::Noun, 6, 10, 0, 1),
+            Token::new("graph", "graph", PosTag::Noun, 11, 16, 0, 2),
+        ])
+    }
+
+    #[test]
+    fn test_decay_shapes() {
+        let stream = stream();
+        let weights = |decay| position_weights(stream.as_ref(), decay, false);
+
+        let first = weights(PositionDecay::FirstOccurrence);
+        assert_eq!(first["graph"], 1.0);
+        assert_eq!(first["rank"], 0.5);
+
+        let inverse = weights(PositionDecay::Inverse);
+        assert!((inverse["graph"] - (1.0 + 1.0 / 3.0)).abs() < 1e-12);
+        assert_eq!(inverse["rank"], 0.5);
+
+        let exp = weights(PositionDecay::Exponential { half_life: 2.0 });
+        assert!((exp["graph"] - 1.5).abs() < 1e-12);
+        assert!((exp["rank"] - 0.5f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_keys_follow_use_pos_in_nodes() {
+        let stream = stream();
+        let weights = position_weights(stream.as_ref(), PositionDecay::default(), true);
+        assert!(weights.contains_key("graph|NOUN"));
+        assert!(!weights.contains_key("graph"));
+    }
+
+    #[test]
+    fn test_keys_follow_node_key() {
+        let stream = TokenStream::from_tokens(&[
+            Token::new("Graphs", "graph", PosTag::Noun, 0, 6, 0, 0),
+            Token::new("graphs", "graph", PosTag::Noun, 7, 13, 0, 1),
+        ]);
+        let surface = position_weights(stream.as_ref(), PositionDecay::Inverse, NodeKey::Surface);
+        assert_eq!(surface["Graphs"], 1.0);
+        assert_eq!(surface["graphs"], 0.5);
+
+        let lower = position_weights(
+            stream.as_ref(),
+            PositionDecay::Inverse,
+            NodeKey::LowercaseSurface,
+        );
+        assert_eq!(lower["graphs"], 1.5);
+    }
+
+    #[test]
+    fn test_from_shape() {
+        for shape in PositionDecay::SHAPES {
+            assert_eq!(
+                PositionDecay::from_shape(shape, None).unwrap().shape(),
+                shape
+            );
+        }
+        assert_eq!(
+            PositionDecay::from_shape("exponential", Some(8.0)),
+            Some(PositionDecay::Exponential { half_life: 8.0 })
+        );
+        assert_eq!(PositionDecay::from_shape("linear", None), None);
+    }
+}
@@ -4,14 +4,48 @@
 //! statically dispatched for performance; trait objects are available behind a
 //! feature gate for dynamic composition.
 
+use crate::nlp::cjk::{is_fallback_candidate, DEFAULT_MIN_CANDIDATE_CHARS};
+use crate::nlp::corpus::CorpusStats;
+use crate::nlp::lemma::Lemmatizer;
+#[cfg(feature = "pos-tagger")]
+use crate::nlp::pos_tagger::PerceptronTagger;
+use crate::pagerank::hits::HitsScore;
+use crate::phrase::extraction::{choose_surface, select_surface_index, word_count_in_range};
 use crate::pipeline::artifacts::{
     CandidateKind, CandidateSet, CandidateSetRef, ClusterAssignments, DebugPayload,
-    FormattedResult, Graph, PhraseCandidate, PhraseEntry, PhraseSet, RankOutput, TeleportType,
-    TeleportVector, TokenStream, TokenStreamRef, WordCandidate,
+    FormattedResult, Graph, NodeIdentity, PhraseCandidate, PhraseEntry, PhraseSet, RankOutput,
+    TeleportType, TeleportVector, TokenEntry, TokenStream, TokenStreamRef, WordCandidate,
 };
-use crate::types::{ChunkSpan, PosTag, TextRankConfig};
+use crate::pipeline::teleport::{position_weights, PositionDecay};
+use crate::types::{
+    ChunkSpan, PosTag, RankPrecision, RankerAcceleration, Section, SurfaceSelection,
+    TextRankConfig, Token,
+};
+#[cfg(feature = "sentence-rank")]
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+/// [`std::any::type_name`] with module paths stripped, including inside
+/// generic arguments (`TopicGraphBuilder<JaccardHacClusterer>`).
+pub(crate) fn short_type_name<T: ?Sized>() -> String {
+    let full = std::any::type_name::<T>();
+    let mut out = String::with_capacity(full.len());
+    let mut path = String::new();
+    for c in full.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            out.push_str(path.rsplit("::").next().unwrap_or_default());
+            path.clear();
+            out.push(c);
+        }
+    }
+    out.push_str(path.rsplit("::").next().unwrap_or_default());
+    out
+}
 
 // ============================================================================
 // Preprocessor — optional token normalization (stage 0)
@@ -45,6 +79,26 @@ use std::collections::HashMap;
 pub trait Preprocessor {
     /// Preprocess the token stream in place.
     fn preprocess(&self, tokens: &mut TokenStream, cfg: &TextRankConfig);
+
+    /// Tokenize raw `text`; runs first in the preprocess stage of
+    /// [`Pipeline::run_text`](crate::pipeline::Pipeline::run_text), before
+    /// [`preprocess`](Self::preprocess).
+    ///
+    /// The default uses the built-in tokenizer
+    /// ([`TokenStream::from_text`]). Override it to plug in another
+    /// tokenizer.
+    fn tokenize(&self, text: &str, cfg: &TextRankConfig) -> TokenStream {
+        TokenStream::from_text(text, cfg)
+    }
+
+    /// Short description of this stage, used by
+    /// [`Pipeline::explain`](crate::pipeline::Pipeline::explain).
+    ///
+    /// Defaults to the type name. Stages with parameters override it to
+    /// include them.
+    fn describe(&self) -> String {
+        short_type_name::<Self>()
+    }
 }
 
 /// No-op preprocessor — the default for most pipeline configurations.
@@ -60,6 +114,109 @@ impl Preprocessor for NoopPreprocessor {
     }
 }
 
+/// Re-derives token lemmas according to `cfg.lemma_strategy` and
+/// `cfg.normalization`.
+///
+/// A no-op for the defaults ([`LemmaStrategy::AsProvided`](crate::types::LemmaStrategy), no
+/// normalization), so it is safe to install unconditionally. Useful when
+/// tokens come from a tokenizer without a lemmatizer, or when the same word
+/// arrives in different Unicode forms.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LemmaPreprocessor;
+
+impl Preprocessor for LemmaPreprocessor {
+    fn preprocess(&self, tokens: &mut TokenStream, cfg: &TextRankConfig) {
+        let lemmatizer = Lemmatizer::for_config(cfg);
+        if lemmatizer.is_identity() {
+            return;
+        }
+        for i in 0..tokens.len() {
+            let entry = tokens.tokens()[i];
+            let lemma = lemmatizer.lemma(tokens.text(&entry), tokens.lemma(&entry));
+            let lemma_id = tokens.pool_mut().intern(&lemma);
+            tokens.tokens_mut()[i].lemma_id = lemma_id;
+        }
+    }
+}
+
+/// Makes untagged (`PosTag::Other`) tokens usable as candidates.
+///
+/// Intended for pre-segmented CJK input without POS tags: see
+/// [`apply_pos_fallback`](crate::nlp::cjk::apply_pos_fallback) for the
+/// stopword + length rule.
+#[derive(Debug, Clone, Copy)]
+pub struct PosFallbackPreprocessor {
+    /// Minimum candidate length in characters
+    pub min_chars: usize,
+}
+
+impl Default for PosFallbackPreprocessor {
+    fn default() -> Self {
+        Self {
+            min_chars: DEFAULT_MIN_CANDIDATE_CHARS,
+        }
+    }
+}
+
+impl PosFallbackPreprocessor {
+    /// Create with a custom minimum candidate length
+    pub fn new(min_chars: usize) -> Self {
+        Self { min_chars }
+    }
+}
+
+impl Preprocessor for PosFallbackPreprocessor {
+    fn preprocess(&self, tokens: &mut TokenStream, _cfg: &TextRankConfig) {
+        for i in 0..tokens.len() {
+            let entry = tokens.tokens()[i];
+            if entry.pos != PosTag::Other {
+                continue;
+            }
+            let text = tokens.text(&entry);
+            let candidate = is_fallback_candidate(text, entry.is_stopword, self.min_chars);
+            let entry = &mut tokens.tokens_mut()[i];
+            if candidate {
+                entry.pos = PosTag::Noun;
+            } else {
+                entry.is_stopword = true;
+            }
+        }
+    }
+}
+
+/// Re-tags token POS with a trained [`PerceptronTagger`].
+///
+/// Useful when tokens come from the built-in tokenizer or another source
+/// without a real tagger. Sentences are tagged independently.
+#[cfg(feature = "pos-tagger")]
+#[derive(Debug, Clone)]
+pub struct PosTagPreprocessor {
+    tagger: Arc<PerceptronTagger>,
+}
+
+#[cfg(feature = "pos-tagger")]
+impl PosTagPreprocessor {
+    /// Create a preprocessor around a trained tagger
+    pub fn new(tagger: Arc<PerceptronTagger>) -> Self {
+        Self { tagger }
+    }
+}
+
+#[cfg(feature = "pos-tagger")]
+impl Preprocessor for PosTagPreprocessor {
+    fn preprocess(&self, tokens: &mut TokenStream, _cfg: &TextRankConfig) {
+        let entries = tokens.tokens();
+        let mut tags = Vec::with_capacity(entries.len());
+        for sentence in entries.chunk_by(|a, b| a.sentence_idx == b.sentence_idx) {
+            let words: Vec<&str> = sentence.iter().map(|e| tokens.text(e)).collect();
+            tags.extend(self.tagger.tag_words(&words));
+        }
+        for (entry, tag) in tokens.tokens_mut().iter_mut().zip(tags) {
+            entry.pos = tag;
+        }
+    }
+}
+
 // ============================================================================
 // CandidateSelector — token stream to candidate nodes (stage 1)
 // ============================================================================
@@ -76,6 +233,12 @@ impl Preprocessor for NoopPreprocessor {
 ///   chunks as multi-token candidates for topic-based clustering. Used by
 ///   TopicRank and MultipartiteRank.
 ///
+/// [`NounChunkSelector`] is a phrase-level variant that chunks the tokens
+/// itself instead of taking pre-computed spans.
+///
+/// [`GrammarCandidateSelector`] is a word-level variant whose node set comes
+/// from POS-pattern matches rather than a fixed POS filter.
+///
 /// # Contract
 ///
 /// - **Input**: a borrowed [`TokenStreamRef`] (read-only) and config.
@@ -85,6 +248,15 @@ impl Preprocessor for NoopPreprocessor {
 pub trait CandidateSelector {
     /// Select candidates from the token stream.
     fn select(&self, tokens: TokenStreamRef<'_>, cfg: &TextRankConfig) -> CandidateSet;
+
+    /// Short description of this stage, used by
+    /// [`Pipeline::explain`](crate::pipeline::Pipeline::explain).
+    ///
+    /// Defaults to the type name. Stages with parameters override it to
+    /// include them.
+    fn describe(&self) -> String {
+        short_type_name::<Self>()
+    }
 }
 
 /// Word-level candidate selector for the TextRank family.
@@ -100,11 +272,11 @@ pub struct WordNodeSelector;
 
 impl CandidateSelector for WordNodeSelector {
     fn select(&self, tokens: TokenStreamRef<'_>, cfg: &TextRankConfig) -> CandidateSet {
-        use crate::types::PosTag;
         use rustc_hash::FxHashMap;
 
-        // Key: (lemma_id, optional POS discriminant) → index into `words`.
-        let mut seen: FxHashMap<(u32, Option<PosTag>), usize> = FxHashMap::default();
+        let node_key = cfg.effective_node_key();
+        // Node identity → index into `words`.
+        let mut seen: FxHashMap<NodeIdentity, usize> = FxHashMap::default();
         let mut words = Vec::new();
 
         for entry in tokens.tokens() {
@@ -120,12 +292,7 @@ impl CandidateSelector for WordNodeSelector {
                 continue;
             }
 
-            let key = if cfg.use_pos_in_nodes {
-                (entry.lemma_id, Some(entry.pos))
-            } else {
-                (entry.lemma_id, None)
-            };
-
+            let key = entry.node_identity(tokens.pool(), &node_key);
             if let std::collections::hash_map::Entry::Vacant(e) = seen.entry(key) {
                 e.insert(words.len());
                 words.push(WordCandidate {
@@ -173,35 +340,236 @@ impl CandidateSelector for PhraseCandidateSelector {
             if start >= end || end > tokens.len() {
                 continue;
             }
+            phrases.push(phrase_candidate(
+                tokens,
+                start..end,
+                chunk.start_char as u32,
+                chunk.end_char as u32,
+                chunk.sentence_idx as u32,
+            ));
+        }
 
-            let mut lemma_ids = Vec::with_capacity(end - start);
-            let mut term_ids = Vec::new();
+        CandidateSet::from_kind(CandidateKind::Phrases(phrases))
+    }
+}
 
-            for &entry in &tokens.tokens()[start..end] {
-                lemma_ids.push(entry.lemma_id);
-                if !entry.is_stopword {
-                    // Use text_id for term set (matches legacy PhraseCandidate).
-                    if !term_ids.contains(&entry.text_id) {
-                        term_ids.push(entry.text_id);
-                    }
+/// Build a [`PhraseCandidate`] for the token span `range`.
+fn phrase_candidate(
+    tokens: TokenStreamRef<'_>,
+    range: std::ops::Range<usize>,
+    start_char: u32,
+    end_char: u32,
+    sentence_idx: u32,
+) -> PhraseCandidate {
+    let mut lemma_ids = Vec::with_capacity(range.len());
+    let mut term_ids = Vec::new();
+
+    for &entry in &tokens.tokens()[range.clone()] {
+        lemma_ids.push(entry.lemma_id);
+        if !entry.is_stopword {
+            // Lemmas, so inflected variants share terms (matches legacy
+            // PhraseCandidate).
+            if !term_ids.contains(&entry.lemma_id) {
+                term_ids.push(entry.lemma_id);
+            }
+        }
+    }
+
+    PhraseCandidate {
+        start_token: range.start as u32,
+        end_token: range.end as u32,
+        start_char,
+        end_char,
+        sentence_idx,
+        lemma_ids,
+        term_ids,
+    }
+}
+
+/// Phrase-level candidate selector that chunks the token stream itself.
+///
+/// Greedily builds maximal runs of non-stopword adjectives and nouns
+/// (`(ADJ)* (NOUN|PROPN)+`-shaped) within each sentence and turns each run
+/// into one [`PhraseCandidate`].  Trailing adjectives are dropped so every
+/// chunk ends on its head noun; runs longer than `max_phrase_length` keep
+/// their rightmost `max_phrase_length` tokens, and runs shorter than
+/// `min_phrase_length` are skipped.
+///
+/// Two chunks separated by one of `phrase_bridge_words` are joined into one
+/// candidate when the result fits in `max_phrase_length`.
+///
+/// Unlike [`PhraseCandidateSelector`] this needs no pre-computed chunks, so
+/// TopicRank / MultipartiteRank pipelines can run straight from tokens.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NounChunkSelector;
+
+impl CandidateSelector for NounChunkSelector {
+    fn select(&self, tokens: TokenStreamRef<'_>, cfg: &TextRankConfig) -> CandidateSet {
+        let entries = tokens.tokens();
+        let is_bridge = |t: &TokenEntry| {
+            !cfg.phrase_bridge_words.is_empty() && {
+                let text = tokens.text(t).to_lowercase();
+                cfg.phrase_bridge_words
+                    .iter()
+                    .any(|w| w.to_lowercase() == text)
+            }
+        };
+
+        // Maximal adjective+noun runs, trimmed to end on a noun.
+        let mut runs: Vec<(usize, usize)> = Vec::new();
+        let mut i = 0;
+        while i < entries.len() {
+            let sentence_idx = entries[i].sentence_idx;
+            let in_chunk = |t: &TokenEntry| {
+                !t.is_stopword
+                    && t.sentence_idx == sentence_idx
+                    && (t.pos.is_noun() || t.pos == PosTag::Adjective)
+            };
+            if !in_chunk(&entries[i]) {
+                i += 1;
+                continue;
+            }
+
+            let run_start = i;
+            while i < entries.len() && in_chunk(&entries[i]) {
+                i += 1;
+            }
+            let Some(last_noun) = entries[run_start..i].iter().rposition(|t| t.pos.is_noun())
+            else {
+                continue;
+            };
+            let end = run_start + last_noun + 1;
+
+            // Join with the previous run across a single bridge word
+            // ("quality of service") while within the maximum length.
+            match runs.last_mut() {
+                Some((prev_start, prev_end))
+                    if *prev_end + 1 == run_start
+                        && entries[*prev_start].sentence_idx == sentence_idx
+                        && is_bridge(&entries[*prev_end])
+                        && end - *prev_start <= cfg.max_phrase_length =>
+                {
+                    *prev_end = end;
                 }
+                _ => runs.push((run_start, end)),
             }
+        }
 
-            phrases.push(PhraseCandidate {
-                start_token: start as u32,
-                end_token: end as u32,
-                start_char: chunk.start_char as u32,
-                end_char: chunk.end_char as u32,
-                sentence_idx: chunk.sentence_idx as u32,
-                lemma_ids,
-                term_ids,
-            });
+        let mut phrases = Vec::with_capacity(runs.len());
+        for (run_start, end) in runs {
+            let start = run_start.max(end.saturating_sub(cfg.max_phrase_length));
+            if end - start < cfg.min_phrase_length {
+                continue;
+            }
+            phrases.push(phrase_candidate(
+                tokens,
+                start..end,
+                entries[start].start,
+                entries[end - 1].end,
+                entries[start].sentence_idx,
+            ));
         }
 
         CandidateSet::from_kind(CandidateKind::Phrases(phrases))
     }
 }
 
+/// Word-level candidate selector driven by a POS-pattern grammar.
+///
+/// Matches a [`PosGrammar`] within each sentence (leftmost-longest,
+/// non-overlapping, `min_phrase_length..=max_phrase_length` tokens) in
+/// place of the fixed `include_pos` filter:
+///
+/// - the graph nodes are the distinct graph keys of the non-stopword tokens
+///   inside matches, so the pattern decides which words get ranked;
+/// - the matches themselves are attached as
+///   [`phrase spans`](CandidateSet::phrase_spans), which
+///   [`ChunkPhraseBuilder`] scores instead of its own noun chunks.
+///
+/// This reproduces PKE's `grammar_selection` and domain patterns such as
+/// `Noun Prep Noun` ("rate of change").
+///
+/// [`PosGrammar`]: crate::phrase::grammar::PosGrammar
+#[derive(Debug, Clone, Default)]
+pub struct GrammarCandidateSelector {
+    grammar: crate::phrase::grammar::PosGrammar,
+}
+
+impl GrammarCandidateSelector {
+    /// Create a selector for a compiled grammar.
+    pub fn new(grammar: crate::phrase::grammar::PosGrammar) -> Self {
+        Self { grammar }
+    }
+
+    /// Parse `pattern` (see [`PosGrammar`](crate::phrase::grammar::PosGrammar)
+    /// for the syntax) and create a selector for it.
+    pub fn from_pattern(pattern: &str) -> crate::errors::Result<Self> {
+        crate::phrase::grammar::PosGrammar::parse(pattern).map(Self::new)
+    }
+
+    /// The grammar in use.
+    pub fn grammar(&self) -> &crate::phrase::grammar::PosGrammar {
+        &self.grammar
+    }
+}
+
+impl CandidateSelector for GrammarCandidateSelector {
+    fn select(&self, tokens: TokenStreamRef<'_>, cfg: &TextRankConfig) -> CandidateSet {
+        use crate::types::PosTag;
+        use rustc_hash::FxHashMap;
+
+        let entries = tokens.tokens();
+        let node_key = cfg.effective_node_key();
+        let mut seen: FxHashMap<NodeIdentity, usize> = FxHashMap::default();
+        let mut words = Vec::new();
+        let mut spans = Vec::new();
+
+        let mut sentence_start = 0;
+        while sentence_start < entries.len() {
+            let sentence_idx = entries[sentence_start].sentence_idx;
+            let sentence_end = entries[sentence_start..]
+                .iter()
+                .position(|t| t.sentence_idx != sentence_idx)
+                .map_or(entries.len(), |n| sentence_start + n);
+            let tags: Vec<PosTag> = entries[sentence_start..sentence_end]
+                .iter()
+                .map(|t| t.pos)
+                .collect();
+
+            for span in self
+                .grammar
+                .find_spans(&tags, cfg.min_phrase_length, cfg.max_phrase_length)
+            {
+                let (start, end) = (sentence_start + span.start, sentence_start + span.end);
+                for entry in &entries[start..end] {
+                    if entry.is_stopword {
+                        continue;
+                    }
+                    let key = entry.node_identity(tokens.pool(), &node_key);
+                    if let std::collections::hash_map::Entry::Vacant(e) = seen.entry(key) {
+                        e.insert(words.len());
+                        words.push(WordCandidate {
+                            lemma_id: entry.lemma_id,
+                            pos: entry.pos,
+                            first_position: entry.token_idx,
+                        });
+                    }
+                }
+                spans.push(ChunkSpan {
+                    start_token: start,
+                    end_token: end,
+                    start_char: entries[start].start as usize,
+                    end_char: entries[end - 1].end as usize,
+                    sentence_idx: sentence_idx as usize,
+                });
+            }
+            sentence_start = sentence_end;
+        }
+
+        CandidateSet::from_kind(CandidateKind::Words(words)).with_phrase_spans(spans)
+    }
+}
+
 /// Sentence-level candidate selector for extractive summarization.
 ///
 /// Each sentence in the token stream becomes a candidate node. Non-stopword
@@ -258,7 +626,10 @@ impl CandidateSelector for SentenceCandidateSelector {
 /// Windowing strategy for co-occurrence graph construction.
 ///
 /// Controls whether the sliding window respects sentence boundaries or spans
-/// the entire document, and embeds the configurable window size.
+/// the entire document, and embeds the configurable window size. The
+/// [`Sentence`](Self::Sentence) and [`Paragraph`](Self::Paragraph) scopes
+/// replace the window with a whole sentence or paragraph (see
+/// [`TokenStream::with_paragraph_starts`](crate::pipeline::artifacts::TokenStream::with_paragraph_starts)).
 ///
 /// # Serde
 ///
@@ -267,6 +638,8 @@ impl CandidateSelector for SentenceCandidateSelector {
 /// ```json
 /// { "type": "sentence_bounded", "window_size": 3 }
 /// { "type": "cross_sentence",   "window_size": 5 }
+/// { "type": "sentence" }
+/// { "type": "paragraph" }
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -283,6 +656,14 @@ pub enum WindowStrategy {
         /// Number of tokens ahead to consider for co-occurrence edges.
         window_size: usize,
     },
+    /// Every pair of candidates in the same sentence co-occurs, however far
+    /// apart.
+    Sentence,
+    /// Every pair of candidates in the same paragraph co-occurs. Streams
+    /// without paragraph information (see
+    /// [`TokenStreamRef::has_paragraphs`]) fall back to
+    /// [`Sentence`](Self::Sentence) scope, as does [`ExpandGraphBuilder`].
+    Paragraph,
 }
 
 /// Default window size used when constructing a `WindowStrategy` without an
@@ -299,12 +680,14 @@ impl Default for WindowStrategy {
 }
 
 impl WindowStrategy {
-    /// Return the window size embedded in this strategy.
+    /// Return the window size embedded in this strategy; `usize::MAX` for
+    /// the whole-sentence and whole-paragraph scopes.
     pub fn window_size(&self) -> usize {
         match self {
             Self::SentenceBounded { window_size } | Self::CrossSentence { window_size } => {
                 *window_size
             }
+            Self::Sentence | Self::Paragraph => usize::MAX,
         }
     }
 
@@ -313,6 +696,12 @@ impl WindowStrategy {
         matches!(self, Self::SentenceBounded { .. })
     }
 
+    /// Returns `true` if co-occurrence spans a whole sentence or paragraph
+    /// instead of a fixed window.
+    pub fn is_scope(&self) -> bool {
+        matches!(self, Self::Sentence | Self::Paragraph)
+    }
+
     /// Returns `true` if this is the cross-sentence variant.
     pub fn is_cross_sentence(&self) -> bool {
         matches!(self, Self::CrossSentence { .. })
@@ -321,8 +710,10 @@ impl WindowStrategy {
 
 /// Edge weight policy for co-occurrence graph construction.
 ///
-/// Controls whether repeated co-occurrences within the window accumulate
-/// weight or produce binary (0/1) edges.
+/// Controls how co-occurrences within the window become edge weights:
+/// binary (0/1), raw counts, distance-decayed counts, or an association
+/// score (PMI, Dice) computed from corpus counts gathered during
+/// construction.
 ///
 /// # Serde
 ///
@@ -331,8 +722,12 @@ impl WindowStrategy {
 /// ```json
 /// "binary"
 /// "count_accumulating"
+/// "distance_decay"
+/// {"exponential_decay": {"tau": 3.0}}
+/// "pmi"
+/// "dice"
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EdgeWeightPolicy {
     /// Edge weight is 1.0 if any co-occurrence exists (default for
@@ -343,6 +738,25 @@ pub enum EdgeWeightPolicy {
     /// window (used by SingleRank). Multiple hits between the same pair
     /// produce higher weights, capturing co-occurrence frequency.
     CountAccumulating,
+    /// Each co-occurrence adds `1 / d`, where `d` is the token distance
+    /// between the two words, so adjacent words bind most strongly.
+    DistanceDecay,
+    /// Each co-occurrence adds `exp(-d / tau)`: like
+    /// [`DistanceDecay`](Self::DistanceDecay), with the fall-off set by
+    /// `tau` (> 0; larger values flatten it).
+    ExponentialDecay {
+        /// Decay length in tokens.
+        tau: f64,
+    },
+    /// Positive pointwise mutual information of the pair:
+    /// `max(0, ln(p(a,b) / (p(a) p(b))))`, with `p(a,b)` over all windowed
+    /// pairs and `p(a)` over all candidate occurrences. Pairs with
+    /// non-positive PMI get no edge.
+    Pmi,
+    /// Dice coefficient `2 c(a,b) / (c(a) + c(b))`, with `c(a,b)` the
+    /// windowed pair count and `c(a)` the number of windowed pairs `a` takes
+    /// part in, so the weight lies in `(0, 1]`.
+    Dice,
 }
 
 impl Default for EdgeWeightPolicy {
@@ -363,6 +777,29 @@ impl EdgeWeightPolicy {
     pub fn is_count_accumulating(&self) -> bool {
         matches!(self, Self::CountAccumulating)
     }
+
+    /// Returns `true` if weights are derived from corpus counts after all
+    /// pairs have been seen (PMI, Dice).
+    pub fn is_association(&self) -> bool {
+        matches!(self, Self::Pmi | Self::Dice)
+    }
+
+    /// Returns `true` if a co-occurrence's weight depends on the distance
+    /// between the two words.
+    pub fn is_decay(&self) -> bool {
+        matches!(self, Self::DistanceDecay | Self::ExponentialDecay { .. })
+    }
+
+    /// Weight of one co-occurrence `distance` tokens apart (at least 1):
+    /// the decayed weight for decay policies, `1.0` otherwise.
+    pub fn distance_weight(&self, distance: usize) -> f64 {
+        let distance = distance.max(1) as f64;
+        match *self {
+            Self::DistanceDecay => 1.0 / distance,
+            Self::ExponentialDecay { tau } => (-distance / tau).exp(),
+            _ => 1.0,
+        }
+    }
 }
 
 /// Builds a co-occurrence graph from tokens and pre-selected candidates.
@@ -400,6 +837,53 @@ pub trait GraphBuilder {
         candidates: CandidateSetRef<'_>,
         cfg: &TextRankConfig,
     ) -> Graph;
+
+    /// Build a graph, calling `progress(tokens_done, tokens_total)` about
+    /// every `every` tokens and once when done; [`ControlFlow::Break`] stops
+    /// construction early and returns an incomplete graph.
+    ///
+    /// The default implementation delegates to [`build`](Self::build) and
+    /// only reports completion. [`WindowGraphBuilder`] reports as it walks
+    /// the token stream.
+    fn build_with_progress(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+        every: usize,
+        progress: &mut dyn FnMut(usize, usize) -> ControlFlow<()>,
+    ) -> Graph {
+        let _ = every;
+        let graph = self.build(tokens, candidates, cfg);
+        let _ = progress(tokens.len(), tokens.len());
+        graph
+    }
+
+    /// Build a graph, taking scratch memory from workspace buffers.
+    ///
+    /// The default implementation ignores the workspace and delegates to
+    /// [`build`](Self::build). [`WindowGraphBuilder`] collects window pairs
+    /// in the workspace edge buffer and, with the `arena` feature, allocates
+    /// its per-document occurrence buffer in the workspace arena.
+    fn build_reusing(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+        ws: &mut crate::pipeline::artifacts::PipelineWorkspace,
+    ) -> Graph {
+        let _ = ws;
+        self.build(tokens, candidates, cfg)
+    }
+
+    /// Short description of this stage, used by
+    /// [`Pipeline::explain`](crate::pipeline::Pipeline::explain).
+    ///
+    /// Defaults to the type name. Stages with parameters override it to
+    /// include them.
+    fn describe(&self) -> String {
+        short_type_name::<Self>()
+    }
 }
 
 /// Composable windowed graph builder for the word-graph TextRank family.
@@ -416,7 +900,7 @@ pub trait GraphBuilder {
 ///
 /// 1. Build a lookup set from the candidate words (fast `O(1)` membership).
 /// 2. Walk the token stream in document order, collecting candidate
-///    occurrences with their graph keys and sentence indices.
+///    occurrences with their graph nodes and sentence indices.
 /// 3. Slide a window of size [`WindowStrategy::window_size()`] over these
 ///    occurrences, creating undirected edges in the underlying
 ///    [`GraphBuilder`](crate::graph::builder::GraphBuilder) (the mutable
@@ -472,11 +956,105 @@ impl WindowGraphBuilder {
 }
 
 impl GraphBuilder for WindowGraphBuilder {
+    fn describe(&self) -> String {
+        format!("{self:?}")
+    }
+
     fn build(
         &self,
         tokens: TokenStreamRef<'_>,
         candidates: CandidateSetRef<'_>,
         cfg: &TextRankConfig,
+    ) -> Graph {
+        self.build_impl(
+            tokens,
+            candidates,
+            cfg,
+            0,
+            None,
+            &mut Vec::new(),
+            &mut Vec::new(),
+        )
+    }
+
+    fn build_with_progress(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+        every: usize,
+        progress: &mut dyn FnMut(usize, usize) -> ControlFlow<()>,
+    ) -> Graph {
+        let mut stopped = false;
+        let graph = self.build_impl(
+            tokens,
+            candidates,
+            cfg,
+            every.max(1),
+            Some(&mut |done, total| {
+                let flow = progress(done, total);
+                stopped = flow.is_break();
+                flow
+            }),
+            &mut Vec::new(),
+            &mut Vec::new(),
+        );
+        if !stopped {
+            let _ = progress(tokens.len(), tokens.len());
+        }
+        graph
+    }
+
+    fn build_reusing(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+        ws: &mut crate::pipeline::artifacts::PipelineWorkspace,
+    ) -> Graph {
+        let mut edges = std::mem::take(&mut ws.edge_buf);
+        edges.clear();
+        let graph = {
+            #[cfg(feature = "arena")]
+            let mut occurrences = bumpalo::collections::Vec::new_in(ws.arena());
+            #[cfg(not(feature = "arena"))]
+            let mut occurrences = Vec::new();
+            self.build_impl(
+                tokens,
+                candidates,
+                cfg,
+                0,
+                None,
+                &mut occurrences,
+                &mut edges,
+            )
+        };
+        edges.clear();
+        ws.edge_buf = edges;
+        graph
+    }
+}
+
+/// Scratch buffer of candidate occurrences `(sentence_idx, token_idx, node)`
+/// in document order: a `Vec`, or an arena-backed vector with `arena`.
+trait OccurrenceBuf: Extend<(u32, u32, u32)> + std::ops::Deref<Target = [(u32, u32, u32)]> {}
+
+impl<T> OccurrenceBuf for T where
+    T: Extend<(u32, u32, u32)> + std::ops::Deref<Target = [(u32, u32, u32)]>
+{
+}
+
+impl WindowGraphBuilder {
+    #[allow(clippy::too_many_arguments)]
+    fn build_impl(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+        every: usize,
+        mut progress: Option<&mut dyn FnMut(usize, usize) -> ControlFlow<()>>,
+        occurrences: &mut impl OccurrenceBuf,
+        edges: &mut Vec<(u32, u32, f64)>,
     ) -> Graph {
         // Only word-level candidates produce co-occurrence graphs.
         // Other candidate families use their own graph construction.
@@ -488,107 +1066,298 @@ impl GraphBuilder for WindowGraphBuilder {
             }
         };
 
-        // Pre-compute graph key strings once per unique candidate.
-        // This avoids repeated `format!("{}|{}", lemma, pos)` allocations
-        // for every token occurrence.
+        // Map node identities to graph nodes, so each key string is built
+        // once per unique candidate rather than for every token occurrence.
         use rustc_hash::FxHashMap;
-        let key_strings: FxHashMap<(u32, Option<PosTag>), String> = words
+        let pool = tokens.pool();
+        let node_key = cfg.effective_node_key();
+        let mut nodes: FxHashMap<NodeIdentity, Option<u32>> = words
             .iter()
-            .map(|w| {
-                let lookup = if cfg.use_pos_in_nodes {
-                    (w.lemma_id, Some(w.pos))
-                } else {
-                    (w.lemma_id, None)
-                };
-                let gk = w.graph_key(tokens.pool(), cfg.use_pos_in_nodes);
-                (lookup, gk)
-            })
+            .map(|w| (w.node_identity(tokens, &node_key), None))
             .collect();
 
-        // Collect candidate token occurrences in document order with graph
-        // keys and sentence indices for windowing.
-        let mut occurrences: Vec<(u32, &str)> = Vec::new();
-
-        for entry in tokens.tokens() {
-            let key = if cfg.use_pos_in_nodes {
-                (entry.lemma_id, Some(entry.pos))
+        // Collect candidate token occurrences in document order with sentence
+        // (or paragraph) indices (for windowing), token positions (for
+        // distance decay) and graph nodes, created in order of first
+        // occurrence.
+        let mut builder = crate::graph::builder::GraphBuilder::with_capacity(nodes.len());
+        let by_paragraph =
+            self.window_strategy == WindowStrategy::Paragraph && tokens.has_paragraphs();
+        occurrences.extend(tokens.tokens().iter().filter_map(|entry| {
+            let node = nodes.get_mut(&entry.node_identity(pool, &node_key))?;
+            let node = *node.get_or_insert_with(|| {
+                builder.get_or_create_node(&entry.node_key(pool, &node_key))
+            });
+            let unit = if by_paragraph {
+                tokens.paragraph_of(entry.sentence_idx as usize)
             } else {
-                (entry.lemma_id, None)
+                entry.sentence_idx
             };
-            if let Some(gk) = key_strings.get(&key) {
-                occurrences.push((entry.sentence_idx, gk.as_str()));
+            Some((unit, entry.token_idx, node))
+        }));
+
+        let window_size = self.window_strategy.window_size();
+        // Under the paragraph scope occurrences carry their paragraph, so
+        // this bounds the window by either unit.
+        let sentence_bounded =
+            !matches!(self.window_strategy, WindowStrategy::CrossSentence { .. });
+
+        // Long documents: count overlapping chunks in parallel. Decay
+        // weights may then differ in the last bits, so deterministic mode
+        // stays serial.
+        #[cfg(feature = "parallel")]
+        if progress.is_none()
+            && !self.window_strategy.is_scope()
+            && occurrences.len() >= crate::graph::builder::PARALLEL_GRAPH_THRESHOLD
+            && !cfg.determinism.is_deterministic()
+        {
+            let policy = self.edge_weight_policy;
+            let totals = crate::graph::builder::window_cooccurrences_parallel(
+                occurrences,
+                window_size,
+                sentence_bounded,
+                |distance| policy.distance_weight(distance as usize),
+            );
+            match self.edge_weight_policy {
+                EdgeWeightPolicy::Binary => {
+                    edges.extend(totals.iter().map(|&((a, b), _)| (a, b, 1.0)));
+                    builder.set_edges(edges);
+                }
+                EdgeWeightPolicy::CountAccumulating
+                | EdgeWeightPolicy::DistanceDecay
+                | EdgeWeightPolicy::ExponentialDecay { .. } => {
+                    edges.extend(totals.iter().map(|&((a, b), w)| (a, b, w)));
+                    builder.increment_edges(edges);
+                }
+                EdgeWeightPolicy::Pmi | EdgeWeightPolicy::Dice => {
+                    self.add_association_edges(&mut builder, &totals, occurrences);
+                }
             }
+            return Graph::from_builder(&builder);
         }
 
-        // Build edges via the mutable GraphBuilder.
-        let mut builder = crate::graph::builder::GraphBuilder::with_capacity(key_strings.len());
-
-        let window_size = self.window_strategy.window_size();
+        let mut next_report = if progress.is_some() {
+            every
+        } else {
+            usize::MAX
+        };
 
-        match self.window_strategy {
-            WindowStrategy::SentenceBounded { .. } => {
-                let mut i = 0;
-                while i < occurrences.len() {
-                    let sent_idx = occurrences[i].0;
-                    let sent_start = i;
-                    while i < occurrences.len() && occurrences[i].0 == sent_idx {
-                        i += 1;
-                    }
-                    let sent_end = i;
-
-                    for j in sent_start..sent_end {
-                        let node_j = builder.get_or_create_node(occurrences[j].1);
-                        let window_end = std::cmp::min(j + window_size, sent_end);
-                        for occ_k in occurrences.iter().take(window_end).skip(j + 1) {
-                            let node_k = builder.get_or_create_node(occ_k.1);
-                            match self.edge_weight_policy {
-                                EdgeWeightPolicy::Binary => {
-                                    builder.set_edge(node_j, node_k, 1.0);
-                                }
-                                EdgeWeightPolicy::CountAccumulating => {
-                                    builder.increment_edge(node_j, node_k, 1.0);
-                                }
-                            }
-                        }
+        // Collect windowed pairs, then apply them in one sorted pass.
+        for j in 0..occurrences.len() {
+            let position = occurrences[j].1 as usize;
+            if position >= next_report {
+                if let Some(progress) = progress.as_mut() {
+                    if progress(position, tokens.len()).is_break() {
+                        return Graph::from_builder(&builder);
                     }
+                    next_report = (position / every + 1) * every;
                 }
             }
-            WindowStrategy::CrossSentence { .. } => {
-                for j in 0..occurrences.len() {
-                    let node_j = builder.get_or_create_node(occurrences[j].1);
-                    let window_end = std::cmp::min(j + window_size, occurrences.len());
-                    for occ_k in occurrences.iter().take(window_end).skip(j + 1) {
-                        let node_k = builder.get_or_create_node(occ_k.1);
-                        match self.edge_weight_policy {
-                            EdgeWeightPolicy::Binary => {
-                                builder.set_edge(node_j, node_k, 1.0);
-                            }
-                            EdgeWeightPolicy::CountAccumulating => {
-                                builder.increment_edge(node_j, node_k, 1.0);
-                            }
-                        }
+            let window_end = std::cmp::min(j.saturating_add(window_size), occurrences.len());
+            for k in (j + 1)..window_end {
+                if sentence_bounded && occurrences[k].0 != occurrences[j].0 {
+                    break;
+                }
+                let distance = occurrences[k].1.saturating_sub(occurrences[j].1);
+                let weight = self.edge_weight_policy.distance_weight(distance as usize);
+                edges.push((occurrences[j].2, occurrences[k].2, weight));
+            }
+        }
+
+        match self.edge_weight_policy {
+            EdgeWeightPolicy::Binary => builder.set_edges(edges),
+            EdgeWeightPolicy::CountAccumulating
+            | EdgeWeightPolicy::DistanceDecay
+            | EdgeWeightPolicy::ExponentialDecay { .. } => builder.increment_edges(edges),
+            EdgeWeightPolicy::Pmi | EdgeWeightPolicy::Dice => {
+                // Run-length pair counts keyed (min, max), in key order.
+                edges.retain(|&(a, b, _)| a != b);
+                for e in edges.iter_mut() {
+                    *e = (e.0.min(e.1), e.0.max(e.1), e.2);
+                }
+                edges.sort_unstable_by_key(|&(a, b, _)| (a, b));
+                let mut pairs: Vec<((u32, u32), f64)> = Vec::new();
+                for &(a, b, _) in edges.iter() {
+                    match pairs.last_mut() {
+                        Some((pair, count)) if *pair == (a, b) => *count += 1.0,
+                        _ => pairs.push(((a, b), 1.0)),
                     }
                 }
+                self.add_association_edges(&mut builder, &pairs, occurrences);
             }
         }
 
         Graph::from_builder(&builder)
     }
+
+    /// Set PMI or Dice edges from windowed pair counts, sorted by pair.
+    fn add_association_edges(
+        &self,
+        builder: &mut crate::graph::builder::GraphBuilder,
+        pairs: &[((u32, u32), f64)],
+        occurrences: &[(u32, u32, u32)],
+    ) {
+        if pairs.is_empty() {
+            return;
+        }
+
+        // PMI uses occurrence counts; Dice uses windowed pair marginals, so
+        // that `c(a,b) <= min(c(a), c(b))`.
+        let mut word_counts = vec![0.0; builder.node_count()];
+        if self.edge_weight_policy == EdgeWeightPolicy::Pmi {
+            for &(_, _, node) in occurrences {
+                word_counts[node as usize] += 1.0;
+            }
+        } else {
+            for &((a, b), count) in pairs {
+                word_counts[a as usize] += count;
+                word_counts[b as usize] += count;
+            }
+        }
+        let total_words = occurrences.len() as f64;
+        let total_pairs: f64 = pairs.iter().map(|&(_, count)| count).sum();
+
+        for &((a, b), count) in pairs {
+            let (ca, cb) = (word_counts[a as usize], word_counts[b as usize]);
+            let weight = match self.edge_weight_policy {
+                EdgeWeightPolicy::Pmi => {
+                    let p_ab = count / total_pairs;
+                    (p_ab / ((ca / total_words) * (cb / total_words))).ln()
+                }
+                _ => 2.0 * count / (ca + cb),
+            };
+            if weight > 0.0 {
+                builder.set_edge(a, b, weight);
+            }
+        }
+    }
 }
 
-// ============================================================================
-// GraphTransform — optional in-place graph modifications (stage 2a)
-// ============================================================================
+// ---------------------------------------------------------------------------
+// ExpandGraphBuilder — neighborhood-expanded co-occurrence graph (ExpandRank)
+// ---------------------------------------------------------------------------
 
-/// Optional in-place graph modification applied after initial construction.
-///
-/// Transforms mutate the [`Graph`] without rebuilding it — typically adjusting
-/// edge weights or removing edges.  Multiple transforms compose as an ordered
-/// `Vec<Box<dyn GraphTransform>>`; **order matters** (e.g., intra-cluster
-/// edge removal must precede alpha-boost weighting in MultipartiteRank).
+/// Co-occurrence graph over the target document plus similarity-weighted
+/// neighbor documents (ExpandRank, Wan & Xiao 2008).
 ///
-/// The provided [`NoopGraphTransform`] is the default for variants that don't
-/// need post-construction modifications (BaseTextRank, PositionRank, etc.).
+/// The target document contributes edges with multiplier `1.0`; each
+/// neighbor contributes its co-occurrence edges scaled by its similarity
+/// weight.  Windowing and edge weighting follow the embedded
+/// [`WindowStrategy`] and [`EdgeWeightPolicy`], exactly as in
+/// [`WindowGraphBuilder`].
+///
+/// Neighbor-only words become graph nodes too (they carry score between
+/// target words), but phrases are still built from the target document only.
+/// Neighbor tokens are filtered with the same `include_pos` / stopword rules
+/// as the target, so their `is_stopword` flags should already be set.
+/// Any non-binary [`EdgeWeightPolicy`] accumulates plain counts here.
+#[derive(Debug, Clone)]
+pub struct ExpandGraphBuilder {
+    /// Windowing behavior (sentence-bounded vs cross-sentence).
+    pub window_strategy: WindowStrategy,
+    /// Edge weight policy (binary vs count-accumulating).
+    pub edge_weight_policy: EdgeWeightPolicy,
+    /// Neighbor documents with their similarity to the target.
+    neighbors: Vec<(Vec<Token>, f64)>,
+}
+
+impl ExpandGraphBuilder {
+    /// ExpandRank configuration: SingleRank windowing (cross-sentence,
+    /// count-accumulating) over the target and the given neighbors.
+    ///
+    /// Negative similarity weights are clamped to `0.0`.
+    pub fn new(neighbors: Vec<(Vec<Token>, f64)>) -> Self {
+        let single = WindowGraphBuilder::single_rank();
+        Self {
+            window_strategy: single.window_strategy,
+            edge_weight_policy: single.edge_weight_policy,
+            neighbors: neighbors
+                .into_iter()
+                .map(|(tokens, w)| (tokens, w.max(0.0)))
+                .collect(),
+        }
+    }
+
+    /// Number of neighbor documents.
+    pub fn num_neighbors(&self) -> usize {
+        self.neighbors.len()
+    }
+}
+
+impl GraphBuilder for ExpandGraphBuilder {
+    fn describe(&self) -> String {
+        format!(
+            "ExpandGraphBuilder {{ window_strategy: {:?}, edge_weight_policy: {:?}, neighbors: {} }}",
+            self.window_strategy,
+            self.edge_weight_policy,
+            self.neighbors.len()
+        )
+    }
+
+    fn build(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+    ) -> Graph {
+        if !matches!(candidates.kind(), CandidateKind::Words(_)) {
+            return Graph::empty();
+        }
+
+        let target = tokens.to_legacy_tokens();
+        let mut streams: Vec<(&[Token], f64)> = Vec::with_capacity(self.neighbors.len() + 1);
+        streams.push((&target, 1.0));
+        streams.extend(self.neighbors.iter().map(|(t, w)| (t.as_slice(), *w)));
+
+        let include_pos = if cfg.include_pos.is_empty() {
+            None
+        } else {
+            Some(cfg.include_pos.as_slice())
+        };
+        // The legacy builder has no paragraph indices: a scoped window spans
+        // the longest sentence and is bounded by sentences (paragraph scope
+        // falls back to sentence scope here).
+        let window_size = if self.window_strategy.is_scope() {
+            longest_sentence(&streams) + 1
+        } else {
+            self.window_strategy.window_size()
+        };
+        let builder = crate::graph::builder::GraphBuilder::from_weighted_streams(
+            &streams,
+            window_size,
+            !self.edge_weight_policy.is_binary(),
+            include_pos,
+            cfg.effective_node_key(),
+            !matches!(self.window_strategy, WindowStrategy::CrossSentence { .. }),
+        );
+
+        Graph::from_builder(&builder)
+    }
+}
+
+/// Most tokens in any one sentence of `streams`.
+fn longest_sentence(streams: &[(&[Token], f64)]) -> usize {
+    streams
+        .iter()
+        .flat_map(|(tokens, _)| tokens.chunk_by(|a, b| a.sentence_idx == b.sentence_idx))
+        .map(<[Token]>::len)
+        .max()
+        .unwrap_or(0)
+}
+
+// ============================================================================
+// GraphTransform — optional in-place graph modifications (stage 2a)
+// ============================================================================
+
+/// Optional in-place graph modification applied after initial construction.
+///
+/// Transforms mutate the [`Graph`] without rebuilding it — typically adjusting
+/// edge weights or removing edges.  Multiple transforms compose as an ordered
+/// `Vec<Box<dyn GraphTransform>>`; **order matters** (e.g., intra-cluster
+/// edge removal must precede alpha-boost weighting in MultipartiteRank).
+///
+/// The provided [`NoopGraphTransform`] is the default for variants that don't
+/// need post-construction modifications (BaseTextRank, PositionRank, etc.).
 ///
 /// # Contract
 ///
@@ -606,6 +1375,12 @@ impl GraphBuilder for WindowGraphBuilder {
 ///   belong to the same topic cluster, forming a k-partite graph.
 /// - **Alpha-boost weighting**: boosts incoming edges to the first-occurring
 ///   variant in each topic cluster, encoding positional preference.
+///
+/// # Pruning
+///
+/// - [`KCoreTransform`] and [`DegreeThresholdTransform`] isolate weakly
+///   connected nodes, shrinking the edge set PageRank iterates over.
+/// - [`EdgeThreshold`] drops low-weight edges.
 pub trait GraphTransform {
     /// Apply the transform to the graph in place.
     fn transform(
@@ -615,6 +1390,15 @@ pub trait GraphTransform {
         candidates: CandidateSetRef<'_>,
         cfg: &TextRankConfig,
     );
+
+    /// Short description of this stage, used by
+    /// [`Pipeline::explain`](crate::pipeline::Pipeline::explain).
+    ///
+    /// Defaults to the type name. Stages with parameters override it to
+    /// include them.
+    fn describe(&self) -> String {
+        short_type_name::<Self>()
+    }
 }
 
 /// No-op graph transform — the default for most pipeline configurations.
@@ -936,6 +1720,10 @@ impl Default for MultipartiteTransform {
 }
 
 impl GraphTransform for MultipartiteTransform {
+    fn describe(&self) -> String {
+        format!("{self:?}")
+    }
+
     fn transform(
         &self,
         graph: &mut Graph,
@@ -1064,6 +1852,326 @@ impl GraphTransform for MultipartiteTransform {
     }
 }
 
+/// Prunes the graph to its k-core before ranking.
+///
+/// The k-core is the maximal subgraph in which every node has at least `k`
+/// neighbours; it is found by repeatedly peeling nodes whose remaining
+/// degree is below `k`. Peripheral words that only co-occur a handful of
+/// times are dropped, which shrinks long-document graphs substantially
+/// while keeping the densely connected terms that dominate the ranking.
+///
+/// Pruned nodes keep their IDs (so node indices stay aligned with
+/// candidates) but lose all edges, leaving them with only the teleport
+/// share of PageRank mass. `k <= 1` leaves the graph unchanged apart from
+/// isolated nodes.
+#[derive(Debug, Clone, Copy)]
+pub struct KCoreTransform {
+    /// Minimum degree a node must retain inside the core.
+    pub k: usize,
+}
+
+impl KCoreTransform {
+    /// Create a k-core transform.
+    pub fn new(k: usize) -> Self {
+        Self { k }
+    }
+}
+
+impl GraphTransform for KCoreTransform {
+    fn describe(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn transform(
+        &self,
+        graph: &mut Graph,
+        _tokens: TokenStreamRef<'_>,
+        _candidates: CandidateSetRef<'_>,
+        _cfg: &TextRankConfig,
+    ) {
+        let n = graph.num_nodes();
+        if n == 0 || self.k == 0 {
+            return;
+        }
+
+        let csr = graph.csr();
+        let positive_neighbors = |node: usize| {
+            (csr.row_ptr[node]..csr.row_ptr[node + 1])
+                .filter(move |&idx| csr.weights[idx] > 0.0 && csr.col_idx[idx] as usize != node)
+                .map(move |idx| csr.col_idx[idx] as usize)
+        };
+
+        let mut degree: Vec<usize> = (0..n)
+            .map(|node| positive_neighbors(node).count())
+            .collect();
+        let mut keep = vec![true; n];
+        let mut queue: Vec<usize> = (0..n).filter(|&node| degree[node] < self.k).collect();
+        for &node in &queue {
+            keep[node] = false;
+        }
+
+        while let Some(node) = queue.pop() {
+            for neighbor in positive_neighbors(node) {
+                if keep[neighbor] {
+                    degree[neighbor] -= 1;
+                    if degree[neighbor] < self.k {
+                        keep[neighbor] = false;
+                        queue.push(neighbor);
+                    }
+                }
+            }
+        }
+
+        if keep.iter().all(|&k| k) {
+            return;
+        }
+        graph.csr_mut().retain_nodes(&keep);
+    }
+}
+
+/// Removes nodes below a degree or weighted-degree threshold.
+///
+/// A single-pass alternative to [`KCoreTransform`]: each node is judged on
+/// its degree in the original graph, without cascading. A node is pruned
+/// if it has fewer than `min_degree` neighbours **or** its total edge
+/// weight is below `min_weight`. As with the k-core, pruned nodes are
+/// isolated rather than removed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DegreeThresholdTransform {
+    /// Minimum number of neighbours (default: 0 — no degree filter).
+    pub min_degree: u32,
+    /// Minimum total outgoing edge weight (default: 0.0 — no weight filter).
+    pub min_weight: f64,
+}
+
+impl DegreeThresholdTransform {
+    /// Create a transform that prunes nodes with fewer than `min_degree` neighbours.
+    pub fn new(min_degree: u32) -> Self {
+        Self {
+            min_degree,
+            min_weight: 0.0,
+        }
+    }
+
+    /// Also prune nodes whose total edge weight is below `min_weight`.
+    pub fn with_min_weight(mut self, min_weight: f64) -> Self {
+        self.min_weight = min_weight;
+        self
+    }
+}
+
+impl GraphTransform for DegreeThresholdTransform {
+    fn describe(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn transform(
+        &self,
+        graph: &mut Graph,
+        _tokens: TokenStreamRef<'_>,
+        _candidates: CandidateSetRef<'_>,
+        _cfg: &TextRankConfig,
+    ) {
+        let n = graph.num_nodes();
+        if n == 0 {
+            return;
+        }
+
+        let csr = graph.csr();
+        let keep: Vec<bool> = (0..n)
+            .map(|node| {
+                csr.out_degree[node] >= self.min_degree && csr.total_weight[node] >= self.min_weight
+            })
+            .collect();
+
+        if keep.iter().all(|&k| k) {
+            return;
+        }
+        graph.csr_mut().retain_nodes(&keep);
+    }
+}
+
+/// Cutoff used by [`EdgeThreshold`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeWeightCutoff {
+    /// Drop edges whose weight is below this value.
+    Absolute(f64),
+    /// Drop edges below this quantile (0.0–1.0) of the positive edge
+    /// weights, e.g. `0.5` drops the lighter half.
+    Quantile(f64),
+}
+
+/// Drops edges below a minimum weight.
+///
+/// On long documents most co-occurrence edges are seen once or twice and
+/// add little signal; removing them cuts memory and PageRank time. The
+/// cutoff is either absolute or a quantile of the graph's own weight
+/// distribution, which adapts to document length and the edge weighting
+/// policy.
+///
+/// Edges are removed from the CSR arrays, not just zeroed. Nodes left
+/// without edges stay in the graph (node indices must stay aligned with
+/// candidates) as isolated nodes that receive only teleport mass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdgeThreshold {
+    /// How the cutoff is determined.
+    pub cutoff: EdgeWeightCutoff,
+}
+
+impl EdgeThreshold {
+    /// Drop edges whose weight is below `min_weight`.
+    pub fn absolute(min_weight: f64) -> Self {
+        Self {
+            cutoff: EdgeWeightCutoff::Absolute(min_weight),
+        }
+    }
+
+    /// Drop edges below the given quantile (clamped to 0.0–1.0).
+    pub fn quantile(q: f64) -> Self {
+        Self {
+            cutoff: EdgeWeightCutoff::Quantile(q.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// Resolve the cutoff to an absolute weight for `graph`.
+    pub fn min_weight(&self, graph: &Graph) -> f64 {
+        match self.cutoff {
+            EdgeWeightCutoff::Absolute(w) => w,
+            EdgeWeightCutoff::Quantile(q) => {
+                let mut weights: Vec<f64> = graph
+                    .csr()
+                    .weights
+                    .iter()
+                    .copied()
+                    .filter(|&w| w > 0.0)
+                    .collect();
+                if weights.is_empty() {
+                    return 0.0;
+                }
+                weights.sort_by(|a, b| a.total_cmp(b));
+                let idx = (q.clamp(0.0, 1.0) * (weights.len() - 1) as f64).floor() as usize;
+                weights[idx]
+            }
+        }
+    }
+}
+
+impl GraphTransform for EdgeThreshold {
+    fn describe(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn transform(
+        &self,
+        graph: &mut Graph,
+        _tokens: TokenStreamRef<'_>,
+        _candidates: CandidateSetRef<'_>,
+        _cfg: &TextRankConfig,
+    ) {
+        if graph.num_nodes() == 0 {
+            return;
+        }
+        let min_weight = self.min_weight(graph);
+        if graph.csr().weights.iter().all(|&w| w >= min_weight) {
+            return;
+        }
+        graph.csr_mut().retain_edges(|_, _, w| w >= min_weight);
+    }
+}
+
+/// Merges frequent adjacent word pairs into meta-vertices (RaKUn-style).
+///
+/// Counts how often two graph words occur directly next to each other in a
+/// sentence. Each pair seen at least `min_count` times is merged, most
+/// frequent first: the second word's edges move to the first (weights of
+/// shared neighbours add up, the edge between the two is dropped), which
+/// shrinks the graph and ranks the collocation as one node. A word joins
+/// at most one meta-vertex, so of two overlapping pairs only the more
+/// frequent is merged.
+///
+/// The second word keeps its node ID (node indices must stay aligned with
+/// candidates) as an isolated node, but its key resolves to the
+/// meta-vertex, so phrase scoring gives both words the collocation's score.
+#[derive(Debug, Clone, Copy)]
+pub struct MetaVertexTransform {
+    /// Minimum number of adjacent occurrences for a pair to merge.
+    pub min_count: u32,
+}
+
+impl MetaVertexTransform {
+    /// Merge pairs seen adjacent at least `min_count` times.
+    pub fn new(min_count: u32) -> Self {
+        Self { min_count }
+    }
+}
+
+impl Default for MetaVertexTransform {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl GraphTransform for MetaVertexTransform {
+    fn describe(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn transform(
+        &self,
+        graph: &mut Graph,
+        tokens: TokenStreamRef<'_>,
+        _candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+    ) {
+        let n = graph.num_nodes();
+        if n < 2 {
+            return;
+        }
+
+        let pool = tokens.pool();
+        let node_key = cfg.effective_node_key();
+        let node_of = |entry: &TokenEntry| {
+            if entry.is_stopword {
+                return None;
+            }
+            graph.get_node_by_lemma(&entry.node_key(pool, &node_key))
+        };
+        let mut counts: HashMap<(u32, u32), u32> = HashMap::new();
+        for pair in tokens.tokens().windows(2) {
+            if pair[0].sentence_idx != pair[1].sentence_idx {
+                continue;
+            }
+            if let (Some(a), Some(b)) = (node_of(&pair[0]), node_of(&pair[1])) {
+                if a != b {
+                    *counts.entry((a, b)).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut pairs: Vec<((u32, u32), u32)> = counts
+            .into_iter()
+            .filter(|&(_, count)| count >= self.min_count.max(1))
+            .collect();
+        pairs.sort_unstable_by(|x, y| y.1.cmp(&x.1).then(x.0.cmp(&y.0)));
+
+        let mut into: Vec<u32> = (0..n as u32).collect();
+        let mut used = vec![false; n];
+        let mut merged = false;
+        for ((a, b), _) in pairs {
+            if used[a as usize] || used[b as usize] {
+                continue;
+            }
+            used[a as usize] = true;
+            used[b as usize] = true;
+            into[b as usize] = a;
+            merged = true;
+        }
+        if merged {
+            graph.csr_mut().merge_nodes(&into);
+        }
+    }
+}
+
 // ============================================================================
 // Clusterer — topic clustering of phrase candidates (stage 1a)
 // ============================================================================
@@ -1097,6 +2205,21 @@ impl GraphTransform for MultipartiteTransform {
 pub trait Clusterer {
     /// Cluster phrase candidates into topic groups.
     fn cluster(&self, candidates: CandidateSetRef<'_>, cfg: &TextRankConfig) -> ClusterAssignments;
+
+    /// Cluster with access to the token stream, for clusterers that compare
+    /// candidate text rather than interned IDs.
+    ///
+    /// Graph builders call this; the default ignores `tokens` and delegates
+    /// to [`cluster`](Self::cluster).
+    fn cluster_with_tokens(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+    ) -> ClusterAssignments {
+        let _ = tokens;
+        self.cluster(candidates, cfg)
+    }
 }
 
 /// No-op clusterer — the default for word-level pipelines.
@@ -1201,37 +2324,133 @@ impl Clusterer for JaccardHacClusterer {
     }
 }
 
-// ============================================================================
-// TeleportBuilder — optional personalization vector (stage 3a)
-// ============================================================================
+/// HAC clusterer over a pluggable [`Similarity`](crate::similarity::Similarity).
+///
+/// Like [`JaccardHacClusterer`], but candidates are compared as
+/// [`Unit`](crate::similarity::Unit)s (surface text and lemmas) by any
+/// similarity — e.g. an [`EmbeddingSimilarity`](crate::similarity::EmbeddingSimilarity)
+/// so that "car" and "automobile" land in the same topic.  Distance is
+/// `1 - similarity`; `similarity_threshold` means the same as for
+/// [`JaccardHacClusterer`].
+///
+/// Candidate text is only available through
+/// [`Clusterer::cluster_with_tokens`]; a plain [`Clusterer::cluster`] call
+/// falls back to Jaccard over term IDs.
+#[derive(Clone)]
+pub struct SimilarityHacClusterer {
+    /// Pairwise candidate similarity.
+    pub similarity: Arc<dyn crate::similarity::Similarity>,
+    /// Similarity threshold for cluster merging.
+    pub similarity_threshold: f64,
+    /// Linkage strategy (default: Average).
+    pub linkage: Linkage,
+}
 
-/// Builds a personalization (teleport) vector for PageRank.
-///
-/// The teleport vector controls where the random surfer jumps during the
-/// teleportation step of PageRank.  It is the key differentiator between:
-///
-/// - **Standard PageRank** (uniform teleport): all nodes equally likely →
-///   builder returns `None`.
-/// - **PositionRank**: earlier-occurring tokens get higher teleport
-///   probability.
-/// - **BiasedTextRank**: user-specified focus terms get boosted teleport
-///   probability.
-/// - **TopicalPageRank**: per-lemma topic weights drive teleport distribution.
-///
-/// Separating teleport construction from ranking allows mixing and matching:
-/// e.g., SingleRank graph construction + PositionRank teleport strategy.
-///
-/// # Contract
-///
-/// - **Input**: a borrowed [`TokenStreamRef`] (read-only), a
-///   [`CandidateSetRef`] (authority on which tokens are graph-eligible),
-///   and config.
-/// - **Output**: `None` for uniform teleport (standard PageRank) or
-///   `Some(TeleportVector)` for a personalized distribution.
-/// - **Normalization**: when returning `Some`, the vector **must** be
-///   normalized (entries sum to 1.0).  The [`Ranker`] stage does **not**
-///   re-normalize.
-/// - **Length**: the vector length must equal the number of graph nodes
+impl std::fmt::Debug for SimilarityHacClusterer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SimilarityHacClusterer")
+            .field("similarity_threshold", &self.similarity_threshold)
+            .field("linkage", &self.linkage)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SimilarityHacClusterer {
+    /// Create a clusterer with the given similarity, threshold and
+    /// [`Linkage::Average`].
+    pub fn new(
+        similarity: Arc<dyn crate::similarity::Similarity>,
+        similarity_threshold: f64,
+    ) -> Self {
+        Self {
+            similarity,
+            similarity_threshold,
+            linkage: Linkage::Average,
+        }
+    }
+
+    /// Override the linkage strategy.
+    pub fn with_linkage(mut self, linkage: Linkage) -> Self {
+        self.linkage = linkage;
+        self
+    }
+}
+
+impl Clusterer for SimilarityHacClusterer {
+    fn cluster(&self, candidates: CandidateSetRef<'_>, cfg: &TextRankConfig) -> ClusterAssignments {
+        JaccardHacClusterer::with_linkage(self.similarity_threshold, self.linkage)
+            .cluster(candidates, cfg)
+    }
+
+    fn cluster_with_tokens(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        _cfg: &TextRankConfig,
+    ) -> ClusterAssignments {
+        use crate::clustering;
+        use crate::similarity::Unit;
+
+        let phrases = candidates.phrases();
+        if phrases.is_empty() {
+            return ClusterAssignments::empty();
+        }
+
+        let entries = tokens.tokens();
+        let texts: Vec<(String, String)> = phrases
+            .iter()
+            .map(|pc| {
+                let span = entries
+                    .get(pc.start_token as usize..pc.end_token as usize)
+                    .unwrap_or(&[]);
+                let text: Vec<&str> = span.iter().map(|e| tokens.text(e)).collect();
+                let lemma: Vec<&str> = span.iter().map(|e| tokens.lemma(e)).collect();
+                (text.join(" "), lemma.join(" "))
+            })
+            .collect();
+        let units: Vec<Unit<'_>> = texts.iter().map(|(t, l)| Unit::new(t, l)).collect();
+
+        let cluster_vecs = clustering::cluster_by_similarity(
+            units.len(),
+            |i, j| self.similarity.sim(&units[i], &units[j]) as f64,
+            self.similarity_threshold,
+            self.linkage,
+        );
+        ClusterAssignments::from_cluster_vecs(&cluster_vecs, phrases.len())
+    }
+}
+
+// ============================================================================
+// TeleportBuilder — optional personalization vector (stage 3a)
+// ============================================================================
+
+/// Builds a personalization (teleport) vector for PageRank.
+///
+/// The teleport vector controls where the random surfer jumps during the
+/// teleportation step of PageRank.  It is the key differentiator between:
+///
+/// - **Standard PageRank** (uniform teleport): all nodes equally likely →
+///   builder returns `None`.
+/// - **PositionRank**: earlier-occurring tokens get higher teleport
+///   probability.
+/// - **BiasedTextRank**: user-specified focus terms get boosted teleport
+///   probability.
+/// - **TopicalPageRank**: per-lemma topic weights drive teleport distribution.
+///
+/// Separating teleport construction from ranking allows mixing and matching:
+/// e.g., SingleRank graph construction + PositionRank teleport strategy.
+///
+/// # Contract
+///
+/// - **Input**: a borrowed [`TokenStreamRef`] (read-only), a
+///   [`CandidateSetRef`] (authority on which tokens are graph-eligible),
+///   and config.
+/// - **Output**: `None` for uniform teleport (standard PageRank) or
+///   `Some(TeleportVector)` for a personalized distribution.
+/// - **Normalization**: when returning `Some`, the vector **must** be
+///   normalized (entries sum to 1.0).  The [`Ranker`] stage does **not**
+///   re-normalize.
+/// - **Length**: the vector length must equal the number of graph nodes
 ///   (i.e., the number of unique candidates that became nodes).
 /// - **Deterministic**: same input → same output (no internal randomness).
 ///
@@ -1250,6 +2469,30 @@ pub trait TeleportBuilder {
         candidates: CandidateSetRef<'_>,
         cfg: &TextRankConfig,
     ) -> Option<TeleportVector>;
+
+    /// Like [`build`](Self::build), also given the (transformed) graph the
+    /// vector will rank. This is what the pipeline runner calls.
+    ///
+    /// Defaults to [`build`](Self::build); builders that weight graph nodes
+    /// directly, like [`FnTeleportBuilder`], override it.
+    fn build_for_graph(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        _graph: &Graph,
+        cfg: &TextRankConfig,
+    ) -> Option<TeleportVector> {
+        self.build(tokens, candidates, cfg)
+    }
+
+    /// Short description of this stage, used by
+    /// [`Pipeline::explain`](crate::pipeline::Pipeline::explain).
+    ///
+    /// Defaults to the type name. Stages with parameters override it to
+    /// include them.
+    fn describe(&self) -> String {
+        short_type_name::<Self>()
+    }
 }
 
 /// Uniform teleport builder — the default for standard PageRank variants.
@@ -1279,7 +2522,8 @@ impl TeleportBuilder for UniformTeleportBuilder {
 /// Assigns teleport probability inversely proportional to each candidate's
 /// first occurrence position in the document: `weight = 1 / (position + 1)`.
 /// Earlier candidates receive higher teleport probability, biasing PageRank
-/// towards terms that appear near the start of the text.
+/// towards terms that appear near the start of the text. For other decay
+/// shapes use [`PositionDecayTeleportBuilder`].
 ///
 /// Returns `None` for phrase-level candidates or empty candidate sets
 /// (falling back to uniform teleportation).
@@ -1289,9 +2533,46 @@ pub struct PositionTeleportBuilder;
 impl TeleportBuilder for PositionTeleportBuilder {
     fn build(
         &self,
-        _tokens: TokenStreamRef<'_>,
+        tokens: TokenStreamRef<'_>,
         candidates: CandidateSetRef<'_>,
-        _cfg: &TextRankConfig,
+        cfg: &TextRankConfig,
+    ) -> Option<TeleportVector> {
+        PositionDecayTeleportBuilder::new(PositionDecay::FirstOccurrence)
+            .build(tokens, candidates, cfg)
+    }
+}
+
+/// Position-biased teleport builder with a configurable [`PositionDecay`].
+///
+/// Weights each candidate by where it occurs in the document, per the
+/// decay function; with [`PositionDecay::FirstOccurrence`] it matches
+/// [`PositionTeleportBuilder`].
+///
+/// Returns `None` for phrase-level candidates or empty candidate sets
+/// (falling back to uniform teleportation).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PositionDecayTeleportBuilder {
+    decay: PositionDecay,
+}
+
+impl PositionDecayTeleportBuilder {
+    /// Create a builder with the given decay function.
+    pub fn new(decay: PositionDecay) -> Self {
+        Self { decay }
+    }
+
+    /// The decay function.
+    pub fn decay(&self) -> PositionDecay {
+        self.decay
+    }
+}
+
+impl TeleportBuilder for PositionDecayTeleportBuilder {
+    fn build(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
     ) -> Option<TeleportVector> {
         let words = match candidates.kind() {
             CandidateKind::Words(w) => w,
@@ -1302,8 +2583,110 @@ impl TeleportBuilder for PositionTeleportBuilder {
         }
 
         let mut tv = TeleportVector::zeros(words.len(), TeleportType::Position);
+        if self.decay.sums_occurrences() {
+            let node_key = cfg.effective_node_key();
+            let weights = position_weights(tokens, self.decay, node_key.clone());
+            for (i, w) in words.iter().enumerate() {
+                let key = w.node_key(tokens, &node_key);
+                tv.set(i, weights.get(&key).copied().unwrap_or(0.0));
+            }
+        } else {
+            for (i, w) in words.iter().enumerate() {
+                tv.set(i, self.decay.weight(w.first_position));
+            }
+        }
+        tv.normalize();
+        Some(tv)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SectionTeleportBuilder — boosts words in title / abstract sections
+// ---------------------------------------------------------------------------
+
+/// Assigns higher teleport probability to candidates occurring in boosted
+/// document sections.
+///
+/// Tokens carry an optional [`Section`] label (see
+/// [`TokenStream::with_sections`](super::artifacts::TokenStream::with_sections)).
+/// Each candidate's weight is the largest boost among the sections it
+/// occurs in; candidates occurring only in unboosted or unlabeled text keep
+/// the base weight of `1.0`. Occurrences match the candidate's graph key, so
+/// with `use_pos_in_nodes` the POS tag must match too.
+///
+/// Returns `None` for phrase-level candidates, empty candidate sets, or
+/// streams without section labels.
+#[derive(Debug, Clone)]
+pub struct SectionTeleportBuilder {
+    /// Weight multiplier per section.
+    boosts: BTreeMap<Section, f64>,
+}
+
+impl SectionTeleportBuilder {
+    /// Default boosts: title `3.0`, abstract `2.0`, heading `1.5`.
+    pub fn default_boosts() -> BTreeMap<Section, f64> {
+        BTreeMap::from([
+            (Section::Title, 3.0),
+            (Section::Abstract, 2.0),
+            (Section::Heading, 1.5),
+        ])
+    }
+
+    /// Create a builder with the given per-section multipliers; sections
+    /// not listed get `1.0`.
+    pub fn new(boosts: BTreeMap<Section, f64>) -> Self {
+        Self { boosts }
+    }
+
+    /// Multiplier for `section`.
+    pub fn boost(&self, section: Section) -> f64 {
+        self.boosts.get(&section).copied().unwrap_or(1.0)
+    }
+}
+
+impl Default for SectionTeleportBuilder {
+    fn default() -> Self {
+        Self::new(Self::default_boosts())
+    }
+}
+
+impl TeleportBuilder for SectionTeleportBuilder {
+    fn build(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+    ) -> Option<TeleportVector> {
+        let words = match candidates.kind() {
+            CandidateKind::Words(w) => w,
+            _ => return None,
+        };
+        if words.is_empty() {
+            return None;
+        }
+
+        let node_key = cfg.effective_node_key();
+        let mut best: HashMap<NodeIdentity, f64> = HashMap::new();
+        for (i, t) in tokens.tokens().iter().enumerate() {
+            let Some(section) = tokens.section_of(i) else {
+                continue;
+            };
+            if !t.is_graph_candidate() {
+                continue;
+            }
+            let weight = best
+                .entry(t.node_identity(tokens.pool(), &node_key))
+                .or_insert(1.0);
+            *weight = weight.max(self.boost(section));
+        }
+        if best.is_empty() {
+            return None;
+        }
+
+        let mut tv = TeleportVector::zeros(words.len(), TeleportType::Section);
         for (i, w) in words.iter().enumerate() {
-            tv.set(i, 1.0 / (w.first_position as f64 + 1.0));
+            let weight = best.get(&w.node_identity(tokens, &node_key)).copied();
+            tv.set(i, weight.unwrap_or(1.0));
         }
         tv.normalize();
         Some(tv)
@@ -1374,6 +2757,134 @@ impl TeleportBuilder for FocusTermsTeleportBuilder {
     }
 }
 
+// ---------------------------------------------------------------------------
+// QueryTeleportBuilder — query-similarity biased teleport (BiasedTextRank)
+// ---------------------------------------------------------------------------
+
+/// Similarity between a graph node's lemma and a query/focus text.
+///
+/// Implementations return a value in `[0.0, 1.0]`; `query_terms` are the
+/// lowercased, stopword-filtered tokens of the query.  Any
+/// `Fn(&str, &[String]) -> f64 + Send + Sync` closure implements this trait.
+pub trait QuerySimilarity: Send + Sync {
+    /// Similarity of `lemma` to the query.
+    fn similarity(&self, lemma: &str, query_terms: &[String]) -> f64;
+}
+
+impl<F> QuerySimilarity for F
+where
+    F: Fn(&str, &[String]) -> f64 + Send + Sync,
+{
+    fn similarity(&self, lemma: &str, query_terms: &[String]) -> f64 {
+        self(lemma, query_terms)
+    }
+}
+
+/// Default [`QuerySimilarity`]: fraction of the node's words that occur in
+/// the query.
+///
+/// Single-word nodes score `1.0` on an exact match and `0.0` otherwise;
+/// multi-word lemmas (whitespace-separated) score proportionally.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenOverlapSimilarity;
+
+impl QuerySimilarity for TokenOverlapSimilarity {
+    fn similarity(&self, lemma: &str, query_terms: &[String]) -> f64 {
+        let mut total = 0usize;
+        let mut hits = 0usize;
+        for word in lemma.split_whitespace() {
+            total += 1;
+            if query_terms.iter().any(|q| q.eq_ignore_ascii_case(word)) {
+                hits += 1;
+            }
+        }
+        if total == 0 {
+            0.0
+        } else {
+            hits as f64 / total as f64
+        }
+    }
+}
+
+/// Assigns teleport probability from the similarity between each candidate
+/// and a query text.
+///
+/// A candidate with similarity `s` gets weight `1.0 + (bias_weight - 1.0) * s`,
+/// so unrelated candidates keep the base weight of `1.0` and perfect matches
+/// receive `bias_weight` — the same scale as [`FocusTermsTeleportBuilder`].
+///
+/// The resulting vector is normalized so the values form a valid probability
+/// distribution.
+///
+/// Returns `None` for phrase-level candidates or empty candidate sets.
+#[derive(Clone)]
+pub struct QueryTeleportBuilder {
+    /// Query tokens (lowercased, stopwords removed).
+    query_terms: Vec<String>,
+    /// Node-to-query similarity function.
+    similarity: Arc<dyn QuerySimilarity>,
+    /// Weight for a perfect match relative to the base weight of `1.0`.
+    bias_weight: f64,
+}
+
+impl std::fmt::Debug for QueryTeleportBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueryTeleportBuilder")
+            .field("query_terms", &self.query_terms)
+            .field("bias_weight", &self.bias_weight)
+            .finish_non_exhaustive()
+    }
+}
+
+impl QueryTeleportBuilder {
+    /// Create a builder using [`TokenOverlapSimilarity`].
+    pub fn new(query_terms: Vec<String>, bias_weight: f64) -> Self {
+        Self {
+            query_terms,
+            similarity: Arc::new(TokenOverlapSimilarity),
+            bias_weight,
+        }
+    }
+
+    /// Replace the similarity function.
+    pub fn with_similarity(mut self, similarity: Arc<dyn QuerySimilarity>) -> Self {
+        self.similarity = similarity;
+        self
+    }
+}
+
+impl TeleportBuilder for QueryTeleportBuilder {
+    fn build(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        _cfg: &TextRankConfig,
+    ) -> Option<TeleportVector> {
+        let words = match candidates.kind() {
+            CandidateKind::Words(w) => w,
+            _ => return None,
+        };
+        if words.is_empty() {
+            return None;
+        }
+
+        let pool = tokens.pool();
+        let mut tv = TeleportVector::zeros(words.len(), TeleportType::Focus);
+
+        for (i, w) in words.iter().enumerate() {
+            let lemma = pool.get(w.lemma_id).unwrap_or("");
+            let sim = self
+                .similarity
+                .similarity(lemma, &self.query_terms)
+                .clamp(0.0, 1.0);
+            tv.set(i, 1.0 + (self.bias_weight - 1.0) * sim);
+        }
+
+        tv.normalize();
+        Some(tv)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // TopicWeightsTeleportBuilder — per-lemma topic weights (TopicalPageRank)
 // ---------------------------------------------------------------------------
@@ -1443,6 +2954,171 @@ impl TeleportBuilder for TopicWeightsTeleportBuilder {
     }
 }
 
+/// Assigns teleport probability proportional to each candidate's TF-IDF:
+/// its term frequency in the document times its smoothed IDF in a
+/// [`CorpusStats`].
+///
+/// This biases the walk toward words that are frequent in the document but
+/// rare in the corpus. The statistics are shared behind an `Arc` so one
+/// corpus can serve many pipelines.
+///
+/// Returns `None` for phrase-level candidates or empty candidate sets.
+#[derive(Debug, Clone)]
+pub struct TfIdfTeleportBuilder {
+    stats: Arc<CorpusStats>,
+}
+
+impl TfIdfTeleportBuilder {
+    /// Create a builder over the given corpus statistics.
+    pub fn new(stats: Arc<CorpusStats>) -> Self {
+        Self { stats }
+    }
+}
+
+impl TeleportBuilder for TfIdfTeleportBuilder {
+    fn build(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        _cfg: &TextRankConfig,
+    ) -> Option<TeleportVector> {
+        let words = match candidates.kind() {
+            CandidateKind::Words(w) => w,
+            _ => return None,
+        };
+        if words.is_empty() {
+            return None;
+        }
+
+        let mut tf: HashMap<u32, u32> = HashMap::new();
+        for entry in tokens.tokens() {
+            *tf.entry(entry.lemma_id).or_insert(0) += 1;
+        }
+
+        let pool = tokens.pool();
+        let mut tv = TeleportVector::zeros(words.len(), TeleportType::Topic);
+        for (i, w) in words.iter().enumerate() {
+            let lemma = pool.get(w.lemma_id).unwrap_or("");
+            let count = tf.get(&w.lemma_id).copied().unwrap_or(0);
+            tv.set(i, count as f64 * self.stats.idf(lemma));
+        }
+
+        tv.normalize();
+        Some(tv)
+    }
+}
+
+/// Node weight function of a [`FnTeleportBuilder`].
+type TeleportFn = dyn Fn(&Graph) -> Vec<f64> + Send + Sync;
+
+/// Teleport builder backed by a caller-supplied function of the graph.
+///
+/// The function returns one non-negative weight per graph node (see
+/// [`Graph::lemma`] for a node's key); the builder normalizes them. This
+/// covers one-off biasing — by recency, document section, a user's history —
+/// without writing a [`TeleportBuilder`] type:
+///
+/// ```
+/// use rapid_textrank::pipeline::{FnTeleportBuilder, PipelineBuilder};
+///
+/// let pipeline = PipelineBuilder::new()
+///     .teleport_builder(FnTeleportBuilder::from_node_weights([("graph", 5.0)], 1.0))
+///     .build();
+/// # let _ = pipeline;
+/// ```
+///
+/// Negative weights count as zero, and all-zero weights normalize to a
+/// uniform vector. Returns `None` (uniform teleport) for an empty graph and
+/// from [`build`](TeleportBuilder::build), which has no graph.
+///
+/// # Panics
+///
+/// Panics if the function returns a vector whose length differs from the
+/// number of graph nodes.
+#[derive(Clone)]
+pub struct FnTeleportBuilder {
+    weights: Arc<TeleportFn>,
+}
+
+impl std::fmt::Debug for FnTeleportBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FnTeleportBuilder").finish_non_exhaustive()
+    }
+}
+
+impl FnTeleportBuilder {
+    /// Weight nodes with `weights`, called once per document.
+    pub fn new(weights: impl Fn(&Graph) -> Vec<f64> + Send + Sync + 'static) -> Self {
+        Self {
+            weights: Arc::new(weights),
+        }
+    }
+
+    /// Weight nodes from a map keyed by lemma or by full node key
+    /// (`lemma|POS` when `use_pos_in_nodes` is set, see [`Graph::lemma`]);
+    /// nodes matching neither get `default_weight`.
+    pub fn from_node_weights<I, S>(weights: I, default_weight: f64) -> Self
+    where
+        I: IntoIterator<Item = (S, f64)>,
+        S: Into<String>,
+    {
+        let weights: HashMap<String, f64> =
+            weights.into_iter().map(|(k, w)| (k.into(), w)).collect();
+        Self::new(move |graph: &Graph| {
+            (0..graph.num_nodes() as u32)
+                .map(|node| {
+                    let key = graph.lemma(node);
+                    weights
+                        .get(key)
+                        .or_else(|| {
+                            let (lemma, _) = key.split_once('|')?;
+                            weights.get(lemma)
+                        })
+                        .copied()
+                        .unwrap_or(default_weight)
+                })
+                .collect()
+        })
+    }
+}
+
+impl TeleportBuilder for FnTeleportBuilder {
+    fn build(
+        &self,
+        _tokens: TokenStreamRef<'_>,
+        _candidates: CandidateSetRef<'_>,
+        _cfg: &TextRankConfig,
+    ) -> Option<TeleportVector> {
+        None
+    }
+
+    fn build_for_graph(
+        &self,
+        _tokens: TokenStreamRef<'_>,
+        _candidates: CandidateSetRef<'_>,
+        graph: &Graph,
+        _cfg: &TextRankConfig,
+    ) -> Option<TeleportVector> {
+        if graph.is_empty() {
+            return None;
+        }
+        let weights = (self.weights)(graph);
+        assert_eq!(
+            weights.len(),
+            graph.num_nodes(),
+            "FnTeleportBuilder: weight function returned {} weights for {} graph nodes",
+            weights.len(),
+            graph.num_nodes()
+        );
+        let mut tv = TeleportVector::zeros(weights.len(), TeleportType::Custom);
+        for (i, weight) in weights.into_iter().enumerate() {
+            tv.set(i, weight.max(0.0));
+        }
+        tv.normalize();
+        Some(tv)
+    }
+}
+
 // ============================================================================
 // Ranker — PageRank / Personalized PageRank execution (stage 3)
 // ============================================================================
@@ -1495,19 +3171,75 @@ pub trait Ranker {
         let _ = ws;
         self.rank(graph, teleport, cfg)
     }
+
+    /// Rank nodes, calling `progress(iteration, residual)` every `every`
+    /// iterations; [`ControlFlow::Break`] stops iterating early.
+    ///
+    /// The default implementation delegates to [`rank`](Self::rank) and
+    /// reports only the final iteration. [`PageRankRanker`] reports from
+    /// inside the power iteration on its default (serial `f64`,
+    /// unaccelerated) path.
+    fn rank_with_progress(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+        every: u32,
+        progress: &mut dyn FnMut(u32, f64) -> ControlFlow<()>,
+    ) -> RankOutput {
+        let _ = every;
+        let output = self.rank(graph, teleport, cfg);
+        let _ = progress(output.iterations(), output.final_delta());
+        output
+    }
+
+    /// Rank nodes, warm-starting from `initial` (scores indexed by node ID,
+    /// e.g. from a previous run on a similar graph).
+    ///
+    /// The default implementation ignores `initial` and delegates to
+    /// [`rank`](Self::rank). [`PageRankRanker`] starts power iteration
+    /// from it.
+    fn rank_warm(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+        initial: &[f64],
+    ) -> RankOutput {
+        let _ = initial;
+        self.rank(graph, teleport, cfg)
+    }
+
+    /// Short description of this stage, used by
+    /// [`Pipeline::explain`](crate::pipeline::Pipeline::explain).
+    ///
+    /// Defaults to the type name. Stages with parameters override it to
+    /// include them.
+    fn describe(&self) -> String {
+        short_type_name::<Self>()
+    }
 }
 
-/// PageRank-based ranker — the default (and currently only) [`Ranker`]
-/// implementation.
+/// PageRank-based ranker — the default [`Ranker`] implementation.
 ///
 /// Handles both standard and personalized PageRank through a single struct.
 /// When `teleport` is `None`, runs standard PageRank (uniform teleportation).
 /// When `teleport` is `Some(tv)`, runs Personalized PageRank using `tv` as
 /// the teleport distribution.
 ///
-/// Config parameters (damping, max_iterations, convergence_threshold) are read
-/// from [`TextRankConfig`] at call time, making this struct stateless and
-/// zero-sized — ideal for static pipeline composition.
+/// Config parameters (damping, max_iterations, convergence_threshold,
+/// dangling_policy) are read from [`TextRankConfig`] at call time, making
+/// this struct stateless and zero-sized — ideal for static pipeline
+/// composition.
+///
+/// With the `parallel` feature, graphs with at least
+/// [`TextRankConfig::parallel_threshold`] nodes are ranked with
+/// [`ParallelPageRank`](crate::pagerank::parallel::ParallelPageRank), which
+/// is bit-identical to the serial rankers, so this holds in deterministic
+/// mode too. With [`RankPrecision::F32`] the serial
+/// [`SinglePrecisionPageRank`](crate::pagerank::single_precision::SinglePrecisionPageRank)
+/// is used instead, and with a [`RankerAcceleration`] other than `None`
+/// [`AcceleratedPageRank`](crate::pagerank::accelerated::AcceleratedPageRank).
 ///
 /// # Examples
 ///
@@ -1521,38 +3253,77 @@ pub trait Ranker {
 #[derive(Debug, Clone, Copy, Default)]
 pub struct PageRankRanker;
 
-impl Ranker for PageRankRanker {
-    fn rank(
-        &self,
+impl PageRankRanker {
+    fn rank_from(
         graph: &Graph,
         teleport: Option<&TeleportVector>,
         cfg: &TextRankConfig,
+        initial: Option<&[f64]>,
     ) -> RankOutput {
         let csr = graph.csr();
 
+        if cfg.acceleration != RankerAcceleration::None {
+            return RankOutput::from_pagerank_result(&accelerated_pagerank(
+                csr, teleport, cfg, initial,
+            ));
+        }
+        if cfg.rank_precision == RankPrecision::F32 {
+            return RankOutput::from_pagerank_result(&single_precision_pagerank(
+                csr, teleport, cfg, initial,
+            ));
+        }
+
+        #[cfg(feature = "parallel")]
+        if let Some(result) = parallel_pagerank(csr, teleport, cfg, initial) {
+            return RankOutput::from_pagerank_result(&result);
+        }
+
         let result = match teleport {
             None => {
                 // Standard PageRank — uniform teleportation.
-                crate::pagerank::standard::StandardPageRank {
-                    damping: cfg.damping,
-                    max_iterations: cfg.max_iterations,
-                    threshold: cfg.convergence_threshold,
+                let mut pr = crate::pagerank::standard::StandardPageRank::from_config(cfg);
+                if let Some(scores) = initial {
+                    pr = pr.with_initial_scores(scores.to_vec());
                 }
-                .run(csr)
+                pr.run(csr)
             }
             Some(tv) => {
                 // Personalized PageRank — use the provided teleport vector.
-                crate::pagerank::personalized::PersonalizedPageRank::new()
-                    .with_damping(cfg.damping)
-                    .with_max_iterations(cfg.max_iterations)
-                    .with_threshold(cfg.convergence_threshold)
-                    .with_personalization(tv.as_slice().to_vec())
-                    .run(csr)
+                let mut pr = crate::pagerank::personalized::PersonalizedPageRank::from_config(cfg)
+                    .with_personalization(tv.as_slice().to_vec());
+                if let Some(scores) = initial {
+                    pr = pr.with_initial_scores(scores.to_vec());
+                }
+                pr.run(csr)
             }
         };
 
         RankOutput::from_pagerank_result(&result)
     }
+}
+
+impl Ranker for PageRankRanker {
+    fn rank(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+    ) -> RankOutput {
+        Self::rank_from(graph, teleport, cfg, None)
+    }
+
+    /// Nodes past the end of `initial` start at `1/n`. The result agrees
+    /// with a cold [`rank`](Ranker::rank) to within the convergence
+    /// threshold, usually in fewer iterations.
+    fn rank_warm(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+        initial: &[f64],
+    ) -> RankOutput {
+        Self::rank_from(graph, teleport, cfg, Some(initial))
+    }
 
     fn rank_reusing(
         &self,
@@ -1563,23 +3334,211 @@ impl Ranker for PageRankRanker {
     ) -> RankOutput {
         let csr = graph.csr();
 
+        if cfg.acceleration != RankerAcceleration::None {
+            return RankOutput::from_pagerank_result(&accelerated_pagerank(
+                csr, teleport, cfg, None,
+            ));
+        }
+        if cfg.rank_precision == RankPrecision::F32 {
+            return RankOutput::from_pagerank_result(&single_precision_pagerank(
+                csr, teleport, cfg, None,
+            ));
+        }
+
+        #[cfg(feature = "parallel")]
+        if let Some(result) = parallel_pagerank(csr, teleport, cfg, None) {
+            return RankOutput::from_pagerank_result(&result);
+        }
+
         let result = match teleport {
-            None => crate::pagerank::standard::StandardPageRank {
-                damping: cfg.damping,
-                max_iterations: cfg.max_iterations,
-                threshold: cfg.convergence_threshold,
-            }
-            .run_reusing(csr, &mut ws.score_buf, &mut ws.norm_buf),
-            Some(tv) => crate::pagerank::personalized::PersonalizedPageRank::new()
-                .with_damping(cfg.damping)
-                .with_max_iterations(cfg.max_iterations)
-                .with_threshold(cfg.convergence_threshold)
+            None => crate::pagerank::standard::StandardPageRank::from_config(cfg).run_reusing(
+                csr,
+                &mut ws.score_buf,
+                &mut ws.norm_buf,
+            ),
+            Some(tv) => crate::pagerank::personalized::PersonalizedPageRank::from_config(cfg)
                 .with_personalization(tv.as_slice().to_vec())
                 .run_reusing(csr, &mut ws.score_buf, &mut ws.norm_buf),
         };
 
         RankOutput::from_pagerank_result(&result)
     }
+
+    fn rank_with_progress(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+        every: u32,
+        progress: &mut dyn FnMut(u32, f64) -> ControlFlow<()>,
+    ) -> RankOutput {
+        let csr = graph.csr();
+        let serial = cfg.acceleration == RankerAcceleration::None
+            && cfg.rank_precision == RankPrecision::F64;
+        #[cfg(feature = "parallel")]
+        let serial = serial && !use_parallel_pagerank(csr, cfg);
+        if !serial {
+            let output = self.rank(graph, teleport, cfg);
+            let _ = progress(output.iterations(), output.final_delta());
+            return output;
+        }
+
+        let result = match teleport {
+            None => crate::pagerank::standard::StandardPageRank::from_config(cfg)
+                .run_with_progress(csr, every, progress),
+            Some(tv) => crate::pagerank::personalized::PersonalizedPageRank::from_config(cfg)
+                .with_personalization(tv.as_slice().to_vec())
+                .run_with_progress(csr, every, progress),
+        };
+
+        RankOutput::from_pagerank_result(&result)
+    }
+}
+
+/// HITS-based ranker — hub or authority scores instead of PageRank.
+///
+/// Runs [`Hits`](crate::pagerank::hits::Hits) with `max_iterations` and
+/// `convergence_threshold` from [`TextRankConfig`] and reports the selected
+/// [`HitsScore`] for every node. HITS has no teleportation, so the teleport
+/// vector and `damping` are ignored, and nodes without edges score 0.
+///
+/// On the default symmetric co-occurrence graphs hub and authority scores
+/// coincide; the choice matters for directed graphs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HitsRanker {
+    /// Which score is exposed to the phrase builder.
+    pub score: HitsScore,
+}
+
+impl HitsRanker {
+    /// Create a ranker reporting the given score.
+    pub fn new(score: HitsScore) -> Self {
+        Self { score }
+    }
+
+    /// Create a ranker reporting authority scores.
+    pub fn authorities() -> Self {
+        Self::new(HitsScore::Authority)
+    }
+
+    /// Create a ranker reporting hub scores.
+    pub fn hubs() -> Self {
+        Self::new(HitsScore::Hub)
+    }
+}
+
+impl Ranker for HitsRanker {
+    fn describe(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn rank(
+        &self,
+        graph: &Graph,
+        _teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+    ) -> RankOutput {
+        let result = crate::pagerank::hits::Hits::new()
+            .with_max_iterations(cfg.max_iterations)
+            .with_threshold(cfg.convergence_threshold)
+            .run(graph.csr());
+        RankOutput::new(
+            result.scores(self.score).to_vec(),
+            result.converged,
+            result.iterations as u32,
+            result.delta,
+        )
+    }
+}
+
+/// Degree-centrality ranker — weighted degree instead of PageRank.
+///
+/// Scores each node by the total weight of its edges, normalized to sum to
+/// 1 (so on binary graphs, by its number of neighbors). There is no
+/// iteration: the output is always converged after 0 iterations, and the
+/// teleport vector and all PageRank parameters are ignored. A cheap
+/// baseline for comparing against PageRank.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DegreeRanker;
+
+impl Ranker for DegreeRanker {
+    fn rank(
+        &self,
+        graph: &Graph,
+        _teleport: Option<&TeleportVector>,
+        _cfg: &TextRankConfig,
+    ) -> RankOutput {
+        let mut scores = graph.csr().total_weight.clone();
+        let total: f64 = scores.iter().sum();
+        if total > 0.0 {
+            for score in &mut scores {
+                *score /= total;
+            }
+        }
+        RankOutput::new(scores, true, 0, 0.0)
+    }
+}
+
+/// Run extrapolation-accelerated PageRank with the config's parameters.
+fn accelerated_pagerank(
+    csr: &crate::graph::csr::CsrGraph,
+    teleport: Option<&TeleportVector>,
+    cfg: &TextRankConfig,
+    initial: Option<&[f64]>,
+) -> crate::pagerank::PageRankResult {
+    let mut pr = crate::pagerank::accelerated::AcceleratedPageRank::from_config(cfg);
+    if let Some(tv) = teleport {
+        pr = pr.with_personalization(tv.as_slice().to_vec());
+    }
+    if let Some(scores) = initial {
+        pr = pr.with_initial_scores(scores.to_vec());
+    }
+    pr.run(csr)
+}
+
+/// Run single-precision PageRank with the config's parameters.
+fn single_precision_pagerank(
+    csr: &crate::graph::csr::CsrGraph,
+    teleport: Option<&TeleportVector>,
+    cfg: &TextRankConfig,
+    initial: Option<&[f64]>,
+) -> crate::pagerank::PageRankResult {
+    let mut pr = crate::pagerank::single_precision::SinglePrecisionPageRank::from_config(cfg);
+    if let Some(tv) = teleport {
+        pr = pr.with_personalization(tv.as_slice().to_vec());
+    }
+    if let Some(scores) = initial {
+        pr = pr.with_initial_scores(scores.to_vec());
+    }
+    pr.run(csr)
+}
+
+/// Whether the `parallel` ranker should handle this graph.
+#[cfg(feature = "parallel")]
+fn use_parallel_pagerank(csr: &crate::graph::csr::CsrGraph, cfg: &TextRankConfig) -> bool {
+    csr.num_nodes >= cfg.parallel_threshold
+}
+
+/// Run parallel PageRank when the graph is large enough; `None` means the
+/// serial path should be used.
+#[cfg(feature = "parallel")]
+fn parallel_pagerank(
+    csr: &crate::graph::csr::CsrGraph,
+    teleport: Option<&TeleportVector>,
+    cfg: &TextRankConfig,
+    initial: Option<&[f64]>,
+) -> Option<crate::pagerank::PageRankResult> {
+    if !use_parallel_pagerank(csr, cfg) {
+        return None;
+    }
+    let mut pr = crate::pagerank::parallel::ParallelPageRank::from_config(cfg);
+    if let Some(tv) = teleport {
+        pr = pr.with_personalization(tv.as_slice().to_vec());
+    }
+    if let Some(scores) = initial {
+        pr = pr.with_initial_scores(scores.to_vec());
+    }
+    Some(pr.run(csr))
 }
 
 // ============================================================================
@@ -1616,6 +3575,15 @@ pub trait ResultFormatter {
         debug: Option<DebugPayload>,
         cfg: &TextRankConfig,
     ) -> FormattedResult;
+
+    /// Short description of this stage, used by
+    /// [`Pipeline::explain`](crate::pipeline::Pipeline::explain).
+    ///
+    /// Defaults to the type name. Stages with parameters override it to
+    /// include them.
+    fn describe(&self) -> String {
+        short_type_name::<Self>()
+    }
 }
 
 /// Standard result formatter — the default for all pipeline configurations.
@@ -1627,6 +3595,8 @@ pub trait ResultFormatter {
 ///
 /// - Phrases are ordered by score descending (as produced by PhraseBuilder).
 /// - `converged` and `iterations` come from PageRank output.
+/// - Phrases matching `cfg.phrase_blocklist` are dropped.
+/// - Scores are rescaled per `cfg.score_normalization` (raw by default).
 /// - Debug information is attached only when provided.
 ///
 /// This is zero-sized because all configuration is read from [`TextRankConfig`]
@@ -1661,6 +3631,17 @@ impl ResultFormatter for StandardResultFormatter {
                             .collect()
                     })
                     .unwrap_or_default();
+                let mut spans: Vec<(usize, usize)> = entry
+                    .text_spans
+                    .as_ref()
+                    .map(|spans| {
+                        spans
+                            .iter()
+                            .map(|&(s, e)| (s as usize, e as usize))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                cfg.span_mode.apply(&mut spans);
 
                 Phrase {
                     text,
@@ -1668,27 +3649,27 @@ impl ResultFormatter for StandardResultFormatter {
                     score: entry.score,
                     count: entry.count as usize,
                     offsets,
+                    spans,
                     rank: 0, // assigned after sorting
                 }
             })
             .collect();
 
-        // --- 2. Sort (authoritative — the formatter is the canonical sort) ---
-        if cfg.determinism.is_deterministic() {
-            formatted_phrases.sort_by(|a, b| a.stable_cmp(b));
-        } else {
-            formatted_phrases.sort_by(|a, b| {
-                b.score
-                    .partial_cmp(&a.score)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
-        }
+        // --- 2. Drop phrases matching `cfg.phrase_blocklist` ---
+        crate::phrase::blocklist::PhraseFilter::for_config(cfg)
+            .retain_phrases(&mut formatted_phrases);
 
-        // --- 3. Assign 1-indexed ranks after sorting ---
+        // --- 3. Sort (authoritative — the formatter is the canonical sort) ---
+        formatted_phrases.sort_by(|a, b| a.stable_cmp(b));
+
+        // --- 4. Assign 1-indexed ranks after sorting ---
         for (i, phrase) in formatted_phrases.iter_mut().enumerate() {
             phrase.rank = i + 1;
         }
 
+        // --- 5. Normalize scores (order-preserving) ---
+        cfg.score_normalization.apply(&mut formatted_phrases);
+
         let result = FormattedResult::new(formatted_phrases, ranks.converged(), ranks.iterations());
 
         match debug {
@@ -1728,6 +3709,10 @@ impl SentenceFormatter {
 
 #[cfg(feature = "sentence-rank")]
 impl ResultFormatter for SentenceFormatter {
+    fn describe(&self) -> String {
+        format!("{self:?}")
+    }
+
     fn format(
         &self,
         phrases: &PhraseSet,
@@ -1754,6 +3739,17 @@ impl ResultFormatter for SentenceFormatter {
                             .collect()
                     })
                     .unwrap_or_default();
+                let mut spans: Vec<(usize, usize)> = entry
+                    .text_spans
+                    .as_ref()
+                    .map(|spans| {
+                        spans
+                            .iter()
+                            .map(|&(s, e)| (s as usize, e as usize))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                cfg.span_mode.apply(&mut spans);
 
                 Phrase {
                     text,
@@ -1761,6 +3757,7 @@ impl ResultFormatter for SentenceFormatter {
                     score: entry.score,
                     count: entry.count as usize,
                     offsets,
+                    spans,
                     rank: 0,
                 }
             })
@@ -1774,14 +3771,8 @@ impl ResultFormatter for SentenceFormatter {
                 let b_pos = b.offsets.first().map(|o| o.0).unwrap_or(usize::MAX);
                 a_pos.cmp(&b_pos)
             });
-        } else if cfg.determinism.is_deterministic() {
-            formatted_phrases.sort_by(|a, b| a.stable_cmp(b));
         } else {
-            formatted_phrases.sort_by(|a, b| {
-                b.score
-                    .partial_cmp(&a.score)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
+            formatted_phrases.sort_by(|a, b| a.stable_cmp(b));
         }
 
         // --- 3. Assign 1-indexed ranks after sorting ---
@@ -1789,6 +3780,9 @@ impl ResultFormatter for SentenceFormatter {
             phrase.rank = i + 1;
         }
 
+        // --- 4. Normalize scores (order-preserving) ---
+        cfg.score_normalization.apply(&mut formatted_phrases);
+
         let result = FormattedResult::new(formatted_phrases, ranks.converged(), ranks.iterations());
 
         match debug {
@@ -1826,8 +3820,8 @@ impl ResultFormatter for SentenceFormatter {
 /// - **Output**: a [`PhraseSet`] containing scored, deduplicated phrases.
 /// - **Deterministic**: same input → same output (no internal randomness).
 /// - **Config-driven**: reads `min_phrase_length`, `max_phrase_length`,
-///   `score_aggregation`, `phrase_grouping`, and `top_n` from
-///   [`TextRankConfig`].
+///   `score_aggregation`, `phrase_grouping`, `top_n` and
+///   `phrase_selection` from [`TextRankConfig`].
 pub trait PhraseBuilder {
     /// Build scored phrases from ranked graph data.
     fn build(
@@ -1838,6 +3832,15 @@ pub trait PhraseBuilder {
         graph: &Graph,
         cfg: &TextRankConfig,
     ) -> PhraseSet;
+
+    /// Short description of this stage, used by
+    /// [`Pipeline::explain`](crate::pipeline::Pipeline::explain).
+    ///
+    /// Defaults to the type name. Stages with parameters override it to
+    /// include them.
+    fn describe(&self) -> String {
+        short_type_name::<Self>()
+    }
 }
 
 /// Standard chunk-based phrase builder for the word-graph TextRank family.
@@ -1846,7 +3849,8 @@ pub trait PhraseBuilder {
 ///
 /// 1. **Noun chunking**: extract candidate phrases using the pattern
 ///    `(DET)? (ADJ)* (NOUN|PROPN)+`, respecting sentence boundaries and
-///    stopword-based chunk breaks.
+///    stopword-based chunk breaks.  When the candidate set carries its own
+///    phrase spans (see [`GrammarCandidateSelector`]), those are used instead.
 /// 2. **Chunk scoring**: aggregate PageRank scores of the constituent
 ///    tokens in each chunk using the configured [`ScoreAggregation`]
 ///    strategy (sum, mean, max, or RMS).
@@ -1855,7 +3859,8 @@ pub trait PhraseBuilder {
 /// 4. **Variant grouping**: group surface-form variants by lemma or
 ///    scrubbed text (controlled by [`PhraseGrouping`]), selecting a
 ///    canonical form and aggregating counts/offsets.
-/// 5. **Ranking**: sort by score descending and apply `top_n` limit.
+/// 5. **Ranking**: sort by score descending and apply the `top_n` limit
+///    (or the `phrase_selection` cutoff).
 ///
 /// This is the default phrase builder used by BaseTextRank, PositionRank,
 /// BiasedTextRank, SingleRank, and TopicalPageRank.  It is zero-sized
@@ -1878,25 +3883,83 @@ impl PhraseBuilder for ChunkPhraseBuilder {
     fn build(
         &self,
         tokens: TokenStreamRef<'_>,
-        _candidates: CandidateSetRef<'_>,
+        candidates: CandidateSetRef<'_>,
         ranks: &RankOutput,
         graph: &Graph,
         cfg: &TextRankConfig,
     ) -> PhraseSet {
-        use crate::phrase::extraction::PhraseExtractor;
-        use crate::types::StringPool;
+        let extractor = crate::phrase::extraction::PhraseExtractor::with_config(cfg.clone());
+        extract_chunk_phrases(&extractor, tokens, candidates, ranks, graph)
+    }
+}
+
+/// Run `extractor` on the pipeline artifacts through the legacy adapter
+/// bridge, scoring the selector's phrase spans when it chose them (e.g.
+/// grammar matches).
+fn extract_chunk_phrases(
+    extractor: &crate::phrase::extraction::PhraseExtractor,
+    tokens: TokenStreamRef<'_>,
+    candidates: CandidateSetRef<'_>,
+    ranks: &RankOutput,
+    graph: &Graph,
+) -> PhraseSet {
+    use crate::types::StringPool;
+
+    // Bridge: convert pipeline artifacts to legacy types.
+    let legacy_tokens = tokens.to_legacy_tokens();
+    let pagerank_result = ranks.to_pagerank_result();
+
+    let phrases = match candidates.phrase_spans() {
+        Some(spans) => {
+            extractor.extract_with_chunks(&legacy_tokens, spans, graph.csr(), &pagerank_result)
+        }
+        None => extractor.extract(&legacy_tokens, graph.csr(), &pagerank_result),
+    };
+
+    // Convert back to pipeline artifact.
+    let mut pool = StringPool::new();
+    PhraseSet::from_phrases(&phrases, &mut pool)
+}
+
+/// [`ChunkPhraseBuilder`] that drops paraphrases of higher-ranked phrases
+/// with an [`EmbeddingDedup`](crate::phrase::embedding_dedup::EmbeddingDedup)
+/// before the `top_n` cutoff, so the freed slots are backfilled.
+///
+/// Only available with the `embeddings` feature.
+#[cfg(feature = "embeddings")]
+#[derive(Debug, Clone)]
+pub struct EmbeddingDedupPhraseBuilder {
+    dedup: crate::phrase::embedding_dedup::EmbeddingDedup,
+}
 
-        // Bridge: convert pipeline artifacts to legacy types.
-        let legacy_tokens = tokens.to_legacy_tokens();
-        let pagerank_result = ranks.to_pagerank_result();
+#[cfg(feature = "embeddings")]
+impl EmbeddingDedupPhraseBuilder {
+    /// Deduplicate with `dedup`.
+    pub fn new(dedup: crate::phrase::embedding_dedup::EmbeddingDedup) -> Self {
+        Self { dedup }
+    }
+}
 
-        // Delegate to the existing PhraseExtractor.
-        let extractor = PhraseExtractor::with_config(cfg.clone());
-        let phrases = extractor.extract(&legacy_tokens, graph.csr(), &pagerank_result);
+#[cfg(feature = "embeddings")]
+impl PhraseBuilder for EmbeddingDedupPhraseBuilder {
+    fn build(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        ranks: &RankOutput,
+        graph: &Graph,
+        cfg: &TextRankConfig,
+    ) -> PhraseSet {
+        let extractor = crate::phrase::extraction::PhraseExtractor::with_config(cfg.clone())
+            .with_embedding_dedup(self.dedup.clone());
+        extract_chunk_phrases(&extractor, tokens, candidates, ranks, graph)
+    }
 
-        // Convert back to pipeline artifact.
-        let mut pool = StringPool::new();
-        PhraseSet::from_phrases(&phrases, &mut pool)
+    fn describe(&self) -> String {
+        format!(
+            "EmbeddingDedupPhraseBuilder {{ threshold: {} }}",
+            self.dedup.threshold()
+        )
     }
 }
 
@@ -1959,7 +4022,7 @@ impl<C: Clusterer> TopicGraphBuilder<C> {
 impl<C: Clusterer> GraphBuilder for TopicGraphBuilder<C> {
     fn build(
         &self,
-        _tokens: TokenStreamRef<'_>,
+        tokens: TokenStreamRef<'_>,
         candidates: CandidateSetRef<'_>,
         cfg: &TextRankConfig,
     ) -> Graph {
@@ -1967,7 +4030,7 @@ impl<C: Clusterer> GraphBuilder for TopicGraphBuilder<C> {
         use crate::types::ChunkSpan;
 
         // --- 1. Cluster phrase candidates ---
-        let assignments = self.clusterer.cluster(candidates, cfg);
+        let assignments = self.clusterer.cluster_with_tokens(tokens, candidates, cfg);
 
         let phrases = candidates.phrases();
         if phrases.is_empty() || assignments.num_clusters() == 0 {
@@ -2059,7 +4122,7 @@ impl<C: Clusterer> CandidateGraphBuilder<C> {
 impl<C: Clusterer> GraphBuilder for CandidateGraphBuilder<C> {
     fn build(
         &self,
-        _tokens: TokenStreamRef<'_>,
+        tokens: TokenStreamRef<'_>,
         candidates: CandidateSetRef<'_>,
         cfg: &TextRankConfig,
     ) -> Graph {
@@ -2072,7 +4135,7 @@ impl<C: Clusterer> GraphBuilder for CandidateGraphBuilder<C> {
         }
 
         // --- 1. Cluster phrase candidates ---
-        let assignments = self.clusterer.cluster(candidates, cfg);
+        let assignments = self.clusterer.cluster_with_tokens(tokens, candidates, cfg);
 
         let n = phrases.len();
 
@@ -2116,18 +4179,221 @@ impl<C: Clusterer> GraphBuilder for CandidateGraphBuilder<C> {
 // SentenceGraphBuilder — Jaccard-similarity sentence graph (stage 2, sentence)
 // ============================================================================
 
+/// Similarity function used to weight edges in a sentence graph.
+#[cfg(feature = "sentence-rank")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SentenceSimilarity {
+    /// Jaccard similarity of the sentences' lemma sets.
+    #[default]
+    Jaccard,
+    /// Word overlap normalized by sentence length, as in the original
+    /// TextRank paper: `|S_i ∩ S_j| / (ln |S_i| + ln |S_j|)`.
+    ///
+    /// Lengths count non-stopword lemmas (with repeats).  Pairs whose
+    /// denominator is zero (two one-word sentences) get no edge.
+    Overlap,
+    /// Cosine similarity of TF-IDF vectors (LexRank).
+    ///
+    /// Each sentence is treated as a document when computing IDF:
+    /// `idf(w) = ln(N / df(w))` over the `N` sentences of the input.
+    TfIdfCosine,
+    /// Okapi BM25 (Barrios et al., 2016), averaged over both directions.
+    ///
+    /// Each sentence is scored as a query against the other, with term
+    /// saturation `k1 = 1.2` and length normalization `b = 0.75` relative
+    /// to the mean sentence length, so long sentences are not favored the
+    /// way they are under [`Overlap`](Self::Overlap).  IDF is
+    /// `ln(1 + (N - df + 0.5) / (df + 0.5))` over the sentences of the
+    /// input, or over a corpus given to
+    /// [`SentenceGraphBuilder::with_corpus_stats`].  Scores are not bounded
+    /// by `1.0`.
+    Bm25,
+}
+
+/// BM25 term-frequency saturation.
+#[cfg(feature = "sentence-rank")]
+const BM25_K1: f64 = 1.2;
+
+/// BM25 sentence-length normalization.
+#[cfg(feature = "sentence-rank")]
+const BM25_B: f64 = 0.75;
+
+/// Non-negative BM25 IDF of a term in `df` of `n` documents.
+#[cfg(feature = "sentence-rank")]
+fn bm25_idf(n: f64, df: f64) -> f64 {
+    (1.0 + (n - df + 0.5) / (df + 0.5)).ln()
+}
+
+/// Per-sentence features pre-computed once per graph build.
+#[cfg(feature = "sentence-rank")]
+struct SentenceFeatures {
+    /// Distinct lemma IDs per sentence.
+    sets: Vec<FxHashSet<u32>>,
+    /// Sentence length in non-stopword lemmas (counting repeats).
+    lens: Vec<usize>,
+    /// L2-normalized TF-IDF vectors (empty unless [`SentenceSimilarity::TfIdfCosine`]).
+    tfidf: Vec<FxHashMap<u32, f64>>,
+    /// Term counts per sentence (empty unless [`SentenceSimilarity::Bm25`]).
+    term_counts: Vec<FxHashMap<u32, u32>>,
+    /// BM25 IDF per lemma ID (empty unless [`SentenceSimilarity::Bm25`]).
+    idf: FxHashMap<u32, f64>,
+    /// Mean sentence length, for BM25 length normalization.
+    avg_len: f64,
+}
+
+#[cfg(feature = "sentence-rank")]
+impl SentenceFeatures {
+    fn new(
+        sentences: &[crate::pipeline::artifacts::SentenceCandidate],
+        similarity: SentenceSimilarity,
+        pool: &crate::types::StringPool,
+        corpus: Option<&CorpusStats>,
+    ) -> Self {
+        let sets: Vec<FxHashSet<u32>> = sentences
+            .iter()
+            .map(|s| s.lemma_ids.iter().copied().collect())
+            .collect();
+        let lens: Vec<usize> = sentences.iter().map(|s| s.lemma_ids.len()).collect();
+
+        let tfidf = if similarity == SentenceSimilarity::TfIdfCosine {
+            let mut df: FxHashMap<u32, usize> = FxHashMap::default();
+            for set in &sets {
+                for &id in set {
+                    *df.entry(id).or_insert(0) += 1;
+                }
+            }
+            let n = sentences.len() as f64;
+            sentences
+                .iter()
+                .map(|s| {
+                    let mut vec: FxHashMap<u32, f64> = FxHashMap::default();
+                    for &id in &s.lemma_ids {
+                        *vec.entry(id).or_insert(0.0) += 1.0;
+                    }
+                    for (id, w) in vec.iter_mut() {
+                        *w *= (n / df[id] as f64).ln();
+                    }
+                    let norm = vec.values().map(|w| w * w).sum::<f64>().sqrt();
+                    if norm > 0.0 {
+                        for w in vec.values_mut() {
+                            *w /= norm;
+                        }
+                    }
+                    vec
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut term_counts = Vec::new();
+        let mut idf = FxHashMap::default();
+        let mut avg_len = 0.0;
+        if similarity == SentenceSimilarity::Bm25 {
+            term_counts = sentences
+                .iter()
+                .map(|s| {
+                    let mut counts: FxHashMap<u32, u32> = FxHashMap::default();
+                    for &id in &s.lemma_ids {
+                        *counts.entry(id).or_insert(0) += 1;
+                    }
+                    counts
+                })
+                .collect();
+            match corpus {
+                Some(stats) => {
+                    let n = stats.num_docs() as f64;
+                    for &id in sets.iter().flatten() {
+                        idf.entry(id).or_insert_with(|| {
+                            let lemma = pool.get(id).unwrap_or("");
+                            bm25_idf(n, stats.document_frequency(lemma) as f64)
+                        });
+                    }
+                }
+                None => {
+                    let mut df: FxHashMap<u32, usize> = FxHashMap::default();
+                    for &id in sets.iter().flatten() {
+                        *df.entry(id).or_insert(0) += 1;
+                    }
+                    let n = sentences.len() as f64;
+                    idf = df
+                        .into_iter()
+                        .map(|(id, df)| (id, bm25_idf(n, df as f64)))
+                        .collect();
+                }
+            }
+            avg_len = lens.iter().sum::<usize>() as f64 / sentences.len() as f64;
+        }
+
+        Self {
+            sets,
+            lens,
+            tfidf,
+            term_counts,
+            idf,
+            avg_len,
+        }
+    }
+
+    /// BM25 score of sentence `query` as a query against sentence `doc`.
+    fn bm25(&self, query: usize, doc: usize) -> f64 {
+        if self.avg_len <= 0.0 {
+            return 0.0;
+        }
+        let norm = BM25_K1 * (1.0 - BM25_B + BM25_B * self.lens[doc] as f64 / self.avg_len);
+        self.sets[query]
+            .iter()
+            .filter_map(|id| {
+                let tf = f64::from(*self.term_counts[doc].get(id)?);
+                Some(self.idf[id] * tf * (BM25_K1 + 1.0) / (tf + norm))
+            })
+            .sum()
+    }
+}
+
+#[cfg(feature = "sentence-rank")]
+impl SentenceSimilarity {
+    /// Compute the similarity between sentences `i` and `j`.
+    fn score(self, features: &SentenceFeatures, i: usize, j: usize) -> f64 {
+        match self {
+            Self::Jaccard => {
+                1.0 - crate::clustering::jaccard_distance_u32(&features.sets[i], &features.sets[j])
+            }
+            Self::Overlap => {
+                let (len_a, len_b) = (features.lens[i], features.lens[j]);
+                let denom = (len_a as f64).ln() + (len_b as f64).ln();
+                if len_a == 0 || len_b == 0 || denom <= 0.0 {
+                    return 0.0;
+                }
+                let overlap = features.sets[i].intersection(&features.sets[j]).count();
+                overlap as f64 / denom
+            }
+            Self::TfIdfCosine => {
+                let (a, b) = (&features.tfidf[i], &features.tfidf[j]);
+                let (small, large) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+                small
+                    .iter()
+                    .filter_map(|(id, w)| large.get(id).map(|v| w * v))
+                    .sum()
+            }
+            Self::Bm25 => 0.5 * (features.bm25(i, j) + features.bm25(j, i)),
+        }
+    }
+}
+
 /// Builds a graph where each sentence is a node and edges are weighted by the
-/// Jaccard similarity of the sentences' lemma sets.
+/// similarity of the sentences' lemmas (Jaccard by default, see
+/// [`SentenceSimilarity`]).
 ///
 /// Used for extractive summarization (SentenceRank).  The builder:
 ///
 /// 1. Extracts the `Sentences` variant from the candidate set (returns an empty
 ///    graph for other candidate kinds).
-/// 2. Converts each sentence's `lemma_ids` to an `FxHashSet<u32>` for
-///    set-based Jaccard computation.
+/// 2. Pre-computes per-sentence features (lemma sets, lengths, and TF-IDF
+///    vectors when needed).
 /// 3. Creates one node per sentence (`"s_0"`, `"s_1"`, ...).
-/// 4. For all pairs `(i, j)` where `i < j`, computes
-///    `similarity = 1.0 - jaccard_distance_u32(set_i, set_j)`.
+/// 4. For all pairs `(i, j)` where `i < j`, computes the configured
+///    [`SentenceSimilarity`] (Jaccard: `1.0 - jaccard_distance_u32(set_i, set_j)`).
 ///    If the similarity exceeds `min_similarity`, an undirected edge is added.
 /// 5. Wraps the result via `Graph::from_builder()`.
 ///
@@ -2135,10 +4401,21 @@ impl<C: Clusterer> GraphBuilder for CandidateGraphBuilder<C> {
 ///
 /// - `min_similarity` (default `0.0`): edges with similarity ≤ this threshold
 ///   are omitted.  At the default value any non-zero overlap produces an edge.
+/// - `similarity` (default [`SentenceSimilarity::Jaccard`]): edge weight function.
+/// - `binary_edges` (default `false`): when `true`, every surviving edge gets
+///   weight `1.0` (LexRank's thresholded graph) instead of the similarity
+///   value (continuous LexRank).
+/// - corpus statistics (default none, set with
+///   [`with_corpus_stats`](Self::with_corpus_stats)): corpus document
+///   frequencies for [`SentenceSimilarity::Bm25`] IDF, instead of counting
+///   the document's own sentences.
 #[cfg(feature = "sentence-rank")]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct SentenceGraphBuilder {
     pub min_similarity: f64,
+    pub similarity: SentenceSimilarity,
+    pub binary_edges: bool,
+    corpus_stats: Option<Arc<CorpusStats>>,
 }
 
 #[cfg(feature = "sentence-rank")]
@@ -2146,31 +4423,71 @@ impl Default for SentenceGraphBuilder {
     fn default() -> Self {
         Self {
             min_similarity: 0.0,
+            similarity: SentenceSimilarity::Jaccard,
+            binary_edges: false,
+            corpus_stats: None,
         }
     }
 }
 
 #[cfg(feature = "sentence-rank")]
 impl SentenceGraphBuilder {
-    /// Set the minimum Jaccard similarity for an edge to be created.
+    /// LexRank defaults: TF-IDF cosine similarity with continuous edge weights.
+    pub fn lexrank() -> Self {
+        Self::default().with_similarity(SentenceSimilarity::TfIdfCosine)
+    }
+
+    /// Set the minimum similarity for an edge to be created.
     /// Clamped to `[0.0, 1.0]`.
     pub fn with_min_similarity(mut self, threshold: f64) -> Self {
         self.min_similarity = threshold.clamp(0.0, 1.0);
         self
     }
+
+    /// Set the similarity function used for edge weights.
+    pub fn with_similarity(mut self, similarity: SentenceSimilarity) -> Self {
+        self.similarity = similarity;
+        self
+    }
+
+    /// Use unit weights for every edge above `min_similarity`.
+    pub fn with_binary_edges(mut self, binary: bool) -> Self {
+        self.binary_edges = binary;
+        self
+    }
+
+    /// Take [`SentenceSimilarity::Bm25`] IDF from corpus statistics.
+    pub fn with_corpus_stats(mut self, stats: Arc<CorpusStats>) -> Self {
+        self.corpus_stats = Some(stats);
+        self
+    }
+
+    /// The corpus statistics set with
+    /// [`with_corpus_stats`](Self::with_corpus_stats), if any.
+    pub fn corpus_stats(&self) -> Option<&CorpusStats> {
+        self.corpus_stats.as_deref()
+    }
 }
 
 #[cfg(feature = "sentence-rank")]
 impl GraphBuilder for SentenceGraphBuilder {
+    fn describe(&self) -> String {
+        // Corpus statistics can be large; only say whether they're set.
+        format!(
+            "SentenceGraphBuilder {{ min_similarity: {}, similarity: {:?}, binary_edges: {}, corpus_stats: {} }}",
+            self.min_similarity,
+            self.similarity,
+            self.binary_edges,
+            self.corpus_stats.is_some()
+        )
+    }
+
     fn build(
         &self,
-        _tokens: TokenStreamRef<'_>,
+        tokens: TokenStreamRef<'_>,
         candidates: CandidateSetRef<'_>,
         _cfg: &TextRankConfig,
     ) -> Graph {
-        use crate::clustering::jaccard_distance_u32;
-        use rustc_hash::FxHashSet;
-
         // Only operate on sentence candidates.
         let sentences = match candidates.kind() {
             CandidateKind::Sentences(s) => s,
@@ -2182,11 +4499,13 @@ impl GraphBuilder for SentenceGraphBuilder {
             return Graph::empty();
         }
 
-        // --- 1. Pre-compute lemma sets for Jaccard ---
-        let lemma_sets: Vec<FxHashSet<u32>> = sentences
-            .iter()
-            .map(|s| s.lemma_ids.iter().copied().collect())
-            .collect();
+        // --- 1. Pre-compute sentence features ---
+        let features = SentenceFeatures::new(
+            sentences,
+            self.similarity,
+            tokens.pool(),
+            self.corpus_stats.as_deref(),
+        );
 
         // --- 2. Create one node per sentence ---
         let mut builder = crate::graph::builder::GraphBuilder::with_capacity(n);
@@ -2194,12 +4513,13 @@ impl GraphBuilder for SentenceGraphBuilder {
             builder.get_or_create_node(&format!("s_{}", i));
         }
 
-        // --- 3. Pairwise Jaccard similarity edges ---
+        // --- 3. Pairwise similarity edges ---
         for i in 0..n {
             for j in (i + 1)..n {
-                let similarity = 1.0 - jaccard_distance_u32(&lemma_sets[i], &lemma_sets[j]);
+                let similarity = self.similarity.score(&features, i, j);
                 if similarity > self.min_similarity {
-                    builder.set_edge(i as u32, j as u32, similarity);
+                    let weight = if self.binary_edges { 1.0 } else { similarity };
+                    builder.set_edge(i as u32, j as u32, weight);
                 }
             }
         }
@@ -2219,12 +4539,15 @@ impl GraphBuilder for SentenceGraphBuilder {
 /// the [`ClusterAssignments`] embedded in the [`Graph`] artifact and:
 ///
 /// 1. For each cluster, picks the candidate with the lowest `start_token`
-///    (first occurrence in the document).
+///    (first occurrence in the document), or the one
+///    `cfg.surface_selection` picks, among those within
+///    `cfg.min_phrase_words..=cfg.max_phrase_words`.
 /// 2. Materializes the phrase text and lemma from the [`TokenStreamRef`].
 /// 3. Collects token-span offsets from **all** cluster members.
 /// 4. Sorts by score descending, with deterministic tie-breakers (position
 ///    ascending, lemma ascending).
-/// 5. Truncates to `cfg.top_n`.
+/// 5. Drops clusters with fewer than `cfg.min_phrase_frequency` members
+///    or matching `cfg.phrase_blocklist`, then truncates to `cfg.top_n`.
 ///
 /// # Panics
 ///
@@ -2246,6 +4569,16 @@ fn materialize_phrase_text(tokens: TokenStreamRef<'_>, phrase: &PhraseCandidate)
         .join(" ")
 }
 
+/// Byte spans in the source text of the given `[start, end)` token spans.
+fn phrase_text_spans(tokens: TokenStreamRef<'_>, spans: &[(u32, u32)]) -> Vec<(u32, u32)> {
+    let entries = tokens.tokens();
+    spans
+        .iter()
+        .filter(|&&(start, end)| start < end)
+        .map(|&(start, end)| (entries[start as usize].start, entries[end as usize - 1].end))
+        .collect()
+}
+
 /// Materialize the lemma text for a phrase candidate by joining the
 /// token lemmas with spaces.
 fn materialize_phrase_lemma(tokens: TokenStreamRef<'_>, phrase: &PhraseCandidate) -> String {
@@ -2308,17 +4641,24 @@ impl PhraseBuilder for TopicRepresentativeBuilder {
             // Get the cluster's PageRank score.
             let score = ranks.score(cluster_idx as u32);
 
-            // Select representative: first-occurring candidate (min start_token).
-            let &best_idx = cluster_members
-                .iter()
-                .min_by_key(|&&idx| phrases[idx].start_token)
-                .unwrap();
-
-            let representative = &phrases[best_idx];
-
-            // Materialize text and lemma from the token stream.
-            let surface = materialize_phrase_text(tokens, representative);
+            // Select representative: first-occurring candidate (min start_token),
+            // or the one `surface_selection` picks, within the phrase word
+            // bounds; clusters without one are dropped.
+            let (eligible, texts) = eligible_members(tokens, phrases, cluster_members, cfg);
+            if eligible.is_empty() {
+                continue;
+            }
+            let variants = surface_variants(phrases, &eligible, &texts);
+            let selection = cfg
+                .surface_selection
+                .unwrap_or(SurfaceSelection::FirstOccurrence);
+            let best = select_surface_index(&variants, selection);
+            let representative = &phrases[eligible[best]];
+
+            // Materialize the lemma from the token stream.
             let lemma_text = materialize_phrase_lemma(tokens, representative);
+            let surface =
+                choose_surface(&variants, &lemma_text, best, cfg.surface_selection).to_string();
 
             // Collect offsets from all cluster members, sorted by position.
             let mut spans: Vec<(u32, u32)> = cluster_members
@@ -2326,6 +4666,7 @@ impl PhraseBuilder for TopicRepresentativeBuilder {
                 .map(|&idx| (phrases[idx].start_token, phrases[idx].end_token))
                 .collect();
             spans.sort_by_key(|(start, _)| *start);
+            let text_spans = phrase_text_spans(tokens, &spans);
 
             // Intern lemma IDs for the representative.
             let lemma_ids = representative.lemma_ids.clone();
@@ -2337,6 +4678,7 @@ impl PhraseBuilder for TopicRepresentativeBuilder {
                 surface: Some(surface),
                 lemma_text: Some(lemma_text),
                 spans: Some(spans),
+                text_spans: Some(text_spans),
             });
         }
 
@@ -2367,15 +4709,104 @@ impl PhraseBuilder for TopicRepresentativeBuilder {
                 })
         });
 
-        // --- 4. Truncate to top_n ---
-        if cfg.top_n > 0 && entries.len() > cfg.top_n {
-            entries.truncate(cfg.top_n);
+        retain_selectable(&mut entries, cfg);
+        let limit = selection_limit(&entries, cfg);
+        let mut entries = diversify_entries(entries, limit, cfg);
+
+        // --- 4. Truncate to top_n / the `phrase_selection` cutoff ---
+        if let Some(limit) = limit {
+            entries.truncate(limit);
         }
 
         PhraseSet::from_entries(entries)
     }
 }
 
+/// The `members` within `cfg.min_phrase_words` /
+/// `cfg.max_phrase_words`, with their surface texts.
+fn eligible_members(
+    tokens: TokenStreamRef<'_>,
+    phrases: &[PhraseCandidate],
+    members: &[usize],
+    cfg: &TextRankConfig,
+) -> (Vec<usize>, Vec<String>) {
+    members
+        .iter()
+        .map(|&idx| (idx, materialize_phrase_text(tokens, &phrases[idx])))
+        .filter(|(_, text)| word_count_in_range(text, cfg).is_ok())
+        .unzip()
+}
+
+/// `(text, start_token)` of each member, for the surface-selection helpers.
+fn surface_variants<'a>(
+    phrases: &[PhraseCandidate],
+    members: &[usize],
+    texts: &'a [String],
+) -> Vec<(&'a str, usize)> {
+    members
+        .iter()
+        .zip(texts)
+        .map(|(&idx, text)| (text.as_str(), phrases[idx].start_token as usize))
+        .collect()
+}
+
+/// Drop entries seen fewer than `cfg.min_phrase_frequency` times or
+/// matching `cfg.phrase_blocklist` before selection, so the next-best
+/// entries fill the freed slots.
+fn retain_selectable(entries: &mut Vec<PhraseEntry>, cfg: &TextRankConfig) {
+    if let Some(min_frequency) = cfg.min_phrase_frequency {
+        entries.retain(|e| e.count as usize >= min_frequency);
+    }
+    let filter = crate::phrase::blocklist::PhraseFilter::for_config(cfg);
+    if !filter.is_empty() {
+        entries.retain(|e| {
+            !filter.is_blocked(
+                e.surface.as_deref().unwrap_or(""),
+                e.lemma_text.as_deref().unwrap_or(""),
+            )
+        });
+    }
+}
+
+/// Number of score-sorted entries `cfg.phrase_selection` keeps (`None`:
+/// all).
+fn selection_limit(entries: &[PhraseEntry], cfg: &TextRankConfig) -> Option<usize> {
+    let scores: Vec<f64> = entries.iter().map(|e| e.score).collect();
+    cfg.phrase_selection.limit(cfg.top_n, &scores)
+}
+
+/// Re-order score-sorted entries with MMR over their lemma text when
+/// `cfg.mmr_lambda` is set (see [`crate::phrase::mmr`]), picking `limit`
+/// of them first.
+fn diversify_entries(
+    entries: Vec<PhraseEntry>,
+    limit: Option<usize>,
+    cfg: &TextRankConfig,
+) -> Vec<PhraseEntry> {
+    use crate::phrase::mmr::mmr_order;
+    use crate::similarity::{LexicalSimilarity, Unit};
+
+    let Some(lambda) = cfg.mmr_lambda else {
+        return entries;
+    };
+    let candidates: Vec<(f64, Unit<'_>)> = entries
+        .iter()
+        .map(|e| {
+            let unit = Unit::new(
+                e.surface.as_deref().unwrap_or(""),
+                e.lemma_text.as_deref().unwrap_or(""),
+            );
+            (e.score, unit)
+        })
+        .collect();
+    let order = mmr_order(&candidates, lambda, limit.unwrap_or(0), &LexicalSimilarity);
+
+    let mut slots: Vec<Option<PhraseEntry>> = entries.into_iter().map(Some).collect();
+    let mut out: Vec<PhraseEntry> = order.iter().filter_map(|&i| slots[i].take()).collect();
+    out.extend(slots.into_iter().flatten());
+    out
+}
+
 // ============================================================================
 // MultipartitePhraseBuilder — highest-scoring per lemma group (stage 6, multipartite)
 // ============================================================================
@@ -2388,10 +4819,14 @@ impl PhraseBuilder for TopicRepresentativeBuilder {
 /// first-occurring candidate, this builder:
 ///
 /// 1. Groups candidates by **lemma text** (not cluster ID).
-/// 2. Picks the candidate with the **highest PageRank score** per group.
-/// 3. Collects offsets from all group members.
+/// 2. Picks the candidate with the **highest PageRank score** per group
+///    among those within `cfg.min_phrase_words..=cfg.max_phrase_words`.
+/// 3. Reports that candidate's surface form, or the one
+///    `cfg.surface_selection` picks, and collects offsets from all group
+///    members.
 /// 4. Sorts by score descending with deterministic tie-breakers.
-/// 5. Truncates to `cfg.top_n`.
+/// 5. Drops groups with fewer than `cfg.min_phrase_frequency` members or
+///    matching `cfg.phrase_blocklist`, then truncates to `cfg.top_n`.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct MultipartitePhraseBuilder;
 
@@ -2422,20 +4857,23 @@ impl PhraseBuilder for MultipartitePhraseBuilder {
         let mut entries: Vec<PhraseEntry> = Vec::with_capacity(groups.len());
 
         for (lemma_text, member_indices) in &groups {
-            // Pick candidate with highest score.
-            let &best_idx = member_indices
-                .iter()
-                .max_by(|&&a, &&b| {
-                    ranks
-                        .score(a as u32)
-                        .partial_cmp(&ranks.score(b as u32))
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                })
-                .unwrap();
+            // Pick candidate with highest score within the phrase word bounds.
+            let (eligible, texts) = eligible_members(tokens, phrases, member_indices, cfg);
+            let Some(best) = (0..eligible.len()).max_by(|&a, &b| {
+                ranks
+                    .score(eligible[a] as u32)
+                    .partial_cmp(&ranks.score(eligible[b] as u32))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }) else {
+                continue;
+            };
+            let best_idx = eligible[best];
 
             let representative = &phrases[best_idx];
             let score = ranks.score(best_idx as u32);
-            let surface = materialize_phrase_text(tokens, representative);
+            let variants = surface_variants(phrases, &eligible, &texts);
+            let surface =
+                choose_surface(&variants, lemma_text, best, cfg.surface_selection).to_string();
 
             // Collect offsets from all group members, sorted by position.
             let mut spans: Vec<(u32, u32)> = member_indices
@@ -2443,6 +4881,7 @@ impl PhraseBuilder for MultipartitePhraseBuilder {
                 .map(|&idx| (phrases[idx].start_token, phrases[idx].end_token))
                 .collect();
             spans.sort_by_key(|(start, _)| *start);
+            let text_spans = phrase_text_spans(tokens, &spans);
 
             let lemma_ids = representative.lemma_ids.clone();
 
@@ -2453,6 +4892,7 @@ impl PhraseBuilder for MultipartitePhraseBuilder {
                 surface: Some(surface),
                 lemma_text: Some(lemma_text.clone()),
                 spans: Some(spans),
+                text_spans: Some(text_spans),
             });
         }
 
@@ -2481,9 +4921,13 @@ impl PhraseBuilder for MultipartitePhraseBuilder {
                 })
         });
 
-        // --- 4. Truncate to top_n ---
-        if cfg.top_n > 0 && entries.len() > cfg.top_n {
-            entries.truncate(cfg.top_n);
+        retain_selectable(&mut entries, cfg);
+        let limit = selection_limit(&entries, cfg);
+        let mut entries = diversify_entries(entries, limit, cfg);
+
+        // --- 4. Truncate to top_n / the `phrase_selection` cutoff ---
+        if let Some(limit) = limit {
+            entries.truncate(limit);
         }
 
         PhraseSet::from_entries(entries)
@@ -2550,6 +4994,7 @@ impl PhraseBuilder for SentencePhraseBuilder {
                 surface: Some(text),
                 lemma_text: Some(String::new()),
                 spans: Some(vec![(sent.start_char, sent.end_char)]),
+                text_spans: Some(vec![(sent.start_char, sent.end_char)]),
             });
         }
 
@@ -2573,15 +5018,59 @@ impl PhraseBuilder for SentencePhraseBuilder {
                 })
         });
 
-        // Truncate to top_n.
-        if cfg.top_n > 0 && entries.len() > cfg.top_n {
-            entries.truncate(cfg.top_n);
+        // Truncate to top_n / the `phrase_selection` cutoff.
+        if let Some(limit) = selection_limit(&entries, cfg) {
+            entries.truncate(limit);
         }
 
         PhraseSet::from_entries(entries)
     }
 }
 
+// ============================================================================
+// KeywordPhraseBuilder — single-word keywords, no phrase assembly (stage 5)
+// ============================================================================
+
+/// Emits the top-ranked words as one-word phrases, skipping noun chunking.
+///
+/// Each word-graph node with occurrences in the token stream becomes one
+/// [`PhraseEntry`] (see [`rank_keywords`](crate::phrase::keywords::rank_keywords)),
+/// cut by `cfg.top_n` / `cfg.phrase_selection`. For tag clouds and query
+/// expansion, where phrases are unnecessary overhead.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeywordPhraseBuilder;
+
+impl PhraseBuilder for KeywordPhraseBuilder {
+    fn build(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        _candidates: CandidateSetRef<'_>,
+        ranks: &RankOutput,
+        graph: &Graph,
+        cfg: &TextRankConfig,
+    ) -> PhraseSet {
+        let keywords = crate::phrase::keywords::rank_keywords(tokens, graph, ranks, cfg);
+        let entries = keywords
+            .into_iter()
+            .map(|k| PhraseEntry {
+                lemma_ids: vec![tokens.tokens()[k.offsets[0].0].lemma_id],
+                score: k.score,
+                count: k.count as u32,
+                spans: Some(
+                    k.offsets
+                        .iter()
+                        .map(|&(s, e)| (s as u32, e as u32))
+                        .collect(),
+                ),
+                text_spans: Some(k.spans.iter().map(|&(s, e)| (s as u32, e as u32)).collect()),
+                surface: Some(k.text),
+                lemma_text: Some(k.lemma),
+            })
+            .collect();
+        PhraseSet::from_entries(entries)
+    }
+}
+
 // ============================================================================
 // Blanket impls for trait-object dispatch (Box<dyn Trait>)
 // ============================================================================
@@ -2594,12 +5083,24 @@ impl Preprocessor for Box<dyn Preprocessor> {
     fn preprocess(&self, tokens: &mut TokenStream, cfg: &TextRankConfig) {
         (**self).preprocess(tokens, cfg)
     }
+
+    fn tokenize(&self, text: &str, cfg: &TextRankConfig) -> TokenStream {
+        (**self).tokenize(text, cfg)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl CandidateSelector for Box<dyn CandidateSelector> {
     fn select(&self, tokens: TokenStreamRef<'_>, cfg: &TextRankConfig) -> CandidateSet {
         (**self).select(tokens, cfg)
     }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl GraphBuilder for Box<dyn GraphBuilder> {
@@ -2611,6 +5112,21 @@ impl GraphBuilder for Box<dyn GraphBuilder> {
     ) -> Graph {
         (**self).build(tokens, candidates, cfg)
     }
+
+    fn build_with_progress(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+        every: usize,
+        progress: &mut dyn FnMut(usize, usize) -> ControlFlow<()>,
+    ) -> Graph {
+        (**self).build_with_progress(tokens, candidates, cfg, every, progress)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl GraphTransform for Box<dyn GraphTransform> {
@@ -2623,12 +5139,24 @@ impl GraphTransform for Box<dyn GraphTransform> {
     ) {
         (**self).transform(graph, tokens, candidates, cfg)
     }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl Clusterer for Box<dyn Clusterer> {
     fn cluster(&self, candidates: CandidateSetRef<'_>, cfg: &TextRankConfig) -> ClusterAssignments {
         (**self).cluster(candidates, cfg)
     }
+    fn cluster_with_tokens(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+    ) -> ClusterAssignments {
+        (**self).cluster_with_tokens(tokens, candidates, cfg)
+    }
 }
 
 impl TeleportBuilder for Box<dyn TeleportBuilder> {
@@ -2637,19 +5165,58 @@ impl TeleportBuilder for Box<dyn TeleportBuilder> {
         tokens: TokenStreamRef<'_>,
         candidates: CandidateSetRef<'_>,
         cfg: &TextRankConfig,
-    ) -> Option<TeleportVector> {
-        (**self).build(tokens, candidates, cfg)
+    ) -> Option<TeleportVector> {
+        (**self).build(tokens, candidates, cfg)
+    }
+
+    fn build_for_graph(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        graph: &Graph,
+        cfg: &TextRankConfig,
+    ) -> Option<TeleportVector> {
+        (**self).build_for_graph(tokens, candidates, graph, cfg)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+}
+
+impl Ranker for Box<dyn Ranker> {
+    fn rank(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+    ) -> RankOutput {
+        (**self).rank(graph, teleport, cfg)
+    }
+
+    fn rank_with_progress(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+        every: u32,
+        progress: &mut dyn FnMut(u32, f64) -> ControlFlow<()>,
+    ) -> RankOutput {
+        (**self).rank_with_progress(graph, teleport, cfg, every, progress)
     }
-}
 
-impl Ranker for Box<dyn Ranker> {
-    fn rank(
+    fn rank_warm(
         &self,
         graph: &Graph,
         teleport: Option<&TeleportVector>,
         cfg: &TextRankConfig,
+        initial: &[f64],
     ) -> RankOutput {
-        (**self).rank(graph, teleport, cfg)
+        (**self).rank_warm(graph, teleport, cfg, initial)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
     }
 }
 
@@ -2664,6 +5231,10 @@ impl PhraseBuilder for Box<dyn PhraseBuilder> {
     ) -> PhraseSet {
         (**self).build(tokens, candidates, ranks, graph, cfg)
     }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl ResultFormatter for Box<dyn ResultFormatter> {
@@ -2676,6 +5247,10 @@ impl ResultFormatter for Box<dyn ResultFormatter> {
     ) -> FormattedResult {
         (**self).format(phrases, ranks, debug, cfg)
     }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 // ── Send + Sync boxed-trait impls ──────────────────────────────────────────
@@ -2689,12 +5264,24 @@ impl Preprocessor for Box<dyn Preprocessor + Send + Sync> {
     fn preprocess(&self, tokens: &mut TokenStream, cfg: &TextRankConfig) {
         (**self).preprocess(tokens, cfg)
     }
+
+    fn tokenize(&self, text: &str, cfg: &TextRankConfig) -> TokenStream {
+        (**self).tokenize(text, cfg)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl CandidateSelector for Box<dyn CandidateSelector + Send + Sync> {
     fn select(&self, tokens: TokenStreamRef<'_>, cfg: &TextRankConfig) -> CandidateSet {
         (**self).select(tokens, cfg)
     }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl GraphBuilder for Box<dyn GraphBuilder + Send + Sync> {
@@ -2706,6 +5293,21 @@ impl GraphBuilder for Box<dyn GraphBuilder + Send + Sync> {
     ) -> Graph {
         (**self).build(tokens, candidates, cfg)
     }
+
+    fn build_with_progress(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+        every: usize,
+        progress: &mut dyn FnMut(usize, usize) -> ControlFlow<()>,
+    ) -> Graph {
+        (**self).build_with_progress(tokens, candidates, cfg, every, progress)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl GraphTransform for Box<dyn GraphTransform + Send + Sync> {
@@ -2718,12 +5320,24 @@ impl GraphTransform for Box<dyn GraphTransform + Send + Sync> {
     ) {
         (**self).transform(graph, tokens, candidates, cfg)
     }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl Clusterer for Box<dyn Clusterer + Send + Sync> {
     fn cluster(&self, candidates: CandidateSetRef<'_>, cfg: &TextRankConfig) -> ClusterAssignments {
         (**self).cluster(candidates, cfg)
     }
+    fn cluster_with_tokens(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        cfg: &TextRankConfig,
+    ) -> ClusterAssignments {
+        (**self).cluster_with_tokens(tokens, candidates, cfg)
+    }
 }
 
 impl TeleportBuilder for Box<dyn TeleportBuilder + Send + Sync> {
@@ -2735,6 +5349,20 @@ impl TeleportBuilder for Box<dyn TeleportBuilder + Send + Sync> {
     ) -> Option<TeleportVector> {
         (**self).build(tokens, candidates, cfg)
     }
+
+    fn build_for_graph(
+        &self,
+        tokens: TokenStreamRef<'_>,
+        candidates: CandidateSetRef<'_>,
+        graph: &Graph,
+        cfg: &TextRankConfig,
+    ) -> Option<TeleportVector> {
+        (**self).build_for_graph(tokens, candidates, graph, cfg)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl Ranker for Box<dyn Ranker + Send + Sync> {
@@ -2746,6 +5374,79 @@ impl Ranker for Box<dyn Ranker + Send + Sync> {
     ) -> RankOutput {
         (**self).rank(graph, teleport, cfg)
     }
+
+    fn rank_with_progress(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+        every: u32,
+        progress: &mut dyn FnMut(u32, f64) -> ControlFlow<()>,
+    ) -> RankOutput {
+        (**self).rank_with_progress(graph, teleport, cfg, every, progress)
+    }
+
+    fn rank_warm(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+        initial: &[f64],
+    ) -> RankOutput {
+        (**self).rank_warm(graph, teleport, cfg, initial)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+}
+
+/// A borrowed ranker, so a variant or caller can lend its ranker to a
+/// pipeline without cloning it.
+impl<R: Ranker + ?Sized> Ranker for &R {
+    fn rank(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+    ) -> RankOutput {
+        (**self).rank(graph, teleport, cfg)
+    }
+
+    fn rank_reusing(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+        ws: &mut crate::pipeline::artifacts::PipelineWorkspace,
+    ) -> RankOutput {
+        (**self).rank_reusing(graph, teleport, cfg, ws)
+    }
+
+    fn rank_with_progress(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+        every: u32,
+        progress: &mut dyn FnMut(u32, f64) -> ControlFlow<()>,
+    ) -> RankOutput {
+        (**self).rank_with_progress(graph, teleport, cfg, every, progress)
+    }
+
+    fn rank_warm(
+        &self,
+        graph: &Graph,
+        teleport: Option<&TeleportVector>,
+        cfg: &TextRankConfig,
+        initial: &[f64],
+    ) -> RankOutput {
+        (**self).rank_warm(graph, teleport, cfg, initial)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl PhraseBuilder for Box<dyn PhraseBuilder + Send + Sync> {
@@ -2759,6 +5460,10 @@ impl PhraseBuilder for Box<dyn PhraseBuilder + Send + Sync> {
     ) -> PhraseSet {
         (**self).build(tokens, candidates, ranks, graph, cfg)
     }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 impl ResultFormatter for Box<dyn ResultFormatter + Send + Sync> {
@@ -2771,6 +5476,10 @@ impl ResultFormatter for Box<dyn ResultFormatter + Send + Sync> {
     ) -> FormattedResult {
         (**self).format(phrases, ranks, debug, cfg)
     }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
 }
 
 #[cfg(test)]
@@ -2779,7 +5488,7 @@ mod tests {
     use crate::pipeline::artifacts::CandidateKind;
     use crate::pipeline::observer::NoopObserver;
     use crate::pipeline::runner::BaseTextRankPipeline;
-    use crate::types::{ChunkSpan, PosTag, Token};
+    use crate::types::{ChunkSpan, NodeKey, PosTag, Token};
 
     fn sample_tokens() -> Vec<Token> {
         vec![
@@ -2854,6 +5563,86 @@ mod tests {
         assert_eq!(stream.num_sentences(), 0);
     }
 
+    #[test]
+    fn test_lemma_preprocessor_lowercase() {
+        let tokens = sample_tokens();
+        let mut stream = TokenStream::from_tokens(&tokens);
+        let cfg =
+            TextRankConfig::default().with_lemma_strategy(crate::types::LemmaStrategy::Lowercase);
+
+        LemmaPreprocessor.preprocess(&mut stream, &cfg);
+
+        assert_eq!(stream.lemma(&stream.tokens()[0]), "machine");
+        // "is" had lemma "be"; lowercasing replaces it with the surface form.
+        assert_eq!(stream.lemma(&stream.tokens()[2]), "is");
+    }
+
+    #[test]
+    fn test_lemma_preprocessor_merges_unicode_forms() {
+        let tokens = vec![
+            Token::new("Café", "Caf\u{e9}", PosTag::Noun, 0, 5, 0, 0),
+            Token::new("cafe\u{301}", "cafe\u{301}", PosTag::Noun, 6, 12, 0, 1),
+        ];
+        let mut stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default().with_normalization(crate::types::TextNormalization {
+            unicode_form: crate::types::UnicodeForm::Nfc,
+            case_folding: crate::types::CaseFolding::Full,
+            strip_diacritics: false,
+        });
+
+        LemmaPreprocessor.preprocess(&mut stream, &cfg);
+
+        assert_eq!(stream.tokens()[0].lemma_id, stream.tokens()[1].lemma_id);
+        assert_eq!(stream.lemma(&stream.tokens()[0]), "caf\u{e9}");
+    }
+
+    #[test]
+    fn test_lemma_preprocessor_as_provided_is_noop() {
+        let tokens = sample_tokens();
+        let mut stream = TokenStream::from_tokens(&tokens);
+        let before: Vec<_> = stream.tokens().to_vec();
+
+        LemmaPreprocessor.preprocess(&mut stream, &TextRankConfig::default());
+
+        assert_eq!(stream.tokens(), &before[..]);
+    }
+
+    #[cfg(feature = "pos-tagger")]
+    #[test]
+    fn test_pos_tag_preprocessor_retags_stream() {
+        let mut gold = sample_tokens();
+        gold[2].pos = PosTag::Verb;
+        let tagger = Arc::new(PerceptronTagger::train(&gold, 5));
+
+        let mut tokens = sample_tokens();
+        for t in &mut tokens {
+            t.pos = PosTag::Other;
+        }
+        let mut stream = TokenStream::from_tokens(&tokens);
+        PosTagPreprocessor::new(tagger).preprocess(&mut stream, &TextRankConfig::default());
+
+        let tags: Vec<PosTag> = stream.tokens().iter().map(|e| e.pos).collect();
+        assert_eq!(tags, vec![PosTag::Noun, PosTag::Noun, PosTag::Verb]);
+    }
+
+    #[test]
+    fn test_pos_fallback_preprocessor() {
+        let tokens = vec![
+            Token::new("机器", "机器", PosTag::Other, 0, 6, 0, 0),
+            Token::new("的", "的", PosTag::Other, 6, 9, 0, 1),
+            Token::new("graph", "graph", PosTag::Verb, 10, 15, 0, 2),
+        ];
+        let mut stream = TokenStream::from_tokens(&tokens);
+
+        PosFallbackPreprocessor::default().preprocess(&mut stream, &TextRankConfig::default());
+
+        let entries = stream.tokens();
+        assert_eq!(entries[0].pos, PosTag::Noun);
+        assert!(!entries[0].is_stopword);
+        assert!(entries[1].is_stopword);
+        assert_eq!(entries[2].pos, PosTag::Verb);
+    }
+
     #[test]
     fn test_custom_preprocessor_marks_stopwords() {
         struct MarkVerbsAsStopwords;
@@ -2982,6 +5771,43 @@ mod tests {
         assert_eq!(cs.len(), 1);
     }
 
+    #[test]
+    fn test_node_key_strategies_shape_graph_nodes() {
+        let tokens = vec![
+            Token::new("Graphs", "graph", PosTag::Noun, 0, 6, 0, 0),
+            Token::new("rank", "rank", PosTag::Noun, 7, 11, 0, 1),
+            Token::new("graphs", "graph", PosTag::Noun, 12, 18, 0, 2),
+            Token::new("graph", "graph", PosTag::Noun, 19, 24, 0, 3),
+        ];
+        let stream = TokenStream::from_tokens(&tokens);
+        let labels = |node_key: NodeKey| {
+            let cfg = TextRankConfig::default().with_node_key(node_key);
+            let candidates = WordNodeSelector.select(stream.as_ref(), &cfg);
+            let graph = WindowGraphBuilder::base_textrank().build(
+                stream.as_ref(),
+                candidates.as_ref(),
+                &cfg,
+            );
+            assert_eq!(graph.num_nodes(), candidates.len());
+            (0..graph.num_nodes() as u32)
+                .map(|n| graph.lemma(n).to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(labels(NodeKey::Lemma), ["graph", "rank"]);
+        assert_eq!(labels(NodeKey::LemmaPos), ["graph|NOUN", "rank|NOUN"]);
+        assert_eq!(
+            labels(NodeKey::Surface),
+            ["Graphs", "rank", "graphs", "graph"]
+        );
+        assert_eq!(
+            labels(NodeKey::LowercaseSurface),
+            ["graphs", "rank", "graph"]
+        );
+        let first_letter = NodeKey::custom(|t| t.lemma[..1].into());
+        assert_eq!(labels(first_letter), ["g", "r"]);
+    }
+
     #[test]
     fn test_word_selector_records_first_position() {
         let tokens = vec![
@@ -3427,6 +6253,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_graph_builder_sentence_and_paragraph_scopes() {
+        // Paragraph 0: "alpha beta gamma delta" | "epsilon"; paragraph 1: "zeta".
+        let tokens = vec![
+            Token::new("alpha", "alpha", PosTag::Noun, 0, 5, 0, 0),
+            Token::new("beta", "beta", PosTag::Noun, 6, 10, 0, 1),
+            Token::new("gamma", "gamma", PosTag::Noun, 11, 16, 0, 2),
+            Token::new("delta", "delta", PosTag::Noun, 17, 22, 0, 3),
+            Token::new("epsilon", "epsilon", PosTag::Noun, 24, 31, 1, 4),
+            Token::new("zeta", "zeta", PosTag::Noun, 34, 38, 2, 5),
+        ];
+        let stream = TokenStream::from_tokens(&tokens).with_paragraph_starts([0, 2]);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+        let edge = |graph: &Graph, a: &str, b: &str| {
+            let a = graph.get_node_by_lemma(&format!("{a}|NOUN")).unwrap();
+            let b = graph.get_node_by_lemma(&format!("{b}|NOUN")).unwrap();
+            graph.neighbors(a).any(|(n, _)| n == b)
+        };
+
+        let build = |window_strategy| {
+            WindowGraphBuilder {
+                window_strategy,
+                edge_weight_policy: EdgeWeightPolicy::Binary,
+            }
+            .build(stream.as_ref(), cs.as_ref(), &cfg)
+        };
+
+        let sentence = build(WindowStrategy::Sentence);
+        assert!(edge(&sentence, "alpha", "delta"));
+        assert!(!edge(&sentence, "delta", "epsilon"));
+
+        let paragraph = build(WindowStrategy::Paragraph);
+        assert!(edge(&paragraph, "alpha", "delta"));
+        assert!(edge(&paragraph, "alpha", "epsilon"));
+        assert!(!edge(&paragraph, "epsilon", "zeta"));
+
+        // Without paragraph information paragraphs fall back to sentences.
+        let flat = TokenStream::from_tokens(&tokens);
+        let graph = WindowGraphBuilder {
+            window_strategy: WindowStrategy::Paragraph,
+            edge_weight_policy: EdgeWeightPolicy::Binary,
+        }
+        .build(flat.as_ref(), cs.as_ref(), &cfg);
+        assert!(edge(&graph, "alpha", "delta"));
+        assert!(!edge(&graph, "delta", "epsilon"));
+
+        // A single marked paragraph still spans the whole stream.
+        let single = TokenStream::from_tokens(&tokens).with_paragraph_starts([0]);
+        let graph = WindowGraphBuilder {
+            window_strategy: WindowStrategy::Paragraph,
+            edge_weight_policy: EdgeWeightPolicy::Binary,
+        }
+        .build(single.as_ref(), cs.as_ref(), &cfg);
+        assert!(edge(&graph, "alpha", "zeta"));
+
+        // ExpandRank has no paragraphs and scopes by sentence.
+        let mut expand = ExpandGraphBuilder::new(vec![(tokens.clone(), 1.0)]);
+        expand.window_strategy = WindowStrategy::Paragraph;
+        let graph = expand.build(flat.as_ref(), cs.as_ref(), &cfg);
+        assert!(edge(&graph, "alpha", "delta"));
+        assert!(!edge(&graph, "delta", "epsilon"));
+    }
+
     #[test]
     fn test_graph_builder_count_accumulates() {
         // Same pair co-occurs multiple times → count should accumulate.
@@ -4052,34 +6942,167 @@ mod tests {
         let stream = TokenStream::from_tokens(&tokens);
         let cfg = TextRankConfig::default();
         let cs = word_candidates(&stream, &cfg);
-
-        let gb_binary = WindowGraphBuilder {
-            window_strategy: WindowStrategy::SentenceBounded { window_size: 2 },
-            edge_weight_policy: EdgeWeightPolicy::Binary,
+
+        let gb_binary = WindowGraphBuilder {
+            window_strategy: WindowStrategy::SentenceBounded { window_size: 2 },
+            edge_weight_policy: EdgeWeightPolicy::Binary,
+        };
+        let g_binary = gb_binary.build(stream.as_ref(), cs.as_ref(), &cfg);
+        let e_binary = collect_edges(&g_binary);
+
+        let gb_count = WindowGraphBuilder {
+            window_strategy: WindowStrategy::SentenceBounded { window_size: 2 },
+            edge_weight_policy: EdgeWeightPolicy::CountAccumulating,
+        };
+        let g_count = gb_count.build(stream.as_ref(), cs.as_ref(), &cfg);
+        let e_count = collect_edges(&g_count);
+
+        // Same edge set (just A-B), different weights.
+        assert_eq!(e_binary.len(), 1);
+        assert_eq!(e_count.len(), 1);
+        assert!(
+            (e_binary[0].2 - 1.0).abs() < 1e-10,
+            "Binary: weight should be 1.0, got {}",
+            e_binary[0].2
+        );
+        assert!(
+            (e_count[0].2 - 2.0).abs() < 1e-10,
+            "Count: weight should be 2.0, got {}",
+            e_count[0].2
+        );
+    }
+
+    // ---- Distance decay and association weights -----------------------
+
+    fn edge_weight(edges: &[(String, String, f64)], a: &str, b: &str) -> f64 {
+        edges
+            .iter()
+            .find(|e| e.0 == a && e.1 == b)
+            .map(|e| e.2)
+            .unwrap_or(0.0)
+    }
+
+    #[test]
+    fn test_edge_correctness_distance_decay() {
+        // Sentence 0: A STOP B C (window=3 over candidates A, B, C)
+        // Token distances: A-B = 2, A-C = 3, B-C = 1
+        let mut tokens = vec![
+            Token::new("a", "a", PosTag::Noun, 0, 1, 0, 0),
+            Token::new("stop", "stop", PosTag::Noun, 2, 6, 0, 1),
+            Token::new("b", "b", PosTag::Noun, 7, 8, 0, 2),
+            Token::new("c", "c", PosTag::Noun, 9, 10, 0, 3),
+        ];
+        tokens[1].is_stopword = true;
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+
+        let gb = WindowGraphBuilder {
+            window_strategy: WindowStrategy::SentenceBounded { window_size: 3 },
+            edge_weight_policy: EdgeWeightPolicy::DistanceDecay,
+        };
+        let edges = collect_edges(&gb.build(stream.as_ref(), cs.as_ref(), &cfg));
+
+        assert!((edge_weight(&edges, "a|NOUN", "b|NOUN") - 0.5).abs() < 1e-10);
+        assert!((edge_weight(&edges, "a|NOUN", "c|NOUN") - 1.0 / 3.0).abs() < 1e-10);
+        assert!((edge_weight(&edges, "b|NOUN", "c|NOUN") - 1.0).abs() < 1e-10);
+
+        let gb = WindowGraphBuilder {
+            edge_weight_policy: EdgeWeightPolicy::ExponentialDecay { tau: 2.0 },
+            ..gb
+        };
+        let edges = collect_edges(&gb.build(stream.as_ref(), cs.as_ref(), &cfg));
+        for (a, b, distance) in [("a", "b", 2.0), ("a", "c", 3.0), ("b", "c", 1.0)] {
+            let weight = edge_weight(&edges, &format!("{a}|NOUN"), &format!("{b}|NOUN"));
+            assert!((weight - (-distance / 2.0f64).exp()).abs() < 1e-10);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_window_graph_builder_parallel_matches_serial() {
+        let count = crate::graph::builder::PARALLEL_GRAPH_THRESHOLD + 1_000;
+        let tokens: Vec<Token> = (0..count)
+            .map(|i| {
+                let lemma = format!("w{}", (i * 7919) % 211);
+                Token::new(&lemma, &lemma, PosTag::Noun, i, i + 1, i / 15, i)
+            })
+            .collect();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let serial_cfg = cfg
+            .clone()
+            .with_determinism(crate::types::DeterminismMode::Deterministic);
+        let cs = word_candidates(&stream, &cfg);
+
+        for policy in [
+            EdgeWeightPolicy::Binary,
+            EdgeWeightPolicy::CountAccumulating,
+            EdgeWeightPolicy::DistanceDecay,
+            EdgeWeightPolicy::ExponentialDecay { tau: 3.0 },
+            EdgeWeightPolicy::Pmi,
+            EdgeWeightPolicy::Dice,
+        ] {
+            for window_strategy in [
+                WindowStrategy::SentenceBounded { window_size: 4 },
+                WindowStrategy::CrossSentence { window_size: 4 },
+            ] {
+                let gb = WindowGraphBuilder {
+                    window_strategy,
+                    edge_weight_policy: policy,
+                };
+                let parallel = gb.build(stream.as_ref(), cs.as_ref(), &cfg);
+                let serial = gb.build(stream.as_ref(), cs.as_ref(), &serial_cfg);
+                let (p, s) = (parallel.csr(), serial.csr());
+
+                assert_eq!(p.lemmas, s.lemmas, "{policy:?}");
+                assert_eq!(p.row_ptr, s.row_ptr, "{policy:?}");
+                assert_eq!(p.col_idx, s.col_idx, "{policy:?}");
+                if policy.is_decay() {
+                    // Chunk partials may round differently in the last bits.
+                    for (a, b) in p.weights.iter().zip(&s.weights) {
+                        assert!((a - b).abs() < 1e-9, "{a} vs {b}");
+                    }
+                } else {
+                    assert_eq!(p.weights, s.weights, "{policy:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_edge_correctness_dice_and_pmi() {
+        // Sentence 0: A B A C (window=2)
+        // Pairs: A-B ×2, A-C ×1 (3 total); words: A ×2, B ×1, C ×1 (4 total)
+        // Windowed marginals: A ×3, B ×2, C ×1
+        let tokens = vec![
+            Token::new("a", "a", PosTag::Noun, 0, 1, 0, 0),
+            Token::new("b", "b", PosTag::Noun, 2, 3, 0, 1),
+            Token::new("a", "a", PosTag::Noun, 4, 5, 0, 2),
+            Token::new("c", "c", PosTag::Noun, 6, 7, 0, 3),
+        ];
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+        let build = |policy| {
+            let gb = WindowGraphBuilder {
+                window_strategy: WindowStrategy::SentenceBounded { window_size: 2 },
+                edge_weight_policy: policy,
+            };
+            collect_edges(&gb.build(stream.as_ref(), cs.as_ref(), &cfg))
         };
-        let g_binary = gb_binary.build(stream.as_ref(), cs.as_ref(), &cfg);
-        let e_binary = collect_edges(&g_binary);
 
-        let gb_count = WindowGraphBuilder {
-            window_strategy: WindowStrategy::SentenceBounded { window_size: 2 },
-            edge_weight_policy: EdgeWeightPolicy::CountAccumulating,
-        };
-        let g_count = gb_count.build(stream.as_ref(), cs.as_ref(), &cfg);
-        let e_count = collect_edges(&g_count);
+        let dice = build(EdgeWeightPolicy::Dice);
+        assert!((edge_weight(&dice, "a|NOUN", "b|NOUN") - 4.0 / 5.0).abs() < 1e-10);
+        assert!((edge_weight(&dice, "a|NOUN", "c|NOUN") - 2.0 / 4.0).abs() < 1e-10);
+        assert!(dice.iter().all(|&(_, _, w)| w > 0.0 && w <= 1.0));
 
-        // Same edge set (just A-B), different weights.
-        assert_eq!(e_binary.len(), 1);
-        assert_eq!(e_count.len(), 1);
-        assert!(
-            (e_binary[0].2 - 1.0).abs() < 1e-10,
-            "Binary: weight should be 1.0, got {}",
-            e_binary[0].2
-        );
-        assert!(
-            (e_count[0].2 - 2.0).abs() < 1e-10,
-            "Count: weight should be 2.0, got {}",
-            e_count[0].2
-        );
+        let pmi = build(EdgeWeightPolicy::Pmi);
+        let expected_ab = ((2.0_f64 / 3.0) / ((2.0 / 4.0) * (1.0 / 4.0))).ln();
+        let expected_ac = ((1.0_f64 / 3.0) / ((2.0 / 4.0) * (1.0 / 4.0))).ln();
+        assert!((edge_weight(&pmi, "a|NOUN", "b|NOUN") - expected_ab).abs() < 1e-10);
+        assert!((edge_weight(&pmi, "a|NOUN", "c|NOUN") - expected_ac).abs() < 1e-10);
+        assert_eq!(pmi.len(), 2);
     }
 
     // ---- Stopwords are excluded from graph (filtered by candidates) ----
@@ -5524,6 +8547,130 @@ mod tests {
         }
     }
 
+    // ================================================================
+    // QueryTeleportBuilder tests
+    // ================================================================
+
+    #[test]
+    fn test_token_overlap_similarity() {
+        let query = vec!["neural".to_string(), "network".to_string()];
+        assert_eq!(TokenOverlapSimilarity.similarity("network", &query), 1.0);
+        assert_eq!(TokenOverlapSimilarity.similarity("machine", &query), 0.0);
+        assert_eq!(
+            TokenOverlapSimilarity.similarity("neural machine", &query),
+            0.5
+        );
+        assert_eq!(TokenOverlapSimilarity.similarity("", &query), 0.0);
+    }
+
+    #[test]
+    fn test_query_teleport_weights_by_similarity() {
+        let tokens = rich_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+
+        // Half-credit for "learning", full credit for "machine".
+        let sim = |lemma: &str, _: &[String]| match lemma {
+            "machine" => 1.0,
+            "learning" => 0.5,
+            _ => 0.0,
+        };
+        let builder = QueryTeleportBuilder::new(vec![], 5.0).with_similarity(Arc::new(sim));
+        let tv = builder.build(stream.as_ref(), cs.as_ref(), &cfg).unwrap();
+        assert!(tv.is_normalized(1e-10));
+
+        let idx = |lemma: &str| {
+            cs.words()
+                .iter()
+                .position(|w| stream.pool().get(w.lemma_id) == Some(lemma))
+                .unwrap()
+        };
+        let other = cs
+            .words()
+            .iter()
+            .position(|w| !matches!(stream.pool().get(w.lemma_id), Some("machine" | "learning")))
+            .unwrap();
+        // Weights 5.0 : 3.0 : 1.0 before normalization.
+        assert!((tv[idx("machine")] / tv[other] - 5.0).abs() < 1e-10);
+        assert!((tv[idx("learning")] / tv[other] - 3.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_position_teleport_decay_shapes() {
+        // "graph rank graph": graph occurs at 0 and 2, rank at 1.
+        let tokens = vec![
+            Token::new("graph", "graph", PosTag::Noun, 0, 5, 0, 0),
+            Token::new("rank", "rank", PosTag::Noun, 6, 10, 0, 1),
+            Token::new("graph", "graph", PosTag::Noun, 11, 16, 0, 2),
+        ];
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+        let ratio = |decay| {
+            let tv = PositionDecayTeleportBuilder::new(decay)
+                .build(stream.as_ref(), cs.as_ref(), &cfg)
+                .unwrap();
+            assert!(tv.is_normalized(1e-10));
+            tv[0] / tv[1]
+        };
+
+        assert!((ratio(PositionDecay::FirstOccurrence) - 2.0).abs() < 1e-10);
+        assert!((ratio(PositionDecay::Inverse) - (4.0 / 3.0) / 0.5).abs() < 1e-10);
+        let exp = ratio(PositionDecay::Exponential { half_life: 1.0 });
+        assert!((exp - 1.25 / 0.5).abs() < 1e-10);
+    }
+
+    // ================================================================
+    // SectionTeleportBuilder tests
+    // ================================================================
+
+    #[test]
+    fn test_section_teleport_boosts_title_words() {
+        let mut tokens = rich_tokens();
+        for t in &mut tokens[..4] {
+            t.section = Some(Section::Title);
+        }
+        tokens[6].section = Some(Section::Abstract);
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+        let idx = |lemma: &str| {
+            cs.words()
+                .iter()
+                .position(|w| stream.pool().get(w.lemma_id) == Some(lemma))
+                .unwrap()
+        };
+
+        let tv = SectionTeleportBuilder::default()
+            .build(stream.as_ref(), cs.as_ref(), &cfg)
+            .unwrap();
+        assert!(tv.is_normalized(1e-10));
+        assert_eq!(tv.teleport_type(), TeleportType::Section);
+        // Weights 3.0 (title) : 2.0 (abstract) : 1.0 (unlabeled).
+        assert!((tv[idx("machine")] / tv[idx("rust")] - 3.0).abs() < 1e-10);
+        assert!((tv[idx("fast")] / tv[idx("rust")] - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_section_teleport_custom_boosts_and_unlabeled_stream() {
+        let cfg = TextRankConfig::default();
+        let stream = TokenStream::from_tokens(&rich_tokens());
+        let cs = word_candidates(&stream, &cfg);
+        assert!(SectionTeleportBuilder::default()
+            .build(stream.as_ref(), cs.as_ref(), &cfg)
+            .is_none());
+
+        let stream = stream.with_sections([Some(Section::Heading)]);
+        let cs = word_candidates(&stream, &cfg);
+        let builder = SectionTeleportBuilder::new(BTreeMap::from([(Section::Heading, 4.0)]));
+        let tv = builder.build(stream.as_ref(), cs.as_ref(), &cfg).unwrap();
+        let machine = cs.words().iter().position(|w| w.first_position == 0);
+        let machine = machine.unwrap();
+        let other = (machine + 1) % cs.len();
+        assert!((tv[machine] / tv[other] - 4.0).abs() < 1e-10);
+    }
+
     // ================================================================
     // FocusTermsTeleportBuilder tests
     // ================================================================
@@ -5940,6 +9087,89 @@ mod tests {
         }
     }
 
+    // ================================================================
+    // TeleportBuilder — TfIdfTeleportBuilder tests
+    // ================================================================
+
+    #[test]
+    fn test_tfidf_teleport_favors_corpus_rare_terms() {
+        let tokens = rich_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+
+        // "machine" appears in every corpus document, everything else in none.
+        let mut stats = CorpusStats::new();
+        for _ in 0..4 {
+            stats.add_lemmas(["machine"]);
+        }
+        let builder = TfIdfTeleportBuilder::new(Arc::new(stats));
+        let tv = builder.build(stream.as_ref(), cs.as_ref(), &cfg).unwrap();
+        assert!(tv.is_normalized(1e-10));
+        assert_eq!(tv.teleport_type(), TeleportType::Topic);
+
+        let idx = |lemma: &str| {
+            cs.words()
+                .iter()
+                .position(|w| stream.pool().get(w.lemma_id) == Some(lemma))
+                .unwrap()
+        };
+        assert!(tv[idx("learning")] > tv[idx("machine")]);
+    }
+
+    // ================================================================
+    // TeleportBuilder — FnTeleportBuilder tests
+    // ================================================================
+
+    #[test]
+    fn test_fn_teleport_weights_graph_nodes() {
+        let (stream, cs, graph) = build_test_graph();
+        let cfg = TextRankConfig::default();
+        let builder = FnTeleportBuilder::from_node_weights([("learning", 3.0)], 1.0);
+
+        // Without the graph there is nothing to weight.
+        assert!(builder.build(stream.as_ref(), cs.as_ref(), &cfg).is_none());
+
+        let tv = builder
+            .build_for_graph(stream.as_ref(), cs.as_ref(), &graph, &cfg)
+            .unwrap();
+        assert!(tv.is_normalized(1e-10));
+        assert_eq!(tv.len(), graph.num_nodes());
+        assert_eq!(tv.teleport_type(), TeleportType::Custom);
+        // Node keys carry the POS by default; the map is keyed by lemma.
+        let node = |lemma: &str| {
+            (0..graph.num_nodes())
+                .find(|&i| graph.lemma(i as u32).split('|').next() == Some(lemma))
+                .unwrap()
+        };
+        let (learning, machine) = (node("learning"), node("machine"));
+        assert!((tv[learning] - 3.0 * tv[machine]).abs() < 1e-10);
+
+        // A closure over node ids: favour the first node only.
+        let first = FnTeleportBuilder::new(|g: &Graph| {
+            (0..g.num_nodes())
+                .map(|i| if i == 0 { 1.0 } else { 0.0 })
+                .collect()
+        });
+        let tv = first
+            .build_for_graph(stream.as_ref(), cs.as_ref(), &graph, &cfg)
+            .unwrap();
+        assert_eq!(tv[0], 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "graph nodes")]
+    fn test_fn_teleport_rejects_wrong_length() {
+        let (stream, cs, graph) = build_test_graph();
+        let builder = FnTeleportBuilder::new(|_: &Graph| vec![1.0]);
+        builder.build_for_graph(
+            stream.as_ref(),
+            cs.as_ref(),
+            &graph,
+            &TextRankConfig::default(),
+        );
+    }
+
     // ================================================================
     // Ranker — PageRankRanker tests
     // ================================================================
@@ -5976,6 +9206,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pagerank_ranker_warm_start() {
+        // An irregular weighted path, so the cold start needs many iterations.
+        let mut builder = crate::graph::builder::GraphBuilder::new();
+        let ids: Vec<u32> = ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|l| builder.get_or_create_node(l))
+            .collect();
+        for (i, w) in [(0, 3.0), (1, 1.0), (2, 0.5), (3, 2.0)] {
+            builder.increment_edge(ids[i], ids[i + 1], w);
+        }
+        let graph = Graph::from_builder(&builder);
+        let cfg = TextRankConfig::default();
+
+        let cold = PageRankRanker.rank(&graph, None, &cfg);
+        let warm = PageRankRanker.rank_warm(&graph, None, &cfg, cold.scores());
+        assert!(warm.converged());
+        assert!(warm.iterations() < cold.iterations());
+        for (w, c) in warm.scores().iter().zip(cold.scores()) {
+            assert!((w - c).abs() < 1e-4);
+        }
+    }
+
     #[test]
     fn test_pagerank_ranker_personalized() {
         // Personalized PageRank with a non-uniform teleport vector.
@@ -6291,6 +9544,74 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "embeddings")]
+    #[test]
+    fn test_embedding_dedup_phrase_builder_drops_paraphrases() {
+        use crate::nlp::embeddings::WordVectors;
+        use crate::phrase::embedding_dedup::EmbeddingDedup;
+
+        let tokens = phrase_test_tokens();
+        let (stream, cs, graph, ranks) = build_full_pipeline(&tokens);
+        let cfg = TextRankConfig::default();
+        // Every word has the same vector, so every phrase paraphrases the first.
+        let vectors = WordVectors::from_text_reader(
+            "machine 1 0\nlearning 1 0\nalgorithm 1 0\ndata 1 0\ndeep 1 0\nmodel 1 0\n".as_bytes(),
+        )
+        .unwrap();
+        let dedup = EmbeddingDedup::new(vectors);
+
+        let chunked = ChunkPhraseBuilder.build(stream.as_ref(), cs.as_ref(), &ranks, &graph, &cfg);
+        assert!(chunked.len() > 1);
+        let deduped = EmbeddingDedupPhraseBuilder::new(dedup.clone()).build(
+            stream.as_ref(),
+            cs.as_ref(),
+            &ranks,
+            &graph,
+            &cfg,
+        );
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(
+            deduped.entries()[0].lemma_text,
+            chunked.entries()[0].lemma_text
+        );
+
+        let strict = EmbeddingDedupPhraseBuilder::new(dedup.with_threshold(1.0));
+        let kept = strict.build(stream.as_ref(), cs.as_ref(), &ranks, &graph, &cfg);
+        assert_eq!(kept.len(), chunked.len());
+        assert_eq!(
+            strict.describe(),
+            "EmbeddingDedupPhraseBuilder { threshold: 1 }"
+        );
+    }
+
+    #[test]
+    fn test_keyword_phrase_builder_emits_single_words() {
+        let tokens = phrase_test_tokens();
+        let (stream, cs, graph, ranks) = build_full_pipeline(&tokens);
+        let cfg = TextRankConfig::default();
+
+        let phrases =
+            KeywordPhraseBuilder.build(stream.as_ref(), cs.as_ref(), &ranks, &graph, &cfg);
+
+        assert_eq!(phrases.len(), graph.num_nodes());
+        for entry in phrases.entries() {
+            assert_eq!(entry.lemma_ids.len(), 1);
+            assert!(!entry.lemma_text.as_deref().unwrap().contains(' '));
+        }
+        let learning = phrases
+            .entries()
+            .iter()
+            .find(|e| e.lemma_text.as_deref() == Some("learning"))
+            .unwrap();
+        assert_eq!(learning.count, 2);
+        assert_eq!(learning.spans, Some(vec![(1, 2), (6, 7)]));
+        assert_eq!(learning.text_spans, Some(vec![(8, 16), (47, 55)]));
+        assert!(phrases
+            .entries()
+            .windows(2)
+            .all(|w| w[0].score >= w[1].score));
+    }
+
     #[test]
     fn test_chunk_phrase_builder_empty_tokens() {
         let stream = TokenStream::from_tokens(&[]);
@@ -6428,6 +9749,145 @@ mod tests {
         }
     }
 
+    /// "The rate of change matters": Det Noun Prep Noun Verb.
+    fn rate_of_change_tokens() -> Vec<Token> {
+        let mut tokens = vec![
+            Token::new("The", "the", PosTag::Determiner, 0, 3, 0, 0),
+            Token::new("rate", "rate", PosTag::Noun, 4, 8, 0, 1),
+            Token::new("of", "of", PosTag::Preposition, 9, 11, 0, 2),
+            Token::new("change", "change", PosTag::Noun, 12, 18, 0, 3),
+            Token::new("matters", "matter", PosTag::Verb, 19, 26, 0, 4),
+        ];
+        tokens[0].is_stopword = true;
+        tokens[2].is_stopword = true;
+        tokens
+    }
+
+    #[test]
+    fn test_grammar_selector_nodes_and_spans() {
+        let tokens = rate_of_change_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+
+        let selector = GrammarCandidateSelector::from_pattern("Noun Prep Noun").unwrap();
+        let cs = selector.select(stream.as_ref(), &cfg);
+
+        // Nodes: non-stopword tokens inside matches only ("matters" excluded).
+        let keys: Vec<String> = cs
+            .words()
+            .iter()
+            .map(|w| w.graph_key(stream.pool(), false))
+            .collect();
+        assert_eq!(keys, ["rate", "change"]);
+        let spans = cs.as_ref().phrase_spans().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!((spans[0].start_token, spans[0].end_token), (1, 4));
+        assert_eq!((spans[0].start_char, spans[0].end_char), (4, 18));
+
+        // Word-node selection carries no spans of its own.
+        assert!(WordNodeSelector
+            .select(stream.as_ref(), &cfg)
+            .phrase_spans()
+            .is_none());
+    }
+
+    #[test]
+    fn test_noun_chunk_selector_builds_maximal_chunks() {
+        // "Deep neural networks learn fast representations. Rust is great"
+        let mut tokens = vec![
+            Token::new("Deep", "deep", PosTag::Adjective, 0, 4, 0, 0),
+            Token::new("neural", "neural", PosTag::Adjective, 5, 11, 0, 1),
+            Token::new("networks", "network", PosTag::Noun, 12, 20, 0, 2),
+            Token::new("learn", "learn", PosTag::Verb, 21, 26, 0, 3),
+            Token::new("fast", "fast", PosTag::Adjective, 27, 31, 0, 4),
+            Token::new(
+                "representations",
+                "representation",
+                PosTag::Noun,
+                32,
+                47,
+                0,
+                5,
+            ),
+            Token::new("Rust", "rust", PosTag::ProperNoun, 49, 53, 1, 6),
+            Token::new("is", "be", PosTag::Verb, 54, 56, 1, 7),
+            Token::new("great", "great", PosTag::Adjective, 57, 62, 1, 8),
+        ];
+        tokens[7].is_stopword = true;
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+
+        let cs = NounChunkSelector.select(stream.as_ref(), &cfg);
+        let spans: Vec<(u32, u32, u32)> = cs
+            .phrases()
+            .iter()
+            .map(|p| (p.start_token, p.end_token, p.sentence_idx))
+            .collect();
+        // The trailing "great" has no head noun and is not a chunk.
+        assert_eq!(spans, [(0, 3, 0), (4, 6, 0), (6, 7, 1)]);
+        assert_eq!(
+            (cs.phrases()[0].start_char, cs.phrases()[0].end_char),
+            (0, 20)
+        );
+        assert_eq!(cs.phrases()[0].term_ids.len(), 3);
+
+        // Over-long runs keep their head noun and the nearest modifiers.
+        let cfg = TextRankConfig {
+            min_phrase_length: 2,
+            max_phrase_length: 2,
+            ..Default::default()
+        };
+        let cs = NounChunkSelector.select(stream.as_ref(), &cfg);
+        let spans: Vec<(u32, u32)> = cs
+            .phrases()
+            .iter()
+            .map(|p| (p.start_token, p.end_token))
+            .collect();
+        assert_eq!(spans, [(1, 3), (4, 6)]);
+    }
+
+    #[test]
+    fn test_noun_chunk_selector_bridges_stopwords() {
+        let tokens = rate_of_change_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let spans = |cfg: &TextRankConfig| -> Vec<(u32, u32)> {
+            NounChunkSelector
+                .select(stream.as_ref(), cfg)
+                .phrases()
+                .iter()
+                .map(|p| (p.start_token, p.end_token))
+                .collect()
+        };
+
+        assert_eq!(spans(&TextRankConfig::default()), [(1, 2), (3, 4)]);
+        let cfg = TextRankConfig::default().with_phrase_bridge_words(["of"]);
+        assert_eq!(spans(&cfg), [(1, 4)]);
+        // The bridge token stays in the span but not in the term set.
+        let cs = NounChunkSelector.select(stream.as_ref(), &cfg);
+        assert_eq!(cs.phrases()[0].lemma_ids.len(), 3);
+        assert_eq!(cs.phrases()[0].term_ids.len(), 2);
+    }
+
+    #[test]
+    fn test_chunk_phrase_builder_uses_grammar_spans() {
+        let tokens = rate_of_change_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = GrammarCandidateSelector::from_pattern("Noun Prep Noun")
+            .unwrap()
+            .select(stream.as_ref(), &cfg);
+        let graph = CooccurrenceGraphBuilder::default().build(stream.as_ref(), cs.as_ref(), &cfg);
+        let ranks = PageRankRanker.rank(&graph, None, &cfg);
+
+        let phrases = ChunkPhraseBuilder.build(stream.as_ref(), cs.as_ref(), &ranks, &graph, &cfg);
+        let surfaces: Vec<&str> = phrases
+            .entries()
+            .iter()
+            .filter_map(|e| e.surface.as_deref())
+            .collect();
+        assert_eq!(surfaces, ["rate of change"]);
+    }
+
     #[test]
     fn test_chunk_phrase_builder_deterministic() {
         let tokens = phrase_test_tokens();
@@ -6615,6 +10075,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_standard_formatter_normalizes_scores() {
+        use crate::types::ScoreNormalization;
+
+        let tokens = phrase_test_tokens();
+        let (phrases, ranks) = build_phrases(&tokens);
+        let raw =
+            StandardResultFormatter.format(&phrases, &ranks, None, &TextRankConfig::default());
+
+        let cfg = TextRankConfig::default().with_score_normalization(ScoreNormalization::Sum);
+        let result = StandardResultFormatter.format(&phrases, &ranks, None, &cfg);
+        assert!((result.phrases.iter().map(|p| p.score).sum::<f64>() - 1.0).abs() < 1e-12);
+
+        let cfg = TextRankConfig::default().with_score_normalization(ScoreNormalization::MinMax);
+        let result = StandardResultFormatter.format(&phrases, &ranks, None, &cfg);
+        assert_eq!(result.phrases[0].score, 1.0);
+        assert!(result
+            .phrases
+            .iter()
+            .all(|p| (0.0..=1.0).contains(&p.score)));
+
+        // Normalization never reorders phrases.
+        let text = |phrases: &[crate::types::Phrase]| {
+            phrases.iter().map(|p| p.text.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(text(&result.phrases), text(&raw.phrases));
+    }
+
+    #[test]
+    fn test_standard_formatter_drops_blocklisted_phrases() {
+        use crate::types::PhrasePattern;
+
+        let tokens = phrase_test_tokens();
+        let (phrases, ranks) = build_phrases(&tokens);
+        let top = phrases.entries()[0].surface.clone().unwrap();
+
+        let cfg =
+            TextRankConfig::default().with_phrase_blocklist([PhrasePattern::Exact(top.clone())]);
+        let result = StandardResultFormatter.format(&phrases, &ranks, None, &cfg);
+
+        assert_eq!(result.phrases.len(), phrases.len() - 1);
+        assert!(result.phrases.iter().all(|p| p.text != top));
+        assert_eq!(result.phrases[0].rank, 1);
+    }
+
     #[test]
     fn test_standard_formatter_preserves_surface_forms() {
         let tokens = phrase_test_tokens();
@@ -6667,6 +10172,7 @@ mod tests {
             cluster_details: None,
             phrase_diagnostics: None,
             dropped_candidates: None,
+            node_details: None,
         };
 
         let result = StandardResultFormatter.format(&phrases, &ranks, Some(debug), &cfg);
@@ -6709,6 +10215,7 @@ mod tests {
             surface: Some("machine learning".to_string()),
             lemma_text: Some("machine learning".to_string()),
             spans: Some(vec![(0, 16), (42, 55)]),
+            text_spans: None,
         };
         let phrases = PhraseSet::from_entries(vec![entry]);
         let ranks = RankOutput::from_pagerank_result(&crate::pagerank::PageRankResult {
@@ -6742,6 +10249,7 @@ mod tests {
             surface: None,
             lemma_text: None,
             spans: None,
+            text_spans: None,
         };
         let phrases = PhraseSet::from_entries(vec![entry]);
         let ranks = RankOutput::from_pagerank_result(&crate::pagerank::PageRankResult {
@@ -6803,6 +10311,7 @@ mod tests {
                 surface: Some("low".to_string()),
                 lemma_text: Some("low".to_string()),
                 spans: Some(vec![(0, 3)]),
+                text_spans: None,
             },
             PhraseEntry {
                 lemma_ids: vec![1],
@@ -6811,6 +10320,7 @@ mod tests {
                 surface: Some("high".to_string()),
                 lemma_text: Some("high".to_string()),
                 spans: Some(vec![(4, 8)]),
+                text_spans: None,
             },
         ];
         let phrases = PhraseSet::from_entries(entries);
@@ -6844,6 +10354,7 @@ mod tests {
                 surface: Some("later".to_string()),
                 lemma_text: Some("later".to_string()),
                 spans: Some(vec![(10, 15)]),
+                text_spans: None,
             },
             PhraseEntry {
                 lemma_ids: vec![0],
@@ -6852,6 +10363,7 @@ mod tests {
                 surface: Some("earlier".to_string()),
                 lemma_text: Some("earlier".to_string()),
                 spans: Some(vec![(0, 7)]),
+                text_spans: None,
             },
         ];
         let phrases = PhraseSet::from_entries(entries);
@@ -6890,6 +10402,7 @@ mod tests {
                 surface: Some("machine learning model".to_string()),
                 lemma_text: Some("machine learning model".to_string()),
                 spans: Some(vec![(0, 22)]),
+                text_spans: None,
             },
             PhraseEntry {
                 lemma_ids: vec![0, 1],
@@ -6898,6 +10411,7 @@ mod tests {
                 surface: Some("machine learning".to_string()),
                 lemma_text: Some("machine learning".to_string()),
                 spans: Some(vec![(0, 16)]),
+                text_spans: None,
             },
         ];
         let phrases = PhraseSet::from_entries(entries);
@@ -6936,6 +10450,7 @@ mod tests {
                 surface: Some("zebra".to_string()),
                 lemma_text: Some("zebra".to_string()),
                 spans: Some(vec![(0, 5)]),
+                text_spans: None,
             },
             PhraseEntry {
                 lemma_ids: vec![0],
@@ -6944,6 +10459,7 @@ mod tests {
                 surface: Some("alpha".to_string()),
                 lemma_text: Some("alpha".to_string()),
                 spans: Some(vec![(0, 5)]),
+                text_spans: None,
             },
         ];
         let phrases = PhraseSet::from_entries(entries);
@@ -6981,6 +10497,7 @@ mod tests {
                 surface: Some("b_word".to_string()),
                 lemma_text: Some("b_word".to_string()),
                 spans: Some(vec![(5, 11)]),
+                text_spans: None,
             },
             PhraseEntry {
                 lemma_ids: vec![1],
@@ -6989,6 +10506,7 @@ mod tests {
                 surface: Some("a_word".to_string()),
                 lemma_text: Some("a_word".to_string()),
                 spans: Some(vec![(0, 6)]),
+                text_spans: None,
             },
             PhraseEntry {
                 lemma_ids: vec![2],
@@ -6997,6 +10515,7 @@ mod tests {
                 surface: Some("top".to_string()),
                 lemma_text: Some("top".to_string()),
                 spans: Some(vec![(12, 15)]),
+                text_spans: None,
             },
         ];
         let phrases = PhraseSet::from_entries(entries);
@@ -7222,6 +10741,16 @@ mod tests {
         assert_eq!(cs.window_size(), 6);
         assert!(!cs.is_sentence_bounded());
         assert!(cs.is_cross_sentence());
+
+        for scope in [WindowStrategy::Sentence, WindowStrategy::Paragraph] {
+            assert!(scope.is_scope());
+            assert!(!scope.is_sentence_bounded());
+            assert!(!scope.is_cross_sentence());
+        }
+        assert_eq!(
+            serde_json::to_string(&WindowStrategy::Paragraph).unwrap(),
+            r#"{"type":"paragraph"}"#
+        );
     }
 
     // ================================================================
@@ -7259,6 +10788,19 @@ mod tests {
         assert!(p2.is_count_accumulating());
     }
 
+    #[test]
+    fn test_edge_weight_policy_serde_association() {
+        let p: EdgeWeightPolicy = serde_json::from_str("\"pmi\"").unwrap();
+        assert!(p.is_association());
+        let p: EdgeWeightPolicy = serde_json::from_str("\"distance_decay\"").unwrap();
+        assert_eq!(p, EdgeWeightPolicy::DistanceDecay);
+        assert!(!p.is_association());
+        let p: EdgeWeightPolicy =
+            serde_json::from_str(r#"{"exponential_decay": {"tau": 3.0}}"#).unwrap();
+        assert_eq!(p, EdgeWeightPolicy::ExponentialDecay { tau: 3.0 });
+        assert!(p.is_decay());
+    }
+
     #[test]
     fn test_edge_weight_policy_default() {
         let p = EdgeWeightPolicy::default();
@@ -7374,6 +10916,51 @@ mod tests {
         assert_eq!(assignments.num_candidates(), 3);
     }
 
+    #[test]
+    fn test_similarity_hac_clusterer_compares_candidate_text() {
+        use crate::similarity::Unit;
+
+        let tokens = topic_test_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let sel = PhraseCandidateSelector::new(topic_test_chunks());
+        let cfg = TextRankConfig::default();
+        let candidates = sel.select(stream.as_ref(), &cfg);
+
+        // "Machine learning" ~ "Neural networks"; nothing else is similar.
+        let related = |a: &Unit<'_>, b: &Unit<'_>| {
+            let mut pair = [a.lemma, b.lemma];
+            pair.sort_unstable();
+            if pair == ["machine learning", "neural network"] {
+                assert_eq!(a.text.len() + b.text.len(), 31);
+                0.9
+            } else {
+                0.0
+            }
+        };
+        let clusterer = SimilarityHacClusterer::new(Arc::new(related), 0.25);
+
+        let ca = clusterer.cluster_with_tokens(stream.as_ref(), candidates.as_ref(), &cfg);
+        assert_eq!(ca.num_clusters(), 2);
+        assert_eq!(ca.cluster_of(0), ca.cluster_of(2));
+        assert_ne!(ca.cluster_of(0), ca.cluster_of(1));
+
+        // The graph builder routes tokens through to the clusterer.
+        let graph = TopicGraphBuilder::new(clusterer.clone()).build(
+            stream.as_ref(),
+            candidates.as_ref(),
+            &cfg,
+        );
+        assert_eq!(graph.num_nodes(), 2);
+
+        // Without tokens it falls back to Jaccard over term IDs.
+        assert_eq!(
+            clusterer.cluster(candidates.as_ref(), &cfg).as_slice(),
+            JaccardHacClusterer::topic_rank()
+                .cluster(candidates.as_ref(), &cfg)
+                .as_slice()
+        );
+    }
+
     #[test]
     fn test_topic_graph_builder_edge_weight_multiplier() {
         let tokens = topic_test_tokens();
@@ -7681,6 +11268,143 @@ mod tests {
         assert_eq!(graph.num_edges(), 0);
     }
 
+    #[cfg(feature = "sentence-rank")]
+    #[test]
+    fn test_sentence_graph_builder_overlap_similarity() {
+        // s0 = {1, 2, 3}, s1 = {2, 3, 4, 4}: overlap 2, lengths 3 and 4.
+        // s2 = {5}, s3 = {5}: one-word sentences → zero denominator, no edge.
+        let candidates =
+            make_sentence_candidates(vec![vec![1, 2, 3], vec![2, 3, 4, 4], vec![5], vec![5]]);
+        let tokens: Vec<Token> = Vec::new();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+
+        let builder = SentenceGraphBuilder::default().with_similarity(SentenceSimilarity::Overlap);
+        let graph = builder.build(stream.as_ref(), candidates.as_ref(), &cfg);
+
+        assert_eq!(graph.num_nodes(), 4);
+        assert_eq!(graph.num_edges(), 2);
+        let weight_01: f64 = graph
+            .neighbors(0)
+            .find(|(nb, _)| *nb == 1)
+            .map(|(_, w)| w)
+            .unwrap();
+        let expected = 2.0 / (3f64.ln() + 4f64.ln());
+        assert!(
+            (weight_01 - expected).abs() < 1e-10,
+            "Expected {expected}, got {weight_01}"
+        );
+    }
+
+    #[cfg(feature = "sentence-rank")]
+    #[test]
+    fn test_sentence_graph_builder_bm25() {
+        // s0 = {1, 2}, s1 = {2, 3}: share lemma 2 (df 2 of 3), lengths 2 and
+        // 2 against a mean of 5/3. s2 = {4} shares nothing.
+        let candidates = make_sentence_candidates(vec![vec![1, 2], vec![2, 3], vec![4]]);
+        let tokens: Vec<Token> = Vec::new();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+
+        let builder = SentenceGraphBuilder::default().with_similarity(SentenceSimilarity::Bm25);
+        let graph = builder.build(stream.as_ref(), candidates.as_ref(), &cfg);
+
+        assert_eq!(graph.num_nodes(), 3);
+        assert_eq!(graph.num_edges(), 2);
+        let idf = (1.0 + 1.5 / 2.5f64).ln();
+        let norm = 1.2 * (0.25 + 0.75 * 2.0 / (5.0 / 3.0));
+        let expected = idf * 2.2 / (1.0 + norm);
+        let weight_01: f64 = graph
+            .neighbors(0)
+            .find(|(nb, _)| *nb == 1)
+            .map(|(_, w)| w)
+            .unwrap();
+        assert!(
+            (weight_01 - expected).abs() < 1e-10,
+            "Expected {expected}, got {weight_01}"
+        );
+    }
+
+    #[cfg(feature = "sentence-rank")]
+    #[test]
+    fn test_sentence_graph_builder_bm25_corpus_idf() {
+        let tokens = vec![
+            Token::new("graphs", "graph", PosTag::Noun, 0, 6, 0, 0),
+            Token::new("nodes", "node", PosTag::Noun, 7, 12, 0, 1),
+            Token::new("graphs", "graph", PosTag::Noun, 14, 20, 1, 2),
+            Token::new("edges", "edge", PosTag::Noun, 21, 26, 1, 3),
+        ];
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let candidates = SentenceCandidateSelector.select(stream.as_ref(), &cfg);
+
+        // "graph" occurs in every corpus document: its IDF nearly vanishes.
+        let mut stats = CorpusStats::new();
+        for _ in 0..9 {
+            stats.add_lemmas(["graph"]);
+        }
+        stats.add_lemmas(["graph", "edge"]);
+
+        let weight = |builder: SentenceGraphBuilder| {
+            let graph = builder.build(stream.as_ref(), candidates.as_ref(), &cfg);
+            let w = graph.neighbors(0).map(|(_, w)| w).next().unwrap();
+            w
+        };
+        let bm25 = SentenceGraphBuilder::default().with_similarity(SentenceSimilarity::Bm25);
+        let document = weight(bm25.clone());
+        let corpus = weight(bm25.with_corpus_stats(Arc::new(stats)));
+
+        // Equal lengths: norm = k1, so each direction is idf * (k1 + 1) / (1 + k1).
+        assert!((document - (1.0 + 0.5 / 2.5f64).ln()).abs() < 1e-10);
+        assert!((corpus - (1.0 + 0.5 / 10.5f64).ln()).abs() < 1e-10);
+    }
+
+    #[cfg(feature = "sentence-rank")]
+    #[test]
+    fn test_sentence_graph_builder_tfidf_cosine() {
+        // Lemma 9 appears in every sentence → idf = 0, contributes nothing.
+        // s0 and s1 share lemma 2; s2 shares nothing informative.
+        let candidates = make_sentence_candidates(vec![vec![1, 2, 9], vec![2, 3, 9], vec![4, 9]]);
+        let tokens: Vec<Token> = Vec::new();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+
+        let builder = SentenceGraphBuilder::lexrank();
+        let graph = builder.build(stream.as_ref(), candidates.as_ref(), &cfg);
+
+        assert_eq!(graph.num_nodes(), 3);
+        assert_eq!(graph.num_edges(), 2);
+        // s0 = (ln 3/1, ln 3/2), s1 = (ln 3/2, ln 3/1) over lemmas (1, 2) / (2, 3).
+        let (a, b) = (3f64.ln(), 1.5f64.ln());
+        let expected = (b * b) / (a * a + b * b);
+        let weight_01: f64 = graph
+            .neighbors(0)
+            .find(|(nb, _)| *nb == 1)
+            .map(|(_, w)| w)
+            .unwrap();
+        assert!(
+            (weight_01 - expected).abs() < 1e-10,
+            "Expected {expected}, got {weight_01}"
+        );
+    }
+
+    #[cfg(feature = "sentence-rank")]
+    #[test]
+    fn test_sentence_graph_builder_binary_edges() {
+        let candidates = make_sentence_candidates(vec![vec![1, 2, 3], vec![2, 3, 4], vec![5, 6]]);
+        let tokens: Vec<Token> = Vec::new();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+
+        let builder = SentenceGraphBuilder::default().with_binary_edges(true);
+        let graph = builder.build(stream.as_ref(), candidates.as_ref(), &cfg);
+
+        assert_eq!(graph.num_edges(), 2);
+        for (_, w) in graph.neighbors(0) {
+            assert_eq!(w, 1.0);
+        }
+    }
+
     #[cfg(feature = "sentence-rank")]
     #[test]
     fn test_sentence_graph_builder_empty() {
@@ -7874,6 +11598,288 @@ mod tests {
         assert!((t.alpha - 2.5).abs() < 1e-10);
     }
 
+    // ================================================================
+    // KCoreTransform / DegreeThresholdTransform tests
+    // ================================================================
+
+    /// Triangle a-b-c with a tail c-d-e.
+    fn triangle_with_tail() -> Graph {
+        let mut builder = crate::graph::builder::GraphBuilder::new();
+        let ids: Vec<u32> = ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|l| builder.get_or_create_node(l))
+            .collect();
+        builder.increment_edge(ids[0], ids[1], 1.0);
+        builder.increment_edge(ids[1], ids[2], 1.0);
+        builder.increment_edge(ids[0], ids[2], 1.0);
+        builder.increment_edge(ids[2], ids[3], 3.0);
+        builder.increment_edge(ids[3], ids[4], 1.0);
+        Graph::from_builder(&builder)
+    }
+
+    #[test]
+    fn test_k_core_transform_peels_tail() {
+        let stream = TokenStream::from_tokens(&[]);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+        let mut graph = triangle_with_tail();
+
+        KCoreTransform::new(2).transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+
+        // e has degree 1; removing it drops d to degree 1 as well.
+        assert_eq!(graph.num_nodes(), 5);
+        assert_eq!(graph.num_edges(), 6);
+        assert_eq!(graph.csr().degree(2), 2);
+        assert_eq!(graph.csr().degree(3), 0);
+        assert_eq!(graph.csr().degree(4), 0);
+        assert!(graph.is_transformed());
+
+        // Applying it again is a no-op.
+        KCoreTransform::new(2).transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+        assert_eq!(graph.num_edges(), 6);
+    }
+
+    #[test]
+    fn test_k_core_transform_low_k_is_noop() {
+        let stream = TokenStream::from_tokens(&[]);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+        let mut graph = triangle_with_tail();
+        let edges = graph.num_edges();
+
+        KCoreTransform::new(1).transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+        assert_eq!(graph.num_edges(), edges);
+        assert!(!graph.is_transformed());
+
+        // No node has three neighbours inside a 3-core, so everything goes.
+        KCoreTransform::new(3).transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+        assert_eq!(graph.num_edges(), 0);
+    }
+
+    #[test]
+    fn test_degree_threshold_transform() {
+        let stream = TokenStream::from_tokens(&[]);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+
+        // Degree filter is single-pass: only e goes, d keeps its edge to c.
+        let mut graph = triangle_with_tail();
+        DegreeThresholdTransform::new(2).transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+        assert_eq!(graph.csr().degree(3), 1);
+        assert_eq!(graph.csr().degree(4), 0);
+
+        // Weight filter: a, b (2.0) and e (1.0) fall below 2.5.
+        let mut graph = triangle_with_tail();
+        DegreeThresholdTransform::new(0)
+            .with_min_weight(2.5)
+            .transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+        assert_eq!(graph.csr().degree(0), 0);
+        assert_eq!(graph.csr().degree(1), 0);
+        assert_eq!(graph.csr().degree(2), 1);
+        assert_eq!(graph.csr().degree(3), 1);
+        assert_eq!(graph.csr().degree(4), 0);
+    }
+
+    #[test]
+    fn test_meta_vertex_merges_frequent_pairs() {
+        let text = "Neural networks learn features. Deep neural networks generalize. \
+                    Data trains neural networks.";
+        let cfg = TextRankConfig {
+            use_pos_in_nodes: false,
+            ..TextRankConfig::default()
+        };
+        let stream = TokenStream::from_text(text, &cfg);
+        let cs = word_candidates(&stream, &cfg);
+        let mut graph =
+            CooccurrenceGraphBuilder::default().build(stream.as_ref(), cs.as_ref(), &cfg);
+        let neural = graph.get_node_by_lemma("neural").unwrap();
+        let network = graph.get_node_by_lemma("network").unwrap();
+        let deep = graph.get_node_by_lemma("deep").unwrap();
+        let nodes = graph.num_nodes();
+
+        // "deep neural" occurs once: below the threshold.
+        MetaVertexTransform::new(2).transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+
+        assert_eq!(graph.num_nodes(), nodes);
+        assert_eq!(graph.get_node_by_lemma("network"), Some(neural));
+        assert_eq!(graph.csr().degree(network), 0);
+        assert!(graph.neighbors(neural).all(|(n, _)| n != network));
+        assert!(graph.neighbors(neural).any(|(n, _)| n == deep));
+        assert_eq!(graph.get_node_by_lemma("deep"), Some(deep));
+        assert!(graph.is_transformed());
+
+        // Nothing is seen adjacent four times.
+        let mut graph =
+            CooccurrenceGraphBuilder::default().build(stream.as_ref(), cs.as_ref(), &cfg);
+        MetaVertexTransform::new(4).transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+        assert!(!graph.is_transformed());
+    }
+
+    #[test]
+    fn test_edge_threshold_absolute() {
+        let stream = TokenStream::from_tokens(&[]);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+        let mut graph = triangle_with_tail();
+
+        EdgeThreshold::absolute(2.0).transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+
+        // Only c-d (3.0) survives; a, b and e are left isolated.
+        assert_eq!(graph.num_nodes(), 5);
+        assert_eq!(graph.num_edges(), 2);
+        assert_eq!(graph.csr().degree(0), 0);
+        assert_eq!(graph.csr().degree(2), 1);
+        assert!(graph.csr().dangling_nodes().contains(&4));
+    }
+
+    #[test]
+    fn test_edge_threshold_quantile() {
+        let stream = TokenStream::from_tokens(&[]);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+
+        let mut graph = triangle_with_tail();
+        let threshold = EdgeThreshold::quantile(0.9);
+        assert!((threshold.min_weight(&graph) - 3.0).abs() < 1e-10);
+        threshold.transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+        assert_eq!(graph.num_edges(), 2);
+
+        // Quantile 0 keeps every edge and leaves the graph untouched.
+        let mut graph = triangle_with_tail();
+        EdgeThreshold::quantile(0.0).transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+        assert_eq!(graph.num_edges(), 10);
+        assert!(!graph.is_transformed());
+    }
+
+    #[test]
+    fn test_pagerank_ranker_single_precision() {
+        let tokens = rich_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+        let graph = CooccurrenceGraphBuilder::default().build(stream.as_ref(), cs.as_ref(), &cfg);
+
+        let double = PageRankRanker.rank(&graph, None, &cfg);
+        let single_cfg = cfg.clone().with_rank_precision(RankPrecision::F32);
+        let single = PageRankRanker.rank(&graph, None, &single_cfg);
+        let mut ws = crate::pipeline::artifacts::PipelineWorkspace::new();
+        let reused = PageRankRanker.rank_reusing(&graph, None, &single_cfg, &mut ws);
+
+        assert!(single.converged());
+        for node in 0..graph.num_nodes() as u32 {
+            assert!((double.score(node) - single.score(node)).abs() < 1e-5);
+            assert_eq!(single.score(node), reused.score(node));
+        }
+    }
+
+    #[test]
+    fn test_hits_ranker_scores() {
+        let tokens = rich_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+        let mut graph =
+            CooccurrenceGraphBuilder::default().build(stream.as_ref(), cs.as_ref(), &cfg);
+
+        // Symmetric graph: hubs and authorities agree.
+        let authorities = HitsRanker::authorities().rank(&graph, None, &cfg);
+        let hubs = HitsRanker::hubs().rank(&graph, None, &cfg);
+        assert!(authorities.converged());
+        assert!((authorities.scores().iter().sum::<f64>() - 1.0).abs() < 1e-10);
+        for node in 0..graph.num_nodes() as u32 {
+            assert!((authorities.score(node) - hubs.score(node)).abs() < 1e-5);
+        }
+
+        // Drop every edge into node 0: it can still be a hub, never an authority.
+        graph.csr_mut().retain_edges(|_, target, _| target != 0);
+        let authorities = HitsRanker::authorities().rank(&graph, None, &cfg);
+        let hubs = HitsRanker::hubs().rank(&graph, None, &cfg);
+        assert_eq!(authorities.score(0), 0.0);
+        assert!(hubs.score(0) > 0.0);
+    }
+
+    #[test]
+    fn test_degree_ranker_scores() {
+        let tokens = rich_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+        let graph = CooccurrenceGraphBuilder::default().build(stream.as_ref(), cs.as_ref(), &cfg);
+
+        let output = DegreeRanker.rank(&graph, None, &cfg);
+        assert!(output.converged());
+        assert_eq!(output.iterations(), 0);
+        assert!((output.scores().iter().sum::<f64>() - 1.0).abs() < 1e-10);
+
+        // Scores are proportional to weighted degree.
+        let csr = graph.csr();
+        let total: f64 = csr.total_weight.iter().sum();
+        for node in 0..graph.num_nodes() as u32 {
+            assert!((output.score(node) * total - csr.node_total_weight(node)).abs() < 1e-9);
+        }
+
+        // A teleport vector does not change degree scores.
+        let teleport = TeleportVector::uniform(graph.num_nodes());
+        let biased = DegreeRanker.rank(&graph, Some(&teleport), &cfg);
+        assert_eq!(biased.scores(), output.scores());
+    }
+
+    #[test]
+    fn test_pagerank_ranker_acceleration() {
+        let tokens = rich_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default().with_damping(0.95);
+        let cs = word_candidates(&stream, &cfg);
+        let graph = CooccurrenceGraphBuilder::default().build(stream.as_ref(), cs.as_ref(), &cfg);
+
+        let plain = PageRankRanker.rank(&graph, None, &cfg);
+        let accel_cfg = cfg
+            .clone()
+            .with_acceleration(RankerAcceleration::QuadraticExtrapolation);
+        let accelerated = PageRankRanker.rank(&graph, None, &accel_cfg);
+
+        assert!(accelerated.converged());
+        assert!(accelerated.iterations() <= plain.iterations());
+        for node in 0..graph.num_nodes() as u32 {
+            assert!((plain.score(node) - accelerated.score(node)).abs() < 1e-5);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_pagerank_ranker_parallel_threshold() {
+        let tokens = rich_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+        let graph = CooccurrenceGraphBuilder::default().build(stream.as_ref(), cs.as_ref(), &cfg);
+
+        let serial = PageRankRanker.rank(&graph, None, &cfg);
+        let parallel = PageRankRanker.rank(&graph, None, &cfg.clone().with_parallel_threshold(1));
+        for node in 0..graph.num_nodes() as u32 {
+            assert!((serial.score(node) - parallel.score(node)).abs() < 1e-12);
+        }
+        assert_eq!(serial.iterations(), parallel.iterations());
+    }
+
+    #[test]
+    fn test_k_core_transform_ranks_through_pipeline() {
+        let tokens = rich_tokens();
+        let stream = TokenStream::from_tokens(&tokens);
+        let cfg = TextRankConfig::default();
+        let cs = word_candidates(&stream, &cfg);
+
+        let mut graph =
+            CooccurrenceGraphBuilder::default().build(stream.as_ref(), cs.as_ref(), &cfg);
+        let edges_before = graph.num_edges();
+        KCoreTransform::new(3).transform(&mut graph, stream.as_ref(), cs.as_ref(), &cfg);
+        assert!(graph.num_edges() <= edges_before);
+
+        let ranks = PageRankRanker.rank(&graph, None, &cfg);
+        let total: f64 = (0..graph.num_nodes() as u32).map(|i| ranks.score(i)).sum();
+        assert!((total - 1.0).abs() < 1e-6);
+    }
+
     // ================================================================
     // MultipartitePhraseBuilder tests
     // ================================================================
@@ -8100,7 +12106,7 @@ mod tests {
         for entry in phrases.entries() {
             assert!(entry.score > 0.0, "score should be positive");
             assert!(
-                entry.surface.as_ref().unwrap().len() > 0,
+                !entry.surface.as_ref().unwrap().is_empty(),
                 "surface should be non-empty"
             );
             assert!(entry.count == 1, "count should be 1 for sentences");
@@ -8156,8 +12162,11 @@ mod tests {
         tokens.push(Token::new("Data", "data", PosTag::Noun, 41, 45, 2, 7));
         tokens.push(Token::new("science", "science", PosTag::Noun, 46, 53, 2, 8));
 
-        let mut cfg = TextRankConfig::default();
-        cfg.top_n = 2; // request only top 2 of 3 sentences
+        // request only top 2 of 3 sentences
+        let cfg = TextRankConfig {
+            top_n: 2,
+            ..TextRankConfig::default()
+        };
 
         let (stream, candidates, graph, ranks) = sentence_pipeline_artifacts(&tokens, &cfg);
         assert_eq!(candidates.len(), 3, "should have 3 sentence candidates");
@@ -24,6 +24,8 @@ use serde::Serialize;
 use super::error_code::ErrorCode;
 use super::errors::PipelineSpecError;
 use super::spec::*;
+use super::teleport::PositionDecay;
+use crate::types::TextRankConfig;
 
 // ─── Severity ───────────────────────────────────────────────────────────────
 
@@ -146,7 +148,9 @@ impl ValidationEngine {
         engine.add_rule(Box::new(TopicGraphDepsRule));
         engine.add_rule(Box::new(GraphTransformDepsRule));
         engine.add_rule(Box::new(RuntimeLimitsRule));
+        engine.add_rule(Box::new(GraphTransformParamsRule));
         engine.add_rule(Box::new(UnknownFieldsRule));
+        engine.add_rule(Box::new(ConfigFieldsRule));
         engine
     }
 
@@ -175,7 +179,7 @@ impl Default for ValidationEngine {
 //  Concrete rules
 // ═══════════════════════════════════════════════════════════════════════════
 
-// ─── 1. personalized_pagerank requires teleport ─────────────────────────────
+// ─── 1. personalized_pagerank requires teleport; hits/degree ignore it ──────
 
 struct RankTeleportRule;
 
@@ -190,8 +194,9 @@ impl ValidationRule for RankTeleportRule {
             Some(RankSpec::PersonalizedPagerank { .. })
         );
 
+        let mut out = Vec::new();
         if is_personalized && spec.modules.teleport.is_none() {
-            vec![ValidationDiagnostic::error(
+            out.push(ValidationDiagnostic::error(
                 PipelineSpecError::new(
                     ErrorCode::MissingStage,
                     "/modules/teleport",
@@ -199,12 +204,68 @@ impl ValidationRule for RankTeleportRule {
                 )
                 .with_hint(
                     "Add a teleport module: position, focus_terms, \
-                     topic_weights, or uniform",
+                     topic_weights, section, or uniform",
                 ),
-            )]
-        } else {
-            vec![]
+            ));
+        } else if let (Some(rank @ (RankSpec::Hits { .. } | RankSpec::Degree)), Some(_)) =
+            (&spec.modules.rank, &spec.modules.teleport)
+        {
+            out.push(ValidationDiagnostic::warning(
+                PipelineSpecError::new(
+                    ErrorCode::InvalidCombo,
+                    "/modules/teleport",
+                    format!("{} ranking ignores the teleport module", rank.type_name()),
+                )
+                .with_hint("Remove the teleport module or use personalized_pagerank"),
+            ));
         }
+
+        if let Some(TeleportSpec::Position { shape, half_life }) = &spec.modules.teleport {
+            if let Some(shape) = shape {
+                if PositionDecay::from_shape(shape, None).is_none() {
+                    out.push(ValidationDiagnostic::error(
+                        PipelineSpecError::new(
+                            ErrorCode::InvalidValue,
+                            "/modules/teleport/shape",
+                            format!("unknown position teleport shape '{shape}'"),
+                        )
+                        .with_hint(format!("Use one of: {}", PositionDecay::SHAPES.join(", "))),
+                    ));
+                }
+            }
+            if let Some(half_life) = half_life {
+                if !half_life.is_finite() || *half_life <= 0.0 {
+                    out.push(ValidationDiagnostic::error(
+                        PipelineSpecError::new(
+                            ErrorCode::InvalidValue,
+                            "/modules/teleport/half_life",
+                            format!("half_life must be finite and > 0, got {half_life}"),
+                        )
+                        .with_hint("Use e.g. 50.0 to halve an occurrence's weight every 50 tokens"),
+                    ));
+                }
+            }
+        }
+
+        if let Some(TeleportSpec::Section {
+            boosts: Some(boosts),
+        }) = &spec.modules.teleport
+        {
+            for (section, boost) in boosts {
+                if !boost.is_finite() || *boost < 0.0 {
+                    out.push(ValidationDiagnostic::error(
+                        PipelineSpecError::new(
+                            ErrorCode::InvalidValue,
+                            format!("/modules/teleport/boosts/{}", section.as_str()),
+                            format!("section boost must be finite and >= 0, got {boost}"),
+                        )
+                        .with_hint("Use e.g. 3.0 to make title words 3x likelier teleport targets"),
+                    ));
+                }
+            }
+        }
+
+        out
     }
 }
 
@@ -238,7 +299,7 @@ impl ValidationRule for TopicGraphDepsRule {
 
         if !matches!(
             spec.modules.candidates,
-            Some(CandidatesSpec::PhraseCandidates)
+            Some(CandidatesSpec::PhraseCandidates | CandidatesSpec::NounChunks)
         ) {
             out.push(ValidationDiagnostic::error(
                 PipelineSpecError::new(
@@ -300,11 +361,14 @@ impl ValidationRule for RuntimeLimitsRule {
     fn validate(&self, spec: &PipelineSpecV1) -> Vec<ValidationDiagnostic> {
         let mut out = Vec::new();
 
-        let checks: &[(&str, Option<usize>)] = &[
-            ("max_tokens", spec.runtime.max_tokens),
-            ("max_nodes", spec.runtime.max_nodes),
-            ("max_edges", spec.runtime.max_edges),
-            ("max_threads", spec.runtime.max_threads),
+        let as_u64 = |v: Option<usize>| v.map(|v| v as u64);
+        let checks: &[(&str, Option<u64>)] = &[
+            ("max_tokens", as_u64(spec.runtime.max_tokens)),
+            ("max_nodes", as_u64(spec.runtime.max_nodes)),
+            ("max_edges", as_u64(spec.runtime.max_edges)),
+            ("max_threads", as_u64(spec.runtime.max_threads)),
+            ("timeout_ms", spec.runtime.timeout_ms),
+            ("stage_timeout_ms", spec.runtime.stage_timeout_ms),
         ];
 
         for &(field, value) in checks {
@@ -326,7 +390,82 @@ impl ValidationRule for RuntimeLimitsRule {
     }
 }
 
-// ─── 5. Unknown fields (strict → error, non-strict → warning) ──────────────
+// ─── 5. Graph and graph transform parameters must be in range ───────────────
+
+struct GraphTransformParamsRule;
+
+impl ValidationRule for GraphTransformParamsRule {
+    fn name(&self) -> &str {
+        "graph_transform_params"
+    }
+
+    fn validate(&self, spec: &PipelineSpecV1) -> Vec<ValidationDiagnostic> {
+        let mut out = Vec::new();
+
+        if let Some(GraphSpec::CooccurrenceWindow {
+            edge_weighting: Some(EdgeWeightingSpec::ExponentialDecay { tau }),
+            ..
+        }) = &spec.modules.graph
+        {
+            if tau.is_nan() || *tau <= 0.0 {
+                out.push(ValidationDiagnostic::error(
+                    PipelineSpecError::new(
+                        ErrorCode::InvalidValue,
+                        "/modules/graph/edge_weighting/exponential_decay/tau",
+                        format!("exponential_decay tau must be > 0, got {tau}"),
+                    )
+                    .with_hint("Use e.g. 3.0 to halve a pair's weight about every 2 tokens"),
+                ));
+            }
+        }
+
+        for (i, transform) in spec.modules.graph_transforms.iter().enumerate() {
+            if let GraphTransformSpec::MetaVertex { min_count: Some(0) } = transform {
+                out.push(ValidationDiagnostic::error(
+                    PipelineSpecError::new(
+                        ErrorCode::InvalidValue,
+                        format!("/modules/graph_transforms/{i}/min_count"),
+                        "meta_vertex min_count must be >= 1",
+                    )
+                    .with_hint("Use e.g. 2 to merge pairs seen next to each other twice"),
+                ));
+            }
+            if let GraphTransformSpec::EdgeThreshold {
+                min_weight,
+                quantile,
+            } = transform
+            {
+                let path = format!("/modules/graph_transforms/{i}");
+                if min_weight.is_some() && quantile.is_some() {
+                    out.push(ValidationDiagnostic::error(
+                        PipelineSpecError::new(
+                            ErrorCode::InvalidCombo,
+                            path.clone(),
+                            "edge_threshold accepts either min_weight or quantile, not both",
+                        )
+                        .with_hint("Remove one of min_weight / quantile"),
+                    ));
+                }
+                if let Some(q) = quantile {
+                    if !(0.0..=1.0).contains(q) {
+                        out.push(ValidationDiagnostic::error(
+                            PipelineSpecError::new(
+                                ErrorCode::InvalidValue,
+                                format!("{path}/quantile"),
+                                format!("quantile must be between 0 and 1, got {q}"),
+                            )
+                            .with_hint("Use e.g. 0.5 to drop the lighter half of the edges"),
+                        ));
+                    }
+                }
+            }
+        }
+
+        out
+    }
+}
+
+// ─── 6. Unknown fields (strict → error, non-strict → warning) ──────────────
 
 struct UnknownFieldsRule;
 
@@ -381,6 +520,78 @@ impl ValidationRule for UnknownFieldsRule {
     }
 }
 
+// ─── 7. Config overrides must name real fields with valid values ────────────
+
+struct ConfigFieldsRule;
+
+impl ValidationRule for ConfigFieldsRule {
+    fn name(&self) -> &str {
+        "config_fields"
+    }
+
+    fn validate(&self, spec: &PipelineSpecV1) -> Vec<ValidationDiagnostic> {
+        if spec.config.is_empty() {
+            return Vec::new();
+        }
+        let defaults = config_fields(&TextRankConfig::default());
+        let mut keys: Vec<_> = spec.config.keys().collect();
+        keys.sort();
+
+        let mut out = Vec::new();
+        for key in keys {
+            let path = format!("/config/{key}");
+            if !defaults.contains_key(key) {
+                let diag_fn = if spec.strict {
+                    ValidationDiagnostic::error
+                } else {
+                    ValidationDiagnostic::warning
+                };
+                out.push(diag_fn(
+                    PipelineSpecError::new(
+                        ErrorCode::UnknownField,
+                        path,
+                        format!("unrecognized config field \"{key}\""),
+                    )
+                    .with_hint("Use a TextRankConfig field name, e.g. damping or top_n"),
+                ));
+                continue;
+            }
+            let mut fields = defaults.clone();
+            fields.insert(key.clone(), spec.config[key].clone());
+            if let Err(e) =
+                serde_json::from_value::<TextRankConfig>(serde_json::Value::Object(fields))
+            {
+                out.push(ValidationDiagnostic::error(PipelineSpecError::new(
+                    ErrorCode::InvalidValue,
+                    path,
+                    format!("invalid value for config field \"{key}\": {e}"),
+                )));
+            }
+        }
+        if out.iter().any(|d| d.severity == Severity::Error) {
+            return out;
+        }
+
+        // Each value parses; check ranges and combinations on top of the
+        // defaults.
+        let mut fields = defaults;
+        for (key, value) in &spec.config {
+            if let Some(slot) = fields.get_mut(key) {
+                *slot = value.clone();
+            }
+        }
+        if let Ok(cfg) = serde_json::from_value::<TextRankConfig>(serde_json::Value::Object(fields))
+        {
+            out.extend(
+                cfg.validation_errors()
+                    .iter()
+                    .map(|e| ValidationDiagnostic::error(config_spec_error(e))),
+            );
+        }
+        out
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 //  Tests
 // ═══════════════════════════════════════════════════════════════════════════
@@ -487,6 +698,45 @@ mod tests {
         assert_eq!(errs[0].path, "/modules/teleport");
     }
 
+    #[test]
+    fn test_hits_with_teleport_warns() {
+        let report = engine().validate(&spec(
+            r#"{
+                "v": 1,
+                "modules": {
+                    "rank": { "type": "hits", "score": "hub" },
+                    "teleport": { "type": "position" }
+                }
+            }"#,
+        ));
+        assert!(report.is_valid());
+        let warnings: Vec<_> = report.warnings().collect();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, ErrorCode::InvalidCombo);
+
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "rank": { "type": "hits" } } }"#,
+        ));
+        assert_eq!(report.warnings().count(), 0);
+    }
+
+    #[test]
+    fn test_degree_with_teleport_warns() {
+        let report = engine().validate(&spec(
+            r#"{
+                "v": 1,
+                "modules": {
+                    "rank": { "type": "degree" },
+                    "teleport": { "type": "position" }
+                }
+            }"#,
+        ));
+        assert!(report.is_valid());
+        let warnings: Vec<_> = report.warnings().collect();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("degree"));
+    }
+
     #[test]
     fn test_personalized_with_uniform_teleport_is_valid() {
         let report = engine().validate(&spec(
@@ -539,6 +789,21 @@ mod tests {
         assert_eq!(errs[0].code, ErrorCode::InvalidCombo);
     }
 
+    #[test]
+    fn test_topic_graph_accepts_noun_chunks() {
+        let report = engine().validate(&spec(
+            r#"{
+                "v": 1,
+                "modules": {
+                    "candidates": { "type": "noun_chunks" },
+                    "graph": { "type": "topic_graph" },
+                    "clustering": { "type": "hac" }
+                }
+            }"#,
+        ));
+        assert!(!report.has_errors());
+    }
+
     #[test]
     fn test_topic_graph_missing_both_deps_reports_two_errors() {
         let report = engine().validate(&spec(
@@ -875,6 +1140,66 @@ mod tests {
         assert!(paths.contains(&"/runtime/max_edges".to_string()));
     }
 
+    #[test]
+    fn test_config_fields_rule() {
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "config": { "top_n": 5, "dampnig": 0.9, "window_size": "wide" } }"#,
+        ));
+        let errors: Vec<_> = report.errors().collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, ErrorCode::InvalidValue);
+        assert_eq!(errors[0].path, "/config/window_size");
+        let warnings: Vec<_> = report.warnings().collect();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, ErrorCode::UnknownField);
+        assert_eq!(warnings[0].path, "/config/dampnig");
+
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "strict": true, "config": { "dampnig": 0.9 } }"#,
+        ));
+        assert_eq!(
+            report.errors().next().unwrap().code,
+            ErrorCode::UnknownField
+        );
+    }
+
+    #[test]
+    fn test_config_fields_rule_checks_ranges_and_combos() {
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "config": { "window_size": 0, "min_phrase_length": 4, "max_phrase_length": 2 } }"#,
+        ));
+        let errors: Vec<_> = report.errors().map(|e| (e.code, e.path.as_str())).collect();
+        assert_eq!(
+            errors,
+            [
+                (ErrorCode::InvalidValue, "/config/window_size"),
+                (ErrorCode::InvalidCombo, "/config/max_phrase_length"),
+            ]
+        );
+
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "config": { "near_duplicates": { "max_distance": 2.0 } } }"#,
+        ));
+        assert_eq!(
+            report.errors().next().unwrap().path,
+            "/config/near_duplicates/max_distance"
+        );
+    }
+
+    #[test]
+    fn test_zero_timeouts_rejected() {
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "runtime": { "timeout_ms": 0, "stage_timeout_ms": 0 } }"#,
+        ));
+        let paths: Vec<_> = report.errors().map(|e| e.path.clone()).collect();
+        assert_eq!(paths, ["/runtime/timeout_ms", "/runtime/stage_timeout_ms"]);
+
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "runtime": { "timeout_ms": 250, "stage_timeout_ms": 100 } }"#,
+        ));
+        assert!(report.is_valid());
+    }
+
     #[test]
     fn test_runtime_limit_hints_mention_removal() {
         let report = engine().validate(&spec(r#"{ "v": 1, "runtime": { "max_tokens": 0 } }"#));
@@ -1035,7 +1360,13 @@ mod tests {
 
     #[test]
     fn test_all_teleport_types_valid_with_personalized() {
-        for teleport in &["uniform", "position", "focus_terms", "topic_weights"] {
+        for teleport in &[
+            "uniform",
+            "position",
+            "focus_terms",
+            "topic_weights",
+            "section",
+        ] {
             let json = format!(
                 r#"{{
                     "v": 1,
@@ -1246,6 +1577,96 @@ mod tests {
         assert!(report.is_valid());
     }
 
+    #[test]
+    fn test_exponential_decay_tau() {
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "graph": { "type": "cooccurrence_window", "edge_weighting": { "exponential_decay": { "tau": 2.0 } } } } }"#,
+        ));
+        assert!(report.is_valid());
+
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "graph": { "type": "cooccurrence_window", "edge_weighting": { "exponential_decay": { "tau": 0.0 } } } } }"#,
+        ));
+        let errs: Vec<_> = report.errors().collect();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].code, ErrorCode::InvalidValue);
+        assert_eq!(
+            errs[0].path,
+            "/modules/graph/edge_weighting/exponential_decay/tau"
+        );
+    }
+
+    #[test]
+    fn test_position_teleport_shape() {
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "teleport": { "type": "position", "shape": "exponential", "half_life": 20.0 } } }"#,
+        ));
+        assert!(report.is_valid());
+
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "teleport": { "type": "position", "shape": "linear", "half_life": 0.0 } } }"#,
+        ));
+        let paths: Vec<_> = report.errors().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["/modules/teleport/shape", "/modules/teleport/half_life"]
+        );
+    }
+
+    #[test]
+    fn test_section_teleport_boosts() {
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "teleport": { "type": "section", "boosts": { "title": 4.0, "body": 0.5 } } } }"#,
+        ));
+        assert!(report.is_valid());
+
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "teleport": { "type": "section", "boosts": { "abstract": -1.0 } } } }"#,
+        ));
+        let errs: Vec<_> = report.errors().collect();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].code, ErrorCode::InvalidValue);
+        assert_eq!(errs[0].path, "/modules/teleport/boosts/abstract");
+    }
+
+    #[test]
+    fn test_meta_vertex_params() {
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "graph_transforms": [{ "type": "meta_vertex", "min_count": 3 }] } }"#,
+        ));
+        assert!(report.is_valid());
+
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "graph_transforms": [{ "type": "meta_vertex", "min_count": 0 }] } }"#,
+        ));
+        let errs: Vec<_> = report.errors().collect();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].path, "/modules/graph_transforms/0/min_count");
+    }
+
+    #[test]
+    fn test_edge_threshold_params() {
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "graph_transforms": [{ "type": "edge_threshold", "quantile": 0.5 }] } }"#,
+        ));
+        assert!(report.is_valid());
+
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "graph_transforms": [{ "type": "edge_threshold", "quantile": 1.5 }] } }"#,
+        ));
+        let errs: Vec<_> = report.errors().collect();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].code, ErrorCode::InvalidValue);
+        assert_eq!(errs[0].path, "/modules/graph_transforms/0/quantile");
+
+        let report = engine().validate(&spec(
+            r#"{ "v": 1, "modules": { "graph_transforms": [{ "type": "edge_threshold", "min_weight": 2.0, "quantile": 0.5 }] } }"#,
+        ));
+        let errs: Vec<_> = report.errors().collect();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].code, ErrorCode::InvalidCombo);
+    }
+
     #[test]
     fn test_spec_with_preset_is_valid() {
         let report = engine().validate(&spec(r#"{ "v": 1, "preset": "textrank" }"#));
@@ -0,0 +1,289 @@
+//! Ranked-graph snapshots for visualization.
+//!
+//! [`GraphSnapshot`] is a self-contained JSON picture of a word graph after
+//! ranking — nodes with lemma, POS tag, score and degree, and undirected
+//! edges with weights — in the `{ "nodes": [...], "edges": [...] }` shape
+//! D3 force layouts and Gephi's JSON importer load directly. Large graphs
+//! can be down-sampled to the top-N nodes by score plus their neighbors.
+//!
+//! [`VisualizationObserver`] writes one snapshot per run to
+//! `graph_<doc>.json` in a target directory, where `<doc>` counts runs
+//! observed so far (0-based).
+
+use crate::pipeline::artifacts::{Graph, RankOutput};
+use crate::pipeline::observer::PipelineObserver;
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// A graph node in a [`GraphSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotNode {
+    /// Node ID in the ranked graph.
+    pub id: u32,
+    /// Lemma, without the POS suffix of `"lemma|POS"` node keys.
+    pub lemma: String,
+    /// POS tag, when nodes are keyed by lemma and POS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pos: Option<String>,
+    /// Rank score.
+    pub score: f64,
+    /// Number of neighbors in the full graph.
+    pub degree: usize,
+}
+
+/// An undirected weighted edge in a [`GraphSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotEdge {
+    /// Node ID of the lower-numbered endpoint.
+    pub source: u32,
+    /// Node ID of the higher-numbered endpoint.
+    pub target: u32,
+    /// Edge weight.
+    pub weight: f64,
+}
+
+/// Ranked graph nodes and edges, optionally down-sampled.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphSnapshot {
+    /// Nodes, best score first (ties by ID).
+    pub nodes: Vec<SnapshotNode>,
+    /// Edges between the kept nodes, ordered by `(source, target)`.
+    pub edges: Vec<SnapshotEdge>,
+    /// Number of nodes in the full graph.
+    pub total_nodes: usize,
+    /// Number of undirected edges in the full graph.
+    pub total_edges: usize,
+}
+
+impl GraphSnapshot {
+    /// Snapshot `graph` with the scores in `rank`.
+    ///
+    /// With `top_n`, keeps only the `top_n` best-scoring nodes and their
+    /// direct neighbors.
+    pub fn build(graph: &Graph, rank: &RankOutput, top_n: Option<usize>) -> Self {
+        let n = graph.num_nodes();
+        let mut order: Vec<u32> = (0..n as u32).collect();
+        order.sort_by(|&a, &b| rank.score(b).total_cmp(&rank.score(a)).then(a.cmp(&b)));
+
+        let mut kept = vec![top_n.is_none(); n];
+        if let Some(top_n) = top_n {
+            for &node in order.iter().take(top_n) {
+                kept[node as usize] = true;
+                for (neighbor, _) in graph.neighbors(node) {
+                    kept[neighbor as usize] = true;
+                }
+            }
+        }
+
+        let nodes = order
+            .iter()
+            .filter(|&&id| kept[id as usize])
+            .map(|&id| {
+                let key = graph.lemma(id);
+                let (lemma, pos) = match key.split_once('|') {
+                    Some((lemma, pos)) => (lemma, Some(pos.to_string())),
+                    None => (key, None),
+                };
+                SnapshotNode {
+                    id,
+                    lemma: lemma.to_string(),
+                    pos,
+                    score: rank.score(id),
+                    degree: graph.neighbors(id).count(),
+                }
+            })
+            .collect();
+
+        let mut total_edges = 0;
+        let mut edges = Vec::new();
+        for source in 0..n as u32 {
+            for (target, weight) in graph.neighbors(source) {
+                if target <= source {
+                    continue;
+                }
+                total_edges += 1;
+                if kept[source as usize] && kept[target as usize] {
+                    edges.push(SnapshotEdge {
+                        source,
+                        target,
+                        weight,
+                    });
+                }
+            }
+        }
+
+        Self {
+            nodes,
+            edges,
+            total_nodes: n,
+            total_edges,
+        }
+    }
+}
+
+/// Observer writing a [`GraphSnapshot`] of each run's ranked graph.
+///
+/// I/O errors cannot be returned from observer callbacks; the first one is
+/// kept and reported by [`finish`](Self::finish), and later writes are
+/// skipped.
+///
+/// ```no_run
+/// use rapid_textrank::pipeline::runner::BaseTextRankPipeline;
+/// use rapid_textrank::pipeline::visualization::VisualizationObserver;
+/// use rapid_textrank::TextRankConfig;
+///
+/// let mut viz = VisualizationObserver::new("/tmp/textrank-graphs")?.with_top_n(50);
+/// let cfg = TextRankConfig::default();
+/// BaseTextRankPipeline::base_textrank().run_text("Graphs have nodes.", &cfg, &mut viz);
+/// viz.finish()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct VisualizationObserver {
+    dir: PathBuf,
+    top_n: Option<usize>,
+    /// Graph of the current run, held until its scores arrive.
+    graph: Option<Graph>,
+    /// Number of snapshots written.
+    docs: u64,
+    error: Option<io::Error>,
+}
+
+impl VisualizationObserver {
+    /// Create `dir` if needed; snapshots are written into it.
+    pub fn new(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            top_n: None,
+            graph: None,
+            docs: 0,
+            error: None,
+        })
+    }
+
+    /// Down-sample each snapshot to the `top_n` best nodes and their
+    /// neighbors (default: the whole graph).
+    pub fn with_top_n(mut self, top_n: usize) -> Self {
+        self.top_n = Some(top_n);
+        self
+    }
+
+    /// Directory the snapshots are written to.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Number of snapshots written so far.
+    pub fn snapshots_written(&self) -> u64 {
+        self.docs
+    }
+
+    /// Return the first I/O error seen, if any.
+    pub fn finish(self) -> io::Result<()> {
+        self.error.map_or(Ok(()), Err)
+    }
+
+    fn write(&self, snapshot: &GraphSnapshot) -> io::Result<()> {
+        let path = self.dir.join(format!("graph_{}.json", self.docs));
+        let mut writer = BufWriter::new(std::fs::File::create(path)?);
+        serde_json::to_writer(&mut writer, snapshot).map_err(io::Error::from)?;
+        writer.flush()
+    }
+}
+
+impl PipelineObserver for VisualizationObserver {
+    fn on_graph(&mut self, graph: &Graph) {
+        self.graph = Some(graph.clone());
+    }
+
+    fn on_rank(&mut self, rank: &RankOutput) {
+        let Some(graph) = self.graph.take() else {
+            return;
+        };
+        if self.error.is_some() {
+            return;
+        }
+        let snapshot = GraphSnapshot::build(&graph, rank, self.top_n);
+        match self.write(&snapshot) {
+            Ok(()) => self.docs += 1,
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pipeline::runner::BaseTextRankPipeline;
+    use crate::pipeline::traits::Ranker;
+    use crate::pipeline::PageRankRanker;
+    use crate::types::TextRankConfig;
+
+    const TEXT: &str = "Machine learning models learn from data. \
+        Deep learning is a kind of machine learning.";
+
+    fn ranked_graph() -> (Graph, RankOutput) {
+        let mut builder = crate::graph::builder::GraphBuilder::new();
+        let hub = builder.get_or_create_node("hub|NOUN");
+        for leaf in ["a|NOUN", "b|NOUN", "c|NOUN"] {
+            let leaf = builder.get_or_create_node(leaf);
+            builder.increment_edge(hub, leaf, 1.0);
+        }
+        let d = builder.get_or_create_node("d|NOUN");
+        let e = builder.get_or_create_node("e|NOUN");
+        builder.increment_edge(d, e, 2.0);
+        let graph = Graph::from_builder(&builder);
+        let rank = PageRankRanker.rank(&graph, None, &TextRankConfig::default());
+        (graph, rank)
+    }
+
+    #[test]
+    fn test_snapshot_full_graph() {
+        let (graph, rank) = ranked_graph();
+        let snapshot = GraphSnapshot::build(&graph, &rank, None);
+
+        assert_eq!(snapshot.total_nodes, 6);
+        assert_eq!(snapshot.total_edges, 4);
+        assert_eq!(snapshot.nodes.len(), 6);
+        assert_eq!(snapshot.edges.len(), 4);
+        assert_eq!(snapshot.nodes[0].lemma, "hub");
+        assert_eq!(snapshot.nodes[0].pos.as_deref(), Some("NOUN"));
+        assert_eq!(snapshot.nodes[0].degree, 3);
+        assert!(snapshot.edges.iter().all(|e| e.source < e.target));
+    }
+
+    #[test]
+    fn test_snapshot_top_n_keeps_neighbors() {
+        let (graph, rank) = ranked_graph();
+        let snapshot = GraphSnapshot::build(&graph, &rank, Some(1));
+
+        let mut lemmas: Vec<&str> = snapshot.nodes.iter().map(|n| n.lemma.as_str()).collect();
+        lemmas.sort_unstable();
+        assert_eq!(lemmas, vec!["a", "b", "c", "hub"]);
+        assert_eq!(snapshot.edges.len(), 3);
+        assert_eq!(snapshot.total_nodes, 6);
+    }
+
+    #[test]
+    fn test_observer_writes_one_snapshot_per_run() {
+        let dir = std::env::temp_dir().join(format!("rapid_textrank_viz_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let pipeline = BaseTextRankPipeline::base_textrank();
+        let cfg = TextRankConfig::default();
+        let mut viz = VisualizationObserver::new(&dir).unwrap().with_top_n(2);
+        pipeline.run_text(TEXT, &cfg, &mut viz);
+        pipeline.run_text(TEXT, &cfg, &mut viz);
+        assert_eq!(viz.snapshots_written(), 2);
+        viz.finish().unwrap();
+
+        let json = std::fs::read_to_string(dir.join("graph_1.json")).unwrap();
+        let snapshot: GraphSnapshot = serde_json::from_str(&json).unwrap();
+        assert!(!snapshot.nodes.is_empty());
+        assert!(snapshot.nodes.len() <= snapshot.total_nodes);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
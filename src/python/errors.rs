@@ -0,0 +1,96 @@
+//! Python exceptions
+//!
+//! [`TextRankError`] is raised for pipeline, spec, and configuration errors.
+//! It subclasses `ValueError`, so existing `except ValueError` handlers keep
+//! working, and carries the structured fields of the Rust error as
+//! attributes:
+//!
+//! - `code` — the stable [`ErrorCode`] string (e.g. `"limit_exceeded"`),
+//!   or `None` when the error has no code
+//! - `path` — JSON-pointer-like location in the spec (`None` if unknown)
+//! - `stage` — pipeline stage that failed (runtime errors only)
+//! - `hint` — optional remediation hint
+//!
+//! ```python
+//! try:
+//!     extract_keyphrases(text, variant="nope")
+//! except TextRankError as e:
+//!     if e.code == "invalid_value":
+//!         ...
+//! ```
+
+use crate::pipeline::error_code::ErrorCode;
+use crate::pipeline::errors::{PipelineRuntimeError, PipelineSpecError};
+use pyo3::prelude::*;
+
+pyo3::create_exception!(
+    rapid_textrank,
+    TextRankError,
+    pyo3::exceptions::PyValueError,
+    "Extraction error carrying a stable `code` plus `path`, `stage`, and `hint`."
+);
+
+/// Build a [`TextRankError`] with the given attributes.
+fn new_error(
+    message: String,
+    code: Option<ErrorCode>,
+    path: Option<&str>,
+    stage: Option<&str>,
+    hint: Option<&str>,
+) -> PyErr {
+    Python::with_gil(|py| {
+        let err = TextRankError::new_err(message);
+        let value = err.value(py);
+        let attrs = [
+            ("code", code.map(|c| c.as_str())),
+            ("path", path),
+            ("stage", stage),
+            ("hint", hint),
+        ];
+        for (name, attr) in attrs {
+            if let Err(e) = value.setattr(name, attr) {
+                return e;
+            }
+        }
+        err
+    })
+}
+
+/// Convert a pipeline runtime error.
+pub fn runtime_error(err: &PipelineRuntimeError) -> PyErr {
+    new_error(
+        err.to_string(),
+        Some(err.code),
+        Some(&err.path),
+        Some(&err.stage),
+        err.hint.as_deref(),
+    )
+}
+
+/// Convert a pipeline spec error.
+pub fn spec_error(err: &PipelineSpecError) -> PyErr {
+    new_error(
+        err.to_string(),
+        Some(err.code),
+        Some(&err.path),
+        None,
+        err.hint.as_deref(),
+    )
+}
+
+/// Convert a configuration validation error, keeping its code and the
+/// offending field as `path` (e.g. `"/window_size"`).
+pub fn config_error(err: &crate::TextRankError) -> PyErr {
+    new_error(
+        err.to_string(),
+        Some(err.config_code().unwrap_or(ErrorCode::InvalidValue)),
+        err.config_path().as_deref(),
+        None,
+        None,
+    )
+}
+
+/// A [`TextRankError`] without a code, for errors that have none.
+pub fn uncoded_error(message: impl Into<String>) -> PyErr {
+    new_error(message.into(), None, None, None, None)
+}
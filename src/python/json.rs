@@ -3,11 +3,13 @@
 //! For large documents or batch processing, the JSON interface
 //! minimizes Python↔Rust overhead by passing pre-tokenized data.
 
+use crate::nlp::token_filter::JunkTokenFilter;
 use crate::phrase::chunker::NounChunker;
 use crate::phrase::extraction::extract_keyphrases_with_info;
 use crate::pipeline::artifacts::{DebugPayload, FormattedResult, PipelineWorkspace, TokenStream};
 use crate::pipeline::error_code::ErrorCode;
 use crate::pipeline::errors::PipelineRuntimeError;
+use crate::pipeline::json_output::JsonOutput;
 use crate::pipeline::observer::{NoopObserver, StageTimingObserver};
 #[cfg(feature = "sentence-rank")]
 use crate::pipeline::runner::SentenceRankPipeline;
@@ -17,7 +19,8 @@ use crate::pipeline::spec::{
 use crate::pipeline::spec_builder::SpecPipelineBuilder;
 use crate::pipeline::validation::{ValidationDiagnostic, ValidationEngine, ValidationReport};
 use crate::types::{
-    DeterminismMode, PhraseGrouping, PosTag, ScoreAggregation, TextRankConfig, Token,
+    DeterminismMode, NodeKey, PhraseGrouping, PosTag, ScoreAggregation, Section, SharedStringPool,
+    TextRankConfig, Token,
 };
 use crate::variants::biased_textrank::BiasedTextRank;
 use crate::variants::multipartite_rank::MultipartiteRank;
@@ -30,6 +33,7 @@ use pyo3::prelude::*;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 // ─── DocError ─────────────────────────────────────────────────────────────────
 //
@@ -85,6 +89,9 @@ pub struct JsonToken {
     pub token_idx: usize,
     #[serde(default)]
     pub is_stopword: bool,
+    /// Document section: `"title"`, `"abstract"`, `"heading"` or `"body"`
+    #[serde(default)]
+    pub section: Option<Section>,
 }
 
 impl From<JsonToken> for Token {
@@ -98,6 +105,7 @@ impl From<JsonToken> for Token {
             sentence_idx: jt.sentence_idx,
             token_idx: jt.token_idx,
             is_stopword: jt.is_stopword,
+            section: jt.section,
         }
     }
 }
@@ -123,6 +131,10 @@ pub struct JsonDocument {
     /// supported versions, presets, and module types.
     #[serde(default)]
     pub capabilities: bool,
+    /// Caller-supplied document id, written to the `doc_id` column of
+    /// CSV/TSV output.
+    #[serde(default)]
+    pub id: Option<String>,
 }
 
 /// Configuration from JSON
@@ -142,22 +154,52 @@ pub struct JsonConfig {
     pub min_phrase_length: usize,
     #[serde(default = "default_max_length")]
     pub max_phrase_length: usize,
+    /// Minimum words per phrase; omitted disables it
+    #[serde(default)]
+    pub min_phrase_words: Option<usize>,
+    /// Maximum words per phrase; omitted disables it
+    #[serde(default)]
+    pub max_phrase_words: Option<usize>,
+    /// Minimum candidate occurrences per phrase; omitted disables it
+    #[serde(default)]
+    pub min_phrase_frequency: Option<usize>,
     #[serde(default)]
     pub score_aggregation: String,
     #[serde(default = "default_language")]
     pub language: String,
+    /// Detect each raw-text document's language (requires the
+    /// `lang-detect` feature); JSON documents are pre-tokenized and keep
+    /// `language`
+    #[serde(default)]
+    pub detect_language: bool,
     #[serde(default)]
     pub phrase_grouping: String,
     #[serde(default = "default_use_edge_weights")]
     pub use_edge_weights: bool,
     #[serde(default = "default_use_pos_in_nodes")]
     pub use_pos_in_nodes: bool,
+    /// Graph node key strategy (`"lemma"`, `"lemma_pos"`, `"surface"` or
+    /// `"lowercase_surface"`); overrides `use_pos_in_nodes` when set
+    #[serde(default)]
+    pub node_key: Option<NodeKey>,
     /// POS tags to include (e.g., ["NOUN", "ADJ", "PROPN"])
     #[serde(default)]
     pub include_pos: Vec<String>,
     /// Additional stopwords list (extends built-in list when provided)
     #[serde(default)]
     pub stopwords: Vec<String>,
+    /// Words allowed to join noun phrases, e.g. `["of"]`
+    #[serde(default)]
+    pub phrase_bridge_words: Vec<String>,
+    /// Junk-token filters, e.g. `{"min_length": 2, "drop_numerals": true}`
+    #[serde(default)]
+    pub token_filter: crate::types::TokenFilter,
+    /// Phrases never returned, e.g. `[{"exact": "figure 1"}, {"regex": "^table \\d+$"}]`
+    #[serde(default)]
+    pub phrase_blocklist: Vec<crate::types::PhrasePattern>,
+    /// Terms kept as candidates despite stopwords and the blocklist
+    #[serde(default)]
+    pub keep_terms: Vec<String>,
     #[serde(default)]
     pub focus_terms: Vec<String>,
     #[serde(default = "default_bias_weight")]
@@ -181,6 +223,48 @@ pub struct JsonConfig {
     /// Determinism mode: "default" (fastest) or "deterministic" (reproducible)
     #[serde(default)]
     pub determinism: String,
+    /// PageRank precision: "f64" (default) or "f32"
+    #[serde(default)]
+    pub rank_precision: String,
+    /// PageRank acceleration: "none" (default), "aitken" or "quadratic_extrapolation"
+    #[serde(default)]
+    pub acceleration: String,
+    /// Dangling-node policy: "personalization" (default), "uniform" or "self_loop"
+    #[serde(default)]
+    pub dangling_policy: String,
+    /// Convergence norm: "l1" (default), "l2", "linf" or "relative"
+    #[serde(default)]
+    pub convergence_criterion: String,
+    /// Non-convergence policy: "continue" (default), "warn_and_continue" or "fail"
+    #[serde(default)]
+    pub non_convergence: String,
+    /// MMR diversification lambda in [0, 1]; omitted disables it
+    #[serde(default)]
+    pub mmr_lambda: Option<f64>,
+    /// Near-duplicate merging, e.g. `{"max_distance": 0.2, "distance":
+    /// "jaro_winkler", "score": "sum"}`; omitted disables it
+    #[serde(default)]
+    pub near_duplicates: Option<crate::types::NearDuplicateMerge>,
+    /// Phrases to return: "top_n" (default), "elbow",
+    /// `{"score_threshold": {"min_score": 0.05}}` or
+    /// `{"proportion": {"fraction": 0.05}}`
+    #[serde(default)]
+    pub phrase_selection: crate::types::PhraseSelection,
+    /// Phrase spans to report: "all" (default) or "first"
+    #[serde(default)]
+    pub span_mode: String,
+    /// Surface form of a phrase: "first_occurrence", "most_frequent",
+    /// "longest" or "lemma"; omitted uses the grouping's own choice
+    #[serde(default)]
+    pub surface_selection: Option<crate::types::SurfaceSelection>,
+    /// Overlapping candidates: "keep_highest_scored" (default),
+    /// "keep_longest", "keep_all" or "merge_maximal"
+    #[serde(default)]
+    pub overlap_policy: crate::types::OverlapPolicy,
+    /// Score normalization: "raw" (default), "min_max", "sum" or
+    /// `{"softmax": {"temperature": 0.5}}`
+    #[serde(default)]
+    pub score_normalization: crate::types::ScoreNormalization,
 }
 
 fn default_use_edge_weights() -> bool {
@@ -248,13 +332,22 @@ impl Default for JsonConfig {
             top_n: default_top_n(),
             min_phrase_length: default_min_length(),
             max_phrase_length: default_max_length(),
+            min_phrase_words: None,
+            max_phrase_words: None,
+            min_phrase_frequency: None,
             score_aggregation: String::new(),
             language: default_language(),
+            detect_language: false,
             phrase_grouping: default_phrase_grouping(),
             use_edge_weights: default_use_edge_weights(),
             use_pos_in_nodes: true,
+            node_key: None,
             include_pos: Vec::new(),
             stopwords: Vec::new(),
+            phrase_bridge_words: Vec::new(),
+            token_filter: crate::types::TokenFilter::default(),
+            phrase_blocklist: Vec::new(),
+            keep_terms: Vec::new(),
             focus_terms: Vec::new(),
             bias_weight: default_bias_weight(),
             topic_similarity_threshold: default_topic_similarity_threshold(),
@@ -264,6 +357,18 @@ impl Default for JsonConfig {
             multipartite_alpha: default_multipartite_alpha(),
             multipartite_similarity_threshold: default_multipartite_similarity_threshold(),
             determinism: String::new(),
+            rank_precision: String::new(),
+            acceleration: String::new(),
+            dangling_policy: String::new(),
+            convergence_criterion: String::new(),
+            non_convergence: String::new(),
+            mmr_lambda: None,
+            near_duplicates: None,
+            phrase_selection: Default::default(),
+            span_mode: String::new(),
+            surface_selection: None,
+            overlap_policy: crate::types::OverlapPolicy::KeepHighestScored,
+            score_normalization: crate::types::ScoreNormalization::Raw,
         }
     }
 }
@@ -300,12 +405,21 @@ impl From<JsonConfig> for TextRankConfig {
             top_n: jc.top_n,
             min_phrase_length: jc.min_phrase_length,
             max_phrase_length: jc.max_phrase_length,
+            min_phrase_words: jc.min_phrase_words,
+            max_phrase_words: jc.max_phrase_words,
+            min_phrase_frequency: jc.min_phrase_frequency,
             score_aggregation: aggregation,
             language: jc.language,
+            detect_language: jc.detect_language,
             use_edge_weights: jc.use_edge_weights,
             include_pos,
             stopwords: jc.stopwords,
+            phrase_bridge_words: jc.phrase_bridge_words,
+            token_filter: jc.token_filter,
+            phrase_blocklist: jc.phrase_blocklist,
+            keep_terms: jc.keep_terms,
             use_pos_in_nodes: jc.use_pos_in_nodes,
+            node_key: jc.node_key,
             phrase_grouping: jc.phrase_grouping.parse().unwrap_or(PhraseGrouping::Lemma),
             determinism: match jc.determinism.to_lowercase().as_str() {
                 "deterministic" => DeterminismMode::Deterministic,
@@ -315,6 +429,49 @@ impl From<JsonConfig> for TextRankConfig {
             debug_top_k: crate::pipeline::artifacts::DebugLevel::DEFAULT_TOP_K,
             max_nodes: None,
             max_edges: None,
+            stage_timeout_ms: None,
+            pipeline_timeout_ms: None,
+            lemma_strategy: crate::types::LemmaStrategy::AsProvided,
+            normalization: crate::types::TextNormalization::default(),
+            variant_linking: crate::types::VariantLinking::default(),
+            parallel_threshold: crate::pagerank::DEFAULT_PARALLEL_THRESHOLD,
+            rank_precision: match jc.rank_precision.to_lowercase().as_str() {
+                "f32" => crate::types::RankPrecision::F32,
+                _ => crate::types::RankPrecision::F64,
+            },
+            acceleration: match jc.acceleration.to_lowercase().as_str() {
+                "aitken" => crate::types::RankerAcceleration::Aitken,
+                "quadratic_extrapolation" | "quadratic" => {
+                    crate::types::RankerAcceleration::QuadraticExtrapolation
+                }
+                _ => crate::types::RankerAcceleration::None,
+            },
+            dangling_policy: match jc.dangling_policy.to_lowercase().as_str() {
+                "uniform" => crate::types::DanglingPolicy::Uniform,
+                "self_loop" => crate::types::DanglingPolicy::SelfLoop,
+                _ => crate::types::DanglingPolicy::Personalization,
+            },
+            convergence_criterion: match jc.convergence_criterion.to_lowercase().as_str() {
+                "l2" => crate::types::ConvergenceCriterion::L2,
+                "linf" => crate::types::ConvergenceCriterion::LInf,
+                "relative" => crate::types::ConvergenceCriterion::Relative,
+                _ => crate::types::ConvergenceCriterion::L1,
+            },
+            non_convergence: match jc.non_convergence.to_lowercase().as_str() {
+                "warn_and_continue" | "warn" => crate::types::NonConvergencePolicy::WarnAndContinue,
+                "fail" => crate::types::NonConvergencePolicy::Fail,
+                _ => crate::types::NonConvergencePolicy::Continue,
+            },
+            mmr_lambda: jc.mmr_lambda,
+            near_duplicates: jc.near_duplicates,
+            phrase_selection: jc.phrase_selection,
+            span_mode: match jc.span_mode.to_lowercase().as_str() {
+                "first" => crate::types::SpanMode::First,
+                _ => crate::types::SpanMode::All,
+            },
+            surface_selection: jc.surface_selection,
+            overlap_policy: jc.overlap_policy,
+            score_normalization: jc.score_normalization,
         }
     }
 }
@@ -376,6 +533,8 @@ pub struct JsonPhrase {
     pub score: f64,
     pub count: usize,
     pub rank: usize,
+    /// Spans of the occurrences, in the units of the input token offsets
+    pub spans: Vec<(usize, usize)>,
 }
 
 /// Output result for JSON
@@ -418,7 +577,12 @@ pub fn build_capabilities() -> CapabilitiesResponse {
     let mut modules = HashMap::new();
     modules.insert("preprocess".into(), vec!["default".into()]);
 
-    let mut candidates = vec!["word_nodes".into(), "phrase_candidates".into()];
+    let mut candidates = vec![
+        "word_nodes".into(),
+        "phrase_candidates".into(),
+        "noun_chunks".into(),
+        "grammar".into(),
+    ];
     #[cfg(feature = "sentence-rank")]
     candidates.push("sentence_candidates".into());
     modules.insert("candidates".into(), candidates);
@@ -434,7 +598,14 @@ pub fn build_capabilities() -> CapabilitiesResponse {
 
     modules.insert(
         "graph_transforms".into(),
-        vec!["remove_intra_cluster_edges".into(), "alpha_boost".into()],
+        vec![
+            "remove_intra_cluster_edges".into(),
+            "alpha_boost".into(),
+            "k_core".into(),
+            "edge_threshold".into(),
+            "degree_threshold".into(),
+            "meta_vertex".into(),
+        ],
     );
     modules.insert(
         "teleport".into(),
@@ -443,20 +614,32 @@ pub fn build_capabilities() -> CapabilitiesResponse {
             "position".into(),
             "focus_terms".into(),
             "topic_weights".into(),
+            "section".into(),
         ],
     );
     modules.insert("clustering".into(), vec!["hac".into()]);
     modules.insert(
         "rank".into(),
-        vec!["standard_pagerank".into(), "personalized_pagerank".into()],
+        vec![
+            "standard_pagerank".into(),
+            "personalized_pagerank".into(),
+            "hits".into(),
+            "degree".into(),
+        ],
     );
 
-    let mut phrases = vec!["chunk_phrases".into()];
+    let mut phrases = vec!["chunk_phrases".into(), "keywords".into()];
     #[cfg(feature = "sentence-rank")]
     phrases.push("sentence_phrases".into());
     modules.insert("phrases".into(), phrases);
 
-    let mut format = vec!["standard_json".into(), "standard_json_with_debug".into()];
+    let mut format = vec![
+        "standard_json".into(),
+        "standard_json_with_debug".into(),
+        "versioned_json".into(),
+        "csv".into(),
+        "tsv".into(),
+    ];
     #[cfg(feature = "sentence-rank")]
     format.push("sentence_json".into());
     modules.insert("format".into(), format);
@@ -534,9 +717,10 @@ fn serialize_result_with_format(
 ///
 /// This is the shared wiring logic for both `process_single_doc` and
 /// `process_single_doc_with_workspace`. It:
-/// 1. Resolves + validates the spec
+/// 1. Resolves + validates the spec and applies its `config` overrides
 /// 2. Applies `expose` → `debug_level` + `debug_top_k`
-/// 3. Applies `runtime.deterministic`, `max_tokens`, `max_nodes`, `max_edges`
+/// 3. Applies `runtime.deterministic`, `max_tokens`, `max_nodes`, `max_edges`,
+///    `timeout_ms`, `stage_timeout_ms`
 /// 4. Builds the pipeline via `SpecPipelineBuilder`
 /// 5. Runs with conditional `StageTimingObserver`
 /// 6. Wraps in `runtime.scoped()` for thread control
@@ -546,6 +730,7 @@ fn run_pipeline_from_spec(
     tokens: &[Token],
     config: &mut TextRankConfig,
     json_config: &JsonConfig,
+    doc_id: &str,
     workspace: Option<&mut PipelineWorkspace>,
     force_single_thread: bool,
 ) -> Result<String, DocError> {
@@ -559,6 +744,11 @@ fn run_pipeline_from_spec(
         return Err(DocError::Other(err.to_string()));
     }
 
+    // 1a. Apply spec-level config overrides
+    *config = resolved
+        .apply_config(config)
+        .map_err(|e| DocError::Other(e.to_string()))?;
+
     // 2. Apply expose → debug_level + debug_top_k
     if let Some(ref expose) = resolved.expose {
         config.debug_level = expose.to_debug_level();
@@ -599,10 +789,19 @@ fn run_pipeline_from_spec(
         config.max_edges = Some(max);
     }
 
+    // 3c. Apply wall-clock budgets (checked between stages)
+    if let Some(ms) = resolved.runtime.timeout_ms {
+        config.pipeline_timeout_ms = Some(ms);
+    }
+    if let Some(ms) = resolved.runtime.stage_timeout_ms {
+        config.stage_timeout_ms = Some(ms);
+    }
+
     // 4. Build pipeline
     let chunks = NounChunker::new()
         .with_min_length(config.min_phrase_length)
         .with_max_length(config.max_phrase_length)
+        .with_bridge_words(&config.phrase_bridge_words)
         .extract_chunks(tokens);
 
     let builder = SpecPipelineBuilder::new()
@@ -621,8 +820,11 @@ fn run_pipeline_from_spec(
     let format_spec = resolved.modules.format.as_ref();
 
     // 5+6. Run pipeline (conditional observer + scoped threading)
-    let stream = TokenStream::from_tokens(tokens);
-    let formatted = resolved.runtime.scoped(|| {
+    let stream = match workspace.as_deref() {
+        Some(ws) => ws.token_stream(tokens),
+        None => TokenStream::from_tokens(tokens),
+    };
+    let (formatted, timing_obs) = resolved.runtime.scoped(|| {
         if use_timings {
             let mut timing_obs = StageTimingObserver::new();
             let result = match workspace {
@@ -632,16 +834,17 @@ fn run_pipeline_from_spec(
                 }
                 None => pipeline.run(stream, config, &mut timing_obs),
             };
-            attach_stage_timings(result, &timing_obs)
+            (attach_stage_timings(result, &timing_obs), Some(timing_obs))
         } else {
             let mut obs = NoopObserver;
-            match workspace {
+            let result = match workspace {
                 Some(ws) => {
                     ws.clear();
                     pipeline.run_with_workspace(stream, config, &mut obs, ws)
                 }
                 None => pipeline.run(stream, config, &mut obs),
-            }
+            };
+            (result, None)
         }
     });
 
@@ -651,6 +854,19 @@ fn run_pipeline_from_spec(
     }
 
     // 7. Serialize with format awareness
+    if let Some(writer) = format_spec.and_then(FormatSpec::delimited_writer) {
+        // Delimited text is returned as a JSON string so batch and JSONL
+        // output stay valid JSON.
+        let text = writer.write_batch([(doc_id, &formatted)]);
+        return serde_json::to_string(&text).map_err(|e| DocError::Other(e.to_string()));
+    }
+    if let Some(FormatSpec::VersionedJson) = format_spec {
+        let mut output = JsonOutput::from_result(&formatted);
+        if let Some(obs) = &timing_obs {
+            output = output.with_stage_reports(obs.reports());
+        }
+        return output.to_json().map_err(|e| DocError::Other(e.to_string()));
+    }
     let json_result = formatted_to_json_result(formatted);
     serialize_result_with_format(&json_result, format_spec)
 }
@@ -667,6 +883,7 @@ fn extraction_to_json_result(result: crate::phrase::extraction::ExtractionResult
                 score: p.score,
                 count: p.count,
                 rank: p.rank,
+                spans: p.spans,
             })
             .collect(),
         converged: result.converged,
@@ -687,6 +904,7 @@ fn formatted_to_json_result(result: crate::pipeline::artifacts::FormattedResult)
                 score: p.score,
                 count: p.count,
                 rank: p.rank,
+                spans: p.spans,
             })
             .collect(),
         converged: result.converged,
@@ -695,6 +913,34 @@ fn formatted_to_json_result(result: crate::pipeline::artifacts::FormattedResult)
     }
 }
 
+/// Flag `config.stopwords` and `config.token_filter` junk on pre-tokenized
+/// input and clear the flag on `config.keep_terms`; other stopword flags are
+/// kept as given.
+fn apply_config_stopwords(config: &TextRankConfig, tokens: &mut [Token]) {
+    if !config.stopwords.is_empty() {
+        let stopwords = crate::nlp::stopwords::StopwordFilter::with_additional(
+            &config.language,
+            &config.stopwords,
+        );
+        for token in tokens.iter_mut() {
+            if stopwords.is_stopword(&token.text) {
+                token.is_stopword = true;
+            }
+        }
+    }
+    JunkTokenFilter::for_config(config).apply(tokens);
+    if !config.keep_terms.is_empty() {
+        let keep: Vec<String> = config.keep_terms.iter().map(|t| t.to_lowercase()).collect();
+        for token in tokens.iter_mut() {
+            if keep.contains(&token.text.to_lowercase())
+                || keep.contains(&token.lemma.to_lowercase())
+            {
+                token.is_stopword = false;
+            }
+        }
+    }
+}
+
 /// Process a single `JsonDocument` → serialized JSON result string.
 ///
 /// Handles capabilities, validate_only, pipeline spec, and legacy variant
@@ -730,21 +976,20 @@ fn process_single_doc(doc: JsonDocument) -> Result<String, DocError> {
     let mut config: TextRankConfig = json_config.clone().into();
     let mut tokens: Vec<Token> = doc.tokens.into_iter().map(Token::from).collect();
 
-    if !config.stopwords.is_empty() {
-        let stopwords = crate::nlp::stopwords::StopwordFilter::with_additional(
-            &config.language,
-            &config.stopwords,
-        );
-        for token in &mut tokens {
-            if stopwords.is_stopword(&token.text) {
-                token.is_stopword = true;
-            }
-        }
-    }
+    apply_config_stopwords(&config, &mut tokens);
 
     // Pipeline path — takes precedence over `variant`
     if let Some(ref spec) = doc.pipeline {
-        return run_pipeline_from_spec(spec, &tokens, &mut config, &json_config, None, false);
+        let doc_id = doc.id.as_deref().unwrap_or_default();
+        return run_pipeline_from_spec(
+            spec,
+            &tokens,
+            &mut config,
+            &json_config,
+            doc_id,
+            None,
+            false,
+        );
     }
 
     // Legacy variant dispatch (fallback when `pipeline` is absent)
@@ -801,25 +1046,17 @@ fn process_single_doc_with_workspace(
     let mut config: TextRankConfig = json_config.clone().into();
     let mut tokens: Vec<Token> = doc.tokens.into_iter().map(Token::from).collect();
 
-    if !config.stopwords.is_empty() {
-        let stopwords = crate::nlp::stopwords::StopwordFilter::with_additional(
-            &config.language,
-            &config.stopwords,
-        );
-        for token in &mut tokens {
-            if stopwords.is_stopword(&token.text) {
-                token.is_stopword = true;
-            }
-        }
-    }
+    apply_config_stopwords(&config, &mut tokens);
 
     // Pipeline path — takes precedence over `variant`
     if let Some(ref spec) = doc.pipeline {
+        let doc_id = doc.id.as_deref().unwrap_or_default();
         return run_pipeline_from_spec(
             spec,
             &tokens,
             &mut config,
             &json_config,
+            doc_id,
             workspace,
             force_single_thread,
         );
@@ -905,6 +1142,10 @@ fn build_batch_pool(num_threads: Option<usize>) -> PyResult<BatchPool> {
 ///
 /// Returns:
 ///     JSON string with extracted phrases, or a validation report
+///
+/// Raises:
+///     TextRankError: if extraction fails; pipeline errors carry `code`,
+///         `path`, `stage`, and `hint`.
 #[pyfunction]
 #[pyo3(signature = (json_input))]
 pub fn extract_from_json(py: Python<'_>, json_input: &str) -> PyResult<String> {
@@ -912,7 +1153,10 @@ pub fn extract_from_json(py: Python<'_>, json_input: &str) -> PyResult<String> {
         .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid JSON: {}", e)))?;
 
     py.allow_threads(move || process_single_doc(doc))
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+        .map_err(|e| match e {
+            DocError::Pipeline(err) => super::errors::runtime_error(&err),
+            DocError::Other(message) => super::errors::uncoded_error(message),
+        })
 }
 
 /// Extract keyphrases from JSONL (newline-delimited JSON) input.
@@ -938,6 +1182,8 @@ pub fn extract_jsonl_from_json(
     num_threads: Option<usize>,
 ) -> PyResult<String> {
     let pool = build_batch_pool(num_threads)?;
+    // Lemma and text strings are interned once for the whole batch.
+    let shared = Arc::new(SharedStringPool::new());
     let input = jsonl_input.to_owned();
     let result = py.allow_threads(move || {
         // Pre-parse non-blank lines (par_iter().collect() preserves input order)
@@ -958,7 +1204,7 @@ pub fn extract_jsonl_from_json(
         };
 
         if pool.is_sequential() {
-            let mut ws = PipelineWorkspace::new();
+            let mut ws = PipelineWorkspace::new().with_shared_pool(shared.clone());
             let mut output = String::new();
             for line in &lines {
                 if !output.is_empty() {
@@ -972,7 +1218,7 @@ pub fn extract_jsonl_from_json(
                 lines
                     .par_iter()
                     .map(|line| {
-                        let mut ws = PipelineWorkspace::new();
+                        let mut ws = PipelineWorkspace::new().with_shared_pool(shared.clone());
                         process_line(line, Some(&mut ws), true)
                     })
                     .collect()
@@ -1026,12 +1272,14 @@ pub fn extract_batch_from_json(
     let docs: Vec<JsonDocument> = serde_json::from_str(json_input)
         .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid JSON: {}", e)))?;
     let pool = build_batch_pool(num_threads)?;
+    // Lemma and text strings are interned once for the whole batch.
+    let shared = Arc::new(SharedStringPool::new());
 
     // Release the GIL for the entire batch.
     let result = py.allow_threads(move || {
         if pool.is_sequential() {
             // Sequential path: reuse a single workspace across iterations.
-            let mut ws = PipelineWorkspace::new();
+            let mut ws = PipelineWorkspace::new().with_shared_pool(shared.clone());
             let mut output = String::from("[");
             for (i, doc) in docs.into_iter().enumerate() {
                 if i > 0 {
@@ -1051,7 +1299,7 @@ pub fn extract_batch_from_json(
             let results: Vec<String> = pool.install(|| {
                 docs.into_par_iter()
                     .map(|doc| {
-                        let mut ws = PipelineWorkspace::new();
+                        let mut ws = PipelineWorkspace::new().with_shared_pool(shared.clone());
                         match process_single_doc_with_workspace(doc, Some(&mut ws), true) {
                             Ok(json) => json,
                             Err(e) => serialize_doc_error(&e),
@@ -1170,12 +1418,14 @@ pub fn extract_batch_iter(
     let docs: Vec<JsonDocument> = serde_json::from_str(json_input)
         .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid JSON: {}", e)))?;
     let pool = build_batch_pool(num_threads)?;
+    // Lemma and text strings are interned once for the whole batch.
+    let shared = Arc::new(SharedStringPool::new());
 
     if pool.is_sequential() {
         Ok(JsonBatchIter {
             docs: docs.into_iter().map(Some).collect(),
             index: 0,
-            workspace: PipelineWorkspace::new(),
+            workspace: PipelineWorkspace::new().with_shared_pool(shared.clone()),
             precomputed: None,
             precomputed_index: 0,
         })
@@ -1185,7 +1435,7 @@ pub fn extract_batch_iter(
             pool.install(|| {
                 docs.into_par_iter()
                     .map(|doc| {
-                        let mut ws = PipelineWorkspace::new();
+                        let mut ws = PipelineWorkspace::new().with_shared_pool(shared.clone());
                         match process_single_doc_with_workspace(doc, Some(&mut ws), true) {
                             Ok(json) => json,
                             Err(e) => serialize_doc_error(&e),
@@ -1993,6 +2243,7 @@ mod tests {
             modules: crate::pipeline::spec::ModuleSet::default(),
             runtime: crate::pipeline::spec::RuntimeSpec::default(),
             expose: None,
+            config: std::collections::HashMap::new(),
             strict: false,
             unknown_fields: std::collections::HashMap::new(),
         }));
@@ -2162,17 +2413,22 @@ mod tests {
                     window_size: Some(4),
                     cross_sentence: Some(true),
                     edge_weighting: None,
+                    scope: None,
                 }),
                 rank: Some(RankSpec::PersonalizedPagerank {
                     damping: Some(0.9),
                     max_iterations: Some(150),
                     convergence_threshold: None,
                 }),
-                teleport: Some(TeleportSpec::Position { shape: None }),
+                teleport: Some(TeleportSpec::Position {
+                    shape: None,
+                    half_life: None,
+                }),
                 ..Default::default()
             },
             runtime: RuntimeSpec::default(),
             expose: None,
+            config: std::collections::HashMap::new(),
             strict: false,
             unknown_fields: std::collections::HashMap::new(),
         };
@@ -2242,11 +2498,13 @@ mod tests {
                     window_size: Some(5),
                     cross_sentence: None, // should inherit true from preset
                     edge_weighting: None,
+                    scope: None,
                 }),
                 ..Default::default()
             },
             runtime: RuntimeSpec::default(),
             expose: None,
+            config: std::collections::HashMap::new(),
             strict: false,
             unknown_fields: std::collections::HashMap::new(),
         }));
@@ -2296,8 +2554,6 @@ mod tests {
     /// every module type listed can be embedded in a V1 spec and parsed.
     #[test]
     fn test_capabilities_modules_are_parseable_spec_types() {
-        use crate::pipeline::spec::ModuleSet;
-
         let caps = build_capabilities();
         // For each stage→types pair, construct a JSON spec with that module
         // and verify it parses without error.
@@ -2524,8 +2780,6 @@ mod tests {
     /// Pipeline-level module override trumps config-level defaults.
     #[test]
     fn test_pipeline_module_overrides_config_window_size() {
-        use crate::pipeline::spec::{GraphSpec, ModuleSet, RuntimeSpec};
-
         // config.window_size = 3, but pipeline spec sets window_size = 6
         let json_input = format!(
             r#"{{
@@ -2886,7 +3140,7 @@ mod tests {
         let doc: JsonDocument = serde_json::from_str(&json_input).unwrap();
         let result = process_single_doc(doc).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
-        assert!(parsed["phrases"].as_array().unwrap().len() > 0);
+        assert!(!parsed["phrases"].as_array().unwrap().is_empty());
     }
 
     // ─── Patch 2+3: expose → debug_level + debug_top_k ──────────────
@@ -3078,6 +3332,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_versioned_json() {
+        let json_input = format!(
+            r#"{{
+                "tokens": {},
+                "pipeline": {{
+                    "v": 1,
+                    "modules": {{ "format": {{ "type": "versioned_json" }} }},
+                    "expose": {{ "stage_timings": true }}
+                }},
+                "config": {{ "determinism": "deterministic" }}
+            }}"#,
+            pipeline_test_tokens_json()
+        );
+        let doc: JsonDocument = serde_json::from_str(&json_input).unwrap();
+        let result = process_single_doc(doc).unwrap();
+        let output: JsonOutput = serde_json::from_str(&result).unwrap();
+
+        assert_eq!(
+            output.schema_version,
+            crate::pipeline::json_output::JSON_SCHEMA_VERSION
+        );
+        assert!(!output.phrases.is_empty());
+        assert_eq!(output.document.phrases, output.phrases.len());
+        assert!(output.document.tokens.is_some());
+        assert!(output.document.nodes.is_some());
+        assert!(!output.stage_timings.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_format_csv() {
+        let json_input = format!(
+            r#"{{
+                "id": "doc-7",
+                "tokens": {},
+                "pipeline": {{
+                    "v": 1,
+                    "modules": {{ "format": {{ "type": "csv" }} }}
+                }},
+                "config": {{ "determinism": "deterministic" }}
+            }}"#,
+            pipeline_test_tokens_json()
+        );
+        let doc: JsonDocument = serde_json::from_str(&json_input).unwrap();
+        let result = process_single_doc(doc).unwrap();
+        let csv: String = serde_json::from_str(&result).unwrap();
+        let mut lines = csv.lines();
+
+        assert_eq!(
+            lines.next(),
+            Some("doc_id,rank,phrase,lemma,score,first_offset")
+        );
+        let row: Vec<&str> = lines.next().unwrap().split(',').collect();
+        assert_eq!(row.len(), 6);
+        assert_eq!(row[0], "doc-7");
+        assert_eq!(row[1], "1");
+        assert!(row[4].parse::<f64>().is_ok());
+    }
+
     #[test]
     fn test_format_standard_json_with_debug_default_key() {
         let json_input = format!(
@@ -3164,6 +3477,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pipeline_config_overrides_apply() {
+        let json_input = format!(
+            r#"{{
+                "tokens": {},
+                "pipeline": {{
+                    "v": 1,
+                    "preset": "textrank",
+                    "config": {{ "top_n": 1 }}
+                }},
+                "config": {{ "top_n": 10 }}
+            }}"#,
+            pipeline_test_tokens_json()
+        );
+        let doc: JsonDocument = serde_json::from_str(&json_input).unwrap();
+        let result = process_single_doc(doc).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(parsed["phrases"].as_array().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_runtime_max_nodes_rejects() {
         // max_nodes=1 with multi-node graph should error
@@ -3236,7 +3569,7 @@ mod tests {
         let doc: JsonDocument = serde_json::from_str(&json_input).unwrap();
         let result = process_single_doc(doc).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
-        assert!(parsed["phrases"].as_array().unwrap().len() > 0);
+        assert!(!parsed["phrases"].as_array().unwrap().is_empty());
     }
 
     // ─── Capabilities ────────────────────────────────────────────────
@@ -3425,7 +3758,7 @@ mod tests {
         assert_eq!(arr.len(), 2);
         for item in arr {
             assert!(
-                item["phrases"].as_array().unwrap().len() > 0,
+                !item["phrases"].as_array().unwrap().is_empty(),
                 "each doc should have phrases"
             );
         }
@@ -3468,7 +3801,7 @@ mod tests {
         assert_eq!(arr.len(), 2);
         // Both should produce phrases (pipeline path and legacy path)
         for item in arr {
-            assert!(item["phrases"].as_array().unwrap().len() > 0);
+            assert!(!item["phrases"].as_array().unwrap().is_empty());
         }
     }
 
@@ -3600,7 +3933,7 @@ mod tests {
         for line_str in &output_lines {
             let parsed: serde_json::Value = serde_json::from_str(line_str).unwrap();
             assert!(
-                parsed["phrases"].as_array().unwrap().len() > 0,
+                !parsed["phrases"].as_array().unwrap().is_empty(),
                 "each workspace-reusing line should produce phrases"
             );
         }
@@ -2,6 +2,7 @@
 //!
 //! This module provides the Python interface for rapid_textrank.
 
+pub mod errors;
 pub mod json;
 pub mod native;
 
@@ -34,6 +35,10 @@ pub fn register_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
     #[cfg(feature = "sentence-rank")]
     m.add_class::<native::PySentenceRank>()?;
     m.add_function(wrap_pyfunction!(native::get_stopwords, m)?)?;
+    m.add_function(wrap_pyfunction!(native::extract_keyphrases, m)?)?;
+
+    // Exceptions
+    m.add("TextRankError", m.py().get_type::<errors::TextRankError>())?;
 
     // JSON interface functions
     m.add_function(wrap_pyfunction!(json::extract_from_json, m)?)?;
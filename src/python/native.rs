@@ -5,11 +5,14 @@
 
 use crate::nlp::stopwords::StopwordFilter;
 use crate::nlp::tokenizer::Tokenizer;
+use crate::phrase::chunker::NounChunker;
 use crate::phrase::extraction::extract_keyphrases_with_info;
 use crate::pipeline::artifacts::{DebugLevel, DebugPayload, TokenStream};
 use crate::pipeline::observer::NoopObserver;
 #[cfg(feature = "sentence-rank")]
 use crate::pipeline::runner::SentenceRankPipeline;
+use crate::pipeline::spec::PipelineSpec;
+use crate::pipeline::spec_builder::SpecPipelineBuilder;
 use crate::types::{Phrase, PhraseGrouping, ScoreAggregation, TextRankConfig};
 use crate::variants::biased_textrank::BiasedTextRank;
 use crate::variants::multipartite_rank::MultipartiteRank;
@@ -487,6 +490,74 @@ pub fn get_stopwords(language: &str) -> PyResult<Vec<String>> {
     Ok(StopwordFilter::built_in_list(language))
 }
 
+/// Extract keyphrases from text with any variant.
+///
+/// Runs the named preset (e.g. `"textrank"`, `"position_rank"`,
+/// `"topic_rank"`) through the modular pipeline with the built-in tokenizer.
+///
+/// Args:
+///     text: Input text
+///     variant: Preset name
+///     top_n: Number of phrases to return (overrides `config.top_n`)
+///     language: Language code (overrides `config.language`)
+///     config: Optional TextRankConfig
+///
+/// Raises:
+///     TextRankError: for unknown variants, invalid configs, or pipeline
+///         failures; `code` holds the stable error code.
+#[pyfunction]
+#[pyo3(signature = (text, variant = "textrank", top_n = None, language = None, config = None))]
+pub fn extract_keyphrases(
+    py: Python<'_>,
+    text: &str,
+    variant: &str,
+    top_n: Option<usize>,
+    language: Option<&str>,
+    config: Option<PyTextRankConfig>,
+) -> PyResult<PyTextRankResult> {
+    let mut config = config.map(|c| c.inner).unwrap_or_default();
+    if let Some(n) = top_n {
+        config.top_n = n;
+    }
+    if let Some(lang) = language {
+        config.language = lang.to_string();
+    }
+    config
+        .validate()
+        .map_err(|e| super::errors::config_error(&e))?;
+
+    let text = text.to_owned();
+    let spec = PipelineSpec::Preset(variant.to_string());
+    let result = py.allow_threads(move || {
+        let config = text_config(config, &text);
+        let tokens = Tokenizer::new().tokenize_with_config(&text, &config);
+        let chunks = NounChunker::new()
+            .with_min_length(config.min_phrase_length)
+            .with_max_length(config.max_phrase_length)
+            .with_bridge_words(&config.phrase_bridge_words)
+            .extract_chunks(&tokens);
+        let (pipeline, config) = SpecPipelineBuilder::new()
+            .with_chunks(chunks)
+            .build_with_config(&spec, &config)?;
+        Ok(pipeline.run(
+            TokenStream::from_tokens(&tokens),
+            &config,
+            &mut NoopObserver,
+        ))
+    });
+
+    let result = result.map_err(|e| super::errors::spec_error(&e))?;
+    if let Some(err) = &result.error {
+        return Err(super::errors::runtime_error(err));
+    }
+    Ok(PyTextRankResult {
+        phrases: result.phrases.into_iter().map(PyPhrase::from).collect(),
+        converged: result.converged,
+        iterations: result.iterations as usize,
+        debug: result.debug.map(convert_debug_payload),
+    })
+}
+
 /// Build an optional dedicated rayon thread pool.
 fn build_thread_pool(max_threads: Option<usize>) -> PyResult<Option<Arc<rayon::ThreadPool>>> {
     match max_threads {
@@ -517,6 +588,20 @@ fn run_in_pool<R: Send>(pool: &Option<Arc<rayon::ThreadPool>>, f: impl FnOnce()
     }
 }
 
+/// `config` adjusted to the detected language of `text` when
+/// `detect_language` is set (with the `lang-detect` feature).
+fn text_config(config: TextRankConfig, text: &str) -> TextRankConfig {
+    #[cfg(feature = "lang-detect")]
+    if let std::borrow::Cow::Owned(detected) =
+        crate::nlp::langdetect::config_for_text(&config, text)
+    {
+        return detected;
+    }
+    #[cfg(not(feature = "lang-detect"))]
+    let _ = text;
+    config
+}
+
 /// Configuration for TextRank
 #[pyclass(name = "TextRankConfig")]
 #[derive(Clone)]
@@ -537,6 +622,7 @@ impl PyTextRankConfig {
         max_phrase_length=4,
         score_aggregation="sum",
         language="en",
+        detect_language=false,
         use_edge_weights=true,
         include_pos=None,
         stopwords=None,
@@ -557,6 +643,7 @@ impl PyTextRankConfig {
         max_phrase_length: usize,
         score_aggregation: &str,
         language: &str,
+        detect_language: bool,
         use_edge_weights: bool,
         include_pos: Option<Vec<String>>,
         stopwords: Option<Vec<String>>,
@@ -619,23 +706,50 @@ impl PyTextRankConfig {
             top_n,
             min_phrase_length,
             max_phrase_length,
+            min_phrase_words: None,
+            max_phrase_words: None,
+            min_phrase_frequency: None,
             score_aggregation: aggregation,
             language: language.to_string(),
+            detect_language,
             use_edge_weights,
             include_pos: pos_tags,
             stopwords: stopwords.unwrap_or_default(),
+            phrase_bridge_words: Vec::new(),
+            token_filter: crate::types::TokenFilter::default(),
+            phrase_blocklist: Vec::new(),
+            keep_terms: Vec::new(),
             use_pos_in_nodes,
+            node_key: None,
             phrase_grouping: phrase_grouping.parse().unwrap_or(PhraseGrouping::Lemma),
             determinism: det_mode,
             debug_level: dbg_level,
             debug_top_k,
             max_nodes: None,
             max_edges: None,
+            stage_timeout_ms: None,
+            pipeline_timeout_ms: None,
+            lemma_strategy: crate::types::LemmaStrategy::AsProvided,
+            normalization: crate::types::TextNormalization::default(),
+            variant_linking: crate::types::VariantLinking::default(),
+            parallel_threshold: crate::pagerank::DEFAULT_PARALLEL_THRESHOLD,
+            rank_precision: crate::types::RankPrecision::F64,
+            acceleration: crate::types::RankerAcceleration::None,
+            dangling_policy: crate::types::DanglingPolicy::Personalization,
+            convergence_criterion: crate::types::ConvergenceCriterion::L1,
+            non_convergence: crate::types::NonConvergencePolicy::Continue,
+            mmr_lambda: None,
+            near_duplicates: None,
+            phrase_selection: crate::types::PhraseSelection::TopN,
+            span_mode: crate::types::SpanMode::All,
+            surface_selection: None,
+            overlap_policy: crate::types::OverlapPolicy::KeepHighestScored,
+            score_normalization: crate::types::ScoreNormalization::Raw,
         };
 
         config
             .validate()
-            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+            .map_err(|e| super::errors::config_error(&e))?;
 
         Ok(Self { inner: config })
     }
@@ -694,17 +808,8 @@ impl PyBaseTextRank {
         // Release the GIL for CPU-intensive extraction.
         let result = py.allow_threads(move || {
             run_in_pool(&pool, move || {
-                let tokenizer = Tokenizer::new();
-                let (_sentences, mut tokens) = tokenizer.tokenize(&text);
-
-                let stopwords = if config.stopwords.is_empty() {
-                    StopwordFilter::new(&config.language)
-                } else {
-                    StopwordFilter::with_additional(&config.language, &config.stopwords)
-                };
-                for token in &mut tokens {
-                    token.is_stopword = stopwords.is_stopword(&token.text);
-                }
+                let config = text_config(config, &text);
+                let tokens = Tokenizer::new().tokenize_with_config(&text, &config);
 
                 extract_keyphrases_with_info(&tokens, &config)
             })
@@ -775,17 +880,8 @@ impl PyPositionRank {
 
         let result = py.allow_threads(move || {
             run_in_pool(&pool, move || {
-                let tokenizer = Tokenizer::new();
-                let (_, mut tokens) = tokenizer.tokenize(&text);
-
-                let stopwords = if config.stopwords.is_empty() {
-                    StopwordFilter::new(&config.language)
-                } else {
-                    StopwordFilter::with_additional(&config.language, &config.stopwords)
-                };
-                for token in &mut tokens {
-                    token.is_stopword = stopwords.is_stopword(&token.text);
-                }
+                let config = text_config(config, &text);
+                let tokens = Tokenizer::new().tokenize_with_config(&text, &config);
 
                 PositionRank::with_config(config).extract_with_info(&tokens)
             })
@@ -877,17 +973,8 @@ impl PyBiasedTextRank {
 
         let result = py.allow_threads(move || {
             run_in_pool(&pool, move || {
-                let tokenizer = Tokenizer::new();
-                let (_, mut tokens) = tokenizer.tokenize(&text);
-
-                let stopwords = if config.stopwords.is_empty() {
-                    StopwordFilter::new(&config.language)
-                } else {
-                    StopwordFilter::with_additional(&config.language, &config.stopwords)
-                };
-                for token in &mut tokens {
-                    token.is_stopword = stopwords.is_stopword(&token.text);
-                }
+                let config = text_config(config, &text);
+                let tokens = Tokenizer::new().tokenize_with_config(&text, &config);
 
                 let focus_refs: Vec<&str> = focus_terms.iter().map(|s| s.as_str()).collect();
                 BiasedTextRank::with_config(config)
@@ -963,17 +1050,8 @@ impl PySingleRank {
 
         let result = py.allow_threads(move || {
             run_in_pool(&pool, move || {
-                let tokenizer = Tokenizer::new();
-                let (_, mut tokens) = tokenizer.tokenize(&text);
-
-                let stopwords = if config.stopwords.is_empty() {
-                    StopwordFilter::new(&config.language)
-                } else {
-                    StopwordFilter::with_additional(&config.language, &config.stopwords)
-                };
-                for token in &mut tokens {
-                    token.is_stopword = stopwords.is_stopword(&token.text);
-                }
+                let config = text_config(config, &text);
+                let tokens = Tokenizer::new().tokenize_with_config(&text, &config);
 
                 SingleRank::with_config(config).extract_with_info(&tokens)
             })
@@ -1068,17 +1146,8 @@ impl PyTopicalPageRank {
 
         let result = py.allow_threads(move || {
             run_in_pool(&pool, move || {
-                let tokenizer = Tokenizer::new();
-                let (_, mut tokens) = tokenizer.tokenize(&text);
-
-                let stopwords = if config.stopwords.is_empty() {
-                    StopwordFilter::new(&config.language)
-                } else {
-                    StopwordFilter::with_additional(&config.language, &config.stopwords)
-                };
-                for token in &mut tokens {
-                    token.is_stopword = stopwords.is_stopword(&token.text);
-                }
+                let config = text_config(config, &text);
+                let tokens = Tokenizer::new().tokenize_with_config(&text, &config);
 
                 TopicalPageRank::with_config(config)
                     .with_topic_weights(topic_weights)
@@ -1164,17 +1233,8 @@ impl PyMultipartiteRank {
 
         let result = py.allow_threads(move || {
             run_in_pool(&pool, move || {
-                let tokenizer = Tokenizer::new();
-                let (_, mut tokens) = tokenizer.tokenize(&text);
-
-                let stopwords = if config.stopwords.is_empty() {
-                    StopwordFilter::new(&config.language)
-                } else {
-                    StopwordFilter::with_additional(&config.language, &config.stopwords)
-                };
-                for token in &mut tokens {
-                    token.is_stopword = stopwords.is_stopword(&token.text);
-                }
+                let config = text_config(config, &text);
+                let tokens = Tokenizer::new().tokenize_with_config(&text, &config);
 
                 MultipartiteRank::with_config(config)
                     .with_similarity_threshold(similarity_threshold)
@@ -1255,17 +1315,8 @@ impl PySentenceRank {
 
         let result = py.allow_threads(move || {
             run_in_pool(&pool, move || {
-                let tokenizer = Tokenizer::new();
-                let (_, mut tokens) = tokenizer.tokenize(&text);
-
-                let stopwords = if config.stopwords.is_empty() {
-                    StopwordFilter::new(&config.language)
-                } else {
-                    StopwordFilter::with_additional(&config.language, &config.stopwords)
-                };
-                for token in &mut tokens {
-                    token.is_stopword = stopwords.is_stopword(&token.text);
-                }
+                let config = text_config(config, &text);
+                let tokens = Tokenizer::new().tokenize_with_config(&text, &config);
 
                 let stream = TokenStream::from_tokens(&tokens);
                 let mut obs = NoopObserver;
@@ -0,0 +1,256 @@
+//! Pluggable similarity between text units
+//!
+//! Several stages compare two pieces of text: the summarizer's MMR
+//! selector (sentence vs. sentence), the phrase MMR diversifier (phrase vs.
+//! phrase) and TopicRank clustering (candidate vs. candidate). [`Similarity`]
+//! is the one interface they share, so the graph algorithms don't depend on
+//! how a unit is represented.
+//!
+//! - [`LexicalSimilarity`] is the default: Jaccard overlap of lemma words.
+//! - [`EmbeddingSimilarity`] wraps any [`Embedder`] — an ONNX model, a
+//!   remote embedding service, or [`WordVectors`](crate::nlp::embeddings::WordVectors)
+//!   with the `embeddings` feature — and compares units by cosine.
+//!
+//! Any `Fn(&Unit, &Unit) -> f32 + Send + Sync` closure is also a
+//! [`Similarity`].
+
+use crate::types::Phrase;
+use parking_lot::Mutex;
+use rustc_hash::FxHashMap;
+use std::sync::Arc;
+
+/// A piece of text being compared: a phrase, a sentence, or a candidate.
+///
+/// `text` is the surface form and `lemma` the space-separated lemmas of its
+/// words. Either may be empty when the caller only has one of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Unit<'a> {
+    /// Surface text.
+    pub text: &'a str,
+    /// Space-separated lemmas.
+    pub lemma: &'a str,
+}
+
+impl<'a> Unit<'a> {
+    /// Unit with both surface text and lemmas.
+    pub fn new(text: &'a str, lemma: &'a str) -> Self {
+        Self { text, lemma }
+    }
+
+    /// Unit known only by its lemmas (used as the text too).
+    pub fn from_lemma(lemma: &'a str) -> Self {
+        Self { text: lemma, lemma }
+    }
+
+    /// The lemma words, or the surface words when there are no lemmas.
+    pub fn words(&self) -> std::str::SplitWhitespace<'a> {
+        if self.lemma.trim().is_empty() {
+            self.text.split_whitespace()
+        } else {
+            self.lemma.split_whitespace()
+        }
+    }
+}
+
+impl<'a> From<&'a Phrase> for Unit<'a> {
+    fn from(phrase: &'a Phrase) -> Self {
+        Self::new(&phrase.text, &phrase.lemma)
+    }
+}
+
+/// Similarity between two units.
+///
+/// Implementations should return values in `[0, 1]`, with 1 for identical
+/// units, and be symmetric.
+pub trait Similarity: Send + Sync {
+    /// Similarity of `a` and `b`.
+    fn sim(&self, a: &Unit<'_>, b: &Unit<'_>) -> f32;
+}
+
+impl<F> Similarity for F
+where
+    F: Fn(&Unit<'_>, &Unit<'_>) -> f32 + Send + Sync,
+{
+    fn sim(&self, a: &Unit<'_>, b: &Unit<'_>) -> f32 {
+        self(a, b)
+    }
+}
+
+/// Jaccard overlap of the lemma words of two units (the default).
+///
+/// Repeated words count once. Units without words have similarity 0.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LexicalSimilarity;
+
+impl Similarity for LexicalSimilarity {
+    fn sim(&self, a: &Unit<'_>, b: &Unit<'_>) -> f32 {
+        let mut a: Vec<&str> = a.words().collect();
+        let mut b: Vec<&str> = b.words().collect();
+        a.sort_unstable();
+        a.dedup();
+        b.sort_unstable();
+        b.dedup();
+        if a.is_empty() || b.is_empty() {
+            return 0.0;
+        }
+        let shared = a.iter().filter(|w| b.binary_search(w).is_ok()).count();
+        let union = a.len() + b.len() - shared;
+        (shared as f64 / union as f64) as f32
+    }
+}
+
+/// Turns a unit into a dense vector.
+///
+/// Returning `None` (no vector for this unit) makes its similarity to
+/// everything 0.
+pub trait Embedder: Send + Sync {
+    /// Embedding of `unit`.
+    fn embed(&self, unit: &Unit<'_>) -> Option<Vec<f32>>;
+}
+
+/// Cosine similarity of unit embeddings, clamped to `[0, 1]`.
+///
+/// Embeddings are cached by `(text, lemma)`, so each distinct unit is
+/// embedded once however many pairs it takes part in.
+pub struct EmbeddingSimilarity<E> {
+    embedder: E,
+    cache: Mutex<FxHashMap<(String, String), CachedEmbedding>>,
+}
+
+type CachedEmbedding = Option<Arc<[f32]>>;
+
+impl<E: Embedder> EmbeddingSimilarity<E> {
+    /// Wrap `embedder`.
+    pub fn new(embedder: E) -> Self {
+        Self {
+            embedder,
+            cache: Mutex::new(FxHashMap::default()),
+        }
+    }
+
+    /// The wrapped embedder.
+    pub fn embedder(&self) -> &E {
+        &self.embedder
+    }
+
+    /// Drop all cached embeddings.
+    pub fn clear_cache(&self) {
+        self.cache.lock().clear();
+    }
+
+    fn embedding(&self, unit: &Unit<'_>) -> Option<Arc<[f32]>> {
+        let key = (unit.text.to_string(), unit.lemma.to_string());
+        if let Some(cached) = self.cache.lock().get(&key) {
+            return cached.clone();
+        }
+        // Embed without holding the lock: the embedder may be slow.
+        let vector: Option<Arc<[f32]>> = self.embedder.embed(unit).map(Arc::from);
+        self.cache.lock().insert(key, vector.clone());
+        vector
+    }
+}
+
+impl<E> std::fmt::Debug for EmbeddingSimilarity<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmbeddingSimilarity")
+            .field("cached", &self.cache.lock().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<E: Embedder> Similarity for EmbeddingSimilarity<E> {
+    fn sim(&self, a: &Unit<'_>, b: &Unit<'_>) -> f32 {
+        match (self.embedding(a), self.embedding(b)) {
+            (Some(a), Some(b)) => cosine(&a, &b).max(0.0),
+            _ => 0.0,
+        }
+    }
+}
+
+/// Embeds a unit as the centroid of its lemma word vectors.
+#[cfg(feature = "embeddings")]
+impl Embedder for crate::nlp::embeddings::WordVectors {
+    fn embed(&self, unit: &Unit<'_>) -> Option<Vec<f32>> {
+        self.centroid(unit.words())
+    }
+}
+
+/// Cosine of two vectors; 0 for mismatched lengths or zero vectors.
+pub(crate) fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    let (mut dot, mut na, mut nb) = (0.0f64, 0.0f64, 0.0f64);
+    for (&x, &y) in a.iter().zip(b) {
+        let (x, y) = (x as f64, y as f64);
+        dot += x * y;
+        na += x * x;
+        nb += y * y;
+    }
+    if na == 0.0 || nb == 0.0 {
+        0.0
+    } else {
+        (dot / (na.sqrt() * nb.sqrt())) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_lexical_similarity() {
+        let a = Unit::from_lemma("neural network");
+        let b = Unit::from_lemma("deep neural network");
+        assert_eq!(LexicalSimilarity.sim(&a, &a), 1.0);
+        assert!((LexicalSimilarity.sim(&a, &b) - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(LexicalSimilarity.sim(&a, &Unit::from_lemma("")), 0.0);
+        // Falls back to surface words without lemmas.
+        assert_eq!(
+            LexicalSimilarity.sim(&Unit::new("graph", ""), &Unit::new("graph", "")),
+            1.0
+        );
+    }
+
+    struct CountingEmbedder(AtomicUsize);
+
+    impl Embedder for CountingEmbedder {
+        fn embed(&self, unit: &Unit<'_>) -> Option<Vec<f32>> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            match unit.lemma {
+                "car" => Some(vec![1.0, 0.1]),
+                "automobile" => Some(vec![0.9, 0.1]),
+                "banana" => Some(vec![-1.0, 1.0]),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn test_embedding_similarity_cosine_and_cache() {
+        let sim = EmbeddingSimilarity::new(CountingEmbedder(AtomicUsize::new(0)));
+        let car = Unit::from_lemma("car");
+        let auto = Unit::from_lemma("automobile");
+        assert!(sim.sim(&car, &auto) > 0.99);
+        // Negative cosine clamps to zero; unknown units score zero.
+        assert_eq!(sim.sim(&car, &Unit::from_lemma("banana")), 0.0);
+        assert_eq!(sim.sim(&car, &Unit::from_lemma("zzz")), 0.0);
+        sim.sim(&auto, &car);
+        assert_eq!(sim.embedder().0.load(Ordering::Relaxed), 4);
+    }
+
+    #[test]
+    fn test_closure_similarity() {
+        let exact = |a: &Unit<'_>, b: &Unit<'_>| if a.lemma == b.lemma { 1.0 } else { 0.0 };
+        let sim: &dyn Similarity = &exact;
+        assert_eq!(
+            sim.sim(&Unit::from_lemma("a b"), &Unit::from_lemma("a b")),
+            1.0
+        );
+        assert_eq!(
+            sim.sim(&Unit::from_lemma("a b"), &Unit::from_lemma("a c")),
+            0.0
+        );
+    }
+}
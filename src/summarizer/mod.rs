@@ -1,7 +1,9 @@
 //! Summarization components
 //!
 //! Provides extractive summarization using MMR (Maximal Marginal Relevance)
-//! for selecting diverse, relevant sentences.
+//! for selecting diverse, relevant sentences, and TextRank sentence ranking.
 
 pub mod selector;
+#[cfg(feature = "sentence-rank")]
+pub mod textrank;
 pub mod unit_vector;
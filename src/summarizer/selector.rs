@@ -2,9 +2,14 @@
 //!
 //! Implements Maximal Marginal Relevance (MMR) for selecting
 //! diverse yet relevant sentences for extractive summarization.
+//! Redundancy between sentences defaults to the cosine of their phrase
+//! vectors; [`SentenceSelector::with_similarity`] swaps in any
+//! [`Similarity`], such as a sentence embedding model.
 
-use super::unit_vector::{UnitVector, UnitVectorBuilder};
+use super::unit_vector::UnitVectorBuilder;
+use crate::similarity::{Similarity, Unit};
 use crate::types::{Phrase, Sentence, Token};
+use std::sync::Arc;
 
 /// Configuration for sentence selection
 #[derive(Debug, Clone)]
@@ -53,9 +58,18 @@ pub struct SelectedSentence {
 }
 
 /// MMR-based sentence selector
-#[derive(Debug)]
 pub struct SentenceSelector {
     config: SelectorConfig,
+    similarity: Option<Arc<dyn Similarity>>,
+}
+
+impl std::fmt::Debug for SentenceSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SentenceSelector")
+            .field("config", &self.config)
+            .field("custom_similarity", &self.similarity.is_some())
+            .finish()
+    }
 }
 
 impl Default for SentenceSelector {
@@ -67,14 +81,22 @@ impl Default for SentenceSelector {
 impl SentenceSelector {
     /// Create a new selector with default config
     pub fn new() -> Self {
-        Self {
-            config: SelectorConfig::default(),
-        }
+        Self::with_config(SelectorConfig::default())
     }
 
     /// Create with custom config
     pub fn with_config(config: SelectorConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            similarity: None,
+        }
+    }
+
+    /// Measure sentence-to-sentence redundancy with `similarity` instead of
+    /// phrase-vector cosine. Relevance to the document is unchanged.
+    pub fn with_similarity(mut self, similarity: Arc<dyn Similarity>) -> Self {
+        self.similarity = Some(similarity);
+        self
     }
 
     /// Set lambda (relevance vs diversity tradeoff)
@@ -131,9 +153,24 @@ impl SentenceSelector {
             .map(|v| v.cosine_similarity(&query_vector))
             .collect();
 
+        // Sentence-to-sentence similarity for the redundancy term
+        let lemmas: Vec<String> = match self.similarity {
+            Some(_) => candidates
+                .iter()
+                .map(|s| sentence_lemma(s, tokens))
+                .collect(),
+            None => Vec::new(),
+        };
+        let pair_similarity = |i: usize, j: usize| match &self.similarity {
+            Some(similarity) => similarity.sim(
+                &Unit::new(&candidates[i].text, &lemmas[i]),
+                &Unit::new(&candidates[j].text, &lemmas[j]),
+            ) as f64,
+            None => sent_vectors[i].cosine_similarity(&sent_vectors[j]),
+        };
+
         // MMR selection
         let mut selected: Vec<usize> = Vec::new();
-        let mut selected_vectors: Vec<&UnitVector> = Vec::new();
 
         while selected.len() < self.config.num_sentences && selected.len() < candidates.len() {
             let mut best_idx = None;
@@ -148,12 +185,12 @@ impl SentenceSelector {
                 let relevance = relevance_scores[i];
 
                 // Diversity component: max similarity to already selected
-                let max_sim = if selected_vectors.is_empty() {
+                let max_sim = if selected.is_empty() {
                     0.0
                 } else {
-                    selected_vectors
+                    selected
                         .iter()
-                        .map(|sv| sent_vectors[i].cosine_similarity(sv))
+                        .map(|&j| pair_similarity(i, j))
                         .fold(f64::NEG_INFINITY, f64::max)
                 };
 
@@ -168,7 +205,6 @@ impl SentenceSelector {
 
             if let Some(idx) = best_idx {
                 selected.push(idx);
-                selected_vectors.push(&sent_vectors[idx]);
             } else {
                 break;
             }
@@ -196,8 +232,7 @@ impl SentenceSelector {
             let mut count = 0;
             for i in 0..selected.len() {
                 for j in (i + 1)..selected.len() {
-                    total_sim +=
-                        sent_vectors[selected[i]].cosine_similarity(&sent_vectors[selected[j]]);
+                    total_sim += pair_similarity(selected[i], selected[j]);
                     count += 1;
                 }
             }
@@ -218,6 +253,17 @@ impl SentenceSelector {
     }
 }
 
+/// Space-separated lemmas of a sentence's tokens.
+fn sentence_lemma(sentence: &Sentence, tokens: &[Token]) -> String {
+    tokens
+        .get(sentence.start_token..sentence.end_token)
+        .unwrap_or(&[])
+        .iter()
+        .map(|t| t.lemma.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -361,4 +407,19 @@ mod tests {
         // Sentence is too short (< 5 tokens)
         assert!(result.sentences.is_empty());
     }
+
+    #[test]
+    fn test_custom_similarity_drives_redundancy() {
+        let (sentences, tokens, phrases) = make_test_data();
+
+        let identical = |_: &Unit<'_>, _: &Unit<'_>| 1.0;
+        let selector = SentenceSelector::new()
+            .with_num_sentences(2)
+            .with_similarity(Arc::new(identical));
+        let result = selector.select(&sentences, &tokens, &phrases);
+
+        assert_eq!(result.sentences.len(), 2);
+        // Every selected pair is "identical" under the custom similarity.
+        assert_eq!(result.diversity_score, 0.0);
+    }
 }
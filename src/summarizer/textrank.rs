@@ -0,0 +1,260 @@
+//! TextRank sentence ranking for extractive summarization
+//!
+//! Ranks whole sentences (Mihalcea & Tarau, 2004) instead of keyphrases:
+//! each sentence is a node, edges are weighted by sentence similarity, and
+//! the PageRank core scores the resulting graph.
+//!
+//! LexRank (Erkan & Radev, 2004) is available via
+//! [`TextRankSummarizer::lexrank`]: TF-IDF cosine similarity, optionally
+//! thresholded into an unweighted graph. BM25 similarity (Barrios et al.,
+//! 2016) avoids the bias of plain overlap toward long sentences; select it
+//! with `with_similarity(SentenceSimilarity::Bm25)`, optionally with corpus
+//! IDF from [`TextRankSummarizer::with_corpus_stats`].
+//!
+//! This reuses the SentenceRank pipeline stages (sentence candidates,
+//! [`SentenceGraphBuilder`], [`PageRankRanker`]) and returns sentences with
+//! their token/character spans intact.
+
+use crate::nlp::corpus::CorpusStats;
+use crate::pipeline::artifacts::{CandidateKind, TokenStream};
+use crate::pipeline::traits::{
+    CandidateSelector, GraphBuilder, PageRankRanker, Ranker, SentenceCandidateSelector,
+    SentenceGraphBuilder, SentenceSimilarity,
+};
+use crate::types::{Sentence, TextRankConfig, Token};
+use std::sync::Arc;
+
+/// A sentence with its TextRank score and 1-indexed rank
+#[derive(Debug, Clone)]
+pub struct RankedSentence {
+    /// The sentence (its `score` field holds the PageRank score)
+    pub sentence: Sentence,
+    /// 1-indexed rank by score
+    pub rank: usize,
+}
+
+/// TextRank extractive summarizer
+#[derive(Debug)]
+pub struct TextRankSummarizer {
+    config: TextRankConfig,
+    graph_builder: SentenceGraphBuilder,
+    sort_by_position: bool,
+}
+
+impl Default for TextRankSummarizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextRankSummarizer {
+    /// Create a new summarizer using the original TextRank overlap similarity
+    pub fn new() -> Self {
+        Self::with_config(TextRankConfig::default())
+    }
+
+    /// Create with custom config (`top_n` controls the number of sentences)
+    pub fn with_config(config: TextRankConfig) -> Self {
+        Self {
+            config,
+            graph_builder: SentenceGraphBuilder::default()
+                .with_similarity(SentenceSimilarity::Overlap),
+            sort_by_position: false,
+        }
+    }
+
+    /// Create a LexRank summarizer (continuous TF-IDF cosine graph).
+    ///
+    /// Use [`with_min_similarity`](Self::with_min_similarity) and
+    /// [`with_binary_edges`](Self::with_binary_edges) for thresholded LexRank.
+    pub fn lexrank(config: TextRankConfig) -> Self {
+        Self {
+            config,
+            graph_builder: SentenceGraphBuilder::lexrank(),
+            sort_by_position: false,
+        }
+    }
+
+    /// Set the sentence similarity function
+    pub fn with_similarity(mut self, similarity: SentenceSimilarity) -> Self {
+        self.graph_builder = self.graph_builder.with_similarity(similarity);
+        self
+    }
+
+    /// Set the minimum similarity for an edge between two sentences
+    pub fn with_min_similarity(mut self, threshold: f64) -> Self {
+        self.graph_builder = self.graph_builder.with_min_similarity(threshold);
+        self
+    }
+
+    /// Use unit edge weights above the similarity threshold
+    pub fn with_binary_edges(mut self, binary: bool) -> Self {
+        self.graph_builder = self.graph_builder.with_binary_edges(binary);
+        self
+    }
+
+    /// Take [`SentenceSimilarity::Bm25`] IDF from corpus statistics
+    /// instead of the document's own sentences
+    pub fn with_corpus_stats(mut self, stats: Arc<CorpusStats>) -> Self {
+        self.graph_builder = self.graph_builder.with_corpus_stats(stats);
+        self
+    }
+
+    /// Return selected sentences in document order instead of score order
+    pub fn with_sort_by_position(mut self, sort: bool) -> Self {
+        self.sort_by_position = sort;
+        self
+    }
+
+    /// Rank the sentences of a tokenized document and return the top `top_n`.
+    ///
+    /// Sentences are delimited by `Token::sentence_idx`; stopwords are
+    /// ignored when computing similarity.
+    pub fn summarize(&self, tokens: &[Token]) -> Vec<RankedSentence> {
+        let stream = TokenStream::from_tokens(tokens);
+        let candidates = SentenceCandidateSelector.select(stream.as_ref(), &self.config);
+        let sentences = match candidates.kind() {
+            CandidateKind::Sentences(s) if !s.is_empty() => s,
+            _ => return Vec::new(),
+        };
+
+        let graph = self
+            .graph_builder
+            .build(stream.as_ref(), candidates.as_ref(), &self.config);
+        let ranks = PageRankRanker.rank(&graph, None, &self.config);
+
+        let tokens_ref = stream.as_ref();
+        let mut ranked: Vec<RankedSentence> = sentences
+            .iter()
+            .enumerate()
+            .map(|(i, sent)| {
+                let start = sent.start_token as usize;
+                let end = sent.end_token as usize;
+                let text = tokens_ref.tokens()[start..end]
+                    .iter()
+                    .map(|e| tokens_ref.text(e))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                let mut sentence = Sentence::new(
+                    text,
+                    sent.start_char as usize,
+                    sent.end_char as usize,
+                    sent.sentence_idx as usize,
+                );
+                sentence.start_token = start;
+                sentence.end_token = end;
+                sentence.score = ranks.score(i as u32);
+
+                RankedSentence { sentence, rank: 0 }
+            })
+            .collect();
+
+        // Score descending, earlier sentences first on ties.
+        ranked.sort_by(|a, b| {
+            b.sentence
+                .score
+                .partial_cmp(&a.sentence.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.sentence.index.cmp(&b.sentence.index))
+        });
+        if self.config.top_n > 0 {
+            ranked.truncate(self.config.top_n);
+        }
+        for (i, r) in ranked.iter_mut().enumerate() {
+            r.rank = i + 1;
+        }
+
+        if self.sort_by_position {
+            ranked.sort_by_key(|r| r.sentence.index);
+        }
+
+        ranked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PosTag;
+
+    fn make_tokens() -> Vec<Token> {
+        vec![
+            // Sentence 0: "Machine learning models learn patterns"
+            Token::new("Machine", "machine", PosTag::Noun, 0, 7, 0, 0),
+            Token::new("learning", "learning", PosTag::Noun, 8, 16, 0, 1),
+            Token::new("models", "model", PosTag::Noun, 17, 23, 0, 2),
+            Token::new("learn", "learn", PosTag::Verb, 24, 29, 0, 3),
+            Token::new("patterns", "pattern", PosTag::Noun, 30, 38, 0, 4),
+            // Sentence 1: "Deep learning models use neural networks"
+            Token::new("Deep", "deep", PosTag::Adjective, 40, 44, 1, 5),
+            Token::new("learning", "learning", PosTag::Noun, 45, 53, 1, 6),
+            Token::new("models", "model", PosTag::Noun, 54, 60, 1, 7),
+            Token::new("use", "use", PosTag::Verb, 61, 64, 1, 8),
+            Token::new("neural", "neural", PosTag::Adjective, 65, 71, 1, 9),
+            Token::new("networks", "network", PosTag::Noun, 72, 80, 1, 10),
+            // Sentence 2: "Cats sleep often"
+            Token::new("Cats", "cat", PosTag::Noun, 82, 86, 2, 11),
+            Token::new("sleep", "sleep", PosTag::Verb, 87, 92, 2, 12),
+            Token::new("often", "often", PosTag::Adverb, 93, 98, 2, 13),
+            // Sentence 3: "Learning models generalize"
+            Token::new("Learning", "learning", PosTag::Noun, 100, 108, 3, 14),
+            Token::new("models", "model", PosTag::Noun, 109, 115, 3, 15),
+            Token::new("generalize", "generalize", PosTag::Verb, 116, 126, 3, 16),
+        ]
+    }
+
+    #[test]
+    fn test_summarize_ranks_connected_sentences_first() {
+        let config = TextRankConfig::default().with_top_n(2);
+        let ranked = TextRankSummarizer::with_config(config).summarize(&make_tokens());
+
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].rank, 1);
+        assert_eq!(ranked[1].rank, 2);
+        assert!(ranked[0].sentence.score >= ranked[1].sentence.score);
+        // The unrelated sentence about cats should not be selected.
+        assert!(ranked.iter().all(|r| r.sentence.index != 2));
+    }
+
+    #[test]
+    fn test_summarize_preserves_spans() {
+        let ranked = TextRankSummarizer::new().summarize(&make_tokens());
+        let s1 = ranked.iter().find(|r| r.sentence.index == 1).unwrap();
+        assert_eq!(s1.sentence.start, 40);
+        assert_eq!(s1.sentence.end, 80);
+        assert_eq!(s1.sentence.start_token, 5);
+        assert_eq!(s1.sentence.end_token, 11);
+        assert_eq!(s1.sentence.text, "Deep learning models use neural networks");
+    }
+
+    #[test]
+    fn test_summarize_sort_by_position() {
+        let config = TextRankConfig::default().with_top_n(3);
+        let ranked = TextRankSummarizer::with_config(config)
+            .with_sort_by_position(true)
+            .summarize(&make_tokens());
+
+        let indices: Vec<usize> = ranked.iter().map(|r| r.sentence.index).collect();
+        let mut sorted = indices.clone();
+        sorted.sort();
+        assert_eq!(indices, sorted);
+    }
+
+    #[test]
+    fn test_lexrank_thresholded() {
+        let config = TextRankConfig::default().with_top_n(2);
+        let ranked = TextRankSummarizer::lexrank(config)
+            .with_min_similarity(0.1)
+            .with_binary_edges(true)
+            .summarize(&make_tokens());
+
+        assert_eq!(ranked.len(), 2);
+        assert!(ranked.iter().all(|r| r.sentence.index != 2));
+    }
+
+    #[test]
+    fn test_summarize_empty() {
+        assert!(TextRankSummarizer::new().summarize(&[]).is_empty());
+    }
+}
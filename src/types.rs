@@ -4,8 +4,13 @@
 //! including string interning, tokens, phrases, and configuration.
 
 use crate::errors::{Result, TextRankError};
-use rustc_hash::FxHashMap;
+pub use crate::pagerank::{ConvergenceCriterion, DanglingPolicy};
+use crate::pipeline::error_code::ErrorCode;
+use parking_lot::RwLock;
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 // ============================================================================
@@ -16,7 +21,7 @@ use std::sync::Arc;
 ///
 /// String interning stores each unique string once and returns lightweight references.
 /// This is particularly useful for lemmas which may repeat many times across a document.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct StringPool {
     /// Maps strings to their interned IDs
     string_to_id: FxHashMap<Arc<str>, u32>,
@@ -43,9 +48,23 @@ impl StringPool {
         if let Some(&id) = self.string_to_id.get(s) {
             return id;
         }
+        self.push(s.into())
+    }
+
+    /// Intern a string, taking its storage from `shared` on first sight.
+    ///
+    /// IDs stay local to this pool, but the string allocation is the one
+    /// held by `shared`, so pools built from many documents with the same
+    /// shared pool hold one copy of each distinct string between them.
+    pub fn intern_shared(&mut self, s: &str, shared: &SharedStringPool) -> u32 {
+        if let Some(&id) = self.string_to_id.get(s) {
+            return id;
+        }
+        self.push(shared.intern(s))
+    }
 
+    fn push(&mut self, arc: Arc<str>) -> u32 {
         let id = self.id_to_string.len() as u32;
-        let arc: Arc<str> = s.into();
         self.string_to_id.insert(arc.clone(), id);
         self.id_to_string.push(arc);
         id
@@ -65,6 +84,141 @@ impl StringPool {
     pub fn is_empty(&self) -> bool {
         self.id_to_string.is_empty()
     }
+
+    /// Estimated heap size in bytes (strings, lookup table and ID vector)
+    pub fn heap_bytes(&self) -> usize {
+        // Each `Arc<str>` allocation carries two reference counts.
+        let strings: usize = self
+            .id_to_string
+            .iter()
+            .map(|s| s.len() + 2 * std::mem::size_of::<usize>())
+            .sum();
+        strings
+            + self.id_to_string.capacity() * std::mem::size_of::<Arc<str>>()
+            + self.string_to_id.capacity() * (std::mem::size_of::<(Arc<str>, u32)>() + 1)
+    }
+}
+
+/// Serialized as the list of strings in ID order; the lookup map is rebuilt
+/// on deserialization.
+impl Serialize for StringPool {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.id_to_string.iter().map(|s| s.as_ref()))
+    }
+}
+
+impl<'de> Deserialize<'de> for StringPool {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+        let mut pool = Self::with_capacity(strings.len());
+        for (id, s) in strings.iter().enumerate() {
+            if pool.intern(s) as usize != id {
+                return Err(serde::de::Error::custom(format!(
+                    "duplicate string in pool: {:?}",
+                    s
+                )));
+            }
+        }
+        Ok(pool)
+    }
+}
+
+/// Default number of shards in a [`SharedStringPool`]
+const SHARED_POOL_SHARDS: usize = 16;
+
+/// A thread-safe string interner shared across documents.
+///
+/// Where a [`StringPool`] deduplicates strings within one document, a
+/// `SharedStringPool` deduplicates them across a whole batch: every thread
+/// interns into the same pool and gets back the same `Arc<str>` for equal
+/// strings. Strings are spread over independently locked shards by hash, so
+/// threads interning different strings rarely contend.
+///
+/// Used through [`StringPool::intern_shared`], which keeps per-document IDs
+/// dense while sharing the underlying allocations.
+#[derive(Debug)]
+pub struct SharedStringPool {
+    shards: Box<[RwLock<FxHashSet<Arc<str>>>]>,
+}
+
+impl SharedStringPool {
+    /// Create an empty pool with the default shard count
+    pub fn new() -> Self {
+        Self::with_shards(SHARED_POOL_SHARDS)
+    }
+
+    /// Create an empty pool with `shards` independently locked shards
+    /// (at least one)
+    pub fn with_shards(shards: usize) -> Self {
+        Self {
+            shards: (0..shards.max(1))
+                .map(|_| RwLock::new(FxHashSet::default()))
+                .collect(),
+        }
+    }
+
+    /// Intern a string, returning the pool's shared copy of it
+    pub fn intern(&self, s: &str) -> Arc<str> {
+        let shard = &self.shards[self.shard_of(s)];
+        if let Some(arc) = shard.read().get(s) {
+            return arc.clone();
+        }
+        let mut set = shard.write();
+        // Another thread may have inserted it between the two locks.
+        if let Some(arc) = set.get(s) {
+            return arc.clone();
+        }
+        let arc: Arc<str> = s.into();
+        set.insert(arc.clone());
+        arc
+    }
+
+    /// Whether the pool holds `s`
+    pub fn contains(&self, s: &str) -> bool {
+        self.shards[self.shard_of(s)].read().contains(s)
+    }
+
+    /// Number of distinct strings in the pool
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(|shard| shard.read().len()).sum()
+    }
+
+    /// Check if the pool is empty
+    pub fn is_empty(&self) -> bool {
+        self.shards.iter().all(|shard| shard.read().is_empty())
+    }
+
+    /// Estimated heap size in bytes (strings and lookup tables)
+    pub fn heap_bytes(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| {
+                let set = shard.read();
+                let strings: usize = set
+                    .iter()
+                    .map(|s| s.len() + 2 * std::mem::size_of::<usize>())
+                    .sum();
+                strings + set.capacity() * (std::mem::size_of::<Arc<str>>() + 1)
+            })
+            .sum()
+    }
+
+    fn shard_of(&self, s: &str) -> usize {
+        let mut hasher = FxHasher::default();
+        s.hash(&mut hasher);
+        (hasher.finish() as usize) % self.shards.len()
+    }
+}
+
+impl Default for SharedStringPool {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // ============================================================================
@@ -187,6 +341,39 @@ impl PosTag {
     }
 }
 
+/// Document section a token belongs to
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Section {
+    Title,
+    Abstract,
+    Heading,
+    #[default]
+    Body,
+}
+
+impl Section {
+    /// All sections, in declaration order.
+    pub const ALL: [Section; 4] = [
+        Section::Title,
+        Section::Abstract,
+        Section::Heading,
+        Section::Body,
+    ];
+
+    /// Lowercase name, as used in JSON
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Section::Title => "title",
+            Section::Abstract => "abstract",
+            Section::Heading => "heading",
+            Section::Body => "body",
+        }
+    }
+}
+
 /// A token from the input text
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Token {
@@ -206,6 +393,9 @@ pub struct Token {
     pub token_idx: usize,
     /// Whether this token is a stopword
     pub is_stopword: bool,
+    /// Document section (title, abstract, ...), when known
+    #[serde(default)]
+    pub section: Option<Section>,
 }
 
 impl Token {
@@ -228,9 +418,16 @@ impl Token {
             sentence_idx,
             token_idx,
             is_stopword: false,
+            section: None,
         }
     }
 
+    /// Label the token with the document section it belongs to
+    pub fn with_section(mut self, section: Section) -> Self {
+        self.section = Some(section);
+        self
+    }
+
     /// Build the graph key for this token, optionally including POS.
     pub fn graph_key(&self, use_pos_in_nodes: bool) -> String {
         if use_pos_in_nodes {
@@ -246,6 +443,193 @@ impl Token {
     }
 }
 
+/// Signature of a [`NodeKey::Custom`] key function
+///
+/// Stages working on a [`TokenStream`](crate::pipeline::artifacts::TokenStream)
+/// pass the function a [`Token`] rebuilt from the interned entry, whose
+/// `section` is always `None`; key on the other fields.
+pub type NodeKeyFn = dyn for<'a> Fn(&'a Token) -> Cow<'a, str> + Send + Sync;
+
+/// How tokens map to graph nodes
+///
+/// Tokens with equal keys share a node, so the key decides what counts as
+/// the same word: `Lemma` merges inflections ("models", "model"),
+/// `LemmaPos` also keeps the noun "rank" apart from the verb, and the
+/// surface strategies keep every spelling apart.
+///
+/// Serializes as its snake_case name. `Custom` holds a function, so it
+/// cannot be serialized or deserialized: serializing it (or a config using
+/// it) returns an error rather than writing a key that cannot be read back.
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeKey {
+    /// The lemma, e.g. `"learning"`
+    Lemma,
+    /// Lemma and POS tag, e.g. `"learning|NOUN"`
+    LemmaPos,
+    /// The surface form as written, e.g. `"Learning"`
+    Surface,
+    /// The lowercased surface form, e.g. `"learning"`
+    LowercaseSurface,
+    /// A user-supplied key function, see [`NodeKey::custom`]
+    #[serde(skip_deserializing)]
+    Custom(Arc<NodeKeyFn>),
+}
+
+impl NodeKey {
+    /// Key nodes with `f`.
+    ///
+    /// ```
+    /// use rapid_textrank::types::PosTag;
+    /// use rapid_textrank::{NodeKey, Token};
+    /// use std::borrow::Cow;
+    ///
+    /// let key = NodeKey::custom(|t: &Token| Cow::Owned(t.text.to_uppercase()));
+    /// let token = Token::new("Graphs", "graph", PosTag::Noun, 0, 6, 0, 0);
+    /// assert_eq!(key.key(&token), "GRAPHS");
+    /// ```
+    pub fn custom(f: impl for<'a> Fn(&'a Token) -> Cow<'a, str> + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(f))
+    }
+
+    /// Strategy name, as serialized
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Lemma => "lemma",
+            Self::LemmaPos => "lemma_pos",
+            Self::Surface => "surface",
+            Self::LowercaseSurface => "lowercase_surface",
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    /// Graph node key of `token`
+    pub fn key<'a>(&self, token: &'a Token) -> Cow<'a, str> {
+        match self {
+            Self::Lemma => Cow::Borrowed(&token.lemma),
+            Self::LemmaPos => Cow::Owned(format!("{}|{}", token.lemma, token.pos.as_str())),
+            Self::Surface => Cow::Borrowed(&token.text),
+            Self::LowercaseSurface => Cow::Owned(token.text.to_lowercase()),
+            Self::Custom(f) => f(token),
+        }
+    }
+}
+
+/// `use_pos_in_nodes`: `true` is [`NodeKey::LemmaPos`], `false` is
+/// [`NodeKey::Lemma`].
+impl From<bool> for NodeKey {
+    fn from(use_pos_in_nodes: bool) -> Self {
+        if use_pos_in_nodes {
+            Self::LemmaPos
+        } else {
+            Self::Lemma
+        }
+    }
+}
+
+impl std::fmt::Debug for NodeKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lemma => f.write_str("Lemma"),
+            Self::LemmaPos => f.write_str("LemmaPos"),
+            Self::Surface => f.write_str("Surface"),
+            Self::LowercaseSurface => f.write_str("LowercaseSurface"),
+            Self::Custom(_) => f.debug_tuple("Custom").finish_non_exhaustive(),
+        }
+    }
+}
+
+impl Serialize for NodeKey {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Custom(_) => Err(serde::ser::Error::custom(
+                "NodeKey::Custom cannot be serialized",
+            )),
+            _ => serializer.serialize_str(self.name()),
+        }
+    }
+}
+
+/// A token borrowing its strings from the input text
+///
+/// The zero-copy counterpart of [`Token`], produced by
+/// [`Tokenizer::tokenize_borrowed`](crate::nlp::tokenizer::Tokenizer::tokenize_borrowed):
+/// `text` is a slice of the document, and `lemma` borrows from it too when
+/// the lemma is a substring of the word (already-lowercase words, plain
+/// suffix stripping). Only lemmas that differ from the source allocate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenRef<'a> {
+    /// The surface form (original text)
+    pub text: &'a str,
+    /// The lemmatized form (normalized)
+    pub lemma: Cow<'a, str>,
+    /// Part-of-speech tag
+    pub pos: PosTag,
+    /// Character offset (start) in original text
+    pub start: usize,
+    /// Character offset (end) in original text
+    pub end: usize,
+    /// Sentence index this token belongs to
+    pub sentence_idx: usize,
+    /// Token index within the document
+    pub token_idx: usize,
+    /// Whether this token is a stopword
+    pub is_stopword: bool,
+    /// Document section, when known
+    pub section: Option<Section>,
+}
+
+impl TokenRef<'_> {
+    /// A copy of this token borrowing its strings, without allocating
+    pub fn reborrow(&self) -> TokenRef<'_> {
+        TokenRef {
+            text: self.text,
+            lemma: Cow::Borrowed(&self.lemma),
+            pos: self.pos,
+            start: self.start,
+            end: self.end,
+            sentence_idx: self.sentence_idx,
+            token_idx: self.token_idx,
+            is_stopword: self.is_stopword,
+            section: self.section,
+        }
+    }
+
+    /// Copy the borrowed strings into an owned [`Token`]
+    pub fn into_owned(self) -> Token {
+        Token {
+            text: self.text.to_string(),
+            lemma: self.lemma.into_owned(),
+            pos: self.pos,
+            start: self.start,
+            end: self.end,
+            sentence_idx: self.sentence_idx,
+            token_idx: self.token_idx,
+            is_stopword: self.is_stopword,
+            section: self.section,
+        }
+    }
+}
+
+impl<'a> From<&'a Token> for TokenRef<'a> {
+    fn from(token: &'a Token) -> Self {
+        Self {
+            text: &token.text,
+            lemma: Cow::Borrowed(&token.lemma),
+            pos: token.pos,
+            start: token.start,
+            end: token.end,
+            sentence_idx: token.sentence_idx,
+            token_idx: token.token_idx,
+            is_stopword: token.is_stopword,
+            section: token.section,
+        }
+    }
+}
+
 // ============================================================================
 // Phrase & Chunk
 // ============================================================================
@@ -290,6 +674,10 @@ pub struct Phrase {
     pub count: usize,
     /// Token offsets for each occurrence
     pub offsets: Vec<(usize, usize)>,
+    /// Byte spans `[start, end)` of the occurrences in the original text,
+    /// in document order (all or only the first, per [`SpanMode`])
+    #[serde(default)]
+    pub spans: Vec<(usize, usize)>,
     /// The rank (1-indexed, based on score)
     pub rank: usize,
 }
@@ -308,14 +696,35 @@ impl Phrase {
             score,
             count,
             offsets: Vec::new(),
+            spans: Vec::new(),
             rank: 0,
         }
     }
 
-    /// Stable tie-breaker comparator for deterministic phrase ranking.
+    /// [`spans`](Self::spans) converted to char offsets into `text`, the
+    /// document the phrase was extracted from.
+    ///
+    /// Byte offsets that do not fall on a char boundary are rounded down.
+    pub fn char_spans(&self, text: &str) -> Vec<(usize, usize)> {
+        let to_char = |byte: usize| {
+            let mut byte = byte.min(text.len());
+            while !text.is_char_boundary(byte) {
+                byte -= 1;
+            }
+            text[..byte].chars().count()
+        };
+        self.spans
+            .iter()
+            .map(|&(start, end)| (to_char(start), to_char(end)))
+            .collect()
+    }
+
+    /// Stable tie-breaker comparator used for the final phrase ranking in
+    /// every [`DeterminismMode`].
     ///
-    /// When two phrases have scores within `SCORE_EPSILON`, tie-breakers are
-    /// applied in order:
+    /// Scores are compared after rounding to multiples of
+    /// `SCORE_RESOLUTION`; when two phrases round to the same value,
+    /// tie-breakers are applied in order:
     ///
     /// 1. **Score** descending (primary)
     /// 2. **Earliest first occurrence** ascending (`offsets[0].0`)
@@ -323,19 +732,18 @@ impl Phrase {
     /// 4. **Lemma** lexicographic ascending
     ///
     /// This guarantees a total, deterministic ordering regardless of platform,
-    /// hash seed, or floating-point reduction order.
+    /// hash seed, or floating-point reduction order. Rounding (rather than
+    /// comparing within an epsilon) keeps the order transitive, as
+    /// `sort_by` requires.
     pub fn stable_cmp(&self, other: &Self) -> std::cmp::Ordering {
-        /// Two scores within this epsilon are considered tied.
-        const SCORE_EPSILON: f64 = 1e-10;
-
-        let score_diff = self.score - other.score;
-        if score_diff.abs() > SCORE_EPSILON {
-            // Higher score first (descending).
-            return if score_diff > 0.0 {
-                std::cmp::Ordering::Less
-            } else {
-                std::cmp::Ordering::Greater
-            };
+        /// Scores are rounded to this resolution before comparing.
+        const SCORE_RESOLUTION: f64 = 1e-10;
+
+        // Higher score first (descending).
+        let quantized = |score: f64| (score / SCORE_RESOLUTION).round();
+        let score_ord = quantized(other.score).total_cmp(&quantized(self.score));
+        if score_ord != std::cmp::Ordering::Equal {
+            return score_ord;
         }
 
         // Tie-breaker 1: earliest first occurrence (ascending).
@@ -446,6 +854,184 @@ impl std::str::FromStr for PhraseGrouping {
     }
 }
 
+// ============================================================================
+// Surface Selection
+// ============================================================================
+
+/// Which surface form a grouped phrase reports as its [`Phrase::text`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SurfaceSelection {
+    /// The earliest occurrence in the document, verbatim
+    FirstOccurrence,
+    /// The most frequent surface form; ties go to the lexicographically
+    /// smallest
+    MostFrequent,
+    /// The longest surface form in characters; ties go to the earliest
+    Longest,
+    /// The lemma string instead of a surface form
+    Lemma,
+}
+
+// ============================================================================
+// Overlap Policy
+// ============================================================================
+
+/// How overlapping phrase candidates (e.g. "deep neural" and "neural
+/// networks" over "deep neural networks") are resolved before grouping
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlapPolicy {
+    /// Keep the highest-scored candidate, dropping any that overlap it
+    /// (default)
+    #[default]
+    KeepHighestScored,
+    /// Keep the candidate with the most tokens; ties go to the higher score
+    KeepLongest,
+    /// Keep every candidate, overlapping or not
+    KeepAll,
+    /// Merge overlapping candidates into their maximal span before scoring
+    MergeMaximal,
+}
+
+// ============================================================================
+// Token Filters
+// ============================================================================
+
+/// Token filters applied before graph construction, for inputs (e.g. OCR
+/// output) full of junk tokens. Filtered tokens are marked as stopwords; see
+/// [`crate::nlp::token_filter`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TokenFilter {
+    /// Drop tokens shorter than this many characters
+    pub min_length: Option<usize>,
+    /// Drop tokens longer than this many characters
+    pub max_length: Option<usize>,
+    /// Drop pure numerals such as `"42"`, `"3.14"` or `"1,000"`
+    pub drop_numerals: bool,
+    /// Drop tokens mixing letters and digits, such as `"A12B"` or `"x86"`
+    pub drop_alphanumeric_ids: bool,
+    /// Drop tokens containing a match of this regular expression (anchor
+    /// with `^...$` to match the whole token)
+    pub exclude_pattern: Option<String>,
+}
+
+impl TokenFilter {
+    /// Whether no filter is enabled.
+    pub fn is_noop(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+// ============================================================================
+// Phrase Blocklist
+// ============================================================================
+
+/// A phrase pattern in [`TextRankConfig::phrase_blocklist`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PhrasePattern {
+    /// Matches a phrase whose text or lemma equals this one, ignoring case
+    /// and runs of whitespace
+    Exact(String),
+    /// Matches a phrase whose text or lemma contains a match of this regular
+    /// expression (anchor with `^...$` to match the whole phrase)
+    Regex(String),
+}
+
+// ============================================================================
+// Phrase Spans
+// ============================================================================
+
+/// Which occurrences of a phrase are reported in [`Phrase::spans`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpanMode {
+    /// Every occurrence (default)
+    #[default]
+    All,
+    /// Only the earliest occurrence
+    First,
+}
+
+impl SpanMode {
+    /// Sort `spans` into document order and keep the ones this mode reports.
+    pub fn apply(&self, spans: &mut Vec<(usize, usize)>) {
+        spans.sort_unstable();
+        if *self == SpanMode::First {
+            spans.truncate(1);
+        }
+    }
+}
+
+// ============================================================================
+// Near-Duplicate Merging
+// ============================================================================
+
+/// Normalized string distance used to detect near-duplicate phrase lemmas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StringDistance {
+    /// Levenshtein edit distance divided by the longer lemma's length in
+    /// characters
+    #[default]
+    Levenshtein,
+    /// `1 - Jaro-Winkler similarity`; favours lemmas sharing a prefix
+    JaroWinkler,
+}
+
+/// How the score of a merged phrase is derived from its near-duplicates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeScore {
+    /// Keep the highest score among the merged phrases
+    #[default]
+    Max,
+    /// Add up the scores of the merged phrases
+    Sum,
+}
+
+/// Merging of phrases whose lemmas are within a small string distance
+///
+/// Catches variants that grouping leaves apart, such as spelling variants
+/// ("colour model" / "color model") or untagged plurals. The higher-scored
+/// phrase keeps its surface form; see [`crate::phrase::near_duplicates`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NearDuplicateMerge {
+    /// Distance metric
+    #[serde(default)]
+    pub distance: StringDistance,
+    /// Largest normalized distance (in `[0, 1]`) at which two lemmas merge
+    pub max_distance: f64,
+    /// Score of the merged phrase
+    #[serde(default)]
+    pub score: MergeScore,
+}
+
+impl NearDuplicateMerge {
+    /// Merge lemmas within `max_distance` by Levenshtein, keeping the max score
+    pub fn new(max_distance: f64) -> Self {
+        Self {
+            distance: StringDistance::Levenshtein,
+            max_distance,
+            score: MergeScore::Max,
+        }
+    }
+
+    /// Builder method: set the distance metric
+    pub fn with_distance(mut self, distance: StringDistance) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    /// Builder method: set the score policy
+    pub fn with_score(mut self, score: MergeScore) -> Self {
+        self.score = score;
+        self
+    }
+}
+
 impl ScoreAggregation {
     /// Aggregate a slice of scores
     pub fn aggregate(&self, scores: &[f64]) -> f64 {
@@ -465,18 +1051,165 @@ impl ScoreAggregation {
     }
 }
 
+// ============================================================================
+// Score Normalization
+// ============================================================================
+
+/// Rescaling of final phrase scores, applied when results are formatted
+///
+/// Raw PageRank-derived scores depend on document length and graph shape,
+/// so they are not comparable across documents. Normalization is computed
+/// over the returned phrases (after `top_n`) and never changes their order.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreNormalization {
+    /// Scores as computed (default)
+    #[default]
+    Raw,
+    /// Min-max scaling to `[0, 1]`; all scores become 1 when they are equal
+    MinMax,
+    /// Divide by the total so scores sum to 1
+    Sum,
+    /// Softmax with the given temperature (> 0); lower temperatures sharpen
+    /// the distribution
+    Softmax { temperature: f64 },
+}
+
+impl ScoreNormalization {
+    /// Rescale the scores of `phrases` in place
+    pub fn apply(&self, phrases: &mut [Phrase]) {
+        if phrases.is_empty() {
+            return;
+        }
+        let max = phrases
+            .iter()
+            .map(|p| p.score)
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        match *self {
+            ScoreNormalization::Raw => {}
+            ScoreNormalization::MinMax => {
+                let min = phrases
+                    .iter()
+                    .map(|p| p.score)
+                    .fold(f64::INFINITY, f64::min);
+                let range = max - min;
+                for phrase in phrases {
+                    phrase.score = if range > 0.0 {
+                        (phrase.score - min) / range
+                    } else {
+                        1.0
+                    };
+                }
+            }
+            ScoreNormalization::Sum => {
+                let total: f64 = phrases.iter().map(|p| p.score).sum();
+                if total > 0.0 {
+                    for phrase in phrases {
+                        phrase.score /= total;
+                    }
+                }
+            }
+            ScoreNormalization::Softmax { temperature } => {
+                // Shift by the max score so `exp` cannot overflow.
+                for phrase in phrases.iter_mut() {
+                    phrase.score = ((phrase.score - max) / temperature).exp();
+                }
+                let total: f64 = phrases.iter().map(|p| p.score).sum();
+                for phrase in phrases {
+                    phrase.score /= total;
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Phrase Selection
+// ============================================================================
+
+/// How many of the ranked phrases are returned
+///
+/// A fixed `top_n` suits neither a tweet nor a book chapter; the other modes
+/// derive the cut from the phrase scores instead. Scores are the raw ones,
+/// before [`ScoreNormalization`].
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PhraseSelection {
+    /// The `top_n` highest-scored phrases, all of them when `top_n` is 0
+    /// (default)
+    #[default]
+    TopN,
+    /// Phrases scoring at least `min_score`
+    ScoreThreshold { min_score: f64 },
+    /// The top `fraction` (in `(0, 1]`) of the phrases, rounded up; the
+    /// original TextRank paper keeps a third of the graph's vertices
+    Proportion { fraction: f64 },
+    /// Phrases before the elbow of the descending score curve, where it
+    /// flattens out: the point farthest below the line from the first score
+    /// to the last
+    Elbow,
+}
+
+impl PhraseSelection {
+    /// Number of phrases to keep from `scores`, sorted descending, or
+    /// `None` to keep them all
+    pub fn limit(&self, top_n: usize, scores: &[f64]) -> Option<usize> {
+        match *self {
+            PhraseSelection::TopN => (top_n > 0).then_some(top_n),
+            PhraseSelection::ScoreThreshold { min_score } => {
+                Some(scores.iter().filter(|&&s| s >= min_score).count())
+            }
+            PhraseSelection::Proportion { fraction } => {
+                Some((fraction * scores.len() as f64).ceil() as usize)
+            }
+            PhraseSelection::Elbow => Some(elbow(scores)),
+        }
+    }
+}
+
+/// Length of the prefix of descending `scores` before the elbow.
+///
+/// Both axes are scaled to `[0, 1]`, so the elbow is the point maximizing
+/// `1 - x - y`. Curves with no point below the chord (linear or concave)
+/// have no elbow and are kept whole.
+fn elbow(scores: &[f64]) -> usize {
+    let n = scores.len();
+    if n < 3 {
+        return n;
+    }
+    let (first, last) = (scores[0], scores[n - 1]);
+    let range = first - last;
+    if range <= 0.0 {
+        return n;
+    }
+    let mut best = (0.0, n);
+    for (i, &score) in scores.iter().enumerate() {
+        let x = i as f64 / (n - 1) as f64;
+        let y = (score - last) / range;
+        let below = 1.0 - x - y;
+        if below > best.0 {
+            best = (below, i);
+        }
+    }
+    best.1
+}
+
 // ============================================================================
 // Determinism Mode
 // ============================================================================
 
 /// Controls whether pipeline execution prioritizes speed or reproducibility.
 ///
-/// By default the library uses the fastest available code path, which may
-/// include parallel reductions, non-deterministic hash iteration order, and
-/// floating-point summation order that varies across runs. Setting
-/// `Deterministic` forces every stage to use stable ordering and
-/// deterministic reductions so that the same input always produces the
-/// same output, at a potential throughput cost.
+/// Ordering never depends on hash iteration in either mode: graph nodes are
+/// numbered by first occurrence (or sorted key, when built in parallel),
+/// phrase groups are visited in key order, and equal-scored phrases are
+/// ordered by [`Phrase::stable_cmp`]. What remains is floating-point
+/// summation order: by default, graphs above
+/// [`TextRankConfig::parallel_threshold`] nodes are ranked with parallel
+/// reductions whose scores may differ in the last bits between runs. Setting
+/// `Deterministic` keeps every reduction serial so that the same input
+/// always produces byte-identical output, at a potential throughput cost.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum DeterminismMode {
@@ -486,11 +1219,8 @@ pub enum DeterminismMode {
     Default,
     /// Stable, reproducible results across runs and machines.
     ///
-    /// When active, stages must:
-    /// - use sorted keys instead of hash-map iteration order,
-    /// - build CSR graphs in a stable order,
-    /// - apply deterministic reductions,
-    /// - use the stable tie-breaker comparator for final ranking.
+    /// When active, stages must also apply deterministic (serial)
+    /// reductions.
     Deterministic,
 }
 
@@ -501,12 +1231,227 @@ impl DeterminismMode {
     }
 }
 
-// ============================================================================
-// Configuration
-// ============================================================================
-
+/// Floating-point precision of the PageRank power iteration.
+///
+/// `F32` halves the memory traffic of the score vectors and edge weights,
+/// which speeds up ranking on large graphs. Keyphrase ordering rarely
+/// depends on double precision, but near-ties may resolve differently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RankPrecision {
+    /// Double precision (reference results).
+    #[default]
+    F64,
+    /// Single precision; scores are widened back to `f64` on output.
+    F32,
+}
+
+/// Convergence acceleration scheme for the PageRank power iteration.
+///
+/// Extrapolation periodically jumps ahead towards the fixed point, cutting
+/// iteration counts on slowly converging graphs (high damping, long chains).
+/// Accelerated scores agree with plain iteration up to the convergence
+/// threshold, so results are not bit-identical to [`RankerAcceleration::None`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RankerAcceleration {
+    /// Plain power iteration.
+    #[default]
+    None,
+    /// Component-wise Aitken Δ² extrapolation.
+    Aitken,
+    /// Quadratic extrapolation (Kamvar et al., 2003).
+    QuadraticExtrapolation,
+}
+
+/// What the pipeline does when PageRank reaches `max_iterations` without
+/// converging.
+///
+/// Rankers stopped early by an observer or a budget are not affected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NonConvergencePolicy {
+    /// Use the last iterate silently.
+    #[default]
+    Continue,
+    /// Use the last iterate and report a
+    /// [`ConvergenceFailed`](crate::pipeline::ErrorCode::ConvergenceFailed)
+    /// warning to the observer's
+    /// [`on_warning`](crate::pipeline::PipelineObserver::on_warning).
+    WarnAndContinue,
+    /// End the run with a
+    /// [`ConvergenceFailed`](crate::pipeline::ErrorCode::ConvergenceFailed)
+    /// error and no phrases.
+    Fail,
+}
+
+/// Snowball stemming language for [`LemmaStrategy::Stem`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StemLanguage {
+    Arabic,
+    Danish,
+    Dutch,
+    English,
+    Finnish,
+    French,
+    German,
+    Greek,
+    Hungarian,
+    Italian,
+    Norwegian,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+    Swedish,
+    Tamil,
+    Turkish,
+}
+
+impl StemLanguage {
+    /// Look up a stemming language by ISO 639-1 code (e.g., "en", "de")
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.to_lowercase().as_str() {
+            "ar" => Some(StemLanguage::Arabic),
+            "da" => Some(StemLanguage::Danish),
+            "nl" => Some(StemLanguage::Dutch),
+            "en" => Some(StemLanguage::English),
+            "fi" => Some(StemLanguage::Finnish),
+            "fr" => Some(StemLanguage::French),
+            "de" => Some(StemLanguage::German),
+            "el" => Some(StemLanguage::Greek),
+            "hu" => Some(StemLanguage::Hungarian),
+            "it" => Some(StemLanguage::Italian),
+            "no" | "nb" => Some(StemLanguage::Norwegian),
+            "pt" => Some(StemLanguage::Portuguese),
+            "ro" => Some(StemLanguage::Romanian),
+            "ru" => Some(StemLanguage::Russian),
+            "es" => Some(StemLanguage::Spanish),
+            "sv" => Some(StemLanguage::Swedish),
+            "ta" => Some(StemLanguage::Tamil),
+            "tr" => Some(StemLanguage::Turkish),
+            _ => None,
+        }
+    }
+}
+
+/// How token lemmas are populated before candidate selection
+///
+/// External lemmatizers (e.g., spaCy) supply good lemmas, so the default
+/// keeps them untouched. When no lemmatizer is available, `Stem` derives
+/// lemmas with a Snowball stemmer (requires the `stemming` feature).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LemmaStrategy {
+    /// Keep the lemma supplied with each token
+    #[default]
+    AsProvided,
+    /// Use the lowercased surface form
+    Lowercase,
+    /// Snowball-stem the lowercased surface form
+    Stem(StemLanguage),
+}
+
+/// Unicode normalization form applied to lemmas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnicodeForm {
+    /// Leave code points as provided
+    #[default]
+    None,
+    /// Canonical composition
+    Nfc,
+    /// Compatibility composition (also folds ligatures, full-width forms, ...)
+    Nfkc,
+}
+
+/// Case folding applied to lemmas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseFolding {
+    /// Leave case as provided
+    #[default]
+    None,
+    /// Lowercase ASCII letters only
+    Ascii,
+    /// Full Unicode case folding (e.g., "Straße" → "strasse")
+    Full,
+}
+
+/// Text normalization applied to lemmas before graph construction
+///
+/// Without normalization, the same word in different Unicode forms (e.g.,
+/// precomposed "é" vs. "e" + combining accent) becomes separate graph nodes
+/// and splits its score. All options are off by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct TextNormalization {
+    /// Unicode normalization form
+    #[serde(default)]
+    pub unicode_form: UnicodeForm,
+    /// Case folding mode
+    #[serde(default)]
+    pub case_folding: CaseFolding,
+    /// Remove combining diacritical marks ("café" → "cafe")
+    #[serde(default)]
+    pub strip_diacritics: bool,
+}
+
+impl TextNormalization {
+    /// Returns `true` when normalization leaves text unchanged.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Linking of spelling variants before graph construction
+///
+/// Without linking, "support vector machine" and its acronym "SVM", or
+/// "co-occurrence" and "cooccurrence", become separate graph nodes and split
+/// their score. Applied when tokenizing raw text; see
+/// [`link_variants`](crate::nlp::variants::link_variants). All options are
+/// off by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct VariantLinking {
+    /// Map acronyms defined as "expansion (ACRONYM)" onto their expansion
+    #[serde(default)]
+    pub acronyms: bool,
+    /// Drop soft hyphens and join hyphenated compounds whose unhyphenated
+    /// form also occurs in the document
+    #[serde(default)]
+    pub hyphenation: bool,
+}
+
+impl VariantLinking {
+    /// Returns `true` when linking leaves tokens unchanged.
+    pub fn is_identity(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
 /// Configuration for TextRank extraction
+///
+/// Serializes to a JSON object keyed by field name. Missing fields take
+/// their [`Default`] value, so a config file only needs the fields it
+/// changes:
+///
+/// ```
+/// use rapid_textrank::TextRankConfig;
+///
+/// let cfg: TextRankConfig = serde_json::from_str(r#"{ "window_size": 10, "top_n": 5 }"#).unwrap();
+/// assert_eq!(cfg.window_size, 10);
+/// assert_eq!(cfg.damping, 0.85);
+/// ```
+///
+/// Named presets ([`textrank`](Self::textrank),
+/// [`singlerank`](Self::singlerank), ...) start from published parameter
+/// sets instead.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TextRankConfig {
     /// Damping factor for PageRank (typically 0.85)
     pub damping: f64,
@@ -522,10 +1467,27 @@ pub struct TextRankConfig {
     pub min_phrase_length: usize,
     /// Maximum phrase length in tokens
     pub max_phrase_length: usize,
+    /// Drop phrases with fewer words than this (default: off). Unlike
+    /// `min_phrase_length`, which bounds the chunker in tokens, this counts
+    /// the alphanumeric words of every scored candidate, whatever selected it.
+    #[serde(default)]
+    pub min_phrase_words: Option<usize>,
+    /// Drop phrases with more words than this (default: off).
+    #[serde(default)]
+    pub max_phrase_words: Option<usize>,
+    /// Drop phrases occurring fewer times than this among the candidates
+    /// (default: off).
+    #[serde(default)]
+    pub min_phrase_frequency: Option<usize>,
     /// Score aggregation method
     pub score_aggregation: ScoreAggregation,
     /// Language code for stopwords (e.g., "en", "de", "fr")
     pub language: String,
+    /// Detect each raw-text document's language and switch `language`, the
+    /// stemmer and the default POS filter to it (default: off; requires the
+    /// `lang-detect` feature, see [`crate::nlp::langdetect`]).
+    #[serde(default)]
+    pub detect_language: bool,
     /// Include edge weights in graph (co-occurrence counts)
     pub use_edge_weights: bool,
     /// POS tags to include in graph
@@ -533,8 +1495,30 @@ pub struct TextRankConfig {
     /// Optional additional stopwords list (extends the built-in list when provided)
     #[serde(default)]
     pub stopwords: Vec<String>,
+    /// Words allowed inside a phrase between two noun phrases, e.g. `"of"`
+    /// for "quality of service" (default: none, so stopwords always split
+    /// phrases). Bridge words never become graph nodes.
+    #[serde(default)]
+    pub phrase_bridge_words: Vec<String>,
+    /// Junk-token filters applied before graph construction (default:
+    /// none).
+    #[serde(default)]
+    pub token_filter: TokenFilter,
+    /// Phrases never returned, e.g. boilerplate such as "figure 1"
+    /// (default: none). See [`crate::phrase::blocklist`].
+    #[serde(default)]
+    pub phrase_blocklist: Vec<PhrasePattern>,
+    /// Terms that must stay candidates: they are never treated as
+    /// stopwords, and phrases matching one are never blocklisted (default:
+    /// none).
+    #[serde(default)]
+    pub keep_terms: Vec<String>,
     /// Whether to include POS tags in graph node keys
     pub use_pos_in_nodes: bool,
+    /// Graph node key strategy; overrides `use_pos_in_nodes` when set
+    /// (default: `None`, see [`effective_node_key`](Self::effective_node_key)).
+    #[serde(default)]
+    pub node_key: Option<NodeKey>,
     /// How to group phrase variants
     pub phrase_grouping: PhraseGrouping,
     /// Determinism mode (default: fastest; opt-in: reproducible)
@@ -552,12 +1536,100 @@ pub struct TextRankConfig {
     /// Maximum graph edges before rejecting (pipeline runtime limit).
     #[serde(default)]
     pub max_edges: Option<usize>,
+    /// Wall-clock budget for any single pipeline stage, in milliseconds.
+    #[serde(default)]
+    pub stage_timeout_ms: Option<u64>,
+    /// Wall-clock budget for the whole pipeline run, in milliseconds.
+    #[serde(default)]
+    pub pipeline_timeout_ms: Option<u64>,
+    /// How token lemmas are populated during preprocessing (default: as provided).
+    ///
+    /// Applied together with `normalization` by the built-in tokenizer,
+    /// [`extract_keyphrases`](crate::phrase::extraction::extract_keyphrases),
+    /// batch extraction, and spec-built pipelines. Hand-built pipelines and
+    /// the variant extractors (`SingleRank`, `PositionRank`, ...) keep
+    /// token lemmas unless a
+    /// [`LemmaPreprocessor`](crate::pipeline::LemmaPreprocessor) is installed.
+    #[serde(default)]
+    pub lemma_strategy: LemmaStrategy,
+    /// Unicode normalization and case folding of lemmas (default: none).
+    #[serde(default)]
+    pub normalization: TextNormalization,
+    /// Acronym and hyphenation variant linking of raw text (default: none).
+    #[serde(default)]
+    pub variant_linking: VariantLinking,
+    /// Graph size (in nodes) at which ranking switches to the parallel
+    /// PageRank implementation (`parallel` feature only; default: 50,000).
+    ///
+    /// Applies in [`DeterminismMode::Deterministic`] too: parallel ranking
+    /// is bit-identical to the serial path.
+    #[serde(default = "default_parallel_threshold")]
+    pub parallel_threshold: usize,
+    /// Floating-point precision used by PageRank (default: `f64`).
+    ///
+    /// `f32` always runs serially, taking precedence over
+    /// [`parallel_threshold`](Self::parallel_threshold).
+    #[serde(default)]
+    pub rank_precision: RankPrecision,
+    /// PageRank convergence acceleration (default: none).
+    ///
+    /// Accelerated ranking always runs serially in `f64`, taking precedence
+    /// over [`rank_precision`](Self::rank_precision) and
+    /// [`parallel_threshold`](Self::parallel_threshold).
+    #[serde(default)]
+    pub acceleration: RankerAcceleration,
+    /// Redistribution of dangling-node mass in PageRank (default: follow
+    /// the teleport distribution).
+    #[serde(default)]
+    pub dangling_policy: DanglingPolicy,
+    /// Norm compared against `convergence_threshold` (default: L1).
+    #[serde(default)]
+    pub convergence_criterion: ConvergenceCriterion,
+    /// Handling of PageRank runs that hit `max_iterations` without
+    /// converging (default: continue silently).
+    #[serde(default)]
+    pub non_convergence: NonConvergencePolicy,
+    /// Re-rank the final phrases with Maximal Marginal Relevance using this
+    /// lambda in `[0, 1]` (default: off). Lower values favour diversity over
+    /// score; see [`crate::phrase::mmr`].
+    #[serde(default)]
+    pub mmr_lambda: Option<f64>,
+    /// Merge phrases whose lemmas are within a normalized edit distance of
+    /// each other (default: off).
+    #[serde(default)]
+    pub near_duplicates: Option<NearDuplicateMerge>,
+    /// How many ranked phrases are returned (default: `top_n`).
+    #[serde(default)]
+    pub phrase_selection: PhraseSelection,
+    /// Occurrences reported in [`Phrase::spans`] (default: all).
+    #[serde(default)]
+    pub span_mode: SpanMode,
+    /// Surface form reported for a phrase seen in several forms, by every
+    /// phrase builder (default: each builder's own choice — the most
+    /// frequent one under lemma grouping, the highest-scored one under
+    /// scrubbed-text grouping, the first occurrence for TopicRank).
+    #[serde(default)]
+    pub surface_selection: Option<SurfaceSelection>,
+    /// Resolution of overlapping phrase candidates (default: keep the
+    /// highest-scored one).
+    #[serde(default)]
+    pub overlap_policy: OverlapPolicy,
+    /// Rescaling of the returned phrase scores (default: raw).
+    #[serde(default)]
+    pub score_normalization: ScoreNormalization,
 }
 
 fn default_debug_top_k() -> usize {
     crate::pipeline::artifacts::DebugLevel::DEFAULT_TOP_K
 }
 
+fn default_parallel_threshold() -> usize {
+    crate::pagerank::DEFAULT_PARALLEL_THRESHOLD
+}
+
+/// Names accepted by [`TextRankConfig::preset`]
+pub const CONFIG_PRESETS: &[&str] = &["textrank", "singlerank", "positionrank", "topicalpagerank"];
+
 impl Default for TextRankConfig {
     fn default() -> Self {
         Self {
@@ -568,8 +1640,12 @@ impl Default for TextRankConfig {
             top_n: 10,
             min_phrase_length: 1,
             max_phrase_length: 4,
+            min_phrase_words: None,
+            max_phrase_words: None,
+            min_phrase_frequency: None,
             score_aggregation: ScoreAggregation::Sum,
             language: "en".to_string(),
+            detect_language: false,
             use_edge_weights: true,
             include_pos: vec![
                 PosTag::Noun,
@@ -578,13 +1654,36 @@ impl Default for TextRankConfig {
                 PosTag::Verb,
             ],
             stopwords: Vec::new(),
+            phrase_bridge_words: Vec::new(),
+            token_filter: TokenFilter::default(),
+            phrase_blocklist: Vec::new(),
+            keep_terms: Vec::new(),
             use_pos_in_nodes: true,
+            node_key: None,
             phrase_grouping: PhraseGrouping::ScrubbedText,
             determinism: DeterminismMode::Default,
             debug_level: crate::pipeline::artifacts::DebugLevel::None,
             debug_top_k: default_debug_top_k(),
             max_nodes: None,
             max_edges: None,
+            stage_timeout_ms: None,
+            pipeline_timeout_ms: None,
+            lemma_strategy: LemmaStrategy::AsProvided,
+            normalization: TextNormalization::default(),
+            variant_linking: VariantLinking::default(),
+            parallel_threshold: default_parallel_threshold(),
+            rank_precision: RankPrecision::F64,
+            acceleration: RankerAcceleration::None,
+            dangling_policy: DanglingPolicy::Personalization,
+            convergence_criterion: ConvergenceCriterion::L1,
+            non_convergence: NonConvergencePolicy::Continue,
+            mmr_lambda: None,
+            near_duplicates: None,
+            phrase_selection: PhraseSelection::TopN,
+            span_mode: SpanMode::All,
+            surface_selection: None,
+            overlap_policy: OverlapPolicy::KeepHighestScored,
+            score_normalization: ScoreNormalization::Raw,
         }
     }
 }
@@ -595,42 +1694,263 @@ impl TextRankConfig {
         Self::default()
     }
 
-    /// Validate the configuration
+    /// TextRank as published by Mihalcea & Tarau (2004): unweighted
+    /// co-occurrence within a window of 2, nouns and adjectives only,
+    /// damping 0.85, convergence threshold 1e-4.
+    pub fn textrank() -> Self {
+        Self {
+            window_size: 2,
+            use_edge_weights: false,
+            include_pos: vec![PosTag::Noun, PosTag::Adjective, PosTag::ProperNoun],
+            convergence_threshold: 1e-4,
+            ..Self::default()
+        }
+    }
+
+    /// SingleRank as published by Wan & Xiao (2008): TextRank with
+    /// co-occurrence counts as edge weights over a window of 10.
+    pub fn singlerank() -> Self {
+        Self {
+            window_size: 10,
+            use_edge_weights: true,
+            ..Self::textrank()
+        }
+    }
+
+    /// PositionRank as published by Florescu & Caragea (2017): the
+    /// SingleRank graph, with phrases of at most 3 words. Use with
+    /// [`PositionRank`](crate::variants::position_rank::PositionRank) for
+    /// the position-biased random walk.
+    pub fn positionrank() -> Self {
+        Self {
+            max_phrase_length: 3,
+            ..Self::singlerank()
+        }
+    }
+
+    /// Topical PageRank as published by Sterckx et al. (2015): the
+    /// SingleRank graph. Use with
+    /// [`TopicalPageRank`](crate::variants::topical_pagerank::TopicalPageRank)
+    /// and topic weights for the topic-biased random walk.
+    pub fn topicalpagerank() -> Self {
+        Self::singlerank()
+    }
+
+    /// Look up a preset by name: one of [`CONFIG_PRESETS`], ignoring case,
+    /// `_` and `-` (so `"position_rank"` works too).
+    pub fn preset(name: &str) -> Result<Self> {
+        let key: String = name
+            .chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .flat_map(char::to_lowercase)
+            .collect();
+        match key.as_str() {
+            "textrank" => Ok(Self::textrank()),
+            "singlerank" => Ok(Self::singlerank()),
+            "positionrank" => Ok(Self::positionrank()),
+            "topicalpagerank" => Ok(Self::topicalpagerank()),
+            _ => Err(TextRankError::invalid_config(format!(
+                "unknown config preset \"{name}\"; expected one of: {}",
+                CONFIG_PRESETS.join(", ")
+            ))),
+        }
+    }
+
+    /// Validate the configuration, returning the first problem found.
+    ///
+    /// The error is a [`TextRankError::InvalidConfig`] tagged with an
+    /// [`ErrorCode`] and the offending field; use
+    /// [`validation_errors`](Self::validation_errors) to get all of them.
     pub fn validate(&self) -> Result<()> {
-        if !(0.0..=1.0).contains(&self.damping) {
-            return Err(TextRankError::invalid_config(format!(
-                "damping must be between 0 and 1, got {}",
-                self.damping
-            )));
+        match self.validation_errors().into_iter().next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Every problem with the configuration, in field order
+    ///
+    /// Out-of-range values carry [`ErrorCode::InvalidValue`], fields that
+    /// contradict each other [`ErrorCode::InvalidCombo`], and options that
+    /// need a disabled cargo feature [`ErrorCode::ModuleUnavailable`].
+    pub fn validation_errors(&self) -> Vec<TextRankError> {
+        let mut errors = Vec::new();
+        let mut push = |code, field, message: String| {
+            errors.push(TextRankError::invalid_field(code, field, message));
+        };
+
+        if !(self.damping > 0.0 && self.damping < 1.0) {
+            push(
+                ErrorCode::InvalidValue,
+                "damping",
+                format!(
+                    "damping must be between 0 and 1 (exclusive), got {}",
+                    self.damping
+                ),
+            );
         }
 
         if self.max_iterations == 0 {
-            return Err(TextRankError::invalid_config("max_iterations must be > 0"));
+            push(
+                ErrorCode::InvalidValue,
+                "max_iterations",
+                "max_iterations must be > 0".to_string(),
+            );
         }
 
-        if self.convergence_threshold <= 0.0 {
-            return Err(TextRankError::invalid_config(
-                "convergence_threshold must be > 0",
-            ));
+        if self.convergence_threshold.is_nan() || self.convergence_threshold <= 0.0 {
+            push(
+                ErrorCode::InvalidValue,
+                "convergence_threshold",
+                format!(
+                    "convergence_threshold must be > 0, got {}",
+                    self.convergence_threshold
+                ),
+            );
         }
 
         if self.window_size < 2 {
-            return Err(TextRankError::invalid_config("window_size must be >= 2"));
+            push(
+                ErrorCode::InvalidValue,
+                "window_size",
+                format!("window_size must be >= 2, got {}", self.window_size),
+            );
         }
 
         if self.min_phrase_length == 0 {
-            return Err(TextRankError::invalid_config(
-                "min_phrase_length must be > 0",
-            ));
+            push(
+                ErrorCode::InvalidValue,
+                "min_phrase_length",
+                "min_phrase_length must be > 0".to_string(),
+            );
+        } else if self.max_phrase_length < self.min_phrase_length {
+            push(
+                ErrorCode::InvalidCombo,
+                "max_phrase_length",
+                format!(
+                    "max_phrase_length ({}) must be >= min_phrase_length ({})",
+                    self.max_phrase_length, self.min_phrase_length
+                ),
+            );
+        }
+
+        if self.max_phrase_words == Some(0) {
+            push(
+                ErrorCode::InvalidValue,
+                "max_phrase_words",
+                "max_phrase_words must be > 0".to_string(),
+            );
+        } else if let (Some(min), Some(max)) = (self.min_phrase_words, self.max_phrase_words) {
+            if max < min {
+                push(
+                    ErrorCode::InvalidCombo,
+                    "max_phrase_words",
+                    format!("max_phrase_words ({max}) must be >= min_phrase_words ({min})"),
+                );
+            }
         }
 
-        if self.max_phrase_length < self.min_phrase_length {
-            return Err(TextRankError::invalid_config(
-                "max_phrase_length must be >= min_phrase_length",
-            ));
+        if let Some(lambda) = self.mmr_lambda {
+            if !(0.0..=1.0).contains(&lambda) {
+                push(
+                    ErrorCode::InvalidValue,
+                    "mmr_lambda",
+                    format!("mmr_lambda must be between 0 and 1, got {}", lambda),
+                );
+            }
+        }
+
+        if let Some(merge) = &self.near_duplicates {
+            if !(0.0..=1.0).contains(&merge.max_distance) {
+                push(
+                    ErrorCode::InvalidValue,
+                    "near_duplicates.max_distance",
+                    format!(
+                        "near_duplicates.max_distance must be between 0 and 1, got {}",
+                        merge.max_distance
+                    ),
+                );
+            }
+        }
+
+        match self.phrase_selection {
+            PhraseSelection::ScoreThreshold { min_score } if min_score.is_nan() => push(
+                ErrorCode::InvalidValue,
+                "phrase_selection.min_score",
+                "phrase_selection.min_score must be a number".to_string(),
+            ),
+            PhraseSelection::Proportion { fraction } if !(fraction > 0.0 && fraction <= 1.0) => {
+                push(
+                    ErrorCode::InvalidValue,
+                    "phrase_selection.fraction",
+                    format!(
+                        "phrase_selection.fraction must be in (0, 1], got {}",
+                        fraction
+                    ),
+                )
+            }
+            _ => {}
+        }
+
+        if let ScoreNormalization::Softmax { temperature } = self.score_normalization {
+            if temperature.is_nan() || temperature <= 0.0 {
+                push(
+                    ErrorCode::InvalidValue,
+                    "score_normalization.temperature",
+                    format!("softmax temperature must be > 0, got {}", temperature),
+                );
+            }
         }
 
-        Ok(())
+        if let (Some(min), Some(max)) = (self.token_filter.min_length, self.token_filter.max_length)
+        {
+            if max < min {
+                push(
+                    ErrorCode::InvalidCombo,
+                    "token_filter.max_length",
+                    format!("token_filter.max_length ({max}) must be >= min_length ({min})"),
+                );
+            }
+        }
+        if let Some(re) = &self.token_filter.exclude_pattern {
+            if let Err(err) = regex::Regex::new(re) {
+                push(
+                    ErrorCode::InvalidValue,
+                    "token_filter.exclude_pattern",
+                    format!("invalid token exclusion regex {:?}: {}", re, err),
+                );
+            }
+        }
+
+        for pattern in &self.phrase_blocklist {
+            if let PhrasePattern::Regex(re) = pattern {
+                if let Err(err) = regex::Regex::new(re) {
+                    push(
+                        ErrorCode::InvalidValue,
+                        "phrase_blocklist",
+                        format!("invalid blocklist regex {:?}: {}", re, err),
+                    );
+                }
+            }
+        }
+
+        if matches!(self.lemma_strategy, LemmaStrategy::Stem(_)) && !cfg!(feature = "stemming") {
+            push(
+                ErrorCode::ModuleUnavailable,
+                "lemma_strategy",
+                "lemma_strategy stem requires the `stemming` feature".to_string(),
+            );
+        }
+
+        if self.detect_language && !cfg!(feature = "lang-detect") {
+            push(
+                ErrorCode::ModuleUnavailable,
+                "detect_language",
+                "detect_language requires the `lang-detect` feature".to_string(),
+            );
+        }
+
+        errors
     }
 
     /// Builder method: set damping factor
@@ -663,12 +1983,68 @@ impl TextRankConfig {
         self
     }
 
+    /// Builder method: keep only phrases of `min..=max` words
+    pub fn with_phrase_words(mut self, min: usize, max: usize) -> Self {
+        self.min_phrase_words = Some(min);
+        self.max_phrase_words = Some(max);
+        self
+    }
+
+    /// Builder method: let these words join noun phrases into one phrase
+    pub fn with_phrase_bridge_words<S: Into<String>>(
+        mut self,
+        words: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.phrase_bridge_words = words.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Builder method: add stopwords on top of the built-in list
+    pub fn with_stopwords<S: Into<String>>(mut self, words: impl IntoIterator<Item = S>) -> Self {
+        self.stopwords.extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    /// Builder method: filter junk tokens before graph construction
+    pub fn with_token_filter(mut self, filter: TokenFilter) -> Self {
+        self.token_filter = filter;
+        self
+    }
+
+    /// Builder method: never return phrases matching these patterns
+    pub fn with_phrase_blocklist(
+        mut self,
+        patterns: impl IntoIterator<Item = PhrasePattern>,
+    ) -> Self {
+        self.phrase_blocklist = patterns.into_iter().collect();
+        self
+    }
+
+    /// Builder method: keep these terms as candidates despite the stopword
+    /// list and phrase blocklist
+    pub fn with_keep_terms<S: Into<String>>(mut self, terms: impl IntoIterator<Item = S>) -> Self {
+        self.keep_terms = terms.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Builder method: keep only phrases seen at least `min` times
+    pub fn with_min_phrase_frequency(mut self, min: usize) -> Self {
+        self.min_phrase_frequency = Some(min);
+        self
+    }
+
     /// Builder method: set language
     pub fn with_language(mut self, language: impl Into<String>) -> Self {
         self.language = language.into();
         self
     }
 
+    /// Builder method: enable per-document language detection
+    pub fn with_detect_language(mut self, detect: bool) -> Self {
+        self.detect_language = detect;
+        self
+    }
+
     /// Builder method: set score aggregation
     pub fn with_score_aggregation(mut self, aggregation: ScoreAggregation) -> Self {
         self.score_aggregation = aggregation;
@@ -681,6 +2057,117 @@ impl TextRankConfig {
         self
     }
 
+    /// Builder method: set lemma strategy
+    pub fn with_lemma_strategy(mut self, strategy: LemmaStrategy) -> Self {
+        self.lemma_strategy = strategy;
+        self
+    }
+
+    /// Builder method: set lemma normalization
+    pub fn with_normalization(mut self, normalization: TextNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Builder method: set acronym and hyphenation variant linking
+    pub fn with_variant_linking(mut self, linking: VariantLinking) -> Self {
+        self.variant_linking = linking;
+        self
+    }
+
+    /// Builder method: set the node count at which ranking runs in parallel
+    pub fn with_parallel_threshold(mut self, threshold: usize) -> Self {
+        self.parallel_threshold = threshold;
+        self
+    }
+
+    /// Builder method: set the PageRank floating-point precision
+    pub fn with_rank_precision(mut self, precision: RankPrecision) -> Self {
+        self.rank_precision = precision;
+        self
+    }
+
+    /// Builder method: set the PageRank convergence acceleration scheme
+    pub fn with_acceleration(mut self, acceleration: RankerAcceleration) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// Builder method: set the PageRank dangling-node policy
+    pub fn with_dangling_policy(mut self, policy: DanglingPolicy) -> Self {
+        self.dangling_policy = policy;
+        self
+    }
+
+    /// Builder method: set the PageRank convergence criterion
+    pub fn with_convergence_criterion(mut self, criterion: ConvergenceCriterion) -> Self {
+        self.convergence_criterion = criterion;
+        self
+    }
+
+    /// Builder method: set the non-convergence policy
+    pub fn with_non_convergence(mut self, policy: NonConvergencePolicy) -> Self {
+        self.non_convergence = policy;
+        self
+    }
+
+    /// Builder method: diversify the final phrases with MMR (`lambda` in
+    /// `[0, 1]`, 1 = pure score order)
+    pub fn with_mmr(mut self, lambda: f64) -> Self {
+        self.mmr_lambda = Some(lambda);
+        self
+    }
+
+    /// Builder method: set the normalization of returned phrase scores
+    pub fn with_score_normalization(mut self, normalization: ScoreNormalization) -> Self {
+        self.score_normalization = normalization;
+        self
+    }
+
+    /// Builder method: set how many ranked phrases are returned
+    pub fn with_phrase_selection(mut self, selection: PhraseSelection) -> Self {
+        self.phrase_selection = selection;
+        self
+    }
+
+    /// Builder method: set which phrase occurrences are reported as spans
+    pub fn with_span_mode(mut self, mode: SpanMode) -> Self {
+        self.span_mode = mode;
+        self
+    }
+
+    /// Builder method: set the graph node key strategy
+    pub fn with_node_key(mut self, node_key: NodeKey) -> Self {
+        self.node_key = Some(node_key);
+        self
+    }
+
+    /// Graph node key strategy: `node_key` when set, otherwise
+    /// [`NodeKey::LemmaPos`] or [`NodeKey::Lemma`] per `use_pos_in_nodes`.
+    pub fn effective_node_key(&self) -> NodeKey {
+        self.node_key
+            .clone()
+            .unwrap_or_else(|| self.use_pos_in_nodes.into())
+    }
+
+    /// Builder method: set which surface form a phrase reports
+    pub fn with_surface_selection(mut self, selection: SurfaceSelection) -> Self {
+        self.surface_selection = Some(selection);
+        self
+    }
+
+    /// Builder method: set how overlapping phrase candidates are resolved
+    pub fn with_overlap_policy(mut self, policy: OverlapPolicy) -> Self {
+        self.overlap_policy = policy;
+        self
+    }
+
+    /// Builder method: merge near-duplicate phrases
+    pub fn with_near_duplicate_merge(mut self, merge: NearDuplicateMerge) -> Self {
+        self.near_duplicates = Some(merge);
+        self
+    }
+
     /// Builder method: set debug output level
     pub fn with_debug_level(mut self, level: crate::pipeline::artifacts::DebugLevel) -> Self {
         self.debug_level = level;
@@ -704,6 +2191,18 @@ impl TextRankConfig {
         self.max_edges = Some(max);
         self
     }
+
+    /// Builder method: set the per-stage wall-clock budget (milliseconds)
+    pub fn with_stage_timeout_ms(mut self, ms: u64) -> Self {
+        self.stage_timeout_ms = Some(ms);
+        self
+    }
+
+    /// Builder method: set the whole-pipeline wall-clock budget (milliseconds)
+    pub fn with_pipeline_timeout_ms(mut self, ms: u64) -> Self {
+        self.pipeline_timeout_ms = Some(ms);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -724,6 +2223,39 @@ mod tests {
         assert_eq!(pool.len(), 2);
     }
 
+    #[test]
+    fn test_shared_string_pool_across_threads() {
+        let shared = SharedStringPool::with_shards(4);
+        let docs = [["graph", "rank"], ["rank", "node"], ["node", "graph"]];
+        let pools: Vec<StringPool> = std::thread::scope(|scope| {
+            let handles: Vec<_> = docs
+                .iter()
+                .map(|words| {
+                    let shared = &shared;
+                    scope.spawn(move || {
+                        let mut pool = StringPool::new();
+                        for word in words {
+                            pool.intern_shared(word, shared);
+                        }
+                        pool
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        // Per-document IDs stay dense; the strings exist once corpus-wide.
+        assert!(pools.iter().all(|p| p.len() == 2));
+        assert_eq!(pools[1].get(0), Some("rank"));
+        assert_eq!(shared.len(), 3);
+        assert!(shared.contains("node") && !shared.contains("edge"));
+        assert!(Arc::ptr_eq(
+            &shared.intern("graph"),
+            &shared.intern("graph")
+        ));
+        assert_eq!(SharedStringPool::with_shards(0).intern("x").as_ref(), "x");
+    }
+
     #[test]
     fn test_score_aggregation() {
         let scores = vec![1.0, 2.0, 3.0, 4.0];
@@ -747,6 +2279,133 @@ mod tests {
 
         let bad_config = TextRankConfig::default().with_window_size(1);
         assert!(bad_config.validate().is_err());
+
+        assert!(TextRankConfig::default().with_mmr(0.7).validate().is_ok());
+        assert!(TextRankConfig::default().with_mmr(1.2).validate().is_err());
+        assert!(TextRankConfig::default()
+            .with_near_duplicate_merge(NearDuplicateMerge::new(0.2))
+            .validate()
+            .is_ok());
+        assert!(TextRankConfig::default()
+            .with_near_duplicate_merge(NearDuplicateMerge::new(-0.1))
+            .validate()
+            .is_err());
+        assert!(TextRankConfig::default()
+            .with_score_normalization(ScoreNormalization::Softmax { temperature: 0.0 })
+            .validate()
+            .is_err());
+        assert!(TextRankConfig::default()
+            .with_phrase_words(2, 5)
+            .with_min_phrase_frequency(2)
+            .validate()
+            .is_ok());
+        assert!(TextRankConfig::default()
+            .with_phrase_words(3, 2)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_config_presets() {
+        for name in CONFIG_PRESETS {
+            let cfg = TextRankConfig::preset(name).unwrap();
+            assert!(cfg.validate().is_ok(), "{name} preset is invalid");
+            assert!(!cfg.include_pos.contains(&PosTag::Verb));
+        }
+
+        let textrank = TextRankConfig::textrank();
+        assert_eq!(textrank.window_size, 2);
+        assert!(!textrank.use_edge_weights);
+        let singlerank = TextRankConfig::preset("Single-Rank").unwrap();
+        assert_eq!(singlerank.window_size, 10);
+        assert!(singlerank.use_edge_weights);
+        assert_eq!(
+            TextRankConfig::preset("position_rank")
+                .unwrap()
+                .max_phrase_length,
+            3
+        );
+
+        let err = TextRankConfig::preset("pagerank").unwrap_err();
+        assert!(err.to_string().contains("topicalpagerank"));
+    }
+
+    #[test]
+    fn test_node_key_resolution_and_serde() {
+        let cfg = TextRankConfig::default();
+        assert!(matches!(cfg.effective_node_key(), NodeKey::LemmaPos));
+        let cfg = TextRankConfig {
+            use_pos_in_nodes: false,
+            ..cfg
+        };
+        assert!(matches!(cfg.effective_node_key(), NodeKey::Lemma));
+        let cfg = cfg.with_node_key(NodeKey::Surface);
+        assert!(matches!(cfg.effective_node_key(), NodeKey::Surface));
+
+        let cfg: TextRankConfig =
+            serde_json::from_str(r#"{ "node_key": "lowercase_surface" }"#).unwrap();
+        assert!(matches!(cfg.node_key, Some(NodeKey::LowercaseSurface)));
+        let custom = NodeKey::custom(|t| t.text.as_str().into());
+        assert!(serde_json::to_string(&custom).is_err());
+        assert!(serde_json::to_string(&cfg.clone().with_node_key(custom.clone())).is_err());
+        assert!(serde_json::from_str::<NodeKey>(r#""custom""#).is_err());
+
+        let token = Token::new("Graphs", "graph", PosTag::Noun, 0, 6, 0, 0);
+        assert_eq!(NodeKey::LemmaPos.key(&token), token.graph_key(true));
+        assert_eq!(NodeKey::LowercaseSurface.key(&token), "graphs");
+        assert_eq!(custom.key(&token), "Graphs");
+    }
+
+    #[test]
+    fn test_config_serde_partial_and_round_trip() {
+        let cfg: TextRankConfig = serde_json::from_str(r#"{ "top_n": 3 }"#).unwrap();
+        assert_eq!(cfg.top_n, 3);
+        assert_eq!(cfg.window_size, TextRankConfig::default().window_size);
+
+        let preset = TextRankConfig::positionrank();
+        let json = serde_json::to_string(&preset).unwrap();
+        let back: TextRankConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+    }
+
+    #[test]
+    fn test_validation_errors_carry_code_and_field() {
+        let err = TextRankConfig::default()
+            .with_window_size(0)
+            .validate()
+            .unwrap_err();
+        assert_eq!(err.config_code(), Some(ErrorCode::InvalidValue));
+        assert_eq!(err.config_path().as_deref(), Some("/window_size"));
+        assert!(err.to_string().contains("window_size must be >= 2, got 0"));
+
+        for damping in [0.0, 1.0, f64::NAN] {
+            let err = TextRankConfig::default().with_damping(damping).validate();
+            assert!(err.is_err(), "damping {damping} accepted");
+        }
+
+        let mut cfg = TextRankConfig::default()
+            .with_damping(1.0)
+            .with_phrase_words(3, 2);
+        cfg.convergence_threshold = f64::NAN;
+        cfg.min_phrase_length = 4;
+        cfg.max_phrase_length = 2;
+        let errors: Vec<_> = cfg
+            .validation_errors()
+            .iter()
+            .map(|e| (e.config_code().unwrap(), e.config_path().unwrap()))
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                (ErrorCode::InvalidValue, "/damping".to_string()),
+                (
+                    ErrorCode::InvalidValue,
+                    "/convergence_threshold".to_string()
+                ),
+                (ErrorCode::InvalidCombo, "/max_phrase_length".to_string()),
+                (ErrorCode::InvalidCombo, "/max_phrase_words".to_string()),
+            ]
+        );
     }
 
     #[test]
@@ -836,6 +2495,109 @@ mod tests {
         assert!(cfg.determinism.is_deterministic());
     }
 
+    #[test]
+    fn test_rank_precision_serde() {
+        assert_eq!(TextRankConfig::default().rank_precision, RankPrecision::F64);
+        let json = serde_json::to_string(&RankPrecision::F32).unwrap();
+        assert_eq!(json, r#""f32""#);
+
+        let cfg: TextRankConfig = serde_json::from_str(
+            r#"{
+            "damping": 0.85,
+            "max_iterations": 100,
+            "convergence_threshold": 1e-6,
+            "window_size": 3,
+            "top_n": 10,
+            "min_phrase_length": 1,
+            "max_phrase_length": 4,
+            "score_aggregation": "Sum",
+            "language": "en",
+            "use_edge_weights": true,
+            "include_pos": ["Noun"],
+            "stopwords": [],
+            "use_pos_in_nodes": true,
+            "phrase_grouping": "Lemma",
+            "rank_precision": "f32"
+        }"#,
+        )
+        .unwrap();
+        assert_eq!(cfg.rank_precision, RankPrecision::F32);
+    }
+
+    #[test]
+    fn test_dangling_policy_serde() {
+        assert_eq!(
+            TextRankConfig::default().dangling_policy,
+            DanglingPolicy::Personalization
+        );
+        let json = serde_json::to_string(&DanglingPolicy::SelfLoop).unwrap();
+        assert_eq!(json, r#""self_loop""#);
+        let back: DanglingPolicy = serde_json::from_str(r#""uniform""#).unwrap();
+        assert_eq!(back, DanglingPolicy::Uniform);
+    }
+
+    #[test]
+    fn test_convergence_criterion_serde() {
+        assert_eq!(
+            TextRankConfig::default().convergence_criterion,
+            ConvergenceCriterion::L1
+        );
+        let json = serde_json::to_string(&ConvergenceCriterion::LInf).unwrap();
+        assert_eq!(json, r#""linf""#);
+        let back: ConvergenceCriterion = serde_json::from_str(r#""relative""#).unwrap();
+        assert_eq!(back, ConvergenceCriterion::Relative);
+    }
+
+    #[test]
+    fn test_non_convergence_policy_serde() {
+        assert_eq!(
+            TextRankConfig::default().non_convergence,
+            NonConvergencePolicy::Continue
+        );
+        let json = serde_json::to_string(&NonConvergencePolicy::WarnAndContinue).unwrap();
+        assert_eq!(json, r#""warn_and_continue""#);
+        let back: NonConvergencePolicy = serde_json::from_str(r#""fail""#).unwrap();
+        assert_eq!(back, NonConvergencePolicy::Fail);
+    }
+
+    #[test]
+    fn test_ranker_acceleration_serde() {
+        assert_eq!(
+            TextRankConfig::default().acceleration,
+            RankerAcceleration::None
+        );
+        let json = serde_json::to_string(&RankerAcceleration::QuadraticExtrapolation).unwrap();
+        assert_eq!(json, r#""quadratic_extrapolation""#);
+        let back: RankerAcceleration = serde_json::from_str(r#""aitken""#).unwrap();
+        assert_eq!(back, RankerAcceleration::Aitken);
+    }
+
+    #[test]
+    fn test_lemma_strategy_serde() {
+        let strategy = LemmaStrategy::Stem(StemLanguage::German);
+        let json = serde_json::to_string(&strategy).unwrap();
+        assert_eq!(json, r#"{"stem":"german"}"#);
+        let back: LemmaStrategy = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, strategy);
+        assert_eq!(
+            TextRankConfig::default().lemma_strategy,
+            LemmaStrategy::AsProvided
+        );
+    }
+
+    #[test]
+    fn test_config_validate_stem_requires_feature() {
+        let cfg = TextRankConfig::default()
+            .with_lemma_strategy(LemmaStrategy::Stem(StemLanguage::English));
+        assert_eq!(cfg.validate().is_ok(), cfg!(feature = "stemming"));
+    }
+
+    #[test]
+    fn test_config_validate_detect_language_requires_feature() {
+        let cfg = TextRankConfig::default().with_detect_language(true);
+        assert_eq!(cfg.validate().is_ok(), cfg!(feature = "lang-detect"));
+    }
+
     #[test]
     fn test_chunk_overlap() {
         let c1 = ChunkSpan {
@@ -869,6 +2631,93 @@ mod tests {
     // Phrase::stable_cmp — deterministic tie-breaker comparator
     // ================================================================
 
+    #[test]
+    fn test_phrase_char_spans() {
+        let text = "Café society meets café culture";
+        let mut phrase = Phrase::new("café culture", "café culture", 1.0, 1);
+        phrase.spans = vec![(20, 33)];
+        let spans = phrase.char_spans(text);
+        assert_eq!(spans, [(19, 31)]);
+        let chars: Vec<char> = text.chars().collect();
+        let (start, end) = spans[0];
+        assert_eq!(chars[start..end].iter().collect::<String>(), "café culture");
+
+        // Offsets inside a multi-byte char round down.
+        phrase.spans = vec![(4, 100)];
+        assert_eq!(phrase.char_spans(text), [(3, 31)]);
+    }
+
+    #[test]
+    fn test_score_normalization() {
+        let scored = |scores: &[f64]| -> Vec<Phrase> {
+            scores
+                .iter()
+                .map(|&s| Phrase::new("p", "p", s, 1))
+                .collect()
+        };
+        let scores = |phrases: &[Phrase]| phrases.iter().map(|p| p.score).collect::<Vec<_>>();
+
+        let mut phrases = scored(&[0.5, 0.3, 0.2]);
+        ScoreNormalization::Raw.apply(&mut phrases);
+        assert_eq!(scores(&phrases), [0.5, 0.3, 0.2]);
+
+        ScoreNormalization::MinMax.apply(&mut phrases);
+        let min_max = scores(&phrases);
+        assert_eq!(min_max[0], 1.0);
+        assert!((min_max[1] - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(min_max[2], 0.0);
+
+        let mut phrases = scored(&[0.4, 0.4]);
+        ScoreNormalization::MinMax.apply(&mut phrases);
+        assert_eq!(scores(&phrases), [1.0, 1.0]);
+
+        let mut phrases = scored(&[3.0, 1.0]);
+        ScoreNormalization::Sum.apply(&mut phrases);
+        assert_eq!(scores(&phrases), [0.75, 0.25]);
+
+        let mut phrases = scored(&[2.0, 1.0, 1.0]);
+        ScoreNormalization::Softmax { temperature: 1.0 }.apply(&mut phrases);
+        let softmax = scores(&phrases);
+        let e = std::f64::consts::E;
+        assert!((softmax[0] - e / (e + 2.0)).abs() < 1e-12);
+        assert!((softmax.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+        // Lower temperatures sharpen the distribution.
+        let mut sharp = scored(&[2.0, 1.0, 1.0]);
+        ScoreNormalization::Softmax { temperature: 0.1 }.apply(&mut sharp);
+        assert!(sharp[0].score > softmax[0]);
+    }
+
+    #[test]
+    fn test_phrase_selection_limit() {
+        let scores = [1.0, 0.9, 0.3, 0.2, 0.15, 0.1];
+        assert_eq!(PhraseSelection::TopN.limit(0, &scores), None);
+        assert_eq!(PhraseSelection::TopN.limit(4, &scores), Some(4));
+        let threshold = PhraseSelection::ScoreThreshold { min_score: 0.2 };
+        assert_eq!(threshold.limit(4, &scores), Some(4));
+        let proportion = PhraseSelection::Proportion { fraction: 0.34 };
+        assert_eq!(proportion.limit(0, &scores), Some(3));
+        // The curve drops off after the second score.
+        assert_eq!(PhraseSelection::Elbow.limit(0, &scores), Some(2));
+        // Linear and flat curves have no elbow.
+        assert_eq!(PhraseSelection::Elbow.limit(0, &[3.0, 2.0, 1.0]), Some(3));
+        assert_eq!(PhraseSelection::Elbow.limit(0, &[1.0; 4]), Some(4));
+        assert_eq!(PhraseSelection::Elbow.limit(0, &[]), Some(0));
+
+        let invalid = TextRankConfig::default()
+            .with_phrase_selection(PhraseSelection::Proportion { fraction: 0.0 });
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_span_mode_apply() {
+        let mut spans = vec![(10, 14), (0, 4)];
+        SpanMode::All.apply(&mut spans);
+        assert_eq!(spans, [(0, 4), (10, 14)]);
+        SpanMode::First.apply(&mut spans);
+        assert_eq!(spans, [(0, 4)]);
+    }
+
     /// Helper: build a Phrase with offsets for testing stable_cmp.
     fn phrase(lemma: &str, score: f64, offsets: Vec<(usize, usize)>) -> Phrase {
         Phrase {
@@ -877,6 +2726,7 @@ mod tests {
             score,
             count: offsets.len(),
             offsets,
+            spans: Vec::new(),
             rank: 0,
         }
     }
@@ -933,6 +2783,31 @@ mod tests {
         assert_eq!(a.stable_cmp(&b), std::cmp::Ordering::Less);
     }
 
+    #[test]
+    fn test_stable_cmp_is_transitive() {
+        // Pairwise within 1e-10 but not end to end: an epsilon compare
+        // would put a < b and b < c by position, yet c < a by score.
+        let a = phrase("alpha", 0.5, vec![(0, 1)]);
+        let b = phrase("beta", 0.5 + 0.7e-10, vec![(5, 6)]);
+        let c = phrase("gamma", 0.5 + 1.4e-10, vec![(9, 10)]);
+        let phrases = [a, b, c];
+        for x in &phrases {
+            for y in &phrases {
+                for z in &phrases {
+                    if x.stable_cmp(y).is_le() && y.stable_cmp(z).is_le() {
+                        assert!(
+                            x.stable_cmp(z).is_le(),
+                            "{} {} {}",
+                            x.lemma,
+                            y.lemma,
+                            z.lemma
+                        );
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_stable_cmp_no_offsets() {
         // Phrases without offsets → position defaults to usize::MAX.
@@ -10,21 +10,51 @@
 //! Internally this is BaseTextRank + [`FocusTermsTeleportBuilder`]: the only
 //! difference from the base algorithm is the teleport (personalization)
 //! strategy.
+//!
+//! For query-focused extraction, [`BiasedTextRank::with_query`] derives the
+//! teleport vector from the similarity between each node and a free-text
+//! query instead ([`QueryTeleportBuilder`]); the similarity function is
+//! pluggable via [`BiasedTextRank::with_query_similarity`].
+//!
+//! [`FocusTermsTeleportBuilder`]: crate::pipeline::traits::FocusTermsTeleportBuilder
+
+use std::sync::Arc;
 
+use crate::nlp::stopwords::StopwordFilter;
 use crate::phrase::extraction::ExtractionResult;
-use crate::pipeline::artifacts::TokenStream;
-use crate::pipeline::observer::NoopObserver;
-use crate::pipeline::runner::BiasedTextRankPipeline;
+use crate::pipeline::runner::{BiasedTextRankPipeline, QueryBiasedTextRankPipeline};
+use crate::pipeline::traits::{
+    PageRankRanker, QuerySimilarity, QueryTeleportBuilder, Ranker, TokenOverlapSimilarity,
+};
 use crate::types::{Phrase, TextRankConfig, Token};
 
 /// BiasedTextRank implementation
-#[derive(Debug)]
-pub struct BiasedTextRank {
+///
+/// Ranks with PageRank unless another [`Ranker`] is set with
+/// [`with_ranker`](Self::with_ranker).
+pub struct BiasedTextRank<R = PageRankRanker> {
     config: TextRankConfig,
     /// Focus terms (lemmatized)
     focus_terms: Vec<String>,
     /// Weight multiplier for focus terms
     bias_weight: f64,
+    /// Query tokens; when set, teleportation follows query similarity
+    query_terms: Option<Vec<String>>,
+    /// Node-to-query similarity function
+    query_similarity: Arc<dyn QuerySimilarity>,
+    ranker: R,
+}
+
+impl<R: std::fmt::Debug> std::fmt::Debug for BiasedTextRank<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BiasedTextRank")
+            .field("config", &self.config)
+            .field("focus_terms", &self.focus_terms)
+            .field("bias_weight", &self.bias_weight)
+            .field("query_terms", &self.query_terms)
+            .field("ranker", &self.ranker)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for BiasedTextRank {
@@ -36,11 +66,7 @@ impl Default for BiasedTextRank {
 impl BiasedTextRank {
     /// Create a new BiasedTextRank extractor
     pub fn new() -> Self {
-        Self {
-            config: TextRankConfig::default(),
-            focus_terms: Vec::new(),
-            bias_weight: 5.0,
-        }
+        Self::with_config(TextRankConfig::default())
     }
 
     /// Create with custom config
@@ -49,6 +75,23 @@ impl BiasedTextRank {
             config,
             focus_terms: Vec::new(),
             bias_weight: 5.0,
+            query_terms: None,
+            query_similarity: Arc::new(TokenOverlapSimilarity),
+            ranker: PageRankRanker,
+        }
+    }
+}
+
+impl<R: Ranker> BiasedTextRank<R> {
+    /// Rank with `ranker` instead of PageRank
+    pub fn with_ranker<S: Ranker>(self, ranker: S) -> BiasedTextRank<S> {
+        BiasedTextRank {
+            config: self.config,
+            focus_terms: self.focus_terms,
+            bias_weight: self.bias_weight,
+            query_terms: self.query_terms,
+            query_similarity: self.query_similarity,
+            ranker,
         }
     }
 
@@ -58,6 +101,29 @@ impl BiasedTextRank {
         self
     }
 
+    /// Set a query/focus text; teleportation is then biased by each node's
+    /// similarity to the query instead of by exact focus-term matches.
+    ///
+    /// The query is lowercased, split on non-alphanumeric characters, and
+    /// stopwords for the configured language are removed.
+    pub fn with_query(mut self, query: &str) -> Self {
+        let stopwords = StopwordFilter::new(&self.config.language);
+        let terms = query
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|t| !t.is_empty())
+            .map(|t| t.to_lowercase())
+            .filter(|t| !stopwords.is_stopword(t))
+            .collect();
+        self.query_terms = Some(terms);
+        self
+    }
+
+    /// Set the node-to-query similarity function (default: token overlap)
+    pub fn with_query_similarity(mut self, similarity: impl QuerySimilarity + 'static) -> Self {
+        self.query_similarity = Arc::new(similarity);
+        self
+    }
+
     /// Set bias weight for focus terms
     pub fn with_bias_weight(mut self, weight: f64) -> Self {
         self.bias_weight = weight;
@@ -71,16 +137,18 @@ impl BiasedTextRank {
 
     /// Extract keyphrases with PageRank convergence information
     pub fn extract_with_info(&self, tokens: &[Token]) -> ExtractionResult {
-        let pipeline = BiasedTextRankPipeline::biased(self.focus_terms.clone(), self.bias_weight);
-        let stream = TokenStream::from_tokens(tokens);
-        let mut obs = NoopObserver;
-        let result = pipeline.run(stream, &self.config, &mut obs);
-
-        ExtractionResult {
-            phrases: result.phrases,
-            converged: result.converged,
-            iterations: result.iterations as usize,
-            debug: result.debug,
+        match &self.query_terms {
+            Some(query_terms) => {
+                let teleport = QueryTeleportBuilder::new(query_terms.clone(), self.bias_weight)
+                    .with_similarity(Arc::clone(&self.query_similarity));
+                let pipeline = QueryBiasedTextRankPipeline::query_biased(teleport);
+                super::run_with_ranker(pipeline, &self.ranker, tokens, &self.config)
+            }
+            None => {
+                let pipeline =
+                    BiasedTextRankPipeline::biased(self.focus_terms.clone(), self.bias_weight);
+                super::run_with_ranker(pipeline, &self.ranker, tokens, &self.config)
+            }
         }
     }
 
@@ -97,6 +165,11 @@ impl BiasedTextRank {
         &self.focus_terms
     }
 
+    /// Get the query tokens, if a query is set
+    pub fn query_terms(&self) -> Option<&[String]> {
+        self.query_terms.as_deref()
+    }
+
     /// Get the bias weight
     pub fn bias_weight(&self) -> f64 {
         self.bias_weight
@@ -224,6 +297,65 @@ mod tests {
         assert!(result.iterations > 0);
     }
 
+    #[test]
+    fn test_query_parsing_drops_stopwords() {
+        let extractor = BiasedTextRank::new().with_query("What are the Neural networks?");
+        assert_eq!(
+            extractor.query_terms(),
+            Some(&["neural".to_string(), "networks".to_string()][..])
+        );
+    }
+
+    #[test]
+    fn test_query_matches_focus_terms_with_default_similarity() {
+        // Token-overlap similarity on single-word nodes is an exact match,
+        // so a one-word query behaves like the equivalent focus term.
+        let tokens = make_tokens();
+        let config = TextRankConfig::default().with_top_n(10);
+        let by_focus = BiasedTextRank::with_config(config.clone())
+            .with_focus(&["neural"])
+            .extract(&tokens);
+        let by_query = BiasedTextRank::with_config(config)
+            .with_query("neural")
+            .extract(&tokens);
+
+        assert_eq!(by_focus.len(), by_query.len());
+        for (a, b) in by_focus.iter().zip(by_query.iter()) {
+            assert_eq!(a.lemma, b.lemma);
+            assert!((a.score - b.score).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_custom_query_similarity() {
+        let tokens = make_tokens();
+        let config = TextRankConfig::default().with_top_n(10);
+        // Prefix similarity: "networks" in the query matches the lemma "network".
+        let prefix = |lemma: &str, terms: &[String]| {
+            if terms.iter().any(|t| t.starts_with(lemma)) {
+                1.0
+            } else {
+                0.0
+            }
+        };
+        let baseline = BiasedTextRank::with_config(config.clone())
+            .with_query("networks")
+            .extract(&tokens);
+        let custom = BiasedTextRank::with_config(config)
+            .with_query("networks")
+            .with_query_similarity(prefix)
+            .extract(&tokens);
+
+        let score = |phrases: &[Phrase]| {
+            phrases
+                .iter()
+                .find(|p| p.lemma.contains("network"))
+                .map(|p| p.score)
+                .unwrap()
+        };
+        assert!(score(&custom) > score(&baseline));
+    }
+
     #[test]
     fn test_biased_pipeline_constructs() {
         let _pipeline = BiasedTextRankPipeline::biased(vec!["machine".to_string()], 5.0);
@@ -0,0 +1,198 @@
+//! ExpandRank variant
+//!
+//! ExpandRank (Wan & Xiao, 2008) improves single-document keyphrase
+//! extraction by borrowing co-occurrence evidence from a small neighborhood
+//! of similar documents:
+//! 1. The target document and each neighbor contribute co-occurrence counts
+//!    (SingleRank windowing: cross-sentence, weighted edges).
+//! 2. A neighbor's counts are scaled by its similarity to the target, so
+//!    closer documents have more influence.
+//! 3. PageRank runs on the combined graph; phrases are assembled from the
+//!    target document only.
+//!
+//! With no neighbors, ExpandRank is identical to SingleRank.
+
+use crate::phrase::extraction::ExtractionResult;
+use crate::pipeline::runner::ExpandRankPipeline;
+use crate::pipeline::traits::{PageRankRanker, Ranker};
+use crate::types::{Phrase, TextRankConfig, Token};
+
+/// ExpandRank implementation
+///
+/// Ranks with PageRank unless another [`Ranker`] is set with
+/// [`with_ranker`](Self::with_ranker).
+#[derive(Debug)]
+pub struct ExpandRank<R = PageRankRanker> {
+    config: TextRankConfig,
+    /// Neighbor documents with their similarity to the target
+    neighbors: Vec<(Vec<Token>, f64)>,
+    ranker: R,
+}
+
+impl Default for ExpandRank {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExpandRank {
+    /// Create a new ExpandRank extractor with default config
+    pub fn new() -> Self {
+        Self::with_config(TextRankConfig::default())
+    }
+
+    /// Create with custom config
+    pub fn with_config(config: TextRankConfig) -> Self {
+        Self {
+            config,
+            neighbors: Vec::new(),
+            ranker: PageRankRanker,
+        }
+    }
+}
+
+impl<R: Ranker> ExpandRank<R> {
+    /// Rank with `ranker` instead of PageRank
+    pub fn with_ranker<S: Ranker>(self, ranker: S) -> ExpandRank<S> {
+        ExpandRank {
+            config: self.config,
+            neighbors: self.neighbors,
+            ranker,
+        }
+    }
+
+    /// Add a neighbor document with its similarity weight to the target
+    pub fn with_neighbor(mut self, tokens: &[Token], similarity: f64) -> Self {
+        self.neighbors.push((tokens.to_vec(), similarity));
+        self
+    }
+
+    /// Replace all neighbor documents
+    pub fn with_neighbors(mut self, neighbors: Vec<(Vec<Token>, f64)>) -> Self {
+        self.neighbors = neighbors;
+        self
+    }
+
+    /// Get the number of neighbor documents
+    pub fn num_neighbors(&self) -> usize {
+        self.neighbors.len()
+    }
+
+    /// Extract keyphrases using ExpandRank
+    pub fn extract(&self, tokens: &[Token]) -> Vec<Phrase> {
+        self.extract_with_info(tokens).phrases
+    }
+
+    /// Extract keyphrases with PageRank convergence information
+    pub fn extract_with_info(&self, tokens: &[Token]) -> ExtractionResult {
+        let pipeline = ExpandRankPipeline::expand_rank(self.neighbors.clone());
+        super::run_with_ranker(pipeline, &self.ranker, tokens, &self.config)
+    }
+}
+
+/// Convenience function to extract keyphrases using ExpandRank
+pub fn extract_keyphrases_expandrank(
+    tokens: &[Token],
+    neighbors: &[(&[Token], f64)],
+    config: &TextRankConfig,
+) -> Vec<Phrase> {
+    ExpandRank::with_config(config.clone())
+        .with_neighbors(neighbors.iter().map(|(t, w)| (t.to_vec(), *w)).collect())
+        .extract(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PosTag;
+    use crate::variants::single_rank::SingleRank;
+
+    fn target_tokens() -> Vec<Token> {
+        // "Graph ranking. Keyword extraction uses graph ranking."
+        vec![
+            Token::new("Graph", "graph", PosTag::Noun, 0, 5, 0, 0),
+            Token::new("ranking", "ranking", PosTag::Noun, 6, 13, 0, 1),
+            Token::new("Keyword", "keyword", PosTag::Noun, 15, 22, 1, 2),
+            Token::new("extraction", "extraction", PosTag::Noun, 23, 33, 1, 3),
+            Token::new("uses", "use", PosTag::Verb, 34, 38, 1, 4),
+            Token::new("graph", "graph", PosTag::Noun, 39, 44, 1, 5),
+            Token::new("ranking", "ranking", PosTag::Noun, 45, 52, 1, 6),
+        ]
+    }
+
+    fn neighbor_tokens() -> Vec<Token> {
+        // "Keyword extraction. Keyword extraction improves search."
+        vec![
+            Token::new("Keyword", "keyword", PosTag::Noun, 0, 7, 0, 0),
+            Token::new("extraction", "extraction", PosTag::Noun, 8, 18, 0, 1),
+            Token::new("Keyword", "keyword", PosTag::Noun, 20, 27, 1, 2),
+            Token::new("extraction", "extraction", PosTag::Noun, 28, 38, 1, 3),
+            Token::new("improves", "improve", PosTag::Verb, 39, 47, 1, 4),
+            Token::new("search", "search", PosTag::Noun, 48, 54, 1, 5),
+        ]
+    }
+
+    fn score_of(phrases: &[Phrase], lemma: &str) -> f64 {
+        phrases
+            .iter()
+            .find(|p| p.lemma == lemma)
+            .map(|p| p.score)
+            .unwrap_or(0.0)
+    }
+
+    #[test]
+    fn test_no_neighbors_matches_single_rank() {
+        let tokens = target_tokens();
+        let config = TextRankConfig::default().with_top_n(10);
+        let expand = ExpandRank::with_config(config.clone()).extract(&tokens);
+        let single = SingleRank::with_config(config).extract(&tokens);
+
+        assert_eq!(expand.len(), single.len());
+        for (a, b) in expand.iter().zip(single.iter()) {
+            assert_eq!(a.lemma, b.lemma);
+            assert!((a.score - b.score).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_neighbor_boosts_shared_terms() {
+        let tokens = target_tokens();
+        let neighbor = neighbor_tokens();
+        let config = TextRankConfig::default().with_top_n(10);
+
+        let baseline = ExpandRank::with_config(config.clone()).extract(&tokens);
+        let expanded = ExpandRank::with_config(config)
+            .with_neighbor(&neighbor, 1.0)
+            .extract(&tokens);
+
+        assert!(
+            score_of(&expanded, "keyword extraction") > score_of(&baseline, "keyword extraction")
+        );
+        // Neighbor-only words never surface as phrases.
+        assert!(expanded.iter().all(|p| !p.lemma.contains("search")));
+    }
+
+    #[test]
+    fn test_zero_similarity_neighbor_is_ignored() {
+        let tokens = target_tokens();
+        let neighbor = neighbor_tokens();
+        let config = TextRankConfig::default().with_top_n(10);
+
+        let baseline = ExpandRank::with_config(config.clone()).extract(&tokens);
+        let expanded = extract_keyphrases_expandrank(&tokens, &[(&neighbor, 0.0)], &config);
+
+        assert_eq!(baseline.len(), expanded.len());
+        for (a, b) in baseline.iter().zip(expanded.iter()) {
+            assert_eq!(a.lemma, b.lemma);
+            assert!((a.score - b.score).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let phrases = ExpandRank::new()
+            .with_neighbor(&neighbor_tokens(), 0.5)
+            .extract(&[]);
+        assert!(phrases.is_empty());
+    }
+}
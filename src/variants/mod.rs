@@ -6,8 +6,20 @@
 //! - TopicRank: Clusters similar phrases before ranking
 //! - SingleRank: TextRank with forced weighted edges and cross-sentence windowing
 //! - TopicalPageRank: SingleRank graph + topic-weight-biased personalized PageRank
+//! - ExpandRank: SingleRank graph expanded with similarity-weighted neighbor documents
+
+use crate::phrase::extraction::ExtractionResult;
+use crate::pipeline::artifacts::TokenStream;
+use crate::pipeline::observer::NoopObserver;
+use crate::pipeline::runner::Pipeline;
+use crate::pipeline::traits::{
+    CandidateSelector, GraphBuilder, GraphTransform, PhraseBuilder, Preprocessor, Ranker,
+    ResultFormatter, TeleportBuilder,
+};
+use crate::types::{TextRankConfig, Token};
 
 pub mod biased_textrank;
+pub mod expand_rank;
 pub mod multipartite_rank;
 pub mod position_rank;
 pub mod single_rank;
@@ -55,3 +67,30 @@ impl std::str::FromStr for Variant {
         Ok(Variant::parse(value))
     }
 }
+
+/// Run `pipeline` on `tokens` with `ranker` as its ranking stage.
+///
+/// Shared by the pipeline-backed variants' `extract_with_info`, so each
+/// only chooses its pipeline.
+fn run_with_ranker<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt>(
+    pipeline: Pipeline<Pre, Sel, GB, GT, TB, Rnk, PB, Fmt>,
+    ranker: &impl Ranker,
+    tokens: &[Token],
+    config: &TextRankConfig,
+) -> ExtractionResult
+where
+    Pre: Preprocessor,
+    Sel: CandidateSelector,
+    GB: GraphBuilder,
+    GT: GraphTransform,
+    TB: TeleportBuilder,
+    Rnk: Ranker,
+    PB: PhraseBuilder,
+    Fmt: ResultFormatter,
+{
+    let stream = TokenStream::from_tokens(tokens);
+    pipeline
+        .with_ranker(ranker)
+        .run(stream, config, &mut NoopObserver)
+        .into()
+}
@@ -8,15 +8,19 @@
 
 use crate::clustering::{self, compute_gap, extract_candidates, PhraseCandidate};
 use crate::graph::builder::GraphBuilder;
-use crate::graph::csr::CsrGraph;
-use crate::pagerank::standard::StandardPageRank;
+use crate::phrase::blocklist::PhraseFilter;
 use crate::phrase::extraction::ExtractionResult;
+use crate::pipeline::artifacts::Graph;
+use crate::pipeline::traits::{PageRankRanker, Ranker};
 use crate::types::{Phrase, TextRankConfig, Token};
 use rustc_hash::FxHashMap;
 
 /// MultipartiteRank implementation
+///
+/// Ranks the candidate graph with PageRank unless another [`Ranker`] is set
+/// with [`with_ranker`](Self::with_ranker).
 #[derive(Debug)]
-pub struct MultipartiteRank {
+pub struct MultipartiteRank<R = PageRankRanker> {
     config: TextRankConfig,
     /// Jaccard similarity threshold for topic clustering (default: 0.26)
     similarity_threshold: f64,
@@ -24,6 +28,7 @@ pub struct MultipartiteRank {
     alpha: f64,
     /// Maximum number of candidate phrases to process (default: 200)
     max_phrases: usize,
+    ranker: R,
 }
 
 impl Default for MultipartiteRank {
@@ -35,12 +40,7 @@ impl Default for MultipartiteRank {
 impl MultipartiteRank {
     /// Create a new MultipartiteRank extractor with default settings
     pub fn new() -> Self {
-        Self {
-            config: TextRankConfig::default(),
-            similarity_threshold: 0.26,
-            alpha: 1.1,
-            max_phrases: 200,
-        }
+        Self::with_config(TextRankConfig::default())
     }
 
     /// Create with custom config
@@ -50,6 +50,20 @@ impl MultipartiteRank {
             similarity_threshold: 0.26,
             alpha: 1.1,
             max_phrases: 200,
+            ranker: PageRankRanker,
+        }
+    }
+}
+
+impl<R: Ranker> MultipartiteRank<R> {
+    /// Rank the candidate graph with `ranker` instead of PageRank
+    pub fn with_ranker<S: Ranker>(self, ranker: S) -> MultipartiteRank<S> {
+        MultipartiteRank {
+            config: self.config,
+            similarity_threshold: self.similarity_threshold,
+            alpha: self.alpha,
+            max_phrases: self.max_phrases,
+            ranker,
         }
     }
 
@@ -140,13 +154,12 @@ impl MultipartiteRank {
             self.adjust_weights(&mut builder, &candidates, &clusters, &topic_of);
         }
 
-        // 5. Run PageRank
-        let graph = CsrGraph::from_builder(&builder);
-        let pagerank = StandardPageRank::new()
-            .with_damping(self.config.damping)
-            .with_max_iterations(self.config.max_iterations)
-            .with_threshold(self.config.convergence_threshold)
-            .run(&graph);
+        // 5. Rank
+        let graph = Graph::from_builder(&builder);
+        let pagerank = self
+            .ranker
+            .rank(&graph, None, &self.config)
+            .into_pagerank_result();
 
         // 6. Group by lemma and build output phrases
         let mut lemma_indices: FxHashMap<String, Vec<usize>> = FxHashMap::default();
@@ -157,13 +170,9 @@ impl MultipartiteRank {
                 .push(i);
         }
 
-        let lemma_groups: Vec<Vec<usize>> = if self.config.determinism.is_deterministic() {
-            let mut sorted: Vec<_> = lemma_indices.into_iter().collect();
-            sorted.sort_by(|(a, _), (b, _)| a.cmp(b));
-            sorted.into_iter().map(|(_, v)| v).collect()
-        } else {
-            lemma_indices.into_values().collect()
-        };
+        let mut sorted: Vec<_> = lemma_indices.into_iter().collect();
+        sorted.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let lemma_groups: Vec<Vec<usize>> = sorted.into_iter().map(|(_, v)| v).collect();
 
         let mut phrases: Vec<Phrase> = lemma_groups
             .into_iter()
@@ -191,6 +200,11 @@ impl MultipartiteRank {
                     })
                     .collect();
                 offsets.sort_by_key(|(start, _)| *start);
+                let mut spans: Vec<(usize, usize)> = indices
+                    .iter()
+                    .map(|&i| (candidates[i].chunk.start_char, candidates[i].chunk.end_char))
+                    .collect();
+                self.config.span_mode.apply(&mut spans);
 
                 Phrase {
                     text: candidate.text.clone(),
@@ -198,16 +212,14 @@ impl MultipartiteRank {
                     score,
                     count: offsets.len(),
                     offsets,
+                    spans,
                     rank: 0,
                 }
             })
             .collect();
+        PhraseFilter::for_config(&self.config).retain_phrases(&mut phrases);
 
-        if self.config.determinism.is_deterministic() {
-            phrases.sort_by(|a, b| a.stable_cmp(b));
-        } else {
-            phrases.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
-        }
+        phrases.sort_by(|a, b| a.stable_cmp(b));
 
         for (i, phrase) in phrases.iter_mut().enumerate() {
             phrase.rank = i + 1;
@@ -220,7 +232,7 @@ impl MultipartiteRank {
         // Build debug payload from legacy types if requested.
         let mut debug = crate::pipeline::artifacts::DebugPayload::build_from_legacy(
             self.config.debug_level,
-            &graph,
+            graph.csr(),
             &pagerank,
             self.config.debug_top_k,
         );
@@ -809,17 +821,17 @@ mod tests {
             .extract_with_info(&tokens);
 
         assert!(result.converged, "PageRank should converge");
-        assert_eq!(result.iterations, 20);
+        assert_eq!(result.iterations, 17);
         assert_eq!(result.phrases.len(), 7);
 
         let expected = [
-            (1, "machine learning algorithm", 0.2536742),
-            (2, "neural network", 0.2351832),
-            (3, "large dataset", 0.1184485),
-            (4, "deep learning model", 0.1157130),
-            (5, "machine learning technique", 0.0855296),
-            (6, "data analysis", 0.0795817),
-            (7, "deep learning application", 0.0500771),
+            (1, "machine learning algorithm", 0.2409297),
+            (2, "neural network", 0.2270727),
+            (3, "deep learning model", 0.1721273),
+            (4, "large dataset", 0.1094935),
+            (5, "machine learning technique", 0.0816575),
+            (6, "data analysis", 0.0704261),
+            (7, "deep learning application", 0.0421851),
         ];
         for (phrase, &(rank, lemma, approx_score)) in result.phrases.iter().zip(expected.iter()) {
             assert_eq!(phrase.rank, rank, "rank mismatch for {:?}", phrase.lemma);
@@ -11,15 +11,18 @@
 //! strategy.
 
 use crate::phrase::extraction::ExtractionResult;
-use crate::pipeline::artifacts::TokenStream;
-use crate::pipeline::observer::NoopObserver;
 use crate::pipeline::runner::PositionRankPipeline;
+use crate::pipeline::traits::{PageRankRanker, Ranker};
 use crate::types::{Phrase, TextRankConfig, Token};
 
 /// PositionRank implementation
+///
+/// Ranks with PageRank unless another [`Ranker`] is set with
+/// [`with_ranker`](Self::with_ranker).
 #[derive(Debug)]
-pub struct PositionRank {
+pub struct PositionRank<R = PageRankRanker> {
     config: TextRankConfig,
+    ranker: R,
 }
 
 impl Default for PositionRank {
@@ -31,14 +34,25 @@ impl Default for PositionRank {
 impl PositionRank {
     /// Create a new PositionRank extractor with default config
     pub fn new() -> Self {
-        Self {
-            config: TextRankConfig::default(),
-        }
+        Self::with_config(TextRankConfig::default())
     }
 
     /// Create with custom config
     pub fn with_config(config: TextRankConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            ranker: PageRankRanker,
+        }
+    }
+}
+
+impl<R: Ranker> PositionRank<R> {
+    /// Rank with `ranker` instead of PageRank
+    pub fn with_ranker<S: Ranker>(self, ranker: S) -> PositionRank<S> {
+        PositionRank {
+            config: self.config,
+            ranker,
+        }
     }
 
     /// Extract keyphrases using PositionRank
@@ -48,17 +62,12 @@ impl PositionRank {
 
     /// Extract keyphrases with PageRank convergence information
     pub fn extract_with_info(&self, tokens: &[Token]) -> ExtractionResult {
-        let pipeline = PositionRankPipeline::position_rank();
-        let stream = TokenStream::from_tokens(tokens);
-        let mut obs = NoopObserver;
-        let result = pipeline.run(stream, &self.config, &mut obs);
-
-        ExtractionResult {
-            phrases: result.phrases,
-            converged: result.converged,
-            iterations: result.iterations as usize,
-            debug: result.debug,
-        }
+        super::run_with_ranker(
+            PositionRankPipeline::position_rank(),
+            &self.ranker,
+            tokens,
+            &self.config,
+        )
     }
 }
 
@@ -105,6 +114,23 @@ mod tests {
         assert!(result.iterations > 0);
     }
 
+    #[test]
+    fn test_with_ranker() {
+        use crate::pipeline::traits::{DegreeRanker, HitsRanker};
+
+        let tokens = make_tokens();
+        let degree = PositionRank::new()
+            .with_ranker(DegreeRanker)
+            .extract_with_info(&tokens);
+        assert!(!degree.phrases.is_empty());
+        assert_eq!(degree.iterations, 0);
+
+        let hits = PositionRank::new()
+            .with_ranker(HitsRanker::authorities())
+            .extract(&tokens);
+        assert!(!hits.is_empty());
+    }
+
     #[test]
     fn test_empty_input() {
         let tokens: Vec<Token> = Vec::new();
@@ -11,15 +11,18 @@
 //! identical to base TextRank.
 
 use crate::phrase::extraction::ExtractionResult;
-use crate::pipeline::artifacts::TokenStream;
-use crate::pipeline::observer::NoopObserver;
 use crate::pipeline::runner::SingleRankPipeline;
+use crate::pipeline::traits::{PageRankRanker, Ranker};
 use crate::types::{Phrase, TextRankConfig, Token};
 
 /// SingleRank implementation
+///
+/// Ranks with PageRank unless another [`Ranker`] is set with
+/// [`with_ranker`](Self::with_ranker).
 #[derive(Debug)]
-pub struct SingleRank {
+pub struct SingleRank<R = PageRankRanker> {
     config: TextRankConfig,
+    ranker: R,
 }
 
 impl Default for SingleRank {
@@ -31,14 +34,25 @@ impl Default for SingleRank {
 impl SingleRank {
     /// Create a new SingleRank extractor with default config
     pub fn new() -> Self {
-        Self {
-            config: TextRankConfig::default(),
-        }
+        Self::with_config(TextRankConfig::default())
     }
 
     /// Create with custom config
     pub fn with_config(config: TextRankConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            ranker: PageRankRanker,
+        }
+    }
+}
+
+impl<R: Ranker> SingleRank<R> {
+    /// Rank with `ranker` instead of PageRank
+    pub fn with_ranker<S: Ranker>(self, ranker: S) -> SingleRank<S> {
+        SingleRank {
+            config: self.config,
+            ranker,
+        }
     }
 
     /// Extract keyphrases using SingleRank
@@ -48,17 +62,12 @@ impl SingleRank {
 
     /// Extract keyphrases with PageRank convergence information
     pub fn extract_with_info(&self, tokens: &[Token]) -> ExtractionResult {
-        let pipeline = SingleRankPipeline::single_rank();
-        let stream = TokenStream::from_tokens(tokens);
-        let mut obs = NoopObserver;
-        let result = pipeline.run(stream, &self.config, &mut obs);
-
-        ExtractionResult {
-            phrases: result.phrases,
-            converged: result.converged,
-            iterations: result.iterations as usize,
-            debug: result.debug,
-        }
+        super::run_with_ranker(
+            SingleRankPipeline::single_rank(),
+            &self.ranker,
+            tokens,
+            &self.config,
+        )
     }
 }
 
@@ -82,6 +91,7 @@ mod tests {
             sentence_idx: sent,
             token_idx: idx,
             is_stopword: false,
+            section: None,
         }
     }
 
@@ -14,13 +14,18 @@
 use crate::clustering::{self, compute_gap, extract_candidates, PhraseCandidate};
 use crate::graph::builder::GraphBuilder;
 use crate::graph::csr::CsrGraph;
-use crate::pagerank::standard::StandardPageRank;
+use crate::phrase::blocklist::PhraseFilter;
 use crate::phrase::extraction::ExtractionResult;
+use crate::pipeline::artifacts::Graph;
+use crate::pipeline::traits::{PageRankRanker, Ranker};
 use crate::types::{Phrase, TextRankConfig, Token};
 
 /// TopicRank implementation
+///
+/// Ranks the cluster graph with PageRank unless another [`Ranker`] is set
+/// with [`with_ranker`](Self::with_ranker).
 #[derive(Debug)]
-pub struct TopicRank {
+pub struct TopicRank<R = PageRankRanker> {
     config: TextRankConfig,
     /// Jaccard similarity threshold for clustering
     similarity_threshold: f64,
@@ -28,6 +33,7 @@ pub struct TopicRank {
     edge_weight: f64,
     /// Maximum number of phrases to cluster (for performance)
     max_phrases: usize,
+    ranker: R,
 }
 
 impl Default for TopicRank {
@@ -39,12 +45,7 @@ impl Default for TopicRank {
 impl TopicRank {
     /// Create a new TopicRank extractor
     pub fn new() -> Self {
-        Self {
-            config: TextRankConfig::default(),
-            similarity_threshold: 0.25,
-            edge_weight: 1.0,
-            max_phrases: 200,
-        }
+        Self::with_config(TextRankConfig::default())
     }
 
     /// Create with custom config
@@ -54,6 +55,20 @@ impl TopicRank {
             similarity_threshold: 0.25,
             edge_weight: 1.0,
             max_phrases: 200,
+            ranker: PageRankRanker,
+        }
+    }
+}
+
+impl<R: Ranker> TopicRank<R> {
+    /// Rank the cluster graph with `ranker` instead of PageRank
+    pub fn with_ranker<S: Ranker>(self, ranker: S) -> TopicRank<S> {
+        TopicRank {
+            config: self.config,
+            similarity_threshold: self.similarity_threshold,
+            edge_weight: self.edge_weight,
+            max_phrases: self.max_phrases,
+            ranker,
         }
     }
 
@@ -115,22 +130,19 @@ impl TopicRank {
         // Build cluster graph
         let (cluster_graph, cluster_members) = self.build_cluster_graph(&clusters, &candidates);
 
-        // Run PageRank on cluster graph
-        let pagerank = StandardPageRank::new()
-            .with_damping(self.config.damping)
-            .with_max_iterations(self.config.max_iterations)
-            .with_threshold(self.config.convergence_threshold)
-            .run(&cluster_graph);
+        // Rank the cluster graph
+        let cluster_graph = Graph::from_csr(cluster_graph);
+        let pagerank = self
+            .ranker
+            .rank(&cluster_graph, None, &self.config)
+            .into_pagerank_result();
 
         // Select best phrase from each top cluster
         let mut phrases = self.select_representatives(&cluster_members, &candidates, &pagerank);
+        PhraseFilter::for_config(&self.config).retain_phrases(&mut phrases);
 
-        // Sort by score (with stable tie-breakers in deterministic mode).
-        if self.config.determinism.is_deterministic() {
-            phrases.sort_by(|a, b| a.stable_cmp(b));
-        } else {
-            phrases.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
-        }
+        // Sort by score, breaking ties with the stable comparator.
+        phrases.sort_by(|a, b| a.stable_cmp(b));
 
         // Assign ranks
         for (i, phrase) in phrases.iter_mut().enumerate() {
@@ -145,7 +157,7 @@ impl TopicRank {
         // Build debug payload from legacy types if requested.
         let mut debug = crate::pipeline::artifacts::DebugPayload::build_from_legacy(
             self.config.debug_level,
-            &cluster_graph,
+            cluster_graph.csr(),
             &pagerank,
             self.config.debug_top_k,
         );
@@ -249,6 +261,16 @@ impl TopicRank {
                     })
                     .collect();
                 offsets.sort_by_key(|(start, _)| *start);
+                let mut spans: Vec<(usize, usize)> = members
+                    .iter()
+                    .map(|&idx| {
+                        (
+                            candidates[idx].chunk.start_char,
+                            candidates[idx].chunk.end_char,
+                        )
+                    })
+                    .collect();
+                self.config.span_mode.apply(&mut spans);
 
                 Phrase {
                     text: candidate.text.clone(),
@@ -256,6 +278,7 @@ impl TopicRank {
                     score: cluster_score,
                     count: members.len(),
                     offsets,
+                    spans,
                     rank: 0,
                 }
             })
@@ -518,17 +541,18 @@ mod tests {
         let result = TopicRank::with_config(config).extract_with_info(&tokens);
 
         assert!(result.converged, "PageRank should converge");
-        assert_eq!(result.iterations, 17);
-        assert_eq!(result.phrases.len(), 6);
+        assert_eq!(result.iterations, 14);
+        assert_eq!(result.phrases.len(), 5);
 
-        // Pin exact phrase order, lemmas, and approximate scores.
+        // Pin exact phrase order, lemmas, and approximate scores. Clustering
+        // by lemma puts "Machine learning ..." and "machine learning ..."
+        // (and both "deep learning" phrases) in one topic each.
         let expected = [
-            (1, "neural network", 0.2579807),
-            (2, "machine learning algorithm", 0.2114519),
-            (3, "deep learning model", 0.1531721),
-            (4, "data analysis", 0.1479700),
-            (5, "large dataset", 0.1399592),
-            (6, "deep learning application", 0.0894658),
+            (1, "neural network", 0.2633387),
+            (2, "deep learning model", 0.2191205),
+            (3, "machine learning algorithm", 0.2187046),
+            (4, "data analysis", 0.1533960),
+            (5, "large dataset", 0.1454401),
         ];
         for (phrase, &(rank, lemma, approx_score)) in result.phrases.iter().zip(expected.iter()) {
             assert_eq!(phrase.rank, rank, "rank mismatch for {:?}", phrase.lemma);
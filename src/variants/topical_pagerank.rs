@@ -8,22 +8,38 @@
 //! Users supply pre-computed topic weights (`lemma → weight`). Words absent
 //! from the map receive a configurable minimum weight (default 0.0, matching
 //! PKE's OOV behavior).
+//!
+//! The full Topical PageRank of Liu et al. (2010) is available through
+//! [`TopicalPageRank::with_topic`]: one personalized PageRank runs per topic
+//! (e.g. per LDA topic, with `p(word | topic)` as weights) and node scores
+//! are combined as a prior-weighted sum, `R(w) = Σ_t p(t) · R_t(w)`.
 
 use crate::graph::builder::GraphBuilder;
 use crate::graph::csr::CsrGraph;
-use crate::pagerank::personalized::{topic_weight_personalization, PersonalizedPageRank};
+use crate::pagerank::personalized::topic_weight_personalization;
+use crate::pagerank::PageRankResult;
 use crate::phrase::extraction::{ExtractionResult, PhraseExtractor};
+use crate::pipeline::artifacts::{Graph, TeleportType, TeleportVector};
+use crate::pipeline::traits::{PageRankRanker, Ranker};
 use crate::types::{Phrase, TextRankConfig, Token};
 use std::collections::HashMap;
 
 /// Topical PageRank implementation
+///
+/// Runs personalized PageRank unless another [`Ranker`] is set with
+/// [`with_ranker`](Self::with_ranker); the topic weights are passed to it as
+/// the teleport vector.
 #[derive(Debug)]
-pub struct TopicalPageRank {
+pub struct TopicalPageRank<R = PageRankRanker> {
     config: TextRankConfig,
     /// Topic importance weights: lemma → weight
     topic_weights: HashMap<String, f64>,
     /// Weight assigned to words absent from topic_weights (PKE default: 0.0)
     min_weight: f64,
+    /// Per-topic weight maps with their priors (full TPR); replaces
+    /// `topic_weights` when non-empty
+    topics: Vec<(HashMap<String, f64>, f64)>,
+    ranker: R,
 }
 
 impl Default for TopicalPageRank {
@@ -35,11 +51,7 @@ impl Default for TopicalPageRank {
 impl TopicalPageRank {
     /// Create a new TopicalPageRank extractor with default config
     pub fn new() -> Self {
-        Self {
-            config: TextRankConfig::default(),
-            topic_weights: HashMap::new(),
-            min_weight: 0.0,
-        }
+        Self::with_config(TextRankConfig::default())
     }
 
     /// Create with custom config
@@ -48,6 +60,21 @@ impl TopicalPageRank {
             config,
             topic_weights: HashMap::new(),
             min_weight: 0.0,
+            topics: Vec::new(),
+            ranker: PageRankRanker,
+        }
+    }
+}
+
+impl<R: Ranker> TopicalPageRank<R> {
+    /// Rank with `ranker` instead of personalized PageRank
+    pub fn with_ranker<S: Ranker>(self, ranker: S) -> TopicalPageRank<S> {
+        TopicalPageRank {
+            config: self.config,
+            topic_weights: self.topic_weights,
+            min_weight: self.min_weight,
+            topics: self.topics,
+            ranker,
         }
     }
 
@@ -63,6 +90,28 @@ impl TopicalPageRank {
         self
     }
 
+    /// Add a topic (lemma → weight) with prior probability `prior`.
+    ///
+    /// Once any topic is added, extraction runs one personalized PageRank
+    /// per topic and ignores [`with_topic_weights`](Self::with_topic_weights).
+    /// The topics share one co-occurrence graph, and each run warm-starts
+    /// from the previous topic's scores.
+    /// Priors are normalized to sum to 1; negative priors count as 0, and
+    /// if none is positive every topic gets the same prior.
+    pub fn with_topic(mut self, weights: HashMap<String, f64>, prior: f64) -> Self {
+        self.topics.push((weights, prior));
+        self
+    }
+
+    /// Add several topics with their priors; see [`with_topic`](Self::with_topic).
+    pub fn with_topics(
+        mut self,
+        topics: impl IntoIterator<Item = (HashMap<String, f64>, f64)>,
+    ) -> Self {
+        self.topics.extend(topics);
+        self
+    }
+
     /// Extract keyphrases using Topical PageRank
     pub fn extract(&self, tokens: &[Token]) -> Vec<Phrase> {
         self.extract_with_info(tokens).phrases
@@ -70,6 +119,67 @@ impl TopicalPageRank {
 
     /// Extract keyphrases with PageRank convergence information
     pub fn extract_with_info(&self, tokens: &[Token]) -> ExtractionResult {
+        if !self.topics.is_empty() {
+            return self.extract_multi_topic(tokens);
+        }
+
+        let Some(graph) = self.build_graph(tokens) else {
+            return ExtractionResult {
+                phrases: Vec::new(),
+                converged: true,
+                iterations: 0,
+                debug: None,
+            };
+        };
+        let pagerank = self.rank(&graph, &self.topic_weights, None);
+        let mut result = self.finish(tokens, graph.csr(), &pagerank);
+        if let Some(debug) = &mut result.debug {
+            debug.set_teleport(&self.personalization(graph.csr(), &self.topic_weights));
+        }
+        result
+    }
+
+    /// Full TPR: one personalized PageRank per topic, prior-weighted sum.
+    fn extract_multi_topic(&self, tokens: &[Token]) -> ExtractionResult {
+        let Some(shared) = self.build_graph(tokens) else {
+            return ExtractionResult {
+                phrases: Vec::new(),
+                converged: true,
+                iterations: 0,
+                debug: None,
+            };
+        };
+
+        let priors: Vec<f64> = self.topics.iter().map(|(_, p)| p.max(0.0)).collect();
+        let total: f64 = priors.iter().sum();
+        let priors: Vec<f64> = if total > 0.0 {
+            priors.iter().map(|p| p / total).collect()
+        } else {
+            vec![1.0 / priors.len() as f64; priors.len()]
+        };
+
+        let mut scores = vec![0.0; shared.num_nodes()];
+        let mut iterations = 0;
+        let mut converged = true;
+        let mut delta: f64 = 0.0;
+        let mut previous: Option<Vec<f64>> = None;
+        for ((weights, _), prior) in self.topics.iter().zip(&priors) {
+            let result = self.rank(&shared, weights, previous.take());
+            for (total, score) in scores.iter_mut().zip(&result.scores) {
+                *total += prior * score;
+            }
+            iterations += result.iterations;
+            converged &= result.converged;
+            delta = delta.max(result.delta);
+            previous = Some(result.scores);
+        }
+
+        let pagerank = PageRankResult::new(scores, iterations, delta, converged);
+        self.finish(tokens, shared.csr(), &pagerank)
+    }
+
+    /// SingleRank-style co-occurrence graph, or `None` if it has no nodes.
+    fn build_graph(&self, tokens: &[Token]) -> Option<Graph> {
         let include_pos = if self.config.include_pos.is_empty() {
             None
         } else {
@@ -82,46 +192,59 @@ impl TopicalPageRank {
             self.config.window_size,
             true, // always weighted co-occurrence counts
             include_pos,
-            self.config.use_pos_in_nodes,
+            self.config.effective_node_key(),
             false, // ignore sentence boundaries
         );
 
-        if builder.is_empty() {
-            return ExtractionResult {
-                phrases: Vec::new(),
-                converged: true,
-                iterations: 0,
-                debug: None,
-            };
-        }
+        (!builder.is_empty()).then(|| Graph::from_builder(&builder))
+    }
 
-        let graph = CsrGraph::from_builder(&builder);
+    /// Rank biased by one topic's weights.
+    fn rank(
+        &self,
+        graph: &Graph,
+        weights: &HashMap<String, f64>,
+        initial_scores: Option<Vec<f64>>,
+    ) -> PageRankResult {
+        let personalization = self.personalization(graph.csr(), weights);
+        let mut teleport = TeleportVector::zeros(personalization.len(), TeleportType::Topic);
+        teleport.as_mut_slice().copy_from_slice(&personalization);
+        teleport.normalize();
+
+        let output = match initial_scores {
+            Some(scores) => self
+                .ranker
+                .rank_warm(graph, Some(&teleport), &self.config, &scores),
+            None => self.ranker.rank(graph, Some(&teleport), &self.config),
+        };
+        output.into_pagerank_result()
+    }
 
-        // Build personalization vector from topic weights
-        let personalization = topic_weight_personalization(
-            &self.topic_weights,
-            &graph,
+    /// Unnormalized teleport weights for one topic.
+    fn personalization(&self, graph: &CsrGraph, weights: &HashMap<String, f64>) -> Vec<f64> {
+        topic_weight_personalization(
+            weights,
+            graph,
             &self.config.include_pos,
-            self.config.use_pos_in_nodes,
+            self.config.effective_node_key(),
             self.min_weight,
-        );
-
-        // Run Personalized PageRank
-        let pagerank = PersonalizedPageRank::new()
-            .with_damping(self.config.damping)
-            .with_max_iterations(self.config.max_iterations)
-            .with_threshold(self.config.convergence_threshold)
-            .with_personalization(personalization)
-            .run(&graph);
+        )
+    }
 
+    fn finish(
+        &self,
+        tokens: &[Token],
+        graph: &CsrGraph,
+        pagerank: &PageRankResult,
+    ) -> ExtractionResult {
         let extractor = PhraseExtractor::with_config(self.config.clone());
-        let phrases = extractor.extract(tokens, &graph, &pagerank);
+        let phrases = extractor.extract(tokens, graph, pagerank);
 
         // Build debug payload from legacy types if requested.
         let debug = crate::pipeline::artifacts::DebugPayload::build_from_legacy(
             self.config.debug_level,
-            &graph,
-            &pagerank,
+            graph,
+            pagerank,
             self.config.debug_top_k,
         );
 
@@ -142,6 +265,11 @@ impl TopicalPageRank {
     pub fn min_weight(&self) -> f64 {
         self.min_weight
     }
+
+    /// Get the per-topic weight maps and their (unnormalized) priors
+    pub fn topics(&self) -> &[(HashMap<String, f64>, f64)] {
+        &self.topics
+    }
 }
 
 /// Convenience function to extract keyphrases using Topical PageRank
@@ -172,6 +300,7 @@ mod tests {
             sentence_idx: sent,
             token_idx: idx,
             is_stopword: false,
+            section: None,
         }
     }
 
@@ -206,6 +335,34 @@ mod tests {
         assert!(result.converged);
     }
 
+    #[test]
+    fn test_custom_ranker() {
+        use crate::pipeline::traits::DegreeRanker;
+
+        let tokens = sample_tokens();
+        let weights: HashMap<String, f64> = [("machine".to_string(), 1.0)].into();
+        let explicit = TopicalPageRank::new()
+            .with_topic_weights(weights.clone())
+            .with_ranker(PageRankRanker)
+            .extract_with_info(&tokens);
+        let default = TopicalPageRank::new()
+            .with_topic_weights(weights.clone())
+            .extract_with_info(&tokens);
+        assert_eq!(explicit, default);
+
+        // Degree centrality ignores the topic teleport vector.
+        let degree = |weights| {
+            TopicalPageRank::new()
+                .with_topic_weights(weights)
+                .with_ranker(DegreeRanker)
+                .extract_with_info(&tokens)
+        };
+        let result = degree(weights);
+        assert_eq!(result.iterations, 0);
+        assert!(!result.phrases.is_empty());
+        assert_eq!(result, degree(HashMap::new()));
+    }
+
     #[test]
     fn test_empty_input() {
         let tokens: Vec<Token> = Vec::new();
@@ -274,6 +431,63 @@ mod tests {
         }
     }
 
+    fn weights(pairs: &[(&str, f64)]) -> HashMap<String, f64> {
+        pairs.iter().map(|&(l, w)| (l.to_string(), w)).collect()
+    }
+
+    #[test]
+    fn test_single_topic_matches_single_tpr() {
+        let tokens = sample_tokens();
+        let config = TextRankConfig::default().with_top_n(10);
+        let w = weights(&[("machine", 0.8), ("learning", 0.6)]);
+
+        let single = TopicalPageRank::with_config(config.clone())
+            .with_topic_weights(w.clone())
+            .extract_with_info(&tokens);
+        let multi = TopicalPageRank::with_config(config)
+            .with_topic(w, 3.0)
+            .extract_with_info(&tokens);
+        assert_eq!(single, multi);
+    }
+
+    #[test]
+    fn test_multi_topic_prior_weighted_sum() {
+        // Distinct offsets so every noun phrase survives overlap resolution.
+        let mut tokens = sample_tokens();
+        for (i, t) in tokens.iter_mut().enumerate() {
+            t.start = i * 16;
+            t.end = t.start + t.text.len();
+        }
+        let config = TextRankConfig::default().with_top_n(10);
+        let machine = weights(&[("machine", 1.0)]);
+        let neural = weights(&[("neural", 1.0), ("network", 1.0)]);
+
+        let run = |priors: (f64, f64)| {
+            TopicalPageRank::with_config(config.clone())
+                .with_topics([(machine.clone(), priors.0), (neural.clone(), priors.1)])
+                .with_min_weight(0.1)
+                .extract_with_info(&tokens)
+        };
+        let score = |result: &ExtractionResult, lemma: &str| {
+            result
+                .phrases
+                .iter()
+                .find(|p| p.lemma.contains(lemma))
+                .map(|p| p.score)
+                .unwrap()
+        };
+
+        // Priors are normalized.
+        assert_eq!(run((0.5, 0.5)), run((4.0, 4.0)));
+        // Shifting prior mass toward a topic raises its words.
+        let machine_heavy = run((0.9, 0.1));
+        let neural_heavy = run((0.1, 0.9));
+        assert!(score(&machine_heavy, "machine") > score(&neural_heavy, "machine"));
+        assert!(score(&neural_heavy, "neural") > score(&machine_heavy, "neural"));
+
+        assert!(machine_heavy.converged && neural_heavy.converged);
+    }
+
     #[test]
     fn test_min_weight_affects_oov() {
         let tokens = sample_tokens();
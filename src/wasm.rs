@@ -0,0 +1,105 @@
+//! WebAssembly bindings via wasm-bindgen
+//!
+//! Build for the browser with the default features off:
+//!
+//! ```text
+//! wasm-pack build --target web -- --no-default-features --features wasm
+//! ```
+//!
+//! ```js
+//! import init, { extract } from "./pkg/rapid_textrank.js";
+//! await init();
+//! const result = JSON.parse(extract(text, JSON.stringify({ top_n: 5 }), "position_rank"));
+//! ```
+//!
+//! Extraction runs on the calling thread. The result is the versioned JSON
+//! schema of [`JsonOutput`].
+
+use wasm_bindgen::prelude::*;
+
+use crate::nlp::tokenizer::Tokenizer;
+use crate::phrase::chunker::NounChunker;
+use crate::pipeline::artifacts::TokenStream;
+use crate::pipeline::json_output::JsonOutput;
+use crate::pipeline::observer::NoopObserver;
+use crate::pipeline::spec::{resolve_spec, PipelineSpec};
+use crate::pipeline::spec_builder::SpecPipelineBuilder;
+use crate::types::TextRankConfig;
+
+/// Extract keyphrases from `text`.
+///
+/// `config_json` is an object of [`TextRankConfig`] field overrides (e.g.
+/// `{"top_n": 5, "language": "de"}`); pass `"{}"` or an empty string for the
+/// defaults. `variant` is a preset name and defaults to `"textrank"`.
+///
+/// Returns the [`JsonOutput`] JSON string, or throws an `Error` whose
+/// message starts with the error code in brackets.
+#[wasm_bindgen]
+pub fn extract(text: &str, config_json: &str, variant: Option<String>) -> Result<String, JsError> {
+    extract_json(text, config_json, variant.as_deref()).map_err(|e| JsError::new(&e))
+}
+
+fn extract_json(text: &str, config_json: &str, variant: Option<&str>) -> Result<String, String> {
+    let overrides: serde_json::Value = if config_json.trim().is_empty() {
+        serde_json::Value::Object(Default::default())
+    } else {
+        serde_json::from_str(config_json).map_err(|e| format!("invalid config JSON: {e}"))?
+    };
+    let spec = serde_json::json!({
+        "v": 1,
+        "preset": variant.unwrap_or("textrank"),
+        "config": overrides,
+    });
+    let spec: PipelineSpec = serde_json::from_value(spec).map_err(|e| e.to_string())?;
+
+    // Tokenization and chunking need the effective config before the
+    // pipeline can be built.
+    let base = TextRankConfig::default();
+    let config = resolve_spec(&spec)
+        .and_then(|resolved| resolved.apply_config(&base))
+        .map_err(|e| e.to_string())?;
+    config.validate().map_err(|e| e.to_string())?;
+
+    let tokens = Tokenizer::new().tokenize_with_config(text, &config);
+    let chunks = NounChunker::new()
+        .with_min_length(config.min_phrase_length)
+        .with_max_length(config.max_phrase_length)
+        .with_bridge_words(&config.phrase_bridge_words)
+        .extract_chunks(&tokens);
+    let (pipeline, config) = SpecPipelineBuilder::new()
+        .with_chunks(chunks)
+        .build_with_config(&spec, &base)
+        .map_err(|e| e.to_string())?;
+
+    let result = pipeline.run(
+        TokenStream::from_tokens(&tokens),
+        &config,
+        &mut NoopObserver,
+    );
+    if let Some(err) = &result.error {
+        return Err(err.to_string());
+    }
+    JsonOutput::from_result(&result)
+        .to_json()
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_json() {
+        let text = "Machine learning is a subset of artificial intelligence. \
+                    Machine learning models learn from data.";
+        let json = extract_json(text, r#"{"top_n": 2}"#, Some("position_rank")).unwrap();
+        let output: JsonOutput = serde_json::from_str(&json).unwrap();
+        assert_eq!(output.phrases.len(), 2);
+
+        let default = extract_json(text, "", None).unwrap();
+        assert!(default.starts_with(r#"{"schema_version":1"#));
+
+        let err = extract_json(text, "{}", Some("nope")).unwrap_err();
+        assert!(err.starts_with("[invalid_value]"));
+    }
+}
@@ -0,0 +1,67 @@
+//! Tests for the `rapid-textrank` binary.
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const TEXT: &str = "Machine learning is a subset of artificial intelligence. \
+                    Machine learning models learn from data.";
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rapid-textrank"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_text_to_versioned_json() {
+    let output = run(&["--variant", "position_rank", "--top-n", "3"], TEXT);
+    assert!(output.status.success());
+
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["schema_version"], 1);
+    assert_eq!(value["phrases"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn test_json_tokens_to_tsv_with_timing() {
+    let tokens = r#"{"tokens": [
+        {"text": "Graph", "lemma": "graph", "pos": "NOUN", "start": 0, "end": 5, "sentence_idx": 0, "token_idx": 0},
+        {"text": "ranking", "lemma": "ranking", "pos": "NOUN", "start": 6, "end": 13, "sentence_idx": 0, "token_idx": 1},
+        {"text": "scales", "lemma": "scale", "pos": "VERB", "start": 14, "end": 20, "sentence_idx": 0, "token_idx": 2}
+    ]}"#;
+    let output = run(&["-i", "json", "--format", "tsv", "--timing"], tokens);
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.lines();
+    assert_eq!(
+        lines.next(),
+        Some("doc_id\trank\tphrase\tlemma\tscore\tfirst_offset")
+    );
+    assert!(lines.next().unwrap().starts_with("-\t1\tGraph ranking\t"));
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.lines().any(|l| l.starts_with("rank ")));
+    assert!(stderr.lines().any(|l| l.starts_with("total ")));
+}
+
+#[test]
+fn test_unknown_variant_fails() {
+    let output = run(&["--variant", "nope"], TEXT);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("unknown preset"));
+}
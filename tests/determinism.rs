@@ -0,0 +1,114 @@
+//! Reproducibility tests: byte-identical JSON output on a fixture corpus
+
+use rapid_textrank::nlp::tokenizer::Tokenizer;
+use rapid_textrank::variants::topic_rank::TopicRank;
+use rapid_textrank::*;
+
+/// Fixture corpus: repeated terms, equal-frequency candidates (score ties),
+/// Unicode, and a one-sentence document.
+const CORPUS: &[&str] = &[
+    "Machine learning is a subset of artificial intelligence. Machine learning \
+     systems learn from data. Deep learning uses neural networks, and neural \
+     networks need large datasets.",
+    "Graph ranking scores words. Sentence ranking scores sentences. Keyword \
+     extraction and keyword ranking rely on graph ranking.",
+    "Red apples. Green pears. Blue plums. Yellow lemons.",
+    "Café owners in Zürich serve café crème. Zürich tourists prefer café crème.",
+    "Solar power and wind power complement hydro power in modern energy grids.",
+];
+
+fn json(phrases: &[Phrase]) -> String {
+    serde_json::to_string(phrases).unwrap()
+}
+
+/// JSON output of every extractor over the corpus, one line per document.
+fn corpus_json(config: &TextRankConfig) -> Vec<String> {
+    let tokenizer = Tokenizer::new();
+    let mut out = Vec::new();
+    for text in CORPUS {
+        let tokens = tokenizer.tokenize_with_config(text, config);
+        out.push(json(&extract_from_text_with_info(text, config).phrases));
+        out.push(json(
+            &PositionRank::with_config(config.clone())
+                .extract_with_info(&tokens)
+                .phrases,
+        ));
+        out.push(json(
+            &SingleRank::with_config(config.clone())
+                .extract_with_info(&tokens)
+                .phrases,
+        ));
+        out.push(json(
+            &TopicRank::with_config(config.clone())
+                .extract_with_info(&tokens)
+                .phrases,
+        ));
+        out.push(json(
+            &MultipartiteRank::with_config(config.clone())
+                .extract_with_info(&tokens)
+                .phrases,
+        ));
+    }
+    out
+}
+
+fn deterministic() -> TextRankConfig {
+    TextRankConfig::default().with_determinism(DeterminismMode::Deterministic)
+}
+
+#[test]
+fn test_repeated_runs_are_byte_identical() {
+    let config = deterministic();
+    let first = corpus_json(&config);
+    for _ in 0..5 {
+        assert_eq!(corpus_json(&config), first);
+    }
+}
+
+#[test]
+fn test_default_mode_matches_deterministic_below_parallel_threshold() {
+    assert_eq!(
+        corpus_json(&TextRankConfig::default()),
+        corpus_json(&deterministic())
+    );
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_parallel_ranking_matches_serial() {
+    // A threshold of 1 ranks every graph with parallel PageRank.
+    let parallel = TextRankConfig::default().with_parallel_threshold(1);
+    assert_eq!(corpus_json(&parallel), corpus_json(&deterministic()));
+    assert_eq!(
+        corpus_json(&parallel.with_determinism(DeterminismMode::Deterministic)),
+        corpus_json(&deterministic())
+    );
+}
+
+#[test]
+fn test_batch_matches_sequential() {
+    let config = deterministic();
+    let docs: Vec<BatchDocument> = CORPUS.iter().map(|t| BatchDocument::Text(t)).collect();
+    let batch: Vec<String> = extract_batch(&docs, &config)
+        .iter()
+        .map(|r| json(&r.phrases))
+        .collect();
+    let sequential: Vec<String> = CORPUS
+        .iter()
+        .map(|t| json(&extract_from_text_with_info(t, &config).phrases))
+        .collect();
+    assert_eq!(batch, sequential);
+}
+
+#[test]
+fn test_tied_phrases_follow_first_occurrence() {
+    // Four disconnected, symmetric sentences: every phrase scores the same.
+    let phrases = extract_from_text_with_info(CORPUS[2], &TextRankConfig::default()).phrases;
+    let lemmas: Vec<&str> = phrases.iter().map(|p| p.lemma.as_str()).collect();
+    assert_eq!(
+        lemmas,
+        ["red apple", "green pear", "blue plum", "yellow lemon"]
+    );
+    let ranks: Vec<usize> = phrases.iter().map(|p| p.rank).collect();
+    assert_eq!(ranks, [1, 2, 3, 4]);
+}